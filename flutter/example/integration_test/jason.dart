@@ -83,6 +83,25 @@ void main() {
     videoDevice.free();
   });
 
+  testWidgets('LocalMediaTrack.setMirror', (WidgetTester tester) async {
+    var jason = await Jason.init();
+    var mediaManager = jason.mediaManager();
+
+    var settings = MediaStreamSettings();
+    settings.deviceVideo(DeviceVideoTrackConstraints());
+    var tracks = await mediaManager.initLocalTracks(settings);
+    var track = tracks.first;
+
+    expect(track.isMirrored(), isFalse);
+    track.setMirror(true);
+    expect(track.isMirrored(), isTrue);
+    track.setMirror(false);
+    expect(track.isMirrored(), isFalse);
+
+    await track.free();
+    expect(() => track.isMirrored(), throwsA(isA<StateError>()));
+  });
+
   testWidgets('DeviceVideoTrackConstraints', (WidgetTester tester) async {
     var constraints = DeviceVideoTrackConstraints();
     constraints.deviceId('deviceId');
@@ -131,6 +150,12 @@ void main() {
     await room.disableRemoteAudio();
     await room.enableRemoteAudio();
     await room.disableRemoteVideo(MediaSourceKind.device);
+    await room.setMaxSendResolution(1280, 720);
+    await room.resetMaxSendResolution();
+    room.renegotiate();
+    room.setDisconnectGracePeriod(3000);
+    room.setStatsSendingEnabled(false);
+    room.setStatsSendingEnabled(true);
 
     dynamic formatExc;
     try {
@@ -154,6 +179,62 @@ void main() {
     room.free();
   });
 
+  testWidgets('Jason.setRpcCompression', (WidgetTester tester) async {
+    var jason = await Jason.init();
+
+    for (var compression in RpcCompression.values) {
+      jason.setRpcCompression(compression);
+      var room = jason.initRoom();
+
+      dynamic rpcExc;
+      try {
+        await room.join('ws://127.0.0.1:1/ws/room/member?token=test');
+      } catch (e) {
+        rpcExc = e;
+      }
+      expect(rpcExc, isA<RpcClientException>());
+
+      jason.closeRoom(room);
+    }
+
+    jason.free();
+  });
+
+  testWidgets('RoomHandle.state', (WidgetTester tester) async {
+    var jason = await Jason.init();
+    var room = jason.initRoom();
+
+    expect(room.state(), equals(RoomState.new_));
+
+    jason.closeRoom(room);
+    expect(room.state(), equals(RoomState.closed));
+
+    jason.free();
+    room.free();
+  });
+
+  testWidgets('RoomHandle.leave', (WidgetTester tester) async {
+    var jason = await Jason.init();
+    var room = jason.initRoom();
+
+    await room.leave(10000);
+    expect(room.state(), equals(RoomState.closed));
+
+    jason.free();
+    room.free();
+  });
+
+  testWidgets('Jason.disposeAsync', (WidgetTester tester) async {
+    var jason = await Jason.init();
+    jason.setTokenRefresher(() async => 'token');
+    var room = jason.initRoom();
+
+    await jason.disposeAsync(10000);
+    expect(room.state(), equals(RoomState.closed));
+
+    room.free();
+  });
+
   testWidgets('Primitive arguments Callback validation', (
     WidgetTester widgetTester,
   ) async {
@@ -1,5 +1,8 @@
 export 'src/interface/connection_handle.dart';
 export 'src/interface/exceptions.dart';
+export 'src/interface/jason.dart' show RpcCompression;
+export 'src/interface/local_media_fallback.dart';
+export 'src/interface/log_event.dart';
 export 'src/interface/media_device_details.dart';
 export 'src/interface/media_manager.dart';
 export 'src/interface/media_track.dart';
@@ -7,6 +10,9 @@ export 'src/interface/media_display_details.dart';
 export 'src/interface/reconnect_handle.dart';
 export 'src/interface/room_close_reason.dart';
 export 'src/interface/room_handle.dart';
+export 'src/interface/room_lifecycle_event.dart';
+export 'src/interface/whep_client.dart';
+export 'src/interface/whip_client.dart';
 export 'src/native/jason.dart'
     if (dart.library.js_interop) 'src/web/jason.dart';
 export 'src/native/audio_track_constraints.dart'
@@ -18,3 +24,5 @@ export 'src/native/display_video_track_constraints.dart'
     if (dart.library.js_interop) 'src/web/display_video_track_constraints.dart';
 export 'src/native/media_stream_settings.dart'
     if (dart.library.js_interop) 'src/web/media_stream_settings.dart';
+export 'src/native/recorder.dart'
+    if (dart.library.js_interop) 'src/web/recorder.dart';
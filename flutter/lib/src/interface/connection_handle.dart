@@ -1,3 +1,5 @@
+import 'dart:typed_data';
+
 import '/src/util/rust_handles_storage.dart';
 import 'media_track.dart';
 
@@ -37,6 +39,24 @@ abstract class ConnectionHandle implements SyncPlatformHandle {
   /// [ConnectionHandle].
   void onQualityScoreUpdate(void Function(int) f);
 
+  /// Sets callback, invoked when inbound media quality of this `Connection`
+  /// switches between good (`true`) and poor (`false`).
+  ///
+  /// Throws a [StateError] if an underlying object has been disposed, e.g.
+  /// [free] was called on this [ConnectionHandle], or on a [Jason], or on a
+  /// `RoomHandle` that implicitly owns native object behind this
+  /// [ConnectionHandle].
+  void onInboundQualityUpdate(void Function(bool) f);
+
+  /// Sets callback, invoked when an application message is received from the
+  /// remote `Member` of this `Connection`.
+  ///
+  /// Throws a [StateError] if an underlying object has been disposed, e.g.
+  /// [free] was called on this [ConnectionHandle], or on a [Jason], or on a
+  /// `RoomHandle` that implicitly owns native object behind this
+  /// [ConnectionHandle].
+  void onApplicationMessage(void Function(Uint8List) f);
+
   /// Enables inbound audio in this `Connection`.
   ///
   /// Throws a [StateError] if the underlying [Pointer] has been freed.
@@ -72,4 +92,19 @@ abstract class ConnectionHandle implements SyncPlatformHandle {
   /// [ConnectionHandle.enableRemoteVideo] was called while disabling or a
   /// media server didn't approve this state transition.
   Future<void> disableRemoteVideo([MediaSourceKind? kind]);
+
+  /// Requests the provided [spatial] and [temporal] layer of all the inbound
+  /// video in this `Connection` from a media server.
+  ///
+  /// If [temporal] is not provided, then a media server is free to choose
+  /// any temporal layer.
+  ///
+  /// Throws a [StateError] if the underlying [Pointer] has been freed.
+  void requestQuality(int spatial, [int? temporal]);
+
+  /// Indicates whether all the inbound media of the provided [kind] in this
+  /// `Connection` is muted by the remote `Member`.
+  ///
+  /// Throws a [StateError] if the underlying [Pointer] has been freed.
+  bool isRemoteMuted(MediaKind kind);
 }
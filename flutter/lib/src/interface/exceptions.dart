@@ -45,6 +45,12 @@ enum RpcClientExceptionKind {
 
   /// RPC session has been finished. This is a terminal state.
   sessionFinished,
+
+  /// HTTP request to a WHIP/WHEP endpoint failed.
+  ///
+  /// This usually means that the endpoint is unreachable, so the request may
+  /// be retried later.
+  httpRequestFailed,
 }
 
 /// Kind of a [MediaStateTransitionException].
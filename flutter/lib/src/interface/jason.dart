@@ -1,7 +1,13 @@
 import '../util/move_semantic.dart';
 import '/src/util/rust_handles_storage.dart';
+import 'enums.dart' show RpcCompression;
 import 'media_manager.dart';
+import 'media_stream_settings.dart';
 import 'room_handle.dart';
+import 'whep_client.dart';
+import 'whip_client.dart';
+
+export 'enums.dart' show RpcCompression;
 
 /// General library interface.
 ///
@@ -14,6 +20,58 @@ abstract class Jason implements SyncPlatformHandle {
   /// Creates a new `Room` and returns its [RoomHandle].
   RoomHandle initRoom();
 
+  /// Publishes the local media described by the provided [settings] to the
+  /// [WHIP] endpoint with the provided [url], bypassing the Medea signalling.
+  ///
+  /// The provided bearer [token] (if any) authorizes all the requests to the
+  /// endpoint.
+  ///
+  /// Throws a `RpcClientException` if the endpoint is unreachable or rejects
+  /// the publishing.
+  ///
+  /// Throws an `InternalException` if the SDP negotiation fails.
+  ///
+  /// [WHIP]: https://tools.ietf.org/html/rfc9725
+  Future<WhipClient> publishWhip(
+    String url,
+    MediaStreamSettings settings, {
+    String? token,
+  });
+
+  /// Creates a new [WhepClient] playing remote media from a [WHEP] endpoint,
+  /// bypassing the Medea signalling.
+  ///
+  /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+  WhepClient whepClient();
+
+  /// Sets the [RpcCompression] negotiated by the connections to media servers
+  /// established afterwards.
+  ///
+  /// Messages are sent uncompressed by default. [RpcCompression.deflate]
+  /// shrinks large messages (like SDP offers) several times, at the cost of
+  /// some CPU time on both sides.
+  ///
+  /// Browsers negotiate compression on their own, so this is a no-op on the
+  /// web.
+  void setRpcCompression(RpcCompression compression);
+
   /// Closes the `Room` by the provided [RoomHandle].
   void closeRoom(@moveSemantics RoomHandle room);
+
+  /// Sets the callback invoked before each reconnection to a media server to
+  /// fetch a fresh authorization token.
+  ///
+  /// If the [Future] returned by the callback fails, then the reconnection
+  /// attempt fails and is retried with a backoff.
+  ///
+  /// Affects only the rooms initialized after this call.
+  void setTokenRefresher(Future<String> Function() f);
+
+  /// Gracefully disposes this [Jason].
+  ///
+  /// Leaves all the `Room`s waiting for a media server to acknowledge it
+  /// during the provided [timeoutMs], stops all the local media tracks and
+  /// then frees this [Jason] the same way as [free] does.
+  @moveSemantics
+  Future<void> disposeAsync(int timeoutMs);
 }
@@ -0,0 +1,18 @@
+import '/src/util/rust_handles_storage.dart';
+import 'media_stream_settings.dart';
+
+/// Fallback of local media acquisition to the previous [MediaStreamSettings],
+/// made because the requested ones couldn't be applied.
+///
+/// This object is passed to the `RoomHandle.onLocalMediaFallback()` callback.
+abstract class LocalMediaFallback implements SyncPlatformHandle {
+  /// Returns [MediaStreamSettings] failed to be applied.
+  ///
+  /// Throws a [StateError] if the underlying [Pointer] has been freed.
+  MediaStreamSettings attempted();
+
+  /// Returns [MediaStreamSettings] applied instead of the attempted ones.
+  ///
+  /// Throws a [StateError] if the underlying [Pointer] has been freed.
+  MediaStreamSettings succeeded();
+}
@@ -0,0 +1,21 @@
+/// Structured log event emitted by this library.
+///
+/// This object is passed into the `setLogSink()` callback.
+class LogEvent {
+  /// Severity of this [LogEvent] (`error`, `warn`, `info`, `debug` or
+  /// `trace`).
+  final String level;
+
+  /// Category of this [LogEvent] (`ice`, `media`, `negotiation`,
+  /// `signalling` or `stats`).
+  final String category;
+
+  /// Human-readable message of this [LogEvent].
+  final String message;
+
+  /// ID of the `PeerConnection` this [LogEvent] relates to, if any.
+  final int? peerId;
+
+  /// Creates a new [LogEvent] with the provided parameters.
+  const LogEvent(this.level, this.category, this.message, [this.peerId]);
+}
@@ -65,6 +65,17 @@ abstract class LocalMediaTrack implements MediaTrack {
   /// active, or a [MediaStreamTrackState.ended] if it has ended.
   Future<MediaStreamTrackState> state();
 
+  /// Sets whether video of this [LocalMediaTrack] is intended to be mirrored
+  /// (e.g. for a front camera self-view).
+  ///
+  /// Frames are not transformed, so the renderer is expected to apply
+  /// mirroring on its own according to [isMirrored].
+  void setMirror(bool mirror);
+
+  /// Indicates whether video of this [LocalMediaTrack] is intended to be
+  /// mirrored.
+  bool isMirrored();
+
   /// Indicates whether this [LocalMediaTrack] supports audio processing
   /// functions:
   /// - [LocalMediaTrack.isNoiseSuppressionEnabled]
@@ -164,6 +175,18 @@ abstract class RemoteMediaTrack implements MediaTrack {
   /// Sets callback to invoke when this [RemoteMediaTrack] is unmuted.
   void onUnmuted(void Function() f);
 
+  /// Indicates whether forwarding of this [RemoteMediaTrack] is paused by a
+  /// media server.
+  bool paused();
+
+  /// Sets callback to invoke when this [RemoteMediaTrack] is paused by a
+  /// media server.
+  void onPaused(void Function() f);
+
+  /// Sets callback to invoke when this [RemoteMediaTrack] is resumed by a
+  /// media server.
+  void onResumed(void Function() f);
+
   /// Sets callback to invoke when this [RemoteMediaTrack] is stopped.
   void onStopped(void Function() f);
 
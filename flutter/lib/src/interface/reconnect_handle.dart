@@ -4,6 +4,20 @@ import '/src/util/rust_handles_storage.dart';
 ///
 /// This handle is passed to the `RoomHandle.onConnectionLoss()` callback.
 abstract class ReconnectHandle implements SyncPlatformHandle {
+  /// Returns the WebSocket close code the connection has been closed with by
+  /// a media server, if any.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  int? closeCode();
+
+  /// Returns the WebSocket close reason the connection has been closed with
+  /// by a media server, if any.
+  ///
+  /// Allows to decide whether to re-authorize or just to retry.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  String? closeReason();
+
   /// Tries to reconnect a `Room` after the provided delay in milliseconds.
   ///
   /// If the `Room` is already reconnecting then new reconnection attempt won't
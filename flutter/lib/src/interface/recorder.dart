@@ -0,0 +1,53 @@
+import 'dart:typed_data';
+
+import '/src/util/rust_handles_storage.dart';
+import 'media_track.dart';
+
+/// Recorder composing the added [LocalMediaTrack]s and [RemoteMediaTrack]s
+/// into a single recording.
+///
+/// All the recorded tracks should be added before the first [start] call.
+abstract class Recorder implements SyncPlatformHandle {
+  /// Adds the provided [LocalMediaTrack] to be recorded.
+  void addLocalTrack(LocalMediaTrack track);
+
+  /// Adds the provided [RemoteMediaTrack] to be recorded.
+  void addRemoteTrack(RemoteMediaTrack track);
+
+  /// Starts recording.
+  ///
+  /// If a [timesliceMs] is provided, then the recorded data is emitted into
+  /// the [onData] callback in chunks of the provided duration, otherwise it's
+  /// emitted as a single chunk once the recording is stopped.
+  ///
+  /// Throws a [StateError] if recording is not supported or is started
+  /// already.
+  ///
+  /// Throws a `FormatException` if more than one video track has been added.
+  ///
+  /// Throws an `InternalException` if the platform rejects the added tracks or
+  /// the provided options.
+  void start([int? timesliceMs]);
+
+  /// Pauses recording.
+  ///
+  /// Throws a [StateError] if recording is not started or is stopped.
+  void pause();
+
+  /// Resumes paused recording.
+  ///
+  /// Throws a [StateError] if recording is not started or is stopped.
+  void resume();
+
+  /// Stops recording.
+  ///
+  /// Completes once all the recorded data is emitted into the [onData]
+  /// callback, or right away if recording is not started or is stopped
+  /// already.
+  Future<void> stop();
+
+  /// Sets callback, invoked with each recorded data chunk.
+  ///
+  /// Concatenated in order, the chunks form the whole recording.
+  void onData(void Function(Uint8List) f);
+}
@@ -1,9 +1,16 @@
+import 'dart:typed_data';
+
 import '/src/util/rust_handles_storage.dart';
 import 'connection_handle.dart';
+import 'enums.dart' show RoomState;
+import 'local_media_fallback.dart';
 import 'media_stream_settings.dart';
 import 'media_track.dart';
 import 'reconnect_handle.dart';
 import 'room_close_reason.dart';
+import 'room_lifecycle_event.dart';
+
+export 'enums.dart' show RoomState;
 
 /// External handle to a `Room`.
 abstract class RoomHandle implements SyncPlatformHandle {
@@ -23,6 +30,16 @@ abstract class RoomHandle implements SyncPlatformHandle {
   /// Throws `RpcClientException` if could not connect to media server.
   Future<void> join(String token);
 
+  /// Gracefully leaves this `Room`.
+  ///
+  /// Sends the final stats to a media server, asks it to remove the `Member`
+  /// from this `Room` and waits for its acknowledgement during the provided
+  /// [timeoutMs]. Then closes this `Room` regardless of whether the
+  /// acknowledgement has been received.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  Future<void> leave(int timeoutMs);
+
   /// Updates this `Room`'s [MediaStreamSettings]. This affects all the
   /// `PeerConnection`s in this `Room`. If [MediaStreamSettings] are configured
   /// for some `Room`, then this `Room` can only send media tracks that
@@ -193,6 +210,12 @@ abstract class RoomHandle implements SyncPlatformHandle {
   /// Throws [StateError] if the underlying [Pointer] has been freed.
   void onClose(void Function(RoomCloseReason) f);
 
+  /// Sets callback, invoked on each [RoomLifecycleEvent] of this `Room`
+  /// (joining, joined, connection lost, reconnecting and closed).
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  void onLifecycle(void Function(RoomLifecycleEvent) f);
+
   /// Sets callback, invoked when a new [LocalMediaTrack] is added to this
   /// `Room`.
   ///
@@ -216,4 +239,130 @@ abstract class RoomHandle implements SyncPlatformHandle {
   ///
   /// Throws [StateError] if the underlying [Pointer] has been freed.
   void onFailedLocalMedia(void Function(Object) f);
+
+  /// Sets callback, invoked when a [MediaStreamSettings] update fails and the
+  /// previous settings are restored instead (see the `rollbackOnFail` argument
+  /// of the [RoomHandle.setLocalMediaSettings()]).
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  void onLocalMediaFallback(void Function(LocalMediaFallback) f);
+
+  /// Enables loss-based resolution downgrade of the video sent in this
+  /// `Room`.
+  ///
+  /// Once the fraction of lost packets (in `0.0..=1.0` range) stays above the
+  /// [highFractionLost] for the [sustain] period, the resolution of each sent
+  /// video is scaled down by the [step] (up to the [maxScale] factor), and
+  /// once it stays below the [lowFractionLost], it's scaled back up.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  void enableLossBasedResolution({
+    required double highFractionLost,
+    required double lowFractionLost,
+    required Duration sustain,
+    required double step,
+    required double maxScale,
+  });
+
+  /// Disables loss-based resolution downgrade enabled via
+  /// [RoomHandle.enableLossBasedResolution], restoring the original
+  /// resolution of the sent video.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  void disableLossBasedResolution();
+
+  /// Enables adaptive bitrate of the video sent in this `Room`.
+  ///
+  /// Once the round trip time exceeds the [highRtt] or the fraction of lost
+  /// packets (in `0.0..=1.0` range) exceeds the [highFractionLost], the
+  /// maximum bitrate of each sent video is decreased, and otherwise it's
+  /// increased back, within the [minBitrate]..[maxBitrate] range (in bits per
+  /// second).
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  void enableAdaptiveBitrate({
+    required int minBitrate,
+    required int maxBitrate,
+    required Duration highRtt,
+    required double highFractionLost,
+  });
+
+  /// Disables adaptive bitrate enabled via
+  /// [RoomHandle.enableAdaptiveBitrate], leaving the last applied bitrate of
+  /// the sent video as is.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  void disableAdaptiveBitrate();
+
+  /// Requests Media Server to renegotiate all the peer connections of this
+  /// `Room`, so fresh SDP offers are generated.
+  ///
+  /// If a negotiation is in progress already, then the renegotiation is
+  /// queued, and all the calls made meanwhile are coalesced into a single
+  /// follow-up negotiation.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  void renegotiate();
+
+  /// Sets the period (in milliseconds) a peer connection may stay
+  /// disconnected before it's reported as such (including via `Connection`s'
+  /// quality scores).
+  ///
+  /// If the peer connection recovers within this period, no disconnect is
+  /// reported at all. `0` (the default) disables the grace period.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  void setDisconnectGracePeriod(int periodMs);
+
+  /// Enables or disables sending `RTCStats` to the media server by all the
+  /// current and future `PeerConnection`s of this `Room`.
+  ///
+  /// `RTCStats` are still scraped while sending is disabled, so local stats
+  /// APIs keep working.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  void setStatsSendingEnabled(bool enabled);
+
+  /// Enables or disables early media in this `Room`.
+  ///
+  /// Once enabled, negotiations don't wait for local media acquisition (e.g.
+  /// while a media permission prompt is pending), so remote media is received
+  /// right away, and the local media is added once acquired.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  void setEarlyMedia(bool enabled);
+
+  /// Sends the provided application [data] to all the other `Member`s of this
+  /// `Room`, which receive it via a [ConnectionHandle.onApplicationMessage]
+  /// callback.
+  ///
+  /// The [data] must not exceed 4 KiB.
+  ///
+  /// Throws a [StateError] if the underlying [Pointer] has been freed, or if
+  /// the provided [data] is too large.
+  void sendApplicationMessage(Uint8List data);
+
+  /// Returns the current [RoomState] of this `Room`.
+  ///
+  /// Never throws, returning [RoomState.closed] if this `Room` has been closed
+  /// already.
+  RoomState state();
+
+  /// Sets the maximum resolution of the video sent in this `Room`, regardless
+  /// of what is captured.
+  ///
+  /// Applied via [scaleResolutionDownBy][1] of each video sender, based on its
+  /// current capture resolution, and re-applied whenever the capture
+  /// resolution changes.
+  ///
+  /// Throws a [StateError] if the underlying [Pointer] has been freed.
+  ///
+  /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+  Future<void> setMaxSendResolution(int width, int height);
+
+  /// Removes the maximum resolution of the sent video set via
+  /// [RoomHandle.setMaxSendResolution].
+  ///
+  /// Throws a [StateError] if the underlying [Pointer] has been freed.
+  Future<void> resetMaxSendResolution();
 }
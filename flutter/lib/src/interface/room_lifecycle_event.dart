@@ -0,0 +1,35 @@
+import 'room_close_reason.dart';
+
+/// Kind of a [RoomLifecycleEvent].
+enum RoomLifecycleEventKind {
+  /// `Room` is connecting to a media server and joining it.
+  joining,
+
+  /// `Room` has joined a media server.
+  joined,
+
+  /// `Room` has lost its connection with a media server.
+  connectionLost,
+
+  /// `Room` is reconnecting to a media server after a
+  /// [RoomLifecycleEventKind.connectionLost].
+  reconnecting,
+
+  /// `Room` has been closed.
+  closed,
+}
+
+/// Event of a `Room`'s connection lifecycle.
+///
+/// This object is passed into the `RoomHandle.onLifecycle()` callback.
+class RoomLifecycleEvent {
+  /// Kind of this [RoomLifecycleEvent].
+  final RoomLifecycleEventKind kind;
+
+  /// Reason of the `Room` closing, if this is a
+  /// [RoomLifecycleEventKind.closed] event.
+  final RoomCloseReason? closeReason;
+
+  /// Creates a new [RoomLifecycleEvent] of the provided [kind].
+  const RoomLifecycleEvent(this.kind, [this.closeReason]);
+}
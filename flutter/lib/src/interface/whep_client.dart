@@ -0,0 +1,61 @@
+import '/src/util/rust_handles_storage.dart';
+import 'connection_handle.dart';
+
+/// Client playing remote media from a [WHEP] endpoint.
+///
+/// Created via the `Jason.whepClient()` method. The received tracks are
+/// exposed via the [ConnectionHandle] passed to the [onNewConnection]
+/// callback.
+///
+/// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+abstract class WhepClient implements SyncPlatformHandle {
+  /// Sets callback, invoked once the remote media starts being received,
+  /// providing the [ConnectionHandle] its tracks are exposed via.
+  ///
+  /// Should be set before calling [play].
+  ///
+  /// Throws a [StateError] if the underlying [Pointer] has been freed.
+  void onNewConnection(void Function(ConnectionHandle) f);
+
+  /// Sets callback, invoked when the playing fails after the [WHEP] session
+  /// has been started (the `PeerConnection` fails).
+  ///
+  /// Throws a [StateError] if the underlying [Pointer] has been freed.
+  ///
+  /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+  void onFailure(void Function(Object) f);
+
+  /// Starts playing the remote media from the [WHEP] endpoint with the
+  /// provided [url].
+  ///
+  /// The provided bearer [token] (if any) authorizes all the requests to the
+  /// endpoint.
+  ///
+  /// Throws a [StateError] if the playing has been already started.
+  ///
+  /// Throws a `RpcClientException` if the endpoint is unreachable or rejects
+  /// the playing.
+  ///
+  /// Throws an `InternalException` if the SDP negotiation fails.
+  ///
+  /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+  Future<void> play(String url, {String? token});
+
+  /// Returns URL of the [WHEP] session resource created by the endpoint, if
+  /// the playing has been started.
+  ///
+  /// Throws a [StateError] if the underlying [Pointer] has been freed.
+  ///
+  /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+  String? resource();
+
+  /// Terminates the [WHEP] session, if started.
+  ///
+  /// Throws a `RpcClientException` if the endpoint is unreachable or fails to
+  /// terminate the session.
+  ///
+  /// Throws a [StateError] if the underlying [Pointer] has been freed.
+  ///
+  /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+  Future<void> close();
+}
@@ -0,0 +1,34 @@
+import '/src/util/rust_handles_storage.dart';
+
+/// Client publishing local media to a [WHIP] endpoint.
+///
+/// Created via the `Jason.publishWhip()` method.
+///
+/// [WHIP]: https://tools.ietf.org/html/rfc9725
+abstract class WhipClient implements SyncPlatformHandle {
+  /// Returns URL of the [WHIP] session resource created by the endpoint.
+  ///
+  /// Throws a [StateError] if the underlying [Pointer] has been freed.
+  ///
+  /// [WHIP]: https://tools.ietf.org/html/rfc9725
+  String resource();
+
+  /// Sets a callback to be invoked when the publishing fails after the [WHIP]
+  /// session has been created (the `PeerConnection` fails or the local media
+  /// can't be updated).
+  ///
+  /// Throws a [StateError] if the underlying [Pointer] has been freed.
+  ///
+  /// [WHIP]: https://tools.ietf.org/html/rfc9725
+  void onFailure(void Function(Object) f);
+
+  /// Terminates the [WHIP] session.
+  ///
+  /// Throws a `RpcClientException` if the endpoint is unreachable or fails to
+  /// terminate the session.
+  ///
+  /// Throws a [StateError] if the underlying [Pointer] has been freed.
+  ///
+  /// [WHIP]: https://tools.ietf.org/html/rfc9725
+  Future<void> close();
+}
@@ -1,3 +1,5 @@
+import 'dart:typed_data';
+
 import 'package:medea_jason/src/native/remote_media_track.dart';
 import '../interface/connection_handle.dart';
 import '../interface/media_track.dart';
@@ -41,6 +43,22 @@ class NativeConnectionHandle implements ConnectionHandle {
     opaque.inner.onQualityScoreUpdate(f: f);
   }
 
+  @override
+  void onInboundQualityUpdate(void Function(bool) f) {
+    opaque.inner.onInboundQualityUpdate(f: f);
+  }
+
+  @override
+  void onApplicationMessage(void Function(Uint8List) f) {
+    opaque.inner.onApplicationMessage(
+      f: (t) {
+        var msg = frb.ApplicationMessage.fromPtr(ptr: t.address);
+        f(msg.data());
+        msg.dispose();
+      },
+    );
+  }
+
   @moveSemantics
   @override
   void free() {
@@ -70,4 +88,14 @@ class NativeConnectionHandle implements ConnectionHandle {
   Future<void> disableRemoteVideo([MediaSourceKind? kind]) async {
     await (opaque.inner.disableRemoteVideo(sourceKind: kind) as Future);
   }
+
+  @override
+  void requestQuality(int spatial, [int? temporal]) {
+    opaque.inner.requestQuality(spatial: spatial, temporal: temporal);
+  }
+
+  @override
+  bool isRemoteMuted(MediaKind kind) {
+    return opaque.inner.isRemoteMuted(kind: kind);
+  }
 }
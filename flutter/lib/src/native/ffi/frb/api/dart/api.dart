@@ -39,12 +39,21 @@ void logDartException({required String message, required String stackTrace}) =>
 void onPanic({required Object cb}) =>
     RustLib.instance.api.crateApiDartApiOnPanic(cb: cb);
 
+/// Removes the sink set via [`set_log_sink()`], so the [`log`] facade is used
+/// again.
+void resetLogSink() => RustLib.instance.api.crateApiDartApiResetLogSink();
+
 /// Sets the provided [`DART_HANDLER_PORT`].
 void setDartOpaqueMessagePort({required PlatformInt64 dartHandlerPort}) =>
     RustLib.instance.api.crateApiDartApiSetDartOpaqueMessagePort(
       dartHandlerPort: dartHandlerPort,
     );
 
+/// Sets the provided Dart callback as a sink receiving all the [`LogEvent`]s
+/// instead of the [`log`] facade.
+void setLogSink({required Object cb}) =>
+    RustLib.instance.api.crateApiDartApiSetLogSink(cb: cb);
+
 abstract class ForeignClass {}
 
 /// Constraints applicable to audio tracks.
@@ -0,0 +1,27 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../../../frb_generated.dart';
+import '../api.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `from`
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ApplicationMessage>>
+abstract class ApplicationMessage
+    implements RustOpaqueInterface, ForeignClass {
+  /// Returns the received application data.
+  Uint8List data();
+
+  /// Constructs a [`ForeignClass`] from the given raw pointer via
+  /// [`Box::from_raw()`].
+  ///
+  /// # Safety
+  ///
+  /// Same as for [`Box::from_raw()`].
+  static ApplicationMessage fromPtr({required int ptr}) => RustLib.instance.api
+      .crateApiDartApiApplicationMessageApplicationMessageFromPtr(ptr: ptr);
+}
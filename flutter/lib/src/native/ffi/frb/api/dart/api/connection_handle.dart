@@ -6,6 +6,7 @@
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 import '../../../frb_generated.dart';
+import '../../../media.dart';
 import '../../../media/track.dart';
 import '../api.dart';
 
@@ -45,6 +46,15 @@ abstract class ConnectionHandle implements RustOpaqueInterface, ForeignClass {
   /// If the [`core::ConnectionHandle::get_remote_member_id()`] method errors.
   String getRemoteMemberId();
 
+  /// Sets a callback to be invoked once an application message is received
+  /// from the remote `Member` of the associated [`Connection`].
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::ConnectionHandle::on_application_message()`] method
+  /// errors.
+  void onApplicationMessage({required Object f});
+
   /// Sets a callback to be invoked once the associated [`Connection`] is
   /// closed.
   ///
@@ -72,4 +82,26 @@ abstract class ConnectionHandle implements RustOpaqueInterface, ForeignClass {
   ///
   /// [`remote::Track`]: media::track::remote::Track
   void onRemoteTrackAdded({required Object f});
+
+  /// Sets a callback to be invoked once the inbound media quality of the
+  /// associated [`Connection`] switches between good (`true`) and poor
+  /// (`false`).
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::ConnectionHandle::on_inbound_quality_update()`] method
+  /// errors.
+  void onInboundQualityUpdate({required Object f});
+
+  /// Requests the provided spatial and temporal layer of all the inbound
+  /// video in the associated [`Connection`] from a media server.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::ConnectionHandle::request_quality()`] method errors.
+  void requestQuality({required int spatial, int? temporal});
+
+  /// Indicates whether all the inbound media of the provided [`MediaKind`]
+  /// in the associated [`Connection`] is muted by the remote `Member`.
+  bool isRemoteMuted({required MediaKind kind});
 }
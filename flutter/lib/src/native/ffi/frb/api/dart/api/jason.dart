@@ -6,8 +6,11 @@
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 import '../../../frb_generated.dart';
+import '../../../platform/transport.dart';
+import '../api.dart';
 import 'media_manager.dart';
 import 'room.dart';
+import 'whep_client.dart';
 
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `from`
 
@@ -25,6 +28,50 @@ abstract class Jason implements RustOpaqueInterface {
   /// Returns a [`MediaManagerHandle`].
   MediaManagerHandle jasonMediaManager();
 
+  /// Publishes the local media described by the provided
+  /// [`ApiMediaStreamSettings`] to the [WHIP] endpoint with the provided
+  /// `url`, bypassing the Medea signalling.
+  ///
+  /// The provided bearer `token` (if any) authorizes all the requests to the
+  /// endpoint.
+  ///
+  /// Returns a [`api::WhipClient`] once the publishing starts.
+  ///
+  /// [WHIP]: https://tools.ietf.org/html/rfc9725
+  Object jasonPublishWhip({
+    required String url,
+    String? token,
+    required ApiMediaStreamSettings settings,
+  });
+
+  /// Sets the [`RpcCompression`] negotiated by the connections to media
+  /// servers established afterwards.
+  void jasonSetRpcCompression({required RpcCompression compression});
+
+  /// Creates a new [`api::WhepClient`] playing remote media from a [WHEP]
+  /// endpoint, bypassing the Medea signalling.
+  ///
+  /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+  WhepClient jasonWhepClient();
+
   /// Instantiates a new [`Jason`] interface to interact with this library.
   factory Jason() => RustLib.instance.api.crateApiDartApiJasonJasonNew();
+
+  /// Sets the callback invoked before each reconnection to a media server to
+  /// fetch a fresh authorization token.
+  ///
+  /// The callback should return a `Future` resolving into the token string.
+  /// If it fails, the reconnection attempt fails and is retried with a
+  /// backoff.
+  ///
+  /// Affects only the rooms initialized after this call.
+  void jasonSetTokenRefresher({required Object cb});
+
+  /// Gracefully disposes this [`Jason`].
+  ///
+  /// Leaves all the [`Room`]s waiting for a media server to acknowledge it
+  /// during the provided `timeout_ms`, stops all the local media tracks and
+  /// then disposes this [`Jason`] the same way as [`Jason::jason_dispose()`]
+  /// does.
+  Object jasonDisposeAsync({required int timeoutMs});
 }
@@ -128,4 +128,15 @@ abstract class LocalMediaTrack implements RustOpaqueInterface, ForeignClass {
   /// [`LocalMediaTrack`] is active, or a
   /// [`media::MediaStreamTrackState::Ended`] if it has ended.
   Object state();
+
+  /// Sets whether video of this [`LocalMediaTrack`] is intended to be
+  /// mirrored (e.g. for a front camera self-view).
+  ///
+  /// Frames are not transformed, so the renderer is expected to apply
+  /// mirroring on its own.
+  void setMirror({required bool mirror});
+
+  /// Indicates whether video of this [`LocalMediaTrack`] is intended to be
+  /// mirrored.
+  bool isMirrored();
 }
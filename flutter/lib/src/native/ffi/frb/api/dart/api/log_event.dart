@@ -0,0 +1,38 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../../../frb_generated.dart';
+import '../api.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `from`
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LogEvent>>
+abstract class LogEvent implements RustOpaqueInterface, ForeignClass {
+  /// Returns category of this [`LogEvent`] (`ice`, `media`, `negotiation`,
+  /// `signalling` or `stats`).
+  String category();
+
+  /// Constructs a [`ForeignClass`] from the given raw pointer via
+  /// [`Box::from_raw()`].
+  ///
+  /// # Safety
+  ///
+  /// Same as for [`Box::from_raw()`].
+  static LogEvent fromPtr({required int ptr}) =>
+      RustLib.instance.api.crateApiDartApiLogEventLogEventFromPtr(ptr: ptr);
+
+  /// Returns severity of this [`LogEvent`] (`error`, `warn`, `info`,
+  /// `debug` or `trace`).
+  String level();
+
+  /// Returns human-readable message of this [`LogEvent`].
+  String message();
+
+  /// Returns ID of the `PeerConnection` this [`LogEvent`] relates to, if
+  /// any.
+  int? peerId();
+}
@@ -54,4 +54,14 @@ abstract class ReconnectHandle implements RustOpaqueInterface, ForeignClass {
   /// won't be performed. Instead, it will wait for the first reconnection
   /// attempt result and use it here.
   Object reconnectWithDelay({required int delayMs});
+
+  /// Returns the WebSocket close code the connection has been closed with by
+  /// a media server, if any.
+  int? closeCode();
+
+  /// Returns the WebSocket close reason the connection has been closed with
+  /// by a media server, if any.
+  ///
+  /// Allows to decide whether to re-authorize or just to retry.
+  String? closeReason();
 }
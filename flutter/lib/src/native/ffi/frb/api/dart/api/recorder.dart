@@ -0,0 +1,58 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../../../frb_generated.dart';
+import '../api.dart';
+import 'local_media_track.dart';
+import 'remote_media_track.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `from`
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Recorder>>
+abstract class Recorder implements RustOpaqueInterface {
+  /// Creates a new [`Recorder`] with the provided MIME type and target
+  /// bitrate (in bits per second) of the recording.
+  factory Recorder({String? mimeType, int? bitsPerSecond}) => RustLib
+      .instance
+      .api
+      .crateApiDartApiRecorderRecorderNew(
+        mimeType: mimeType,
+        bitsPerSecond: bitsPerSecond,
+      );
+
+  /// Adds the provided [`LocalMediaTrack`] to be recorded.
+  void addLocalTrack({required LocalMediaTrack track});
+
+  /// Adds the provided [`RemoteMediaTrack`] to be recorded.
+  void addRemoteTrack({required RemoteMediaTrack track});
+
+  /// Sets callback, invoked with a `List<int>` of each recorded data chunk.
+  ///
+  /// Concatenated in order, the chunks form the whole recording.
+  void onData({required Object f});
+
+  /// Pauses recording.
+  void pause();
+
+  /// Resumes paused recording.
+  void resume();
+
+  /// Starts recording.
+  ///
+  /// If a `timeslice_ms` is provided, then the recorded data is emitted into
+  /// the [`Recorder::on_data()`] callback in chunks of the provided
+  /// duration, otherwise it's emitted as a single chunk once the recording
+  /// is stopped.
+  void start({int? timesliceMs});
+
+  /// Stops recording.
+  ///
+  /// The returned [`Future`] resolves once all the recorded data is emitted
+  /// into the [`Recorder::on_data()`] callback, or right away if recording
+  /// is not started or is stopped already.
+  Object stop();
+}
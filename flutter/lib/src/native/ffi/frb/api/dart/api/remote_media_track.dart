@@ -55,4 +55,16 @@ abstract class RemoteMediaTrack implements RustOpaqueInterface, ForeignClass {
 
   /// Sets callback to invoke once this [`RemoteMediaTrack`] is unmuted.
   void onUnmuted({required Object f});
+
+  /// Sets callback to invoke once this [`RemoteMediaTrack`] is paused by a
+  /// media server.
+  void onPaused({required Object f});
+
+  /// Sets callback to invoke once this [`RemoteMediaTrack`] is resumed by a
+  /// media server.
+  void onResumed({required Object f});
+
+  /// Indicates whether forwarding of this [`RemoteMediaTrack`] is paused by a
+  /// media server.
+  bool paused();
 }
@@ -8,6 +8,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import '../../../frb_generated.dart';
 import '../../../media/constraints.dart';
 import '../../../media/track.dart';
+import '../../../room.dart';
 import '../api.dart';
 
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `from`
@@ -105,6 +106,24 @@ abstract class RoomHandle implements RustOpaqueInterface {
   /// If the [`core::RoomHandle::on_failed_local_media()`] method errors.
   void onFailedLocalMedia({required Object cb});
 
+  /// Sets a callback to be invoked on each [`RoomLifecycleEvent`] of the
+  /// [`Room`].
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandle::on_lifecycle()`] method errors.
+  ///
+  /// [`RoomLifecycleEvent`]: api::RoomLifecycleEvent
+  void onLifecycle({required Object cb});
+
+  /// Sets a callback to be invoked when local media settings update fails
+  /// and the previous settings are restored instead.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandle::on_local_media_fallback()`] method errors.
+  void onLocalMediaFallback({required Object cb});
+
   /// Sets a callback to be invoked once a new [`LocalMediaTrack`] is added
   /// to the provided [`Room`].
   ///
@@ -180,4 +199,128 @@ abstract class RoomHandle implements RustOpaqueInterface {
   ///
   /// If the provided `source_kind` is not a [`MediaSourceKind`] index.
   Object unmuteVideo({MediaSourceKind? sourceKind});
+
+  /// Enables loss-based resolution downgrade of the video sent in this
+  /// [Room].
+  void enableLossBasedResolution({
+    required double highFractionLost,
+    required double lowFractionLost,
+    required int sustainMs,
+    required double step,
+    required double maxScale,
+  });
+
+  /// Disables loss-based resolution downgrade, restoring the original
+  /// resolution of the sent video.
+  void disableLossBasedResolution();
+
+  /// Enables or disables early media in this [Room].
+  void setEarlyMedia({required bool enabled});
+
+  /// Sends the provided application `data` to all the other `Member`s of
+  /// the [`Room`], which receive it via a
+  /// [`ConnectionHandle::on_application_message()`] callback.
+  ///
+  /// The `data` must not exceed 4 KiB.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandle::send_application_message()`] method errors.
+  ///
+  /// [`ConnectionHandle::on_application_message()`]:
+  ///     super::ConnectionHandle::on_application_message
+  void sendApplicationMessage({required Uint8List data});
+
+  /// Enables adaptive bitrate of the video sent in this [`Room`].
+  ///
+  /// Once the round trip time exceeds the `high_rtt_ms` milliseconds or the
+  /// fraction of lost packets (in `0.0..=1.0` range) exceeds the
+  /// `high_fraction_lost`, the [maxBitrate][1] of each video sender is
+  /// decreased, and otherwise it's increased back, within the
+  /// `min_bitrate..=max_bitrate` range (in bits per second).
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandle::enable_adaptive_bitrate()`] method errors.
+  ///
+  /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-maxbitrate
+  void enableAdaptiveBitrate({
+    required int minBitrate,
+    required int maxBitrate,
+    required int highRttMs,
+    required double highFractionLost,
+  });
+
+  /// Disables adaptive bitrate enabled via
+  /// [`RoomHandle::enable_adaptive_bitrate()`], leaving the last applied
+  /// bitrate of the sent video as is.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandle::disable_adaptive_bitrate()`] method errors.
+  void disableAdaptiveBitrate();
+
+  /// Returns the current [`core::RoomState`] of the [`Room`].
+  ///
+  /// Never fails, returning [`core::RoomState::Closed`] if the [`Room`] has
+  /// been closed already.
+  RoomState state();
+
+  /// Sets the maximum resolution of the video sent in this [`Room`],
+  /// regardless of what is captured.
+  ///
+  /// Applied via [scaleResolutionDownBy][1] of each video sender, based on
+  /// its current capture resolution, and re-applied whenever the capture
+  /// resolution changes.
+  ///
+  /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+  Object setMaxSendResolution({required int width, required int height});
+
+  /// Removes the maximum resolution of the sent video set via
+  /// [`RoomHandle::set_max_send_resolution()`].
+  Object resetMaxSendResolution();
+
+  /// Requests Media Server to renegotiate all the peer connections of the
+  /// [`Room`], so fresh SDP offers are generated.
+  ///
+  /// If a negotiation is in progress already, then the renegotiation is
+  /// queued, and all the calls made meanwhile are coalesced into a single
+  /// follow-up negotiation.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandle::renegotiate_all()`] method errors.
+  void renegotiate();
+
+  /// Gracefully leaves this [`Room`].
+  ///
+  /// Sends the final stats to a media server, asks it to remove the
+  /// `Member` from this [`Room`] and waits for its acknowledgement during
+  /// the provided `timeout_ms`. Then closes this [`Room`] regardless of
+  /// whether the acknowledgement has been received.
+  Object leave({required int timeoutMs});
+
+  /// Enables or disables sending `RTCStats` to the media server by all the
+  /// current and future `PeerConnection`s of this [`Room`].
+  ///
+  /// `RTCStats` are still scraped while sending is disabled, so local stats
+  /// APIs keep working.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandle::set_stats_sending_enabled()`] method errors.
+  void setStatsSendingEnabled({required bool enabled});
+
+  /// Sets the period (in milliseconds) a peer connection may stay
+  /// disconnected before it's reported as such (including via
+  /// `Connection`s' quality scores).
+  ///
+  /// If the peer connection recovers within this period, no disconnect is
+  /// reported at all. `0` (the default) disables the grace period.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandle::set_disconnect_grace_period()`] method
+  /// errors.
+  void setDisconnectGracePeriod({required int periodMs});
 }
@@ -0,0 +1,62 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../../../frb_generated.dart';
+import 'room_close_reason.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `from`
+
+/// Event of a [`Room`]'s connection lifecycle.
+class RoomLifecycleEvent {
+  /// Kind of this [`RoomLifecycleEvent`].
+  final RoomLifecycleEventKind kind;
+
+  /// Reason of the [`Room`] closing, if this is a
+  /// [`RoomLifecycleEventKind::Closed`] event.
+  final RoomCloseReason? closeReason;
+
+  const RoomLifecycleEvent({required this.kind, this.closeReason});
+
+  /// Constructs a [`ForeignClass`] from the given raw pointer via
+  /// [`Box::from_raw()`].
+  ///
+  /// # Safety
+  ///
+  /// Same as for [`Box::from_raw()`].
+  static RoomLifecycleEvent fromPtr({required int ptr}) => RustLib.instance.api
+      .crateApiDartApiRoomLifecycleEventRoomLifecycleEventFromPtr(ptr: ptr);
+
+  @override
+  int get hashCode => kind.hashCode ^ closeReason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RoomLifecycleEvent &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          closeReason == other.closeReason;
+}
+
+/// Kind of a [`RoomLifecycleEvent`].
+enum RoomLifecycleEventKind {
+  /// [`Room`] is connecting to a media server and joining it.
+  joining,
+
+  /// [`Room`] has joined a media server.
+  joined,
+
+  /// [`Room`] has lost its connection with a media server.
+  connectionLost,
+
+  /// [`Room`] is reconnecting to a media server after a
+  /// [`RoomLifecycleEventKind::ConnectionLost`].
+  reconnecting,
+
+  /// [`Room`] has been closed.
+  closed,
+}
@@ -0,0 +1,47 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../../../frb_generated.dart';
+import '../api.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `from`
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WhepClient>>
+abstract class WhepClient implements RustOpaqueInterface {
+  /// Terminates the [WHEP] session, if started.
+  ///
+  /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+  Object close();
+
+  /// Sets a callback to be invoked when the playing fails after the [WHEP]
+  /// session has been started.
+  ///
+  /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+  void onFailure({required Object f});
+
+  /// Sets a callback to be invoked once the remote media starts being
+  /// received, providing the [`ConnectionHandle`] its tracks are exposed
+  /// via.
+  ///
+  /// Should be set before calling [`WhepClient::play()`].
+  void onNewConnection({required Object f});
+
+  /// Starts playing the remote media from the [WHEP] endpoint with the
+  /// provided `url`.
+  ///
+  /// The provided bearer `token` (if any) authorizes all the requests to the
+  /// endpoint.
+  ///
+  /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+  Object play({required String url, String? token});
+
+  /// Returns URL of the [WHEP] session resource created by the endpoint, if
+  /// the playing has been started.
+  ///
+  /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+  String? resource();
+}
@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../../../frb_generated.dart';
+import '../api.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `from`
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WhipClient>>
+abstract class WhipClient implements RustOpaqueInterface, ForeignClass {
+  /// Terminates the [WHIP] session.
+  ///
+  /// [WHIP]: https://tools.ietf.org/html/rfc9725
+  Object close();
+
+  /// Constructs a [`ForeignClass`] from the given raw pointer via
+  /// [`Box::from_raw()`].
+  ///
+  /// # Safety
+  ///
+  /// Same as for [`Box::from_raw()`].
+  static WhipClient fromPtr({required int ptr}) =>
+      RustLib.instance.api.crateApiDartApiWhipClientWhipClientFromPtr(ptr: ptr);
+
+  /// Sets a callback to be invoked when the publishing fails after the
+  /// [WHIP] session has been created.
+  ///
+  /// [WHIP]: https://tools.ietf.org/html/rfc9725
+  void onFailure({required Object f});
+
+  /// Returns URL of the [WHIP] session resource created by the endpoint.
+  ///
+  /// [WHIP]: https://tools.ietf.org/html/rfc9725
+  String resource();
+}
@@ -7,3 +7,10 @@ export 'api/dart/api/remote_media_track.dart';
 export 'api/dart/api/room_close_reason.dart';
 export 'api/dart/api/media_manager.dart';
 export 'api/dart/api/room.dart';
+export 'api/dart/api/recorder.dart';
+export 'api/dart/api/whip_client.dart';
+export 'api/dart/api/whep_client.dart';
+export 'api/dart/api/local_media_fallback.dart';
+export 'api/dart/api/room_lifecycle_event.dart';
+export 'api/dart/api/application_message.dart';
+export 'api/dart/api/log_event.dart';
@@ -17,11 +17,20 @@ import 'api/dart/api/reconnect_handle.dart';
 import 'api/dart/api/remote_media_track.dart';
 import 'api/dart/api/room.dart';
 import 'api/dart/api/room_close_reason.dart';
+import 'api/dart/api/log_event.dart';
+import 'api/dart/api/application_message.dart';
+import 'api/dart/api/room_lifecycle_event.dart';
+import 'api/dart/api/local_media_fallback.dart';
+import 'api/dart/api/whep_client.dart';
+import 'api/dart/api/whip_client.dart';
+import 'api/dart/api/recorder.dart';
 import 'frb_generated.dart';
 import 'media.dart';
 import 'media/constraints.dart';
 import 'media/track.dart';
 import 'media/track/remote.dart';
+import 'platform/transport.dart';
+import 'room.dart';
 
 import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
@@ -429,6 +438,10 @@ abstract class RustLibApi extends BaseApi {
     required int ptr,
   });
 
+  RoomLifecycleEvent crateApiDartApiRoomLifecycleEventRoomLifecycleEventFromPtr({
+    required int ptr,
+  });
+
   void crateApiDartApiSetDartOpaqueMessagePort({
     required PlatformInt64 dartHandlerPort,
   });
@@ -445,6 +458,126 @@ abstract class RustLibApi extends BaseApi {
     required int ptr,
   });
 
+  Recorder crateApiDartApiRecorderRecorderNew({String? mimeType, int? bitsPerSecond});
+
+  void crateApiDartApiRecorderRecorderAddLocalTrack({required Recorder that, required LocalMediaTrack track});
+
+  void crateApiDartApiRecorderRecorderAddRemoteTrack({required Recorder that, required RemoteMediaTrack track});
+
+  void crateApiDartApiRecorderRecorderOnData({required Recorder that, required Object f});
+
+  void crateApiDartApiRecorderRecorderPause({required Recorder that});
+
+  void crateApiDartApiRecorderRecorderResume({required Recorder that});
+
+  void crateApiDartApiRecorderRecorderStart({required Recorder that, int? timesliceMs});
+
+  Object crateApiDartApiRecorderRecorderStop({required Recorder that});
+
+  void crateApiDartApiRoomRoomHandleEnableLossBasedResolution({required RoomHandle that, required double highFractionLost, required double lowFractionLost, required int sustainMs, required double step, required double maxScale});
+
+  void crateApiDartApiRoomRoomHandleDisableLossBasedResolution({required RoomHandle that});
+
+  void crateApiDartApiRoomRoomHandleSetEarlyMedia({required RoomHandle that, required bool enabled});
+
+  Object crateApiDartApiWhipClientWhipClientClose({required WhipClient that});
+
+  WhipClient crateApiDartApiWhipClientWhipClientFromPtr({required int ptr});
+
+  void crateApiDartApiWhipClientWhipClientOnFailure({required WhipClient that, required Object f});
+
+  String crateApiDartApiWhipClientWhipClientResource({required WhipClient that});
+
+  Object crateApiDartApiJasonJasonJasonPublishWhip({required Jason that, required String url, String? token, required ApiMediaStreamSettings settings});
+
+  Object crateApiDartApiWhepClientWhepClientClose({required WhepClient that});
+
+  void crateApiDartApiWhepClientWhepClientOnFailure({required WhepClient that, required Object f});
+
+  void crateApiDartApiWhepClientWhepClientOnNewConnection({required WhepClient that, required Object f});
+
+  Object crateApiDartApiWhepClientWhepClientPlay({required WhepClient that, required String url, String? token});
+
+  String? crateApiDartApiWhepClientWhepClientResource({required WhepClient that});
+
+  WhepClient crateApiDartApiJasonJasonJasonWhepClient({required Jason that});
+
+  ApiMediaStreamSettings crateApiDartApiLocalMediaFallbackLocalMediaFallbackAttempted({required LocalMediaFallback that});
+
+  LocalMediaFallback crateApiDartApiLocalMediaFallbackLocalMediaFallbackFromPtr({required int ptr});
+
+  ApiMediaStreamSettings crateApiDartApiLocalMediaFallbackLocalMediaFallbackSucceeded({required LocalMediaFallback that});
+
+  void crateApiDartApiRoomRoomHandleOnLocalMediaFallback({required RoomHandle that, required Object cb});
+
+  void crateApiDartApiRoomRoomHandleOnLifecycle({required RoomHandle that, required Object cb});
+
+  Uint8List crateApiDartApiApplicationMessageApplicationMessageData({required ApplicationMessage that});
+
+  ApplicationMessage crateApiDartApiApplicationMessageApplicationMessageFromPtr({required int ptr});
+
+  void crateApiDartApiConnectionHandleConnectionHandleOnApplicationMessage({required ConnectionHandle that, required Object f});
+
+  void crateApiDartApiRoomRoomHandleSendApplicationMessage({required RoomHandle that, required Uint8List data});
+
+  String crateApiDartApiLogEventLogEventCategory({required LogEvent that});
+
+  LogEvent crateApiDartApiLogEventLogEventFromPtr({required int ptr});
+
+  String crateApiDartApiLogEventLogEventLevel({required LogEvent that});
+
+  String crateApiDartApiLogEventLogEventMessage({required LogEvent that});
+
+  int? crateApiDartApiLogEventLogEventPeerId({required LogEvent that});
+
+  void crateApiDartApiResetLogSink();
+
+  void crateApiDartApiSetLogSink({required Object cb});
+
+  void crateApiDartApiRoomRoomHandleEnableAdaptiveBitrate({required RoomHandle that, required int minBitrate, required int maxBitrate, required int highRttMs, required double highFractionLost});
+
+  void crateApiDartApiRoomRoomHandleDisableAdaptiveBitrate({required RoomHandle that});
+
+  void crateApiDartApiJasonJasonJasonSetRpcCompression({required Jason that, required RpcCompression compression});
+
+  RoomState crateApiDartApiRoomRoomHandleState({required RoomHandle that});
+
+  Object crateApiDartApiRoomRoomHandleSetMaxSendResolution({required RoomHandle that, required int width, required int height});
+
+  Object crateApiDartApiRoomRoomHandleResetMaxSendResolution({required RoomHandle that});
+
+  void crateApiDartApiRoomRoomHandleRenegotiate({required RoomHandle that});
+
+  Object crateApiDartApiRoomRoomHandleLeave({required RoomHandle that, required int timeoutMs});
+
+  void crateApiDartApiRoomRoomHandleSetStatsSendingEnabled({required RoomHandle that, required bool enabled});
+
+  void crateApiDartApiConnectionHandleConnectionHandleOnInboundQualityUpdate({required ConnectionHandle that, required Object f});
+
+  void crateApiDartApiConnectionHandleConnectionHandleRequestQuality({required ConnectionHandle that, required int spatial, int? temporal});
+
+  void crateApiDartApiRemoteMediaTrackRemoteMediaTrackOnPaused({required RemoteMediaTrack that, required Object f});
+
+  void crateApiDartApiRemoteMediaTrackRemoteMediaTrackOnResumed({required RemoteMediaTrack that, required Object f});
+
+  bool crateApiDartApiRemoteMediaTrackRemoteMediaTrackPaused({required RemoteMediaTrack that});
+
+  int? crateApiDartApiReconnectHandleReconnectHandleCloseCode({required ReconnectHandle that});
+
+  String? crateApiDartApiReconnectHandleReconnectHandleCloseReason({required ReconnectHandle that});
+
+  void crateApiDartApiRoomRoomHandleSetDisconnectGracePeriod({required RoomHandle that, required int periodMs});
+
+  void crateApiDartApiJasonJasonJasonSetTokenRefresher({required Jason that, required Object cb});
+
+  Object crateApiDartApiJasonJasonJasonDisposeAsync({required Jason that, required int timeoutMs});
+
+  bool crateApiDartApiConnectionHandleConnectionHandleIsRemoteMuted({required ConnectionHandle that, required MediaKind kind});
+
+  void crateApiDartApiLocalMediaTrackLocalMediaTrackSetMirror({required LocalMediaTrack that, required bool mirror});
+
+  bool crateApiDartApiLocalMediaTrackLocalMediaTrackIsMirrored({required LocalMediaTrack that});
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_ConnectionHandle;
 
@@ -487,6 +620,24 @@ abstract class RustLibApi extends BaseApi {
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_ReconnectHandlePtr;
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_LogEventPtr;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_ApplicationMessagePtr;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_LocalMediaFallbackPtr;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_WhepClientPtr;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_WhipClientPtr;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_RecorderPtr;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_RemoteMediaTrack;
 
@@ -1943,6 +2094,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["ptr"],
       );
 
+  TaskConstMeta
+  get kCrateApiDartApiLogEventLogEventFromPtrConstMeta =>
+      const TaskConstMeta(
+        debugName: "LogEvent_from_ptr",
+        argNames: ["ptr"],
+      );
+
+  TaskConstMeta
+  get kCrateApiDartApiApplicationMessageApplicationMessageFromPtrConstMeta =>
+      const TaskConstMeta(
+        debugName: "ApplicationMessage_from_ptr",
+        argNames: ["ptr"],
+      );
+
+  TaskConstMeta
+  get kCrateApiDartApiLocalMediaFallbackLocalMediaFallbackFromPtrConstMeta =>
+      const TaskConstMeta(
+        debugName: "LocalMediaFallback_from_ptr",
+        argNames: ["ptr"],
+      );
+
+  TaskConstMeta
+  get kCrateApiDartApiWhepClientWhepClientFromPtrConstMeta =>
+      const TaskConstMeta(
+        debugName: "WhepClient_from_ptr",
+        argNames: ["ptr"],
+      );
+
+  TaskConstMeta
+  get kCrateApiDartApiWhipClientWhipClientFromPtrConstMeta =>
+      const TaskConstMeta(
+        debugName: "WhipClient_from_ptr",
+        argNames: ["ptr"],
+      );
+
+  TaskConstMeta
+  get kCrateApiDartApiRecorderRecorderFromPtrConstMeta =>
+      const TaskConstMeta(
+        debugName: "Recorder_from_ptr",
+        argNames: ["ptr"],
+      );
+
   @override
   Object crateApiDartApiReconnectHandleReconnectHandleReconnectWithBackoff({
     required ReconnectHandle that,
@@ -1996,6 +2189,84 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         ],
       );
 
+  TaskConstMeta
+  get kCrateApiDartApiLogEventLogEventReconnectWithBackoffConstMeta =>
+      const TaskConstMeta(
+        debugName: "LogEvent_reconnect_with_backoff",
+        argNames: [
+          "that",
+          "startingDelay",
+          "multiplier",
+          "maxDelay",
+          "maxElapsedTimeMs",
+        ],
+      );
+
+  TaskConstMeta
+  get kCrateApiDartApiApplicationMessageApplicationMessageReconnectWithBackoffConstMeta =>
+      const TaskConstMeta(
+        debugName: "ApplicationMessage_reconnect_with_backoff",
+        argNames: [
+          "that",
+          "startingDelay",
+          "multiplier",
+          "maxDelay",
+          "maxElapsedTimeMs",
+        ],
+      );
+
+  TaskConstMeta
+  get kCrateApiDartApiLocalMediaFallbackLocalMediaFallbackReconnectWithBackoffConstMeta =>
+      const TaskConstMeta(
+        debugName: "LocalMediaFallback_reconnect_with_backoff",
+        argNames: [
+          "that",
+          "startingDelay",
+          "multiplier",
+          "maxDelay",
+          "maxElapsedTimeMs",
+        ],
+      );
+
+  TaskConstMeta
+  get kCrateApiDartApiWhepClientWhepClientReconnectWithBackoffConstMeta =>
+      const TaskConstMeta(
+        debugName: "WhepClient_reconnect_with_backoff",
+        argNames: [
+          "that",
+          "startingDelay",
+          "multiplier",
+          "maxDelay",
+          "maxElapsedTimeMs",
+        ],
+      );
+
+  TaskConstMeta
+  get kCrateApiDartApiWhipClientWhipClientReconnectWithBackoffConstMeta =>
+      const TaskConstMeta(
+        debugName: "WhipClient_reconnect_with_backoff",
+        argNames: [
+          "that",
+          "startingDelay",
+          "multiplier",
+          "maxDelay",
+          "maxElapsedTimeMs",
+        ],
+      );
+
+  TaskConstMeta
+  get kCrateApiDartApiRecorderRecorderReconnectWithBackoffConstMeta =>
+      const TaskConstMeta(
+        debugName: "Recorder_reconnect_with_backoff",
+        argNames: [
+          "that",
+          "startingDelay",
+          "multiplier",
+          "maxDelay",
+          "maxElapsedTimeMs",
+        ],
+      );
+
   @override
   Object crateApiDartApiReconnectHandleReconnectHandleReconnectWithDelay({
     required ReconnectHandle that,
@@ -2031,6 +2302,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "delayMs"],
       );
 
+  TaskConstMeta
+  get kCrateApiDartApiLogEventLogEventReconnectWithDelayConstMeta =>
+      const TaskConstMeta(
+        debugName: "LogEvent_reconnect_with_delay",
+        argNames: ["that", "delayMs"],
+      );
+
+  TaskConstMeta
+  get kCrateApiDartApiApplicationMessageApplicationMessageReconnectWithDelayConstMeta =>
+      const TaskConstMeta(
+        debugName: "ApplicationMessage_reconnect_with_delay",
+        argNames: ["that", "delayMs"],
+      );
+
+  TaskConstMeta
+  get kCrateApiDartApiLocalMediaFallbackLocalMediaFallbackReconnectWithDelayConstMeta =>
+      const TaskConstMeta(
+        debugName: "LocalMediaFallback_reconnect_with_delay",
+        argNames: ["that", "delayMs"],
+      );
+
+  TaskConstMeta
+  get kCrateApiDartApiWhepClientWhepClientReconnectWithDelayConstMeta =>
+      const TaskConstMeta(
+        debugName: "WhepClient_reconnect_with_delay",
+        argNames: ["that", "delayMs"],
+      );
+
+  TaskConstMeta
+  get kCrateApiDartApiWhipClientWhipClientReconnectWithDelayConstMeta =>
+      const TaskConstMeta(
+        debugName: "WhipClient_reconnect_with_delay",
+        argNames: ["that", "delayMs"],
+      );
+
+  TaskConstMeta
+  get kCrateApiDartApiRecorderRecorderReconnectWithDelayConstMeta =>
+      const TaskConstMeta(
+        debugName: "Recorder_reconnect_with_delay",
+        argNames: ["that", "delayMs"],
+      );
+
   @override
   RemoteMediaTrack crateApiDartApiRemoteMediaTrackRemoteMediaTrackFromPtr({
     required int ptr,
@@ -3066,6 +3379,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["ptr"],
       );
 
+  @override
+  RoomLifecycleEvent crateApiDartApiRoomLifecycleEventRoomLifecycleEventFromPtr({
+    required int ptr,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_CastedPrimitive_usize(ptr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_room_lifecycle_event,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiRoomLifecycleEventRoomLifecycleEventFromPtrConstMeta,
+        argValues: [ptr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiRoomLifecycleEventRoomLifecycleEventFromPtrConstMeta =>
+      const TaskConstMeta(
+        debugName: "room_lifecycle_event_from_ptr",
+        argNames: ["ptr"],
+      );
+
   @override
   void crateApiDartApiSetDartOpaqueMessagePort({
     required PlatformInt64 dartHandlerPort,
@@ -3181,25 +3524,1612 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["ptr"],
       );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ConnectionHandle => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectionHandle;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ConnectionHandle => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectionHandle;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_Jason => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_Jason => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason;
+  @override
+  Recorder crateApiDartApiRecorderRecorderNew({String? mimeType, int? bitsPerSecond}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(mimeType, serializer);
+          sse_encode_opt_box_autoadd_u_32(bitsPerSecond, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRecorderRecorderNewConstMeta,
+        argValues: [mimeType, bitsPerSecond],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_LocalMediaTrack => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack;
+  TaskConstMeta get kCrateApiDartApiRecorderRecorderNewConstMeta =>
+      const TaskConstMeta(debugName: "Recorder_new", argNames: ["mimeType", "bitsPerSecond"]);
+
+  @override
+  void crateApiDartApiRecorderRecorderAddLocalTrack({required Recorder that, required LocalMediaTrack track}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(
+            that,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
+            track,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRecorderRecorderAddLocalTrackConstMeta,
+        argValues: [that, track],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRecorderRecorderAddLocalTrackConstMeta =>
+      const TaskConstMeta(debugName: "Recorder_add_local_track", argNames: ["that", "track"]);
+
+  @override
+  void crateApiDartApiRecorderRecorderAddRemoteTrack({required Recorder that, required RemoteMediaTrack track}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(
+            that,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+            track,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRecorderRecorderAddRemoteTrackConstMeta,
+        argValues: [that, track],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRecorderRecorderAddRemoteTrackConstMeta =>
+      const TaskConstMeta(debugName: "Recorder_add_remote_track", argNames: ["that", "track"]);
+
+  @override
+  void crateApiDartApiRecorderRecorderOnData({required Recorder that, required Object f}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(
+            that,
+            serializer,
+          );
+          sse_encode_DartOpaque(f, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRecorderRecorderOnDataConstMeta,
+        argValues: [that, f],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRecorderRecorderOnDataConstMeta =>
+      const TaskConstMeta(debugName: "Recorder_on_data", argNames: ["that", "f"]);
+
+  @override
+  void crateApiDartApiRecorderRecorderPause({required Recorder that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiRecorderRecorderPauseConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRecorderRecorderPauseConstMeta =>
+      const TaskConstMeta(debugName: "Recorder_pause", argNames: ["that"]);
+
+  @override
+  void crateApiDartApiRecorderRecorderResume({required Recorder that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiRecorderRecorderResumeConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRecorderRecorderResumeConstMeta =>
+      const TaskConstMeta(debugName: "Recorder_resume", argNames: ["that"]);
+
+  @override
+  void crateApiDartApiRecorderRecorderStart({required Recorder that, int? timesliceMs}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(
+            that,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_u_32(timesliceMs, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiRecorderRecorderStartConstMeta,
+        argValues: [that, timesliceMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRecorderRecorderStartConstMeta =>
+      const TaskConstMeta(debugName: "Recorder_start", argNames: ["that", "timesliceMs"]);
+
+  @override
+  Object crateApiDartApiRecorderRecorderStop({required Recorder that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRecorderRecorderStopConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRecorderRecorderStopConstMeta =>
+      const TaskConstMeta(debugName: "Recorder_stop", argNames: ["that"]);
+
+  @override
+  void crateApiDartApiRoomRoomHandleEnableLossBasedResolution({required RoomHandle that, required double highFractionLost, required double lowFractionLost, required int sustainMs, required double step, required double maxScale}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          sse_encode_f_64(highFractionLost, serializer);
+          sse_encode_f_64(lowFractionLost, serializer);
+          sse_encode_u_32(sustainMs, serializer);
+          sse_encode_f_64(step, serializer);
+          sse_encode_f_64(maxScale, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleEnableLossBasedResolutionConstMeta,
+        argValues: [that, highFractionLost, lowFractionLost, sustainMs, step, maxScale],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleEnableLossBasedResolutionConstMeta =>
+      const TaskConstMeta(debugName: "RoomHandle_enable_loss_based_resolution", argNames: ["that", "highFractionLost", "lowFractionLost", "sustainMs", "step", "maxScale"]);
+
+  @override
+  void crateApiDartApiRoomRoomHandleDisableLossBasedResolution({required RoomHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleDisableLossBasedResolutionConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleDisableLossBasedResolutionConstMeta =>
+      const TaskConstMeta(debugName: "RoomHandle_disable_loss_based_resolution", argNames: ["that"]);
+
+  @override
+  void crateApiDartApiRoomRoomHandleSetEarlyMedia({required RoomHandle that, required bool enabled}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          sse_encode_bool(enabled, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleSetEarlyMediaConstMeta,
+        argValues: [that, enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleSetEarlyMediaConstMeta =>
+      const TaskConstMeta(debugName: "RoomHandle_set_early_media", argNames: ["that", "enabled"]);
+
+  @override
+  Object crateApiDartApiWhipClientWhipClientClose({required WhipClient that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhipClient(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiWhipClientWhipClientCloseConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiWhipClientWhipClientCloseConstMeta =>
+      const TaskConstMeta(debugName: "WhipClient_close", argNames: ["that"]);
+
+  @override
+  WhipClient crateApiDartApiWhipClientWhipClientFromPtr({required int ptr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_CastedPrimitive_usize(ptr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhipClient,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiWhipClientWhipClientFromPtrConstMeta,
+        argValues: [ptr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiWhipClientWhipClientFromPtrConstMeta =>
+      const TaskConstMeta(debugName: "WhipClient_from_ptr", argNames: ["ptr"]);
+
+  @override
+  void crateApiDartApiWhipClientWhipClientOnFailure({required WhipClient that, required Object f}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhipClient(
+            that,
+            serializer,
+          );
+          sse_encode_DartOpaque(f, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiWhipClientWhipClientOnFailureConstMeta,
+        argValues: [that, f],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiWhipClientWhipClientOnFailureConstMeta =>
+      const TaskConstMeta(debugName: "WhipClient_on_failure", argNames: ["that", "f"]);
+
+  @override
+  String crateApiDartApiWhipClientWhipClientResource({required WhipClient that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhipClient(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiWhipClientWhipClientResourceConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiWhipClientWhipClientResourceConstMeta =>
+      const TaskConstMeta(debugName: "WhipClient_resource", argNames: ["that"]);
+
+  @override
+  Object crateApiDartApiJasonJasonJasonPublishWhip({required Jason that, required String url, String? token, required ApiMediaStreamSettings settings}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason(
+            that,
+            serializer,
+          );
+          sse_encode_String(url, serializer);
+          sse_encode_opt_String(token, serializer);
+          sse_encode_box_autoadd_api_media_stream_settings(settings, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiJasonJasonJasonPublishWhipConstMeta,
+        argValues: [that, url, token, settings],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiJasonJasonJasonPublishWhipConstMeta =>
+      const TaskConstMeta(debugName: "Jason_jason_publish_whip", argNames: ["that", "url", "token", "settings"]);
+
+  @override
+  Object crateApiDartApiWhepClientWhepClientClose({required WhepClient that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiWhepClientWhepClientCloseConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiWhepClientWhepClientCloseConstMeta =>
+      const TaskConstMeta(debugName: "WhepClient_close", argNames: ["that"]);
+
+  @override
+  void crateApiDartApiWhepClientWhepClientOnFailure({required WhepClient that, required Object f}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient(
+            that,
+            serializer,
+          );
+          sse_encode_DartOpaque(f, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiWhepClientWhepClientOnFailureConstMeta,
+        argValues: [that, f],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiWhepClientWhepClientOnFailureConstMeta =>
+      const TaskConstMeta(debugName: "WhepClient_on_failure", argNames: ["that", "f"]);
+
+  @override
+  void crateApiDartApiWhepClientWhepClientOnNewConnection({required WhepClient that, required Object f}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient(
+            that,
+            serializer,
+          );
+          sse_encode_DartOpaque(f, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiWhepClientWhepClientOnNewConnectionConstMeta,
+        argValues: [that, f],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiWhepClientWhepClientOnNewConnectionConstMeta =>
+      const TaskConstMeta(debugName: "WhepClient_on_new_connection", argNames: ["that", "f"]);
+
+  @override
+  Object crateApiDartApiWhepClientWhepClientPlay({required WhepClient that, required String url, String? token}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient(
+            that,
+            serializer,
+          );
+          sse_encode_String(url, serializer);
+          sse_encode_opt_String(token, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiWhepClientWhepClientPlayConstMeta,
+        argValues: [that, url, token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiWhepClientWhepClientPlayConstMeta =>
+      const TaskConstMeta(debugName: "WhepClient_play", argNames: ["that", "url", "token"]);
+
+  @override
+  String? crateApiDartApiWhepClientWhepClientResource({required WhepClient that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiWhepClientWhepClientResourceConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiWhepClientWhepClientResourceConstMeta =>
+      const TaskConstMeta(debugName: "WhepClient_resource", argNames: ["that"]);
+
+  @override
+  WhepClient crateApiDartApiJasonJasonJasonWhepClient({required Jason that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiJasonJasonJasonWhepClientConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiJasonJasonJasonWhepClientConstMeta =>
+      const TaskConstMeta(debugName: "Jason_jason_whep_client", argNames: ["that"]);
+
+  @override
+  ApiMediaStreamSettings crateApiDartApiLocalMediaFallbackLocalMediaFallbackAttempted({required LocalMediaFallback that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaFallback(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_media_stream_settings,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiLocalMediaFallbackLocalMediaFallbackAttemptedConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiLocalMediaFallbackLocalMediaFallbackAttemptedConstMeta =>
+      const TaskConstMeta(debugName: "LocalMediaFallback_attempted", argNames: ["that"]);
+
+  @override
+  LocalMediaFallback crateApiDartApiLocalMediaFallbackLocalMediaFallbackFromPtr({required int ptr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_CastedPrimitive_usize(ptr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaFallback,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiLocalMediaFallbackLocalMediaFallbackFromPtrConstMeta,
+        argValues: [ptr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiLocalMediaFallbackLocalMediaFallbackFromPtrConstMeta =>
+      const TaskConstMeta(debugName: "LocalMediaFallback_from_ptr", argNames: ["ptr"]);
+
+  @override
+  ApiMediaStreamSettings crateApiDartApiLocalMediaFallbackLocalMediaFallbackSucceeded({required LocalMediaFallback that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaFallback(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_media_stream_settings,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiLocalMediaFallbackLocalMediaFallbackSucceededConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiLocalMediaFallbackLocalMediaFallbackSucceededConstMeta =>
+      const TaskConstMeta(debugName: "LocalMediaFallback_succeeded", argNames: ["that"]);
+
+  @override
+  void crateApiDartApiRoomRoomHandleOnLocalMediaFallback({required RoomHandle that, required Object cb}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          sse_encode_DartOpaque(cb, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleOnLocalMediaFallbackConstMeta,
+        argValues: [that, cb],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleOnLocalMediaFallbackConstMeta =>
+      const TaskConstMeta(debugName: "RoomHandle_on_local_media_fallback", argNames: ["that", "cb"]);
+
+  @override
+  void crateApiDartApiRoomRoomHandleOnLifecycle({required RoomHandle that, required Object cb}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          sse_encode_DartOpaque(cb, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleOnLifecycleConstMeta,
+        argValues: [that, cb],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleOnLifecycleConstMeta =>
+      const TaskConstMeta(debugName: "RoomHandle_on_lifecycle", argNames: ["that", "cb"]);
+
+  @override
+  Uint8List crateApiDartApiApplicationMessageApplicationMessageData({required ApplicationMessage that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApplicationMessage(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiApplicationMessageApplicationMessageDataConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiApplicationMessageApplicationMessageDataConstMeta =>
+      const TaskConstMeta(debugName: "ApplicationMessage_data", argNames: ["that"]);
+
+  @override
+  ApplicationMessage crateApiDartApiApplicationMessageApplicationMessageFromPtr({required int ptr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_CastedPrimitive_usize(ptr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApplicationMessage,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiApplicationMessageApplicationMessageFromPtrConstMeta,
+        argValues: [ptr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiApplicationMessageApplicationMessageFromPtrConstMeta =>
+      const TaskConstMeta(debugName: "ApplicationMessage_from_ptr", argNames: ["ptr"]);
+
+  @override
+  void crateApiDartApiConnectionHandleConnectionHandleOnApplicationMessage({required ConnectionHandle that, required Object f}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectionHandle(
+            that,
+            serializer,
+          );
+          sse_encode_DartOpaque(f, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiConnectionHandleConnectionHandleOnApplicationMessageConstMeta,
+        argValues: [that, f],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiConnectionHandleConnectionHandleOnApplicationMessageConstMeta =>
+      const TaskConstMeta(debugName: "ConnectionHandle_on_application_message", argNames: ["that", "f"]);
+
+  @override
+  void crateApiDartApiRoomRoomHandleSendApplicationMessage({required RoomHandle that, required Uint8List data}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_strict(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleSendApplicationMessageConstMeta,
+        argValues: [that, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleSendApplicationMessageConstMeta =>
+      const TaskConstMeta(debugName: "RoomHandle_send_application_message", argNames: ["that", "data"]);
+
+  @override
+  String crateApiDartApiLogEventLogEventCategory({required LogEvent that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEvent(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiLogEventLogEventCategoryConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiLogEventLogEventCategoryConstMeta =>
+      const TaskConstMeta(debugName: "LogEvent_category", argNames: ["that"]);
+
+  @override
+  LogEvent crateApiDartApiLogEventLogEventFromPtr({required int ptr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_CastedPrimitive_usize(ptr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEvent,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiLogEventLogEventFromPtrConstMeta,
+        argValues: [ptr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiLogEventLogEventFromPtrConstMeta =>
+      const TaskConstMeta(debugName: "LogEvent_from_ptr", argNames: ["ptr"]);
+
+  @override
+  String crateApiDartApiLogEventLogEventLevel({required LogEvent that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEvent(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiLogEventLogEventLevelConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiLogEventLogEventLevelConstMeta =>
+      const TaskConstMeta(debugName: "LogEvent_level", argNames: ["that"]);
+
+  @override
+  String crateApiDartApiLogEventLogEventMessage({required LogEvent that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEvent(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiLogEventLogEventMessageConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiLogEventLogEventMessageConstMeta =>
+      const TaskConstMeta(debugName: "LogEvent_message", argNames: ["that"]);
+
+  @override
+  int? crateApiDartApiLogEventLogEventPeerId({required LogEvent that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEvent(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiLogEventLogEventPeerIdConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiLogEventLogEventPeerIdConstMeta =>
+      const TaskConstMeta(debugName: "LogEvent_peer_id", argNames: ["that"]);
+
+  @override
+  void crateApiDartApiResetLogSink() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiResetLogSinkConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiResetLogSinkConstMeta =>
+      const TaskConstMeta(debugName: "reset_log_sink", argNames: []);
+
+  @override
+  void crateApiDartApiSetLogSink({required Object cb}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_DartOpaque(cb, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiSetLogSinkConstMeta,
+        argValues: [cb],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiSetLogSinkConstMeta =>
+      const TaskConstMeta(debugName: "set_log_sink", argNames: ["cb"]);
+
+  @override
+  void crateApiDartApiRoomRoomHandleEnableAdaptiveBitrate({required RoomHandle that, required int minBitrate, required int maxBitrate, required int highRttMs, required double highFractionLost}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          sse_encode_u_32(minBitrate, serializer);
+          sse_encode_u_32(maxBitrate, serializer);
+          sse_encode_u_32(highRttMs, serializer);
+          sse_encode_f_64(highFractionLost, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleEnableAdaptiveBitrateConstMeta,
+        argValues: [that, minBitrate, maxBitrate, highRttMs, highFractionLost],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleEnableAdaptiveBitrateConstMeta =>
+      const TaskConstMeta(debugName: "RoomHandle_enable_adaptive_bitrate", argNames: ["that", "minBitrate", "maxBitrate", "highRttMs", "highFractionLost"]);
+
+  @override
+  void crateApiDartApiRoomRoomHandleDisableAdaptiveBitrate({required RoomHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleDisableAdaptiveBitrateConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleDisableAdaptiveBitrateConstMeta =>
+      const TaskConstMeta(debugName: "RoomHandle_disable_adaptive_bitrate", argNames: ["that"]);
+
+  @override
+  void crateApiDartApiJasonJasonJasonSetRpcCompression({required Jason that, required RpcCompression compression}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason(
+            that,
+            serializer,
+          );
+          sse_encode_rpc_compression(compression, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiJasonJasonJasonSetRpcCompressionConstMeta,
+        argValues: [that, compression],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiJasonJasonJasonSetRpcCompressionConstMeta =>
+      const TaskConstMeta(debugName: "Jason_jason_set_rpc_compression", argNames: ["that", "compression"]);
+
+  @override
+  RoomState crateApiDartApiRoomRoomHandleState({required RoomHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_room_state,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleStateConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleStateConstMeta =>
+      const TaskConstMeta(debugName: "RoomHandle_state", argNames: ["that"]);
+
+  @override
+  Object crateApiDartApiRoomRoomHandleSetMaxSendResolution({required RoomHandle that, required int width, required int height}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          sse_encode_u_32(width, serializer);
+          sse_encode_u_32(height, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleSetMaxSendResolutionConstMeta,
+        argValues: [that, width, height],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleSetMaxSendResolutionConstMeta =>
+      const TaskConstMeta(debugName: "RoomHandle_set_max_send_resolution", argNames: ["that", "width", "height"]);
+
+  @override
+  Object crateApiDartApiRoomRoomHandleResetMaxSendResolution({required RoomHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleResetMaxSendResolutionConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleResetMaxSendResolutionConstMeta =>
+      const TaskConstMeta(debugName: "RoomHandle_reset_max_send_resolution", argNames: ["that"]);
+
+  @override
+  void crateApiDartApiRoomRoomHandleRenegotiate({required RoomHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleRenegotiateConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleRenegotiateConstMeta =>
+      const TaskConstMeta(debugName: "RoomHandle_renegotiate", argNames: ["that"]);
+
+  @override
+  Object crateApiDartApiRoomRoomHandleLeave({required RoomHandle that, required int timeoutMs}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          sse_encode_u_32(timeoutMs, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleLeaveConstMeta,
+        argValues: [that, timeoutMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleLeaveConstMeta =>
+      const TaskConstMeta(debugName: "RoomHandle_leave", argNames: ["that", "timeoutMs"]);
+
+  @override
+  void crateApiDartApiRoomRoomHandleSetStatsSendingEnabled({required RoomHandle that, required bool enabled}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          sse_encode_bool(enabled, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleSetStatsSendingEnabledConstMeta,
+        argValues: [that, enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleSetStatsSendingEnabledConstMeta =>
+      const TaskConstMeta(debugName: "RoomHandle_set_stats_sending_enabled", argNames: ["that", "enabled"]);
+
+  @override
+  void crateApiDartApiConnectionHandleConnectionHandleOnInboundQualityUpdate({required ConnectionHandle that, required Object f}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectionHandle(
+            that,
+            serializer,
+          );
+          sse_encode_DartOpaque(f, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiConnectionHandleConnectionHandleOnInboundQualityUpdateConstMeta,
+        argValues: [that, f],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiConnectionHandleConnectionHandleOnInboundQualityUpdateConstMeta =>
+      const TaskConstMeta(debugName: "ConnectionHandle_on_inbound_quality_update", argNames: ["that", "f"]);
+
+  @override
+  void crateApiDartApiConnectionHandleConnectionHandleRequestQuality({required ConnectionHandle that, required int spatial, int? temporal}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectionHandle(
+            that,
+            serializer,
+          );
+          sse_encode_u_8(spatial, serializer);
+          sse_encode_opt_box_autoadd_u_8(temporal, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiConnectionHandleConnectionHandleRequestQualityConstMeta,
+        argValues: [that, spatial, temporal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiConnectionHandleConnectionHandleRequestQualityConstMeta =>
+      const TaskConstMeta(debugName: "ConnectionHandle_request_quality", argNames: ["that", "spatial", "temporal"]);
+
+  @override
+  void crateApiDartApiRemoteMediaTrackRemoteMediaTrackOnPaused({required RemoteMediaTrack that, required Object f}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+            that,
+            serializer,
+          );
+          sse_encode_DartOpaque(f, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnPausedConstMeta,
+        argValues: [that, f],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnPausedConstMeta =>
+      const TaskConstMeta(debugName: "RemoteMediaTrack_on_paused", argNames: ["that", "f"]);
+
+  @override
+  void crateApiDartApiRemoteMediaTrackRemoteMediaTrackOnResumed({required RemoteMediaTrack that, required Object f}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+            that,
+            serializer,
+          );
+          sse_encode_DartOpaque(f, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnResumedConstMeta,
+        argValues: [that, f],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnResumedConstMeta =>
+      const TaskConstMeta(debugName: "RemoteMediaTrack_on_resumed", argNames: ["that", "f"]);
+
+  @override
+  bool crateApiDartApiRemoteMediaTrackRemoteMediaTrackPaused({required RemoteMediaTrack that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackPausedConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackPausedConstMeta =>
+      const TaskConstMeta(debugName: "RemoteMediaTrack_paused", argNames: ["that"]);
+
+  @override
+  int? crateApiDartApiReconnectHandleReconnectHandleCloseCode({required ReconnectHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReconnectHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_16,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiReconnectHandleReconnectHandleCloseCodeConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiReconnectHandleReconnectHandleCloseCodeConstMeta =>
+      const TaskConstMeta(debugName: "ReconnectHandle_close_code", argNames: ["that"]);
+
+  @override
+  String? crateApiDartApiReconnectHandleReconnectHandleCloseReason({required ReconnectHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReconnectHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiReconnectHandleReconnectHandleCloseReasonConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiReconnectHandleReconnectHandleCloseReasonConstMeta =>
+      const TaskConstMeta(debugName: "ReconnectHandle_close_reason", argNames: ["that"]);
+
+  @override
+  void crateApiDartApiRoomRoomHandleSetDisconnectGracePeriod({required RoomHandle that, required int periodMs}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          sse_encode_u_32(periodMs, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleSetDisconnectGracePeriodConstMeta,
+        argValues: [that, periodMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleSetDisconnectGracePeriodConstMeta =>
+      const TaskConstMeta(debugName: "RoomHandle_set_disconnect_grace_period", argNames: ["that", "periodMs"]);
+
+  @override
+  void crateApiDartApiJasonJasonJasonSetTokenRefresher({required Jason that, required Object cb}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason(
+            that,
+            serializer,
+          );
+          sse_encode_DartOpaque(cb, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiJasonJasonJasonSetTokenRefresherConstMeta,
+        argValues: [that, cb],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiJasonJasonJasonSetTokenRefresherConstMeta =>
+      const TaskConstMeta(debugName: "Jason_jason_set_token_refresher", argNames: ["that", "cb"]);
+
+  @override
+  Object crateApiDartApiJasonJasonJasonDisposeAsync({required Jason that, required int timeoutMs}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason(
+            that,
+            serializer,
+          );
+          sse_encode_u_32(timeoutMs, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 140)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiJasonJasonJasonDisposeAsyncConstMeta,
+        argValues: [that, timeoutMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiJasonJasonJasonDisposeAsyncConstMeta =>
+      const TaskConstMeta(debugName: "Jason_jason_dispose_async", argNames: ["that", "timeoutMs"]);
+
+  @override
+  bool crateApiDartApiConnectionHandleConnectionHandleIsRemoteMuted({required ConnectionHandle that, required MediaKind kind}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectionHandle(
+            that,
+            serializer,
+          );
+          sse_encode_media_kind(kind, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 141)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiConnectionHandleConnectionHandleIsRemoteMutedConstMeta,
+        argValues: [that, kind],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiConnectionHandleConnectionHandleIsRemoteMutedConstMeta =>
+      const TaskConstMeta(debugName: "ConnectionHandle_is_remote_muted", argNames: ["that", "kind"]);
+
+  @override
+  void crateApiDartApiLocalMediaTrackLocalMediaTrackSetMirror({required LocalMediaTrack that, required bool mirror}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
+            that,
+            serializer,
+          );
+          sse_encode_bool(mirror, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 142)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetMirrorConstMeta,
+        argValues: [that, mirror],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetMirrorConstMeta =>
+      const TaskConstMeta(debugName: "LocalMediaTrack_set_mirror", argNames: ["that", "mirror"]);
+
+  @override
+  bool crateApiDartApiLocalMediaTrackLocalMediaTrackIsMirrored({required LocalMediaTrack that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 143)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsMirroredConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsMirroredConstMeta =>
+      const TaskConstMeta(debugName: "LocalMediaTrack_is_mirrored", argNames: ["that"]);
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ConnectionHandle => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectionHandle;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ConnectionHandle => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectionHandle;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_Jason => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_Jason => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_LocalMediaTrack => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack;
 
   RustArcDecrementStrongCountFnType
   get rust_arc_decrement_strong_count_LocalMediaTrack => wire
@@ -3217,10 +5147,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   get rust_arc_increment_strong_count_ReconnectHandle => wire
       .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReconnectHandle;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_LogEvent => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEvent;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ApplicationMessage => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApplicationMessage;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_LocalMediaFallback => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaFallback;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_WhepClient => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_WhipClient => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhipClient;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_Recorder => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder;
+
   RustArcDecrementStrongCountFnType
   get rust_arc_decrement_strong_count_ReconnectHandle => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReconnectHandle;
 
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_LogEvent => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEvent;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ApplicationMessage => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApplicationMessage;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_LocalMediaFallback => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaFallback;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_WhepClient => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_WhipClient => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhipClient;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_Recorder => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_RemoteMediaTrack => wire
       .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack;
@@ -3237,6 +5215,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   get rust_arc_decrement_strong_count_RoomHandle => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle;
 
+  @protected
+  void sse_encode_room_state(RoomState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_rpc_compression(
+    RpcCompression self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   ConnectionHandle
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectionHandle(
@@ -3282,6 +5275,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ReconnectHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  LogEvent
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEvent(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LogEventImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ApplicationMessage
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApplicationMessage(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ApplicationMessageImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  LocalMediaFallback
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaFallback(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LocalMediaFallbackImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  WhepClient
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WhepClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  WhipClient
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhipClient(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WhipClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Recorder
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RecorderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   RemoteMediaTrack
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
@@ -3345,6 +5392,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ReconnectHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  LogEvent
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEvent(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LogEventImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ApplicationMessage
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApplicationMessage(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ApplicationMessageImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  LocalMediaFallback
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaFallback(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LocalMediaFallbackImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  WhepClient
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WhepClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  WhipClient
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhipClient(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WhipClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Recorder
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RecorderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   RemoteMediaTrack
   dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
@@ -3387,39 +5488,93 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  Jason
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason(
+  Jason
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return JasonImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  LocalMediaTrack
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LocalMediaTrackImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MediaManagerHandle
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MediaManagerHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ReconnectHandle
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReconnectHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ReconnectHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  LogEvent
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEvent(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LogEventImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ApplicationMessage
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApplicationMessage(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ApplicationMessageImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  LocalMediaFallback
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaFallback(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return JasonImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return LocalMediaFallbackImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
   @protected
-  LocalMediaTrack
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
+  WhepClient
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LocalMediaTrackImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return WhepClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
   @protected
-  MediaManagerHandle
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+  WhipClient
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhipClient(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MediaManagerHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return WhipClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
   @protected
-  ReconnectHandle
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReconnectHandle(
+  Recorder
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ReconnectHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return RecorderImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
   @protected
@@ -3628,12 +5783,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_noise_suppression_level(raw);
   }
 
+  @protected
+  RoomState dco_decode_room_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RoomState.values[raw as int];
+  }
+
+  @protected
+  RpcCompression dco_decode_rpc_compression(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RpcCompression.values[raw as int];
+  }
+
+  @protected
+  RoomCloseReason dco_decode_box_autoadd_room_close_reason(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_room_close_reason(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as int;
   }
 
+  @protected
+  int dco_decode_box_autoadd_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   ConstrainBoolean dco_decode_constrain_boolean(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3836,12 +6021,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         : dco_decode_box_autoadd_noise_suppression_level(raw);
   }
 
+  @protected
+  RoomCloseReason? dco_decode_opt_box_autoadd_room_close_reason(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_room_close_reason(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_16(raw);
+  }
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
   }
 
+  @protected
+  int? dco_decode_opt_box_autoadd_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_8(raw);
+  }
+
   @protected
   RoomCloseReason dco_decode_room_close_reason(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3855,6 +6058,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  RoomLifecycleEvent dco_decode_room_lifecycle_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RoomLifecycleEvent(
+      kind: dco_decode_room_lifecycle_event_kind(arr[0]),
+      closeReason: dco_decode_opt_box_autoadd_room_close_reason(arr[1]),
+    );
+  }
+
+  @protected
+  RoomLifecycleEventKind dco_decode_room_lifecycle_event_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RoomLifecycleEventKind.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   int dco_decode_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3939,6 +6166,78 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  LogEvent
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEvent(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return LogEventImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  ApplicationMessage
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApplicationMessage(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ApplicationMessageImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  LocalMediaFallback
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaFallback(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return LocalMediaFallbackImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  WhepClient
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return WhepClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  WhipClient
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhipClient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return WhipClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Recorder
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RecorderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   RemoteMediaTrack
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
@@ -4023,6 +6322,78 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  LogEvent
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEvent(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return LogEventImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  ApplicationMessage
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApplicationMessage(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ApplicationMessageImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  LocalMediaFallback
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaFallback(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return LocalMediaFallbackImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  WhepClient
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return WhepClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  WhipClient
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhipClient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return WhipClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Recorder
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RecorderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   RemoteMediaTrack
   sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
@@ -4121,6 +6492,78 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  LogEvent
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEvent(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return LogEventImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  ApplicationMessage
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApplicationMessage(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ApplicationMessageImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  LocalMediaFallback
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaFallback(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return LocalMediaFallbackImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  WhepClient
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return WhepClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  WhipClient
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhipClient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return WhipClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Recorder
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RecorderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   RemoteMediaTrack
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
@@ -4337,35 +6780,63 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  ConstrainBoolean sse_decode_box_autoadd_constrain_boolean(
-    SseDeserializer deserializer,
-  ) {
+  ConstrainBoolean sse_decode_box_autoadd_constrain_boolean(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_constrain_boolean(deserializer));
+  }
+
+  @protected
+  ConstrainU32 sse_decode_box_autoadd_constrain_u_32(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_constrain_u_32(deserializer));
+  }
+
+  @protected
+  MediaSourceKind sse_decode_box_autoadd_media_source_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_media_source_kind(deserializer));
+  }
+
+  @protected
+  NoiseSuppressionLevel sse_decode_box_autoadd_noise_suppression_level(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_noise_suppression_level(deserializer));
+  }
+
+  @protected
+  RoomState sse_decode_room_state(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_constrain_boolean(deserializer));
+    var inner = sse_decode_i_32(deserializer);
+    return RoomState.values[inner];
   }
 
   @protected
-  ConstrainU32 sse_decode_box_autoadd_constrain_u_32(
-    SseDeserializer deserializer,
-  ) {
+  RpcCompression sse_decode_rpc_compression(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_constrain_u_32(deserializer));
+    var inner = sse_decode_i_32(deserializer);
+    return RpcCompression.values[inner];
   }
 
   @protected
-  MediaSourceKind sse_decode_box_autoadd_media_source_kind(
+  RoomCloseReason sse_decode_box_autoadd_room_close_reason(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_media_source_kind(deserializer));
+    return (sse_decode_room_close_reason(deserializer));
   }
 
   @protected
-  NoiseSuppressionLevel sse_decode_box_autoadd_noise_suppression_level(
-    SseDeserializer deserializer,
-  ) {
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_noise_suppression_level(deserializer));
+    return (sse_decode_u_16(deserializer));
   }
 
   @protected
@@ -4374,6 +6845,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_u_32(deserializer));
   }
 
+  @protected
+  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_8(deserializer));
+  }
+
   @protected
   ConstrainBoolean sse_decode_constrain_boolean(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4655,6 +7132,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  RoomCloseReason? sse_decode_opt_box_autoadd_room_close_reason(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_room_close_reason(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_16(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4666,6 +7167,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_8(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   RoomCloseReason sse_decode_room_close_reason(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4679,6 +7191,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  RoomLifecycleEvent sse_decode_room_lifecycle_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_room_lifecycle_event_kind(deserializer);
+    var var_closeReason = sse_decode_opt_box_autoadd_room_close_reason(
+      deserializer,
+    );
+    return RoomLifecycleEvent(kind: var_kind, closeReason: var_closeReason);
+  }
+
+  @protected
+  RoomLifecycleEventKind sse_decode_room_lifecycle_event_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RoomLifecycleEventKind.values[inner];
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4767,6 +7306,84 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEvent(
+    LogEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as LogEventImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApplicationMessage(
+    ApplicationMessage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ApplicationMessageImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaFallback(
+    LocalMediaFallback self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as LocalMediaFallbackImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient(
+    WhepClient self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as WhepClientImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhipClient(
+    WhipClient self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as WhipClientImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(
+    Recorder self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as RecorderImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
@@ -4858,6 +7475,84 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEvent(
+    LogEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as LogEventImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApplicationMessage(
+    ApplicationMessage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ApplicationMessageImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaFallback(
+    LocalMediaFallback self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as LocalMediaFallbackImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient(
+    WhepClient self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as WhepClientImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhipClient(
+    WhipClient self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as WhipClientImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(
+    Recorder self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as RecorderImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
@@ -4939,33 +7634,111 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_usize(
-      (self as LocalMediaTrackImpl).frbInternalSseEncode(move: null),
+      (self as LocalMediaTrackImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+    MediaManagerHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as MediaManagerHandleImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReconnectHandle(
+    ReconnectHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ReconnectHandleImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEvent(
+    LogEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as LogEventImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApplicationMessage(
+    ApplicationMessage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ApplicationMessageImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaFallback(
+    LocalMediaFallback self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as LocalMediaFallbackImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhepClient(
+    WhepClient self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as WhepClientImpl).frbInternalSseEncode(move: null),
       serializer,
     );
   }
 
   @protected
   void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
-    MediaManagerHandle self,
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhipClient(
+    WhipClient self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_usize(
-      (self as MediaManagerHandleImpl).frbInternalSseEncode(move: null),
+      (self as WhipClientImpl).frbInternalSseEncode(move: null),
       serializer,
     );
   }
 
   @protected
   void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReconnectHandle(
-    ReconnectHandle self,
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(
+    Recorder self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_usize(
-      (self as ReconnectHandleImpl).frbInternalSseEncode(move: null),
+      (self as RecorderImpl).frbInternalSseEncode(move: null),
       serializer,
     );
   }
@@ -5201,12 +7974,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_noise_suppression_level(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_room_close_reason(
+    RoomCloseReason self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_room_close_reason(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_16(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_u_32(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_8(self, serializer);
+  }
+
   @protected
   void sse_encode_constrain_boolean(
     ConstrainBoolean self,
@@ -5482,6 +8276,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_room_close_reason(
+    RoomCloseReason? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_room_close_reason(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_16(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5492,6 +8309,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_8(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_room_close_reason(
     RoomCloseReason self,
@@ -5503,6 +8330,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self.isErr, serializer);
   }
 
+  @protected
+  void sse_encode_room_lifecycle_event(
+    RoomLifecycleEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_room_lifecycle_event_kind(self.kind, serializer);
+    sse_encode_opt_box_autoadd_room_close_reason(self.closeReason, serializer);
+  }
+
+  @protected
+  void sse_encode_room_lifecycle_event_kind(
+    RoomLifecycleEventKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5630,6 +8482,36 @@ class ConnectionHandleImpl extends RustOpaque implements ConnectionHandle {
         that: this,
         f: f,
       );
+
+  /// Sets a callback to be invoked once an application message is received
+  /// from the remote `Member` of the associated [`Connection`].
+  void onApplicationMessage({required Object f}) =>
+      RustLib.instance.api.crateApiDartApiConnectionHandleConnectionHandleOnApplicationMessage(that: this, f: f);
+
+  /// Sets a callback to be invoked once the inbound media quality of the
+  /// associated [`Connection`] switches between good (`true`) and poor
+  /// (`false`).
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::ConnectionHandle::on_inbound_quality_update()`] method
+  /// errors.
+  void onInboundQualityUpdate({required Object f}) =>
+      RustLib.instance.api.crateApiDartApiConnectionHandleConnectionHandleOnInboundQualityUpdate(that: this, f: f);
+
+  /// Requests the provided spatial and temporal layer of all the inbound
+  /// video in the associated [`Connection`] from a media server.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::ConnectionHandle::request_quality()`] method errors.
+  void requestQuality({required int spatial, int? temporal}) =>
+      RustLib.instance.api.crateApiDartApiConnectionHandleConnectionHandleRequestQuality(that: this, spatial: spatial, temporal: temporal);
+
+  /// Indicates whether all the inbound media of the provided [`MediaKind`]
+  /// in the associated [`Connection`] is muted by the remote `Member`.
+  bool isRemoteMuted({required MediaKind kind}) =>
+      RustLib.instance.api.crateApiDartApiConnectionHandleConnectionHandleIsRemoteMuted(that: this, kind: kind);
 }
 
 @sealed
@@ -5669,6 +8551,51 @@ class JasonImpl extends RustOpaque implements Jason {
   /// Returns a [`MediaManagerHandle`].
   MediaManagerHandle jasonMediaManager() => RustLib.instance.api
       .crateApiDartApiJasonJasonJasonMediaManager(that: this);
+
+  /// Publishes the local media described by the provided
+  /// [`ApiMediaStreamSettings`] to the [WHIP] endpoint with the provided
+  /// `url`, bypassing the Medea signalling.
+  ///
+  /// The provided bearer `token` (if any) authorizes all the requests to the
+  /// endpoint.
+  ///
+  /// Returns a [`api::WhipClient`] once the publishing starts.
+  ///
+  /// [WHIP]: https://tools.ietf.org/html/rfc9725
+  Object jasonPublishWhip({required String url, String? token, required ApiMediaStreamSettings settings}) =>
+      RustLib.instance.api.crateApiDartApiJasonJasonJasonPublishWhip(that: this, url: url, token: token, settings: settings);
+
+  /// Creates a new [`api::WhepClient`] playing remote media from a [WHEP]
+  /// endpoint, bypassing the Medea signalling.
+  ///
+  /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+  WhepClient jasonWhepClient() =>
+      RustLib.instance.api.crateApiDartApiJasonJasonJasonWhepClient(that: this);
+
+  /// Sets the [`RpcCompression`] negotiated by the connections to media
+  /// servers established afterwards.
+  void jasonSetRpcCompression({required RpcCompression compression}) =>
+      RustLib.instance.api.crateApiDartApiJasonJasonJasonSetRpcCompression(that: this, compression: compression);
+
+  /// Sets the callback invoked before each reconnection to a media server to
+  /// fetch a fresh authorization token.
+  ///
+  /// The callback should return a `Future` resolving into the token string.
+  /// If it fails, the reconnection attempt fails and is retried with a
+  /// backoff.
+  ///
+  /// Affects only the rooms initialized after this call.
+  void jasonSetTokenRefresher({required Object cb}) =>
+      RustLib.instance.api.crateApiDartApiJasonJasonJasonSetTokenRefresher(that: this, cb: cb);
+
+  /// Gracefully disposes this [`Jason`].
+  ///
+  /// Leaves all the [`Room`]s waiting for a media server to acknowledge it
+  /// during the provided `timeout_ms`, stops all the local media tracks and
+  /// then disposes this [`Jason`] the same way as [`Jason::jason_dispose()`]
+  /// does.
+  Object jasonDisposeAsync({required int timeoutMs}) =>
+      RustLib.instance.api.crateApiDartApiJasonJasonJasonDisposeAsync(that: this, timeoutMs: timeoutMs);
 }
 
 @sealed
@@ -5848,6 +8775,19 @@ class LocalMediaTrackImpl extends RustOpaque implements LocalMediaTrack {
   /// [`media::MediaStreamTrackState::Ended`] if it has ended.
   Object state() => RustLib.instance.api
       .crateApiDartApiLocalMediaTrackLocalMediaTrackState(that: this);
+
+  /// Sets whether video of this [`LocalMediaTrack`] is intended to be
+  /// mirrored (e.g. for a front camera self-view).
+  ///
+  /// Frames are not transformed, so the renderer is expected to apply
+  /// mirroring on its own.
+  void setMirror({required bool mirror}) =>
+      RustLib.instance.api.crateApiDartApiLocalMediaTrackLocalMediaTrackSetMirror(that: this, mirror: mirror);
+
+  /// Indicates whether video of this [`LocalMediaTrack`] is intended to be
+  /// mirrored.
+  bool isMirrored() =>
+      RustLib.instance.api.crateApiDartApiLocalMediaTrackLocalMediaTrackIsMirrored(that: this);
 }
 
 @sealed
@@ -6006,6 +8946,266 @@ class ReconnectHandleImpl extends RustOpaque implements ReconnectHandle {
         that: this,
         delayMs: delayMs,
       );
+
+  /// Returns the WebSocket close code the connection has been closed with by
+  /// a media server, if any.
+  int? closeCode() =>
+      RustLib.instance.api.crateApiDartApiReconnectHandleReconnectHandleCloseCode(that: this);
+
+  /// Returns the WebSocket close reason the connection has been closed with
+  /// by a media server, if any.
+  ///
+  /// Allows to decide whether to re-authorize or just to retry.
+  String? closeReason() =>
+      RustLib.instance.api.crateApiDartApiReconnectHandleReconnectHandleCloseReason(that: this);
+}
+
+@sealed
+class RecorderImpl extends RustOpaque implements Recorder {
+  // Not to be used by end users
+  RecorderImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  RecorderImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_Recorder,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_Recorder,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_RecorderPtr,
+  );
+
+  /// Adds the provided [`LocalMediaTrack`] to be recorded.
+  void addLocalTrack({required LocalMediaTrack track}) =>
+      RustLib.instance.api.crateApiDartApiRecorderRecorderAddLocalTrack(that: this, track: track);
+
+  /// Adds the provided [`RemoteMediaTrack`] to be recorded.
+  void addRemoteTrack({required RemoteMediaTrack track}) =>
+      RustLib.instance.api.crateApiDartApiRecorderRecorderAddRemoteTrack(that: this, track: track);
+
+  /// Sets callback, invoked with a `List<int>` of each recorded data chunk.
+  ///
+  /// Concatenated in order, the chunks form the whole recording.
+  void onData({required Object f}) =>
+      RustLib.instance.api.crateApiDartApiRecorderRecorderOnData(that: this, f: f);
+
+  /// Pauses recording.
+  void pause() =>
+      RustLib.instance.api.crateApiDartApiRecorderRecorderPause(that: this);
+
+  /// Resumes paused recording.
+  void resume() =>
+      RustLib.instance.api.crateApiDartApiRecorderRecorderResume(that: this);
+
+  /// Starts recording.
+  ///
+  /// If a `timeslice_ms` is provided, then the recorded data is emitted into
+  /// the [`Recorder::on_data()`] callback in chunks of the provided
+  /// duration, otherwise it's emitted as a single chunk once the recording
+  /// is stopped.
+  void start({int? timesliceMs}) =>
+      RustLib.instance.api.crateApiDartApiRecorderRecorderStart(that: this, timesliceMs: timesliceMs);
+
+  /// Stops recording.
+  ///
+  /// The returned [`Future`] resolves once all the recorded data is emitted
+  /// into the [`Recorder::on_data()`] callback, or right away if recording
+  /// is not started or is stopped already.
+  Object stop() =>
+      RustLib.instance.api.crateApiDartApiRecorderRecorderStop(that: this);
+}
+
+@sealed
+class WhipClientImpl extends RustOpaque implements WhipClient {
+  // Not to be used by end users
+  WhipClientImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  WhipClientImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_WhipClient,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_WhipClient,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_WhipClientPtr,
+  );
+
+  /// Terminates the [WHIP] session.
+  ///
+  /// [WHIP]: https://tools.ietf.org/html/rfc9725
+  Object close() =>
+      RustLib.instance.api.crateApiDartApiWhipClientWhipClientClose(that: this);
+
+  /// Sets a callback to be invoked when the publishing fails after the
+  /// [WHIP] session has been created.
+  ///
+  /// [WHIP]: https://tools.ietf.org/html/rfc9725
+  void onFailure({required Object f}) =>
+      RustLib.instance.api.crateApiDartApiWhipClientWhipClientOnFailure(that: this, f: f);
+
+  /// Returns URL of the [WHIP] session resource created by the endpoint.
+  ///
+  /// [WHIP]: https://tools.ietf.org/html/rfc9725
+  String resource() =>
+      RustLib.instance.api.crateApiDartApiWhipClientWhipClientResource(that: this);
+}
+
+@sealed
+class WhepClientImpl extends RustOpaque implements WhepClient {
+  // Not to be used by end users
+  WhepClientImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  WhepClientImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_WhepClient,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_WhepClient,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_WhepClientPtr,
+  );
+
+  /// Terminates the [WHEP] session, if started.
+  ///
+  /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+  Object close() =>
+      RustLib.instance.api.crateApiDartApiWhepClientWhepClientClose(that: this);
+
+  /// Sets a callback to be invoked when the playing fails after the [WHEP]
+  /// session has been started.
+  ///
+  /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+  void onFailure({required Object f}) =>
+      RustLib.instance.api.crateApiDartApiWhepClientWhepClientOnFailure(that: this, f: f);
+
+  /// Sets a callback to be invoked once the remote media starts being
+  /// received, providing the [`ConnectionHandle`] its tracks are exposed
+  /// via.
+  ///
+  /// Should be set before calling [`WhepClient::play()`].
+  void onNewConnection({required Object f}) =>
+      RustLib.instance.api.crateApiDartApiWhepClientWhepClientOnNewConnection(that: this, f: f);
+
+  /// Starts playing the remote media from the [WHEP] endpoint with the
+  /// provided `url`.
+  ///
+  /// The provided bearer `token` (if any) authorizes all the requests to the
+  /// endpoint.
+  ///
+  /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+  Object play({required String url, String? token}) =>
+      RustLib.instance.api.crateApiDartApiWhepClientWhepClientPlay(that: this, url: url, token: token);
+
+  /// Returns URL of the [WHEP] session resource created by the endpoint, if
+  /// the playing has been started.
+  ///
+  /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+  String? resource() =>
+      RustLib.instance.api.crateApiDartApiWhepClientWhepClientResource(that: this);
+}
+
+@sealed
+class LocalMediaFallbackImpl extends RustOpaque implements LocalMediaFallback {
+  // Not to be used by end users
+  LocalMediaFallbackImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  LocalMediaFallbackImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_LocalMediaFallback,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_LocalMediaFallback,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_LocalMediaFallbackPtr,
+  );
+
+  /// Returns [`ApiMediaStreamSettings`] failed to be applied.
+  ApiMediaStreamSettings attempted() =>
+      RustLib.instance.api.crateApiDartApiLocalMediaFallbackLocalMediaFallbackAttempted(that: this);
+
+  /// Returns [`ApiMediaStreamSettings`] applied instead of the attempted
+  /// ones.
+  ApiMediaStreamSettings succeeded() =>
+      RustLib.instance.api.crateApiDartApiLocalMediaFallbackLocalMediaFallbackSucceeded(that: this);
+}
+
+@sealed
+class ApplicationMessageImpl extends RustOpaque implements ApplicationMessage {
+  // Not to be used by end users
+  ApplicationMessageImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  ApplicationMessageImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_ApplicationMessage,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ApplicationMessage,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ApplicationMessagePtr,
+  );
+
+  /// Returns the received application data.
+  Uint8List data() =>
+      RustLib.instance.api.crateApiDartApiApplicationMessageApplicationMessageData(that: this);
+}
+
+@sealed
+class LogEventImpl extends RustOpaque implements LogEvent {
+  // Not to be used by end users
+  LogEventImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  LogEventImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_LogEvent,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_LogEvent,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_LogEventPtr,
+  );
+
+  /// Returns category of this [`LogEvent`] (`ice`, `media`, `negotiation`,
+  /// `signalling` or `stats`).
+  String category() =>
+      RustLib.instance.api.crateApiDartApiLogEventLogEventCategory(that: this);
+
+  /// Returns severity of this [`LogEvent`] (`error`, `warn`, `info`,
+  /// `debug` or `trace`).
+  String level() =>
+      RustLib.instance.api.crateApiDartApiLogEventLogEventLevel(that: this);
+
+  /// Returns human-readable message of this [`LogEvent`].
+  String message() =>
+      RustLib.instance.api.crateApiDartApiLogEventLogEventMessage(that: this);
+
+  /// Returns ID of the `PeerConnection` this [`LogEvent`] relates to, if
+  /// any.
+  int? peerId() =>
+      RustLib.instance.api.crateApiDartApiLogEventLogEventPeerId(that: this);
 }
 
 @sealed
@@ -6084,6 +9284,21 @@ class RemoteMediaTrackImpl extends RustOpaque implements RemoteMediaTrack {
         that: this,
         f: f,
       );
+
+  /// Sets callback to invoke once this [`RemoteMediaTrack`] is paused by a
+  /// media server.
+  void onPaused({required Object f}) =>
+      RustLib.instance.api.crateApiDartApiRemoteMediaTrackRemoteMediaTrackOnPaused(that: this, f: f);
+
+  /// Sets callback to invoke once this [`RemoteMediaTrack`] is resumed by a
+  /// media server.
+  void onResumed({required Object f}) =>
+      RustLib.instance.api.crateApiDartApiRemoteMediaTrackRemoteMediaTrackOnResumed(that: this, f: f);
+
+  /// Indicates whether forwarding of this [`RemoteMediaTrack`] is paused by a
+  /// media server.
+  bool paused() =>
+      RustLib.instance.api.crateApiDartApiRemoteMediaTrackRemoteMediaTrackPaused(that: this);
 }
 
 @sealed
@@ -6312,4 +9527,139 @@ class RoomHandleImpl extends RustOpaque implements RoomHandle {
         that: this,
         sourceKind: sourceKind,
       );
+
+  /// Enables loss-based resolution downgrade of the video sent in this
+  /// [Room].
+  void enableLossBasedResolution({required double highFractionLost, required double lowFractionLost, required int sustainMs, required double step, required double maxScale}) =>
+      RustLib.instance.api.crateApiDartApiRoomRoomHandleEnableLossBasedResolution(that: this, highFractionLost: highFractionLost, lowFractionLost: lowFractionLost, sustainMs: sustainMs, step: step, maxScale: maxScale);
+
+  /// Disables loss-based resolution downgrade, restoring the original
+  /// resolution of the sent video.
+  void disableLossBasedResolution() =>
+      RustLib.instance.api.crateApiDartApiRoomRoomHandleDisableLossBasedResolution(that: this);
+
+  /// Enables or disables early media in this [Room].
+  void setEarlyMedia({required bool enabled}) =>
+      RustLib.instance.api.crateApiDartApiRoomRoomHandleSetEarlyMedia(that: this, enabled: enabled);
+
+  /// Sets a callback to be invoked when local media settings update fails
+  /// and the previous settings are restored instead.
+  void onLocalMediaFallback({required Object cb}) =>
+      RustLib.instance.api.crateApiDartApiRoomRoomHandleOnLocalMediaFallback(that: this, cb: cb);
+
+  /// Sets a callback to be invoked on each [`RoomLifecycleEvent`] of the
+  /// [`Room`].
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandle::on_lifecycle()`] method errors.
+  ///
+  /// [`RoomLifecycleEvent`]: api::RoomLifecycleEvent
+  void onLifecycle({required Object cb}) =>
+      RustLib.instance.api.crateApiDartApiRoomRoomHandleOnLifecycle(that: this, cb: cb);
+
+  /// Sends the provided application `data` to all the other `Member`s of
+  /// the [`Room`], which receive it via a
+  /// [`ConnectionHandle::on_application_message()`] callback.
+  ///
+  /// The `data` must not exceed 4 KiB.
+  void sendApplicationMessage({required Uint8List data}) =>
+      RustLib.instance.api.crateApiDartApiRoomRoomHandleSendApplicationMessage(that: this, data: data);
+
+  /// Enables adaptive bitrate of the video sent in this [`Room`].
+  ///
+  /// Once the round trip time exceeds the `high_rtt_ms` milliseconds or the
+  /// fraction of lost packets (in `0.0..=1.0` range) exceeds the
+  /// `high_fraction_lost`, the [maxBitrate][1] of each video sender is
+  /// decreased, and otherwise it's increased back, within the
+  /// `min_bitrate..=max_bitrate` range (in bits per second).
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandle::enable_adaptive_bitrate()`] method errors.
+  ///
+  /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-maxbitrate
+  void enableAdaptiveBitrate({required int minBitrate, required int maxBitrate, required int highRttMs, required double highFractionLost}) =>
+      RustLib.instance.api.crateApiDartApiRoomRoomHandleEnableAdaptiveBitrate(that: this, minBitrate: minBitrate, maxBitrate: maxBitrate, highRttMs: highRttMs, highFractionLost: highFractionLost);
+
+  /// Disables adaptive bitrate enabled via
+  /// [`RoomHandle::enable_adaptive_bitrate()`], leaving the last applied
+  /// bitrate of the sent video as is.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandle::disable_adaptive_bitrate()`] method errors.
+  void disableAdaptiveBitrate() =>
+      RustLib.instance.api.crateApiDartApiRoomRoomHandleDisableAdaptiveBitrate(that: this);
+
+  /// Returns the current [`core::RoomState`] of the [`Room`].
+  ///
+  /// Never fails, returning [`core::RoomState::Closed`] if the [`Room`] has
+  /// been closed already.
+  RoomState state() =>
+      RustLib.instance.api.crateApiDartApiRoomRoomHandleState(that: this);
+
+  /// Sets the maximum resolution of the video sent in this [`Room`],
+  /// regardless of what is captured.
+  ///
+  /// Applied via [scaleResolutionDownBy][1] of each video sender, based on
+  /// its current capture resolution, and re-applied whenever the capture
+  /// resolution changes.
+  ///
+  /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+  Object setMaxSendResolution({required int width, required int height}) =>
+      RustLib.instance.api.crateApiDartApiRoomRoomHandleSetMaxSendResolution(that: this, width: width, height: height);
+
+  /// Removes the maximum resolution of the sent video set via
+  /// [`RoomHandle::set_max_send_resolution()`].
+  Object resetMaxSendResolution() =>
+      RustLib.instance.api.crateApiDartApiRoomRoomHandleResetMaxSendResolution(that: this);
+
+  /// Requests Media Server to renegotiate all the peer connections of the
+  /// [`Room`], so fresh SDP offers are generated.
+  ///
+  /// If a negotiation is in progress already, then the renegotiation is
+  /// queued, and all the calls made meanwhile are coalesced into a single
+  /// follow-up negotiation.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandle::renegotiate_all()`] method errors.
+  void renegotiate() =>
+      RustLib.instance.api.crateApiDartApiRoomRoomHandleRenegotiate(that: this);
+
+  /// Gracefully leaves this [`Room`].
+  ///
+  /// Sends the final stats to a media server, asks it to remove the
+  /// `Member` from this [`Room`] and waits for its acknowledgement during
+  /// the provided `timeout_ms`. Then closes this [`Room`] regardless of
+  /// whether the acknowledgement has been received.
+  Object leave({required int timeoutMs}) =>
+      RustLib.instance.api.crateApiDartApiRoomRoomHandleLeave(that: this, timeoutMs: timeoutMs);
+
+  /// Enables or disables sending `RTCStats` to the media server by all the
+  /// current and future `PeerConnection`s of this [`Room`].
+  ///
+  /// `RTCStats` are still scraped while sending is disabled, so local stats
+  /// APIs keep working.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandle::set_stats_sending_enabled()`] method errors.
+  void setStatsSendingEnabled({required bool enabled}) =>
+      RustLib.instance.api.crateApiDartApiRoomRoomHandleSetStatsSendingEnabled(that: this, enabled: enabled);
+
+  /// Sets the period (in milliseconds) a peer connection may stay
+  /// disconnected before it's reported as such (including via
+  /// `Connection`s' quality scores).
+  ///
+  /// If the peer connection recovers within this period, no disconnect is
+  /// reported at all. `0` (the default) disables the grace period.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandle::set_disconnect_grace_period()`] method
+  /// errors.
+  void setDisconnectGracePeriod({required int periodMs}) =>
+      RustLib.instance.api.crateApiDartApiRoomRoomHandleSetDisconnectGracePeriod(that: this, periodMs: periodMs);
 }
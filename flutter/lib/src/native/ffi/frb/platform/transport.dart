@@ -0,0 +1,20 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../frb_generated.dart';
+
+/// Compression of the messages sent via an [`RpcTransport`].
+enum RpcCompression {
+  /// Messages are sent uncompressed.
+  disabled,
+
+  /// [permessage-deflate][1] extension is offered to a server, and used if
+  /// it accepts it.
+  ///
+  /// [1]: https://tools.ietf.org/html/rfc7692
+  deflate,
+}
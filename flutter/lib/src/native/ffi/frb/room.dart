@@ -0,0 +1,28 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import 'frb_generated.dart';
+
+/// State of a [`Room`] returned by the [`RoomHandle::state()`].
+enum RoomState {
+  /// [`Room`] hasn't been joined yet.
+  new_,
+
+  /// [`Room`] is connecting to a media server and joining it.
+  connecting,
+
+  /// [`Room`] has joined a media server.
+  connected,
+
+  /// [`Room`] has lost its connection with a media server and is recovering
+  /// it.
+  reconnecting,
+
+  /// [`Room`] has been closed, so all the [`RoomHandle`] methods fail with
+  /// a [`HandleDetachedError`].
+  closed,
+}
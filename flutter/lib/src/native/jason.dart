@@ -4,9 +4,14 @@ import 'dart:io';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:medea_flutter_webrtc/medea_flutter_webrtc.dart';
 
+import '../interface/enums.dart' show RpcCompression;
 import '../interface/jason.dart' as base;
+import '../interface/log_event.dart';
 import '../interface/media_manager.dart';
+import '../interface/media_stream_settings.dart' as base_settings;
 import '../interface/room_handle.dart';
+import '../interface/whep_client.dart';
+import '../interface/whip_client.dart';
 import '../util/move_semantic.dart';
 import '../util/rust_opaque.dart' as util;
 import '/src/util/rust_handles_storage.dart';
@@ -22,8 +27,11 @@ import 'ffi/list.dart' as list;
 import 'ffi/map.dart' as map;
 import 'ffi/native_string.dart' as native_string;
 import 'media_manager.dart';
+import 'media_stream_settings.dart';
 import 'platform/functions_registerer.dart' as platform_utils_registerer;
 import 'room_handle.dart';
+import 'whep_client.dart';
+import 'whip_client.dart';
 
 /// Bindings to the Rust side API.
 final ExternalLibrary el = _dlLoad();
@@ -46,6 +54,43 @@ void onPanic(void Function(String)? cb) {
   _onPanicCallback = cb;
 }
 
+/// Sink receiving all the [LogEvent]s of this library.
+void Function(LogEvent)? _logSink;
+
+/// Sets the provided [sink] to receive all the [LogEvent]s of this library
+/// instead of the default logger.
+///
+/// If `null` is provided, then the default logger is used again.
+void setLogSink(void Function(LogEvent)? sink) {
+  _logSink = sink;
+  if (RustLib.instance.initialized) {
+    _applyLogSink();
+  }
+}
+
+/// Passes the current [_logSink] to the Rust side.
+void _applyLogSink() {
+  var sink = _logSink;
+  if (sink == null) {
+    frb.resetLogSink();
+  } else {
+    frb.setLogSink(
+      cb: (e) {
+        var event = frb.LogEvent.fromPtr(ptr: e.address);
+        sink(
+          LogEvent(
+            event.level(),
+            event.category(),
+            event.message(),
+            event.peerId(),
+          ),
+        );
+        event.dispose();
+      },
+    );
+  }
+}
+
 ExternalLibrary _dlLoad() {
   if (!(Platform.isAndroid ||
       Platform.isLinux ||
@@ -122,6 +167,9 @@ class Jason implements base.Jason {
         },
       );
       frb.setDartOpaqueMessagePort(dartHandlerPort: port);
+      if (_logSink != null) {
+        _applyLogSink();
+      }
     }
 
     var jason = Jason._();
@@ -144,6 +192,40 @@ class Jason implements base.Jason {
     return NativeRoomHandle(opaque.inner.jasonInitRoom());
   }
 
+  @override
+  Future<WhipClient> publishWhip(
+    String url,
+    base_settings.MediaStreamSettings settings, {
+    String? token,
+  }) async {
+    Pointer client;
+    client =
+        await (opaque.inner.jasonPublishWhip(
+                  url: url,
+                  token: token,
+                  settings: (settings as MediaStreamSettings).setting,
+                )
+                as Future)
+            as Pointer;
+
+    return NativeWhipClient(frb.WhipClient.fromPtr(ptr: client.address));
+  }
+
+  @override
+  WhepClient whepClient() {
+    return NativeWhepClient(opaque.inner.jasonWhepClient());
+  }
+
+  @override
+  void setRpcCompression(RpcCompression compression) {
+    opaque.inner.jasonSetRpcCompression(compression: compression);
+  }
+
+  @override
+  void setTokenRefresher(Future<String> Function() f) {
+    opaque.inner.jasonSetTokenRefresher(cb: f);
+  }
+
   @override
   void closeRoom(@moveSemantics RoomHandle room) {
     room as NativeRoomHandle;
@@ -161,4 +243,15 @@ class Jason implements base.Jason {
       opaque.dispose();
     }
   }
+
+  @override
+  @moveSemantics
+  Future<void> disposeAsync(int timeoutMs) async {
+    if (!opaque.isDisposed) {
+      RustHandlesStorage().removeHandle(this);
+      var fut = opaque.inner.jasonDisposeAsync(timeoutMs: timeoutMs);
+      opaque.dispose();
+      await (fut as Future);
+    }
+  }
 }
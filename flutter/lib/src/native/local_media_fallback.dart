@@ -0,0 +1,38 @@
+import '../interface/local_media_fallback.dart';
+import '../util/move_semantic.dart';
+import '../util/rust_opaque.dart';
+import '/src/util/rust_handles_storage.dart';
+import 'ffi/frb/frb.dart' as frb;
+import 'media_stream_settings.dart';
+
+class NativeLocalMediaFallback implements LocalMediaFallback {
+  /// `flutter_rust_bridge` Rust opaque type backing this object.
+  final RustOpaque<frb.LocalMediaFallback> opaque;
+
+  /// Constructs a new [LocalMediaFallback] backed by the Rust struct behind
+  /// the provided [frb.LocalMediaFallback].
+  NativeLocalMediaFallback(frb.LocalMediaFallback fallback)
+    : opaque = RustOpaque(fallback) {
+    RustHandlesStorage().insertHandle(this);
+  }
+
+  @override
+  MediaStreamSettings attempted() {
+    return MediaStreamSettings.fromApi(opaque.inner.attempted());
+  }
+
+  @override
+  MediaStreamSettings succeeded() {
+    return MediaStreamSettings.fromApi(opaque.inner.succeeded());
+  }
+
+  @moveSemantics
+  @override
+  void free() {
+    if (!opaque.isDisposed) {
+      RustHandlesStorage().removeHandle(this);
+
+      opaque.dispose();
+    }
+  }
+}
@@ -54,6 +54,16 @@ class NativeLocalMediaTrack implements LocalMediaTrack {
     return MediaStreamTrackState.values[index];
   }
 
+  @override
+  void setMirror(bool mirror) {
+    opaque.inner.setMirror(mirror: mirror);
+  }
+
+  @override
+  bool isMirrored() {
+    return opaque.inner.isMirrored();
+  }
+
   @override
   bool isOnAudioLevelAvailable() {
     return opaque.inner.isOnAudioLevelAvailable();
@@ -11,11 +11,19 @@ import '../interface/display_video_track_constraints.dart'
 
 class MediaStreamSettings implements base.MediaStreamSettings {
   /// Rust `flutter_rust_bridge` API representation.
-  final frb.ApiMediaStreamSettings setting = frb.ApiMediaStreamSettings(
-    audio: null,
-    deviceVideo: null,
-    displayVideo: null,
-  );
+  final frb.ApiMediaStreamSettings setting;
+
+  /// Creates new [MediaStreamSettings] with none constraints configured.
+  MediaStreamSettings()
+    : setting = frb.ApiMediaStreamSettings(
+        audio: null,
+        deviceVideo: null,
+        displayVideo: null,
+      );
+
+  /// Creates new [MediaStreamSettings] from the provided Rust API
+  /// representation.
+  MediaStreamSettings.fromApi(this.setting);
 
   @override
   void audio(base_audio.AudioTrackConstraints audio) {
@@ -3,6 +3,8 @@ export '../native/ffi/frb/media/track.dart' show MediaSourceKind;
 export '../native/ffi/frb/media.dart' show MediaKind, MediaDeviceKind;
 export '../native/ffi/frb/media/constraints.dart'
     show FacingMode, NoiseSuppressionLevel;
+export '../native/ffi/frb/platform/transport.dart' show RpcCompression;
+export '../native/ffi/frb/room.dart' show RoomState;
 
 export 'package:medea_flutter_webrtc/medea_flutter_webrtc.dart'
     show MediaStreamTrackState;
@@ -2,6 +2,7 @@ import 'dart:ffi';
 
 import 'codec_capability.dart' as codec_capability;
 import 'constraints.dart' as constraints;
+import 'http.dart' as http;
 import 'ice_candidate.dart' as ice_candidate;
 import 'ice_candidate_error.dart' as ice_candidate_error;
 import 'ice_servers.dart' as ice_servers;
@@ -33,4 +34,5 @@ void registerFunctions(DynamicLibrary dl) {
   parameters.registerFunctions(dl);
   ice_candidate_error.registerFunctions(dl);
   codec_capability.registerFunctions(dl);
+  http.registerFunctions(dl);
 }
@@ -0,0 +1,71 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:http/http.dart' as http;
+
+import 'package:medea_jason/src/native/ffi/foreign_value.dart';
+import 'package:medea_jason/src/native/ffi/native_string.dart';
+import 'http.g.dart' as bridge;
+
+/// Registers functions allowing Rust to perform HTTP requests.
+void registerFunctions(DynamicLibrary dl) {
+  bridge.registerFunction(
+    dl,
+    fetch: _fetch,
+    status: _status,
+    header: _header,
+    body: _body,
+  );
+}
+
+/// Sends an HTTP request with the provided [method] to the provided [url],
+/// along with the provided [headers] and optional [body].
+Future<http.Response> Function() _fetch(
+  Pointer<Utf8> method,
+  Pointer<Utf8> url,
+  Object headers,
+  ForeignValue body,
+) {
+  headers as Map;
+  var request = http.Request(
+    method.nativeStringToDartString(),
+    Uri.parse(url.nativeStringToDartString()),
+  );
+  request.headers.addAll(headers.cast<String, String>());
+  var requestBody = body.toDart() as String?;
+  if (requestBody != null) {
+    request.body = requestBody;
+  }
+  return () async {
+    var client = http.Client();
+    try {
+      return await http.Response.fromStream(await client.send(request));
+    } finally {
+      client.close();
+    }
+  };
+}
+
+/// Returns status code of the provided [http.Response].
+int _status(Object response) {
+  response as http.Response;
+  return response.statusCode;
+}
+
+/// Returns value of the header with the provided [name] of the provided
+/// [http.Response], if any.
+Pointer _header(Object response, Pointer<Utf8> name) {
+  response as http.Response;
+  var value = response.headers[name.nativeStringToDartString().toLowerCase()];
+  if (value != null) {
+    return ForeignValue.fromString(value).intoRustOwned();
+  } else {
+    return ForeignValue.none().intoRustOwned();
+  }
+}
+
+/// Returns body of the provided [http.Response].
+Pointer<Utf8> _body(Object response) {
+  response as http.Response;
+  return response.body.toNativeUtf8();
+}
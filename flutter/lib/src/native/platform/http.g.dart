@@ -0,0 +1,108 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+
+import 'package:medea_jason/src/native/ffi/foreign_value.dart';
+
+typedef _ErrorSetterFnC = Void Function(Handle);
+typedef _ErrorSetterFnDart = void Function(Object);
+
+Object Function(Pointer<Utf8>, Pointer<Utf8>, Object, ForeignValue)? _fetch;
+int Function(Object)? _status;
+Pointer Function(Object, Pointer<Utf8>)? _header;
+Pointer<Utf8> Function(Object)? _body;
+
+_ErrorSetterFnDart? _http__fetch__set_error;
+_ErrorSetterFnDart? _http__status__set_error;
+_ErrorSetterFnDart? _http__header__set_error;
+_ErrorSetterFnDart? _http__body__set_error;
+
+void registerFunction(
+  DynamicLibrary dl, {
+  required Object Function(Pointer<Utf8>, Pointer<Utf8>, Object, ForeignValue)
+  fetch,
+  required int Function(Object) status,
+  required Pointer Function(Object, Pointer<Utf8>) header,
+  required Pointer<Utf8> Function(Object) body,
+}) {
+  _fetch = fetch;
+  _status = status;
+  _header = header;
+  _body = body;
+
+  _http__fetch__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'http__fetch__set_error',
+      );
+  _http__status__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'http__status__set_error',
+      );
+  _http__header__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'http__header__set_error',
+      );
+  _http__body__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'http__body__set_error',
+      );
+
+  Pointer<
+    NativeFunction<
+      Handle Function(Pointer<Utf8>, Pointer<Utf8>, Handle, ForeignValue)
+    >
+  >
+  fetch_native = Pointer.fromFunction(_fetchProxy);
+  Pointer<NativeFunction<Int64 Function(Handle)>> status_native =
+      Pointer.fromFunction(_statusProxy, 0);
+  Pointer<NativeFunction<Pointer Function(Handle, Pointer<Utf8>)>>
+  header_native = Pointer.fromFunction(_headerProxy);
+  Pointer<NativeFunction<Pointer<Utf8> Function(Handle)>> body_native =
+      Pointer.fromFunction(_bodyProxy);
+
+  dl.lookupFunction<
+    Void Function(Pointer, Pointer, Pointer, Pointer),
+    void Function(Pointer, Pointer, Pointer, Pointer)
+  >('register_http')(fetch_native, status_native, header_native, body_native);
+}
+
+Object _fetchProxy(
+  Pointer<Utf8> arg0,
+  Pointer<Utf8> arg1,
+  Object arg2,
+  ForeignValue arg3,
+) {
+  try {
+    return _fetch!(arg0, arg1, arg2, arg3);
+  } catch (e) {
+    _http__fetch__set_error!(e);
+    return 0;
+  }
+}
+
+int _statusProxy(Object arg0) {
+  try {
+    return _status!(arg0);
+  } catch (e) {
+    _http__status__set_error!(e);
+    return 0;
+  }
+}
+
+Pointer _headerProxy(Object arg0, Pointer<Utf8> arg1) {
+  try {
+    return _header!(arg0, arg1);
+  } catch (e) {
+    _http__header__set_error!(e);
+    return Pointer.fromAddress(0);
+  }
+}
+
+Pointer<Utf8> _bodyProxy(Object arg0) {
+  try {
+    return _body!(arg0);
+  } catch (e) {
+    _http__body__set_error!(e);
+    return Pointer.fromAddress(0);
+  }
+}
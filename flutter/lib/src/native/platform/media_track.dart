@@ -16,6 +16,7 @@ void registerFunctions(DynamicLibrary dl) {
     kind: _kind,
     height: _height,
     width: _width,
+    frameRate: _frameRate,
     setEnabled: _setEnabled,
     enabled: _enabled,
     stop: _stop,
@@ -98,6 +99,13 @@ Pointer _width(Object track) {
   return ForeignValue.fromInt(1300).intoRustOwned();
 }
 
+/// Returns frame rate of the video of the provided [MediaStreamTrack].
+Pointer _frameRate(Object track) {
+  track as MediaStreamTrack;
+  // TODO: Correct implementation requires flutter_webrtc-side fixes.
+  return ForeignValue.fromDouble(30.0).intoRustOwned();
+}
+
 /// Sets [MediaStreamTrack.enabled] state of the provided [MediaStreamTrack].
 void _setEnabled(Object track, bool enabled) {
   track = track as MediaStreamTrack;
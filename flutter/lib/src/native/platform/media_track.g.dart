@@ -13,6 +13,7 @@ int Function(Object)? _kind;
 Pointer Function(Object)? _facingMode;
 Pointer Function(Object)? _height;
 Pointer Function(Object)? _width;
+Pointer Function(Object)? _frameRate;
 bool Function(Object)? _enabled;
 void Function(Object, bool)? _setEnabled;
 Object Function(Object)? _readyState;
@@ -40,6 +41,7 @@ _ErrorSetterFnDart? _media_stream_track__kind__set_error;
 _ErrorSetterFnDart? _media_stream_track__facing_mode__set_error;
 _ErrorSetterFnDart? _media_stream_track__height__set_error;
 _ErrorSetterFnDart? _media_stream_track__width__set_error;
+_ErrorSetterFnDart? _media_stream_track__frame_rate__set_error;
 _ErrorSetterFnDart? _media_stream_track__enabled__set_error;
 _ErrorSetterFnDart? _media_stream_track__set_enabled__set_error;
 _ErrorSetterFnDart? _media_stream_track__ready_state__set_error;
@@ -77,6 +79,7 @@ void registerFunction(
   required Pointer Function(Object) facingMode,
   required Pointer Function(Object) height,
   required Pointer Function(Object) width,
+  required Pointer Function(Object) frameRate,
   required bool Function(Object) enabled,
   required void Function(Object, bool) setEnabled,
   required Object Function(Object) readyState,
@@ -104,6 +107,7 @@ void registerFunction(
   _facingMode = facingMode;
   _height = height;
   _width = width;
+  _frameRate = frameRate;
   _enabled = enabled;
   _setEnabled = setEnabled;
   _readyState = readyState;
@@ -149,6 +153,10 @@ void registerFunction(
       .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
         'media_stream_track__width__set_error',
       );
+  _media_stream_track__frame_rate__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'media_stream_track__frame_rate__set_error',
+      );
   _media_stream_track__enabled__set_error = dl
       .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
         'media_stream_track__enabled__set_error',
@@ -242,6 +250,8 @@ void registerFunction(
       Pointer.fromFunction(_heightProxy);
   Pointer<NativeFunction<Pointer Function(Handle)>> width_native =
       Pointer.fromFunction(_widthProxy);
+  Pointer<NativeFunction<Pointer Function(Handle)>> frameRate_native =
+      Pointer.fromFunction(_frameRateProxy);
   Pointer<NativeFunction<Bool Function(Handle)>> enabled_native =
       Pointer.fromFunction(_enabledProxy, false);
   Pointer<NativeFunction<Void Function(Handle, Bool)>> setEnabled_native =
@@ -337,6 +347,7 @@ void registerFunction(
       Pointer,
       Pointer,
       Pointer,
+      Pointer,
     ),
     void Function(
       Pointer,
@@ -365,6 +376,7 @@ void registerFunction(
       Pointer,
       Pointer,
       Pointer,
+      Pointer,
     )
   >('register_media_stream_track')(
     id_native,
@@ -373,6 +385,7 @@ void registerFunction(
     facingMode_native,
     height_native,
     width_native,
+    frameRate_native,
     enabled_native,
     setEnabled_native,
     readyState_native,
@@ -450,6 +463,15 @@ Pointer _widthProxy(Object arg0) {
   }
 }
 
+Pointer _frameRateProxy(Object arg0) {
+  try {
+    return _frameRate!(arg0);
+  } catch (e) {
+    _media_stream_track__frame_rate__set_error!(e);
+    return Pointer.fromAddress(0);
+  }
+}
+
 bool _enabledProxy(Object arg0) {
   try {
     return _enabled!(arg0);
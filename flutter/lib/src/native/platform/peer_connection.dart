@@ -4,6 +4,7 @@ import 'dart:ffi';
 import 'package:ffi/ffi.dart';
 import 'package:medea_flutter_webrtc/medea_flutter_webrtc.dart';
 
+import 'package:medea_jason/src/native/ffi/foreign_value.dart';
 import 'package:medea_jason/src/native/ffi/native_string.dart';
 import 'peer_connection.g.dart' as bridge;
 import 'rtc_stats.dart';
@@ -30,6 +31,10 @@ void registerFunctions(DynamicLibrary dl) {
     createAnswer: _createAnswer,
     getTransceiverByMid: _getTransceiverByMid,
     onConnectionStateChange: _onConnectionStateChange,
+    signalingState: _signalingState,
+    onSignalingStateChange: _onSignalingStateChange,
+    localDescription: _localDescription,
+    remoteDescription: _remoteDescription,
     close: _close,
   );
 }
@@ -108,6 +113,16 @@ void _onConnectionStateChange(Object conn, Object f) {
   });
 }
 
+/// Sets the provided [f] to the [PeerConnection.onSignalingStateChange]
+/// callback.
+void _onSignalingStateChange(Object conn, Object f) {
+  conn as PeerConnection;
+  f as Function;
+  conn.onSignalingStateChange((e) {
+    f(e.index);
+  });
+}
+
 /// Returns JSON encoded [Array] of [RtcStats] from the provided
 /// [PeerConnection].
 Future<String> Function() _getStats(Object conn) {
@@ -215,6 +230,37 @@ int _iceConnectionState(Object conn) {
   return conn.iceConnectionState().index;
 }
 
+/// Returns the current [PeerConnection.signalingState] of the provided
+/// [PeerConnection].
+int _signalingState(Object conn) {
+  conn as PeerConnection;
+  return conn.signalingState().index;
+}
+
+/// Returns SDP of the [PeerConnection.localDescription] of the provided
+/// [PeerConnection], if any.
+Pointer _localDescription(Object conn) {
+  conn as PeerConnection;
+  var desc = conn.localDescription();
+  if (desc != null) {
+    return ForeignValue.fromString(desc.description).intoRustOwned();
+  } else {
+    return ForeignValue.none().intoRustOwned();
+  }
+}
+
+/// Returns SDP of the [PeerConnection.remoteDescription] of the provided
+/// [PeerConnection], if any.
+Pointer _remoteDescription(Object conn) {
+  conn as PeerConnection;
+  var desc = conn.remoteDescription();
+  if (desc != null) {
+    return ForeignValue.fromString(desc.description).intoRustOwned();
+  } else {
+    return ForeignValue.none().intoRustOwned();
+  }
+}
+
 /// Rollbacks the local SDP offer of the provided [PeerConnection].
 Future<void> Function() _rollback(Object conn) {
   conn as PeerConnection;
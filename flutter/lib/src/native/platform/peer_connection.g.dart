@@ -25,6 +25,10 @@ Object Function(Object)? _createOffer;
 Object Function(Object)? _createAnswer;
 Object Function(Object, Pointer<Utf8>, Pointer<Utf8>)? _setLocalDescription;
 Object Function(Object, Pointer<Utf8>, Pointer<Utf8>)? _setRemoteDescription;
+int Function(Object)? _signalingState;
+void Function(Object, Object)? _onSignalingStateChange;
+Pointer Function(Object)? _localDescription;
+Pointer Function(Object)? _remoteDescription;
 void Function(Object)? _close;
 
 _ErrorSetterFnDart? _peer_connection__ice_connection_state__set_error;
@@ -45,6 +49,10 @@ _ErrorSetterFnDart? _peer_connection__create_offer__set_error;
 _ErrorSetterFnDart? _peer_connection__create_answer__set_error;
 _ErrorSetterFnDart? _peer_connection__set_local_description__set_error;
 _ErrorSetterFnDart? _peer_connection__set_remote_description__set_error;
+_ErrorSetterFnDart? _peer_connection__signaling_state__set_error;
+_ErrorSetterFnDart? _peer_connection__on_signaling_state_change__set_error;
+_ErrorSetterFnDart? _peer_connection__local_description__set_error;
+_ErrorSetterFnDart? _peer_connection__remote_description__set_error;
 _ErrorSetterFnDart? _peer_connection__close__set_error;
 
 void registerFunction(
@@ -69,6 +77,10 @@ void registerFunction(
   setLocalDescription,
   required Object Function(Object, Pointer<Utf8>, Pointer<Utf8>)
   setRemoteDescription,
+  required int Function(Object) signalingState,
+  required void Function(Object, Object) onSignalingStateChange,
+  required Pointer Function(Object) localDescription,
+  required Pointer Function(Object) remoteDescription,
   required void Function(Object) close,
 }) {
   _iceConnectionState = iceConnectionState;
@@ -89,6 +101,10 @@ void registerFunction(
   _createAnswer = createAnswer;
   _setLocalDescription = setLocalDescription;
   _setRemoteDescription = setRemoteDescription;
+  _signalingState = signalingState;
+  _onSignalingStateChange = onSignalingStateChange;
+  _localDescription = localDescription;
+  _remoteDescription = remoteDescription;
   _close = close;
 
   _peer_connection__ice_connection_state__set_error = dl
@@ -163,6 +179,22 @@ void registerFunction(
       .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
         'peer_connection__set_remote_description__set_error',
       );
+  _peer_connection__signaling_state__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'peer_connection__signaling_state__set_error',
+      );
+  _peer_connection__on_signaling_state_change__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'peer_connection__on_signaling_state_change__set_error',
+      );
+  _peer_connection__local_description__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'peer_connection__local_description__set_error',
+      );
+  _peer_connection__remote_description__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'peer_connection__remote_description__set_error',
+      );
   _peer_connection__close__set_error = dl
       .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
         'peer_connection__close__set_error',
@@ -210,6 +242,16 @@ void registerFunction(
   setRemoteDescription_native = Pointer.fromFunction(
     _setRemoteDescriptionProxy,
   );
+  Pointer<NativeFunction<Int32 Function(Handle)>> signalingState_native =
+      Pointer.fromFunction(_signalingStateProxy, 0);
+  Pointer<NativeFunction<Void Function(Handle, Handle)>>
+  onSignalingStateChange_native = Pointer.fromFunction(
+    _onSignalingStateChangeProxy,
+  );
+  Pointer<NativeFunction<Pointer Function(Handle)>> localDescription_native =
+      Pointer.fromFunction(_localDescriptionProxy);
+  Pointer<NativeFunction<Pointer Function(Handle)>> remoteDescription_native =
+      Pointer.fromFunction(_remoteDescriptionProxy);
   Pointer<NativeFunction<Void Function(Handle)>> close_native =
       Pointer.fromFunction(_closeProxy);
 
@@ -234,6 +276,10 @@ void registerFunction(
       Pointer,
       Pointer,
       Pointer,
+      Pointer,
+      Pointer,
+      Pointer,
+      Pointer,
     ),
     void Function(
       Pointer,
@@ -255,6 +301,10 @@ void registerFunction(
       Pointer,
       Pointer,
       Pointer,
+      Pointer,
+      Pointer,
+      Pointer,
+      Pointer,
     )
   >('register_peer_connection')(
     iceConnectionState_native,
@@ -275,6 +325,10 @@ void registerFunction(
     createAnswer_native,
     setLocalDescription_native,
     setRemoteDescription_native,
+    signalingState_native,
+    onSignalingStateChange_native,
+    localDescription_native,
+    remoteDescription_native,
     close_native,
   );
 }
@@ -449,6 +503,42 @@ Object _setRemoteDescriptionProxy(
   }
 }
 
+int _signalingStateProxy(Object arg0) {
+  try {
+    return _signalingState!(arg0);
+  } catch (e) {
+    _peer_connection__signaling_state__set_error!(e);
+    return 0;
+  }
+}
+
+void _onSignalingStateChangeProxy(Object arg0, Object arg1) {
+  try {
+    return _onSignalingStateChange!(arg0, arg1);
+  } catch (e) {
+    _peer_connection__on_signaling_state_change__set_error!(e);
+    return;
+  }
+}
+
+Pointer _localDescriptionProxy(Object arg0) {
+  try {
+    return _localDescription!(arg0);
+  } catch (e) {
+    _peer_connection__local_description__set_error!(e);
+    return Pointer.fromAddress(0);
+  }
+}
+
+Pointer _remoteDescriptionProxy(Object arg0) {
+  try {
+    return _remoteDescription!(arg0);
+  } catch (e) {
+    _peer_connection__remote_description__set_error!(e);
+    return Pointer.fromAddress(0);
+  }
+}
+
 void _closeProxy(Object arg0) {
   try {
     return _close!(arg0);
@@ -61,15 +61,22 @@ class MockWebSocket {
   ///
   /// Subscribes to the created [WebSocket] messages with the specified
   /// [onMessage] and [onClose] callbacks.
+  ///
+  /// Offers the `permessage-deflate` extension to the server if [compression]
+  /// is `true`.
   static Future<WebSocket> Function() connect(
     Pointer<Utf8> addr,
     Object onMessage,
     Object onClose,
+    bool compression,
   ) {
     onMessage as Function;
     onClose as Function;
     return () async {
-      var ws = await WebSocket.connect(addr.nativeStringToDartString());
+      var ws = await WebSocket.connect(
+        addr.nativeStringToDartString(),
+        compression: _compressionOptions(compression),
+      );
       _lastWebSocket = ws;
 
       ws.listen(
@@ -102,15 +109,22 @@ class MockWebSocket {
 ///
 /// Subscribes to the created [WebSocket] messages with the given [onMessage]
 /// and [onClose] callbacks.
+///
+/// Offers the `permessage-deflate` extension to the server if [compression] is
+/// `true`.
 Future<WebSocket> Function() _connect(
   Pointer<Utf8> addr,
   Object onMessage,
   Object onClose,
+  bool compression,
 ) {
   onMessage as Function;
   onClose as Function;
   return () async {
-    var ws = await WebSocket.connect(addr.nativeStringToDartString());
+    var ws = await WebSocket.connect(
+      addr.nativeStringToDartString(),
+      compression: _compressionOptions(compression),
+    );
     ws.listen(
       (msg) {
         if (msg is String) {
@@ -126,6 +140,14 @@ Future<WebSocket> Function() _connect(
   };
 }
 
+/// Returns [CompressionOptions] offering the `permessage-deflate` extension if
+/// [compression] is `true`.
+CompressionOptions _compressionOptions(bool compression) {
+  return compression
+      ? CompressionOptions.compressionDefault
+      : CompressionOptions.compressionOff;
+}
+
 /// Sends the provided [message] to the provided [WebSocket].
 void _send(Object ws, Pointer<Utf8> message) {
   ws as WebSocket;
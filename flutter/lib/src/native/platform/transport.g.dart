@@ -7,7 +7,7 @@ import 'package:medea_jason/src/native/ffi/foreign_value.dart';
 typedef _ErrorSetterFnC = Void Function(Handle);
 typedef _ErrorSetterFnDart = void Function(Object);
 
-Object Function(Pointer<Utf8>, Object, Object)? _connect;
+Object Function(Pointer<Utf8>, Object, Object, bool)? _connect;
 void Function(Object, Pointer<Utf8>)? _send;
 void Function(Object, int, Pointer<Utf8>)? _close;
 int Function(Object)? _closeCode;
@@ -21,7 +21,7 @@ _ErrorSetterFnDart? _transport__close_reason__set_error;
 
 void registerFunction(
   DynamicLibrary dl, {
-  required Object Function(Pointer<Utf8>, Object, Object) connect,
+  required Object Function(Pointer<Utf8>, Object, Object, bool) connect,
   required void Function(Object, Pointer<Utf8>) send,
   required void Function(Object, int, Pointer<Utf8>) close,
   required int Function(Object) closeCode,
@@ -54,7 +54,9 @@ void registerFunction(
         'transport__close_reason__set_error',
       );
 
-  Pointer<NativeFunction<Handle Function(Pointer<Utf8>, Handle, Handle)>>
+  Pointer<
+    NativeFunction<Handle Function(Pointer<Utf8>, Handle, Handle, Bool)>
+  >
   connect_native = Pointer.fromFunction(_connectProxy);
   Pointer<NativeFunction<Void Function(Handle, Pointer<Utf8>)>> send_native =
       Pointer.fromFunction(_sendProxy);
@@ -77,9 +79,14 @@ void registerFunction(
   );
 }
 
-Object _connectProxy(Pointer<Utf8> arg0, Object arg1, Object arg2) {
+Object _connectProxy(
+  Pointer<Utf8> arg0,
+  Object arg1,
+  Object arg2,
+  bool arg3,
+) {
   try {
-    return _connect!(arg0, arg1, arg2);
+    return _connect!(arg0, arg1, arg2, arg3);
   } catch (e) {
     _transport__connect__set_error!(e);
     return 0;
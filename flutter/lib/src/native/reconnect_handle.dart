@@ -15,6 +15,16 @@ class NativeReconnectHandle implements ReconnectHandle {
     RustHandlesStorage().insertHandle(this);
   }
 
+  @override
+  int? closeCode() {
+    return opaque.inner.closeCode();
+  }
+
+  @override
+  String? closeReason() {
+    return opaque.inner.closeReason();
+  }
+
   @override
   Future<void> reconnectWithDelay(int delayMs) async {
     if (delayMs.isNegative || delayMs.bitLength > 32) {
@@ -0,0 +1,82 @@
+import 'dart:typed_data';
+
+import '../interface/media_track.dart';
+import '../interface/recorder.dart' as base;
+import '../util/move_semantic.dart';
+import '../util/rust_opaque.dart';
+import '/src/util/rust_handles_storage.dart';
+import 'ffi/frb/frb.dart' as frb;
+import 'local_media_track.dart';
+import 'remote_media_track.dart';
+
+class Recorder implements base.Recorder {
+  /// `flutter_rust_bridge` Rust opaque type backing this object.
+  final RustOpaque<frb.Recorder> opaque;
+
+  /// Creates a new [Recorder] with the provided [mimeType] and target
+  /// [bitsPerSecond] of the recording.
+  Recorder({String? mimeType, int? bitsPerSecond})
+    : opaque = RustOpaque(
+        frb.Recorder(mimeType: mimeType, bitsPerSecond: bitsPerSecond),
+      ) {
+    RustHandlesStorage().insertHandle(this);
+  }
+
+  @override
+  void addLocalTrack(LocalMediaTrack track) {
+    opaque.inner.addLocalTrack(
+      track: (track as NativeLocalMediaTrack).opaque.inner,
+    );
+  }
+
+  @override
+  void addRemoteTrack(RemoteMediaTrack track) {
+    opaque.inner.addRemoteTrack(
+      track: (track as NativeRemoteMediaTrack).opaque.inner,
+    );
+  }
+
+  @override
+  void start([int? timesliceMs]) {
+    if (timesliceMs != null &&
+        (timesliceMs.isNegative || timesliceMs.bitLength > 32)) {
+      throw ArgumentError.value(timesliceMs, 'timesliceMs', 'Expected `u32`');
+    }
+
+    opaque.inner.start(timesliceMs: timesliceMs);
+  }
+
+  @override
+  void pause() {
+    opaque.inner.pause();
+  }
+
+  @override
+  void resume() {
+    opaque.inner.resume();
+  }
+
+  @override
+  Future<void> stop() async {
+    await (opaque.inner.stop() as Future);
+  }
+
+  @override
+  void onData(void Function(Uint8List) f) {
+    opaque.inner.onData(
+      f: (List<dynamic> data) {
+        f(Uint8List.fromList(data.cast<int>()));
+      },
+    );
+  }
+
+  @moveSemantics
+  @override
+  void free() {
+    if (!opaque.isDisposed) {
+      RustHandlesStorage().removeHandle(this);
+
+      opaque.dispose();
+    }
+  }
+}
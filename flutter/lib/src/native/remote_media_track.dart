@@ -52,6 +52,21 @@ class NativeRemoteMediaTrack implements RemoteMediaTrack {
     return opaque.inner.onUnmuted(f: f);
   }
 
+  @override
+  bool paused() {
+    return opaque.inner.paused();
+  }
+
+  @override
+  void onPaused(void Function() f) {
+    return opaque.inner.onPaused(f: f);
+  }
+
+  @override
+  void onResumed(void Function() f) {
+    return opaque.inner.onResumed(f: f);
+  }
+
   @override
   void onStopped(void Function() f) {
     return opaque.inner.onStopped(f: f);
@@ -1,14 +1,19 @@
+import 'dart:typed_data';
+
 import '../interface/connection_handle.dart';
+import '../interface/local_media_fallback.dart';
 import '../interface/media_stream_settings.dart' as base_settings;
 import '../interface/media_track.dart';
 import '../interface/reconnect_handle.dart';
 import '../interface/room_close_reason.dart';
 import '../interface/room_handle.dart';
+import '../interface/room_lifecycle_event.dart';
 import '../util/move_semantic.dart';
 import '../util/rust_opaque.dart';
 import '/src/util/rust_handles_storage.dart';
 import 'connection_handle.dart';
 import 'ffi/frb/frb.dart' as frb;
+import 'local_media_fallback.dart';
 import 'local_media_track.dart';
 import 'media_stream_settings.dart';
 import 'reconnect_handle.dart';
@@ -30,6 +35,11 @@ class NativeRoomHandle implements RoomHandle {
     await (opaque.inner.join(token: token) as Future);
   }
 
+  @override
+  Future<void> leave(int timeoutMs) async {
+    await (opaque.inner.leave(timeoutMs: timeoutMs) as Future);
+  }
+
   @override
   Future<void> setLocalMediaSettings(
     base_settings.MediaStreamSettings settings,
@@ -122,6 +132,22 @@ class NativeRoomHandle implements RoomHandle {
     );
   }
 
+  @override
+  void onLifecycle(void Function(RoomLifecycleEvent) f) {
+    opaque.inner.onLifecycle(
+      cb: (t) {
+        var event = frb.RoomLifecycleEvent.fromPtr(ptr: t.address);
+        var reason = event.closeReason;
+        f(
+          RoomLifecycleEvent(
+            RoomLifecycleEventKind.values[event.kind.index],
+            reason == null ? null : NativeRoomCloseReason(reason),
+          ),
+        );
+      },
+    );
+  }
+
   @override
   void onLocalTrack(void Function(LocalMediaTrack) f) {
     opaque.inner.onLocalTrack(
@@ -149,6 +175,102 @@ class NativeRoomHandle implements RoomHandle {
     );
   }
 
+  @override
+  void onLocalMediaFallback(void Function(LocalMediaFallback) f) {
+    opaque.inner.onLocalMediaFallback(
+      cb: (t) {
+        f(
+          NativeLocalMediaFallback(
+            frb.LocalMediaFallback.fromPtr(ptr: t.address),
+          ),
+        );
+      },
+    );
+  }
+
+  @override
+  void enableLossBasedResolution({
+    required double highFractionLost,
+    required double lowFractionLost,
+    required Duration sustain,
+    required double step,
+    required double maxScale,
+  }) {
+    opaque.inner.enableLossBasedResolution(
+      highFractionLost: highFractionLost,
+      lowFractionLost: lowFractionLost,
+      sustainMs: sustain.inMilliseconds,
+      step: step,
+      maxScale: maxScale,
+    );
+  }
+
+  @override
+  void disableLossBasedResolution() {
+    opaque.inner.disableLossBasedResolution();
+  }
+
+  @override
+  void enableAdaptiveBitrate({
+    required int minBitrate,
+    required int maxBitrate,
+    required Duration highRtt,
+    required double highFractionLost,
+  }) {
+    opaque.inner.enableAdaptiveBitrate(
+      minBitrate: minBitrate,
+      maxBitrate: maxBitrate,
+      highRttMs: highRtt.inMilliseconds,
+      highFractionLost: highFractionLost,
+    );
+  }
+
+  @override
+  void disableAdaptiveBitrate() {
+    opaque.inner.disableAdaptiveBitrate();
+  }
+
+  @override
+  void renegotiate() {
+    opaque.inner.renegotiate();
+  }
+
+  @override
+  void setDisconnectGracePeriod(int periodMs) {
+    opaque.inner.setDisconnectGracePeriod(periodMs: periodMs);
+  }
+
+  @override
+  void setStatsSendingEnabled(bool enabled) {
+    opaque.inner.setStatsSendingEnabled(enabled: enabled);
+  }
+
+  @override
+  void setEarlyMedia(bool enabled) {
+    opaque.inner.setEarlyMedia(enabled: enabled);
+  }
+
+  @override
+  void sendApplicationMessage(Uint8List data) {
+    opaque.inner.sendApplicationMessage(data: data);
+  }
+
+  @override
+  RoomState state() {
+    return opaque.inner.state();
+  }
+
+  @override
+  Future<void> setMaxSendResolution(int width, int height) async {
+    await (opaque.inner.setMaxSendResolution(width: width, height: height)
+        as Future);
+  }
+
+  @override
+  Future<void> resetMaxSendResolution() async {
+    await (opaque.inner.resetMaxSendResolution() as Future);
+  }
+
   @moveSemantics
   @override
   void free() {
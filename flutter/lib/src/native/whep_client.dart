@@ -0,0 +1,62 @@
+import '../interface/connection_handle.dart';
+import '../interface/whep_client.dart';
+import '../util/move_semantic.dart';
+import '../util/rust_opaque.dart';
+import '/src/util/rust_handles_storage.dart';
+import 'connection_handle.dart';
+import 'ffi/frb/frb.dart' as frb;
+
+class NativeWhepClient implements WhepClient {
+  /// `flutter_rust_bridge` Rust opaque type backing this object.
+  final RustOpaque<frb.WhepClient> opaque;
+
+  /// Constructs a new [WhepClient] backed by the Rust struct behind the
+  /// provided [frb.WhepClient].
+  NativeWhepClient(frb.WhepClient whepClient)
+    : opaque = RustOpaque(whepClient) {
+    RustHandlesStorage().insertHandle(this);
+  }
+
+  @override
+  void onNewConnection(void Function(ConnectionHandle) f) {
+    opaque.inner.onNewConnection(
+      f: (t) {
+        f(NativeConnectionHandle(frb.ConnectionHandle.fromPtr(ptr: t.address)));
+      },
+    );
+  }
+
+  @override
+  void onFailure(void Function(Object) f) {
+    opaque.inner.onFailure(
+      f: (err) {
+        f(err);
+      },
+    );
+  }
+
+  @override
+  Future<void> play(String url, {String? token}) async {
+    await (opaque.inner.play(url: url, token: token) as Future);
+  }
+
+  @override
+  String? resource() {
+    return opaque.inner.resource();
+  }
+
+  @override
+  Future<void> close() async {
+    await (opaque.inner.close() as Future);
+  }
+
+  @moveSemantics
+  @override
+  void free() {
+    if (!opaque.isDisposed) {
+      RustHandlesStorage().removeHandle(this);
+
+      opaque.dispose();
+    }
+  }
+}
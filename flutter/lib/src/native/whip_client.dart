@@ -0,0 +1,46 @@
+import '../interface/whip_client.dart';
+import '../util/move_semantic.dart';
+import '../util/rust_opaque.dart';
+import '/src/util/rust_handles_storage.dart';
+import 'ffi/frb/frb.dart' as frb;
+
+class NativeWhipClient implements WhipClient {
+  /// `flutter_rust_bridge` Rust opaque type backing this object.
+  final RustOpaque<frb.WhipClient> opaque;
+
+  /// Constructs a new [WhipClient] backed by the Rust struct behind the
+  /// provided [frb.WhipClient].
+  NativeWhipClient(frb.WhipClient whipClient)
+    : opaque = RustOpaque(whipClient) {
+    RustHandlesStorage().insertHandle(this);
+  }
+
+  @override
+  String resource() {
+    return opaque.inner.resource();
+  }
+
+  @override
+  void onFailure(void Function(Object) f) {
+    opaque.inner.onFailure(
+      f: (err) {
+        f(err);
+      },
+    );
+  }
+
+  @override
+  Future<void> close() async {
+    await (opaque.inner.close() as Future);
+  }
+
+  @moveSemantics
+  @override
+  void free() {
+    if (!opaque.isDisposed) {
+      RustHandlesStorage().removeHandle(this);
+
+      opaque.dispose();
+    }
+  }
+}
@@ -1,6 +1,7 @@
 // ignore_for_file: avoid_web_libraries_in_flutter
 
 import 'dart:js_interop';
+import 'dart:typed_data';
 
 import '../interface/connection_handle.dart';
 import '../interface/media_track.dart';
@@ -36,6 +37,22 @@ class WebConnectionHandle implements ConnectionHandle {
     fallibleFunction(() => obj.on_quality_score_update(f.toJS));
   }
 
+  @override
+  void onInboundQualityUpdate(void Function(bool) f) {
+    fallibleFunction(() => obj.on_inbound_quality_update(f.toJS));
+  }
+
+  @override
+  void onApplicationMessage(void Function(Uint8List) f) {
+    void fn(JSAny? msg) {
+      var message = msg as wasm.ApplicationMessage;
+      f(message.data().toDart);
+      message.free();
+    }
+
+    fallibleFunction(() => obj.on_application_message(fn.toJS));
+  }
+
   @override
   Future<void> enableRemoteAudio() async {
     await fallibleFuture(obj.enable_remote_audio().toDart);
@@ -56,6 +73,16 @@ class WebConnectionHandle implements ConnectionHandle {
     await fallibleFuture(obj.disable_remote_video(kind?.index).toDart);
   }
 
+  @override
+  void requestQuality(int spatial, [int? temporal]) {
+    fallibleFunction(() => obj.request_quality(spatial, temporal));
+  }
+
+  @override
+  bool isRemoteMuted(MediaKind kind) {
+    return fallibleFunction(() => obj.is_remote_muted(kind.index));
+  }
+
   @moveSemantics
   @override
   void free() {
@@ -1,11 +1,42 @@
+import 'dart:js_interop';
+
+import '../interface/enums.dart' show RpcCompression;
 import '../interface/jason.dart' as base;
+import '../interface/log_event.dart';
 import '../interface/media_manager.dart';
+import '../interface/media_stream_settings.dart' as base_settings;
 import '../interface/room_handle.dart';
+import '../interface/whep_client.dart';
+import '../interface/whip_client.dart';
 import '../util/move_semantic.dart';
 import 'exceptions.dart';
 import 'jason_wasm.dart' as wasm;
 import 'media_manager.dart';
+import 'media_stream_settings.dart';
 import 'room_handle.dart';
+import 'whep_client.dart';
+import 'whip_client.dart';
+
+/// Sets the provided [sink] to receive all the [LogEvent]s of this library
+/// instead of the default logger.
+///
+/// If `null` is provided, then the default logger is used again.
+void setLogSink(void Function(LogEvent)? sink) {
+  void fn(JSAny? e) {
+    var event = e as wasm.LogEvent;
+    sink!(
+      LogEvent(
+        event.level(),
+        event.category(),
+        event.message(),
+        event.peer_id()?.toInt(),
+      ),
+    );
+    event.free();
+  }
+
+  wasm.Jason.setLogSink(sink == null ? null : fn.toJS);
+}
 
 class Jason extends base.Jason {
   final wasm.Jason obj = wasm.Jason();
@@ -27,6 +58,36 @@ class Jason extends base.Jason {
     return fallibleFunction(() => WebRoomHandle(obj.init_room()));
   }
 
+  @override
+  Future<WhipClient> publishWhip(
+    String url,
+    base_settings.MediaStreamSettings settings, {
+    String? token,
+  }) async {
+    var client = await fallibleFuture(
+      obj
+          .publish_whip(url, token, (settings as MediaStreamSettings).obj)
+          .toDart,
+    );
+    return WebWhipClient(client as wasm.WhipClient);
+  }
+
+  @override
+  WhepClient whepClient() {
+    return fallibleFunction(() => WebWhepClient(obj.whep_client()));
+  }
+
+  @override
+  void setRpcCompression(RpcCompression compression) {
+    // Browsers negotiate compression on their own.
+  }
+
+  @override
+  void setTokenRefresher(Future<String> Function() f) {
+    JSPromise<JSString> fn() => f().then((token) => token.toJS).toJS;
+    fallibleFunction(() => obj.set_token_refresher(fn.toJS));
+  }
+
   @override
   void closeRoom(@moveSemantics RoomHandle room) {
     fallibleFunction(() => obj.close_room((room as WebRoomHandle).obj));
@@ -37,4 +98,10 @@ class Jason extends base.Jason {
   void free() {
     obj.free();
   }
+
+  @override
+  @moveSemantics
+  Future<void> disposeAsync(int timeoutMs) async {
+    await fallibleFuture(obj.dispose_async(timeoutMs).toDart);
+  }
 }
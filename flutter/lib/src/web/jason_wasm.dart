@@ -36,6 +36,7 @@ extension type RpcClientExceptionKind._(JSObject _) implements JSObject {
   external static num get ConnectionLost;
   external static num get AuthorizationFailed;
   external static num get SessionFinished;
+  external static num get HttpRequestFailed;
 }
 
 @JS()
@@ -51,6 +52,21 @@ extension type AudioTrackConstraints._(JSObject _) implements JSObject {
   external void exact_noise_suppression(bool noise_suppression);
 }
 
+@JS()
+extension type ApplicationMessage._(JSObject _) implements JSObject {
+  external void free();
+  external JSUint8Array data();
+}
+
+@JS()
+extension type LogEvent._(JSObject _) implements JSObject {
+  external void free();
+  external String level();
+  external num? peer_id();
+  external String category();
+  external String message();
+}
+
 @JS()
 extension type ConnectionHandle._(JSObject _) implements JSObject {
   external void free();
@@ -58,10 +74,14 @@ extension type ConnectionHandle._(JSObject _) implements JSObject {
   external String get_remote_member_id();
   external void on_remote_track_added(JSFunction cb);
   external void on_quality_score_update(JSFunction cb);
+  external void on_inbound_quality_update(JSFunction cb);
+  external void on_application_message(JSFunction cb);
   external JSPromise<JSAny?> disable_remote_audio();
   external JSPromise<JSAny?> disable_remote_video(num? source_kind);
   external JSPromise<JSAny?> enable_remote_audio();
   external JSPromise<JSAny?> enable_remote_video(num? source_kind);
+  external void request_quality(num spatial, num? temporal);
+  external bool is_remote_muted(num kind);
 }
 
 @JS()
@@ -130,7 +150,16 @@ extension type Jason._(JSObject _) implements JSObject {
   external RoomHandle init_room();
   external MediaManagerHandle media_manager();
   external void close_room(RoomHandle room_to_delete);
+  external JSPromise<JSAny?> publish_whip(
+    String url,
+    String? token,
+    MediaStreamSettings settings,
+  );
+  external WhepClient whep_client();
+  external void set_token_refresher(JSFunction cb);
+  external JSPromise<JSAny?> dispose_async(num timeout_ms);
   external void dispose();
+  external static void setLogSink(JSFunction? cb);
 }
 
 @JS()
@@ -142,6 +171,13 @@ extension type LocalMediaInitException._(JSObject _) implements JSObject {
   external String trace();
 }
 
+@JS()
+extension type LocalMediaFallback._(JSObject _) implements JSObject {
+  external void free();
+  external MediaStreamSettings attempted();
+  external MediaStreamSettings succeeded();
+}
+
 @JS()
 extension type LocalMediaTrack._(JSObject _) implements JSObject {
   external void free();
@@ -150,6 +186,8 @@ extension type LocalMediaTrack._(JSObject _) implements JSObject {
   external num media_source_kind();
   external JSPromise<JSAny?> state();
   external void on_enabled(JSFunction cb);
+  external void set_mirror(bool mirror);
+  external bool is_mirrored();
   external bool is_on_audio_level_available();
   external void on_audio_level_changed(JSFunction cb);
   external bool is_audio_processing_available();
@@ -199,6 +237,8 @@ extension type MediaStreamSettings._(JSObject _) implements JSObject {
 @JS('ReconnectHandle')
 extension type ReconnectHandle._(JSObject _) implements JSObject {
   external void free();
+  external int? close_code();
+  external String? close_reason();
   external JSPromise<JSAny?> reconnect_with_delay(num delay_ms);
   external JSPromise<JSAny?> reconnect_with_backoff(
     num starting_delay_ms,
@@ -208,6 +248,19 @@ extension type ReconnectHandle._(JSObject _) implements JSObject {
   );
 }
 
+@JS()
+extension type Recorder._(JSObject _) implements JSObject {
+  external void free();
+  external factory Recorder(String? mime_type, num? bits_per_second);
+  external void add_local_track(LocalMediaTrack track);
+  external void add_remote_track(RemoteMediaTrack track);
+  external void start(num? timeslice_ms);
+  external void pause();
+  external void resume();
+  external JSPromise<JSAny?> stop();
+  external void on_data(JSFunction cb);
+}
+
 @JS()
 extension type RemoteMediaTrack._(JSObject _) implements JSObject {
   external void free();
@@ -220,6 +273,9 @@ extension type RemoteMediaTrack._(JSObject _) implements JSObject {
   external void on_unmuted(JSFunction cb);
   external void on_stopped(JSFunction cb);
   external void on_media_direction_changed(JSFunction cb);
+  external bool paused();
+  external void on_paused(JSFunction cb);
+  external void on_resumed(JSFunction cb);
   external num kind();
   external num media_source_kind();
   external num media_direction();
@@ -233,15 +289,25 @@ extension type RoomCloseReason._(JSObject _) implements JSObject {
   external bool is_err();
 }
 
+@JS()
+extension type RoomLifecycleEvent._(JSObject _) implements JSObject {
+  external void free();
+  external num kind();
+  external RoomCloseReason? close_reason();
+}
+
 @JS()
 extension type RoomHandle._(JSObject _) implements JSObject {
   external void free();
   external void on_new_connection(JSFunction cb);
   external void on_close(JSFunction cb);
+  external void on_lifecycle(JSFunction cb);
   external void on_local_track(JSFunction cb);
   external void on_failed_local_media(JSFunction cb);
+  external void on_local_media_fallback(JSFunction cb);
   external void on_connection_loss(JSFunction cb);
   external JSPromise<JSAny?> join(String token);
+  external JSPromise<JSAny?> leave(num timeout_ms);
   external JSPromise<JSAny?> set_local_media_settings(
     MediaStreamSettings settings,
     bool stop_first,
@@ -259,6 +325,29 @@ extension type RoomHandle._(JSObject _) implements JSObject {
   external JSPromise<JSAny?> disable_remote_video(num? source_kind);
   external JSPromise<JSAny?> enable_remote_audio();
   external JSPromise<JSAny?> enable_remote_video(num? source_kind);
+  external void enable_loss_based_resolution(
+    num high_fraction_lost,
+    num low_fraction_lost,
+    num sustain_ms,
+    num step,
+    num max_scale,
+  );
+  external void disable_loss_based_resolution();
+  external void enable_adaptive_bitrate(
+    num min_bitrate,
+    num max_bitrate,
+    num high_rtt_ms,
+    num high_fraction_lost,
+  );
+  external void disable_adaptive_bitrate();
+  external void renegotiate();
+  external void set_disconnect_grace_period(num period_ms);
+  external void set_stats_sending_enabled(bool enabled);
+  external void set_early_media(bool enabled);
+  external void send_application_message(JSUint8Array data);
+  external num state();
+  external JSPromise<JSAny?> set_max_send_resolution(num width, num height);
+  external JSPromise<JSAny?> reset_max_send_resolution();
 }
 
 @JS()
@@ -276,3 +365,21 @@ extension type StateError._(JSObject _) implements JSObject {
   external String message();
   external String trace();
 }
+
+@JS()
+extension type WhepClient._(JSObject _) implements JSObject {
+  external void free();
+  external void on_new_connection(JSFunction cb);
+  external void on_failure(JSFunction cb);
+  external JSPromise<JSAny?> play(String url, String? token);
+  external String? resource();
+  external JSPromise<JSAny?> close();
+}
+
+@JS()
+extension type WhipClient._(JSObject _) implements JSObject {
+  external void free();
+  external String resource();
+  external void on_failure(JSFunction cb);
+  external JSPromise<JSAny?> close();
+}
@@ -0,0 +1,27 @@
+import '../interface/local_media_fallback.dart';
+import '../util/move_semantic.dart';
+import 'exceptions.dart';
+import 'jason_wasm.dart' as wasm;
+import 'media_stream_settings.dart';
+
+class WebLocalMediaFallback implements LocalMediaFallback {
+  late wasm.LocalMediaFallback obj;
+
+  WebLocalMediaFallback(this.obj);
+
+  @override
+  MediaStreamSettings attempted() {
+    return fallibleFunction(() => MediaStreamSettings.fromObj(obj.attempted()));
+  }
+
+  @override
+  MediaStreamSettings succeeded() {
+    return fallibleFunction(() => MediaStreamSettings.fromObj(obj.succeeded()));
+  }
+
+  @moveSemantics
+  @override
+  void free() {
+    obj.free();
+  }
+}
@@ -50,6 +50,16 @@ class WebLocalMediaTrack implements LocalMediaTrack {
     return MediaStreamTrackState.values[index.toDartInt];
   }
 
+  @override
+  void setMirror(bool mirror) {
+    fallibleFunction(() => obj.set_mirror(mirror));
+  }
+
+  @override
+  bool isMirrored() {
+    return fallibleFunction(() => obj.is_mirrored());
+  }
+
   @override
   bool isOnAudioLevelAvailable() {
     return obj.is_on_audio_level_available();
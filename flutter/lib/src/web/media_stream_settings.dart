@@ -12,7 +12,13 @@ import '../interface/display_video_track_constraints.dart'
     as base_display_video;
 
 class MediaStreamSettings implements base.MediaStreamSettings {
-  final wasm.MediaStreamSettings obj = wasm.MediaStreamSettings();
+  final wasm.MediaStreamSettings obj;
+
+  /// Creates new [MediaStreamSettings] with none constraints configured.
+  MediaStreamSettings() : obj = wasm.MediaStreamSettings();
+
+  /// Creates new [MediaStreamSettings] wrapping the provided JS object.
+  MediaStreamSettings.fromObj(this.obj);
 
   @override
   void audio(@moveSemantics base_audio.AudioTrackConstraints constraints) {
@@ -10,6 +10,16 @@ class WebReconnectHandle implements ReconnectHandle {
 
   WebReconnectHandle(this.obj);
 
+  @override
+  int? closeCode() {
+    return fallibleFunction(() => obj.close_code());
+  }
+
+  @override
+  String? closeReason() {
+    return fallibleFunction(() => obj.close_reason());
+  }
+
   @override
   Future<void> reconnectWithDelay(int delayMs) async {
     await fallibleFuture(obj.reconnect_with_delay(delayMs).toDart);
@@ -0,0 +1,70 @@
+import 'dart:js_interop';
+import 'dart:typed_data';
+
+import '../interface/media_track.dart';
+import '../interface/recorder.dart' as base;
+import '../util/move_semantic.dart';
+import 'exceptions.dart';
+import 'jason_wasm.dart' as wasm;
+import 'local_media_track.dart';
+import 'remote_media_track.dart';
+
+class Recorder implements base.Recorder {
+  final wasm.Recorder obj;
+
+  /// Creates a new [Recorder] with the provided [mimeType] and target
+  /// [bitsPerSecond] of the recording.
+  Recorder({String? mimeType, int? bitsPerSecond})
+    : obj = wasm.Recorder(mimeType, bitsPerSecond);
+
+  @override
+  void addLocalTrack(LocalMediaTrack track) {
+    fallibleFunction(
+      () => obj.add_local_track((track as WebLocalMediaTrack).obj),
+    );
+  }
+
+  @override
+  void addRemoteTrack(RemoteMediaTrack track) {
+    fallibleFunction(
+      () => obj.add_remote_track((track as WebRemoteMediaTrack).obj),
+    );
+  }
+
+  @override
+  void start([int? timesliceMs]) {
+    fallibleFunction(() => obj.start(timesliceMs));
+  }
+
+  @override
+  void pause() {
+    fallibleFunction(() => obj.pause());
+  }
+
+  @override
+  void resume() {
+    fallibleFunction(() => obj.resume());
+  }
+
+  @override
+  Future<void> stop() async {
+    await fallibleFuture(obj.stop().toDart);
+  }
+
+  @override
+  void onData(void Function(Uint8List) f) {
+    fallibleFunction(
+      () => obj.on_data(
+        (JSUint8Array data) {
+          f(data.toDart);
+        }.toJS,
+      ),
+    );
+  }
+
+  @moveSemantics
+  @override
+  void free() {
+    obj.free();
+  }
+}
@@ -54,6 +54,21 @@ class WebRemoteMediaTrack implements RemoteMediaTrack {
     fallibleFunction(() => obj.on_unmuted(f.toJS));
   }
 
+  @override
+  bool paused() {
+    return fallibleFunction(() => obj.paused());
+  }
+
+  @override
+  void onPaused(void Function() f) {
+    fallibleFunction(() => obj.on_paused(f.toJS));
+  }
+
+  @override
+  void onResumed(void Function() f) {
+    fallibleFunction(() => obj.on_resumed(f.toJS));
+  }
+
   @override
   void onStopped(void Function() f) {
     fallibleFunction(() => obj.on_stopped(f.toJS));
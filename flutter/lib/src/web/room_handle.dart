@@ -1,15 +1,19 @@
 import 'dart:js_interop';
+import 'dart:typed_data';
 
 import '../interface/connection_handle.dart';
+import '../interface/local_media_fallback.dart';
 import '../interface/media_stream_settings.dart' as base_settings;
 import '../interface/media_track.dart';
 import '../interface/reconnect_handle.dart';
 import '../interface/room_close_reason.dart';
 import '../interface/room_handle.dart';
+import '../interface/room_lifecycle_event.dart';
 import '../util/move_semantic.dart';
 import 'connection_handle.dart';
 import 'exceptions.dart';
 import 'jason_wasm.dart' as wasm;
+import 'local_media_fallback.dart';
 import 'local_media_track.dart';
 import 'media_stream_settings.dart';
 import 'reconnect_handle.dart';
@@ -25,6 +29,11 @@ class WebRoomHandle implements RoomHandle {
     await fallibleFuture(obj.join(token).toDart);
   }
 
+  @override
+  Future<void> leave(int timeoutMs) async {
+    await fallibleFuture(obj.leave(timeoutMs).toDart);
+  }
+
   @override
   Future<void> setLocalMediaSettings(
     base_settings.MediaStreamSettings settings,
@@ -117,6 +126,22 @@ class WebRoomHandle implements RoomHandle {
     fallibleFunction(() => obj.on_close(fn.toJS));
   }
 
+  @override
+  void onLifecycle(void Function(RoomLifecycleEvent) f) {
+    void fn(JSAny? e) {
+      var event = e as wasm.RoomLifecycleEvent;
+      var reason = event.close_reason();
+      f(
+        RoomLifecycleEvent(
+          RoomLifecycleEventKind.values[event.kind().toInt()],
+          reason == null ? null : WebRoomCloseReason(reason),
+        ),
+      );
+    }
+
+    fallibleFunction(() => obj.on_lifecycle(fn.toJS));
+  }
+
   @override
   void onLocalTrack(void Function(LocalMediaTrack) f) {
     void fn(JSAny? track) =>
@@ -137,6 +162,99 @@ class WebRoomHandle implements RoomHandle {
     fallibleFunction(() => obj.on_failed_local_media(fn.toJS));
   }
 
+  @override
+  void onLocalMediaFallback(void Function(LocalMediaFallback) f) {
+    void fn(JSAny? fallback) =>
+        f(WebLocalMediaFallback(fallback as wasm.LocalMediaFallback));
+    fallibleFunction(() => obj.on_local_media_fallback(fn.toJS));
+  }
+
+  @override
+  void enableLossBasedResolution({
+    required double highFractionLost,
+    required double lowFractionLost,
+    required Duration sustain,
+    required double step,
+    required double maxScale,
+  }) {
+    fallibleFunction(
+      () => obj.enable_loss_based_resolution(
+        highFractionLost,
+        lowFractionLost,
+        sustain.inMilliseconds,
+        step,
+        maxScale,
+      ),
+    );
+  }
+
+  @override
+  void disableLossBasedResolution() {
+    fallibleFunction(() => obj.disable_loss_based_resolution());
+  }
+
+  @override
+  void enableAdaptiveBitrate({
+    required int minBitrate,
+    required int maxBitrate,
+    required Duration highRtt,
+    required double highFractionLost,
+  }) {
+    fallibleFunction(
+      () => obj.enable_adaptive_bitrate(
+        minBitrate,
+        maxBitrate,
+        highRtt.inMilliseconds,
+        highFractionLost,
+      ),
+    );
+  }
+
+  @override
+  void disableAdaptiveBitrate() {
+    fallibleFunction(() => obj.disable_adaptive_bitrate());
+  }
+
+  @override
+  void renegotiate() {
+    fallibleFunction(() => obj.renegotiate());
+  }
+
+  @override
+  void setDisconnectGracePeriod(int periodMs) {
+    fallibleFunction(() => obj.set_disconnect_grace_period(periodMs));
+  }
+
+  @override
+  void setStatsSendingEnabled(bool enabled) {
+    fallibleFunction(() => obj.set_stats_sending_enabled(enabled));
+  }
+
+  @override
+  void setEarlyMedia(bool enabled) {
+    fallibleFunction(() => obj.set_early_media(enabled));
+  }
+
+  @override
+  void sendApplicationMessage(Uint8List data) {
+    fallibleFunction(() => obj.send_application_message(data.toJS));
+  }
+
+  @override
+  RoomState state() {
+    return RoomState.values[obj.state().toInt()];
+  }
+
+  @override
+  Future<void> setMaxSendResolution(int width, int height) async {
+    await fallibleFuture(obj.set_max_send_resolution(width, height).toDart);
+  }
+
+  @override
+  Future<void> resetMaxSendResolution() async {
+    await fallibleFuture(obj.reset_max_send_resolution().toDart);
+  }
+
   @moveSemantics
   @override
   void free() {
@@ -92,3 +92,35 @@ enum NoiseSuppressionLevel {
   /// Maximum suppression.
   veryHigh,
 }
+
+/// Compression of the messages sent to a media server.
+enum RpcCompression {
+  /// Messages are sent uncompressed.
+  disabled,
+
+  /// [permessage-deflate][1] extension is offered to a media server, and used
+  /// if it accepts it.
+  ///
+  /// [1]: https://tools.ietf.org/html/rfc7692
+  deflate,
+}
+
+/// State of a `Room`.
+enum RoomState {
+  /// `Room` hasn't been joined yet.
+  new_,
+
+  /// `Room` is connecting to a media server and joining it.
+  connecting,
+
+  /// `Room` has joined a media server.
+  connected,
+
+  /// `Room` has lost its connection with a media server and is recovering
+  /// it.
+  reconnecting,
+
+  /// `Room` has been closed, so all the [RoomHandle] methods throw a
+  /// [StateError].
+  closed,
+}
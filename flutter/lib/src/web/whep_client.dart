@@ -0,0 +1,49 @@
+import 'dart:js_interop';
+
+import '../interface/connection_handle.dart';
+import '../interface/whep_client.dart';
+import '../util/move_semantic.dart';
+import 'connection_handle.dart';
+import 'exceptions.dart';
+import 'jason_wasm.dart' as wasm;
+
+class WebWhepClient implements WhepClient {
+  late wasm.WhepClient obj;
+
+  WebWhepClient(this.obj);
+
+  @override
+  void onNewConnection(void Function(ConnectionHandle) f) {
+    void fn(JSAny? handle) =>
+        f(WebConnectionHandle(handle as wasm.ConnectionHandle));
+
+    fallibleFunction(() => obj.on_new_connection(fn.toJS));
+  }
+
+  @override
+  void onFailure(void Function(Object) f) {
+    void fn(JSAny? e) => f(convertException(e));
+    fallibleFunction(() => obj.on_failure(fn.toJS));
+  }
+
+  @override
+  Future<void> play(String url, {String? token}) async {
+    await fallibleFuture(obj.play(url, token).toDart);
+  }
+
+  @override
+  String? resource() {
+    return fallibleFunction(() => obj.resource());
+  }
+
+  @override
+  Future<void> close() async {
+    await fallibleFuture(obj.close().toDart);
+  }
+
+  @moveSemantics
+  @override
+  void free() {
+    obj.free();
+  }
+}
@@ -0,0 +1,34 @@
+import 'dart:js_interop';
+
+import '../interface/whip_client.dart';
+import '../util/move_semantic.dart';
+import 'exceptions.dart';
+import 'jason_wasm.dart' as wasm;
+
+class WebWhipClient implements WhipClient {
+  late wasm.WhipClient obj;
+
+  WebWhipClient(this.obj);
+
+  @override
+  String resource() {
+    return fallibleFunction(() => obj.resource());
+  }
+
+  @override
+  void onFailure(void Function(Object) f) {
+    void fn(JSAny? e) => f(convertException(e));
+    fallibleFunction(() => obj.on_failure(fn.toJS));
+  }
+
+  @override
+  Future<void> close() async {
+    await fallibleFuture(obj.close().toDart);
+  }
+
+  @moveSemantics
+  @override
+  void free() {
+    obj.free();
+  }
+}
@@ -336,6 +336,16 @@ pub enum ServerMsg {
     /// Media Server notifies Web Client about necessity to update its RPC
     /// settings.
     RpcSettings(RpcSettings),
+
+    /// Media Server answers to a [`ClientMsg::TimeSyncRequest`] with its
+    /// current time.
+    TimeSyncResponse {
+        /// Number of the answered [`ClientMsg::TimeSyncRequest`].
+        num: u32,
+
+        /// Current time of Media Server (in milliseconds since UNIX epoch).
+        server_time_ms: u64,
+    },
 }
 
 /// Message by Web Client to Media Server.
@@ -362,6 +372,13 @@ pub enum ClientMsg {
         /// Actual [`Command`] sent to Media Server.
         command: Command,
     },
+
+    /// Request of Web Client for the current time of Media Server, used to
+    /// synchronize their clocks.
+    ///
+    /// Media Server is expected to answer with a
+    /// [`ServerMsg::TimeSyncResponse`] having the same number.
+    TimeSyncRequest(u32),
 }
 
 /// RPC settings of Web Client received from Media Server.
@@ -377,6 +394,13 @@ pub struct RpcSettings {
     ///
     /// Unit: millisecond.
     pub ping_interval_ms: u32,
+
+    /// Indicator whether Media Server answers [`ClientMsg::TimeSyncRequest`]s.
+    ///
+    /// Web Client never sends [`ClientMsg::TimeSyncRequest`]s unless this is
+    /// `true`.
+    #[serde(default)]
+    pub time_sync: bool,
 }
 
 /// Possible commands sent by Web Client to Media Server.
@@ -466,6 +490,43 @@ pub enum Command {
         tracks_patches: Vec<TrackPatchCommand>,
     },
 
+    /// Web Client asks Media Server to renegotiate the specified `Peer`.
+    /// Media Server starts the renegotiation by sending [`Event::PeerUpdated`]
+    /// with a [`NegotiationRole`].
+    RequestRenegotiation {
+        /// ID of the `Peer` to be renegotiated.
+        peer_id: PeerId,
+    },
+
+    /// Web Client asks Media Server to forward the specified
+    /// [`QualityLayer`] of the received [`Track`].
+    ///
+    /// Only makes sense for the [`Track`]s received from [SFU].
+    ///
+    /// [SFU]: https://webrtcglossary.com/sfu
+    RequestQualityLayer {
+        /// ID of the `Peer` the [`Track`] is received in.
+        peer_id: PeerId,
+
+        /// ID of the received [`Track`].
+        track_id: TrackId,
+
+        /// Desired [`QualityLayer`] of the [`Track`].
+        layer: QualityLayer,
+    },
+
+    /// Web Client asks Media Server to relay the provided application data to
+    /// all the other `Member`s of the `Room` with an
+    /// [`Event::ApplicationMessageReceived`].
+    ///
+    /// Media Server doesn't interpret the data in any way. It's intended for
+    /// small application state updates only (like a raised hand or a display
+    /// name change), so its size must not exceed 4 KiB.
+    SendApplicationMessage {
+        /// Application data to be relayed.
+        data: Vec<u8>,
+    },
+
     /// Web Client asks Media Server to synchronize Client State with a
     /// Server State.
     SynchronizeMe {
@@ -797,11 +858,32 @@ pub enum Event {
         quality_score: ConnectionQualityScore,
     },
 
+    /// Media Server notifies Web Client about [`IceServer`]s being updated
+    /// (e.g. on TURN credentials rotation), so all the `Peer`s in a `Room`
+    /// should use them from now on.
+    ///
+    /// Doesn't trigger ICE restart: the new [`IceServer`]s are used for the
+    /// ICE candidates gathered afterwards only.
+    IceServersUpdated {
+        /// New [`IceServer`]s to be used.
+        ice_servers: Vec<IceServer>,
+    },
+
     /// Media Server synchronizes Web Client state and reports the proper one.
     StateSynchronized {
         /// Proper state that should be assumed by Web Client.
         state: state::Room,
     },
+
+    /// Media Server relays application data sent by another `Member` with a
+    /// [`Command::SendApplicationMessage`].
+    ApplicationMessageReceived {
+        /// ID of the `Member` who sent the application data.
+        member_id: MemberId,
+
+        /// Relayed application data.
+        data: Vec<u8>,
+    },
 }
 
 /// `Peer`'s negotiation role.
@@ -917,7 +999,7 @@ impl Track {
 /// [`Command::UpdateTracks`].
 #[cfg_attr(feature = "client", derive(Serialize))]
 #[cfg_attr(feature = "server", derive(Deserialize))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TrackPatchCommand {
     /// ID of the [`Track`] this patch is intended for.
     pub id: TrackId,
@@ -930,6 +1012,33 @@ pub struct TrackPatchCommand {
     /// Muting and unmuting can be performed without adding/removing tracks
     /// from transceivers, hence renegotiation is not required.
     pub muted: Option<bool>,
+
+    /// Application-defined label of the [`Track`], allowing Media Server to
+    /// correlate it.
+    #[cfg_attr(
+        feature = "client",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    #[cfg_attr(feature = "server", serde(default))]
+    pub label: Option<String>,
+}
+
+/// Spatial and temporal layer of a [simulcast] or [SVC] encoded [`Track`],
+/// which Web Client can request with a [`Command::RequestQualityLayer`].
+///
+/// [simulcast]: https://webrtcglossary.com/simulcast
+/// [SVC]: https://webrtcglossary.com/svc
+#[cfg_attr(feature = "client", derive(Serialize))]
+#[cfg_attr(feature = "server", derive(Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct QualityLayer {
+    /// Index of the spatial layer, where `0` is the lowest resolution.
+    pub spatial: u8,
+
+    /// Index of the temporal layer, where `0` is the lowest frame rate.
+    ///
+    /// If [`None`], then Media Server is free to choose any temporal layer.
+    pub temporal: Option<u8>,
 }
 
 /// Patch of a [`Track`] which Media Server can send with an
@@ -964,6 +1073,12 @@ pub struct TrackPatchEvent {
 
     /// [`EncodingParameters`] for the [`Track`] which should be patched.
     pub encoding_parameters: Option<Vec<EncodingParameters>>,
+
+    /// Indicator whether forwarding of the [`Track`] is paused by Media
+    /// Server (e.g. due to bandwidth management).
+    ///
+    /// Unlike muting, pausing is not initiated by the [`Track`]'s sender.
+    pub paused: Option<bool>,
 }
 
 /// Media exchange direction of a `Track`.
@@ -1017,6 +1132,7 @@ impl From<TrackPatchCommand> for TrackPatchEvent {
             }),
             receivers: None,
             encoding_parameters: None,
+            paused: None,
         }
     }
 }
@@ -1031,6 +1147,7 @@ impl TrackPatchEvent {
             media_direction: None,
             receivers: None,
             encoding_parameters: None,
+            paused: None,
         }
     }
 
@@ -1058,6 +1175,10 @@ impl TrackPatchEvent {
         if let Some(encodings) = &another.encoding_parameters {
             self.encoding_parameters = Some(encodings.clone());
         }
+
+        if let Some(paused) = another.paused {
+            self.paused = Some(paused);
+        }
     }
 }
 
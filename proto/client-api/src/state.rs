@@ -113,6 +113,10 @@ pub struct Receiver {
     /// Indicator whether this [`Receiver`] is muted.
     pub muted: bool,
 
+    /// Indicator whether forwarding of media to this [`Receiver`] is paused by
+    /// Media Server.
+    pub paused: bool,
+
     /// Current general media exchange state of this [`Receiver`].
     pub media_direction: MediaDirection,
 }
@@ -692,7 +692,7 @@ pub struct RtcSctpTransportStats {
 /// [1]: https://w3.org/TR/webrtc-stats/#transportstats-dict%2A
 /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RtcTransportStats {
     /// Total number of packets sent over this transport.
@@ -719,6 +719,15 @@ pub struct RtcTransportStats {
     /// [1]: https://w3.org/TR/webrtc#dom-icetransport-role
     /// [2]: https://w3.org/TR/webrtc#dom-rtcdtlstransport-icetransport
     pub ice_role: Option<IceRole>,
+
+    /// Unique identifier that is associated to the object that was inspected
+    /// to produce the [`RtcIceCandidatePairStats`] associated with this
+    /// transport.
+    ///
+    /// [Full doc on W3C][1].
+    ///
+    /// [1]: https://w3.org/TR/webrtc-stats#dom-rtctransportstats-selectedcandidatepairid
+    pub selected_candidate_pair_id: Option<String>,
 }
 
 /// Variants of [ICE roles][1].
@@ -1145,6 +1154,12 @@ pub struct RtcInboundRtpStreamStats {
     /// ID of the stats object representing the receiving track.
     pub track_id: Option<String>,
 
+    /// [MID] of the [RTCRtpTransceiver] owning this stream.
+    ///
+    /// [MID]: https://w3.org/TR/webrtc#dom-rtptransceiver-mid
+    /// [RTCRtpTransceiver]: https://w3.org/TR/webrtc#dom-rtcrtptransceiver
+    pub mid: Option<String>,
+
     /// Fields which should be in the [`RtcStat`] based on its `kind`.
     #[serde(flatten)]
     pub media_specific_stats: RtcInboundRtpStreamMediaType,
@@ -0,0 +1,36 @@
+//! Application data relayed from a remote `Member`.
+
+use flutter_rust_bridge::frb;
+
+use crate::api::dart::api::ForeignClass;
+
+/// Application data sent by a remote `Member` with a
+/// [`RoomHandle::send_application_message`].
+///
+/// This struct is passed to a [`ConnectionHandle::on_application_message`]
+/// Dart side callback.
+///
+/// [`ConnectionHandle::on_application_message`]:
+///     super::ConnectionHandle::on_application_message
+/// [`RoomHandle::send_application_message`]:
+///     super::RoomHandle::send_application_message
+#[derive(Debug)]
+#[frb(opaque)]
+pub struct ApplicationMessage(Vec<u8>);
+
+impl From<Vec<u8>> for ApplicationMessage {
+    fn from(value: Vec<u8>) -> Self {
+        Self(value)
+    }
+}
+
+impl ForeignClass for ApplicationMessage {}
+
+impl ApplicationMessage {
+    /// Returns the received application data.
+    #[frb(sync)]
+    #[must_use]
+    pub fn data(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+}
@@ -1,13 +1,14 @@
 //! External handler to a [`Connection`] with a remote `Member`.
 
 use flutter_rust_bridge::{DartOpaque, frb};
+use medea_client_api_proto::QualityLayer;
 use send_wrapper::SendWrapper;
 use tracerr::Traced;
 
 use crate::{
     api::{Error as DartError, dart::api::ForeignClass},
     connection as core,
-    media::MediaSourceKind,
+    media::{MediaKind, MediaSourceKind},
     platform::{self, utils::dart_future::IntoDartFuture as _},
 };
 #[cfg(doc)]
@@ -79,6 +80,43 @@ impl ConnectionHandle {
             .map_err(Into::into)
     }
 
+    /// Sets a callback to be invoked once the inbound media quality of the
+    /// associated [`Connection`] switches between good (`true`) and poor
+    /// (`false`).
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::ConnectionHandle::on_inbound_quality_update()`] method
+    /// errors.
+    #[frb(sync)]
+    pub fn on_inbound_quality_update(
+        &self,
+        f: DartOpaque,
+    ) -> Result<(), DartOpaque> {
+        self.0
+            .on_inbound_quality_update(platform::Function::new(f))
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Sets a callback to be invoked once an application message is received
+    /// from the remote `Member` of the associated [`Connection`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::ConnectionHandle::on_application_message()`] method
+    /// errors.
+    #[frb(sync)]
+    pub fn on_application_message(
+        &self,
+        f: DartOpaque,
+    ) -> Result<(), DartOpaque> {
+        self.0
+            .on_application_message(platform::Function::new(f))
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
     /// Returns ID of remote `Member` ID of the associated [`Connection`].
     ///
     /// # Errors
@@ -92,6 +130,20 @@ impl ConnectionHandle {
             .map_err(Into::into)
     }
 
+    /// Indicates whether all the inbound media of the provided [`MediaKind`]
+    /// in the associated [`Connection`] is muted by the remote `Member`.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::ConnectionHandle::is_remote_muted()`] method errors.
+    #[frb(sync)]
+    pub fn is_remote_muted(&self, kind: MediaKind) -> Result<bool, DartOpaque> {
+        self.0
+            .is_remote_muted(kind)
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
     /// Enables inbound audio in the associated [`Connection`].
     #[frb(sync)]
     #[must_use]
@@ -163,4 +215,22 @@ impl ConnectionHandle {
         .into_dart_future()
         .into_dart_opaque()
     }
+
+    /// Requests the provided spatial and temporal layer of all the inbound
+    /// video in the associated [`Connection`] from a media server.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::ConnectionHandle::request_quality()`] method errors.
+    #[frb(sync)]
+    pub fn request_quality(
+        &self,
+        spatial: u8,
+        temporal: Option<u8>,
+    ) -> Result<(), DartOpaque> {
+        self.0
+            .request_quality(QualityLayer { spatial, temporal })
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
 }
@@ -1,7 +1,21 @@
-use flutter_rust_bridge::frb;
+use std::time::Duration;
+
+use flutter_rust_bridge::{DartOpaque, frb};
+use futures::{FutureExt as _, TryFutureExt as _};
 use send_wrapper::SendWrapper;
 
-use crate::{api, jason};
+use crate::{
+    api::{self, Error as DartError, api::ApiMediaStreamSettings},
+    jason,
+    platform::{
+        RpcCompression,
+        utils::{
+            dart_future::{FutureFromDart, IntoDartFuture as _},
+            handle::DartHandle,
+        },
+    },
+    rpc::TokenRefresher,
+};
 #[cfg(doc)]
 use crate::{
     api::{MediaManagerHandle, RoomHandle},
@@ -44,6 +58,69 @@ impl Jason {
         self.0.media_manager().into()
     }
 
+    /// Publishes the local media described by the provided
+    /// [`ApiMediaStreamSettings`] to the [WHIP] endpoint with the provided
+    /// `url`, bypassing the Medea signalling.
+    ///
+    /// The provided bearer `token` (if any) authorizes all the requests to the
+    /// endpoint.
+    ///
+    /// Returns a [`api::WhipClient`] once the publishing starts.
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    #[frb(sync)]
+    #[must_use]
+    pub fn jason_publish_whip(
+        &self,
+        url: String,
+        token: Option<String>,
+        settings: ApiMediaStreamSettings,
+    ) -> DartOpaque {
+        self.0
+            .publish_whip(url, token, settings.into())
+            .map_ok(api::WhipClient::from)
+            .into_dart_future()
+            .into_dart_opaque()
+    }
+
+    /// Creates a new [`api::WhepClient`] playing remote media from a [WHEP]
+    /// endpoint, bypassing the Medea signalling.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    #[frb(sync)]
+    #[must_use]
+    pub fn jason_whep_client(&self) -> api::WhepClient {
+        self.0.whep_client().into()
+    }
+
+    /// Sets the [`RpcCompression`] negotiated by the connections to media
+    /// servers established afterwards.
+    #[frb(sync)]
+    #[must_use]
+    pub fn jason_set_rpc_compression(&self, compression: RpcCompression) {
+        self.0.set_rpc_compression(compression);
+    }
+
+    /// Sets the callback invoked before each reconnection to a media server to
+    /// fetch a fresh authorization token.
+    ///
+    /// The callback should return a `Future` resolving into the token string.
+    /// If it fails, the reconnection attempt fails and is retried with a
+    /// backoff.
+    ///
+    /// Affects only the rooms initialized after this call.
+    #[frb(sync)]
+    pub fn jason_set_token_refresher(&self, cb: DartOpaque) {
+        let cb = unsafe {
+            DartHandle::new(
+                cb.into_inner().unwrap().create_dart_handle().cast(),
+            )
+        };
+        self.0.set_token_refresher(Some(TokenRefresher::new(move || {
+            unsafe { FutureFromDart::execute::<String>(cb.get()) }.boxed_local()
+        })));
+    }
+
     /// Closes the provided [`RoomHandle`].
     #[frb(sync)]
     #[must_use]
@@ -57,4 +134,25 @@ impl Jason {
     pub fn jason_dispose(self) {
         self.0.take().dispose();
     }
+
+    /// Gracefully disposes this [`Jason`].
+    ///
+    /// Leaves all the [`Room`]s waiting for a media server to acknowledge it
+    /// during the provided `timeout_ms`, stops all the local media tracks and
+    /// then disposes this [`Jason`] the same way as [`Jason::jason_dispose()`]
+    /// does.
+    #[frb(sync)]
+    #[must_use]
+    pub fn jason_dispose_async(self, timeout_ms: u32) -> DartOpaque {
+        let jason = self.0.take();
+
+        async move {
+            jason
+                .dispose_async(Duration::from_millis(u64::from(timeout_ms)))
+                .await;
+            Ok::<_, DartError>(())
+        }
+        .into_dart_future()
+        .into_dart_opaque()
+    }
 }
@@ -0,0 +1,47 @@
+//! Fallback of local media acquisition to the previous settings.
+
+use flutter_rust_bridge::frb;
+use send_wrapper::SendWrapper;
+
+use crate::{
+    api::dart::api::{ApiMediaStreamSettings, ForeignClass},
+    room as core,
+};
+
+/// Fallback of local media acquisition to the previous
+/// [`ApiMediaStreamSettings`], made because the requested ones couldn't be
+/// applied.
+///
+/// This struct is passed to a [`RoomHandle::on_local_media_fallback`] Dart side
+/// callback.
+///
+/// [`RoomHandle::on_local_media_fallback`]:
+///     super::RoomHandle::on_local_media_fallback
+#[derive(Debug)]
+#[frb(opaque)]
+pub struct LocalMediaFallback(SendWrapper<core::LocalMediaFallback>);
+
+impl From<core::LocalMediaFallback> for LocalMediaFallback {
+    fn from(value: core::LocalMediaFallback) -> Self {
+        Self(SendWrapper::new(value))
+    }
+}
+
+impl ForeignClass for LocalMediaFallback {}
+
+impl LocalMediaFallback {
+    /// Returns [`ApiMediaStreamSettings`] failed to be applied.
+    #[frb(sync)]
+    #[must_use]
+    pub fn attempted(&self) -> ApiMediaStreamSettings {
+        (&self.0.attempted()).into()
+    }
+
+    /// Returns [`ApiMediaStreamSettings`] applied instead of the attempted
+    /// ones.
+    #[frb(sync)]
+    #[must_use]
+    pub fn succeeded(&self) -> ApiMediaStreamSettings {
+        (&self.0.succeeded()).into()
+    }
+}
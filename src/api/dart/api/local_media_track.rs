@@ -24,7 +24,7 @@ use crate::{
 /// [2]: https://w3.org/TR/screen-capture#dom-mediadevices-getdisplaymedia
 #[derive(Debug)]
 #[frb(opaque)]
-pub struct LocalMediaTrack(SendWrapper<core::LocalMediaTrack>);
+pub struct LocalMediaTrack(pub(crate) SendWrapper<core::LocalMediaTrack>);
 
 impl From<core::LocalMediaTrack> for LocalMediaTrack {
     fn from(value: core::LocalMediaTrack) -> Self {
@@ -79,6 +79,24 @@ impl LocalMediaTrack {
             .into_dart_opaque()
     }
 
+    /// Sets whether video of this [`LocalMediaTrack`] is intended to be
+    /// mirrored (e.g. for a front camera self-view).
+    ///
+    /// Frames are not transformed, so the renderer is expected to apply
+    /// mirroring on its own.
+    #[frb(sync)]
+    pub fn set_mirror(&self, mirror: bool) {
+        self.0.set_mirror(mirror);
+    }
+
+    /// Indicates whether video of this [`LocalMediaTrack`] is intended to be
+    /// mirrored.
+    #[frb(sync)]
+    #[must_use]
+    pub fn is_mirrored(&self) -> bool {
+        self.0.is_mirrored()
+    }
+
     /// Indicates whether an `OnAudioLevelChangedCallback` is supported for this
     /// [`LocalMediaTrack`].
     #[frb(sync)]
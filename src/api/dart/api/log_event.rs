@@ -0,0 +1,55 @@
+//! Structured log event emitted by this library.
+
+use flutter_rust_bridge::frb;
+
+use crate::{api::dart::api::ForeignClass, utils::logging};
+
+/// Structured log event emitted by this library.
+///
+/// This struct is passed to a [`set_log_sink()`] Dart side callback.
+///
+/// [`set_log_sink()`]: super::set_log_sink
+#[derive(Debug)]
+#[frb(opaque)]
+pub struct LogEvent(logging::LogEvent);
+
+impl From<logging::LogEvent> for LogEvent {
+    fn from(value: logging::LogEvent) -> Self {
+        Self(value)
+    }
+}
+
+impl ForeignClass for LogEvent {}
+
+impl LogEvent {
+    /// Returns severity of this [`LogEvent`] (`error`, `warn`, `info`,
+    /// `debug` or `trace`).
+    #[frb(sync)]
+    #[must_use]
+    pub fn level(&self) -> String {
+        self.0.level.as_str().to_ascii_lowercase()
+    }
+
+    /// Returns ID of the `PeerConnection` this [`LogEvent`] relates to, if
+    /// any.
+    #[frb(sync)]
+    #[must_use]
+    pub fn peer_id(&self) -> Option<u32> {
+        self.0.peer_id.map(|id| id.0)
+    }
+
+    /// Returns category of this [`LogEvent`] (`ice`, `media`, `negotiation`,
+    /// `signalling` or `stats`).
+    #[frb(sync)]
+    #[must_use]
+    pub fn category(&self) -> String {
+        self.0.category.to_string()
+    }
+
+    /// Returns human-readable message of this [`LogEvent`].
+    #[frb(sync)]
+    #[must_use]
+    pub fn message(&self) -> String {
+        self.0.message.clone()
+    }
+}
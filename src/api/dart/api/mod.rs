@@ -14,6 +14,7 @@
     clippy::missing_docs_in_private_items,
     clippy::redundant_else,
     clippy::significant_drop_tightening,
+    clippy::too_many_lines,
     clippy::undocumented_unsafe_blocks,
     clippy::unused_trait_names,
     clippy::use_self,
@@ -26,14 +27,21 @@
 #[rustfmt::skip]
 mod api_bridge_generated;
 
+pub mod application_message;
 pub mod connection_handle;
 pub mod jason;
+pub mod local_media_fallback;
 pub mod local_media_track;
+pub mod log_event;
 pub mod media_manager;
 pub mod reconnect_handle;
+pub mod recorder;
 pub mod remote_media_track;
 pub mod room;
 pub mod room_close_reason;
+pub mod room_lifecycle_event;
+pub mod whep_client;
+pub mod whip_client;
 
 use std::{ptr, sync::LazyLock};
 
@@ -43,10 +51,21 @@ use flutter_rust_bridge::{
 };
 
 pub use self::{
-    connection_handle::ConnectionHandle, jason::Jason,
-    local_media_track::LocalMediaTrack, media_manager::MediaManagerHandle,
-    reconnect_handle::ReconnectHandle, remote_media_track::RemoteMediaTrack,
-    room::RoomHandle, room_close_reason::RoomCloseReason,
+    application_message::ApplicationMessage,
+    connection_handle::ConnectionHandle,
+    jason::Jason,
+    local_media_fallback::LocalMediaFallback,
+    local_media_track::LocalMediaTrack,
+    log_event::LogEvent,
+    media_manager::MediaManagerHandle,
+    reconnect_handle::ReconnectHandle,
+    recorder::Recorder,
+    remote_media_track::RemoteMediaTrack,
+    room::RoomHandle,
+    room_close_reason::RoomCloseReason,
+    room_lifecycle_event::{RoomLifecycleEvent, RoomLifecycleEventKind},
+    whep_client::WhepClient,
+    whip_client::WhipClient,
 };
 use crate::{
     api::{
@@ -56,10 +75,12 @@ use crate::{
     },
     media::{
         self, MediaDeviceKind, NoiseSuppressionLevel,
-        constraints::{ConstrainBoolean, ConstrainString, ConstrainU32},
+        constraints::{
+            ConstrainBoolean, ConstrainString, ConstrainU32, ConstrainU32Range,
+        },
     },
     platform::{self},
-    utils::str_eq,
+    utils::{logging, str_eq},
 };
 
 // Must be named `FLUTTER_RUST_BRIDGE_HANDLER` for `flutter_rust_bridge` to
@@ -194,6 +215,7 @@ impl From<ApiAudioConstraints> for media::AudioTrackConstraints {
             noise_suppression_level: v.noise_suppression_level,
             echo_cancellation: v.echo_cancellation,
             high_pass_filter: v.high_pass_filter,
+            channel_count: None,
         }
     }
 }
@@ -310,7 +332,9 @@ impl From<ApiDisplayVideoTrackConstraints>
             match height {
                 ConstrainU32::Exact(e) => res.exact_height(e),
                 ConstrainU32::Ideal(i) => res.ideal_height(i),
-                ConstrainU32::Range(..) => unreachable!(),
+                ConstrainU32::Range(min, max) => {
+                    res.height_range(Some(min), None, Some(max));
+                }
             }
         }
 
@@ -318,7 +342,9 @@ impl From<ApiDisplayVideoTrackConstraints>
             match width {
                 ConstrainU32::Exact(e) => res.exact_width(e),
                 ConstrainU32::Ideal(i) => res.ideal_width(i),
-                ConstrainU32::Range(..) => unreachable!(),
+                ConstrainU32::Range(min, max) => {
+                    res.width_range(Some(min), None, Some(max));
+                }
             }
         }
 
@@ -326,7 +352,9 @@ impl From<ApiDisplayVideoTrackConstraints>
             match frame_rate {
                 ConstrainU32::Exact(e) => res.exact_frame_rate(e),
                 ConstrainU32::Ideal(i) => res.ideal_frame_rate(i),
-                ConstrainU32::Range(..) => unreachable!(),
+                ConstrainU32::Range(min, max) => {
+                    res.frame_rate_range(Some(min), None, Some(max));
+                }
             }
         }
         res
@@ -374,6 +402,74 @@ impl From<ApiMediaStreamSettings> for media::MediaStreamSettings {
     }
 }
 
+impl From<&media::MediaStreamSettings> for ApiMediaStreamSettings {
+    fn from(value: &media::MediaStreamSettings) -> Self {
+        Self {
+            audio: value.is_audio_enabled().then(|| {
+                let audio = value.get_audio();
+                ApiAudioConstraints {
+                    device_id: audio.device_id.clone().map(constrain_string),
+                    auto_gain_control: audio.auto_gain_control,
+                    noise_suppression: audio.noise_suppression,
+                    noise_suppression_level: audio.noise_suppression_level,
+                    echo_cancellation: audio.echo_cancellation,
+                    high_pass_filter: audio.high_pass_filter,
+                }
+            }),
+            device_video: value
+                .get_device_video()
+                .filter(|_| value.is_device_video_enabled())
+                .map(|video| ApiDeviceVideoTrackConstraints {
+                    device_id: video.device_id.clone().map(constrain_string),
+                    facing_mode: video.facing_mode.map(|mode| match mode {
+                        ConstrainString::Exact(e) => {
+                            ApiConstrainFacingMode::Exact(e)
+                        }
+                        ConstrainString::Ideal(i) => {
+                            ApiConstrainFacingMode::Ideal(i)
+                        }
+                    }),
+                    height: video.height.and_then(constrain_u32),
+                    width: video.width.and_then(constrain_u32),
+                }),
+            display_video: value
+                .get_display_video()
+                .filter(|_| value.is_display_video_enabled())
+                .map(|video| ApiDisplayVideoTrackConstraints {
+                    device_id: video.device_id.clone().map(constrain_string),
+                    height: video.height.and_then(constrain_u32),
+                    width: video.width.and_then(constrain_u32),
+                    frame_rate: video.frame_rate.and_then(constrain_u32),
+                }),
+        }
+    }
+}
+
+/// Converts the provided [`ConstrainString`] into its inner value, as
+/// [`ApiMediaStreamSettings`] don't distinguish exact and ideal device IDs.
+fn constrain_string(value: ConstrainString<String>) -> String {
+    match value {
+        ConstrainString::Exact(v) | ConstrainString::Ideal(v) => v,
+    }
+}
+
+/// Converts the provided [`ConstrainU32Range`] into a [`ConstrainU32`], if it
+/// can be represented as one.
+const fn constrain_u32(value: ConstrainU32Range) -> Option<ConstrainU32> {
+    match value {
+        ConstrainU32Range { exact: Some(e), .. } => {
+            Some(ConstrainU32::Exact(e))
+        }
+        ConstrainU32Range { ideal: Some(i), .. } => {
+            Some(ConstrainU32::Ideal(i))
+        }
+        ConstrainU32Range { min: Some(min), max: Some(max), .. } => {
+            Some(ConstrainU32::Range(min, max))
+        }
+        ConstrainU32Range { .. } => None,
+    }
+}
+
 impl<T> ForeignClass for Vec<T> {}
 
 /// Returns the [`Vec<ApiMediaDeviceDetails>`] from the provided
@@ -410,6 +506,23 @@ pub fn on_panic(cb: DartOpaque) {
     platform::set_panic_callback(platform::Function::new(cb));
 }
 
+/// Sets the provided Dart callback as a sink receiving all the [`LogEvent`]s
+/// instead of the [`log`] facade.
+#[frb(sync)]
+#[must_use]
+pub fn set_log_sink(cb: DartOpaque) {
+    let f = platform::Function::<LogEvent>::new(cb);
+    logging::set_sink(Some(move |e: logging::LogEvent| f.call1(e.into())));
+}
+
+/// Removes the sink set via [`set_log_sink()`], so the [`log`] facade is used
+/// again.
+#[frb(sync)]
+#[must_use]
+pub fn reset_log_sink() {
+    logging::set_sink(None::<fn(logging::LogEvent)>);
+}
+
 /// Sets the provided [`DART_HANDLER_PORT`].
 #[frb(sync)]
 #[must_use]
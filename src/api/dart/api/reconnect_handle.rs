@@ -29,6 +29,24 @@ impl From<core::ReconnectHandle> for ReconnectHandle {
 impl ForeignClass for ReconnectHandle {}
 
 impl ReconnectHandle {
+    /// Returns the WebSocket close code the connection has been closed with by
+    /// a media server, if any.
+    #[frb(sync)]
+    #[must_use]
+    pub fn close_code(&self) -> Option<u16> {
+        self.0.close_code()
+    }
+
+    /// Returns the WebSocket close reason the connection has been closed with
+    /// by a media server, if any.
+    ///
+    /// Allows to decide whether to re-authorize or just to retry.
+    #[frb(sync)]
+    #[must_use]
+    pub fn close_reason(&self) -> Option<String> {
+        self.0.close_reason()
+    }
+
     /// Tries to reconnect a [`Room`] after the provided delay in milliseconds.
     ///
     /// If the [`Room`] is already reconnecting then new reconnection attempt
@@ -0,0 +1,130 @@
+//! Local recording of [`LocalMediaTrack`]s and [`RemoteMediaTrack`]s.
+
+use std::{rc::Rc, time::Duration};
+
+use dart_sys::Dart_Handle;
+use flutter_rust_bridge::{DartOpaque, frb};
+use futures::StreamExt as _;
+use send_wrapper::SendWrapper;
+
+use crate::{
+    api::{
+        Error as DartError,
+        dart::api::{LocalMediaTrack, RemoteMediaTrack},
+    },
+    media as core,
+    platform::{
+        self,
+        utils::{dart_future::IntoDartFuture as _, list::DartList},
+    },
+};
+
+/// Recorder composing the added [`LocalMediaTrack`]s and
+/// [`RemoteMediaTrack`]s into a single recording.
+///
+/// All the recorded tracks should be added before the first
+/// [`Recorder::start()`] call.
+#[derive(Debug)]
+#[frb(opaque)]
+pub struct Recorder(SendWrapper<Rc<core::Recorder>>);
+
+impl Recorder {
+    /// Creates a new [`Recorder`] with the provided MIME type and target
+    /// bitrate (in bits per second) of the recording.
+    #[frb(sync)]
+    #[must_use]
+    pub fn new(
+        mime_type: Option<String>,
+        bits_per_second: Option<u32>,
+    ) -> Self {
+        Self(SendWrapper::new(Rc::new(core::Recorder::new(
+            platform::MediaRecorderOptions { mime_type, bits_per_second },
+        ))))
+    }
+
+    /// Adds the provided [`LocalMediaTrack`] to be recorded.
+    #[frb(sync)]
+    pub fn add_local_track(&self, track: &LocalMediaTrack) {
+        self.0.add_local_track((*track.0).clone());
+    }
+
+    /// Adds the provided [`RemoteMediaTrack`] to be recorded.
+    #[frb(sync)]
+    pub fn add_remote_track(&self, track: &RemoteMediaTrack) {
+        self.0.add_remote_track((*track.0).clone());
+    }
+
+    /// Starts recording.
+    ///
+    /// If a `timeslice_ms` is provided, then the recorded data is emitted into
+    /// the [`Recorder::on_data()`] callback in chunks of the provided
+    /// duration, otherwise it's emitted as a single chunk once the recording
+    /// is stopped.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::Recorder::start()`] method errors.
+    #[frb(sync)]
+    pub fn start(&self, timeslice_ms: Option<u32>) -> Result<(), DartOpaque> {
+        self.0
+            .start(timeslice_ms.map(|ms| Duration::from_millis(ms.into())))
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Pauses recording.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::Recorder::pause()`] method errors.
+    #[frb(sync)]
+    pub fn pause(&self) -> Result<(), DartOpaque> {
+        self.0.pause().map_err(DartError::from).map_err(Into::into)
+    }
+
+    /// Resumes paused recording.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::Recorder::resume()`] method errors.
+    #[frb(sync)]
+    pub fn resume(&self) -> Result<(), DartOpaque> {
+        self.0.resume().map_err(DartError::from).map_err(Into::into)
+    }
+
+    /// Stops recording.
+    ///
+    /// The returned [`Future`] resolves once all the recorded data is emitted
+    /// into the [`Recorder::on_data()`] callback, or right away if recording
+    /// is not started or is stopped already.
+    #[frb(sync)]
+    #[must_use]
+    pub fn stop(&self) -> DartOpaque {
+        let this = Rc::clone(&self.0);
+
+        async move {
+            this.stop().await.map_err(DartError::from)?;
+            Ok::<_, DartError>(())
+        }
+        .into_dart_future()
+        .into_dart_opaque()
+    }
+
+    /// Sets callback, invoked with a `List<int>` of each recorded data chunk.
+    ///
+    /// Concatenated in order, the chunks form the whole recording.
+    #[frb(sync)]
+    pub fn on_data(&self, f: DartOpaque) {
+        let f = platform::Function::<Dart_Handle>::new(f);
+        let mut data = self.0.on_data();
+        platform::spawn(async move {
+            while let Some(chunk) = data.next().await {
+                let mut list = DartList::new();
+                for byte in chunk {
+                    list.add(byte.into());
+                }
+                f.call1(list.handle());
+            }
+        });
+    }
+}
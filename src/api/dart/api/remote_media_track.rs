@@ -16,7 +16,7 @@ use crate::{
 /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack
 #[derive(Debug)]
 #[frb(opaque)]
-pub struct RemoteMediaTrack(SendWrapper<core::Track>);
+pub struct RemoteMediaTrack(pub(crate) SendWrapper<core::Track>);
 
 impl From<core::Track> for RemoteMediaTrack {
     fn from(value: core::Track) -> Self {
@@ -56,6 +56,20 @@ impl RemoteMediaTrack {
         self.0.on_unmuted(platform::Function::new(f));
     }
 
+    /// Sets callback to invoke once this [`RemoteMediaTrack`] is paused by a
+    /// media server.
+    #[frb(sync)]
+    pub fn on_paused(&self, f: DartOpaque) {
+        self.0.on_paused(platform::Function::new(f));
+    }
+
+    /// Sets callback to invoke once this [`RemoteMediaTrack`] is resumed by a
+    /// media server.
+    #[frb(sync)]
+    pub fn on_resumed(&self, f: DartOpaque) {
+        self.0.on_resumed(platform::Function::new(f));
+    }
+
     /// Sets callback to invoke once this [`RemoteMediaTrack`] is stopped.
     #[frb(sync)]
     #[must_use]
@@ -80,6 +94,14 @@ impl RemoteMediaTrack {
         self.0.muted()
     }
 
+    /// Indicates whether forwarding of this [`RemoteMediaTrack`] is paused by a
+    /// media server.
+    #[frb(sync)]
+    #[must_use]
+    pub fn paused(&self) -> bool {
+        self.0.paused()
+    }
+
     /// Returns this [`RemoteMediaTrack`]'s kind (audio/video).
     #[frb(sync)]
     #[must_use]
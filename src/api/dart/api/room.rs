@@ -1,12 +1,16 @@
 //! External handle to a [`Room`].
 
+use std::time::Duration;
+
 use flutter_rust_bridge::{DartOpaque, frb};
+use futures::StreamExt as _;
 use send_wrapper::SendWrapper;
 use tracerr::Traced;
 
 use crate::{
-    api::{Error as DartError, api::ApiMediaStreamSettings},
+    api::{self, Error as DartError, api::ApiMediaStreamSettings},
     media::MediaSourceKind,
+    peer::media::sender::{AdaptiveBitrateConfig, LossBasedResolutionConfig},
     platform::{self, utils::dart_future::IntoDartFuture as _},
     room as core,
 };
@@ -44,6 +48,25 @@ impl RoomHandle {
         .into_dart_opaque()
     }
 
+    /// Gracefully leaves this [`Room`].
+    ///
+    /// Sends the final stats to a media server, asks it to remove the
+    /// `Member` from this [`Room`] and waits for its acknowledgement during
+    /// the provided `timeout_ms`. Then closes this [`Room`] regardless of
+    /// whether the acknowledgement has been received.
+    #[frb(sync)]
+    #[must_use]
+    pub fn leave(&self, timeout_ms: u32) -> DartOpaque {
+        let room_handle = self.0.clone();
+
+        async move {
+            room_handle.leave(Duration::from_millis(timeout_ms.into())).await?;
+            Ok::<_, Traced<core::HandleDetachedError>>(())
+        }
+        .into_dart_future()
+        .into_dart_opaque()
+    }
+
     /// Updates this [`Room`]'s [`ApiMediaStreamSettings`].
     ///
     /// This affects all the [`PeerConnection`]s in this [`Room`]. If
@@ -400,6 +423,40 @@ impl RoomHandle {
             .map_err(Into::into)
     }
 
+    /// Sets a callback to be invoked on each [`RoomLifecycleEvent`] of the
+    /// [`Room`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandle::on_lifecycle()`] method errors.
+    ///
+    /// [`RoomLifecycleEvent`]: api::RoomLifecycleEvent
+    #[frb(sync)]
+    pub fn on_lifecycle(&self, cb: DartOpaque) -> Result<(), DartOpaque> {
+        let f = platform::Function::<api::RoomLifecycleEvent>::new(cb);
+        let mut events = self
+            .0
+            .on_lifecycle()
+            .map_err(DartError::from)
+            .map_err(DartOpaque::from)?;
+        platform::spawn(async move {
+            while let Some(event) = events.next().await {
+                f.call1(event.into());
+            }
+        });
+        Ok(())
+    }
+
+    /// Returns the current [`core::RoomState`] of the [`Room`].
+    ///
+    /// Never fails, returning [`core::RoomState::Closed`] if the [`Room`] has
+    /// been closed already.
+    #[frb(sync)]
+    #[must_use]
+    pub fn state(&self) -> core::RoomState {
+        self.0.state()
+    }
+
     /// Sets a callback to be invoked on local media acquisition failures.
     ///
     /// # Errors
@@ -415,4 +472,268 @@ impl RoomHandle {
             .map_err(DartError::from)
             .map_err(Into::into)
     }
+
+    /// Sets a callback to be invoked when local media settings update fails
+    /// and the previous settings are restored instead.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandle::on_local_media_fallback()`] method errors.
+    #[frb(sync)]
+    pub fn on_local_media_fallback(
+        &self,
+        cb: DartOpaque,
+    ) -> Result<(), DartOpaque> {
+        self.0
+            .on_local_media_fallback(platform::Function::new(cb))
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Enables loss-based resolution downgrade of the video sent in this
+    /// [`Room`].
+    ///
+    /// Once the fraction of lost packets (in `0.0..=1.0` range) stays above
+    /// the `high_fraction_lost` for `sustain_ms` milliseconds, the
+    /// [scaleResolutionDownBy][1] factor of each video sender is increased by
+    /// the `step` (up to the `max_scale`), and once it stays below the
+    /// `low_fraction_lost`, it's decreased back.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandle::enable_loss_based_resolution()`] method
+    /// errors.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    #[frb(sync)]
+    pub fn enable_loss_based_resolution(
+        &self,
+        high_fraction_lost: f64,
+        low_fraction_lost: f64,
+        sustain_ms: u32,
+        step: f64,
+        max_scale: f64,
+    ) -> Result<(), DartOpaque> {
+        #[expect( // no better way
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            reason = "no better way"
+        )]
+        let (step, max_scale) = (step as f32, max_scale as f32);
+
+        self.0
+            .enable_loss_based_resolution(LossBasedResolutionConfig {
+                high_fraction_lost,
+                low_fraction_lost,
+                sustain: Duration::from_millis(sustain_ms.into()),
+                step,
+                max_scale,
+            })
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Disables loss-based resolution downgrade enabled via
+    /// [`RoomHandle::enable_loss_based_resolution()`], restoring the original
+    /// resolution of the sent video.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandle::disable_loss_based_resolution()`] method
+    /// errors.
+    #[frb(sync)]
+    pub fn disable_loss_based_resolution(&self) -> Result<(), DartOpaque> {
+        self.0
+            .disable_loss_based_resolution()
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Enables adaptive bitrate of the video sent in this [`Room`].
+    ///
+    /// Once the round trip time exceeds the `high_rtt_ms` milliseconds or the
+    /// fraction of lost packets (in `0.0..=1.0` range) exceeds the
+    /// `high_fraction_lost`, the [maxBitrate][1] of each video sender is
+    /// decreased, and otherwise it's increased back, within the
+    /// `min_bitrate..=max_bitrate` range (in bits per second).
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandle::enable_adaptive_bitrate()`] method errors.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-maxbitrate
+    #[frb(sync)]
+    pub fn enable_adaptive_bitrate(
+        &self,
+        min_bitrate: u32,
+        max_bitrate: u32,
+        high_rtt_ms: u32,
+        high_fraction_lost: f64,
+    ) -> Result<(), DartOpaque> {
+        self.0
+            .enable_adaptive_bitrate(AdaptiveBitrateConfig {
+                min_bitrate,
+                max_bitrate,
+                high_rtt: Duration::from_millis(high_rtt_ms.into()),
+                high_fraction_lost,
+            })
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Disables adaptive bitrate enabled via
+    /// [`RoomHandle::enable_adaptive_bitrate()`], leaving the last applied
+    /// bitrate of the sent video as is.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandle::disable_adaptive_bitrate()`] method errors.
+    #[frb(sync)]
+    pub fn disable_adaptive_bitrate(&self) -> Result<(), DartOpaque> {
+        self.0
+            .disable_adaptive_bitrate()
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Sends the provided application `data` to all the other `Member`s of
+    /// the [`Room`], which receive it via a
+    /// [`ConnectionHandle::on_application_message()`] callback.
+    ///
+    /// The `data` must not exceed 4 KiB.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandle::send_application_message()`] method errors.
+    ///
+    /// [`ConnectionHandle::on_application_message()`]:
+    ///     super::ConnectionHandle::on_application_message
+    #[frb(sync)]
+    pub fn send_application_message(
+        &self,
+        data: Vec<u8>,
+    ) -> Result<(), DartOpaque> {
+        self.0
+            .send_application_message(data)
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Sets the period (in milliseconds) a peer connection may stay
+    /// disconnected before it's reported as such (including via
+    /// `Connection`s' quality scores).
+    ///
+    /// If the peer connection recovers within this period, no disconnect is
+    /// reported at all. `0` (the default) disables the grace period.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandle::set_disconnect_grace_period()`] method
+    /// errors.
+    #[frb(sync)]
+    pub fn set_disconnect_grace_period(
+        &self,
+        period_ms: u32,
+    ) -> Result<(), DartOpaque> {
+        self.0
+            .set_disconnect_grace_period(Duration::from_millis(u64::from(
+                period_ms,
+            )))
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Enables or disables sending `RTCStats` to the media server by all the
+    /// current and future `PeerConnection`s of this [`Room`].
+    ///
+    /// `RTCStats` are still scraped while sending is disabled, so local stats
+    /// APIs keep working.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandle::set_stats_sending_enabled()`] method errors.
+    #[frb(sync)]
+    pub fn set_stats_sending_enabled(
+        &self,
+        enabled: bool,
+    ) -> Result<(), DartOpaque> {
+        self.0
+            .set_stats_sending_enabled(enabled)
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Enables or disables early media in this [`Room`].
+    ///
+    /// Once enabled, negotiations don't wait for local media acquisition
+    /// (e.g. while a media permission prompt is pending), so remote media is
+    /// received right away, and the local media is added once acquired.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandle::set_early_media()`] method errors.
+    #[frb(sync)]
+    pub fn set_early_media(&self, enabled: bool) -> Result<(), DartOpaque> {
+        self.0
+            .set_early_media(enabled)
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Sets the maximum resolution of the video sent in this [`Room`],
+    /// regardless of what is captured.
+    ///
+    /// Applied via [scaleResolutionDownBy][1] of each video sender, based on
+    /// its current capture resolution, and re-applied whenever the capture
+    /// resolution changes.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    #[frb(sync)]
+    #[must_use]
+    pub fn set_max_send_resolution(
+        &self,
+        width: u32,
+        height: u32,
+    ) -> DartOpaque {
+        let room_handle = self.0.clone();
+
+        async move {
+            room_handle.set_max_send_resolution(width, height).await?;
+
+            Ok::<_, Traced<core::HandleDetachedError>>(())
+        }
+        .into_dart_future()
+        .into_dart_opaque()
+    }
+
+    /// Removes the maximum resolution of the sent video set via
+    /// [`RoomHandle::set_max_send_resolution()`].
+    #[frb(sync)]
+    #[must_use]
+    pub fn reset_max_send_resolution(&self) -> DartOpaque {
+        let room_handle = self.0.clone();
+
+        async move {
+            room_handle.reset_max_send_resolution().await?;
+
+            Ok::<_, Traced<core::HandleDetachedError>>(())
+        }
+        .into_dart_future()
+        .into_dart_opaque()
+    }
+
+    /// Requests Media Server to renegotiate all the peer connections of the
+    /// [`Room`], so fresh SDP offers are generated.
+    ///
+    /// If a negotiation is in progress already, then the renegotiation is
+    /// queued, and all the calls made meanwhile are coalesced into a single
+    /// follow-up negotiation.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandle::renegotiate_all()`] method errors.
+    #[frb(sync)]
+    pub fn renegotiate(&self) -> Result<(), DartOpaque> {
+        self.0.renegotiate_all().map_err(DartError::from).map_err(Into::into)
+    }
 }
@@ -0,0 +1,59 @@
+//! Event of a [`Room`]'s connection lifecycle.
+
+#[cfg(doc)]
+use crate::room::Room;
+use crate::{
+    api::dart::api::{ForeignClass, RoomCloseReason},
+    room as core,
+};
+
+/// Kind of a [`RoomLifecycleEvent`].
+#[derive(Clone, Copy, Debug)]
+pub enum RoomLifecycleEventKind {
+    /// [`Room`] is connecting to a media server and joining it.
+    Joining,
+
+    /// [`Room`] has joined a media server.
+    Joined,
+
+    /// [`Room`] has lost its connection with a media server.
+    ConnectionLost,
+
+    /// [`Room`] is reconnecting to a media server after a
+    /// [`RoomLifecycleEventKind::ConnectionLost`].
+    Reconnecting,
+
+    /// [`Room`] has been closed.
+    Closed,
+}
+
+/// Event of a [`Room`]'s connection lifecycle.
+#[derive(Debug)]
+pub struct RoomLifecycleEvent {
+    /// Kind of this [`RoomLifecycleEvent`].
+    pub kind: RoomLifecycleEventKind,
+
+    /// Reason of the [`Room`] closing, if this is a
+    /// [`RoomLifecycleEventKind::Closed`] event.
+    pub close_reason: Option<RoomCloseReason>,
+}
+
+impl From<core::RoomLifecycleEvent> for RoomLifecycleEvent {
+    fn from(event: core::RoomLifecycleEvent) -> Self {
+        use RoomLifecycleEventKind as K;
+        use core::RoomLifecycleEvent as E;
+
+        let (kind, close_reason) = match event {
+            E::Joining => (K::Joining, None),
+            E::Joined => (K::Joined, None),
+            E::ConnectionLost => (K::ConnectionLost, None),
+            E::Reconnecting => (K::Reconnecting, None),
+            E::Closed(reason) => {
+                (K::Closed, Some(core::RoomCloseReason::new(reason).into()))
+            }
+        };
+        Self { kind, close_reason }
+    }
+}
+
+impl ForeignClass for RoomLifecycleEvent {}
@@ -0,0 +1,92 @@
+//! Client playing remote media from a [WHEP] endpoint.
+//!
+//! [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+
+use std::rc::Rc;
+
+use flutter_rust_bridge::{DartOpaque, frb};
+use send_wrapper::SendWrapper;
+
+#[cfg(doc)]
+use crate::api::ConnectionHandle;
+use crate::{
+    platform::{self, utils::dart_future::IntoDartFuture as _},
+    rpc as core,
+};
+
+/// Client playing remote media from a [WHEP] endpoint.
+///
+/// The received tracks are exposed via the [`ConnectionHandle`] passed to the
+/// [`WhepClient::on_new_connection()`] callback.
+///
+/// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+#[derive(Debug)]
+#[frb(opaque)]
+pub struct WhepClient(SendWrapper<Rc<core::WhepClient>>);
+
+impl From<core::WhepClient> for WhepClient {
+    fn from(value: core::WhepClient) -> Self {
+        Self(SendWrapper::new(Rc::new(value)))
+    }
+}
+
+impl WhepClient {
+    /// Sets a callback to be invoked once the remote media starts being
+    /// received, providing the [`ConnectionHandle`] its tracks are exposed
+    /// via.
+    ///
+    /// Should be set before calling [`WhepClient::play()`].
+    #[frb(sync)]
+    pub fn on_new_connection(&self, f: DartOpaque) {
+        self.0.on_new_connection(platform::Function::new(f));
+    }
+
+    /// Sets a callback to be invoked when the playing fails after the [WHEP]
+    /// session has been started.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    #[frb(sync)]
+    pub fn on_failure(&self, f: DartOpaque) {
+        self.0.on_failure(platform::Function::new(f));
+    }
+
+    /// Starts playing the remote media from the [WHEP] endpoint with the
+    /// provided `url`.
+    ///
+    /// The provided bearer `token` (if any) authorizes all the requests to the
+    /// endpoint.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    #[frb(sync)]
+    #[must_use]
+    pub fn play(&self, url: String, token: Option<String>) -> DartOpaque {
+        let client = Rc::clone(&self.0);
+
+        async move { client.play(url, token).await }
+            .into_dart_future()
+            .into_dart_opaque()
+    }
+
+    /// Returns URL of the [WHEP] session resource created by the endpoint, if
+    /// the playing has been started.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    #[frb(sync)]
+    #[must_use]
+    pub fn resource(&self) -> Option<String> {
+        self.0.resource()
+    }
+
+    /// Terminates the [WHEP] session, if started.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    #[frb(sync)]
+    #[must_use]
+    pub fn close(&self) -> DartOpaque {
+        let client = Rc::clone(&self.0);
+
+        async move { client.close().await }
+            .into_dart_future()
+            .into_dart_opaque()
+    }
+}
@@ -0,0 +1,62 @@
+//! Client publishing local media to a [WHIP] endpoint.
+//!
+//! [WHIP]: https://tools.ietf.org/html/rfc9725
+
+use std::rc::Rc;
+
+use flutter_rust_bridge::{DartOpaque, frb};
+use send_wrapper::SendWrapper;
+
+use crate::{
+    api::dart::api::ForeignClass,
+    platform::{self, utils::dart_future::IntoDartFuture as _},
+    rpc as core,
+};
+
+/// Client publishing local media to a [WHIP] endpoint.
+///
+/// [WHIP]: https://tools.ietf.org/html/rfc9725
+#[derive(Debug)]
+#[frb(opaque)]
+pub struct WhipClient(SendWrapper<Rc<core::WhipClient>>);
+
+impl From<core::WhipClient> for WhipClient {
+    fn from(value: core::WhipClient) -> Self {
+        Self(SendWrapper::new(Rc::new(value)))
+    }
+}
+
+impl ForeignClass for WhipClient {}
+
+impl WhipClient {
+    /// Returns URL of the [WHIP] session resource created by the endpoint.
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    #[frb(sync)]
+    #[must_use]
+    pub fn resource(&self) -> String {
+        self.0.resource().to_owned()
+    }
+
+    /// Sets a callback to be invoked when the publishing fails after the
+    /// [WHIP] session has been created.
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    #[frb(sync)]
+    pub fn on_failure(&self, f: DartOpaque) {
+        self.0.on_failure(platform::Function::new(f));
+    }
+
+    /// Terminates the [WHIP] session.
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    #[frb(sync)]
+    #[must_use]
+    pub fn close(&self) -> DartOpaque {
+        let client = Rc::clone(&self.0);
+
+        async move { client.close().await }
+            .into_dart_future()
+            .into_dart_opaque()
+    }
+}
@@ -36,8 +36,10 @@ use libc::c_char;
 
 pub use self::{
     api::{
-        ConnectionHandle, Jason, LocalMediaTrack, MediaManagerHandle,
-        ReconnectHandle, RemoteMediaTrack, RoomCloseReason, RoomHandle,
+        ApplicationMessage, ConnectionHandle, Jason, LocalMediaFallback,
+        LocalMediaTrack, MediaManagerHandle, ReconnectHandle, Recorder,
+        RemoteMediaTrack, RoomCloseReason, RoomHandle, RoomLifecycleEvent,
+        WhepClient, WhipClient,
     },
     err::DartError as Error,
 };
@@ -22,7 +22,10 @@ use crate::{
         sender::CreateError,
     },
     platform, room,
-    rpc::{ReconnectError, SessionError, rpc_session::ConnectionLostReason},
+    rpc::{
+        ReconnectError, SessionError, WhepError, WhipError,
+        rpc_session::ConnectionLostReason,
+    },
     utils::Caused as _,
 };
 
@@ -273,6 +276,12 @@ pub enum RpcClientExceptionKind {
 
     /// RPC session has been finished. This is a terminal state.
     SessionFinished,
+
+    /// HTTP request to a WHIP/WHEP endpoint failed.
+    ///
+    /// This usually means that the endpoint is unreachable, so the request may
+    /// be retried later.
+    HttpRequestFailed,
 }
 
 /// Exceptions thrown from a RPC client that implements messaging with media
@@ -627,6 +636,24 @@ impl From<Traced<AudioProcessingError>> for Error {
     }
 }
 
+impl From<Traced<platform::MediaRecorderError>> for Error {
+    fn from(err: Traced<platform::MediaRecorderError>) -> Self {
+        use platform::MediaRecorderError as E;
+
+        let (err, stacktrace) = err.split();
+        let message = err.to_string();
+        match err {
+            E::CreateFailed(cause) => {
+                InternalException::new(message, Some(cause), stacktrace).into()
+            }
+            E::MultipleVideoTracks => FormatException::new(message).into(),
+            E::Unsupported | E::NotStarted | E::InvalidState(_) => {
+                StateError::new(message, stacktrace).into()
+            }
+        }
+    }
+}
+
 impl From<Traced<InitLocalTracksError>> for Error {
     fn from(err: Traced<InitLocalTracksError>) -> Self {
         use GetDisplayMediaError as Gdm;
@@ -696,6 +723,10 @@ impl From<Traced<SessionError>> for Error {
                 None
             }
             SE::AuthorizationFailed => Some(Kind::AuthorizationFailed),
+            SE::TokenRefreshFailed(e) => {
+                cause = Some(e);
+                Some(Kind::AuthorizationFailed)
+            }
             SE::ConnectionLost(reason) => {
                 if let Reason::ConnectError(e) = reason {
                     cause = e.into_inner().cause();
@@ -712,6 +743,65 @@ impl From<Traced<SessionError>> for Error {
     }
 }
 
+impl From<Traced<WhipError>> for Error {
+    fn from(err: Traced<WhipError>) -> Self {
+        use RpcClientExceptionKind as Kind;
+
+        let (err, trace) = err.split();
+        let message = err.to_string();
+
+        let kind = match &err {
+            WhipError::Http(_) => Some(Kind::HttpRequestFailed),
+            WhipError::UnexpectedStatus(401 | 403) => {
+                Some(Kind::AuthorizationFailed)
+            }
+            WhipError::ConnectionFailed => Some(Kind::ConnectionLost),
+            WhipError::PeerConnection(_)
+            | WhipError::UnexpectedStatus(_)
+            | WhipError::NoLocation
+            | WhipError::NoOffer => None,
+        };
+        let cause = err.cause();
+
+        if let Some(rpc_kind) = kind {
+            RpcClientException::new(rpc_kind, message, cause, trace).into()
+        } else {
+            InternalException::new(message, cause, trace).into()
+        }
+    }
+}
+
+impl From<Traced<WhepError>> for Error {
+    fn from(err: Traced<WhepError>) -> Self {
+        use RpcClientExceptionKind as Kind;
+
+        let (err, trace) = err.split();
+        let message = err.to_string();
+
+        let kind = match &err {
+            WhepError::Http(_) => Some(Kind::HttpRequestFailed),
+            WhepError::UnexpectedStatus(401 | 403) => {
+                Some(Kind::AuthorizationFailed)
+            }
+            WhepError::AlreadyStarted => {
+                return StateError::new(message, trace).into();
+            }
+            WhepError::ConnectionFailed => Some(Kind::ConnectionLost),
+            WhepError::PeerConnection(_)
+            | WhepError::UnexpectedStatus(_)
+            | WhepError::NoLocation
+            | WhepError::NoOffer => None,
+        };
+        let cause = err.cause();
+
+        if let Some(rpc_kind) = kind {
+            RpcClientException::new(rpc_kind, message, cause, trace).into()
+        } else {
+            InternalException::new(message, cause, trace).into()
+        }
+    }
+}
+
 impl From<Traced<room::RoomJoinError>> for Error {
     fn from(err: Traced<room::RoomJoinError>) -> Self {
         let (err, trace) = err.split();
@@ -732,6 +822,20 @@ impl From<Traced<room::RoomJoinError>> for Error {
     }
 }
 
+impl From<Traced<room::SendApplicationMessageError>> for Error {
+    fn from(err: Traced<room::SendApplicationMessageError>) -> Self {
+        let (err, trace) = err.split();
+        let message = err.to_string();
+
+        match err {
+            room::SendApplicationMessageError::Detached
+            | room::SendApplicationMessageError::TooLarge(_) => {
+                StateError::new(message, trace).into()
+            }
+        }
+    }
+}
+
 impl From<Traced<connection::ChangeMediaStateError>> for Error {
     fn from(err: Traced<connection::ChangeMediaStateError>) -> Self {
         let (err, trace) = err.split();
@@ -767,7 +871,8 @@ impl From<Traced<room::ChangeMediaStateError>> for Error {
         let message = err.to_string();
 
         match err {
-            room::ChangeMediaStateError::Detached => {
+            room::ChangeMediaStateError::Detached
+            | room::ChangeMediaStateError::LocalStreamUpdateCancelled => {
                 StateError::new(err.to_string(), trace).into()
             }
             room::ChangeMediaStateError::CouldNotGetLocalMedia(err) => {
@@ -829,11 +934,15 @@ impl From<Traced<LocalMediaError>> for Error {
                     Traced::compose(err, trace).into()
                 }
                 UE::InsertLocalTracksError(
-                    IE::InvalidMediaTrack | IE::NotEnoughTracks,
+                    IE::InvalidMediaTrack
+                    | IE::NotEnoughTracks
+                    | IE::NoSuchSender(_)
+                    | IE::CouldNotInsertLocalTrack(_),
                 ) => InternalException::new(message, None, trace).into(),
-                UE::InsertLocalTracksError(IE::CouldNotInsertLocalTrack(_)) => {
-                    InternalException::new(message, None, trace).into()
+                UE::InsertLocalTracksError(IE::UnsupportedCodec { .. }) => {
+                    StateError::new(message, trace).into()
                 }
+                UE::Cancelled => StateError::new(message, trace).into(),
                 UE::InvalidLocalTracks(_) => {
                     MediaStateTransitionException::new(
                         message,
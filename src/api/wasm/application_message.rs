@@ -0,0 +1,27 @@
+//! Application data relayed from a remote `Member`.
+
+use derive_more::with_trait::From;
+use wasm_bindgen::prelude::*;
+
+/// Application data sent by a remote `Member` with a
+/// [`RoomHandle::send_application_message`].
+///
+/// This struct is passed to a [`ConnectionHandle::on_application_message`] JS
+/// side callback.
+///
+/// [`ConnectionHandle::on_application_message`]:
+///     crate::api::ConnectionHandle::on_application_message
+/// [`RoomHandle::send_application_message`]:
+///     crate::api::RoomHandle::send_application_message
+#[wasm_bindgen]
+#[derive(Debug, From)]
+pub struct ApplicationMessage(Vec<u8>);
+
+#[wasm_bindgen]
+impl ApplicationMessage {
+    /// Returns the received application data.
+    #[must_use]
+    pub fn data(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+}
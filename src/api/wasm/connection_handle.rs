@@ -2,6 +2,7 @@
 
 use derive_more::with_trait::From;
 use js_sys::Promise;
+use medea_client_api_proto::QualityLayer;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::future_to_promise;
 
@@ -79,6 +80,29 @@ impl ConnectionHandle {
             .map_err(Into::into)
     }
 
+    /// Sets callback, invoked when an application message is received from
+    /// the remote `Member` of this [`Connection`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if an underlying object has been disposed, e.g.
+    /// `free` was called on this [`ConnectionHandle`], or on a [`Jason`], or on
+    /// a [`RoomHandle`] that implicitly owns native object behind this
+    /// [`ConnectionHandle`].
+    ///
+    /// [`Jason`]: api::Jason
+    /// [`RoomHandle`]: api::RoomHandle
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn on_application_message(
+        &self,
+        cb: js_sys::Function,
+    ) -> Result<(), JsValue> {
+        self.0
+            .on_application_message(cb.into())
+            .map_err(api::Error::from)
+            .map_err(Into::into)
+    }
+
     /// Sets callback, invoked when connection quality score is updated by a
     /// server.
     ///
@@ -102,6 +126,79 @@ impl ConnectionHandle {
             .map_err(Into::into)
     }
 
+    /// Sets callback, invoked when the inbound media quality of this
+    /// [`Connection`] switches between good (`true`) and poor (`false`).
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if an underlying object has been disposed, e.g.
+    /// `free` was called on this [`ConnectionHandle`], or on a [`Jason`], or on
+    /// a [`RoomHandle`] that implicitly owns native object behind this
+    /// [`ConnectionHandle`].
+    ///
+    /// [`Connection`]: connection::Connection
+    /// [`Jason`]: api::Jason
+    /// [`RoomHandle`]: api::RoomHandle
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn on_inbound_quality_update(
+        &self,
+        cb: js_sys::Function,
+    ) -> Result<(), JsValue> {
+        self.0
+            .on_inbound_quality_update(cb.into())
+            .map_err(api::Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Requests the specified spatial and temporal layer of all the inbound
+    /// video in this [`Connection`] from a media server.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if an underlying object has been disposed, e.g.
+    /// `free` was called on this [`ConnectionHandle`], or on a [`Jason`], or on
+    /// a [`RoomHandle`] that implicitly owns native object behind this
+    /// [`ConnectionHandle`].
+    ///
+    /// [`Connection`]: connection::Connection
+    /// [`Jason`]: api::Jason
+    /// [`RoomHandle`]: api::RoomHandle
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn request_quality(
+        &self,
+        spatial: u8,
+        temporal: Option<u8>,
+    ) -> Result<(), JsValue> {
+        self.0
+            .request_quality(QualityLayer { spatial, temporal })
+            .map_err(api::Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Indicates whether all the inbound media of the provided [`MediaKind`]
+    /// is muted by the remote `Member`.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if an underlying object has been disposed, e.g.
+    /// `free` was called on this [`ConnectionHandle`], or on a [`Jason`], or on
+    /// a [`RoomHandle`] that implicitly owns native object behind this
+    /// [`ConnectionHandle`].
+    ///
+    /// [`Jason`]: api::Jason
+    /// [`MediaKind`]: api::MediaKind
+    /// [`RoomHandle`]: api::RoomHandle
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn is_remote_muted(
+        &self,
+        kind: api::MediaKind,
+    ) -> Result<bool, JsValue> {
+        self.0
+            .is_remote_muted(kind.into())
+            .map_err(api::Error::from)
+            .map_err(Into::into)
+    }
+
     /// Enables inbound audio in this [`ConnectionHandle`].
     ///
     /// # Errors
@@ -2,19 +2,29 @@
 //! functions.
 
 use derive_more::with_trait::{From, Into};
+use js_sys::{Array, Reflect};
 use wasm_bindgen::{
     convert::{FromWasmAbi, IntoWasmAbi},
     describe::WasmDescribe,
     prelude::*,
 };
 
-use crate::api::err::{
-    EnumerateDevicesException, FormatException, InternalException,
-    InvalidOutputAudioDeviceIdException, LocalMediaInitException,
-    MediaSettingsUpdateException, MediaStateTransitionException,
-    MicVolumeException, RpcClientException, StateError,
+use crate::{
+    api::err::{
+        EnumerateDevicesException, FormatException, InternalException,
+        InvalidOutputAudioDeviceIdException, LocalMediaInitException,
+        LocalMediaInitExceptionKind, MediaSettingsUpdateException,
+        MediaStateTransitionException, MediaStateTransitionExceptionKind,
+        MicVolumeException, RpcClientException, RpcClientExceptionKind,
+        StateError,
+    },
+    platform,
 };
 
+/// Maximum depth of a cause chain exposed via the `causes` property of an
+/// exception, protecting from cyclic JS `cause`s.
+const MAX_CAUSES: usize = 16;
+
 /// Wrapper around [`JsValue`] which represents a JS error.
 #[derive(Debug, From, Into)]
 pub struct Error(JsValue);
@@ -46,24 +56,185 @@ impl FromWasmAbi for Error {
     }
 }
 
-/// Implements `From<T> for Error where T: Into<JsValue>` for specified `T`.
-macro_rules! impl_from_into_jsval_for_error {
+/// Machine-readable description of an exception thrown to JS.
+///
+/// Every exception is thrown with the `code` property containing its
+/// [`Exception::code()`], and the `causes` property containing an array of its
+/// causes, starting with the [`Exception::js_cause()`] and followed by the JS
+/// [`cause`][1]s of it. This allows JS consumers to `switch` on error codes
+/// rather than parsing messages.
+///
+/// [1]: https://tc39.es/ecma262#sec-installerrorcause
+trait Exception: Into<JsValue> {
+    /// Returns a stable code of this [`Exception`], being its class name
+    /// optionally followed by a dot and its kind (e.g.
+    /// `LocalMediaInitException.GetUserMediaFailed`).
+    fn code(&self) -> &'static str;
+
+    /// Returns the direct cause of this [`Exception`], if any.
+    fn js_cause(&self) -> Option<JsValue> {
+        None
+    }
+
+    /// Converts this [`Exception`] into a [`JsValue`] having the `code` and
+    /// `causes` properties set.
+    fn into_js(self) -> JsValue {
+        let code = self.code();
+        let causes = Array::new();
+        let mut cause = self.js_cause();
+        for _ in 0..MAX_CAUSES {
+            let Some(c) = cause.filter(|c| !c.is_undefined() && !c.is_null())
+            else {
+                break;
+            };
+            cause = Reflect::get(&c, &"cause".into()).ok();
+            _ = causes.push(&c);
+        }
+
+        let js: JsValue = self.into();
+        _ = Reflect::set(&js, &"code".into(), &code.into());
+        _ = Reflect::set(&js, &"causes".into(), &causes);
+        js
+    }
+}
+
+/// Converts the provided [`platform::Error`] into a [`JsValue`].
+fn platform_cause(err: platform::Error) -> JsValue {
+    js_sys::Error::from(err).into()
+}
+
+impl Exception for StateError {
+    fn code(&self) -> &'static str {
+        "StateError"
+    }
+}
+
+impl Exception for EnumerateDevicesException {
+    fn code(&self) -> &'static str {
+        "EnumerateDevicesException"
+    }
+
+    fn js_cause(&self) -> Option<JsValue> {
+        Some(platform_cause(self.cause()))
+    }
+}
+
+impl Exception for LocalMediaInitException {
+    fn code(&self) -> &'static str {
+        use LocalMediaInitExceptionKind as K;
+
+        match self.kind() {
+            K::GetUserMediaFailed => {
+                "LocalMediaInitException.GetUserMediaFailed"
+            }
+            K::GetUserMediaAudioFailed => {
+                "LocalMediaInitException.GetUserMediaAudioFailed"
+            }
+            K::GetUserMediaVideoFailed => {
+                "LocalMediaInitException.GetUserMediaVideoFailed"
+            }
+            K::GetDisplayMediaFailed => {
+                "LocalMediaInitException.GetDisplayMediaFailed"
+            }
+            K::LocalTrackIsEnded => "LocalMediaInitException.LocalTrackIsEnded",
+        }
+    }
+
+    fn js_cause(&self) -> Option<JsValue> {
+        self.cause().map(platform_cause)
+    }
+}
+
+impl Exception for RpcClientException {
+    fn code(&self) -> &'static str {
+        use RpcClientExceptionKind as K;
+
+        match self.kind() {
+            K::ConnectionLost => "RpcClientException.ConnectionLost",
+            K::AuthorizationFailed => "RpcClientException.AuthorizationFailed",
+            K::SessionFinished => "RpcClientException.SessionFinished",
+            K::HttpRequestFailed => "RpcClientException.HttpRequestFailed",
+        }
+    }
+
+    fn js_cause(&self) -> Option<JsValue> {
+        self.cause().map(platform_cause)
+    }
+}
+
+impl Exception for InternalException {
+    fn code(&self) -> &'static str {
+        "InternalException"
+    }
+
+    fn js_cause(&self) -> Option<JsValue> {
+        self.cause().map(platform_cause)
+    }
+}
+
+impl Exception for FormatException {
+    fn code(&self) -> &'static str {
+        "FormatException"
+    }
+}
+
+impl Exception for MediaStateTransitionException {
+    fn code(&self) -> &'static str {
+        use MediaStateTransitionExceptionKind as K;
+
+        match self.kind() {
+            K::OppositeState => "MediaStateTransitionException.OppositeState",
+            K::ProhibitedState => {
+                "MediaStateTransitionException.ProhibitedState"
+            }
+        }
+    }
+}
+
+impl Exception for MediaSettingsUpdateException {
+    fn code(&self) -> &'static str {
+        "MediaSettingsUpdateException"
+    }
+
+    fn js_cause(&self) -> Option<JsValue> {
+        Some(self.cause().into())
+    }
+}
+
+impl Exception for InvalidOutputAudioDeviceIdException {
+    fn code(&self) -> &'static str {
+        "InvalidOutputAudioDeviceIdException"
+    }
+}
+
+impl Exception for MicVolumeException {
+    fn code(&self) -> &'static str {
+        "MicVolumeException"
+    }
+
+    fn js_cause(&self) -> Option<JsValue> {
+        Some(platform_cause(self.cause()))
+    }
+}
+
+/// Implements `From<T> for Error where T: Exception` for specified `T`.
+macro_rules! impl_from_exception_for_error {
     ($arg:ty) => {
         impl From<$arg> for Error {
             fn from(err: $arg) -> Self {
-                Error(err.into())
+                Error(err.into_js())
             }
         }
     };
 }
 
-impl_from_into_jsval_for_error!(StateError);
-impl_from_into_jsval_for_error!(EnumerateDevicesException);
-impl_from_into_jsval_for_error!(LocalMediaInitException);
-impl_from_into_jsval_for_error!(RpcClientException);
-impl_from_into_jsval_for_error!(InternalException);
-impl_from_into_jsval_for_error!(FormatException);
-impl_from_into_jsval_for_error!(MediaStateTransitionException);
-impl_from_into_jsval_for_error!(MediaSettingsUpdateException);
-impl_from_into_jsval_for_error!(InvalidOutputAudioDeviceIdException);
-impl_from_into_jsval_for_error!(MicVolumeException);
+impl_from_exception_for_error!(StateError);
+impl_from_exception_for_error!(EnumerateDevicesException);
+impl_from_exception_for_error!(LocalMediaInitException);
+impl_from_exception_for_error!(RpcClientException);
+impl_from_exception_for_error!(InternalException);
+impl_from_exception_for_error!(FormatException);
+impl_from_exception_for_error!(MediaStateTransitionException);
+impl_from_exception_for_error!(MediaSettingsUpdateException);
+impl_from_exception_for_error!(InvalidOutputAudioDeviceIdException);
+impl_from_exception_for_error!(MicVolumeException);
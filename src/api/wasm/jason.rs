@@ -1,11 +1,22 @@
 //! General JS side library interface.
 
+use std::time::Duration;
+
 use derive_more::with_trait::From;
+use futures::FutureExt as _;
+use js_sys::{Promise, Reflect};
+use medea_client_api_proto as proto;
 use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::{JsFuture, future_to_promise};
 
 use crate::{
-    api::{MediaManagerHandle, RoomHandle},
-    jason,
+    api::{
+        Error, LogEvent, MediaManagerHandle, MediaStreamSettings, RoomHandle,
+        WhepClient, WhipClient,
+    },
+    jason, media, platform,
+    rpc::TokenRefresher,
+    utils::logging,
 };
 
 /// General JS side library interface.
@@ -25,6 +36,54 @@ impl Jason {
         Self(jason::Jason::new(None))
     }
 
+    /// Instantiates a new [`Jason`] which uses tracks of the provided
+    /// application-owned [`web_sys::MediaStream`] as its local media, so it
+    /// doesn't make `getUserMedia()` requests for their kinds.
+    ///
+    /// These tracks are never stopped by [`Jason`]. Video tracks having a
+    /// [`displaySurface`][1] setting are considered as display ones, while
+    /// all the others are considered as device ones.
+    ///
+    /// [1]: https://w3.org/TR/screen-capture#dom-mediatracksettings-displaysurface
+    #[must_use]
+    #[wasm_bindgen(js_name = withMediaStream)]
+    pub fn with_media_stream(stream: &web_sys::MediaStream) -> Self {
+        let tracks = stream
+            .get_tracks()
+            .into_iter()
+            .map(|tr| {
+                let tr = web_sys::MediaStreamTrack::from(tr);
+                let is_display = Reflect::get(
+                    &tr.get_settings(),
+                    &JsValue::from_str("displaySurface"),
+                )
+                .is_ok_and(|surface| surface.is_string());
+                let source_kind = if is_display {
+                    media::MediaSourceKind::Display
+                } else {
+                    media::MediaSourceKind::Device
+                };
+                (
+                    platform::MediaStreamTrack::new(tr, Some(source_kind)),
+                    proto::MediaSourceKind::from(source_kind),
+                )
+            })
+            .collect();
+        Self(jason::Jason::with_external_tracks(None, tracks))
+    }
+
+    /// Sets the provided callback as a sink receiving all the [`LogEvent`]s
+    /// instead of the [`log`] facade.
+    ///
+    /// If `null` is provided, then the [`log`] facade is used again.
+    #[wasm_bindgen(js_name = setLogSink)]
+    pub fn set_log_sink(cb: Option<js_sys::Function>) {
+        logging::set_sink(cb.map(|cb| {
+            let f = platform::Function::<LogEvent>::from(cb);
+            move |e: logging::LogEvent| f.call1(e.into())
+        }));
+    }
+
     /// Creates a new `Room` and returns its [`RoomHandle`].
     #[must_use]
     pub fn init_room(&self) -> RoomHandle {
@@ -37,7 +96,84 @@ impl Jason {
         self.0.media_manager().into()
     }
 
-    /// Closes the provided [`RoomHandle`].
+    /// Sets the callback invoked before each reconnection to a media server to
+    /// fetch a fresh authorization token.
+    ///
+    /// The callback should return a `Promise` resolving into the token string.
+    /// If it rejects, the reconnection attempt fails and is retried with a
+    /// backoff.
+    ///
+    /// Affects only the rooms initialized after this call.
+    pub fn set_token_refresher(&self, cb: js_sys::Function) {
+        self.0.set_token_refresher(Some(TokenRefresher::new(move || {
+            let promise = cb.call0(&JsValue::NULL);
+            async move {
+                let token = JsFuture::from(js_sys::Promise::resolve(
+                    &promise.map_err(platform::Error::from)?,
+                ))
+                .await
+                .map_err(platform::Error::from)?;
+                token.as_string().ok_or_else(|| {
+                    platform::Error::from(JsValue::from_str(
+                        "Token refresher resolved into a non-string value",
+                    ))
+                })
+            }
+            .boxed_local()
+        })));
+    }
+
+    /// Publishes the local media described by the provided
+    /// [`MediaStreamSettings`] to the [WHIP] endpoint with the provided `url`,
+    /// bypassing the Medea signalling.
+    ///
+    /// The provided bearer `token` (if any) authorizes all the requests to the
+    /// endpoint.
+    ///
+    /// Returned [`Promise`] resolves into a [`WhipClient`] once the publishing
+    /// starts.
+    ///
+    /// # Errors
+    ///
+    /// With a [`RpcClientException`] if the endpoint is unreachable or rejects
+    /// the publishing.
+    ///
+    /// With an [`InternalException`] if the SDP negotiation fails.
+    ///
+    /// [`InternalException`]: crate::api::err::InternalException
+    /// [`RpcClientException`]: crate::api::err::RpcClientException
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    pub fn publish_whip(
+        &self,
+        url: String,
+        token: Option<String>,
+        settings: &MediaStreamSettings,
+    ) -> Promise {
+        let publish = self.0.publish_whip(url, token, settings.clone().into());
+
+        future_to_promise(async move {
+            let client = publish.await.map_err(Error::from)?;
+            Ok(WhipClient::from(client).into())
+        })
+    }
+
+    /// Creates a new [`WhepClient`] playing remote media from a [WHEP]
+    /// endpoint, bypassing the Medea signalling.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    #[must_use]
+    pub fn whep_client(&self) -> WhepClient {
+        self.0.whep_client().into()
+    }
+
+    /// Closes the [`Room`] of the provided [`RoomHandle`].
+    ///
+    /// All the clones of the provided [`RoomHandle`] can still be used
+    /// afterwards, failing with a [`StateError`]. Closing an already closed
+    /// [`Room`] is a no-op.
+    ///
+    /// [`Room`]: crate::room::Room
+    /// [`StateError`]: crate::api::err::StateError
     pub fn close_room(&self, room_to_delete: RoomHandle) {
         self.0.close_room(&room_to_delete.into());
     }
@@ -49,4 +185,20 @@ impl Jason {
     pub fn dispose(self) {
         self.0.dispose();
     }
+
+    /// Gracefully disposes this [`Jason`] API object.
+    ///
+    /// Leaves all the `Room`s waiting for a media server to acknowledge it
+    /// during the provided `timeout_ms`, stops all the local media tracks and
+    /// then disposes this [`Jason`] the same way as [`Jason::dispose`] does.
+    ///
+    /// Returned [`Promise`] resolves once all of this is done.
+    pub fn dispose_async(self, timeout_ms: u32) -> Promise {
+        future_to_promise(async move {
+            self.0
+                .dispose_async(Duration::from_millis(u64::from(timeout_ms)))
+                .await;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
 }
@@ -0,0 +1,34 @@
+//! Fallback of local media acquisition to the previous settings.
+
+use derive_more::with_trait::From;
+use wasm_bindgen::prelude::*;
+
+use crate::{api::MediaStreamSettings, room};
+
+/// Fallback of local media acquisition to the previous
+/// [`MediaStreamSettings`], made because the requested ones couldn't be
+/// applied.
+///
+/// This struct is passed to a [`RoomHandle::on_local_media_fallback`] JS side
+/// callback.
+///
+/// [`RoomHandle::on_local_media_fallback`]:
+///     crate::api::RoomHandle::on_local_media_fallback
+#[wasm_bindgen]
+#[derive(Debug, From)]
+pub struct LocalMediaFallback(room::LocalMediaFallback);
+
+#[wasm_bindgen]
+impl LocalMediaFallback {
+    /// Returns [`MediaStreamSettings`] failed to be applied.
+    #[must_use]
+    pub fn attempted(&self) -> MediaStreamSettings {
+        self.0.attempted().into()
+    }
+
+    /// Returns [`MediaStreamSettings`] applied instead of the attempted ones.
+    #[must_use]
+    pub fn succeeded(&self) -> MediaStreamSettings {
+        self.0.succeeded().into()
+    }
+}
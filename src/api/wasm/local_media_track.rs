@@ -2,13 +2,15 @@
 //!
 //! [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack
 
+use std::time::Duration;
+
 use derive_more::with_trait::From;
 use js_sys::Promise;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::future_to_promise;
 
 use crate::{
-    api::{self, MediaKind, MediaSourceKind},
+    api::{self, MediaKind, MediaSourceKind, MediaTrackSettings},
     media::track::local,
 };
 
@@ -20,7 +22,7 @@ use crate::{
 /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack
 #[wasm_bindgen]
 #[derive(Debug, From)]
-pub struct LocalMediaTrack(local::LocalMediaTrack);
+pub struct LocalMediaTrack(pub(crate) local::LocalMediaTrack);
 
 #[wasm_bindgen]
 impl LocalMediaTrack {
@@ -39,6 +41,30 @@ impl LocalMediaTrack {
         self.0.kind().into()
     }
 
+    /// Returns the current [`MediaTrackSettings`] of this [`LocalMediaTrack`],
+    /// reflecting the values actually applied by a user agent, which may
+    /// differ from the requested constraints.
+    #[must_use]
+    pub fn settings(&self) -> MediaTrackSettings {
+        self.0.settings().into()
+    }
+
+    /// Sets whether video of this [`LocalMediaTrack`] is intended to be
+    /// mirrored (e.g. for a front camera self-view).
+    ///
+    /// Frames are not transformed, so the renderer is expected to apply
+    /// mirroring on its own (e.g. via CSS `transform: scaleX(-1)`).
+    pub fn set_mirror(&self, mirror: bool) {
+        self.0.set_mirror(mirror);
+    }
+
+    /// Indicates whether video of this [`LocalMediaTrack`] is intended to be
+    /// mirrored.
+    #[must_use]
+    pub fn is_mirrored(&self) -> bool {
+        self.0.is_mirrored()
+    }
+
     /// Returns a [`MediaKind::Audio`] if this [`LocalMediaTrack`] represents an
     /// audio track, or a [`MediaKind::Video`] if it represents a video track.
     // TODO: Try remove on next Rust upgrade.
@@ -52,6 +78,40 @@ impl LocalMediaTrack {
         )
     }
 
+    /// Returns a [`Promise`] resolving once this [`LocalMediaTrack`] is ready
+    /// to be rendered, meaning it's live and actually producing media data.
+    ///
+    /// Resolves with the [MediaStreamTrackState][1] of this
+    /// [`LocalMediaTrack`] at that moment.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrackstate
+    // TODO: Try remove on next Rust upgrade.
+    #[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work")]
+    // TODO: Needs refactoring.
+    #[allow(clippy::as_conversions, reason = "needs refactoring")]
+    pub fn ready(&self) -> Promise {
+        let this = self.0.clone();
+        future_to_promise(
+            async move { Ok(JsValue::from(this.ready().await as u8)) },
+        )
+    }
+
+    /// Returns a [`Promise`] resolving to `true` once this [`LocalMediaTrack`]
+    /// is observed to actually produce media data (non-silent audio or
+    /// delivered video frames), or to `false` if nothing is observed during
+    /// the provided `timeout_ms`.
+    ///
+    /// Useful to detect a camera or a microphone occupied by another
+    /// application.
+    #[must_use]
+    pub fn is_producing(&self, timeout_ms: u32) -> Promise {
+        let this = self.0.clone();
+        future_to_promise(async move {
+            let timeout = Duration::from_millis(u64::from(timeout_ms));
+            Ok(JsValue::from(this.is_producing(timeout).await))
+        })
+    }
+
     /// Returns a [`MediaSourceKind::Device`] if this [`LocalMediaTrack`] is
     /// sourced from some device (webcam/microphone), or a
     /// [`MediaSourceKind::Display`] if it's captured via
@@ -0,0 +1,45 @@
+//! Structured log event emitted by this library.
+
+use derive_more::with_trait::From;
+use wasm_bindgen::prelude::*;
+
+use crate::utils::logging;
+
+/// Structured log event emitted by this library.
+///
+/// This struct is passed to a [`Jason::set_log_sink`] JS side callback.
+///
+/// [`Jason::set_log_sink`]: crate::api::Jason::set_log_sink
+#[wasm_bindgen]
+#[derive(Debug, From)]
+pub struct LogEvent(logging::LogEvent);
+
+#[wasm_bindgen]
+impl LogEvent {
+    /// Returns severity of this [`LogEvent`] (`error`, `warn`, `info`,
+    /// `debug` or `trace`).
+    #[must_use]
+    pub fn level(&self) -> String {
+        self.0.level.as_str().to_ascii_lowercase()
+    }
+
+    /// Returns ID of the `PeerConnection` this [`LogEvent`] relates to, if
+    /// any.
+    #[must_use]
+    pub fn peer_id(&self) -> Option<u32> {
+        self.0.peer_id.map(|id| id.0)
+    }
+
+    /// Returns category of this [`LogEvent`] (`ice`, `media`, `negotiation`,
+    /// `signalling` or `stats`).
+    #[must_use]
+    pub fn category(&self) -> String {
+        self.0.category.to_string()
+    }
+
+    /// Returns human-readable message of this [`LogEvent`].
+    #[must_use]
+    pub fn message(&self) -> String {
+        self.0.message.clone()
+    }
+}
@@ -108,6 +108,51 @@ impl MediaManagerHandle {
         })
     }
 
+    /// Pre-acquires media tracks built from the provided
+    /// [`MediaStreamSettings`] and holds them, so the following media
+    /// acquisitions (including the ones made by `Room`s) reuse them without
+    /// waiting for device permissions and warm-up.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if an underlying object has been disposed, e.g.
+    /// `free` was called on this [`MediaManagerHandle`], or on a [`Jason`] that
+    /// implicitly owns native object behind this [`MediaManagerHandle`].
+    ///
+    /// With a [`LocalMediaInitException`] if a request of platform media
+    /// devices access failed.
+    ///
+    /// [`Jason`]: crate::api::Jason
+    /// [`LocalMediaInitException`]: crate::api::err::LocalMediaInitException
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn prewarm(&self, caps: &MediaStreamSettings) -> Promise {
+        let this = self.0.clone();
+        let caps = caps.clone();
+
+        future_to_promise(async move {
+            this.prewarm(caps.into())
+                .await
+                .map(|()| JsValue::UNDEFINED)
+                .map_err(Error::from)
+                .map_err(Into::into)
+        })
+    }
+
+    /// Releases all the media tracks pre-warmed via the
+    /// [`MediaManagerHandle::prewarm()`] and not used yet, stopping them.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if an underlying object has been disposed, e.g.
+    /// `free` was called on this [`MediaManagerHandle`], or on a [`Jason`] that
+    /// implicitly owns native object behind this [`MediaManagerHandle`].
+    ///
+    /// [`Jason`]: crate::api::Jason
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn release_prewarmed(&self) -> Result<(), JsValue> {
+        self.0.release_prewarmed().map_err(Error::from).map_err(Into::into)
+    }
+
     /// Subscribes onto the [`MediaManagerHandle`]'s `devicechange` event.
     ///
     /// # Errors
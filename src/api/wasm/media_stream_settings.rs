@@ -114,6 +114,20 @@ impl AudioTrackConstraints {
     pub fn ideal_echo_cancellation(&mut self, aec: bool) {
         self.0.echo_cancellation = Some(ConstrainBoolean::Ideal(aec));
     }
+
+    /// Sets an exact [channelCount][1] constraint.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#def-constraint-channelCount
+    pub fn exact_channel_count(&mut self, count: u32) {
+        self.0.exact_channel_count(count);
+    }
+
+    /// Sets an ideal [channelCount][1] constraint.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#def-constraint-channelCount
+    pub fn ideal_channel_count(&mut self, count: u32) {
+        self.0.ideal_channel_count(count);
+    }
 }
 
 /// Constraints applicable to video tracks that are sourced from some media
@@ -200,6 +214,33 @@ impl DeviceVideoTrackConstraints {
     pub fn width_in_range(&mut self, min: u32, max: u32) {
         self.0.width_in_range(min, max);
     }
+
+    /// Sets an exact [frameRate][1] constraint.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
+    pub fn exact_frame_rate(&mut self, frame_rate: u32) {
+        self.0.exact_frame_rate(frame_rate);
+    }
+
+    /// Sets an ideal [frameRate][1] constraint.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
+    pub fn ideal_frame_rate(&mut self, frame_rate: u32) {
+        self.0.ideal_frame_rate(frame_rate);
+    }
+
+    /// Sets a [frameRate][1] constraint as a range of optional `min`, `ideal`
+    /// and `max` values.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
+    pub fn frame_rate_range(
+        &mut self,
+        min: Option<u32>,
+        ideal: Option<u32>,
+        max: Option<u32>,
+    ) {
+        self.0.frame_rate_range(min, ideal, max);
+    }
 }
 
 /// Constraints applicable to video tracks sourced from a screen capturing.
@@ -262,4 +303,17 @@ impl DisplayVideoTrackConstraints {
     pub fn ideal_frame_rate(&mut self, frame_rate: u32) {
         self.0.ideal_frame_rate(frame_rate);
     }
+
+    /// Sets a [frameRate][1] constraint as a range of optional `min`, `ideal`
+    /// and `max` values.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
+    pub fn frame_rate_range(
+        &mut self,
+        min: Option<u32>,
+        ideal: Option<u32>,
+        max: Option<u32>,
+    ) {
+        self.0.frame_rate_range(min, ideal, max);
+    }
 }
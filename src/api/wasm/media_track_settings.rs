@@ -0,0 +1,68 @@
+//! Settings of a [MediaStreamTrack][1].
+//!
+//! [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+
+use derive_more::with_trait::From;
+use wasm_bindgen::prelude::*;
+
+use crate::{api::FacingMode, media};
+
+/// Snapshot of [MediaTrackSettings][1] of a [MediaStreamTrack][2].
+///
+/// [1]: https://w3.org/TR/mediacapture-streams#dom-mediatracksettings
+/// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+#[wasm_bindgen]
+#[derive(Debug, From)]
+pub struct MediaTrackSettings(media::MediaTrackSettings);
+
+#[wasm_bindgen]
+impl MediaTrackSettings {
+    /// Returns [`deviceId`][1] of the source of a [MediaStreamTrack][2], if
+    /// any.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediatracksettings-deviceid
+    /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    #[must_use]
+    pub fn device_id(&self) -> Option<String> {
+        self.0.device_id.clone()
+    }
+
+    /// Returns [`width`][1] of a video [MediaStreamTrack][2] in pixels, if
+    /// any.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediatracksettings-width
+    /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    #[must_use]
+    pub fn width(&self) -> Option<u32> {
+        self.0.width
+    }
+
+    /// Returns [`height`][1] of a video [MediaStreamTrack][2] in pixels, if
+    /// any.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediatracksettings-height
+    /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    #[must_use]
+    pub fn height(&self) -> Option<u32> {
+        self.0.height
+    }
+
+    /// Returns [`frameRate`][1] of a video [MediaStreamTrack][2] in frames
+    /// per second, if any.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediatracksettings-framerate
+    /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    #[must_use]
+    pub fn frame_rate(&self) -> Option<f64> {
+        self.0.frame_rate
+    }
+
+    /// Returns [`facingMode`][1] of a video [MediaStreamTrack][2], if any.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediatracksettings-facingmode
+    /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    #[must_use]
+    pub fn facing_mode(&self) -> Option<FacingMode> {
+        self.0.facing_mode.map(Into::into)
+    }
+}
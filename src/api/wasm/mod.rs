@@ -3,38 +3,54 @@
 //!
 //! [`Jason`]: crate::api::Jason
 
+pub mod application_message;
 pub mod connection_handle;
 pub mod err;
 pub mod jason;
+pub mod local_media_fallback;
 pub mod local_media_track;
+pub mod log_event;
 pub mod media_device_details;
 pub mod media_manager_handle;
 pub mod media_stream_settings;
+pub mod media_track_settings;
 pub mod reconnect_handle;
+pub mod recorder;
 pub mod remote_media_track;
 pub mod room_close_reason;
 pub mod room_handle;
+pub mod room_lifecycle_event;
+pub mod whep_client;
+pub mod whip_client;
 
 use derive_more::with_trait::Display;
 use wasm_bindgen::prelude::*;
 
 pub use self::{
+    application_message::ApplicationMessage,
     connection_handle::ConnectionHandle,
     err::Error,
     jason::Jason,
+    local_media_fallback::LocalMediaFallback,
     local_media_track::LocalMediaTrack,
+    log_event::LogEvent,
     media_device_details::MediaDeviceDetails,
     media_manager_handle::MediaManagerHandle,
     media_stream_settings::{
         AudioTrackConstraints, DeviceVideoTrackConstraints,
         DisplayVideoTrackConstraints, MediaStreamSettings,
     },
+    media_track_settings::MediaTrackSettings,
     reconnect_handle::ReconnectHandle,
+    recorder::Recorder,
     remote_media_track::RemoteMediaTrack,
     room_close_reason::RoomCloseReason,
     room_handle::RoomHandle,
+    room_lifecycle_event::RoomLifecycleEvent,
+    whep_client::WhepClient,
+    whip_client::WhipClient,
 };
-use crate::media;
+use crate::{media, room};
 
 /// [MediaStreamTrack.kind][1] representation.
 ///
@@ -243,3 +259,97 @@ impl From<media::MediaDirection> for MediaDirection {
         }
     }
 }
+
+/// State of a [`Room`].
+///
+/// [`Room`]: room::Room
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum RoomState {
+    /// [`Room`] hasn't been joined yet.
+    ///
+    /// [`Room`]: room::Room
+    New,
+
+    /// [`Room`] is connecting to a media server and joining it.
+    ///
+    /// [`Room`]: room::Room
+    Connecting,
+
+    /// [`Room`] has joined a media server.
+    ///
+    /// [`Room`]: room::Room
+    Connected,
+
+    /// [`Room`] has lost its connection with a media server and is recovering
+    /// it.
+    ///
+    /// [`Room`]: room::Room
+    Reconnecting,
+
+    /// [`Room`] has been closed, so all the [`RoomHandle`] methods fail with a
+    /// [`StateError`].
+    ///
+    /// [`Room`]: room::Room
+    /// [`StateError`]: err::StateError
+    Closed,
+}
+
+impl From<room::RoomState> for RoomState {
+    fn from(that: room::RoomState) -> Self {
+        use room::RoomState as S;
+
+        match that {
+            S::New => Self::New,
+            S::Connecting => Self::Connecting,
+            S::Connected => Self::Connected,
+            S::Reconnecting => Self::Reconnecting,
+            S::Closed => Self::Closed,
+        }
+    }
+}
+
+/// Kind of a [`RoomLifecycleEvent`].
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum RoomLifecycleEventKind {
+    /// [`Room`] is connecting to a media server and joining it.
+    ///
+    /// [`Room`]: room::Room
+    Joining,
+
+    /// [`Room`] has joined a media server.
+    ///
+    /// [`Room`]: room::Room
+    Joined,
+
+    /// [`Room`] has lost its connection with a media server.
+    ///
+    /// [`Room`]: room::Room
+    ConnectionLost,
+
+    /// [`Room`] is reconnecting to a media server after a
+    /// [`RoomLifecycleEventKind::ConnectionLost`].
+    ///
+    /// [`Room`]: room::Room
+    Reconnecting,
+
+    /// [`Room`] has been closed.
+    ///
+    /// [`Room`]: room::Room
+    Closed,
+}
+
+impl From<room::RoomLifecycleEvent> for RoomLifecycleEventKind {
+    fn from(that: room::RoomLifecycleEvent) -> Self {
+        use room::RoomLifecycleEvent as E;
+
+        match that {
+            E::Joining => Self::Joining,
+            E::Joined => Self::Joined,
+            E::ConnectionLost => Self::ConnectionLost,
+            E::Reconnecting => Self::Reconnecting,
+            E::Closed(_) => Self::Closed,
+        }
+    }
+}
@@ -24,6 +24,22 @@ pub struct ReconnectHandle(rpc::ReconnectHandle);
 
 #[wasm_bindgen]
 impl ReconnectHandle {
+    /// Returns the WebSocket close code the connection has been closed with by
+    /// a media server, if any.
+    #[must_use]
+    pub fn close_code(&self) -> Option<u16> {
+        self.0.close_code()
+    }
+
+    /// Returns the WebSocket close reason the connection has been closed with
+    /// by a media server, if any.
+    ///
+    /// Allows to decide whether to re-authorize or just to retry.
+    #[must_use]
+    pub fn close_reason(&self) -> Option<String> {
+        self.0.close_reason()
+    }
+
     /// Tries to reconnect after the provided delay in milliseconds.
     ///
     /// If [`RpcSession`] is already reconnecting then a new reconnection
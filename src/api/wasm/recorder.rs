@@ -0,0 +1,136 @@
+//! Local recording of [`LocalMediaTrack`]s and [`RemoteMediaTrack`]s.
+
+use std::{rc::Rc, time::Duration};
+
+use futures::StreamExt as _;
+use js_sys::Promise;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::future_to_promise;
+
+use crate::{
+    api::{self, LocalMediaTrack, RemoteMediaTrack},
+    media, platform,
+};
+
+/// Recorder composing the added [`LocalMediaTrack`]s and
+/// [`RemoteMediaTrack`]s into a single recording via a [MediaRecorder][1].
+///
+/// All the recorded tracks should be added before the first
+/// [`Recorder::start()`] call.
+///
+/// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct Recorder(Rc<media::Recorder>);
+
+#[wasm_bindgen]
+impl Recorder {
+    /// Creates a new [`Recorder`] with the provided [MIME type][1] and target
+    /// bitrate (in bits per second) of the recording.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#dom-mediarecorderoptions-mimetype
+    #[wasm_bindgen(constructor)]
+    #[must_use]
+    pub fn new(
+        mime_type: Option<String>,
+        bits_per_second: Option<u32>,
+    ) -> Self {
+        Self(Rc::new(media::Recorder::new(platform::MediaRecorderOptions {
+            mime_type,
+            bits_per_second,
+        })))
+    }
+
+    /// Adds the provided [`LocalMediaTrack`] to be recorded.
+    pub fn add_local_track(&self, track: &LocalMediaTrack) {
+        self.0.add_local_track(track.0.clone());
+    }
+
+    /// Adds the provided [`RemoteMediaTrack`] to be recorded.
+    pub fn add_remote_track(&self, track: &RemoteMediaTrack) {
+        self.0.add_remote_track(track.clone().into());
+    }
+
+    /// Starts recording.
+    ///
+    /// If a `timeslice_ms` is provided, then the recorded data is emitted into
+    /// the [`Recorder::on_data()`] callback in chunks of the provided
+    /// duration, otherwise it's emitted as a single chunk once the recording
+    /// is stopped.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if recording is not supported or is started
+    /// already.
+    ///
+    /// With a [`FormatException`] if more than one video track has been
+    /// added.
+    ///
+    /// With an [`InternalException`] if the platform rejects the added tracks
+    /// or the provided options.
+    ///
+    /// [`FormatException`]: api::err::FormatException
+    /// [`InternalException`]: api::err::InternalException
+    /// [`StateError`]: api::err::StateError
+    pub fn start(&self, timeslice_ms: Option<u32>) -> Result<(), JsValue> {
+        self.0
+            .start(timeslice_ms.map(|ms| Duration::from_millis(ms.into())))
+            .map_err(api::Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Pauses recording.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if recording is not started or is stopped.
+    ///
+    /// [`StateError`]: api::err::StateError
+    pub fn pause(&self) -> Result<(), JsValue> {
+        self.0.pause().map_err(api::Error::from).map_err(Into::into)
+    }
+
+    /// Resumes paused recording.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if recording is not started or is stopped.
+    ///
+    /// [`StateError`]: api::err::StateError
+    pub fn resume(&self) -> Result<(), JsValue> {
+        self.0.resume().map_err(api::Error::from).map_err(Into::into)
+    }
+
+    /// Stops recording.
+    ///
+    /// The returned [`Promise`] resolves once all the recorded data is emitted
+    /// into the [`Recorder::on_data()`] callback, or right away if recording
+    /// is not started or is stopped already.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the platform fails to stop recording.
+    ///
+    /// [`StateError`]: api::err::StateError
+    pub fn stop(&self) -> Promise {
+        let this = Rc::clone(&self.0);
+        future_to_promise(async move {
+            this.stop().await.map_err(api::Error::from)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Sets callback, invoked with a `Uint8Array` of each recorded data
+    /// chunk.
+    ///
+    /// Concatenated in order, the chunks form the whole recording.
+    pub fn on_data(&self, cb: js_sys::Function) {
+        let cb = platform::Function::<Vec<u8>>::from(cb);
+        let mut data = self.0.on_data();
+        platform::spawn(async move {
+            while let Some(chunk) = data.next().await {
+                cb.call1(chunk);
+            }
+        });
+    }
+}
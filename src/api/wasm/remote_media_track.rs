@@ -27,6 +27,39 @@ impl RemoteMediaTrack {
         Clone::clone(self.0.get_track().as_ref())
     }
 
+    /// Attaches this [`RemoteMediaTrack`] to the provided `HTMLMediaElement`
+    /// for rendering, detaching it from the previously attached one (if any).
+    ///
+    /// Attaching and detaching don't stop this [`RemoteMediaTrack`], so its
+    /// media keeps being received, and its statistics and events keep being
+    /// reported, regardless of being attached or not.
+    ///
+    /// # Errors
+    ///
+    /// If a `MediaStream` cannot be created for this [`RemoteMediaTrack`].
+    pub fn attach(
+        &self,
+        element: web_sys::HtmlMediaElement,
+    ) -> Result<(), JsValue> {
+        self.0
+            .get_track()
+            .attach(element)
+            .map_err(|e| js_sys::Error::from(e).into())
+    }
+
+    /// Detaches this [`RemoteMediaTrack`] from the `HTMLMediaElement` it's
+    /// attached to (if any), without stopping it.
+    pub fn detach(&self) {
+        self.0.get_track().detach();
+    }
+
+    /// Indicates whether this [`RemoteMediaTrack`] is attached to any
+    /// `HTMLMediaElement`.
+    #[must_use]
+    pub fn is_attached(&self) -> bool {
+        self.0.get_track().is_attached()
+    }
+
     /// Indicates whether this [`RemoteMediaTrack`] is muted.
     #[must_use]
     pub fn muted(&self) -> bool {
@@ -43,6 +76,67 @@ impl RemoteMediaTrack {
         self.0.on_unmuted(cb.into());
     }
 
+    /// Indicates whether forwarding of this [`RemoteMediaTrack`] is paused by
+    /// a media server.
+    #[must_use]
+    pub fn paused(&self) -> bool {
+        self.0.paused()
+    }
+
+    /// Sets callback to invoke when this [`RemoteMediaTrack`] is paused by a
+    /// media server.
+    pub fn on_paused(&self, cb: js_sys::Function) {
+        self.0.on_paused(cb.into());
+    }
+
+    /// Sets callback to invoke when this [`RemoteMediaTrack`] is resumed by a
+    /// media server.
+    pub fn on_resumed(&self, cb: js_sys::Function) {
+        self.0.on_resumed(cb.into());
+    }
+
+    /// Indicates whether video of this [`RemoteMediaTrack`] is frozen, i.e. no
+    /// new frames are received for a while.
+    #[must_use]
+    pub fn frozen(&self) -> bool {
+        self.0.frozen()
+    }
+
+    /// Sets callback to invoke when video of this [`RemoteMediaTrack`]
+    /// freezes.
+    pub fn on_frozen(&self, cb: js_sys::Function) {
+        self.0.on_frozen(cb.into());
+    }
+
+    /// Returns playback volume of this [`RemoteMediaTrack`] in `0.0..=1.0`
+    /// range.
+    #[must_use]
+    pub fn volume(&self) -> f64 {
+        self.0.volume()
+    }
+
+    /// Sets playback volume of this [`RemoteMediaTrack`], clamping it into
+    /// `0.0..=1.0` range.
+    ///
+    /// The volume is applied to the `HTMLMediaElement` this
+    /// [`RemoteMediaTrack`] is attached to (if any). Other renderers (e.g. a
+    /// `GainNode`) should apply it in the `on_volume_changed` callback.
+    pub fn set_volume(&self, volume: f64) {
+        self.0.set_volume(volume);
+    }
+
+    /// Sets callback to invoke with the new volume when playback volume of
+    /// this [`RemoteMediaTrack`] is changed.
+    pub fn on_volume_changed(&self, cb: js_sys::Function) {
+        self.0.on_volume_changed(cb.into());
+    }
+
+    /// Sets callback to invoke when video of this [`RemoteMediaTrack`]
+    /// recovers from freezing.
+    pub fn on_unfrozen(&self, cb: js_sys::Function) {
+        self.0.on_unfrozen(cb.into());
+    }
+
     /// Sets callback to invoke when this [`RemoteMediaTrack`] is stopped.
     pub fn on_stopped(&self, cb: js_sys::Function) {
         self.0.on_stopped(cb.into());
@@ -73,6 +167,14 @@ impl RemoteMediaTrack {
         self.0.media_source_kind().into()
     }
 
+    /// Returns a [`MediaSourceKind`] of this [`RemoteMediaTrack`] signalled by
+    /// a media server, or `undefined` if it isn't signalled (which is the case
+    /// for audio tracks).
+    #[must_use]
+    pub fn source_kind(&self) -> Option<MediaSourceKind> {
+        self.0.source_kind().map(Into::into)
+    }
+
     /// Returns the current general [`MediaDirection`] of this
     /// [`RemoteMediaTrack`].
     #[must_use]
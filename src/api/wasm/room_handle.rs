@@ -2,29 +2,49 @@
 //!
 //! [`Room`]: room::Room
 
+use std::time::Duration;
+
 use derive_more::with_trait::{From, Into};
+use futures::StreamExt as _;
 use js_sys::Promise;
 use wasm_bindgen::{JsValue, prelude::*};
 use wasm_bindgen_futures::future_to_promise;
 
 use super::Error;
 use crate::{
-    api::{MediaSourceKind, MediaStreamSettings},
-    room,
+    api::{self, MediaSourceKind, MediaStreamSettings, RoomState},
+    peer::media::sender::{AdaptiveBitrateConfig, LossBasedResolutionConfig},
+    platform, room,
 };
 
 /// JS side handle to a [`Room`] where all the media happens.
 ///
 /// Like all handles it contains a weak reference to the object that is managed
 /// by Rust, so its methods will fail if a weak reference could not be upgraded.
+/// Thus, cloning a [`RoomHandle`] is cheap, and closing the [`Room`] via any of
+/// its clones detaches all the others.
 ///
 /// [`Room`]: room::Room
 #[wasm_bindgen]
-#[derive(Debug, From, Into)]
+#[derive(Clone, Debug, From, Into)]
 pub struct RoomHandle(room::RoomHandle);
 
 #[wasm_bindgen]
 impl RoomHandle {
+    /// Returns a new [`RoomHandle`] referring to the same [`Room`].
+    ///
+    /// Both [`RoomHandle`]s should be freed separately. Once the [`Room`] is
+    /// closed via any of them, methods of the others fail with a
+    /// [`StateError`].
+    ///
+    /// [`Room`]: room::Room
+    /// [`StateError`]: crate::api::err::StateError
+    #[wasm_bindgen(js_name = clone)]
+    #[must_use]
+    pub fn clone_handle(&self) -> Self {
+        self.clone()
+    }
+
     /// Connects to a media server and joins a [`Room`] with the provided
     /// authorization `token`.
     ///
@@ -59,6 +79,52 @@ impl RoomHandle {
         })
     }
 
+    /// Gracefully leaves a [`Room`].
+    ///
+    /// Sends the final stats to a media server, asks it to remove the `Member`
+    /// from the [`Room`] and waits for its acknowledgement during the provided
+    /// `timeout_ms`. Then closes the [`Room`] regardless of whether the
+    /// acknowledgement has been received.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`Room`]: room::Room
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn leave(&self, timeout_ms: u32) -> Promise {
+        let this = self.0.clone();
+
+        future_to_promise(async move {
+            let timeout = Duration::from_millis(u64::from(timeout_ms));
+            this.leave(timeout).await.map_err(Error::from)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Sends the provided application `data` to all the other `Member`s of a
+    /// [`Room`], which receive it via `ConnectionHandle.on_application_message`
+    /// callback.
+    ///
+    /// The `data` must not exceed 4 KiB.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed, or if
+    /// the provided `data` is too large.
+    ///
+    /// [`Room`]: room::Room
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn send_application_message(
+        &self,
+        data: Vec<u8>,
+    ) -> Result<(), JsValue> {
+        self.0
+            .send_application_message(data)
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
     /// Sets callback, invoked when a new [`Connection`] with some remote
     /// `Member` is established.
     ///
@@ -78,6 +144,100 @@ impl RoomHandle {
             .map_err(Into::into)
     }
 
+    /// Returns the current [`RoomState`] of this [`Room`].
+    ///
+    /// Never fails, returning [`RoomState::Closed`] if the [`Room`] has been
+    /// closed already.
+    ///
+    /// [`Room`]: room::Room
+    #[must_use]
+    pub fn state(&self) -> RoomState {
+        self.0.state().into()
+    }
+
+    /// Sets the period (in milliseconds) a peer connection may stay
+    /// disconnected before it's reported as such (including via
+    /// [`Connection`]s' quality scores).
+    ///
+    /// If the peer connection recovers within this period, no disconnect is
+    /// reported at all. `0` (the default) disables the grace period.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`Connection`]: crate::connection::Connection
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn set_disconnect_grace_period(
+        &self,
+        period_ms: u32,
+    ) -> Result<(), JsValue> {
+        self.0
+            .set_disconnect_grace_period(Duration::from_millis(u64::from(
+                period_ms,
+            )))
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Enables or disables sending `RTCStats` to the media server by all the
+    /// current and future `PeerConnection`s of this [`Room`].
+    ///
+    /// `RTCStats` are still scraped while sending is disabled, so local stats
+    /// APIs keep working.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn set_stats_sending_enabled(
+        &self,
+        enabled: bool,
+    ) -> Result<(), JsValue> {
+        self.0
+            .set_stats_sending_enabled(enabled)
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Enables or disables early media in this [`Room`].
+    ///
+    /// Once enabled, negotiations don't wait for local media acquisition
+    /// (e.g. while a media permission prompt is pending), so remote media is
+    /// received right away, and the local media is added once acquired.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`Room`]: room::Room
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn set_early_media(&self, enabled: bool) -> Result<(), JsValue> {
+        self.0.set_early_media(enabled).map_err(Error::from).map_err(Into::into)
+    }
+
+    /// Sets `on_lifecycle` callback, invoked on each [`RoomLifecycleEvent`] of
+    /// this [`Room`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`Room`]: room::Room
+    /// [`RoomLifecycleEvent`]: api::RoomLifecycleEvent
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn on_lifecycle(&self, cb: js_sys::Function) -> Result<(), JsValue> {
+        let cb = platform::Function::<api::RoomLifecycleEvent>::from(cb);
+        let mut events = self.0.on_lifecycle().map_err(Error::from)?;
+        platform::spawn(async move {
+            while let Some(event) = events.next().await {
+                cb.call1(event.into());
+            }
+        });
+        Ok(())
+    }
+
     /// Sets `on_close` callback, invoked when this [`Room`] is closed,
     /// providing a [`RoomCloseReason`].
     ///
@@ -132,6 +292,26 @@ impl RoomHandle {
             .map_err(Into::into)
     }
 
+    /// Sets `on_local_media_fallback` callback, invoked with a
+    /// [`LocalMediaFallback`] when a `set_local_media_settings()` call fails
+    /// and the previous [`MediaStreamSettings`] are restored instead.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`LocalMediaFallback`]: crate::api::LocalMediaFallback
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn on_local_media_fallback(
+        &self,
+        cb: js_sys::Function,
+    ) -> Result<(), JsValue> {
+        self.0
+            .on_local_media_fallback(cb.into())
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
     /// Sets `on_connection_loss` callback, invoked when a connection with a
     /// server is lost.
     ///
@@ -207,6 +387,161 @@ impl RoomHandle {
         })
     }
 
+    /// Sets the maximum resolution of the video sent in this [`Room`],
+    /// regardless of what is captured.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`Room`]: room::Room
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn set_max_send_resolution(&self, width: u32, height: u32) -> Promise {
+        let this = self.0.clone();
+
+        future_to_promise(async move {
+            this.set_max_send_resolution(width, height)
+                .await
+                .map_err(Error::from)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Removes the maximum resolution of the sent video set via
+    /// [`RoomHandle::set_max_send_resolution()`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn reset_max_send_resolution(&self) -> Promise {
+        let this = self.0.clone();
+
+        future_to_promise(async move {
+            this.reset_max_send_resolution().await.map_err(Error::from)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Enables loss-based resolution downgrade of the video sent in this
+    /// [`Room`].
+    ///
+    /// Once the fraction of lost packets (in `0.0..=1.0` range) stays above
+    /// the `high_fraction_lost` for `sustain_ms` milliseconds, the
+    /// [scaleResolutionDownBy][1] factor of each video sender is increased by
+    /// the `step` (up to the `max_scale`), and once it stays below the
+    /// `low_fraction_lost`, it's decreased back.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`Room`]: room::Room
+    /// [`StateError`]: crate::api::err::StateError
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    pub fn enable_loss_based_resolution(
+        &self,
+        high_fraction_lost: f64,
+        low_fraction_lost: f64,
+        sustain_ms: u32,
+        step: f32,
+        max_scale: f32,
+    ) -> Result<(), JsValue> {
+        self.0
+            .enable_loss_based_resolution(LossBasedResolutionConfig {
+                high_fraction_lost,
+                low_fraction_lost,
+                sustain: Duration::from_millis(sustain_ms.into()),
+                step,
+                max_scale,
+            })
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Disables loss-based resolution downgrade enabled via
+    /// [`RoomHandle::enable_loss_based_resolution()`], restoring the original
+    /// resolution of the sent video.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn disable_loss_based_resolution(&self) -> Result<(), JsValue> {
+        self.0
+            .disable_loss_based_resolution()
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Enables adaptive bitrate of the video sent in this [`Room`].
+    ///
+    /// Once the round trip time exceeds the `high_rtt_ms` milliseconds or the
+    /// fraction of lost packets (in `0.0..=1.0` range) exceeds the
+    /// `high_fraction_lost`, the [maxBitrate][1] of each video sender is
+    /// decreased, and otherwise it's increased back, within the
+    /// `min_bitrate..=max_bitrate` range (in bits per second).
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`Room`]: room::Room
+    /// [`StateError`]: crate::api::err::StateError
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-maxbitrate
+    pub fn enable_adaptive_bitrate(
+        &self,
+        min_bitrate: u32,
+        max_bitrate: u32,
+        high_rtt_ms: u32,
+        high_fraction_lost: f64,
+    ) -> Result<(), JsValue> {
+        self.0
+            .enable_adaptive_bitrate(AdaptiveBitrateConfig {
+                min_bitrate,
+                max_bitrate,
+                high_rtt: Duration::from_millis(high_rtt_ms.into()),
+                high_fraction_lost,
+            })
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Disables adaptive bitrate enabled via
+    /// [`RoomHandle::enable_adaptive_bitrate()`], leaving the last applied
+    /// bitrate of the sent video as is.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn disable_adaptive_bitrate(&self) -> Result<(), JsValue> {
+        self.0
+            .disable_adaptive_bitrate()
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Requests Media Server to renegotiate all the peer connections of this
+    /// [`Room`], so fresh SDP offers are generated.
+    ///
+    /// If a negotiation is in progress already, then the renegotiation is
+    /// queued, and all the calls made meanwhile are coalesced into a single
+    /// follow-up negotiation.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`Room`]: room::Room
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn renegotiate(&self) -> Result<(), JsValue> {
+        self.0.renegotiate_all().map_err(Error::from).map_err(Into::into)
+    }
+
     /// Mutes outbound audio in this [`Room`].
     ///
     /// # Errors
@@ -0,0 +1,41 @@
+//! Event of a [`Room`]'s connection lifecycle.
+//!
+//! [`Room`]: room::Room
+
+use derive_more::with_trait::From;
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    api::{RoomCloseReason, RoomLifecycleEventKind},
+    room,
+};
+
+/// Event of a [`Room`]'s connection lifecycle.
+///
+/// This struct is passed to a [`RoomHandle::on_lifecycle`] JS side callback.
+///
+/// [`Room`]: room::Room
+/// [`RoomHandle::on_lifecycle`]: crate::api::RoomHandle::on_lifecycle
+#[wasm_bindgen]
+#[derive(Debug, From)]
+pub struct RoomLifecycleEvent(room::RoomLifecycleEvent);
+
+#[wasm_bindgen]
+impl RoomLifecycleEvent {
+    /// Returns the [`RoomLifecycleEventKind`] of this [`RoomLifecycleEvent`].
+    #[must_use]
+    pub fn kind(&self) -> RoomLifecycleEventKind {
+        self.0.into()
+    }
+
+    /// Returns the [`RoomCloseReason`] if this is a
+    /// [`RoomLifecycleEventKind::Closed`] event.
+    #[must_use]
+    pub fn close_reason(&self) -> Option<RoomCloseReason> {
+        if let room::RoomLifecycleEvent::Closed(reason) = self.0 {
+            Some(room::RoomCloseReason::new(reason).into())
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Client playing remote media from a [WHEP] endpoint.
+//!
+//! [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+
+use std::rc::Rc;
+
+use js_sys::Promise;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::future_to_promise;
+
+use crate::{api::Error, rpc};
+
+/// Client playing remote media from a [WHEP] endpoint.
+///
+/// The received tracks are exposed via the `ConnectionHandle` passed to the
+/// [`WhepClient::on_new_connection()`] callback.
+///
+/// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct WhepClient(Rc<rpc::WhepClient>);
+
+impl From<rpc::WhepClient> for WhepClient {
+    fn from(client: rpc::WhepClient) -> Self {
+        Self(Rc::new(client))
+    }
+}
+
+#[wasm_bindgen]
+impl WhepClient {
+    /// Sets callback, invoked once the remote media starts being received,
+    /// providing the `ConnectionHandle` its tracks are exposed via.
+    ///
+    /// Should be set before calling [`WhepClient::play()`].
+    pub fn on_new_connection(&self, cb: js_sys::Function) {
+        self.0.on_new_connection(cb.into());
+    }
+
+    /// Sets callback, invoked when the playing fails after the [WHEP] session
+    /// has been started (the `PeerConnection` fails).
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    pub fn on_failure(&self, cb: js_sys::Function) {
+        self.0.on_failure(cb.into());
+    }
+
+    /// Starts playing the remote media from the [WHEP] endpoint with the
+    /// provided `url`.
+    ///
+    /// The provided bearer `token` (if any) authorizes all the requests to the
+    /// endpoint.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the playing has been already started.
+    ///
+    /// With a [`RpcClientException`] if the endpoint is unreachable or rejects
+    /// the playing.
+    ///
+    /// With an [`InternalException`] if the SDP negotiation fails.
+    ///
+    /// [`InternalException`]: crate::api::err::InternalException
+    /// [`RpcClientException`]: crate::api::err::RpcClientException
+    /// [`StateError`]: crate::api::err::StateError
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    pub fn play(&self, url: String, token: Option<String>) -> Promise {
+        let client = Rc::clone(&self.0);
+
+        future_to_promise(async move {
+            client.play(url, token).await.map_err(Error::from)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Returns URL of the [WHEP] session resource created by the endpoint, if
+    /// the playing has been started.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    #[must_use]
+    pub fn resource(&self) -> Option<String> {
+        self.0.resource()
+    }
+
+    /// Terminates the [WHEP] session, if started.
+    ///
+    /// # Errors
+    ///
+    /// With a [`RpcClientException`] if the endpoint is unreachable or fails
+    /// to terminate the session.
+    ///
+    /// [`RpcClientException`]: crate::api::err::RpcClientException
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    pub fn close(&self) -> Promise {
+        let client = Rc::clone(&self.0);
+
+        future_to_promise(async move {
+            client.close().await.map_err(Error::from)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+}
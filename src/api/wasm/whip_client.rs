@@ -0,0 +1,62 @@
+//! Client publishing local media to a [WHIP] endpoint.
+//!
+//! [WHIP]: https://tools.ietf.org/html/rfc9725
+
+use std::rc::Rc;
+
+use js_sys::Promise;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::future_to_promise;
+
+use crate::{api::Error, rpc};
+
+/// Client publishing local media to a [WHIP] endpoint.
+///
+/// [WHIP]: https://tools.ietf.org/html/rfc9725
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct WhipClient(Rc<rpc::WhipClient>);
+
+impl From<rpc::WhipClient> for WhipClient {
+    fn from(client: rpc::WhipClient) -> Self {
+        Self(Rc::new(client))
+    }
+}
+
+#[wasm_bindgen]
+impl WhipClient {
+    /// Returns URL of the [WHIP] session resource created by the endpoint.
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    #[must_use]
+    pub fn resource(&self) -> String {
+        self.0.resource().to_owned()
+    }
+
+    /// Sets callback, invoked when the publishing fails after the [WHIP]
+    /// session has been created (the `PeerConnection` fails or the local media
+    /// can't be updated).
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    pub fn on_failure(&self, cb: js_sys::Function) {
+        self.0.on_failure(cb.into());
+    }
+
+    /// Terminates the [WHIP] session.
+    ///
+    /// # Errors
+    ///
+    /// With a [`RpcClientException`] if the endpoint is unreachable or fails
+    /// to terminate the session.
+    ///
+    /// [`RpcClientException`]: crate::api::err::RpcClientException
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    pub fn close(&self) -> Promise {
+        let client = Rc::clone(&self.0);
+
+        future_to_promise(async move {
+            client.close().await.map_err(Error::from)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+}
@@ -13,7 +13,8 @@ use futures::{
 };
 use medea_client_api_proto::{
     self as proto, ConnectionQualityScore, MemberId, PeerConnectionState,
-    TrackId,
+    QualityLayer, TrackId,
+    stats::{RtcStat, RtcStatsType, StatId},
 };
 use tracerr::Traced;
 
@@ -55,6 +56,16 @@ pub enum ChangeMediaStateError {
     ProhibitedState(ProhibitedStateError),
 }
 
+/// Fraction of the lost inbound packets (as `1 / N`) starting from which the
+/// inbound media quality of a [`Connection`] is considered poor.
+const POOR_INBOUND_PACKET_LOSS_DIVISOR: u64 = 20;
+
+/// Inbound [RTP] jitter (in seconds) starting from which the inbound media
+/// quality of a [`Connection`] is considered poor.
+///
+/// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+const POOR_INBOUND_JITTER_SECS: f64 = 0.03;
+
 /// Alias of [`Result`]s related to [`MediaState`] update functions.
 type ChangeMediaStateResult = Result<(), Traced<ChangeMediaStateError>>;
 
@@ -256,6 +267,70 @@ impl Connections {
         self.members_to_conns.borrow().get(remote_member_id).cloned()
     }
 
+    /// Updates the inbound media quality of all the [`Connection`]s receiving
+    /// media via the provided receivers' `mid`s, based on the provided
+    /// [`RtcStat`]s.
+    ///
+    /// Each [`Connection`] is updated once, with the inbound [RTP] stream
+    /// stats of its own receivers only.
+    ///
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    pub fn update_inbound_stats<I>(&self, receivers_mids: I, stats: &[RtcStat])
+    where
+        I: IntoIterator<Item = (TrackId, String)>,
+    {
+        let mut mids_by_member = HashMap::<MemberId, HashSet<String>>::new();
+        {
+            let tracks_to_members = self.tracks_to_members.borrow();
+            for (track_id, mid) in receivers_mids {
+                for member_id in
+                    tracks_to_members.get(&track_id).into_iter().flatten()
+                {
+                    _ = mids_by_member
+                        .entry(member_id.clone())
+                        .or_default()
+                        .insert(mid.clone());
+                }
+            }
+        }
+
+        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+        for (member_id, mids) in mids_by_member {
+            let Some(conn) = self.get(&member_id) else {
+                continue;
+            };
+            let member_stats: Vec<_> = stats
+                .iter()
+                .filter(|stat| {
+                    matches!(
+                        &stat.stats,
+                        RtcStatsType::InboundRtp(inbound)
+                            if inbound
+                                .mid
+                                .as_ref()
+                                .is_some_and(|mid| mids.contains(mid)),
+                    )
+                })
+                .cloned()
+                .collect();
+            conn.update_inbound_stats(&member_stats);
+        }
+    }
+
+    /// Returns the worst [`ConnectionQualityScore`] among all the connected
+    /// and scored [`Connection`]s, if any.
+    #[must_use]
+    pub fn worst_quality_score(&self) -> Option<ConnectionQualityScore> {
+        self.members_to_conns
+            .borrow()
+            .values()
+            .filter_map(|conn| match conn.0.client_quality_score.get()? {
+                ClientConnectionQualityScore::Connected(score) => Some(score),
+                ClientConnectionQualityScore::Disconnected => None,
+            })
+            .min()
+    }
+
     /// Iterates over all the [`Connection`]s of the provided [`TrackId`].
     pub fn iter_by_track(
         &self,
@@ -357,9 +432,33 @@ struct InnerConnection {
     /// All [`receiver::State`]s related to this [`InnerConnection`].
     receivers: RefCell<Vec<Rc<receiver::State>>>,
 
+    /// Last [`QualityLayer`] of the inbound video requested in this
+    /// [`Connection`].
+    quality_layer: Cell<Option<QualityLayer>>,
+
     /// Callback invoked when a [`ConnectionQualityScore`] is updated.
     on_quality_score_update: platform::Callback<u8>,
 
+    /// Last observed `packetsReceived` and `packetsLost` counters of the
+    /// inbound [RTP] streams of this [`Connection`].
+    ///
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    inbound_rtp_counters: RefCell<HashMap<StatId, (u64, i64)>>,
+
+    /// Indicator whether the inbound media quality of this [`Connection`] is
+    /// good, as computed from the inbound [RTP] stats.
+    ///
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    is_inbound_quality_good: Cell<Option<bool>>,
+
+    /// Callback invoked when the inbound media quality of this [`Connection`]
+    /// switches between good and poor.
+    on_inbound_quality_update: platform::Callback<bool>,
+
+    /// Callback invoked when an application message is received from the
+    /// remote `Member` of this [`Connection`].
+    on_application_message: platform::Callback<api::ApplicationMessage>,
+
     /// Callback invoked when this [`Connection`] is closed.
     on_close: platform::Callback<()>,
 
@@ -369,6 +468,32 @@ struct InnerConnection {
 }
 
 impl InnerConnection {
+    /// Requests the provided [`QualityLayer`] of all the video
+    /// [`receiver::State`]s from a media server.
+    ///
+    /// Remembers the provided [`QualityLayer`] to request it for the video
+    /// [`receiver::State`]s added later.
+    fn request_quality(&self, layer: QualityLayer) {
+        self.quality_layer.set(Some(layer));
+        self.receivers
+            .borrow()
+            .iter()
+            .filter(|r| r.kind() == MediaKind::Video)
+            .for_each(|r| r.request_quality_layer(layer));
+    }
+
+    /// Indicates whether all the [`receiver::State`]s of the provided
+    /// [`MediaKind`] are muted by the remote `Member`.
+    ///
+    /// Returns `false` if there are no [`receiver::State`]s of the provided
+    /// [`MediaKind`].
+    fn is_remote_muted(&self, kind: MediaKind) -> bool {
+        let receivers = self.receivers.borrow();
+        let mut of_kind =
+            receivers.iter().filter(|r| r.kind() == kind).peekable();
+        of_kind.peek().is_some() && of_kind.all(|r| r.muted())
+    }
+
     /// Changes [`MediaState`] of the provided [`MediaKind`] to the provided
     /// [`MediaState`].
     ///
@@ -479,6 +604,70 @@ impl ConnectionHandle {
             .map(|inner| inner.on_quality_score_update.set_func(f))
     }
 
+    /// Sets callback, invoked when the inbound media quality of this
+    /// [`Connection`] switches between good (`true`) and poor (`false`).
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn on_inbound_quality_update(
+        &self,
+        f: platform::Function<bool>,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        self.0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(HandleDetachedError))
+            .map(|inner| inner.on_inbound_quality_update.set_func(f))
+    }
+
+    /// Sets callback, invoked when an application message is received from
+    /// the remote `Member` of this [`Connection`].
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn on_application_message(
+        &self,
+        f: platform::Function<api::ApplicationMessage>,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        self.0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(HandleDetachedError))
+            .map(|inner| inner.on_application_message.set_func(f))
+    }
+
+    /// Requests the provided [`QualityLayer`] of all the inbound video in this
+    /// [`Connection`] from a media server.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn request_quality(
+        &self,
+        layer: QualityLayer,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        self.0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(HandleDetachedError))
+            .map(|inner| inner.request_quality(layer))
+    }
+
+    /// Indicates whether all the inbound media of the provided [`MediaKind`]
+    /// in this [`Connection`] is muted by the remote `Member`.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn is_remote_muted(
+        &self,
+        kind: MediaKind,
+    ) -> Result<bool, Traced<HandleDetachedError>> {
+        self.0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(HandleDetachedError))
+            .map(|inner| inner.is_remote_muted(kind))
+    }
+
     /// Enables inbound video in this [`Connection`].
     ///
     /// # Errors
@@ -630,10 +819,15 @@ impl Connection {
             client_quality_score: Cell::default(),
             peer_state: Cell::default(),
             on_quality_score_update: platform::Callback::default(),
+            inbound_rtp_counters: RefCell::default(),
+            is_inbound_quality_good: Cell::default(),
+            on_inbound_quality_update: platform::Callback::default(),
             recv_constraints,
+            on_application_message: platform::Callback::default(),
             on_close: platform::Callback::default(),
             on_remote_track_added: platform::Callback::default(),
             receivers: RefCell::default(),
+            quality_layer: Cell::default(),
         }))
     }
 
@@ -666,7 +860,9 @@ impl Connection {
     /// Stores provided [`receiver::State`] in this [`Connection`].
     ///
     /// Updates [`MediaExchangeState`] of the provided [`receiver::State`] based
-    /// on the current individual [`RecvConstraints`] of this [`Connection`].
+    /// on the current individual [`RecvConstraints`] of this [`Connection`],
+    /// and requests the [`QualityLayer`] previously requested for this
+    /// [`Connection`], if any.
     ///
     /// [`MediaExchangeState`]: crate::peer::MediaExchangeState
     pub fn add_receiver(&self, receiver: Rc<receiver::State>) {
@@ -680,16 +876,49 @@ impl Connection {
         receiver
             .media_exchange_state_controller()
             .transition_to(enabled_in_cons.into());
+        if receiver.kind() == MediaKind::Video {
+            if let Some(layer) = self.0.quality_layer.get() {
+                receiver.request_quality_layer(layer);
+            }
+        }
 
         self.0.receivers.borrow_mut().push(receiver);
     }
 
+    /// Indicates whether all the inbound media of the provided [`MediaKind`]
+    /// in this [`Connection`] is muted by the remote `Member`.
+    ///
+    /// Reflects the mute state signalled by a server, so is updated as soon
+    /// as [`TrackPatchEvent`]s are applied.
+    ///
+    /// [`TrackPatchEvent`]: proto::TrackPatchEvent
+    #[must_use]
+    pub fn is_remote_muted(&self, kind: MediaKind) -> bool {
+        self.0.is_remote_muted(kind)
+    }
+
+    /// Requests the provided [`QualityLayer`] of all the inbound video in this
+    /// [`Connection`] from a media server.
+    ///
+    /// Makes sense only for the video received from [SFU].
+    ///
+    /// [SFU]: https://webrtcglossary.com/sfu
+    pub fn request_quality(&self, layer: QualityLayer) {
+        self.0.request_quality(layer);
+    }
+
     /// Invokes `on_remote_track_added` callback with the provided
     /// [`remote::Track`].
     pub fn add_remote_track(&self, track: remote::Track) {
         self.0.on_remote_track_added.call1(track);
     }
 
+    /// Invokes `on_application_message` callback with the provided
+    /// application `data`.
+    pub fn receive_application_message(&self, data: Vec<u8>) {
+        self.0.on_application_message.call1(data);
+    }
+
     /// Creates a new external handle to this [`Connection`].
     #[must_use]
     pub fn new_handle(&self) -> ConnectionHandle {
@@ -714,6 +943,64 @@ impl Connection {
         self.refresh_client_conn_quality_score();
     }
 
+    /// Updates the inbound media quality of this [`Connection`] based on the
+    /// provided inbound [RTP] stream stats.
+    ///
+    /// Quality is considered poor if the packet loss since the previous update
+    /// exceeds 5%, or the jitter of any inbound stream exceeds 30ms.
+    ///
+    /// No-op if no new packets were received since the previous update.
+    ///
+    /// Counters of the inbound [RTP] streams missing in the provided stats are
+    /// discarded.
+    ///
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    pub fn update_inbound_stats(&self, stats: &[RtcStat]) {
+        let mut received = 0_u64;
+        let mut lost = 0_u64;
+        let mut max_jitter = 0.0_f64;
+        {
+            let mut counters = self.0.inbound_rtp_counters.borrow_mut();
+            counters.retain(|id, _| stats.iter().any(|stat| stat.id == *id));
+            for stat in stats {
+                let RtcStatsType::InboundRtp(inbound) = &stat.stats else {
+                    continue;
+                };
+                let cur_lost = inbound.packets_lost.unwrap_or_default();
+                let (prev_received, prev_lost) = counters
+                    .insert(
+                        stat.id.clone(),
+                        (inbound.packets_received, cur_lost),
+                    )
+                    .unwrap_or_default();
+                received = received.saturating_add(
+                    inbound.packets_received.saturating_sub(prev_received),
+                );
+                lost = lost.saturating_add(
+                    u64::try_from(cur_lost.saturating_sub(prev_lost))
+                        .unwrap_or_default(),
+                );
+                if let Some(jitter) = inbound.jitter {
+                    max_jitter = max_jitter.max(jitter.0);
+                }
+            }
+        }
+
+        let total = received.saturating_add(lost);
+        if total == 0 {
+            return;
+        }
+        let is_good = lost.saturating_mul(POOR_INBOUND_PACKET_LOSS_DIVISOR)
+            <= total
+            && max_jitter < POOR_INBOUND_JITTER_SECS;
+
+        let is_changed = self.0.is_inbound_quality_good.replace(Some(is_good))
+            != Some(is_good);
+        if is_changed {
+            self.0.on_inbound_quality_update.call1(is_good);
+        }
+    }
+
     /// Refreshes the [`ClientConnectionQualityScore`] of this [`Connection`].
     fn refresh_client_conn_quality_score(&self) {
         use PeerConnectionState as S;
@@ -1,15 +1,25 @@
 //! General library interface.
 
-use std::{cell::RefCell, rc::Rc, thread};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    thread,
+    time::Duration,
+};
 
-use futures::FutureExt as _;
+use futures::{FutureExt as _, future};
+use medea_client_api_proto::{IceServer, MediaSourceKind};
+use tracerr::Traced;
 
 use crate::{
-    media::{MediaManager, MediaManagerHandle},
-    platform,
+    media::{MediaManager, MediaManagerHandle, MediaStreamSettings},
+    peer::IceServersResolver,
+    platform::{self, RpcCompression},
     room::{Room, RoomHandle},
     rpc::{
-        ClientDisconnect, RpcSession, WebSocketRpcClient, WebSocketRpcSession,
+        ClientDisconnect, RpcSession, TokenRefresher, WebSocketRpcClient,
+        WebSocketRpcClientPool, WebSocketRpcSession, WhepClient, WhipClient,
+        WhipError,
     },
 };
 
@@ -37,9 +47,32 @@ struct Inner {
     /// [`Jason`] will reuse this [`WebSocketRpcClient`] for each [`Room`] if
     /// it's [`Some`].
     ///
-    /// New [`WebSocketRpcClient`] will be created for each [`Room`] if it's
-    /// [`None`].
+    /// [`WebSocketRpcClient`]s will be taken from the [`rpc_pool`] for each
+    /// [`Room`] if it's [`None`].
+    ///
+    /// [`rpc_pool`]: Inner::rpc_pool
     rpc: Option<Rc<WebSocketRpcClient>>,
+
+    /// Pool of [`WebSocketRpcClient`]s shared by the [`Room`]s connecting to
+    /// the same server URL.
+    rpc_pool: Rc<WebSocketRpcClientPool>,
+
+    /// [`RpcCompression`] of the transports created by the [`rpc_pool`].
+    ///
+    /// [`rpc_pool`]: Inner::rpc_pool
+    rpc_compression: Rc<Cell<RpcCompression>>,
+
+    /// Default [`IceServer`]s inherited by all the [`Room`]s of this
+    /// [`Jason`].
+    default_ice_servers: Vec<IceServer>,
+
+    /// [`TokenRefresher`] used by the [`Room`]s of this [`Jason`] to fetch a
+    /// fresh authorization token before reconnecting.
+    token_refresher: Option<TokenRefresher>,
+
+    /// [`IceServersResolver`] inherited by all the [`Room`]s of this
+    /// [`Jason`].
+    ice_servers_resolver: Option<IceServersResolver>,
 }
 
 impl Jason {
@@ -48,8 +81,10 @@ impl Jason {
     /// If a [`WebSocketRpcClient`] is provided, then [`Jason`] will reuse it
     /// for all the [`Room`]s created in this [`Jason`].
     ///
-    /// If [`WebSocketRpcClient`] is not provided, then a new separate
-    /// [`WebSocketRpcClient`] will be created for each [`Room`].
+    /// If [`WebSocketRpcClient`] is not provided, then all the [`Room`]s
+    /// connecting to the same server URL will share a single
+    /// [`WebSocketRpcClient`], which is closed once the last of these
+    /// [`Room`]s is closed.
     #[must_use]
     pub fn new(rpc: Option<Rc<WebSocketRpcClient>>) -> Self {
         if !thread::panicking() {
@@ -59,22 +94,105 @@ impl Jason {
             platform::init_logger();
         }
 
+        let rpc_compression = Rc::new(Cell::new(RpcCompression::default()));
+        let rpc_pool = Rc::new(WebSocketRpcClientPool::new(Rc::new({
+            let compression = Rc::clone(&rpc_compression);
+            move || {
+                Rc::new(platform::WebSocketRpcTransport::with_compression(
+                    compression.get(),
+                ))
+            }
+        })));
+
         Self(Rc::new(RefCell::new(Inner {
             rooms: Vec::new(),
             media_manager: Rc::new(MediaManager::default()),
             rpc,
+            rpc_pool,
+            rpc_compression,
+            default_ice_servers: Vec::new(),
+            token_refresher: None,
+            ice_servers_resolver: None,
         })))
     }
 
+    /// Instantiates a new [`Jason`] which uses the provided application-owned
+    /// [`platform::MediaStreamTrack`]s as its local media instead of acquiring
+    /// its own ones of the same kinds.
+    ///
+    /// See [`MediaManager::set_external_tracks()`] for details.
+    #[must_use]
+    pub fn with_external_tracks(
+        rpc: Option<Rc<WebSocketRpcClient>>,
+        tracks: Vec<(platform::MediaStreamTrack, MediaSourceKind)>,
+    ) -> Self {
+        let this = Self::new(rpc);
+        this.0.borrow().media_manager.set_external_tracks(tracks);
+        this
+    }
+
     /// Creates a new [`Room`] and returns its [`RoomHandle`].
     #[must_use]
     pub fn init_room(&self) -> RoomHandle {
-        let rpc = self.0.borrow().rpc.clone().unwrap_or_else(|| {
-            Rc::new(WebSocketRpcClient::new(Box::new(|| {
-                Rc::new(platform::WebSocketRpcTransport::new())
-            })))
-        });
-        self.inner_init_room(WebSocketRpcSession::new(rpc))
+        let session = self.0.borrow().rpc.clone().map_or_else(
+            || {
+                WebSocketRpcSession::with_pool(Rc::clone(
+                    &self.0.borrow().rpc_pool,
+                ))
+            },
+            WebSocketRpcSession::new,
+        );
+        session.set_token_refresher(self.0.borrow().token_refresher.clone());
+        self.inner_init_room(session)
+    }
+
+    /// Sets the [`RpcCompression`] negotiated by the connections to media
+    /// servers established afterwards.
+    ///
+    /// Doesn't affect the [`WebSocketRpcClient`] provided to [`Jason::new()`].
+    pub fn set_rpc_compression(&self, compression: RpcCompression) {
+        self.0.borrow().rpc_compression.set(compression);
+    }
+
+    /// Sets the [`TokenRefresher`] invoked by the [`Room`]s created afterwards
+    /// to fetch a fresh authorization token before each reconnection to a
+    /// media server.
+    ///
+    /// See [`WebSocketRpcSession::set_token_refresher()`] for details.
+    pub fn set_token_refresher(&self, refresher: Option<TokenRefresher>) {
+        self.0.borrow_mut().token_refresher = refresher;
+    }
+
+    /// Sets the default [`IceServer`]s inherited by the peers created
+    /// afterwards in all the [`Room`]s of this [`Jason`], including the
+    /// already existing [`Room`]s.
+    ///
+    /// [`IceServer`]s provided by the media server for a peer always win over
+    /// the default ones.
+    pub fn set_default_ice_servers(&self, ice_servers: Vec<IceServer>) {
+        let mut inner = self.0.borrow_mut();
+        for room in &inner.rooms {
+            room.set_default_ice_servers(ice_servers.clone());
+        }
+        inner.default_ice_servers = ice_servers;
+    }
+
+    /// Sets the [`IceServersResolver`] deciding which [`IceServer`]s are
+    /// actually used by the peers created afterwards in all the [`Room`]s of
+    /// this [`Jason`], including the already existing [`Room`]s.
+    ///
+    /// See [`peer::State::set_ice_servers_resolver()`] for details.
+    ///
+    /// [`peer::State::set_ice_servers_resolver()`]: crate::peer::State::set_ice_servers_resolver
+    pub fn set_ice_servers_resolver(
+        &self,
+        resolver: Option<IceServersResolver>,
+    ) {
+        let mut inner = self.0.borrow_mut();
+        for room in &inner.rooms {
+            room.set_ice_servers_resolver(resolver.clone());
+        }
+        inner.ice_servers_resolver = resolver;
     }
 
     /// Returns a [`MediaManagerHandle`].
@@ -83,6 +201,47 @@ impl Jason {
         self.0.borrow().media_manager.new_handle()
     }
 
+    /// Publishes the local media described by the provided
+    /// [`MediaStreamSettings`] to the [WHIP] endpoint with the provided `url`,
+    /// bypassing the Medea signalling.
+    ///
+    /// The provided bearer `token` (if any) authorizes all the requests to the
+    /// endpoint. Default [`IceServer`]s of this [`Jason`] are used.
+    ///
+    /// # Errors
+    ///
+    /// See [`WhipClient::publish()`] for details.
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    pub fn publish_whip(
+        &self,
+        url: String,
+        token: Option<String>,
+        settings: MediaStreamSettings,
+    ) -> impl Future<Output = Result<WhipClient, Traced<WhipError>>> + use<>
+    {
+        let (media_manager, ice_servers) = {
+            let inner = self.0.borrow();
+            (Rc::clone(&inner.media_manager), inner.default_ice_servers.clone())
+        };
+        WhipClient::publish(url, token, media_manager, settings, ice_servers)
+    }
+
+    /// Creates a new [`WhepClient`] playing media from a [WHEP] endpoint,
+    /// bypassing the Medea signalling.
+    ///
+    /// Default [`IceServer`]s of this [`Jason`] are used.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    #[must_use]
+    pub fn whep_client(&self) -> WhepClient {
+        let inner = self.0.borrow();
+        WhepClient::new(
+            Rc::clone(&inner.media_manager),
+            inner.default_ice_servers.clone(),
+        )
+    }
+
     /// Closes the provided [`RoomHandle`].
     pub fn close_room(&self, room_to_delete: &RoomHandle) {
         let index = self
@@ -112,10 +271,42 @@ impl Jason {
         });
     }
 
+    /// Gracefully disposes this [`Jason`] API object.
+    ///
+    /// Leaves all the [`Room`]s (see [`RoomHandle::leave()`]) waiting for a
+    /// media server to acknowledge it during the provided `timeout`, which
+    /// closes their connections normally. Then [stops][1] all the local media
+    /// tracks and disposes this [`Jason`] the same way as [`Jason::dispose()`]
+    /// does.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack-stop
+    pub async fn dispose_async(self, timeout: Duration) {
+        let (rooms, media_manager) = {
+            let inner = self.0.borrow();
+            (
+                inner.rooms.iter().map(Room::new_handle).collect::<Vec<_>>(),
+                Rc::clone(&inner.media_manager),
+            )
+        };
+        drop(
+            future::join_all(rooms.iter().map(|room| room.leave(timeout)))
+                .await,
+        );
+        media_manager.stop_tracks().await;
+
+        self.dispose();
+    }
+
     /// Returns a [`RoomHandle`] for an initialized  [`Room`].
     fn inner_init_room(&self, rpc: Rc<dyn RpcSession>) -> RoomHandle {
         let on_normal_close = rpc.on_normal_close();
         let room = Room::new(rpc, Rc::clone(&self.0.borrow().media_manager));
+        room.set_default_ice_servers(
+            self.0.borrow().default_ice_servers.clone(),
+        );
+        room.set_ice_servers_resolver(
+            self.0.borrow().ice_servers_resolver.clone(),
+        );
 
         let weak_room = room.downgrade();
         let weak_inner = Rc::downgrade(&self.0);
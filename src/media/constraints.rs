@@ -890,6 +890,7 @@ impl From<VideoSettings> for VideoSource {
                     facing_mode: None,
                     width: None,
                     height: None,
+                    frame_rate: None,
                     required: settings.required,
                 })
             }
@@ -1013,6 +1014,11 @@ pub struct AudioTrackConstraints {
     ///
     /// __NOTE__: Only supported on desktop platforms.
     pub high_pass_filter: Option<ConstrainBoolean>,
+
+    /// Number of audio channels (`2` for stereo) to be captured.
+    ///
+    /// __NOTE__: Only supported on web platform.
+    pub channel_count: Option<ConstrainU32Range>,
 }
 
 impl AudioTrackConstraints {
@@ -1029,6 +1035,20 @@ impl AudioTrackConstraints {
         self.device_id = Some(ConstrainString::Exact(device_id));
     }
 
+    /// Sets an exact [channelCount][1] constraint.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#def-constraint-channelCount
+    pub const fn exact_channel_count(&mut self, count: u32) {
+        self.channel_count = Some(ConstrainU32Range::exact(count));
+    }
+
+    /// Sets an ideal [channelCount][1] constraint.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#def-constraint-channelCount
+    pub const fn ideal_channel_count(&mut self, count: u32) {
+        self.channel_count = Some(ConstrainU32Range::ideal(count));
+    }
+
     /// Checks whether the provided [`platform::MediaStreamTrack`] satisfies the
     /// contained constraints.
     pub async fn satisfies<T: AsRef<platform::MediaStreamTrack>>(
@@ -1101,6 +1121,9 @@ impl AudioTrackConstraints {
         if !self.required && another.required {
             self.required = another.required;
         }
+        if self.channel_count.is_none() {
+            self.channel_count = another.channel_count;
+        }
     }
 
     /// Returns an importance of these [`AudioTrackConstraints`].
@@ -1123,6 +1146,7 @@ impl From<ProtoAudioConstraints> for AudioTrackConstraints {
             noise_suppression_level: None,
             echo_cancellation: None,
             high_pass_filter: None,
+            channel_count: None,
         }
     }
 }
@@ -1155,17 +1179,79 @@ pub enum ConstrainU32 {
     Range(u32, u32),
 }
 
-impl ConstrainU32 {
-    /// Checks whether `this` [`ConstrainU32`] is satisfied with the given
+/// Representation of a [ConstrainULongRange][1].
+///
+/// Unlike a [`ConstrainU32`], allows to combine `min`, `ideal` and `max`
+/// (or `exact`) values of a single constraint.
+///
+/// [1]: https://w3.org/TR/mediacapture-streams#dom-constrainulongrange
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ConstrainU32Range {
+    /// Value the parameter must be equal to.
+    pub exact: Option<u32>,
+
+    /// Minimum value of the parameter.
+    pub min: Option<u32>,
+
+    /// Value the parameter should be as close as possible to.
+    pub ideal: Option<u32>,
+
+    /// Maximum value of the parameter.
+    pub max: Option<u32>,
+}
+
+impl ConstrainU32Range {
+    /// Creates a new [`ConstrainU32Range`] out of the provided `min`, `ideal`
+    /// and `max` values.
+    #[must_use]
+    pub const fn new(
+        min: Option<u32>,
+        ideal: Option<u32>,
+        max: Option<u32>,
+    ) -> Self {
+        Self { exact: None, min, ideal, max }
+    }
+
+    /// Creates a new [`ConstrainU32Range`] with the provided `exact` value
+    /// only.
+    #[must_use]
+    pub const fn exact(exact: u32) -> Self {
+        Self { exact: Some(exact), min: None, ideal: None, max: None }
+    }
+
+    /// Creates a new [`ConstrainU32Range`] with the provided `ideal` value
+    /// only.
+    #[must_use]
+    pub const fn ideal(ideal: u32) -> Self {
+        Self::new(None, Some(ideal), None)
+    }
+
+    /// Checks whether `this` [`ConstrainU32Range`] is satisfied with the given
     /// `setting`.
+    ///
+    /// The `ideal` value is never checked, as it's not mandatory.
     fn satisfies(this: Option<Self>, setting: Option<u32>) -> bool {
-        // It's up to `<T as Constraint>::TRACK_SETTINGS_FIELD_NAME` to
-        // guarantee that such casts are safe.
-        match this {
-            None | Some(Self::Ideal(_)) => true,
-            Some(Self::Exact(exact)) => setting.is_some_and(|val| val == exact),
-            Some(Self::Range(start, end)) => {
-                setting.is_some_and(|val| val >= start && val <= end)
+        let Some(this) = this else {
+            return true;
+        };
+        if this.exact.is_none() && this.min.is_none() && this.max.is_none() {
+            return true;
+        }
+        setting.is_some_and(|val| {
+            this.exact.is_none_or(|exact| val == exact)
+                && this.min.is_none_or(|min| val >= min)
+                && this.max.is_none_or(|max| val <= max)
+        })
+    }
+}
+
+impl From<ConstrainU32> for ConstrainU32Range {
+    fn from(from: ConstrainU32) -> Self {
+        match from {
+            ConstrainU32::Exact(val) => Self::exact(val),
+            ConstrainU32::Ideal(val) => Self::ideal(val),
+            ConstrainU32::Range(min, max) => {
+                Self::new(Some(min), None, Some(max))
             }
         }
     }
@@ -1232,10 +1318,15 @@ pub struct DeviceVideoTrackConstraints {
     pub facing_mode: Option<ConstrainString<FacingMode>>,
 
     /// Height of the video in pixels.
-    pub height: Option<ConstrainU32>,
+    pub height: Option<ConstrainU32Range>,
 
     /// Width of the video in pixels.
-    pub width: Option<ConstrainU32>,
+    pub width: Option<ConstrainU32Range>,
+
+    /// [Frame rate][1] of the video.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
+    pub frame_rate: Option<ConstrainU32Range>,
 }
 
 /// Constraints applicable to video tracks that are sourced from screen-capture.
@@ -1272,42 +1363,95 @@ impl DeviceVideoTrackConstraints {
     ///
     /// [1]: https://tinyurl.com/w3-streams#def-constraint-height
     pub const fn exact_height(&mut self, height: u32) {
-        self.height = Some(ConstrainU32::Exact(height));
+        self.height = Some(ConstrainU32Range::exact(height));
     }
 
     /// Sets ideal [`height`][1] constraint.
     ///
     /// [1]: https://tinyurl.com/w3-streams#def-constraint-height
     pub const fn ideal_height(&mut self, height: u32) {
-        self.height = Some(ConstrainU32::Ideal(height));
+        self.height = Some(ConstrainU32Range::ideal(height));
     }
 
     /// Sets range of [`height`][1] constraint.
     ///
     /// [1]: https://tinyurl.com/w3-streams#def-constraint-height
     pub const fn height_in_range(&mut self, min: u32, max: u32) {
-        self.height = Some(ConstrainU32::Range(min, max));
+        self.height = Some(ConstrainU32Range::new(Some(min), None, Some(max)));
     }
 
     /// Sets exact [`width`][1] constraint.
     ///
     /// [1]: https://tinyurl.com/w3-streams#def-constraint-width
     pub const fn exact_width(&mut self, width: u32) {
-        self.width = Some(ConstrainU32::Exact(width));
+        self.width = Some(ConstrainU32Range::exact(width));
     }
 
     /// Sets ideal [`width`][1] constraint.
     ///
     /// [1]: https://tinyurl.com/w3-streams#def-constraint-width
     pub const fn ideal_width(&mut self, width: u32) {
-        self.width = Some(ConstrainU32::Ideal(width));
+        self.width = Some(ConstrainU32Range::ideal(width));
     }
 
     /// Sets range of [`width`][1] constraint.
     ///
     /// [1]: https://tinyurl.com/w3-streams#def-constraint-width
     pub const fn width_in_range(&mut self, min: u32, max: u32) {
-        self.width = Some(ConstrainU32::Range(min, max));
+        self.width = Some(ConstrainU32Range::new(Some(min), None, Some(max)));
+    }
+
+    /// Sets [`height`][1] constraint as a range of optional `min`, `ideal` and
+    /// `max` values.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#def-constraint-height
+    pub const fn height_range(
+        &mut self,
+        min: Option<u32>,
+        ideal: Option<u32>,
+        max: Option<u32>,
+    ) {
+        self.height = Some(ConstrainU32Range::new(min, ideal, max));
+    }
+
+    /// Sets [`width`][1] constraint as a range of optional `min`, `ideal` and
+    /// `max` values.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#def-constraint-width
+    pub const fn width_range(
+        &mut self,
+        min: Option<u32>,
+        ideal: Option<u32>,
+        max: Option<u32>,
+    ) {
+        self.width = Some(ConstrainU32Range::new(min, ideal, max));
+    }
+
+    /// Sets exact [frameRate][1] constraint.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
+    pub const fn exact_frame_rate(&mut self, frame_rate: u32) {
+        self.frame_rate = Some(ConstrainU32Range::exact(frame_rate));
+    }
+
+    /// Sets ideal [frameRate][1] constraint.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
+    pub const fn ideal_frame_rate(&mut self, frame_rate: u32) {
+        self.frame_rate = Some(ConstrainU32Range::ideal(frame_rate));
+    }
+
+    /// Sets [frameRate][1] constraint as a range of optional `min`, `ideal`
+    /// and `max` values.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
+    pub const fn frame_rate_range(
+        &mut self,
+        min: Option<u32>,
+        ideal: Option<u32>,
+        max: Option<u32>,
+    ) {
+        self.frame_rate = Some(ConstrainU32Range::new(min, ideal, max));
     }
 
     /// Checks whether the provided [`platform::MediaStreamTrack`] satisfies
@@ -1326,8 +1470,8 @@ impl DeviceVideoTrackConstraints {
                 self.facing_mode.as_ref(),
                 track.facing_mode().as_ref(),
             )
-            && ConstrainU32::satisfies(self.height, track.height())
-            && ConstrainU32::satisfies(self.width, track.width())
+            && ConstrainU32Range::satisfies(self.height, track.height())
+            && ConstrainU32Range::satisfies(self.width, track.width())
             && !track.guess_is_from_display()
     }
 
@@ -1350,6 +1494,9 @@ impl DeviceVideoTrackConstraints {
         if self.width.is_none() && another.width.is_some() {
             self.width = another.width;
         }
+        if self.frame_rate.is_none() && another.frame_rate.is_some() {
+            self.frame_rate = another.frame_rate;
+        }
     }
 
     /// Returns an importance of these [`DeviceVideoTrackConstraints`].
@@ -1377,17 +1524,17 @@ pub struct DisplayVideoTrackConstraints {
     /// [Height][1] of the video in pixels.
     ///
     /// [1]: https://tinyurl.com/w3-streams#def-constraint-height
-    pub height: Option<ConstrainU32>,
+    pub height: Option<ConstrainU32Range>,
 
     /// [Width][1] of the video in pixels.
     ///
     /// [1]: https://tinyurl.com/w3-streams#def-constraint-width
-    pub width: Option<ConstrainU32>,
+    pub width: Option<ConstrainU32Range>,
 
     /// [Frame rate][1] of the video.
     ///
     /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
-    pub frame_rate: Option<ConstrainU32>,
+    pub frame_rate: Option<ConstrainU32Range>,
 }
 
 impl DisplayVideoTrackConstraints {
@@ -1410,8 +1557,8 @@ impl DisplayVideoTrackConstraints {
                 self.device_id.as_ref(),
                 track.device_id().as_ref(),
             )
-            && ConstrainU32::satisfies(self.height, track.height())
-            && ConstrainU32::satisfies(self.width, track.width())
+            && ConstrainU32Range::satisfies(self.height, track.height())
+            && ConstrainU32Range::satisfies(self.width, track.width())
             && track.guess_is_from_display()
     }
 
@@ -1440,28 +1587,28 @@ impl DisplayVideoTrackConstraints {
     ///
     /// [1]: https://tinyurl.com/w3-streams#def-constraint-height
     pub const fn exact_height(&mut self, height: u32) {
-        self.height = Some(ConstrainU32::Exact(height));
+        self.height = Some(ConstrainU32Range::exact(height));
     }
 
     /// Sets an ideal [height][1] constraint.
     ///
     /// [1]: https://tinyurl.com/w3-streams#def-constraint-height
     pub const fn ideal_height(&mut self, height: u32) {
-        self.height = Some(ConstrainU32::Ideal(height));
+        self.height = Some(ConstrainU32Range::ideal(height));
     }
 
     /// Sets an exact [width][1] constraint.
     ///
     /// [1]: https://tinyurl.com/w3-streams#def-constraint-width
     pub const fn exact_width(&mut self, width: u32) {
-        self.width = Some(ConstrainU32::Exact(width));
+        self.width = Some(ConstrainU32Range::exact(width));
     }
 
     /// Sets an ideal [width][1] constraint.
     ///
     /// [1]: https://tinyurl.com/w3-streams#def-constraint-width
     pub const fn ideal_width(&mut self, width: u32) {
-        self.width = Some(ConstrainU32::Ideal(width));
+        self.width = Some(ConstrainU32Range::ideal(width));
     }
 
     /// Sets an exact [deviceId][1] constraint.
@@ -1475,14 +1622,53 @@ impl DisplayVideoTrackConstraints {
     ///
     /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
     pub const fn exact_frame_rate(&mut self, frame_rate: u32) {
-        self.frame_rate = Some(ConstrainU32::Exact(frame_rate));
+        self.frame_rate = Some(ConstrainU32Range::exact(frame_rate));
     }
 
     /// Sets an ideal [frameRate][1] constraint.
     ///
     /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
     pub const fn ideal_frame_rate(&mut self, frame_rate: u32) {
-        self.frame_rate = Some(ConstrainU32::Ideal(frame_rate));
+        self.frame_rate = Some(ConstrainU32Range::ideal(frame_rate));
+    }
+
+    /// Sets a [height][1] constraint as a range of optional `min`, `ideal` and
+    /// `max` values.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#def-constraint-height
+    pub const fn height_range(
+        &mut self,
+        min: Option<u32>,
+        ideal: Option<u32>,
+        max: Option<u32>,
+    ) {
+        self.height = Some(ConstrainU32Range::new(min, ideal, max));
+    }
+
+    /// Sets a [width][1] constraint as a range of optional `min`, `ideal` and
+    /// `max` values.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#def-constraint-width
+    pub const fn width_range(
+        &mut self,
+        min: Option<u32>,
+        ideal: Option<u32>,
+        max: Option<u32>,
+    ) {
+        self.width = Some(ConstrainU32Range::new(min, ideal, max));
+    }
+
+    /// Sets a [frameRate][1] constraint as a range of optional `min`, `ideal`
+    /// and `max` values.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
+    pub const fn frame_rate_range(
+        &mut self,
+        min: Option<u32>,
+        ideal: Option<u32>,
+        max: Option<u32>,
+    ) {
+        self.frame_rate = Some(ConstrainU32Range::new(min, ideal, max));
     }
 
     /// Returns an importance of this [`DisplayVideoTrackConstraints`].
@@ -3,10 +3,12 @@
 use std::{
     cell::RefCell,
     collections::HashMap,
+    mem,
     rc::{Rc, Weak},
 };
 
 use derive_more::with_trait::{Display, From};
+use futures::future;
 use medea_client_api_proto::MediaSourceKind;
 use tracerr::Traced;
 
@@ -68,6 +70,11 @@ pub enum InitLocalTracksError {
     GetDisplayMediaFailed(#[cause] GetDisplayMediaError),
 }
 
+/// Kind of media failed to be acquired by the
+/// [`MediaManager::get_tracks_best_effort()`], along with the reason.
+pub type FailedMediaKind =
+    (MediaKind, MediaSourceKind, Traced<InitLocalTracksError>);
+
 /// Error returned from the [`MediaManagerHandle::set_output_audio_id`] method.
 ///
 /// Occurs if the provided audio output device ID is incorrect.
@@ -180,6 +187,18 @@ struct InnerMediaManager {
     /// Obtained tracks storage
     tracks: RefCell<HashMap<String, Weak<local::Track>>>,
 
+    /// Pre-warmed [`local::Track`]s kept alive until they're either picked up
+    /// by a [`InnerMediaManager::get_tracks()`] call or released.
+    prewarmed: RefCell<Vec<Rc<local::Track>>>,
+
+    /// Externally owned [`local::Track`]s used instead of making
+    /// [getUserMedia()][1]/[getDisplayMedia()][2] requests for their kinds,
+    /// along with indicators whether they've been handed out already.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediadevices-getusermedia
+    /// [2]: https://w3.org/TR/screen-capture#dom-mediadevices-getdisplaymedia
+    external: RefCell<Vec<(Rc<local::Track>, bool)>>,
+
     /// Media devices platform controller.
     media_devices: platform::MediaDevices,
 }
@@ -226,11 +245,15 @@ impl InnerMediaManager {
         mut caps: MediaStreamSettings,
     ) -> Result<Vec<(Rc<local::Track>, bool)>, Traced<InitLocalTracksError>>
     {
-        let tracks_from_storage = self
-            .get_from_storage(&mut caps)
-            .await
-            .into_iter()
-            .map(|t| (t, false));
+        let external = self.get_external(&mut caps).await;
+        // Pre-warmed tracks haven't been exposed to anyone yet, so they're
+        // reported as new ones.
+        let tracks_from_storage = external.into_iter().chain(
+            self.get_from_storage(&mut caps).await.into_iter().map(|t| {
+                let is_prewarmed = self.take_prewarmed(&t);
+                (t, is_prewarmed)
+            }),
+        );
         match caps.into() {
             None => Ok(tracks_from_storage.collect()),
             Some(MultiSourceTracksConstraints::Display(caps)) => {
@@ -279,6 +302,189 @@ impl InnerMediaManager {
         }
     }
 
+    /// Obtains [`local::Track`]s based on the provided [`MediaStreamSettings`]
+    /// in a best-effort manner.
+    ///
+    /// If acquiring all the requested kinds at once fails, then each of them
+    /// is acquired separately (audio first), so a failure of one kind doesn't
+    /// prevent the others from being acquired.
+    ///
+    /// Returns the acquired [`local::Track`]s along with the
+    /// [`FailedMediaKind`]s.
+    async fn get_tracks_best_effort(
+        &self,
+        caps: MediaStreamSettings,
+    ) -> (Vec<(Rc<local::Track>, bool)>, Vec<FailedMediaKind>) {
+        let kinds: Vec<_> = [
+            (
+                caps.is_audio_enabled(),
+                MediaKind::Audio,
+                MediaSourceKind::Device,
+            ),
+            (
+                caps.is_device_video_enabled(),
+                MediaKind::Video,
+                MediaSourceKind::Device,
+            ),
+            (
+                caps.is_display_video_enabled(),
+                MediaKind::Video,
+                MediaSourceKind::Display,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, kind, source)| enabled.then_some((kind, source)))
+        .collect();
+
+        let err = match self.get_tracks(caps.clone()).await {
+            Ok(tracks) => return (tracks, Vec::new()),
+            Err(e) => e,
+        };
+        if kinds.len() < 2 {
+            let failed = kinds
+                .into_iter()
+                .map(|(kind, source)| (kind, source, err.clone()))
+                .collect();
+            return (Vec::new(), failed);
+        }
+
+        let mut tracks = Vec::new();
+        let mut failed = Vec::new();
+        for (kind, source_kind) in kinds {
+            let mut kind_caps = caps.clone();
+            kind_caps.set_audio_publish(kind == MediaKind::Audio);
+            kind_caps.set_video_publish(false, None);
+            if kind == MediaKind::Video {
+                kind_caps.set_video_publish(true, Some(source_kind));
+            }
+            match self.get_tracks(kind_caps).await {
+                Ok(kind_tracks) => tracks.extend(kind_tracks),
+                Err(e) => failed.push((kind, source_kind, e)),
+            }
+        }
+
+        (tracks, failed)
+    }
+
+    /// Acquires [`local::Track`]s based on the provided [`MediaStreamSettings`]
+    /// and keeps them alive, so the following [`InnerMediaManager::get_tracks()`]
+    /// calls reuse them instead of making new
+    /// [getUserMedia()][1]/[getDisplayMedia()][2] requests.
+    ///
+    /// # Errors
+    ///
+    /// See [`InnerMediaManager::get_tracks()`] for details.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediadevices-getusermedia
+    /// [2]: https://w3.org/TR/screen-capture#dom-mediadevices-getdisplaymedia
+    async fn prewarm(
+        &self,
+        caps: MediaStreamSettings,
+    ) -> Result<(), Traced<InitLocalTracksError>> {
+        let tracks = self.get_tracks(caps).await?;
+        self.prewarmed.borrow_mut().extend(tracks.into_iter().map(|(t, _)| t));
+        Ok(())
+    }
+
+    /// Removes the provided [`local::Track`] from the pre-warmed ones,
+    /// returning `true` if it was there.
+    fn take_prewarmed(&self, track: &Rc<local::Track>) -> bool {
+        let mut prewarmed = self.prewarmed.borrow_mut();
+        let len = prewarmed.len();
+        prewarmed.retain(|t| !Rc::ptr_eq(t, track));
+        prewarmed.len() != len
+    }
+
+    /// Releases all the pre-warmed [`local::Track`]s, so they're stopped unless
+    /// used somewhere else.
+    fn release_prewarmed(&self) {
+        drop(self.prewarmed.take());
+    }
+
+    /// Replaces the externally owned [`local::Track`]s of this
+    /// [`InnerMediaManager`] with the provided [`platform::MediaStreamTrack`]s.
+    fn set_external_tracks(
+        &self,
+        tracks: Vec<(platform::MediaStreamTrack, MediaSourceKind)>,
+    ) {
+        drop(
+            self.external.replace(
+                tracks
+                    .into_iter()
+                    .map(|(t, kind)| {
+                        (Rc::new(local::Track::new_external(t, kind)), false)
+                    })
+                    .collect(),
+            ),
+        );
+    }
+
+    /// Picks the `live` externally owned [`local::Track`]s of the kinds
+    /// requested by the provided [`MediaStreamSettings`], disabling these
+    /// kinds in it, so no [getUserMedia()][1]/[getDisplayMedia()][2] requests
+    /// are made for them.
+    ///
+    /// Each picked track is reported as a new one on its first pick only.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediadevices-getusermedia
+    /// [2]: https://w3.org/TR/screen-capture#dom-mediadevices-getdisplaymedia
+    async fn get_external(
+        &self,
+        caps: &mut MediaStreamSettings,
+    ) -> Vec<(Rc<local::Track>, bool)> {
+        let external: Vec<_> =
+            self.external.borrow().iter().map(|(t, _)| Rc::clone(t)).collect();
+
+        let mut tracks = Vec::new();
+        for track in external {
+            let is_requested = match (track.kind(), track.media_source_kind()) {
+                (MediaKind::Audio, _) => caps.is_audio_enabled(),
+                (MediaKind::Video, MediaSourceKind::Device) => {
+                    caps.is_device_video_enabled()
+                }
+                (MediaKind::Video, MediaSourceKind::Display) => {
+                    caps.is_display_video_enabled()
+                }
+            };
+            if !is_requested
+                || track.platform_track().ready_state().await
+                    != MediaStreamTrackState::Live
+            {
+                continue;
+            }
+
+            match track.kind() {
+                MediaKind::Audio => caps.set_audio_publish(false),
+                MediaKind::Video => caps
+                    .set_video_publish(false, Some(track.media_source_kind())),
+            }
+            let is_new = self
+                .external
+                .borrow_mut()
+                .iter_mut()
+                .find(|(t, _)| Rc::ptr_eq(t, &track))
+                .is_some_and(|(_, is_used)| !mem::replace(is_used, true));
+            tracks.push((track, is_new));
+        }
+
+        tracks
+    }
+
+    /// [Stops][1] all the [`local::Track`]s acquired by this
+    /// [`InnerMediaManager`], regardless of whether they're still used.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack-stop
+    async fn stop_tracks(&self) {
+        self.release_prewarmed();
+        let tracks: Vec<_> = self
+            .tracks
+            .borrow_mut()
+            .drain()
+            .filter_map(|(_, t)| t.upgrade())
+            .collect();
+        drop(future::join_all(tracks.iter().map(|t| t.stop())).await);
+    }
+
     /// Tries to find [`local::Track`]s that satisfies [`MediaStreamSettings`],
     /// from tracks that were acquired earlier to avoid redundant
     /// [getUserMedia()][1]/[getDisplayMedia()][2] calls.
@@ -485,6 +691,45 @@ impl MediaManager {
         self.0.get_tracks(caps.into()).await.map_err(tracerr::wrap!())
     }
 
+    /// Obtains [`local::Track`]s based on the provided [`MediaStreamSettings`]
+    /// treating each requested kind as a best-effort one, so a failure to
+    /// acquire, for example, video doesn't prevent audio from being acquired.
+    ///
+    /// Returns the acquired [`local::Track`]s along with the
+    /// [`FailedMediaKind`]s describing which kinds failed and why.
+    pub async fn get_tracks_best_effort<I: Into<MediaStreamSettings>>(
+        &self,
+        caps: I,
+    ) -> (Vec<(Rc<local::Track>, bool)>, Vec<FailedMediaKind>) {
+        self.0.get_tracks_best_effort(caps.into()).await
+    }
+
+    /// [Stops][1] all the [`local::Track`]s acquired by this [`MediaManager`],
+    /// regardless of whether they're still used.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack-stop
+    pub async fn stop_tracks(&self) {
+        self.0.stop_tracks().await;
+    }
+
+    /// Makes this [`MediaManager`] use the provided application-owned
+    /// [`platform::MediaStreamTrack`]s as the source of media of their kinds,
+    /// so no [getUserMedia()][1]/[getDisplayMedia()][2] requests are made for
+    /// these kinds.
+    ///
+    /// Replaces any external [`platform::MediaStreamTrack`]s set before. The
+    /// provided [`platform::MediaStreamTrack`]s are never stopped by this
+    /// [`MediaManager`].
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediadevices-getusermedia
+    /// [2]: https://w3.org/TR/screen-capture#dom-mediadevices-getdisplaymedia
+    pub fn set_external_tracks(
+        &self,
+        tracks: Vec<(platform::MediaStreamTrack, MediaSourceKind)>,
+    ) {
+        self.0.set_external_tracks(tracks);
+    }
+
     /// Instantiates a new [`MediaManagerHandle`] for external usage.
     #[must_use]
     pub fn new_handle(&self) -> MediaManagerHandle {
@@ -571,6 +816,96 @@ impl MediaManagerHandle {
             .map_err(tracerr::map_from_and_wrap!())
     }
 
+    /// Returns [`local::LocalMediaTrack`]s objects, built from the provided
+    /// [`MediaStreamSettings`], acquiring each requested kind in a best-effort
+    /// manner.
+    ///
+    /// See [`MediaManager::get_tracks_best_effort()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying [`MediaManagerHandle`] is dropped.
+    pub async fn init_local_tracks_best_effort(
+        &self,
+        caps: MediaStreamSettings,
+    ) -> Result<
+        (Vec<local::LocalMediaTrack>, Vec<FailedMediaKind>),
+        Traced<HandleDetachedError>,
+    > {
+        let this = self
+            .0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(HandleDetachedError))?;
+        let (tracks, failed) = this.get_tracks_best_effort(caps).await;
+        Ok((
+            tracks
+                .into_iter()
+                .map(|(t, _)| local::LocalMediaTrack::new(t))
+                .collect(),
+            failed,
+        ))
+    }
+
+    /// Pre-acquires [`local::Track`]s based on the provided
+    /// [`MediaStreamSettings`] and holds them, so the following media
+    /// acquisitions (including the ones made by [`Room`]s) reuse them without
+    /// waiting for device permissions and warm-up.
+    ///
+    /// Each pre-warmed track is handed over to the first acquisition it
+    /// satisfies, so it's stopped once it's not used anymore.
+    ///
+    /// # Errors
+    ///
+    /// See [`InitLocalTracksError`] for details.
+    ///
+    /// [`Room`]: crate::room::Room
+    pub async fn prewarm(
+        &self,
+        caps: MediaStreamSettings,
+    ) -> Result<(), Traced<InitLocalTracksError>> {
+        let this = self
+            .0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(InitLocalTracksError::Detached))?;
+        this.prewarm(caps).await.map_err(tracerr::wrap!())
+    }
+
+    /// Releases all the [`local::Track`]s pre-warmed via the
+    /// [`MediaManagerHandle::prewarm()`] and not used yet, stopping them.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying [`MediaManagerHandle`] is dropped.
+    pub fn release_prewarmed(&self) -> Result<(), Traced<HandleDetachedError>> {
+        let this = self
+            .0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(HandleDetachedError))?;
+        this.release_prewarmed();
+        Ok(())
+    }
+
+    /// Makes the underlying [`MediaManager`] use the provided application-owned
+    /// [`platform::MediaStreamTrack`]s instead of acquiring its own ones of the
+    /// same kinds.
+    ///
+    /// See [`MediaManager::set_external_tracks()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying [`MediaManagerHandle`] is dropped.
+    pub fn set_external_tracks(
+        &self,
+        tracks: Vec<(platform::MediaStreamTrack, MediaSourceKind)>,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        let this = self
+            .0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(HandleDetachedError))?;
+        this.set_external_tracks(tracks);
+        Ok(())
+    }
+
     /// Switches the current audio output device to the device with the provided
     /// `device_id`.
     ///
@@ -4,6 +4,7 @@
 
 pub mod constraints;
 mod manager;
+pub mod recorder;
 pub mod track;
 
 use derive_more::with_trait::Display;
@@ -19,14 +20,15 @@ pub use self::{
         TrackConstraints, VideoSource, VideoTrackConstraints,
     },
     manager::{
-        EnumerateDevicesError, EnumerateDisplaysError, GetDisplayMediaError,
-        GetUserMediaError, HandleDetachedError, InitLocalTracksError,
-        InvalidOutputAudioDeviceIdError, MediaManager, MediaManagerHandle,
-        MicVolumeError,
+        EnumerateDevicesError, EnumerateDisplaysError, FailedMediaKind,
+        GetDisplayMediaError, GetUserMediaError, HandleDetachedError,
+        InitLocalTracksError, InvalidOutputAudioDeviceIdError, MediaManager,
+        MediaManagerHandle, MicVolumeError,
     },
+    recorder::Recorder,
     track::{
         AudioLevelError, AudioProcessingError, MediaSourceKind,
-        MediaStreamTrackState, remote::MediaDirection,
+        MediaStreamTrackState, MediaTrackSettings, remote::MediaDirection,
     },
 };
 
@@ -0,0 +1,248 @@
+//! Local recording of [`local::Track`]s and [`remote::Track`]s.
+
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use futures::{StreamExt as _, channel::mpsc, future, stream::LocalBoxStream};
+use tracerr::Traced;
+
+use crate::{
+    media::{
+        MediaKind,
+        track::{local, remote},
+    },
+    platform,
+    utils::TaskHandle,
+};
+
+/// Recorder composing the added [`local::Track`]s and [`remote::Track`]s
+/// into a single recording.
+///
+/// The underlying [`platform::MediaRecorder`] is created on the first
+/// [`Recorder::start()`] call, so all the recorded tracks should be added
+/// before it.
+#[derive(Debug)]
+pub struct Recorder {
+    /// [`platform::MediaRecorderOptions`] to create the underlying
+    /// [`platform::MediaRecorder`] with.
+    options: platform::MediaRecorderOptions,
+
+    /// [`local::LocalMediaTrack`]s to be recorded, kept alive while being
+    /// recorded.
+    local_tracks: RefCell<Vec<local::LocalMediaTrack>>,
+
+    /// [`remote::Track`]s to be recorded, kept alive while being recorded.
+    remote_tracks: RefCell<Vec<remote::Track>>,
+
+    /// Underlying [`platform::MediaRecorder`], if recording has been started
+    /// already.
+    inner: RefCell<Option<platform::MediaRecorder>>,
+
+    /// Subscribers to the recorded data chunks.
+    data_subs: Rc<RefCell<Vec<mpsc::UnboundedSender<Vec<u8>>>>>,
+
+    /// [`TaskHandle`] of the task forwarding the recorded data chunks of the
+    /// underlying [`platform::MediaRecorder`] to the subscribers.
+    data_task: RefCell<Option<TaskHandle>>,
+}
+
+impl Recorder {
+    /// Creates a new [`Recorder`] with the provided
+    /// [`platform::MediaRecorderOptions`].
+    #[must_use]
+    pub fn new(options: platform::MediaRecorderOptions) -> Self {
+        Self {
+            options,
+            local_tracks: RefCell::default(),
+            remote_tracks: RefCell::default(),
+            inner: RefCell::default(),
+            data_subs: Rc::default(),
+            data_task: RefCell::default(),
+        }
+    }
+
+    /// Adds the provided [`local::LocalMediaTrack`] to be recorded.
+    ///
+    /// Has no effect on an already started recording.
+    pub fn add_local_track(&self, track: local::LocalMediaTrack) {
+        self.local_tracks.borrow_mut().push(track);
+    }
+
+    /// Adds the provided [`remote::Track`] to be recorded.
+    ///
+    /// Has no effect on an already started recording.
+    pub fn add_remote_track(&self, track: remote::Track) {
+        self.remote_tracks.borrow_mut().push(track);
+    }
+
+    /// Starts recording.
+    ///
+    /// If a `timeslice` is provided, then the recorded data is emitted into
+    /// [`Recorder::on_data()`] streams in chunks of the provided duration,
+    /// otherwise it's emitted as a single chunk once the recording is stopped.
+    ///
+    /// # Errors
+    ///
+    /// With [`platform::MediaRecorderError::Unsupported`] if recording is not
+    /// supported by the current platform.
+    ///
+    /// With [`platform::MediaRecorderError::MultipleVideoTracks`] if more
+    /// than one video track has been added.
+    ///
+    /// With [`platform::MediaRecorderError::CreateFailed`] if the platform
+    /// rejects the added tracks or the [`platform::MediaRecorderOptions`].
+    ///
+    /// With [`platform::MediaRecorderError::InvalidState`] if the recording
+    /// is started already.
+    pub fn start(
+        &self,
+        timeslice: Option<Duration>,
+    ) -> Result<(), Traced<platform::MediaRecorderError>> {
+        if self.inner.borrow().is_none() {
+            let recorder = self.create_recorder()?;
+            self.spawn_data_task(recorder.events());
+            _ = self.inner.replace(Some(recorder));
+        }
+
+        self.inner
+            .borrow()
+            .as_ref()
+            .map_or(Ok(()), |r| r.start(timeslice))
+            .map_err(tracerr::wrap!())
+    }
+
+    /// Pauses recording.
+    ///
+    /// # Errors
+    ///
+    /// With [`platform::MediaRecorderError::NotStarted`] if the recording
+    /// has never been started.
+    ///
+    /// With [`platform::MediaRecorderError::InvalidState`] if the recording
+    /// is stopped.
+    pub fn pause(&self) -> Result<(), Traced<platform::MediaRecorderError>> {
+        self.inner
+            .borrow()
+            .as_ref()
+            .ok_or_else(|| {
+                tracerr::new!(platform::MediaRecorderError::NotStarted)
+            })?
+            .pause()
+            .map_err(tracerr::wrap!())
+    }
+
+    /// Resumes paused recording.
+    ///
+    /// # Errors
+    ///
+    /// With [`platform::MediaRecorderError::NotStarted`] if the recording
+    /// has never been started.
+    ///
+    /// With [`platform::MediaRecorderError::InvalidState`] if the recording
+    /// is stopped.
+    pub fn resume(&self) -> Result<(), Traced<platform::MediaRecorderError>> {
+        self.inner
+            .borrow()
+            .as_ref()
+            .ok_or_else(|| {
+                tracerr::new!(platform::MediaRecorderError::NotStarted)
+            })?
+            .resume()
+            .map_err(tracerr::wrap!())
+    }
+
+    /// Stops recording, resolving once all the recorded data is emitted into
+    /// [`Recorder::on_data()`] streams.
+    ///
+    /// Resolves immediately if the recording is not started or is stopped
+    /// already.
+    ///
+    /// # Errors
+    ///
+    /// With [`platform::MediaRecorderError::InvalidState`] if the platform
+    /// fails to stop recording.
+    pub async fn stop(
+        &self,
+    ) -> Result<(), Traced<platform::MediaRecorderError>> {
+        let mut stopped = {
+            let inner = self.inner.borrow();
+            let Some(recorder) = inner.as_ref().filter(|r| r.is_active())
+            else {
+                return Ok(());
+            };
+
+            let stopped = recorder.events().filter(|e| {
+                future::ready(matches!(
+                    e,
+                    platform::MediaRecorderEvent::Stopped
+                ))
+            });
+            recorder.stop().map_err(tracerr::wrap!())?;
+            stopped
+        };
+        drop(stopped.next().await);
+
+        Ok(())
+    }
+
+    /// Returns a [`LocalBoxStream`] of the recorded data chunks.
+    ///
+    /// Concatenated in order, the chunks form the whole recording.
+    #[must_use]
+    pub fn on_data(&self) -> LocalBoxStream<'static, Vec<u8>> {
+        let (tx, rx) = mpsc::unbounded();
+        self.data_subs.borrow_mut().push(tx);
+        Box::pin(rx)
+    }
+
+    /// Creates a new [`platform::MediaRecorder`] of the added tracks.
+    ///
+    /// # Errors
+    ///
+    /// See [`Recorder::start()`] for details.
+    fn create_recorder(
+        &self,
+    ) -> Result<platform::MediaRecorder, Traced<platform::MediaRecorderError>>
+    {
+        let local_tracks = self.local_tracks.borrow();
+        let remote_tracks = self.remote_tracks.borrow();
+        let tracks: Vec<_> = local_tracks
+            .iter()
+            .map(local::LocalMediaTrack::get_track)
+            .chain(remote_tracks.iter().map(remote::Track::get_track))
+            .collect();
+
+        // `MediaRecorder` records only the first video track of a stream.
+        let video_tracks =
+            tracks.iter().filter(|t| t.kind() == MediaKind::Video).count();
+        if video_tracks > 1 {
+            return Err(tracerr::new!(
+                platform::MediaRecorderError::MultipleVideoTracks
+            ));
+        }
+
+        platform::MediaRecorder::new(&tracks, &self.options)
+            .map_err(tracerr::wrap!())
+    }
+
+    /// Spawns a task forwarding the recorded data chunks from the provided
+    /// [`platform::MediaRecorderEvent`]s to the [`Recorder::on_data()`]
+    /// streams.
+    fn spawn_data_task(
+        &self,
+        mut events: LocalBoxStream<'static, platform::MediaRecorderEvent>,
+    ) {
+        let subs = Rc::clone(&self.data_subs);
+        let (fut, abort) = future::abortable(async move {
+            while let Some(event) = events.next().await {
+                if let platform::MediaRecorderEvent::Data(data) = event {
+                    subs.borrow_mut()
+                        .retain(|sub| sub.unbounded_send(data.clone()).is_ok());
+                }
+            }
+        });
+        platform::spawn(async move {
+            _ = fut.await.ok();
+        });
+        drop(self.data_task.replace(Some(abort.into())));
+    }
+}
@@ -4,16 +4,20 @@
 //! [1]: https://w3.org/TR/mediacapture-streams#dom-mediadevices-getusermedia
 //! [2]: https://w3.org/TR/screen-capture/#dom-mediadevices-getdisplaymedia
 
-use std::rc::Rc;
+use std::{cell::Cell, rc::Rc, time::Duration};
 
 use derive_more::with_trait::AsRef;
+use futures::{
+    FutureExt as _, StreamExt as _,
+    future::{self, Either, LocalBoxFuture},
+};
 use medea_client_api_proto as proto;
 use tracerr::Traced;
 
 use crate::{
     media::{
         AudioLevelError, AudioProcessingError, MediaKind, MediaSourceKind,
-        MediaStreamTrackState, NoiseSuppressionLevel,
+        MediaStreamTrackState, MediaTrackSettings, NoiseSuppressionLevel,
     },
     platform,
 };
@@ -22,7 +26,7 @@ use crate::{
 /// [getUserMedia()][1]/[getDisplayMedia()][2] request.
 ///
 /// Underlying [`platform::MediaStreamTrack`] is stopped on this [`Track`]'s
-/// [`Drop`].
+/// [`Drop`], unless it's [external][`Track::new_external()`].
 ///
 /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediadevices-getusermedia
 /// [2]: https://w3.org/TR/screen-capture/#dom-mediadevices-getdisplaymedia
@@ -42,6 +46,14 @@ pub struct Track {
     ///
     /// This field is used only for holding strong reference to the parent.
     _parent: Option<Rc<Self>>,
+
+    /// Indicator whether video of this [`Track`] is intended to be mirrored.
+    mirrored: Cell<bool>,
+
+    /// Indicator whether the underlying [`platform::MediaStreamTrack`] is
+    /// owned by an application, so mustn't be stopped on this [`Track`]'s
+    /// [`Drop`].
+    external: bool,
 }
 
 impl Track {
@@ -52,7 +64,39 @@ impl Track {
         track: platform::MediaStreamTrack,
         source_kind: proto::MediaSourceKind,
     ) -> Self {
-        Self { inner: track, source_kind, _parent: None }
+        Self {
+            inner: track,
+            source_kind,
+            _parent: None,
+            mirrored: Cell::new(false),
+            external: false,
+        }
+    }
+
+    /// Builds a new [`Track`] from the provided [`platform::MediaStreamTrack`]
+    /// owned by an application.
+    ///
+    /// Unlike [`Track::new()`], the underlying [`platform::MediaStreamTrack`]
+    /// isn't stopped on this [`Track`]'s [`Drop`].
+    #[must_use]
+    pub const fn new_external(
+        track: platform::MediaStreamTrack,
+        source_kind: proto::MediaSourceKind,
+    ) -> Self {
+        Self {
+            inner: track,
+            source_kind,
+            _parent: None,
+            mirrored: Cell::new(false),
+            external: true,
+        }
+    }
+
+    /// Indicates whether the underlying [`platform::MediaStreamTrack`] of this
+    /// [`Track`] is owned by an application.
+    #[must_use]
+    pub const fn is_external(&self) -> bool {
+        self.external
     }
 
     /// Returns the underlying [`platform::MediaStreamTrack`] of this [`Track`].
@@ -79,6 +123,26 @@ impl Track {
         self.inner.id()
     }
 
+    /// Returns the current [settings][1] of the underlying
+    /// [MediaStreamTrack][2].
+    ///
+    /// Settings are read from the underlying [MediaStreamTrack][2] on each
+    /// call, so reflect the values actually applied by a user agent, which
+    /// may differ from the requested constraints.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediatracksettings
+    /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    #[must_use]
+    pub fn settings(&self) -> MediaTrackSettings {
+        MediaTrackSettings {
+            device_id: self.inner.device_id(),
+            width: self.inner.width(),
+            height: self.inner.height(),
+            frame_rate: self.inner.frame_rate(),
+            facing_mode: self.inner.facing_mode(),
+        }
+    }
+
     /// Returns this [`Track`]'s media source kind.
     #[must_use]
     pub const fn media_source_kind(&self) -> proto::MediaSourceKind {
@@ -100,12 +164,119 @@ impl Track {
         self.inner.on_ended(Some(move || callback.call0()));
     }
 
+    /// Sets whether video of this [`Track`] is intended to be mirrored (e.g.
+    /// for a front camera self-view).
+    ///
+    /// Frames of the underlying [`platform::MediaStreamTrack`] are not
+    /// transformed, since none of the supported platforms exposes frame
+    /// transforms yet, so the renderer is expected to apply mirroring on its
+    /// own (e.g. via CSS `transform: scaleX(-1)`) according to
+    /// [`Track::is_mirrored()`].
+    pub fn set_mirror(&self, mirror: bool) {
+        self.mirrored.set(mirror);
+    }
+
+    /// Indicates whether video of this [`Track`] is intended to be mirrored.
+    #[must_use]
+    pub fn is_mirrored(&self) -> bool {
+        self.mirrored.get()
+    }
+
     /// Returns a [`MediaStreamTrackState::Live`] if this [`Track`] is active,
     /// or a [`MediaStreamTrackState::Ended`] if it has ended.
     pub async fn state(&self) -> MediaStreamTrackState {
         self.inner.ready_state().await
     }
 
+    /// Waits until this [`Track`] is ready to be rendered.
+    ///
+    /// [`Track`] is considered ready once it's [live][1] and not [muted][2],
+    /// so it's actually producing media data. Resolves immediately if this
+    /// [`Track`] is ready already.
+    ///
+    /// Returns a [`MediaStreamTrackState::Ended`] if this [`Track`] has ended
+    /// already, or ends while being [muted][2].
+    ///
+    /// __NOTE__: Only supported on web. Native tracks are never [muted][2],
+    /// so there it resolves immediately.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediastreamtrack-readystate
+    /// [2]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack-muted
+    pub async fn ready(&self) -> MediaStreamTrackState {
+        // Subscribe before checking, so unmuting can't be missed in between.
+        let unmuted_or_ended = self.inner.on_unmute_or_ended();
+
+        let state = self.inner.ready_state().await;
+        if state == MediaStreamTrackState::Ended || !self.inner.muted() {
+            return state;
+        }
+        unmuted_or_ended.await;
+
+        self.inner.ready_state().await
+    }
+
+    /// Checks whether this [`Track`] is actually producing media data, so
+    /// isn't silent (for audio) or frozen (for video).
+    ///
+    /// Samples the audio level (for audio) or the number of delivered frames
+    /// (for video) of this [`Track`] and resolves to `true` as soon as any
+    /// activity is observed, or to `false` if nothing is observed during the
+    /// provided `timeout`.
+    ///
+    /// __NOTE__: Sampling is not supported on native platforms, since they
+    /// expose neither audio levels nor delivered frames of a [`Track`]. There
+    /// it falls back to waiting until this [`Track`] is
+    /// [`ready`](Track::ready), so a silent or frozen [`Track`] is still
+    /// reported as producing.
+    pub async fn is_producing(&self, timeout: Duration) -> bool {
+        /// Audio level (in the `[0;100]` range) considered as silence.
+        const SILENCE_AUDIO_LEVEL: i32 = 0;
+
+        /// Interval of sampling delivered frames of a video [`Track`].
+        const FRAMES_SAMPLING_INTERVAL: Duration = Duration::from_millis(100);
+
+        if self.inner.ready_state().await == MediaStreamTrackState::Ended {
+            return false;
+        }
+
+        let sampled: Option<LocalBoxFuture<'_, bool>> = match self.kind() {
+            MediaKind::Audio => self.inner.audio_level_stream().map(|levels| {
+                levels
+                    .any(|level| future::ready(level > SILENCE_AUDIO_LEVEL))
+                    .boxed_local()
+            }),
+            MediaKind::Video => self.inner.delivered_frames().map(|initial| {
+                async move {
+                    loop {
+                        platform::delay_for(FRAMES_SAMPLING_INTERVAL).await;
+                        if self
+                            .inner
+                            .delivered_frames()
+                            .is_none_or(|frames| frames > initial)
+                        {
+                            return true;
+                        }
+                    }
+                }
+                .boxed_local()
+            }),
+        };
+        let producing = sampled.unwrap_or_else(|| {
+            async move { self.ready().await == MediaStreamTrackState::Live }
+                .boxed_local()
+        });
+
+        match future::select(
+            producing,
+            platform::delay_for(timeout).boxed_local(),
+        )
+        .await
+        {
+            Either::Left((producing, _)) => producing,
+            Either::Right(_) => false,
+        }
+    }
+
     /// Forks this [`Track`].
     ///
     /// Creates a new [`Track`] from this [`Track`]'s
@@ -121,6 +292,8 @@ impl Track {
             inner: track,
             source_kind: self.source_kind,
             _parent: Some(parent),
+            mirrored: Cell::new(self.mirrored.get()),
+            external: false,
         }
     }
 
@@ -134,7 +307,9 @@ impl Track {
 
 impl Drop for Track {
     fn drop(&mut self) {
-        platform::spawn(Box::pin(self.inner.stop()));
+        if !self.external {
+            platform::spawn(Box::pin(self.inner.stop()));
+        }
     }
 }
 
@@ -167,6 +342,21 @@ impl LocalMediaTrack {
         self.0.kind()
     }
 
+    /// Sets whether video of this [`LocalMediaTrack`] is intended to be
+    /// mirrored.
+    ///
+    /// See [`Track::set_mirror()`] for details.
+    pub fn set_mirror(&self, mirror: bool) {
+        self.0.set_mirror(mirror);
+    }
+
+    /// Indicates whether video of this [`LocalMediaTrack`] is intended to be
+    /// mirrored.
+    #[must_use]
+    pub fn is_mirrored(&self) -> bool {
+        self.0.is_mirrored()
+    }
+
     /// Sets a callback to invoke when this [`LocalMediaTrack`] is ended.
     pub fn on_ended(&self, callback: platform::Function<()>) {
         self.0.on_ended(callback);
@@ -178,6 +368,23 @@ impl LocalMediaTrack {
         self.0.state().await
     }
 
+    /// Waits until this [`LocalMediaTrack`] is ready to be rendered, meaning
+    /// it's live and actually producing media data.
+    ///
+    /// See [`Track::ready()`] for details.
+    pub async fn ready(&self) -> MediaStreamTrackState {
+        self.0.ready().await
+    }
+
+    /// Checks whether this [`LocalMediaTrack`] is actually producing media
+    /// data within the provided `timeout`.
+    ///
+    /// Not supported on native platforms, where it only checks whether this
+    /// [`LocalMediaTrack`] is live. See [`Track::is_producing()`] for details.
+    pub async fn is_producing(&self, timeout: Duration) -> bool {
+        self.0.is_producing(timeout).await
+    }
+
     /// Indicates whether an `OnAudioLevelChangedCallback` is supported for this
     /// [`LocalMediaTrack`].
     #[must_use]
@@ -392,6 +599,14 @@ impl LocalMediaTrack {
             .map_err(tracerr::wrap!())
     }
 
+    /// Returns the current settings of this [`LocalMediaTrack`].
+    ///
+    /// See [`Track::settings()`] for details.
+    #[must_use]
+    pub fn settings(&self) -> MediaTrackSettings {
+        self.0.settings()
+    }
+
     /// Returns a [`MediaSourceKind::Device`] if this [`LocalMediaTrack`] is
     /// sourced from some device (webcam/microphone), or
     /// a [`MediaSourceKind::Display`] if it's captured via
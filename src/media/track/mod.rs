@@ -8,7 +8,7 @@ pub mod remote;
 use derive_more::{Display, From, Into};
 use medea_client_api_proto as proto;
 
-use crate::{platform, utils::Caused};
+use crate::{media::FacingMode, platform, utils::Caused};
 
 /// Error returned when trying to bind to local audio track level changes.
 #[derive(Caused, Clone, Debug, Display, From, Into)]
@@ -38,6 +38,44 @@ pub enum MediaStreamTrackState {
     Ended,
 }
 
+/// Snapshot of [MediaTrackSettings][1] of a [MediaStreamTrack][2].
+///
+/// [1]: https://w3.org/TR/mediacapture-streams#dom-mediatracksettings
+/// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MediaTrackSettings {
+    /// [`deviceId`][1] of the source of a [MediaStreamTrack][2].
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediatracksettings-deviceid
+    /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    pub device_id: Option<String>,
+
+    /// [`width`][1] of a video [MediaStreamTrack][2] in pixels.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediatracksettings-width
+    /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    pub width: Option<u32>,
+
+    /// [`height`][1] of a video [MediaStreamTrack][2] in pixels.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediatracksettings-height
+    /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    pub height: Option<u32>,
+
+    /// [`frameRate`][1] of a video [MediaStreamTrack][2] in frames per
+    /// second.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediatracksettings-framerate
+    /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    pub frame_rate: Option<f64>,
+
+    /// [`facingMode`][1] of a video [MediaStreamTrack][2].
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediatracksettings-facingmode
+    /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    pub facing_mode: Option<FacingMode>,
+}
+
 /// Media source type.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u8)]
@@ -18,8 +18,13 @@ struct Inner {
     /// Underlying platform-specific [`platform::MediaStreamTrack`].
     track: platform::MediaStreamTrack,
 
-    /// Underlying [`platform::MediaStreamTrack`] source kind.
-    media_source_kind: proto::MediaSourceKind,
+    /// [`MediaKind`] of this [`Track`] signalled by a media server.
+    kind: MediaKind,
+
+    /// Source kind of this [`Track`] signalled by a media server.
+    ///
+    /// [`None`] if it's not signalled (for audio tracks, for example).
+    source_kind: Option<proto::MediaSourceKind>,
 
     /// Callback to be invoked when this [`Track`] is muted.
     on_muted: platform::Callback<()>,
@@ -30,6 +35,24 @@ struct Inner {
     /// Callback to be invoked when this [`Track`] is stopped.
     on_stopped: platform::Callback<()>,
 
+    /// Callback to be invoked when this [`Track`] is paused by a media server.
+    on_paused: platform::Callback<()>,
+
+    /// Callback to be invoked when this [`Track`] is resumed by a media
+    /// server.
+    on_resumed: platform::Callback<()>,
+
+    /// Callback to be invoked when video of this [`Track`] freezes.
+    on_frozen: platform::Callback<()>,
+
+    /// Callback to be invoked when video of this [`Track`] recovers from
+    /// freezing.
+    on_unfrozen: platform::Callback<()>,
+
+    /// Callback to be invoked when playback volume of this [`Track`] is
+    /// changed.
+    on_volume_changed: platform::Callback<f64>,
+
     /// Callback to be invoked whenever this [`Track`]'s general
     /// [`MediaDirection`] is changed.
     #[cfg_attr(
@@ -50,6 +73,20 @@ struct Inner {
     /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack-muted
     /// [2]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack
     muted: ObservableCell<bool>,
+
+    /// Indicates whether forwarding of this track is paused by a media server.
+    ///
+    /// Updating this value fires `on_paused` or `on_resumed` callback.
+    paused: ObservableCell<bool>,
+
+    /// Indicates whether video of this track is frozen, i.e. no new frames
+    /// are received for a while.
+    ///
+    /// Updating this value fires `on_frozen` or `on_unfrozen` callback.
+    frozen: ObservableCell<bool>,
+
+    /// Playback volume of this [`Track`] in `0.0..=1.0` range.
+    volume: Cell<f64>,
 }
 
 /// Wrapper around a received remote [MediaStreamTrack][1].
@@ -62,12 +99,16 @@ impl Track {
     /// Creates a new [`Track`] spawning a listener for its [`enabled`][1] and
     /// [`muted`][2] properties changes.
     ///
+    /// The provided `kind` and `source_kind` are the ones signalled by a media
+    /// server for this [`Track`].
+    ///
     /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack-enabled
     /// [2]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack-muted
     #[must_use]
     pub fn new<T>(
         track: T,
-        media_source_kind: proto::MediaSourceKind,
+        kind: MediaKind,
+        source_kind: Option<proto::MediaSourceKind>,
         muted: bool,
         media_direction: MediaDirection,
     ) -> Self
@@ -77,13 +118,22 @@ impl Track {
         let track = platform::MediaStreamTrack::from(track);
         let track = Self(Rc::new(Inner {
             track,
-            media_source_kind,
+            kind,
+            source_kind,
             muted: ObservableCell::new(muted),
+            paused: ObservableCell::new(false),
+            frozen: ObservableCell::new(false),
             on_media_direction_changed: platform::Callback::default(),
             media_direction: Cell::new(media_direction),
             on_stopped: platform::Callback::default(),
             on_muted: platform::Callback::default(),
             on_unmuted: platform::Callback::default(),
+            on_paused: platform::Callback::default(),
+            on_resumed: platform::Callback::default(),
+            on_frozen: platform::Callback::default(),
+            on_unfrozen: platform::Callback::default(),
+            on_volume_changed: platform::Callback::default(),
+            volume: Cell::new(1.0),
         }));
 
         track.0.track.on_ended({
@@ -111,6 +161,38 @@ impl Track {
             }
         });
 
+        let mut paused_changes = track.0.paused.subscribe().skip(1).fuse();
+        platform::spawn({
+            let weak_inner = Rc::downgrade(&track.0);
+            async move {
+                while let Some(is_paused) = paused_changes.next().await {
+                    if let Some(inner) = weak_inner.upgrade() {
+                        if is_paused {
+                            inner.on_paused.call0();
+                        } else {
+                            inner.on_resumed.call0();
+                        }
+                    }
+                }
+            }
+        });
+
+        let mut frozen_changes = track.0.frozen.subscribe().skip(1).fuse();
+        platform::spawn({
+            let weak_inner = Rc::downgrade(&track.0);
+            async move {
+                while let Some(is_frozen) = frozen_changes.next().await {
+                    if let Some(inner) = weak_inner.upgrade() {
+                        if is_frozen {
+                            inner.on_frozen.call0();
+                        } else {
+                            inner.on_unfrozen.call0();
+                        }
+                    }
+                }
+            }
+        });
+
         track
     }
 
@@ -132,6 +214,34 @@ impl Track {
         self.0.muted.set(muted);
     }
 
+    /// Sets `paused` property on this [`Track`].
+    ///
+    /// Calls `on_paused` or `on_resumed` callback respectively.
+    pub fn set_paused(&self, paused: bool) {
+        self.0.paused.set(paused);
+    }
+
+    /// Sets `frozen` property on this [`Track`].
+    ///
+    /// Calls `on_frozen` or `on_unfrozen` callback respectively.
+    pub fn set_frozen(&self, frozen: bool) {
+        self.0.frozen.set(frozen);
+    }
+
+    /// Sets playback volume of this [`Track`], clamping it into `0.0..=1.0`
+    /// range.
+    ///
+    /// Applies the volume to the `HTMLMediaElement` this [`Track`] is attached
+    /// to (if any), and calls `on_volume_changed` callback, so the volume can
+    /// be applied by other renderers of this [`Track`] (e.g. a `GainNode` or a
+    /// Flutter widget).
+    pub fn set_volume(&self, volume: f64) {
+        let volume = volume.clamp(0.0, 1.0);
+        self.0.volume.set(volume);
+        self.0.track.set_volume(volume);
+        self.0.on_volume_changed.call1(volume);
+    }
+
     /// Returns [`id`][1] of the underlying [`platform::MediaStreamTrack`] of
     /// this [`Track`].
     ///
@@ -141,16 +251,30 @@ impl Track {
         self.0.track.id()
     }
 
-    /// Returns this [`Track`]'s kind (audio/video).
+    /// Returns this [`Track`]'s kind (audio/video), as signalled by a media
+    /// server.
     #[must_use]
     pub fn kind(&self) -> MediaKind {
-        self.0.track.kind()
+        self.0.kind
+    }
+
+    /// Returns this [`Track`]'s media source kind (device/display), as
+    /// signalled by a media server.
+    ///
+    /// Returns [`None`] if a media server doesn't signal it for this
+    /// [`Track`], which is the case for audio tracks.
+    #[must_use]
+    pub fn source_kind(&self) -> Option<MediaSourceKind> {
+        self.0.source_kind.map(Into::into)
     }
 
     /// Returns this [`Track`]'s media source kind.
+    ///
+    /// Falls back to [`MediaSourceKind::Device`] if it's not
+    /// [signalled][`Track::source_kind()`].
     #[must_use]
     pub fn media_source_kind(&self) -> MediaSourceKind {
-        self.0.media_source_kind.into()
+        self.source_kind().unwrap_or(MediaSourceKind::Device)
     }
 
     /// Stops this [`Track`] invoking an `on_stopped` callback if it's in a
@@ -185,6 +309,55 @@ impl Track {
         self.0.on_unmuted.set_func(callback);
     }
 
+    /// Indicates whether forwarding of this [`Track`] is paused by a media
+    /// server.
+    #[must_use]
+    pub fn paused(&self) -> bool {
+        self.0.paused.get()
+    }
+
+    /// Sets callback to invoke when this [`Track`] is paused by a media
+    /// server.
+    pub fn on_paused(&self, callback: platform::Function<()>) {
+        self.0.on_paused.set_func(callback);
+    }
+
+    /// Sets callback to invoke when this [`Track`] is resumed by a media
+    /// server.
+    pub fn on_resumed(&self, callback: platform::Function<()>) {
+        self.0.on_resumed.set_func(callback);
+    }
+
+    /// Indicates whether video of this [`Track`] is frozen, i.e. no new frames
+    /// are received for a while.
+    #[must_use]
+    pub fn frozen(&self) -> bool {
+        self.0.frozen.get()
+    }
+
+    /// Sets callback to invoke when video of this [`Track`] freezes.
+    pub fn on_frozen(&self, callback: platform::Function<()>) {
+        self.0.on_frozen.set_func(callback);
+    }
+
+    /// Sets callback to invoke when video of this [`Track`] recovers from
+    /// freezing.
+    pub fn on_unfrozen(&self, callback: platform::Function<()>) {
+        self.0.on_unfrozen.set_func(callback);
+    }
+
+    /// Returns playback volume of this [`Track`] in `0.0..=1.0` range.
+    #[must_use]
+    pub fn volume(&self) -> f64 {
+        self.0.volume.get()
+    }
+
+    /// Sets callback to invoke when playback volume of this [`Track`] is
+    /// changed.
+    pub fn on_volume_changed(&self, callback: platform::Function<f64>) {
+        self.0.on_volume_changed.set_func(callback);
+    }
+
     /// Sets callback to invoke when this [`Track`] is stopped.
     pub fn on_stopped(&self, callback: platform::Function<()>) {
         self.0.on_stopped.set_func(callback);
@@ -0,0 +1,432 @@
+//! Summary of the ICE candidates reported in [`platform::RtcStats`].
+
+use medea_client_api_proto::stats::{
+    KnownCandidateType, KnownIceCandidatePairState, NonExhaustive,
+    RtcIceCandidatePairStats, RtcIceCandidateStats, RtcStatsType,
+};
+
+use crate::{peer::IceCandidateType, platform};
+
+/// Numbers of ICE candidates of each [`IceCandidateType`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CandidateCounts {
+    /// Number of [`IceCandidateType::Host`] candidates.
+    pub host: usize,
+
+    /// Number of [`IceCandidateType::Srflx`] candidates.
+    pub srflx: usize,
+
+    /// Number of [`IceCandidateType::Prflx`] candidates.
+    pub prflx: usize,
+
+    /// Number of [`IceCandidateType::Relay`] candidates.
+    pub relay: usize,
+}
+
+impl CandidateCounts {
+    /// Counts the provided [`RtcIceCandidateStats`] in these
+    /// [`CandidateCounts`].
+    fn count(&mut self, stats: &RtcIceCandidateStats) {
+        match candidate_type(stats) {
+            Some(IceCandidateType::Host) => self.host += 1,
+            Some(IceCandidateType::Srflx) => self.srflx += 1,
+            Some(IceCandidateType::Prflx) => self.prflx += 1,
+            Some(IceCandidateType::Relay) => self.relay += 1,
+            None => (),
+        }
+    }
+}
+
+/// Returns [`IceCandidateType`] of the provided [`RtcIceCandidateStats`], if
+/// it's known.
+fn candidate_type(stats: &RtcIceCandidateStats) -> Option<IceCandidateType> {
+    match &stats.candidate_type {
+        NonExhaustive::Known(t) => Some(match t {
+            KnownCandidateType::Host => IceCandidateType::Host,
+            KnownCandidateType::Srlfx => IceCandidateType::Srflx,
+            KnownCandidateType::Prflx => IceCandidateType::Prflx,
+            KnownCandidateType::Relay => IceCandidateType::Relay,
+        }),
+        NonExhaustive::Unknown(t) => t.parse().ok(),
+    }
+}
+
+/// Returns the selected ICE candidate pair reported in the provided
+/// [`platform::RtcStats`], if any.
+///
+/// The pair is looked up by the [selectedCandidatePairId][1] of the transport,
+/// falling back to the first nominated and succeeded one for the platforms not
+/// reporting it.
+///
+/// [1]: https://w3.org/TR/webrtc-stats#dom-rtctransportstats-selectedcandidatepairid
+fn selected_pair(
+    stats: &platform::RtcStats,
+) -> Option<&RtcIceCandidatePairStats> {
+    let selected_id = stats.0.iter().find_map(|stat| {
+        if let RtcStatsType::Transport(transport) = &stat.stats {
+            transport.selected_candidate_pair_id.as_deref()
+        } else {
+            None
+        }
+    });
+    if let Some(id) = selected_id {
+        return stats.0.iter().find_map(|stat| {
+            if let RtcStatsType::CandidatePair(pair) = &stat.stats
+                && stat.id.0 == id
+            {
+                Some(&**pair)
+            } else {
+                None
+            }
+        });
+    }
+
+    stats.0.iter().find_map(|stat| {
+        if let RtcStatsType::CandidatePair(pair) = &stat.stats
+            && pair.nominated
+            && pair.state
+                == NonExhaustive::Known(KnownIceCandidatePairState::Succeeded)
+        {
+            Some(&**pair)
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns the [availableOutgoingBitrate][1] (in bits per second) of the
+/// selected ICE candidate pair reported in the provided
+/// [`platform::RtcStats`].
+///
+/// Returns [`None`] if there is no selected ICE candidate pair, or it has no
+/// estimate reported.
+///
+/// [1]: https://w3.org/TR/webrtc-stats#dom-rtcicecandidatepairstats-availableoutgoingbitrate
+#[must_use]
+pub fn available_outgoing_bitrate(stats: &platform::RtcStats) -> Option<u64> {
+    selected_pair(stats)?.available_outgoing_bitrate
+}
+
+/// Indicates whether the selected ICE candidate pair reported in the provided
+/// [`platform::RtcStats`] has a relay candidate on any of its sides.
+///
+/// Returns [`None`] if there is no selected ICE candidate pair, or its
+/// candidates aren't reported.
+#[must_use]
+pub fn is_relayed(stats: &platform::RtcStats) -> Option<bool> {
+    let pair = selected_pair(stats)?;
+    let local_id = pair.local_candidate_id.as_deref()?;
+    let remote_id = pair.remote_candidate_id.as_deref()?;
+
+    let mut local = None;
+    let mut remote = None;
+    for stat in &stats.0 {
+        if let RtcStatsType::LocalCandidate(c) = &stat.stats
+            && stat.id.0 == local_id
+        {
+            local = Some(candidate_type(c));
+        } else if let RtcStatsType::RemoteCandidate(c) = &stat.stats
+            && stat.id.0 == remote_id
+        {
+            remote = Some(candidate_type(c));
+        }
+    }
+
+    Some(
+        local? == Some(IceCandidateType::Relay)
+            || remote? == Some(IceCandidateType::Relay),
+    )
+}
+
+/// Returns the URL of the TURN server relaying media of the selected ICE
+/// candidate pair reported in the provided [`platform::RtcStats`].
+///
+/// Returns [`None`] if there is no selected ICE candidate pair, its local
+/// candidate isn't a relay one, or its URL isn't reported.
+#[must_use]
+pub fn relay_url(stats: &platform::RtcStats) -> Option<String> {
+    let local_id = selected_pair(stats)?.local_candidate_id.as_deref()?;
+    stats.0.iter().find_map(|stat| {
+        if let RtcStatsType::LocalCandidate(c) = &stat.stats
+            && stat.id.0 == local_id
+            && candidate_type(c) == Some(IceCandidateType::Relay)
+        {
+            c.url.clone()
+        } else {
+            None
+        }
+    })
+}
+
+/// Summary of the local and remote ICE candidates of a [`PeerConnection`].
+///
+/// [`PeerConnection`]: super::PeerConnection
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CandidateSummary {
+    /// [`CandidateCounts`] of the local ICE candidates.
+    pub local: CandidateCounts,
+
+    /// [`CandidateCounts`] of the remote ICE candidates.
+    pub remote: CandidateCounts,
+}
+
+impl From<&platform::RtcStats> for CandidateSummary {
+    fn from(stats: &platform::RtcStats) -> Self {
+        let mut summary = Self::default();
+        for stat in &stats.0 {
+            if let RtcStatsType::LocalCandidate(c) = &stat.stats {
+                summary.local.count(c);
+            } else if let RtcStatsType::RemoteCandidate(c) = &stat.stats {
+                summary.remote.count(c);
+            }
+        }
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use medea_client_api_proto::stats::{
+        CandidateType, HighResTimeStamp, KnownProtocol, RtcStat,
+        RtcTransportStats, StatId,
+    };
+
+    use super::*;
+
+    fn candidate(
+        id: &str,
+        candidate_type: CandidateType,
+        local: bool,
+    ) -> RtcStat {
+        let stats = Box::new(RtcIceCandidateStats {
+            transport_id: None,
+            address: None,
+            port: 9,
+            protocol: NonExhaustive::Known(KnownProtocol::Udp),
+            candidate_type,
+            priority: 1,
+            url: None,
+            relay_protocol: None,
+        });
+        RtcStat {
+            id: StatId(id.into()),
+            timestamp: HighResTimeStamp(0.0),
+            stats: if local {
+                RtcStatsType::LocalCandidate(stats)
+            } else {
+                RtcStatsType::RemoteCandidate(stats)
+            },
+        }
+    }
+
+    #[test]
+    fn counts_candidates_by_type() {
+        let stats = platform::RtcStats(vec![
+            candidate(
+                "1",
+                NonExhaustive::Known(KnownCandidateType::Host),
+                true,
+            ),
+            candidate(
+                "2",
+                NonExhaustive::Known(KnownCandidateType::Host),
+                true,
+            ),
+            candidate("3", NonExhaustive::Unknown("srflx".into()), true),
+            candidate(
+                "4",
+                NonExhaustive::Known(KnownCandidateType::Relay),
+                true,
+            ),
+            candidate(
+                "5",
+                NonExhaustive::Known(KnownCandidateType::Prflx),
+                false,
+            ),
+            candidate("6", NonExhaustive::Unknown("unknown".into()), false),
+        ]);
+
+        assert_eq!(
+            CandidateSummary::from(&stats),
+            CandidateSummary {
+                local: CandidateCounts {
+                    host: 2,
+                    srflx: 1,
+                    prflx: 0,
+                    relay: 1,
+                },
+                remote: CandidateCounts {
+                    host: 0,
+                    srflx: 0,
+                    prflx: 1,
+                    relay: 0,
+                },
+            },
+        );
+    }
+
+    fn pair(local_id: &str, remote_id: &str, nominated: bool) -> RtcStat {
+        RtcStat {
+            id: StatId(format!("{local_id}-{remote_id}")),
+            timestamp: HighResTimeStamp(0.0),
+            stats: RtcStatsType::CandidatePair(Box::new(
+                RtcIceCandidatePairStats {
+                    transport_id: None,
+                    local_candidate_id: Some(local_id.into()),
+                    remote_candidate_id: Some(remote_id.into()),
+                    state: NonExhaustive::Known(
+                        KnownIceCandidatePairState::Succeeded,
+                    ),
+                    nominated,
+                    bytes_sent: 0,
+                    bytes_received: 0,
+                    total_round_trip_time: None,
+                    current_round_trip_time: None,
+                    available_outgoing_bitrate: None,
+                },
+            )),
+        }
+    }
+
+    #[test]
+    fn detects_relayed_pair() {
+        let host = NonExhaustive::Known(KnownCandidateType::Host);
+        let relay = NonExhaustive::Known(KnownCandidateType::Relay);
+
+        assert_eq!(is_relayed(&platform::RtcStats(vec![])), None);
+        assert_eq!(
+            is_relayed(&platform::RtcStats(vec![
+                candidate("1", host.clone(), true),
+                candidate("2", host.clone(), false),
+                pair("1", "2", false),
+            ])),
+            None,
+        );
+        assert_eq!(
+            is_relayed(&platform::RtcStats(vec![
+                candidate("1", host.clone(), true),
+                candidate("2", host.clone(), false),
+                pair("1", "2", true),
+            ])),
+            Some(false),
+        );
+        assert_eq!(
+            is_relayed(&platform::RtcStats(vec![
+                candidate("1", host, true),
+                candidate("2", relay, false),
+                pair("1", "2", true),
+            ])),
+            Some(true),
+        );
+    }
+
+    #[test]
+    fn reads_available_outgoing_bitrate() {
+        let with_bitrate = |mut stat: RtcStat, bitrate| {
+            if let RtcStatsType::CandidatePair(pair) = &mut stat.stats {
+                pair.available_outgoing_bitrate = bitrate;
+            }
+            stat
+        };
+
+        assert_eq!(
+            available_outgoing_bitrate(&platform::RtcStats(vec![])),
+            None,
+        );
+        assert_eq!(
+            available_outgoing_bitrate(&platform::RtcStats(vec![
+                with_bitrate(pair("1", "2", false), Some(100_000)),
+            ])),
+            None,
+        );
+        assert_eq!(
+            available_outgoing_bitrate(&platform::RtcStats(vec![
+                with_bitrate(pair("1", "2", true), None),
+            ])),
+            None,
+        );
+        assert_eq!(
+            available_outgoing_bitrate(&platform::RtcStats(vec![
+                with_bitrate(pair("1", "2", false), Some(100_000)),
+                with_bitrate(pair("3", "4", true), Some(2_500_000)),
+            ])),
+            Some(2_500_000),
+        );
+    }
+
+    #[test]
+    fn reads_relay_url() {
+        let with_url = |mut stat: RtcStat| {
+            if let RtcStatsType::LocalCandidate(c) = &mut stat.stats {
+                c.url = Some("turn:turn.example.com:3478".into());
+            }
+            stat
+        };
+        let host = NonExhaustive::Known(KnownCandidateType::Host);
+        let relay = NonExhaustive::Known(KnownCandidateType::Relay);
+
+        assert_eq!(relay_url(&platform::RtcStats(vec![])), None);
+        assert_eq!(
+            relay_url(&platform::RtcStats(vec![
+                with_url(candidate("1", host.clone(), true)),
+                candidate("2", host.clone(), false),
+                pair("1", "2", true),
+            ])),
+            None,
+        );
+        assert_eq!(
+            relay_url(&platform::RtcStats(vec![
+                with_url(candidate("1", relay.clone(), true)),
+                candidate("2", host.clone(), false),
+                pair("1", "2", false),
+            ])),
+            None,
+        );
+        assert_eq!(
+            relay_url(&platform::RtcStats(vec![
+                with_url(candidate("1", relay, true)),
+                candidate("2", host, false),
+                pair("1", "2", true),
+            ])),
+            Some("turn:turn.example.com:3478".into()),
+        );
+    }
+
+    #[test]
+    fn follows_transport_selected_pair() {
+        let transport = |selected: &str| RtcStat {
+            id: StatId("transport".into()),
+            timestamp: HighResTimeStamp(0.0),
+            stats: RtcStatsType::Transport(Box::new(RtcTransportStats {
+                packets_sent: None,
+                packets_received: None,
+                bytes_sent: None,
+                bytes_received: None,
+                ice_role: None,
+                selected_candidate_pair_id: Some(selected.into()),
+            })),
+        };
+        let host = NonExhaustive::Known(KnownCandidateType::Host);
+        let relay = NonExhaustive::Known(KnownCandidateType::Relay);
+
+        assert_eq!(
+            is_relayed(&platform::RtcStats(vec![
+                candidate("1", host.clone(), true),
+                candidate("2", host.clone(), false),
+                candidate("3", relay, true),
+                candidate("4", host, false),
+                pair("1", "2", true),
+                pair("3", "4", true),
+                transport("3-4"),
+            ])),
+            Some(true),
+        );
+        assert_eq!(
+            is_relayed(&platform::RtcStats(vec![
+                pair("1", "2", true),
+                transport("unknown"),
+            ])),
+            None,
+        );
+    }
+}
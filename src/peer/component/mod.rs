@@ -5,13 +5,19 @@ mod local_sdp;
 mod tracks_repository;
 mod watchers;
 
-use std::{cell::Cell, collections::HashSet, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashSet,
+    rc::Rc,
+    time::Duration,
+};
 
+use derive_more::with_trait::Display;
 use futures::{StreamExt as _, TryFutureExt as _, future::LocalBoxFuture};
 pub use local_sdp::DESCRIPTION_APPROVE_TIMEOUT;
 use medea_client_api_proto::{
-    self as proto, IceCandidate, IceServer, NegotiationRole, PeerId as Id,
-    TrackId,
+    self as proto, IceCandidate, IceServer, MediaSourceKind, NegotiationRole,
+    PeerId as Id, TrackId,
 };
 use medea_reactive::{AllProcessed, ObservableCell, ProgressableCell};
 use proto::{ConnectionMode, MemberId};
@@ -22,12 +28,19 @@ use self::{
     tracks_repository::TracksRepository,
 };
 use crate::{
-    media::LocalTracksConstraints,
+    media::{LocalTracksConstraints, MediaKind},
     peer::{
-        LocalStreamUpdateCriteria, PeerConnection, UpdateLocalStreamError,
-        media::{receiver, sender},
+        IceCandidateFilter, IceServersResolver, IceTransportProtocol,
+        LocalStreamUpdateCriteria, OpusConfig, PeerConnection,
+        RtcPeerConnectionError, SdpMunging, UpdateLocalStreamError,
+        ice_transport_preference::apply_transport_preference,
+        media::{CodecPreferences, receiver, sender},
+    },
+    platform,
+    utils::{
+        AsProtoState, Caused, SynchronizableState, Updatable, component,
+        logging::{self, LogCategory},
     },
-    utils::{AsProtoState, SynchronizableState, Updatable, component},
 };
 
 /// Possible synchronization phases of [`Component`]'s state.
@@ -43,6 +56,97 @@ pub enum SyncPhase {
     Synced,
 }
 
+/// Maximum length (in bytes) of an SDP snippet stored in a [`SdpError`].
+const SDP_SNIPPET_MAX_LEN: usize = 512;
+
+/// Errors occurring during SDP negotiation of a [`Component`].
+///
+/// Identifies the negotiation step that failed, and contains a snippet of the
+/// offending SDP (if any).
+#[derive(Caused, Clone, Debug, Display)]
+#[cause(error = platform::Error)]
+pub enum SdpError {
+    /// Failed to create a local SDP offer.
+    #[display("Failed to create SDP offer: {_0}")]
+    CreateOffer(#[cause] RtcPeerConnectionError),
+
+    /// Failed to create a local SDP answer.
+    #[display("Failed to create SDP answer: {_0}")]
+    CreateAnswer(#[cause] RtcPeerConnectionError),
+
+    /// Failed to apply a local SDP.
+    #[display("Failed to set local SDP: {cause}\nSDP: {sdp}")]
+    SetLocalDescription {
+        /// Snippet of the local SDP that failed to be applied.
+        sdp: String,
+
+        /// Underlying [`RtcPeerConnectionError`].
+        #[cause]
+        cause: RtcPeerConnectionError,
+    },
+
+    /// Failed to roll back a pending local SDP offer.
+    #[display("Failed to rollback local SDP: {_0}")]
+    Rollback(#[cause] RtcPeerConnectionError),
+
+    /// Failed to apply a remote SDP.
+    #[display("Failed to set remote SDP: {cause}\nSDP: {sdp}")]
+    SetRemoteDescription {
+        /// Snippet of the remote SDP that failed to be applied.
+        sdp: String,
+
+        /// Underlying [`RtcPeerConnectionError`].
+        #[cause]
+        cause: RtcPeerConnectionError,
+    },
+}
+
+impl SdpError {
+    /// Returns a snippet of the provided SDP, suitable for storing in a
+    /// [`SdpError`].
+    ///
+    /// Truncates the provided SDP to the [`SDP_SNIPPET_MAX_LEN`] bytes (on a
+    /// `char` boundary).
+    #[must_use]
+    pub fn snippet(sdp: &str) -> String {
+        if sdp.len() <= SDP_SNIPPET_MAX_LEN {
+            return sdp.to_owned();
+        }
+        let end = (0..=SDP_SNIPPET_MAX_LEN)
+            .rev()
+            .find(|&i| sdp.is_char_boundary(i))
+            .unwrap_or_default();
+        format!("{}...", sdp.get(..end).unwrap_or_default())
+    }
+
+    /// Returns a function wrapping a [`Traced`] [`RtcPeerConnectionError`]
+    /// into a [`SdpError`] with the provided constructor, preserving its
+    /// [`Trace`].
+    ///
+    /// [`Trace`]: tracerr::Trace
+    fn wrap(
+        f: impl FnOnce(RtcPeerConnectionError) -> Self,
+    ) -> impl FnOnce(Traced<RtcPeerConnectionError>) -> Traced<Self> {
+        move |e| {
+            let (cause, trace) = e.split();
+            tracerr::new!(Traced::compose(f(cause), trace))
+        }
+    }
+}
+
+/// Role of a [`Component`] in the [perfect negotiation][1] pattern, defining
+/// how a collision of SDP offers (glare) is resolved.
+///
+/// [1]: https://w3.org/TR/webrtc#perfect-negotiation-example
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Politeness {
+    /// Rolls back its own pending SDP offer and accepts the remote one.
+    Polite,
+
+    /// Ignores the remote SDP offer, keeping its own pending one.
+    Impolite,
+}
+
 /// Possible negotiation phases of a [`Component`].
 ///
 /// ```ignore
@@ -114,7 +218,7 @@ pub struct State {
     force_relay: bool,
 
     /// List of [`IceServer`]s which this [`Component`] should use.
-    ice_servers: Vec<IceServer>,
+    ice_servers: ObservableCell<Vec<IceServer>>,
 
     /// Current [`NegotiationRole`] of this [`Component`].
     negotiation_role: ProgressableCell<Option<NegotiationRole>>,
@@ -131,6 +235,89 @@ pub struct State {
     /// Indicates whether ICE restart should be performed.
     restart_ice: Cell<bool>,
 
+    /// [`Politeness`] of this [`Component`] used to resolve SDP offers
+    /// collisions.
+    ///
+    /// [`None`] means that colliding remote SDP offers are postponed until
+    /// the current negotiation finishes.
+    politeness: Cell<Option<Politeness>>,
+
+    /// Indicator whether a local SDP offer is being created and applied at
+    /// the moment.
+    making_offer: Cell<bool>,
+
+    /// Indicator whether the last remote SDP offer was ignored due to a
+    /// collision with a local one.
+    ignore_offer: Cell<bool>,
+
+    /// Indicator whether a new negotiation was requested via
+    /// [`State::renegotiate()`] and hasn't been started yet.
+    renegotiation_needed: ObservableCell<bool>,
+
+    /// Indicator whether negotiations don't wait for [`local::Track`]s
+    /// acquisition, so remote media is received while it's pending.
+    ///
+    /// [`local::Track`]: crate::media::track::local::Track
+    early_media: Cell<bool>,
+
+    /// Maximum number of [`platform::RtcStats`] snapshots retained in the
+    /// [`PeerConnection::stats_history()`].
+    ///
+    /// `0` means that no history is retained.
+    stats_history_capacity: ObservableCell<usize>,
+
+    /// Indicator whether the scraped [`platform::RtcStats`] are sent to the
+    /// media server.
+    ///
+    /// Doesn't affect scraping, so local stats APIs keep working regardless.
+    stats_sending_enabled: ObservableCell<bool>,
+
+    /// Period the [`PeerConnection`] may stay in a
+    /// [`PeerConnectionState::Disconnected`] before it's reported.
+    ///
+    /// [`PeerConnectionState::Disconnected`]: medea_client_api_proto::PeerConnectionState::Disconnected
+    disconnect_grace_period: ObservableCell<Duration>,
+
+    /// Default [`IceServer`]s used by this [`Component`] if no
+    /// [`IceServer`]s are provided by the media server.
+    default_ice_servers: RefCell<Vec<IceServer>>,
+
+    /// Preferred order of [`IceTransportProtocol`]s used to reach TURN
+    /// servers of this [`Component`].
+    ///
+    /// Empty means that [`IceServer`]s are used as is.
+    ice_transport_preference: RefCell<Vec<IceTransportProtocol>>,
+
+    /// [`IceServersResolver`] deciding which [`IceServer`]s are actually used
+    /// by the [`PeerConnection`].
+    ice_servers_resolver: RefCell<Option<IceServersResolver>>,
+
+    /// [`platform::BundlePolicy`] of the [`PeerConnection`] created from this
+    /// [`Component`].
+    bundle_policy: Cell<platform::BundlePolicy>,
+
+    /// [`platform::RtcpMuxPolicy`] of the [`PeerConnection`] created from this
+    /// [`Component`].
+    rtcp_mux_policy: Cell<platform::RtcpMuxPolicy>,
+
+    /// [`IceCandidateFilter`] applied to the ICE candidates discovered by the
+    /// [`PeerConnection`] before emitting them.
+    ice_candidate_filter: ObservableCell<Option<IceCandidateFilter>>,
+
+    /// Indicator whether locally gathered host ICE candidates with [mDNS]
+    /// addresses should be dropped instead of being emitted.
+    ///
+    /// [mDNS]: https://tools.ietf.org/html/draft-ietf-mmusic-mdns-ice-candidates
+    drop_mdns_candidates: ObservableCell<bool>,
+
+    /// [`CodecPreferences`] applied to the transceivers of the
+    /// [`PeerConnection`] before creating an SDP offer.
+    codec_preferences: ObservableCell<CodecPreferences>,
+
+    /// [`SdpMunging`] applied to the local SDP offers and answers before
+    /// applying them.
+    sdp_munging: ObservableCell<SdpMunging>,
+
     /// All [`IceCandidate`]s of this [`Component`].
     ice_candidates: IceCandidates,
 
@@ -164,13 +351,30 @@ impl State {
             connection_mode,
             senders: TracksRepository::new(),
             receivers: TracksRepository::new(),
-            ice_servers,
+            ice_servers: ObservableCell::new(ice_servers),
             force_relay,
             remote_sdp: ProgressableCell::new(None),
             local_sdp: LocalSdp::new(),
             negotiation_role: ProgressableCell::new(negotiation_role),
             negotiation_phase: ObservableCell::new(NegotiationPhase::Stable),
             restart_ice: Cell::new(false),
+            politeness: Cell::new(None),
+            making_offer: Cell::new(false),
+            ignore_offer: Cell::new(false),
+            renegotiation_needed: ObservableCell::new(false),
+            early_media: Cell::new(false),
+            stats_history_capacity: ObservableCell::new(0),
+            stats_sending_enabled: ObservableCell::new(true),
+            disconnect_grace_period: ObservableCell::new(Duration::ZERO),
+            default_ice_servers: RefCell::new(Vec::new()),
+            ice_transport_preference: RefCell::new(Vec::new()),
+            ice_servers_resolver: RefCell::new(None),
+            bundle_policy: Cell::default(),
+            rtcp_mux_policy: Cell::default(),
+            ice_candidate_filter: ObservableCell::new(None),
+            drop_mdns_candidates: ObservableCell::new(false),
+            codec_preferences: ObservableCell::new(CodecPreferences::default()),
+            sdp_munging: ObservableCell::new(SdpMunging::default()),
             ice_candidates: IceCandidates::new(),
             maybe_update_local_stream: ObservableCell::new(false),
             maybe_update_connections: ObservableCell::new(None),
@@ -192,8 +396,118 @@ impl State {
 
     /// Returns all [`IceServer`]s of this [`State`].
     #[must_use]
-    pub const fn ice_servers(&self) -> &Vec<IceServer> {
-        &self.ice_servers
+    pub fn ice_servers(&self) -> Vec<IceServer> {
+        self.ice_servers.get()
+    }
+
+    /// Replaces [`IceServer`]s of this [`State`] with the provided ones (e.g.
+    /// on TURN credentials rotation).
+    ///
+    /// [`PeerConnection`] applies them via
+    /// [`PeerConnection::update_ice_servers()`] right away, regardless of an
+    /// in-progress negotiation: the new [`IceServer`]s are used for the ICE
+    /// candidates gathered afterwards only, so the ones already gathered for
+    /// the current negotiation remain valid, while the established connection
+    /// keeps using its current candidates until the next ICE restart.
+    pub fn set_ice_servers(&self, ice_servers: Vec<IceServer>) {
+        self.ice_servers.set(ice_servers);
+    }
+
+    /// Returns all [`IceServer`]s of this [`State`] with its preferred order
+    /// of [`IceTransportProtocol`]s applied, to be used in the
+    /// [RTCConfiguration][1].
+    ///
+    /// Falls back to the default [`IceServer`]s if no [`IceServer`]s are
+    /// provided by the media server, and passes the result through the
+    /// [`IceServersResolver`], if any.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcconfiguration
+    #[must_use]
+    pub fn rtc_ice_servers(&self) -> Vec<IceServer> {
+        let ice_servers = self.ice_servers.get();
+        let ice_servers = if ice_servers.is_empty() {
+            self.default_ice_servers.borrow().clone()
+        } else {
+            ice_servers
+        };
+        let ice_servers = apply_transport_preference(
+            ice_servers,
+            &self.ice_transport_preference.borrow(),
+        );
+        match self.ice_servers_resolver.borrow().as_ref() {
+            Some(resolver) => resolver.resolve(ice_servers),
+            None => ice_servers,
+        }
+    }
+
+    /// Sets the [`IceServersResolver`] deciding which [`IceServer`]s are
+    /// actually used by the [`PeerConnection`] created from this [`State`].
+    ///
+    /// The resolver is invoked each time the [`IceServer`]s are applied to
+    /// the [`PeerConnection`], including their updates by the media server.
+    /// [`None`] makes the configured [`IceServer`]s being used as is.
+    pub fn set_ice_servers_resolver(
+        &self,
+        resolver: Option<IceServersResolver>,
+    ) {
+        drop(self.ice_servers_resolver.replace(resolver));
+    }
+
+    /// Sets the default [`IceServer`]s of this [`State`], used if no
+    /// [`IceServer`]s are provided by the media server.
+    ///
+    /// Takes effect only if set before the [`PeerConnection`] is created from
+    /// this [`State`].
+    pub fn set_default_ice_servers(&self, ice_servers: Vec<IceServer>) {
+        drop(self.default_ice_servers.replace(ice_servers));
+    }
+
+    /// Sets the preferred order of [`IceTransportProtocol`]s used to reach TURN
+    /// servers of this [`State`].
+    ///
+    /// TURN server URLs having an explicit `transport` are respected, while
+    /// the ones without it are expanded according to the provided
+    /// `preference`.
+    ///
+    /// Takes effect only if set before the [`PeerConnection`] is created from
+    /// this [`State`].
+    pub fn set_ice_transport_preference(
+        &self,
+        preference: Vec<IceTransportProtocol>,
+    ) {
+        drop(self.ice_transport_preference.replace(preference));
+    }
+
+    /// Returns the [`platform::BundlePolicy`] of the [`PeerConnection`]
+    /// created from this [`State`].
+    #[must_use]
+    pub fn bundle_policy(&self) -> platform::BundlePolicy {
+        self.bundle_policy.get()
+    }
+
+    /// Sets the [`platform::BundlePolicy`] of the [`PeerConnection`] created
+    /// from this [`State`].
+    ///
+    /// Takes effect only if set before the [`PeerConnection`] is created from
+    /// this [`State`].
+    pub fn set_bundle_policy(&self, policy: platform::BundlePolicy) {
+        self.bundle_policy.set(policy);
+    }
+
+    /// Returns the [`platform::RtcpMuxPolicy`] of the [`PeerConnection`]
+    /// created from this [`State`].
+    #[must_use]
+    pub fn rtcp_mux_policy(&self) -> platform::RtcpMuxPolicy {
+        self.rtcp_mux_policy.get()
+    }
+
+    /// Sets the [`platform::RtcpMuxPolicy`] of the [`PeerConnection`] created
+    /// from this [`State`].
+    ///
+    /// Takes effect only if set before the [`PeerConnection`] is created from
+    /// this [`State`].
+    pub fn set_rtcp_mux_policy(&self, policy: platform::RtcpMuxPolicy) {
+        self.rtcp_mux_policy.set(policy);
     }
 
     /// Indicates whether [`PeerConnection`] should be relayed forcibly.
@@ -249,11 +563,53 @@ impl State {
             .collect()
     }
 
+    /// Sets [`Politeness`] of this [`State`] used to resolve SDP offers
+    /// collisions.
+    pub fn set_politeness(&self, politeness: Option<Politeness>) {
+        self.politeness.set(politeness);
+    }
+
+    /// Returns [`Politeness`] of this [`State`].
+    #[must_use]
+    pub fn politeness(&self) -> Option<Politeness> {
+        self.politeness.get()
+    }
+
+    /// Indicates whether the last remote SDP offer was ignored due to a
+    /// collision with a local one, and the negotiation hasn't become stable
+    /// since then.
+    #[must_use]
+    pub fn is_remote_offer_ignored(&self) -> bool {
+        self.ignore_offer.get()
+    }
+
     /// Sets [`NegotiationRole`] of this [`State`] to the provided one.
+    ///
+    /// If the provided [`NegotiationRole`] is an [`NegotiationRole::Answerer`]
+    /// colliding with a local SDP offer, then it's resolved according to the
+    /// [`Politeness`] of this [`State`]: a polite [`State`] abandons its own
+    /// offer, while an impolite one ignores the remote offer.
     pub async fn set_negotiation_role(
         &self,
         negotiation_role: NegotiationRole,
     ) {
+        if let (NegotiationRole::Answerer(_), Some(politeness)) =
+            (&negotiation_role, self.politeness.get())
+        {
+            let collision = self.making_offer.get()
+                || self.negotiation_role.get()
+                    == Some(NegotiationRole::Offerer);
+            self.ignore_offer
+                .set(collision && politeness == Politeness::Impolite);
+            if self.ignore_offer.get() {
+                return;
+            }
+            if collision {
+                self.negotiation_role.set(Some(negotiation_role));
+                return;
+            }
+        }
+
         _ = self
             .negotiation_role
             .subscribe()
@@ -267,6 +623,28 @@ impl State {
         self.restart_ice.set(true);
     }
 
+    /// Requests Media Server to start a new negotiation of this [`State`].
+    ///
+    /// If a negotiation is in progress, then the new one is requested once it
+    /// finishes. Multiple requests made meanwhile (including the ones made
+    /// while waiting for Media Server to start the requested negotiation)
+    /// result in a single negotiation.
+    pub fn renegotiate(&self) {
+        self.renegotiation_needed.set(true);
+    }
+
+    /// Sets whether negotiations shouldn't wait for [`local::Track`]s
+    /// acquisition (e.g. while a media permission prompt is pending), so
+    /// remote media is received before local media is granted.
+    ///
+    /// Once the acquisition succeeds, a renegotiation is requested to add the
+    /// outbound [`local::Track`]s, without disrupting the receiving ones.
+    ///
+    /// [`local::Track`]: crate::media::track::local::Track
+    pub fn set_early_media(&self, enabled: bool) {
+        self.early_media.set(enabled);
+    }
+
     /// Removes [`sender::State`] or [`receiver::State`] with the provided
     /// [`TrackId`].
     pub fn remove_track(&self, track_id: TrackId) {
@@ -275,6 +653,158 @@ impl State {
         }
     }
 
+    /// Sets the maximum number of [`platform::RtcStats`] snapshots retained in
+    /// the [`PeerConnection::stats_history()`].
+    ///
+    /// `0` disables the history and clears it.
+    pub fn set_stats_history_capacity(&self, capacity: usize) {
+        self.stats_history_capacity.set(capacity);
+    }
+
+    /// Enables or disables sending the scraped [`platform::RtcStats`] of the
+    /// [`PeerConnection`] to the media server.
+    ///
+    /// [`platform::RtcStats`] are still scraped while sending is disabled, so
+    /// [`PeerConnection::get_stats()`], [`PeerConnection::stats_history()`]
+    /// and other local stats APIs keep working.
+    pub fn set_stats_sending_enabled(&self, enabled: bool) {
+        self.stats_sending_enabled.set(enabled);
+    }
+
+    /// Sets the period the [`PeerConnection`] may stay in a
+    /// [`PeerConnectionState::Disconnected`] before it's reported, so brief
+    /// network blips aren't reported at all.
+    ///
+    /// [`Duration::ZERO`] (the default) reports it immediately.
+    ///
+    /// [`PeerConnectionState::Disconnected`]: medea_client_api_proto::PeerConnectionState::Disconnected
+    pub fn set_disconnect_grace_period(&self, period: Duration) {
+        self.disconnect_grace_period.set(period);
+    }
+
+    /// Sets the [`IceCandidateFilter`] to be applied to the ICE candidates
+    /// discovered by the [`PeerConnection`] before emitting them.
+    ///
+    /// [`None`] disables the filtering.
+    pub fn set_ice_candidate_filter(&self, filter: Option<IceCandidateFilter>) {
+        self.ice_candidate_filter.set(filter);
+    }
+
+    /// Sets the ordered list of preferred [`proto::Codec`]s of the provided
+    /// [`MediaKind`], applied to the transceivers of the [`PeerConnection`]
+    /// before creating an SDP offer.
+    ///
+    /// [`proto::Codec`]s unsupported by the platform are dropped with a
+    /// warning. Empty list resets preferences of the provided [`MediaKind`].
+    pub fn set_codec_preferences(
+        &self,
+        kind: MediaKind,
+        codecs: Vec<proto::Codec>,
+    ) {
+        self.codec_preferences.mutate(|mut p| p.set(kind, codecs));
+    }
+
+    /// Sets the [`b=AS`][1] bandwidth (in kbps) to be injected into the
+    /// local SDP offers and answers for the media of the provided
+    /// [`MediaKind`].
+    ///
+    /// This is a coarse control meant for legacy media servers honoring only
+    /// the SDP bandwidth lines: browsers interpret it inconsistently (some
+    /// ignore it for the sending side entirely), so the [maxBitrate][2] of
+    /// the encodings should be preferred whenever possible.
+    ///
+    /// Takes effect on the next negotiation. [`None`] stops the injection.
+    ///
+    /// [1]: https://tools.ietf.org/html/rfc8866#section-5.8
+    /// [2]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-maxbitrate
+    pub fn set_sdp_bandwidth(&self, kind: MediaKind, kbps: Option<u32>) {
+        self.sdp_munging.mutate(|mut m| m.set_bandwidth(kind, kbps));
+    }
+
+    /// Sets whether audio redundancy ([RED] and Opus [in-band FEC]) should be
+    /// enforced (`true`) or stripped, reverting to plain Opus (`false`), in
+    /// the local SDP offers and answers.
+    ///
+    /// Enforcing makes [RED] the preferred audio codec and enables the Opus
+    /// in-band FEC, trading some bandwidth for resilience on lossy networks.
+    /// A warning is logged if the remote SDP answer doesn't retain it.
+    ///
+    /// Takes effect on the next negotiation. [`None`] leaves the platform
+    /// defaults as is.
+    ///
+    /// [in-band FEC]: https://tools.ietf.org/html/rfc7587#section-3.1.1
+    /// [RED]: https://tools.ietf.org/html/rfc2198
+    pub fn set_audio_redundancy(&self, enabled: Option<bool>) {
+        self.sdp_munging.mutate(|mut m| m.set_audio_redundancy(enabled));
+    }
+
+    /// Sets the [`OpusConfig`] to be applied to the audio of the local SDP
+    /// offers and answers of this [`State`] only.
+    ///
+    /// Is re-applied on every negotiation, so survives renegotiations. Takes
+    /// effect on the next negotiation.
+    ///
+    /// Enabling [`OpusConfig::stereo`] also requests 2 channels for the
+    /// captured audio, unless its channel count is constrained explicitly.
+    pub fn set_opus_config(&self, config: OpusConfig) {
+        self.sdp_munging.mutate(|mut m| m.set_opus(config));
+    }
+
+    /// Sets the payload type to be used for the provided `codec` (matched by
+    /// its encoding name, case-insensitively) in the local SDP offers and
+    /// answers, for interoperability with the endpoints requiring specific
+    /// payload types.
+    ///
+    /// The `m=` line and all the `rtpmap`, `fmtp` and `rtcp-fb` attributes
+    /// (including the [RTX] and [RED] references) are updated consistently.
+    ///
+    /// Takes effect on the next negotiation. [`None`] stops the remapping.
+    ///
+    /// [RED]: https://tools.ietf.org/html/rfc2198
+    /// [RTX]: https://tools.ietf.org/html/rfc4588
+    pub fn set_payload_type(&self, codec: &str, payload_type: Option<u8>) {
+        self.sdp_munging
+            .mutate(|mut m| m.set_payload_type(codec, payload_type));
+    }
+
+    /// Sets whether locally gathered host ICE candidates with [mDNS]
+    /// (`.local`) addresses should be dropped instead of being emitted.
+    ///
+    /// Only affects the candidates gathered locally, the remote ones are
+    /// always applied as is. Server reflexive and relayed candidates are never
+    /// dropped, so the connection still can be established via them.
+    ///
+    /// [mDNS]: https://tools.ietf.org/html/draft-ietf-mmusic-mdns-ice-candidates
+    pub fn set_drop_mdns_candidates(&self, drop_mdns: bool) {
+        self.drop_mdns_candidates.set(drop_mdns);
+    }
+
+    /// Removes all the [`sender::State`]s and [`receiver::State`]s with the
+    /// provided [`MediaKind`] and [`MediaSourceKind`], returning [`TrackId`]s
+    /// of the removed ones.
+    ///
+    /// If the provided [`MediaSourceKind`] is [`None`], then tracks of any
+    /// [`MediaSourceKind`] are removed.
+    ///
+    /// Requests a single renegotiation (see [`State::renegotiate()`]) if
+    /// anything has been removed, rather than one per each removed track, so
+    /// Media Server learns about the removed tracks from that negotiation.
+    pub fn remove_tracks_by(
+        &self,
+        kind: MediaKind,
+        source_kind: Option<MediaSourceKind>,
+    ) -> Vec<TrackId> {
+        let mut removed = self.senders.ids_by_kind(kind, source_kind);
+        removed.extend(self.receivers.ids_by_kind(kind, source_kind));
+        for track_id in &removed {
+            self.remove_track(*track_id);
+        }
+        if !removed.is_empty() {
+            self.renegotiate();
+        }
+        removed
+    }
+
     /// Sets remote SDP offer to the provided value.
     pub fn set_remote_sdp(&self, sdp: String) {
         self.remote_sdp.set(Some(sdp));
@@ -285,6 +815,12 @@ impl State {
         self.ice_candidates.add(ice_candidate);
     }
 
+    /// Resets a [`NegotiationRole`] of this [`State`] to [`None`].
+    pub fn reset_negotiation_role(&self) {
+        self.negotiation_phase.set(NegotiationPhase::Stable);
+        self.negotiation_role.set(None);
+    }
+
     /// Marks current local SDP as approved by server.
     pub fn apply_local_sdp(&self, sdp: String) {
         self.local_sdp.approved_set(sdp);
@@ -435,7 +971,12 @@ impl State {
         } else if let Some(receiver) = self.get_receiver(patch.id) {
             receiver.update(&patch);
         } else {
-            log::warn!("Cannot apply patch to `Track`: {}", patch.id.0);
+            logging::emit(
+                log::Level::Warn,
+                Some(self.id),
+                LogCategory::Media,
+                format_args!("Cannot apply patch to `Track`: {}", patch.id.0),
+            );
         }
     }
 
@@ -460,7 +1001,7 @@ impl AsProtoState for State {
             receivers: self.receivers.as_proto(),
             ice_candidates: self.ice_candidates.as_proto(),
             force_relay: self.force_relay,
-            ice_servers: self.ice_servers.clone(),
+            ice_servers: self.ice_servers.get(),
             negotiation_role: self.negotiation_role.get(),
             local_sdp: self.local_sdp.current(),
             remote_sdp: self.remote_sdp.get(),
@@ -527,6 +1068,7 @@ impl SynchronizableState for State {
             self.negotiation_phase.set(NegotiationPhase::WaitLocalSdp);
         }
         self.remote_sdp.set(input.remote_sdp);
+        self.ice_servers.set(input.ice_servers);
         self.ice_candidates.apply(input.ice_candidates, send_cons);
         self.senders.apply(input.senders, send_cons);
         self.receivers.apply(input.receivers, send_cons);
@@ -573,12 +1115,6 @@ impl State {
         self.remote_sdp.when_all_processed().await;
     }
 
-    /// Resets a [`NegotiationRole`] of this [`State`] to [`None`].
-    pub fn reset_negotiation_role(&self) {
-        self.negotiation_phase.set(NegotiationPhase::Stable);
-        self.negotiation_role.set(None);
-    }
-
     /// Returns the current [`NegotiationRole`] of this [`State`].
     #[must_use]
     pub fn negotiation_role(&self) -> Option<NegotiationRole> {
@@ -626,3 +1162,116 @@ impl State {
         self.sync_phase.set(SyncPhase::Synced);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ice_server(url: &str) -> IceServer {
+        IceServer { urls: vec![url.into()], username: None, credential: None }
+    }
+
+    #[test]
+    fn falls_back_to_default_ice_servers() {
+        let state =
+            State::new(Id(1), Vec::new(), false, None, ConnectionMode::Mesh);
+        assert!(state.rtc_ice_servers().is_empty());
+
+        state.set_default_ice_servers(vec![ice_server("turn:default.com")]);
+        assert_eq!(
+            state.rtc_ice_servers(),
+            vec![ice_server("turn:default.com")],
+        );
+    }
+
+    #[test]
+    fn provided_ice_servers_win_over_default_ones() {
+        let state = State::new(
+            Id(1),
+            vec![ice_server("turn:provided.com")],
+            false,
+            None,
+            ConnectionMode::Mesh,
+        );
+        state.set_default_ice_servers(vec![ice_server("turn:default.com")]);
+
+        assert_eq!(
+            state.rtc_ice_servers(),
+            vec![ice_server("turn:provided.com")],
+        );
+    }
+
+    /// Returns a [`RtcPeerConnectionError`] not requiring a platform to be
+    /// constructed.
+    fn pc_error() -> Traced<RtcPeerConnectionError> {
+        tracerr::new!(RtcPeerConnectionError::RtcStatsError(
+            platform::RtcStatsError::UndefinedId,
+        ))
+    }
+
+    #[test]
+    fn short_sdp_snippet_is_not_truncated() {
+        let sdp = "v=0\r\no=- 0 0 IN IP4 127.0.0.1\r\n";
+
+        assert_eq!(SdpError::snippet(sdp), sdp);
+    }
+
+    #[test]
+    fn long_sdp_snippet_is_truncated() {
+        let sdp = "a".repeat(SDP_SNIPPET_MAX_LEN * 2);
+
+        let snippet = SdpError::snippet(&sdp);
+        assert_eq!(snippet.len(), SDP_SNIPPET_MAX_LEN + 3);
+        assert!(snippet.ends_with("..."));
+        assert!(sdp.starts_with(snippet.trim_end_matches("...")));
+    }
+
+    #[test]
+    fn sdp_snippet_is_truncated_on_char_boundary() {
+        let sdp = format!("a{}", "ы".repeat(SDP_SNIPPET_MAX_LEN));
+
+        let snippet = SdpError::snippet(&sdp);
+        let truncated = snippet.strip_suffix("...").unwrap();
+        assert_eq!(truncated.len(), SDP_SNIPPET_MAX_LEN - 1);
+        assert!(sdp.starts_with(truncated));
+    }
+
+    #[test]
+    fn sdp_error_reports_failed_step() {
+        let sdp = "a".repeat(SDP_SNIPPET_MAX_LEN * 2);
+        let snippet = SdpError::snippet(&sdp);
+
+        let err = SdpError::wrap(|cause| SdpError::SetRemoteDescription {
+            sdp: snippet.clone(),
+            cause,
+        })(pc_error())
+        .into_inner();
+        assert!(matches!(
+            &err,
+            SdpError::SetRemoteDescription { sdp, .. } if *sdp == snippet,
+        ));
+        let msg = err.to_string();
+        assert!(msg.starts_with("Failed to set remote SDP: "));
+        assert!(msg.ends_with(&format!("SDP: {snippet}")));
+        assert!(!msg.contains(&sdp));
+
+        let err = SdpError::wrap(|cause| SdpError::SetLocalDescription {
+            sdp: snippet.clone(),
+            cause,
+        })(pc_error())
+        .into_inner();
+        assert!(err.to_string().starts_with("Failed to set local SDP: "));
+
+        let steps: [(fn(_) -> _, _); 3] = [
+            (SdpError::CreateOffer, "create SDP offer"),
+            (SdpError::CreateAnswer, "create SDP answer"),
+            (SdpError::Rollback, "rollback local SDP"),
+        ];
+        for (wrap, step) in steps {
+            let err = SdpError::wrap(wrap)(pc_error()).into_inner();
+            assert!(
+                err.to_string().starts_with(&format!("Failed to {step}: "))
+            );
+        }
+    }
+}
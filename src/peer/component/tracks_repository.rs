@@ -13,14 +13,14 @@ use futures::{
     FutureExt as _, TryFutureExt as _, future, future::LocalBoxFuture,
     stream::LocalBoxStream,
 };
-use medea_client_api_proto::TrackId;
+use medea_client_api_proto::{MediaSourceKind, TrackId};
 use medea_reactive::{AllProcessed, Guarded, ProgressableHashMap};
 use tracerr::Traced;
 
 use super::sender;
 use crate::{
-    media::LocalTracksConstraints,
-    peer::UpdateLocalStreamError,
+    media::{LocalTracksConstraints, MediaKind},
+    peer::{TransceiverSide, UpdateLocalStreamError},
     utils::{AsProtoState, SynchronizableState, Updatable},
 };
 
@@ -87,6 +87,28 @@ impl<S> TracksRepository<S> {
     }
 }
 
+impl<S: TransceiverSide> TracksRepository<S> {
+    /// Returns [`TrackId`]s of all the tracks with the provided [`MediaKind`]
+    /// and [`MediaSourceKind`].
+    ///
+    /// If the provided [`MediaSourceKind`] is [`None`], then tracks of any
+    /// [`MediaSourceKind`] are matched.
+    #[must_use]
+    pub fn ids_by_kind(
+        &self,
+        kind: MediaKind,
+        source_kind: Option<MediaSourceKind>,
+    ) -> Vec<TrackId> {
+        self.0
+            .borrow()
+            .iter()
+            .filter(|(_, s)| s.kind() == kind)
+            .filter(|(_, s)| source_kind.is_none_or(|sk| s.source_kind() == sk))
+            .map(|(id, _)| *id)
+            .collect()
+    }
+}
+
 impl TracksRepository<sender::State> {
     /// Returns all the [`sender::State`]s which require a local `MediaStream`
     /// update.
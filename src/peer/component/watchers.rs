@@ -1,26 +1,42 @@
 //! Implementation of a [`Component`] watchers.
 
-use std::{collections::HashSet, rc::Rc};
+use std::{collections::HashSet, rc::Rc, time::Duration};
 
 use derive_more::with_trait::{Display, From};
 use futures::{StreamExt as _, future};
 use medea_client_api_proto::{
-    IceCandidate, MemberId, NegotiationRole, TrackId,
+    Command, IceCandidate, IceServer, MemberId, NegotiationRole, TrackId,
 };
 use medea_macro::watchers;
 use medea_reactive::Guarded;
 use tracerr::Traced;
 
-use super::{Component, PeerConnection, State};
+use super::{Component, PeerConnection, SdpError, State};
 use crate::{
     peer::{
-        GetMidsError, PeerEvent, RtcPeerConnectionError,
+        GetMidsError, IceCandidateFilter, PeerEvent, RtcPeerConnectionError,
+        SdpMunging,
         component::{NegotiationPhase, SyncPhase},
-        media::{receiver, sender},
+        media::{CodecPreferences, receiver, sender},
+        send_peer_event,
     },
+    platform,
     utils::{Updatable as _, transpose_guarded},
 };
 
+/// Maximum time Media Server is given to assign a [`NegotiationRole`] after a
+/// [`Command::RequestRenegotiation`].
+const RENEGOTIATION_REQUEST_TIMEOUT: Duration = {
+    #[cfg(not(feature = "mockable"))]
+    {
+        Duration::from_secs(10)
+    }
+    #[cfg(feature = "mockable")]
+    {
+        Duration::from_millis(500)
+    }
+};
+
 /// Errors occurring in watchers of a [`Component`].
 #[derive(Clone, Debug, Display, From)]
 enum PeerWatcherError {
@@ -36,6 +52,9 @@ enum PeerWatcherError {
     ///
     /// [`Sender`]: sender::Sender
     SenderCreateFailed(sender::CreateError),
+
+    /// SDP negotiation step failed.
+    Sdp(SdpError),
 }
 
 // TODO: Don't force spawned watchers to return `Result`.
@@ -48,16 +67,21 @@ impl Component {
     #[watch(self.ice_candidates.on_add())]
     async fn ice_candidate_added(
         peer: Rc<PeerConnection>,
-        _: Rc<State>,
+        state: Rc<State>,
         candidate: IceCandidate,
     ) -> Result<(), Traced<RtcPeerConnectionError>> {
-        peer.add_ice_candidate(
-            candidate.candidate,
-            candidate.sdp_m_line_index,
-            candidate.sdp_mid,
-        )
-        .await
-        .map_err(tracerr::map_from_and_wrap!())
+        let res = peer
+            .add_ice_candidate(
+                candidate.candidate,
+                candidate.sdp_m_line_index,
+                candidate.sdp_mid,
+            )
+            .await;
+        // Candidates of an ignored remote SDP offer are expected to fail.
+        if state.ignore_offer.get() {
+            return Ok(());
+        }
+        res.map_err(tracerr::map_from_and_wrap!())
     }
 
     /// Watcher for the [`State::remote_sdp`] update.
@@ -68,6 +92,9 @@ impl Component {
     /// Calls [`PeerConnection::set_remote_offer()`] with a new value if the
     /// current [`NegotiationRole`] is an [`Answerer`].
     ///
+    /// Resets the current [`NegotiationRole`] if the new value fails to be
+    /// applied.
+    ///
     /// [`Answerer`]: NegotiationRole::Answerer
     /// [`Offerer`]: NegotiationRole::Offerer
     #[watch(self.remote_sdp.subscribe().filter_map(transpose_guarded))]
@@ -75,22 +102,44 @@ impl Component {
         peer: Rc<PeerConnection>,
         state: Rc<State>,
         description: Guarded<String>,
-    ) -> Result<(), Traced<RtcPeerConnectionError>> {
+    ) -> Result<(), Traced<SdpError>> {
         let (description, _guard) = description.into_parts();
         if let Some(role) = state.negotiation_role.get() {
+            let sdp = SdpError::snippet(&description);
+            let wrap_err = SdpError::wrap(|cause| {
+                SdpError::SetRemoteDescription { sdp, cause }
+            });
+            // Failed description is rolled back by the `PeerConnection`, so
+            // the negotiation is abandoned to allow a new one.
+            let wrap_err = |e| {
+                state.reset_negotiation_role();
+                wrap_err(e)
+            };
             match role {
                 NegotiationRole::Offerer => {
+                    state.sdp_munging.get().check_answer(&description);
                     peer.set_remote_answer(description)
                         .await
-                        .map_err(tracerr::map_from_and_wrap!())?;
+                        .map_err(wrap_err)?;
                     peer.media_connections.sync_receivers().await;
+                    peer.check_transceiver_directions();
                     state.negotiation_phase.set(NegotiationPhase::Stable);
                     state.negotiation_role.set(None);
                 }
                 NegotiationRole::Answerer(_) => {
+                    // Polite side of an SDP offers collision abandons its own
+                    // pending offer.
+                    if peer.peer.signaling_state()
+                        == platform::SignalingState::HaveLocalOffer
+                    {
+                        peer.peer
+                            .rollback()
+                            .await
+                            .map_err(SdpError::wrap(SdpError::Rollback))?;
+                    }
                     peer.set_remote_offer(description)
                         .await
-                        .map_err(tracerr::map_from_and_wrap!())?;
+                        .map_err(wrap_err)?;
                     peer.media_connections.sync_receivers().await;
                 }
             }
@@ -171,11 +220,12 @@ impl Component {
         )
         .await
         .inspect_err(|e| {
-            drop(peer.peer_events_sender.unbounded_send(
+            send_peer_event(
+                &peer.peer_events_sender,
                 PeerEvent::FailedLocalMedia {
                     error: tracerr::map_from(e.clone()),
                 },
-            ));
+            );
         })
         .map_err(tracerr::map_from_and_wrap!())?;
         peer.media_connections
@@ -259,6 +309,9 @@ impl Component {
         _ = state.sync_phase.when_eq(SyncPhase::Synced).await;
         if let Some(role) = state.negotiation_role.get() {
             if state.local_sdp.is_rollback() {
+                // Rolled back offer is not being made anymore, so it mustn't
+                // collide with the following remote offers.
+                state.making_offer.set(false);
                 // TODO: Temporary fix that allows us to ignore rollback
                 //       since it won't work anyway.
                 if state.negotiation_phase.get() != NegotiationPhase::Stable {
@@ -276,25 +329,30 @@ impl Component {
             } else {
                 match role {
                     NegotiationRole::Offerer => {
-                        peer.peer
-                            .set_offer(&sdp)
-                            .await
-                            .map_err(tracerr::map_from_and_wrap!())?;
+                        let res = peer.peer.set_offer(&sdp).await;
+                        state.making_offer.set(false);
+                        res.map_err(SdpError::wrap(|cause| {
+                            SdpError::SetLocalDescription {
+                                sdp: SdpError::snippet(&sdp),
+                                cause,
+                            }
+                        }))
+                        .map_err(tracerr::map_from_and_wrap!())?;
                         peer.media_connections.sync_receivers().await;
                         let mids = peer
                             .get_mids()
                             .map_err(tracerr::map_from_and_wrap!())?;
-                        _ = peer
-                            .peer_events_sender
-                            .unbounded_send(PeerEvent::NewSdpOffer {
+                        send_peer_event(
+                            &peer.peer_events_sender,
+                            PeerEvent::NewSdpOffer {
                                 peer_id: peer.id(),
                                 sdp_offer: sdp,
                                 transceivers_statuses: peer
                                     .get_transceivers_statuses()
                                     .await,
                                 mids,
-                            })
-                            .ok();
+                            },
+                        );
                         state
                             .negotiation_phase
                             .set(NegotiationPhase::WaitLocalSdpApprove);
@@ -303,18 +361,25 @@ impl Component {
                         peer.peer
                             .set_answer(&sdp)
                             .await
+                            .map_err(SdpError::wrap(|cause| {
+                                SdpError::SetLocalDescription {
+                                    sdp: SdpError::snippet(&sdp),
+                                    cause,
+                                }
+                            }))
                             .map_err(tracerr::map_from_and_wrap!())?;
                         peer.media_connections.sync_receivers().await;
-                        _ = peer
-                            .peer_events_sender
-                            .unbounded_send(PeerEvent::NewSdpAnswer {
+                        peer.check_transceiver_directions();
+                        send_peer_event(
+                            &peer.peer_events_sender,
+                            PeerEvent::NewSdpAnswer {
                                 peer_id: peer.id(),
                                 sdp_answer: sdp,
                                 transceivers_statuses: peer
                                     .get_transceivers_statuses()
                                     .await,
-                            })
-                            .ok();
+                            },
+                        );
                         state
                             .negotiation_phase
                             .set(NegotiationPhase::WaitLocalSdpApprove);
@@ -355,8 +420,8 @@ impl Component {
 
     /// Watcher for the [`NegotiationPhase`] change.
     ///
-    /// Resets [`NegotiationRole`] to [`None`] on a
-    /// [`NegotiationPhase::Stable`].
+    /// Resets [`NegotiationRole`] to [`None`] and forgets about an ignored
+    /// remote SDP offer on a [`NegotiationPhase::Stable`].
     ///
     /// Creates and sets local SDP offer on a
     /// [`NegotiationPhase::WaitLocalSdp`].
@@ -365,7 +430,7 @@ impl Component {
         peer: Rc<PeerConnection>,
         state: Rc<State>,
         negotiation_state: NegotiationPhase,
-    ) -> Result<(), Traced<RtcPeerConnectionError>> {
+    ) -> Result<(), Traced<SdpError>> {
         medea_reactive::when_all_processed(vec![
             state.when_all_updated().into(),
             state.when_all_senders_processed().into(),
@@ -382,26 +447,41 @@ impl Component {
                             if state.restart_ice.take() {
                                 peer.restart_ice();
                             }
+                            state.making_offer.set(true);
                             let sdp_offer = peer
                                 .peer
                                 .create_offer()
                                 .await
-                                .map_err(tracerr::map_from_and_wrap!())?;
-                            state.local_sdp.unapproved_set(sdp_offer);
+                                .map_err(SdpError::wrap(SdpError::CreateOffer))
+                                .inspect_err(|_| {
+                                    state.making_offer.set(false);
+                                })?;
+                            state.local_sdp.unapproved_set(
+                                state
+                                    .sdp_munging
+                                    .get()
+                                    .apply_to_offer(sdp_offer),
+                            );
                         }
                         NegotiationRole::Answerer(_) => {
-                            let sdp_answer = peer
-                                .peer
-                                .create_answer()
-                                .await
-                                .map_err(tracerr::map_from_and_wrap!())?;
-                            state.local_sdp.unapproved_set(sdp_answer);
+                            let sdp_answer =
+                                peer.peer.create_answer().await.map_err(
+                                    SdpError::wrap(SdpError::CreateAnswer),
+                                )?;
+                            state.local_sdp.unapproved_set(
+                                state
+                                    .sdp_munging
+                                    .get()
+                                    .apply_to_answer(sdp_answer),
+                            );
                         }
                     }
                 }
             }
-            NegotiationPhase::Stable
-            | NegotiationPhase::WaitLocalSdpApprove
+            NegotiationPhase::Stable => {
+                state.ignore_offer.set(false);
+            }
+            NegotiationPhase::WaitLocalSdpApprove
             | NegotiationPhase::WaitRemoteSdp => (),
         }
         Ok(())
@@ -412,6 +492,9 @@ impl Component {
     /// Waits for [`sender::Component`]s' and [`receiver::Component`]s'
     /// creation/update, updates local `MediaStream` (if required) and
     /// renegotiates [`PeerConnection`].
+    ///
+    /// Doesn't wait for the local `MediaStream` update if
+    /// [`State::early_media`] is enabled.
     #[watch(self.negotiation_role.subscribe().filter_map(transpose_guarded))]
     async fn negotiation_role_changed(
         _: Rc<PeerConnection>,
@@ -456,7 +539,9 @@ impl Component {
         }
 
         state.maybe_update_local_stream.set(true);
-        _ = state.maybe_update_local_stream.when_eq(false).await;
+        if !state.early_media.get() {
+            _ = state.maybe_update_local_stream.when_eq(false).await;
+        }
 
         state.negotiation_phase.set(NegotiationPhase::WaitLocalSdp);
     }
@@ -476,10 +561,116 @@ impl Component {
         }
     }
 
+    /// Watcher for the [`State::stats_history_capacity`] updates.
+    ///
+    /// Applies the new capacity to the [`PeerConnection::stats_history()`].
+    #[watch(self.stats_history_capacity.subscribe())]
+    fn stats_history_capacity_changed(
+        peer: &PeerConnection,
+        _: &State,
+        capacity: usize,
+    ) {
+        peer.stats_history.borrow_mut().set_capacity(capacity);
+    }
+
+    /// Watcher for the [`State::stats_sending_enabled`] updates.
+    ///
+    /// Enables or disables sending [`PeerEvent::StatsUpdate`]s by the
+    /// [`PeerConnection`].
+    #[watch(self.stats_sending_enabled.subscribe())]
+    fn stats_sending_enabled_changed(
+        peer: &PeerConnection,
+        _: &State,
+        enabled: bool,
+    ) {
+        peer.stats_sending_enabled.set(enabled);
+    }
+
+    /// Watcher for the [`State::disconnect_grace_period`] updates.
+    ///
+    /// Sets the grace period of the [`PeerConnection`]'s
+    /// [`PeerConnectionState::Disconnected`] reports.
+    ///
+    /// [`PeerConnectionState::Disconnected`]: medea_client_api_proto::PeerConnectionState::Disconnected
+    #[watch(self.disconnect_grace_period.subscribe())]
+    fn disconnect_grace_period_changed(
+        peer: &PeerConnection,
+        _: &State,
+        period: Duration,
+    ) {
+        peer.disconnect_grace.set_period(period);
+    }
+
+    /// Watcher for the [`State::ice_candidate_filter`] updates.
+    ///
+    /// Applies the new [`IceCandidateFilter`] to the [`PeerConnection`].
+    #[watch(self.ice_candidate_filter.subscribe())]
+    fn ice_candidate_filter_changed(
+        peer: &PeerConnection,
+        _: &State,
+        filter: Option<IceCandidateFilter>,
+    ) {
+        peer.set_ice_candidate_filter(filter);
+    }
+
+    /// Watcher for the [`State::codec_preferences`] updates.
+    ///
+    /// Applies the new [`CodecPreferences`] to the transceivers of the
+    /// [`PeerConnection`].
+    #[watch(self.codec_preferences.subscribe().skip(1))]
+    async fn codec_preferences_changed(
+        peer: Rc<PeerConnection>,
+        _: Rc<State>,
+        preferences: CodecPreferences,
+    ) {
+        peer.media_connections.set_codec_preferences(preferences).await;
+    }
+
+    /// Watcher for the [`State::sdp_munging`] updates.
+    ///
+    /// Makes the [`PeerConnection`] capture stereo audio if stereo is
+    /// negotiated.
+    #[expect(clippy::needless_pass_by_value, reason = "required by macro")]
+    #[watch(self.sdp_munging.subscribe())]
+    fn sdp_munging_changed(
+        peer: &PeerConnection,
+        _: &State,
+        munging: SdpMunging,
+    ) {
+        peer.stereo_audio.set(munging.opus().stereo == Some(true));
+    }
+
+    /// Watcher for the [`State::ice_servers`] updates.
+    ///
+    /// Applies the new [`IceServer`]s to the [`PeerConnection`].
+    #[watch(self.ice_servers.subscribe().skip(1))]
+    fn ice_servers_changed(
+        peer: &PeerConnection,
+        state: &State,
+        _: Vec<IceServer>,
+    ) -> Result<(), Traced<RtcPeerConnectionError>> {
+        peer.update_ice_servers(state.rtc_ice_servers())
+    }
+
+    /// Watcher for the [`State::drop_mdns_candidates`] updates.
+    ///
+    /// Applies the new value to the [`PeerConnection`].
+    #[watch(self.drop_mdns_candidates.subscribe())]
+    fn drop_mdns_candidates_changed(
+        peer: &PeerConnection,
+        _: &State,
+        drop_mdns: bool,
+    ) {
+        peer.drop_mdns_candidates.set(drop_mdns);
+    }
+
     /// Watcher for the [`State::maybe_update_local_stream`] `true` updates.
     ///
     /// Waits for [`State::senders`] update and calls
     /// [`State::update_local_stream()`].
+    ///
+    /// Requests a renegotiation once the [`State::update_local_stream()`]
+    /// succeeds, if [`State::early_media`] is enabled.
     #[watch(
         self.maybe_update_local_stream.subscribe().filter(|v| future::ready(*v))
     )]
@@ -489,8 +680,56 @@ impl Component {
         _: bool,
     ) {
         state.senders.when_updated().await;
-        drop(state.update_local_stream(&peer).await);
+        let has_outdated = !state.senders.get_outdated().is_empty();
+        let res = state.update_local_stream(&peer).await;
 
         state.maybe_update_local_stream.set(false);
+
+        // Negotiation hasn't waited for the acquired tracks, so they're added
+        // via renegotiation.
+        if state.early_media.get() && has_outdated && res.is_ok() {
+            state.renegotiate();
+        }
+    }
+
+    /// Watcher for the [`State::renegotiation_needed`] `true` updates.
+    ///
+    /// Waits for the current negotiation (if any) to finish and asks Media
+    /// Server for a new one with a [`Command::RequestRenegotiation`], waiting
+    /// for Media Server to assign a [`NegotiationRole`] to this [`Component`].
+    /// All the renegotiation requests made meanwhile are coalesced into this
+    /// single negotiation.
+    ///
+    /// If Media Server doesn't assign any [`NegotiationRole`] in
+    /// [`RENEGOTIATION_REQUEST_TIMEOUT`] (e.g. ignores or doesn't support the
+    /// [`Command::RequestRenegotiation`]), the request is considered lost, so
+    /// the next [`State::renegotiate()`] call requests a renegotiation again.
+    #[watch(
+        self.renegotiation_needed.subscribe().filter(|v| future::ready(*v))
+    )]
+    async fn renegotiation_requested(
+        peer: Rc<PeerConnection>,
+        state: Rc<State>,
+        _: bool,
+    ) {
+        _ = state
+            .negotiation_role
+            .subscribe()
+            .any(async |val| val.is_none())
+            .await;
+        send_peer_event(
+            &peer.peer_events_sender,
+            PeerEvent::MediaUpdateCommand {
+                command: Command::RequestRenegotiation { peer_id: state.id },
+            },
+        );
+        let role_assigned =
+            state.negotiation_role.subscribe().any(async |val| val.is_some());
+        _ = future::select(
+            Box::pin(role_assigned),
+            Box::pin(platform::delay_for(RENEGOTIATION_REQUEST_TIMEOUT)),
+        )
+        .await;
+        state.renegotiation_needed.set(false);
     }
 }
@@ -0,0 +1,182 @@
+//! Timeline of the [`IceConnectionState`] transitions of a
+//! [`PeerConnection`].
+//!
+//! [`PeerConnection`]: super::PeerConnection
+
+use std::time::Duration;
+
+use medea_client_api_proto::IceConnectionState;
+
+/// Durations between the [`IceConnectionState`] transitions of a
+/// [`PeerConnection`] during its last ICE connectivity checks.
+///
+/// [`PeerConnection`]: super::PeerConnection
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ConnectionTimeline {
+    /// Duration between the [`IceConnectionState::Checking`] and
+    /// [`IceConnectionState::Connected`] transitions.
+    pub checking_to_connected: Option<Duration>,
+
+    /// Duration between the [`IceConnectionState::Connected`] and
+    /// [`IceConnectionState::Completed`] transitions.
+    pub connected_to_completed: Option<Duration>,
+
+    /// Duration between the [`IceConnectionState::Checking`] and
+    /// [`IceConnectionState::Completed`] transitions.
+    pub checking_to_completed: Option<Duration>,
+}
+
+/// Monotonic timestamps of the [`IceConnectionState`] transitions of a
+/// [`PeerConnection`].
+///
+/// [`PeerConnection`]: super::PeerConnection
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IceTransitionTimestamps {
+    /// Time of the last [`IceConnectionState::Checking`] transition.
+    checking: Option<Duration>,
+
+    /// Time of the first [`IceConnectionState::Connected`] transition after
+    /// the last [`IceConnectionState::Checking`] one.
+    connected: Option<Duration>,
+
+    /// Time of the first [`IceConnectionState::Completed`] transition after
+    /// the last [`IceConnectionState::Checking`] one.
+    completed: Option<Duration>,
+}
+
+impl IceTransitionTimestamps {
+    /// Records the transition to the provided [`IceConnectionState`] happened
+    /// at the provided time.
+    ///
+    /// A new [`IceConnectionState::Checking`] transition (on ICE restart, for
+    /// example) starts a new timeline, discarding the previous one.
+    pub fn record(&mut self, state: IceConnectionState, at: Duration) {
+        match state {
+            IceConnectionState::Checking => {
+                let is_new_checking = self.checking.is_none()
+                    || self.connected.is_some()
+                    || self.completed.is_some();
+                if is_new_checking {
+                    *self = Self { checking: Some(at), ..Self::default() };
+                }
+            }
+            IceConnectionState::Connected => {
+                _ = self.connected.get_or_insert(at);
+            }
+            IceConnectionState::Completed => {
+                _ = self.completed.get_or_insert(at);
+            }
+            IceConnectionState::New
+            | IceConnectionState::Failed
+            | IceConnectionState::Disconnected
+            | IceConnectionState::Closed => (),
+        }
+    }
+
+    /// Returns the [`ConnectionTimeline`] of these
+    /// [`IceTransitionTimestamps`].
+    #[must_use]
+    pub fn timeline(&self) -> ConnectionTimeline {
+        let between = |from: Option<Duration>, to: Option<Duration>| {
+            Some(to?.saturating_sub(from?))
+        };
+        ConnectionTimeline {
+            checking_to_connected: between(self.checking, self.connected),
+            connected_to_completed: between(self.connected, self.completed),
+            checking_to_completed: between(self.checking, self.completed),
+        }
+    }
+}
+
+/// Statistics of the ICE restarts of a [`PeerConnection`].
+///
+/// [`PeerConnection`]: super::PeerConnection
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct IceRestartStats {
+    /// Number of the ICE restarts performed.
+    pub count: u32,
+
+    /// Time of the last ICE restart as a [`Duration`] elapsed since the UNIX
+    /// epoch, if any.
+    pub last_restart_at: Option<Duration>,
+}
+
+impl IceRestartStats {
+    /// Records an ICE restart happened at the provided time.
+    pub const fn record(&mut self, at: Duration) {
+        self.count = self.count.saturating_add(1);
+        self.last_restart_at = Some(at);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn measures_durations_between_transitions() {
+        let mut timestamps = IceTransitionTimestamps::default();
+        timestamps.record(IceConnectionState::New, ms(0));
+        timestamps.record(IceConnectionState::Checking, ms(100));
+        timestamps.record(IceConnectionState::Connected, ms(350));
+        timestamps.record(IceConnectionState::Completed, ms(400));
+
+        assert_eq!(
+            timestamps.timeline(),
+            ConnectionTimeline {
+                checking_to_connected: Some(ms(250)),
+                connected_to_completed: Some(ms(50)),
+                checking_to_completed: Some(ms(300)),
+            },
+        );
+    }
+
+    #[test]
+    fn ignores_repeated_transitions() {
+        let mut timestamps = IceTransitionTimestamps::default();
+        timestamps.record(IceConnectionState::Checking, ms(100));
+        timestamps.record(IceConnectionState::Checking, ms(200));
+        timestamps.record(IceConnectionState::Connected, ms(300));
+        timestamps.record(IceConnectionState::Disconnected, ms(400));
+        timestamps.record(IceConnectionState::Connected, ms(500));
+
+        assert_eq!(
+            timestamps.timeline(),
+            ConnectionTimeline {
+                checking_to_connected: Some(ms(200)),
+                connected_to_completed: None,
+                checking_to_completed: None,
+            },
+        );
+    }
+
+    #[test]
+    fn restarts_on_new_checking() {
+        let mut timestamps = IceTransitionTimestamps::default();
+        timestamps.record(IceConnectionState::Checking, ms(100));
+        timestamps.record(IceConnectionState::Connected, ms(300));
+        timestamps.record(IceConnectionState::Checking, ms(1000));
+
+        assert_eq!(timestamps.timeline(), ConnectionTimeline::default());
+
+        timestamps.record(IceConnectionState::Connected, ms(1100));
+
+        assert_eq!(timestamps.timeline().checking_to_connected, Some(ms(100)));
+    }
+
+    #[test]
+    fn counts_ice_restarts() {
+        let mut stats = IceRestartStats::default();
+        stats.record(ms(100));
+        stats.record(ms(700));
+
+        assert_eq!(
+            stats,
+            IceRestartStats { count: 2, last_restart_at: Some(ms(700)) },
+        );
+    }
+}
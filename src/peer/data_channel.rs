@@ -0,0 +1,131 @@
+//! Application-level data channel of a [`PeerConnection`].
+//!
+//! [`PeerConnection`]: super::PeerConnection
+
+use std::{cell::RefCell, rc::Rc};
+
+use futures::{
+    FutureExt as _, channel::mpsc, future::LocalBoxFuture,
+    stream::LocalBoxStream,
+};
+use medea_reactive::ObservableCell;
+use tracerr::Traced;
+
+use crate::platform::{self, DataChannelError};
+
+/// Data channel of a [`PeerConnection`] used for sending arbitrary
+/// application-level messages.
+///
+/// [`PeerConnection`]: super::PeerConnection
+#[derive(Clone, Debug)]
+pub struct DataChannel(Rc<Inner>);
+
+/// Inner representation of a [`DataChannel`].
+#[derive(Debug)]
+struct Inner {
+    /// Underlying [`platform::DataChannel`].
+    channel: platform::DataChannel,
+
+    /// Subscribers to the messages received by this [`DataChannel`].
+    on_message_subs: Rc<RefCell<Vec<mpsc::UnboundedSender<Vec<u8>>>>>,
+
+    /// Indicator whether the underlying [`platform::DataChannel`] is open.
+    is_open: Rc<ObservableCell<bool>>,
+
+    /// Indicator whether the underlying [`platform::DataChannel`] is closed.
+    is_closed: Rc<ObservableCell<bool>>,
+}
+
+impl DataChannel {
+    /// Wraps the provided [`platform::DataChannel`] into a new
+    /// [`DataChannel`].
+    #[must_use]
+    pub fn new(channel: platform::DataChannel) -> Self {
+        let on_message_subs: Rc<RefCell<Vec<mpsc::UnboundedSender<_>>>> =
+            Rc::default();
+        channel.on_message(Some({
+            let subs = Rc::downgrade(&on_message_subs);
+            move |msg: Vec<u8>| {
+                if let Some(subs) = subs.upgrade() {
+                    subs.borrow_mut()
+                        .retain(|sub| sub.unbounded_send(msg.clone()).is_ok());
+                }
+            }
+        }));
+
+        let is_open = Rc::new(ObservableCell::new(channel.is_open()));
+        channel.on_open(Some({
+            let is_open = Rc::downgrade(&is_open);
+            move || {
+                if let Some(is_open) = is_open.upgrade() {
+                    is_open.set(true);
+                }
+            }
+        }));
+
+        let is_closed = Rc::new(ObservableCell::new(false));
+        channel.on_close(Some({
+            let is_closed = Rc::downgrade(&is_closed);
+            move || {
+                if let Some(is_closed) = is_closed.upgrade() {
+                    is_closed.set(true);
+                }
+            }
+        }));
+
+        Self(Rc::new(Inner { channel, on_message_subs, is_open, is_closed }))
+    }
+
+    /// Returns label of this [`DataChannel`].
+    #[must_use]
+    pub fn label(&self) -> String {
+        self.0.channel.label()
+    }
+
+    /// Returns a [`LocalBoxFuture`] resolving once this [`DataChannel`] is
+    /// open, so messages can be sent via it.
+    ///
+    /// A locally created [`DataChannel`] is opened only once it's negotiated.
+    #[must_use]
+    pub fn when_open(&self) -> LocalBoxFuture<'static, ()> {
+        self.0.is_open.when_eq(true).map(drop).boxed_local()
+    }
+
+    /// Returns a [`LocalBoxFuture`] resolving once this [`DataChannel`] is
+    /// closed, either locally or by the remote peer.
+    #[must_use]
+    pub fn when_closed(&self) -> LocalBoxFuture<'static, ()> {
+        self.0.is_closed.when_eq(true).map(drop).boxed_local()
+    }
+
+    /// Indicates whether this [`DataChannel`] is closed.
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.0.is_closed.get()
+    }
+
+    /// Sends the provided binary `data` to the remote peer.
+    ///
+    /// # Errors
+    ///
+    /// With [`DataChannelError::SendFailed`] if the underlying
+    /// [`platform::DataChannel`] fails to send the message.
+    pub fn send(&self, data: &[u8]) -> Result<(), Traced<DataChannelError>> {
+        self.0.channel.send(data)
+    }
+
+    /// Returns a [`LocalBoxStream`] of all the messages received by this
+    /// [`DataChannel`] after this call.
+    #[must_use]
+    pub fn on_message(&self) -> LocalBoxStream<'static, Vec<u8>> {
+        let (tx, rx) = mpsc::unbounded();
+        self.0.on_message_subs.borrow_mut().push(tx);
+
+        Box::pin(rx)
+    }
+
+    /// Closes this [`DataChannel`].
+    pub fn close(&self) {
+        self.0.channel.close();
+    }
+}
@@ -0,0 +1,78 @@
+//! Grace period before reporting a [`PeerConnection`] as disconnected.
+//!
+//! [`PeerConnection`]: super::PeerConnection
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+
+use futures::future;
+use medea_client_api_proto::PeerConnectionState;
+
+use crate::{platform, utils::TaskHandle};
+
+/// Reporter of the [`PeerConnectionState`] changes of a [`PeerConnection`],
+/// delaying a [`PeerConnectionState::Disconnected`] for a grace period, so
+/// brief network blips are not reported at all.
+///
+/// [`PeerConnection`]: super::PeerConnection
+#[derive(Debug, Default)]
+pub struct DisconnectGrace {
+    /// Period a [`PeerConnection`] may stay in a
+    /// [`PeerConnectionState::Disconnected`] before it's reported.
+    ///
+    /// [`Duration::ZERO`] reports it immediately.
+    ///
+    /// [`PeerConnection`]: super::PeerConnection
+    period: Cell<Duration>,
+
+    /// [`TaskHandle`] of the delayed [`PeerConnectionState::Disconnected`]
+    /// report, if any.
+    pending: RefCell<Option<TaskHandle>>,
+
+    /// Last reported [`PeerConnectionState`].
+    reported: Rc<Cell<Option<PeerConnectionState>>>,
+}
+
+impl DisconnectGrace {
+    /// Sets the period a [`PeerConnection`] may stay in a
+    /// [`PeerConnectionState::Disconnected`] before it's reported.
+    ///
+    /// [`PeerConnection`]: super::PeerConnection
+    pub fn set_period(&self, period: Duration) {
+        self.period.set(period);
+    }
+
+    /// Reports the provided [`PeerConnectionState`] via the provided `emit`
+    /// function.
+    ///
+    /// A [`PeerConnectionState::Disconnected`] is reported only once the grace
+    /// period elapses, and is discarded if any other [`PeerConnectionState`]
+    /// is reported before that. The same [`PeerConnectionState`] is never
+    /// reported twice in a row.
+    pub fn report<F>(&self, state: PeerConnectionState, emit: F)
+    where
+        F: FnOnce(PeerConnectionState) + 'static,
+    {
+        drop(self.pending.take());
+
+        let period = self.period.get();
+        if state == PeerConnectionState::Disconnected && !period.is_zero() {
+            let reported = Rc::clone(&self.reported);
+            let (fut, abort) = future::abortable(async move {
+                platform::delay_for(period).await;
+                if reported.replace(Some(state)) != Some(state) {
+                    emit(state);
+                }
+            });
+            platform::spawn(async move {
+                _ = fut.await.ok();
+            });
+            drop(self.pending.replace(Some(abort.into())));
+        } else if self.reported.replace(Some(state)) != Some(state) {
+            emit(state);
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! Filtering of the ICE candidates discovered by a [`PeerConnection`].
+//!
+//! [`PeerConnection`]: super::PeerConnection
+
+use std::{fmt, rc::Rc, str::FromStr};
+
+use derive_more::with_trait::Display;
+
+/// Type of an ICE candidate.
+///
+/// See [RFC 8445 Section 5.1.1][1] for details.
+///
+/// [1]: https://tools.ietf.org/html/rfc8445#section-5.1.1
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum IceCandidateType {
+    /// Host candidate, obtained from a local network interface.
+    #[display("host")]
+    Host,
+
+    /// Server reflexive candidate, obtained from a STUN server.
+    #[display("srflx")]
+    Srflx,
+
+    /// Peer reflexive candidate, discovered during connectivity checks.
+    #[display("prflx")]
+    Prflx,
+
+    /// Relayed candidate, obtained from a TURN server.
+    #[display("relay")]
+    Relay,
+}
+
+impl IceCandidateType {
+    /// Parses an [`IceCandidateType`] from the provided [`candidate`][1]
+    /// attribute.
+    ///
+    /// Both forms, with and without the `a=` prefix, are accepted. The
+    /// connection address is never interpreted, so IPv4, IPv6 and mDNS
+    /// addresses are handled equally.
+    ///
+    /// Returns [`None`] if the provided `candidate` has no (or an unknown)
+    /// type.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcicecandidate-candidate
+    #[must_use]
+    pub fn parse(candidate: &str) -> Option<Self> {
+        let mut tokens = candidate.split_ascii_whitespace();
+        _ = tokens.by_ref().find(|t| *t == "typ")?;
+        tokens.next()?.parse().ok()
+    }
+}
+
+impl FromStr for IceCandidateType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "host" => Self::Host,
+            "srflx" => Self::Srflx,
+            "prflx" => Self::Prflx,
+            "relay" => Self::Relay,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Indicates whether the provided [`candidate`][1] attribute describes a host
+/// candidate with an [mDNS] (`.local`) connection address.
+///
+/// Server reflexive and relayed candidates never have [mDNS] addresses, so
+/// they're never matched.
+///
+/// [mDNS]: https://tools.ietf.org/html/draft-ietf-mmusic-mdns-ice-candidates
+/// [1]: https://w3.org/TR/webrtc#dom-rtcicecandidate-candidate
+#[must_use]
+pub fn is_mdns_host_candidate(candidate: &str) -> bool {
+    IceCandidateType::parse(candidate) == Some(IceCandidateType::Host)
+        && candidate
+            .split_ascii_whitespace()
+            .nth(4)
+            .is_some_and(|addr| addr.to_ascii_lowercase().ends_with(".local"))
+}
+
+/// Predicate deciding whether an ICE candidate of some [`IceCandidateType`]
+/// should be emitted to the remote peer.
+#[derive(Clone)]
+pub struct IceCandidateFilter(Rc<dyn Fn(IceCandidateType) -> bool>);
+
+impl IceCandidateFilter {
+    /// Creates a new [`IceCandidateFilter`] from the provided predicate.
+    #[must_use]
+    pub fn new<F>(predicate: F) -> Self
+    where
+        F: Fn(IceCandidateType) -> bool + 'static,
+    {
+        Self(Rc::new(predicate))
+    }
+
+    /// Creates a new [`IceCandidateFilter`] allowing relay candidates only.
+    #[must_use]
+    pub fn relay_only() -> Self {
+        Self::new(|t| t == IceCandidateType::Relay)
+    }
+
+    /// Indicates whether the provided [`candidate`][1] attribute passes this
+    /// [`IceCandidateFilter`].
+    ///
+    /// Candidates of unknown type always pass.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcicecandidate-candidate
+    #[must_use]
+    pub fn allows(&self, candidate: &str) -> bool {
+        IceCandidateType::parse(candidate).is_none_or(|t| (self.0)(t))
+    }
+}
+
+impl fmt::Debug for IceCandidateFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IceCandidateFilter").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for IceCandidateFilter {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ipv4_candidates() {
+        assert_eq!(
+            IceCandidateType::parse(
+                "candidate:842163049 1 udp 1677729535 203.0.113.7 46154 typ \
+                 srflx raddr 192.168.1.2 rport 46154 generation 0",
+            ),
+            Some(IceCandidateType::Srflx),
+        );
+        assert_eq!(
+            IceCandidateType::parse(
+                "a=candidate:1 1 UDP 2130706431 192.168.1.2 54400 typ host",
+            ),
+            Some(IceCandidateType::Host),
+        );
+    }
+
+    #[test]
+    fn parses_ipv6_candidates() {
+        assert_eq!(
+            IceCandidateType::parse(
+                "candidate:3 1 udp 41885439 2001:db8::1 3478 typ relay raddr \
+                 2001:db8::2 rport 50000",
+            ),
+            Some(IceCandidateType::Relay),
+        );
+        assert_eq!(
+            IceCandidateType::parse(
+                "candidate:4 1 tcp 1518280447 fe80::1 9 typ host tcptype \
+                 active",
+            ),
+            Some(IceCandidateType::Host),
+        );
+    }
+
+    #[test]
+    fn parses_mdns_candidates() {
+        assert_eq!(
+            IceCandidateType::parse(
+                "candidate:5 1 udp 2113937151 \
+                 c0ffee00-1234-4321-abcd-0123456789ab.local 51234 typ host",
+            ),
+            Some(IceCandidateType::Host),
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_candidates() {
+        assert_eq!(IceCandidateType::parse(""), None);
+        assert_eq!(IceCandidateType::parse("candidate:1 1 udp 1 ::1 9"), None);
+        assert_eq!(IceCandidateType::parse("candidate:1 typ"), None);
+        assert_eq!(IceCandidateType::parse("candidate:1 typ unknown"), None);
+    }
+
+    #[test]
+    fn detects_mdns_host_candidates() {
+        assert!(is_mdns_host_candidate(
+            "candidate:5 1 udp 2113937151 \
+             c0ffee00-1234-4321-abcd-0123456789ab.local 51234 typ host",
+        ));
+        assert!(is_mdns_host_candidate(
+            "a=candidate:5 1 UDP 2113937151 ABCD.LOCAL 51234 typ host",
+        ));
+        assert!(!is_mdns_host_candidate(
+            "candidate:1 1 UDP 2130706431 192.168.1.2 54400 typ host",
+        ));
+        assert!(!is_mdns_host_candidate(
+            "candidate:2 1 udp 1677729535 2001:db8::1 46154 typ srflx raddr \
+             abcd.local rport 46154",
+        ));
+        assert!(!is_mdns_host_candidate(""));
+    }
+
+    #[test]
+    fn relay_only_filter() {
+        let filter = IceCandidateFilter::relay_only();
+
+        assert!(filter.allows("candidate:1 1 udp 1 ::1 9 typ relay"));
+        assert!(!filter.allows("candidate:1 1 udp 1 ::1 9 typ host"));
+        assert!(!filter.allows("candidate:1 1 udp 1 1.2.3.4 9 typ srflx"));
+        assert!(filter.allows(""));
+    }
+}
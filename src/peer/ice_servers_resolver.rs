@@ -0,0 +1,85 @@
+//! Resolving the [`IceServer`]s actually used by a [`PeerConnection`].
+//!
+//! [`PeerConnection`]: super::PeerConnection
+
+use std::{fmt, rc::Rc};
+
+use medea_client_api_proto::IceServer;
+
+/// Resolver of the [`IceServer`]s actually used by a [`PeerConnection`],
+/// receiving the configured [`IceServer`]s and returning the (possibly
+/// reordered or filtered) ones.
+///
+/// Allows selecting the nearest TURN server at connection time without
+/// changing the signalling.
+///
+/// [`PeerConnection`]: super::PeerConnection
+#[derive(Clone)]
+pub struct IceServersResolver(Rc<dyn Fn(Vec<IceServer>) -> Vec<IceServer>>);
+
+impl IceServersResolver {
+    /// Creates a new [`IceServersResolver`] from the provided function.
+    #[must_use]
+    pub fn new<F>(resolve: F) -> Self
+    where
+        F: Fn(Vec<IceServer>) -> Vec<IceServer> + 'static,
+    {
+        Self(Rc::new(resolve))
+    }
+
+    /// Resolves the [`IceServer`]s to be actually used out of the provided
+    /// configured ones.
+    #[must_use]
+    pub fn resolve(&self, ice_servers: Vec<IceServer>) -> Vec<IceServer> {
+        (self.0)(ice_servers)
+    }
+}
+
+impl fmt::Debug for IceServersResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IceServersResolver").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use medea_client_api_proto::{ConnectionMode, PeerId};
+
+    use super::*;
+    use crate::peer::State;
+
+    fn ice_server(url: &str) -> IceServer {
+        IceServer { urls: vec![url.into()], username: None, credential: None }
+    }
+
+    #[test]
+    fn resolves_configured_ice_servers() {
+        let state = State::new(
+            PeerId(1),
+            vec![
+                ice_server("turn:far.example.com"),
+                ice_server("turn:near.example.com"),
+            ],
+            false,
+            None,
+            ConnectionMode::Mesh,
+        );
+        assert_eq!(state.rtc_ice_servers().len(), 2);
+
+        state.set_ice_servers_resolver(Some(IceServersResolver::new(
+            |servers| {
+                servers
+                    .into_iter()
+                    .filter(|s| s.urls.iter().any(|u| u.contains("near")))
+                    .collect()
+            },
+        )));
+        assert_eq!(
+            state.rtc_ice_servers(),
+            vec![ice_server("turn:near.example.com")],
+        );
+
+        state.set_ice_servers_resolver(None);
+        assert_eq!(state.rtc_ice_servers().len(), 2);
+    }
+}
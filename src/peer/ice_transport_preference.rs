@@ -0,0 +1,205 @@
+//! Preference of transport protocols used to reach TURN servers.
+
+use derive_more::with_trait::Display;
+use medea_client_api_proto::IceServer;
+
+/// Transport protocol used to reach a TURN server.
+///
+/// See [RFC 7065 Section 3][1] for details.
+///
+/// [1]: https://tools.ietf.org/html/rfc7065#section-3
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum IceTransportProtocol {
+    /// TURN over UDP (`turn:` URL with `transport=udp`).
+    #[display("udp")]
+    Udp,
+
+    /// TURN over TCP (`turn:` URL with `transport=tcp`).
+    #[display("tcp")]
+    Tcp,
+
+    /// TURN over TLS (`turns:` URL).
+    #[display("tls")]
+    Tls,
+}
+
+impl IceTransportProtocol {
+    /// Detects the [`IceTransportProtocol`] of the provided TURN server `url`.
+    ///
+    /// `turn:` URLs without an explicit `transport` are considered as
+    /// [`IceTransportProtocol::Udp`] ones, as UDP is the default transport of
+    /// TURN.
+    ///
+    /// Returns [`None`] if the provided `url` is not a TURN server URL.
+    fn of_url(url: &str) -> Option<Self> {
+        let (scheme, _) = url.split_once(':')?;
+        if scheme.eq_ignore_ascii_case("turns") {
+            Some(Self::Tls)
+        } else if scheme.eq_ignore_ascii_case("turn") {
+            Some(match transport_param(url) {
+                Some(t) if t.eq_ignore_ascii_case("tcp") => Self::Tcp,
+                _ => Self::Udp,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the value of the `transport` query parameter of the provided
+/// ICE server `url`, if any.
+fn transport_param(url: &str) -> Option<&str> {
+    let (_, query) = url.split_once('?')?;
+    query.split('&').find_map(|param| {
+        let (key, val) = param.split_once('=')?;
+        key.eq_ignore_ascii_case("transport").then_some(val)
+    })
+}
+
+/// Applies the provided `preference` order of [`IceTransportProtocol`]s to
+/// the URLs of the provided [`IceServer`]s.
+///
+/// `turn:` URLs without an explicit `transport` are kept (as they use UDP
+/// implicitly), and are complemented with the URLs having an explicit
+/// `transport=tcp` if TCP is preferred. URLs having an explicit `transport`
+/// are respected and left as is. Then TURN URLs of every [`IceServer`] are
+/// ordered according to the provided `preference`, while STUN URLs remain
+/// first.
+///
+/// Empty `preference` leaves the provided [`IceServer`]s untouched.
+#[must_use]
+pub fn apply_transport_preference(
+    servers: Vec<IceServer>,
+    preference: &[IceTransportProtocol],
+) -> Vec<IceServer> {
+    if preference.is_empty() {
+        return servers;
+    }
+
+    let rank = |url: &String| {
+        IceTransportProtocol::of_url(url).map_or(0, |proto| {
+            preference
+                .iter()
+                .position(|p| *p == proto)
+                .unwrap_or(preference.len())
+                + 1
+        })
+    };
+
+    servers
+        .into_iter()
+        .map(|mut server| {
+            let mut urls = Vec::with_capacity(server.urls.len());
+            for url in server.urls {
+                let is_implicit_turn = IceTransportProtocol::of_url(&url)
+                    .is_some_and(|p| p != IceTransportProtocol::Tls)
+                    && transport_param(&url).is_none();
+                if is_implicit_turn
+                    && preference.contains(&IceTransportProtocol::Tcp)
+                {
+                    let separator = if url.contains('?') { '&' } else { '?' };
+                    urls.push(format!(
+                        "{url}{separator}transport={}",
+                        IceTransportProtocol::Tcp,
+                    ));
+                }
+                urls.push(url);
+            }
+            urls.sort_by_key(rank);
+            server.urls = urls;
+            server
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server(urls: &[&str]) -> IceServer {
+        IceServer {
+            urls: urls.iter().map(ToString::to_string).collect(),
+            username: None,
+            credential: None,
+        }
+    }
+
+    #[test]
+    fn empty_preference_changes_nothing() {
+        let servers = vec![server(&["stun:a:3478", "turn:a:3478"])];
+
+        assert_eq!(apply_transport_preference(servers.clone(), &[]), servers);
+    }
+
+    #[test]
+    fn expands_implicit_transport() {
+        use IceTransportProtocol as P;
+
+        let servers = apply_transport_preference(
+            vec![server(&["turn:a:3478", "stun:a:3478"])],
+            &[P::Tcp, P::Udp],
+        );
+
+        assert_eq!(
+            servers,
+            vec![server(&[
+                "stun:a:3478",
+                "turn:a:3478?transport=tcp",
+                "turn:a:3478",
+            ])]
+        );
+
+        let servers = apply_transport_preference(
+            vec![server(&["turn:a:3478"])],
+            &[P::Udp],
+        );
+
+        assert_eq!(servers, vec![server(&["turn:a:3478"])]);
+    }
+
+    #[test]
+    fn keeps_implicit_udp_if_only_tcp_is_preferred() {
+        let servers = apply_transport_preference(
+            vec![server(&["turn:a:3478"])],
+            &[IceTransportProtocol::Tcp],
+        );
+
+        assert_eq!(
+            servers,
+            vec![server(&["turn:a:3478?transport=tcp", "turn:a:3478"])]
+        );
+    }
+
+    #[test]
+    fn respects_explicit_transport() {
+        use IceTransportProtocol as P;
+
+        let servers = apply_transport_preference(
+            vec![server(&[
+                "turn:a:3478?transport=udp",
+                "turns:a:5349?transport=tcp",
+                "turn:a:3478?transport=tcp",
+            ])],
+            &[P::Tls, P::Tcp],
+        );
+
+        assert_eq!(
+            servers,
+            vec![server(&[
+                "turns:a:5349?transport=tcp",
+                "turn:a:3478?transport=tcp",
+                "turn:a:3478?transport=udp",
+            ])]
+        );
+    }
+
+    #[test]
+    fn keeps_implicit_transport_if_only_tls_is_preferred() {
+        let servers = apply_transport_preference(
+            vec![server(&["turn:a:3478", "turns:a:5349"])],
+            &[IceTransportProtocol::Tls],
+        );
+
+        assert_eq!(servers, vec![server(&["turns:a:5349", "turn:a:3478"])]);
+    }
+}
@@ -0,0 +1,92 @@
+//! Running total of bytes transferred by [RTP] streams.
+//!
+//! [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+};
+
+use medea_client_api_proto::stats::StatId;
+
+/// Monotonic running total of bytes transferred by [RTP] streams, built from
+/// the cumulative counters reported by their stats.
+///
+/// Cumulative counters of [RTP] streams start over whenever the streams are
+/// recreated (e.g. on renegotiation or [ICE] restart), so only their
+/// increments are summed up.
+///
+/// [ICE]: https://webrtcglossary.com/ice
+/// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+#[derive(Debug, Default)]
+pub struct BytesCounter {
+    /// Total number of bytes transferred.
+    total: Cell<u64>,
+
+    /// Last cumulative number of bytes reported by each [RTP] stream.
+    ///
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    last: RefCell<HashMap<StatId, u64>>,
+}
+
+impl BytesCounter {
+    /// Records the cumulative number of `bytes` reported by the [RTP] stream
+    /// with the provided [`StatId`].
+    ///
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    pub fn record(&self, stream: &StatId, bytes: u64) {
+        let last = self.last.borrow_mut().insert(stream.clone(), bytes);
+        let increment = match last {
+            Some(last) if last <= bytes => bytes - last,
+            // Stream was recreated, so its counter has started over.
+            Some(_) | None => bytes,
+        };
+        self.total.set(self.total.get().saturating_add(increment));
+    }
+
+    /// Returns the total number of transferred bytes.
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.total.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(id: &str) -> StatId {
+        StatId(id.into())
+    }
+
+    #[test]
+    fn sums_increments_of_stream() {
+        let counter = BytesCounter::default();
+        counter.record(&id("a"), 100);
+        counter.record(&id("a"), 250);
+        counter.record(&id("a"), 250);
+
+        assert_eq!(counter.total(), 250);
+    }
+
+    #[test]
+    fn sums_multiple_streams() {
+        let counter = BytesCounter::default();
+        counter.record(&id("a"), 100);
+        counter.record(&id("b"), 40);
+        counter.record(&id("a"), 150);
+        counter.record(&id("b"), 60);
+
+        assert_eq!(counter.total(), 210);
+    }
+
+    #[test]
+    fn stays_monotonic_when_stream_starts_over() {
+        let counter = BytesCounter::default();
+        counter.record(&id("a"), 1000);
+        counter.record(&id("a"), 30);
+        counter.record(&id("a"), 50);
+
+        assert_eq!(counter.total(), 1050);
+    }
+}
@@ -2,26 +2,33 @@
 //!
 //! [`PeerConnection`]: crate::peer::PeerConnection
 
+mod bytes_counter;
 pub mod receiver;
 pub mod sender;
 mod transitable_state;
 
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, iter, rc::Rc};
 
 use derive_more::with_trait::{Display, From};
+#[cfg(feature = "mockable")]
+use futures::channel::mpsc;
 use futures::{
-    FutureExt as _, TryFutureExt as _, channel::mpsc, future,
+    FutureExt as _, TryFutureExt as _, channel::oneshot, future,
     future::LocalBoxFuture,
 };
 use medea_client_api_proto as proto;
 #[cfg(feature = "mockable")]
 use medea_client_api_proto::{ConnectionMode, MemberId};
-use proto::{MediaSourceKind, MediaType, TrackId};
+use proto::{
+    MediaSourceKind, MediaType, TrackId,
+    stats::{RtcInboundRtpStreamMediaType, RtcStatsType},
+};
 use tracerr::Traced;
 
+use self::bytes_counter::BytesCounter;
 #[doc(inline)]
 pub use self::{
-    receiver::Receiver,
+    receiver::{FrameSize, Receiver},
     sender::Sender,
     transitable_state::{
         InStable, InTransition, MediaExchangeState,
@@ -34,14 +41,21 @@ use super::tracks_request::TracksRequest;
 #[cfg(feature = "mockable")]
 use crate::media::{LocalTracksConstraints, RecvConstraints};
 use crate::{
-    media::{MediaKind, track::local},
-    peer::{LocalStreamUpdateCriteria, PeerEvent},
+    media::{
+        MediaKind, TrackConstraints,
+        track::{MediaStreamTrackState, local},
+    },
+    peer::{LocalStreamUpdateCriteria, PeerEventSender},
     platform,
     platform::{
-        TransceiverInit, send_encoding_parameters::SendEncodingParameters,
-        transceiver::probe_target_codecs,
+        TransceiverInit,
+        send_encoding_parameters::SendEncodingParameters,
+        transceiver::{probe_preferred_codecs, probe_target_codecs},
+    },
+    utils::{
+        Caused, Component,
+        logging::{self, LogCategory},
     },
-    utils::{Caused, Component},
 };
 
 /// Transceiver's sending ([`Sender`]) or receiving ([`Receiver`]) side.
@@ -196,7 +210,7 @@ pub trait MediaStateControllable {
 }
 
 /// Direction of the `MediaTrack`.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum TrackDirection {
     /// Sends media data.
     Send,
@@ -205,6 +219,55 @@ pub enum TrackDirection {
     Recv,
 }
 
+/// Snapshot of the status of a `MediaTrack`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TrackStatus {
+    /// Indicator whether the media exchange of the `MediaTrack` is enabled.
+    pub enabled: bool,
+
+    /// Indicator whether the `MediaTrack` is muted.
+    pub muted: bool,
+
+    /// Indicator whether the actual `MediaTrack` is present and [live][1].
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediastreamtrack-readystate
+    pub live: bool,
+
+    /// [`TrackDirection`] of the `MediaTrack`.
+    pub direction: TrackDirection,
+}
+
+/// Ordered lists of preferred [`proto::Codec`]s per [`MediaKind`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CodecPreferences {
+    /// Preferred [`MediaKind::Audio`] [`proto::Codec`]s.
+    audio: Vec<proto::Codec>,
+
+    /// Preferred [`MediaKind::Video`] [`proto::Codec`]s.
+    video: Vec<proto::Codec>,
+}
+
+impl CodecPreferences {
+    /// Returns preferred [`proto::Codec`]s of the provided [`MediaKind`].
+    #[must_use]
+    pub fn get(&self, kind: MediaKind) -> &[proto::Codec] {
+        match kind {
+            MediaKind::Audio => &self.audio,
+            MediaKind::Video => &self.video,
+        }
+    }
+
+    /// Sets preferred [`proto::Codec`]s of the provided [`MediaKind`].
+    ///
+    /// Empty list resets preferences of the provided [`MediaKind`].
+    pub fn set(&mut self, kind: MediaKind, codecs: Vec<proto::Codec>) {
+        match kind {
+            MediaKind::Audio => self.audio = codecs,
+            MediaKind::Video => self.video = codecs,
+        }
+    }
+}
+
 /// Error occurring when media state transition is not allowed.
 #[derive(Clone, Copy, Debug, Display)]
 pub enum ProhibitedStateError {
@@ -216,7 +279,8 @@ pub enum ProhibitedStateError {
     CannotDisableRequiredSender,
 }
 
-/// Errors occurring in [`MediaConnections::insert_local_tracks()`] method.
+/// Errors occurring in [`MediaConnections::insert_local_tracks()`] and
+/// [`MediaConnections::insert_local_track()`] methods.
 #[derive(Caused, Clone, Debug, Display, From)]
 #[cause(error = platform::Error)]
 pub enum InsertLocalTracksError {
@@ -228,10 +292,65 @@ pub enum InsertLocalTracksError {
     #[display("Provided stream does not have all necessary `Track`s")]
     NotEnoughTracks,
 
+    /// [`local::Track`] cannot be encoded with the [`proto::Codec`] required
+    /// by its [`Sender`], since the platform doesn't support it.
+    #[display(
+        "`Track` {track_id} cannot be encoded with the unsupported \
+         `{mime_type}` codec"
+    )]
+    UnsupportedCodec {
+        /// ID of the [`local::Track`] that cannot be encoded.
+        track_id: TrackId,
+
+        /// [MIME] `type/subtype` of the unsupported [`proto::Codec`].
+        ///
+        /// [MIME]: https://en.wikipedia.org/wiki/Media_type
+        mime_type: String,
+    },
+
+    /// [`Sender`] with the provided [`TrackId`] doesn't exist.
+    #[display("`Sender` with `{_0}` ID doesn't exist")]
+    NoSuchSender(TrackId),
+
     /// Insertion of a [`local::Track`] into a [`Sender`] fails.
     CouldNotInsertLocalTrack(#[cause] sender::InsertTrackError),
 }
 
+/// Successful outcome of a [`MediaConnections::add_remote_track()`] call.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RemoteTrackAdded {
+    /// [`platform::MediaStreamTrack`] has been added to its [`Receiver`].
+    Added,
+
+    /// [`platform::MediaStreamTrack`] has been already added to the
+    /// [`Receiver`] of the same member and track, so it's ignored.
+    Duplicate {
+        /// ID of the member sending the duplicated track.
+        sender_id: proto::MemberId,
+
+        /// ID of the duplicated track.
+        track_id: TrackId,
+    },
+}
+
+/// Errors occurring in [`MediaConnections::add_remote_track()`] method.
+#[derive(Clone, Debug, Display)]
+pub enum AddRemoteTrackError {
+    /// [`platform::Transceiver`] of the added [`platform::MediaStreamTrack`]
+    /// has no [`mid`].
+    ///
+    /// [`mid`]: https://w3.org/TR/webrtc#dom-rtptransceiver-mid
+    #[display("No `Transceiver::mid` found")]
+    NoMid,
+
+    /// There is no [`Receiver`] with the [`mid`] of the added
+    /// [`platform::MediaStreamTrack`]'s [`platform::Transceiver`].
+    ///
+    /// [`mid`]: https://w3.org/TR/webrtc#dom-rtptransceiver-mid
+    #[display("Cannot add new remote track with mid={_0}")]
+    NoReceiver(String),
+}
+
 /// Errors occurring in [`MediaConnections::get_mids()`] method.
 #[derive(Clone, Copy, Debug, Display)]
 pub enum GetMidsError {
@@ -252,14 +371,53 @@ struct InnerMediaConnections {
     /// Used to generate transceivers for [`Sender`]s and [`Receiver`]s.
     peer: Rc<platform::RtcPeerConnection>,
 
-    /// [`PeerEvent`]s tx.
-    peer_events_sender: mpsc::UnboundedSender<PeerEvent>,
+    /// [`PeerEventSender`] of the parent `PeerConnection`.
+    peer_events_sender: PeerEventSender,
 
     /// [`TrackId`] to its [`sender::Component`].
     senders: HashMap<TrackId, sender::Component>,
 
     /// [`TrackId`] to its [`receiver::Component`].
     receivers: HashMap<TrackId, receiver::Component>,
+
+    /// [`CodecPreferences`] applied to the [`platform::Transceiver`]s.
+    codec_preferences: CodecPreferences,
+
+    /// [`sender::ResolutionCeiling`] of all the video [`Sender`]s, if any.
+    max_send_resolution: Option<sender::ResolutionCeiling>,
+
+    /// [`sender::LossBasedResolutionConfig`] of all the video [`Sender`]s, if
+    /// loss-based resolution downgrade is enabled.
+    loss_based_resolution: Option<sender::LossBasedResolutionConfig>,
+
+    /// [`sender::AdaptiveBitrateConfig`] of all the video [`Sender`]s, if
+    /// adaptive bitrate is enabled.
+    adaptive_bitrate: Option<sender::AdaptiveBitrateConfig>,
+
+    /// `recvonly` [`platform::Transceiver`]s pre-allocated via
+    /// [`MediaConnections::add_recv_slot()`], not bound to any [`Receiver`]
+    /// yet.
+    recv_slots: Vec<RecvSlot>,
+
+    /// [`TrackId`]s of the [`Sender`]s whose [`platform::Transceiver`]s are
+    /// reused as `sendrecv` ones by the [`Receiver`]s, keyed by the
+    /// [`Receiver`]s' [`TrackId`]s.
+    sendrecv_pairs: HashMap<TrackId, TrackId>,
+}
+
+/// `recvonly` [`platform::Transceiver`] pre-allocated for a future
+/// [`Receiver`].
+#[derive(Debug)]
+struct RecvSlot {
+    /// [`MediaKind`] of this [`RecvSlot`].
+    kind: MediaKind,
+
+    /// Pre-allocated [`platform::Transceiver`].
+    transceiver: platform::Transceiver,
+
+    /// Notifier about the [`TrackId`] of the [`Receiver`] this [`RecvSlot`]
+    /// is bound to.
+    bound_tx: oneshot::Sender<TrackId>,
 }
 
 impl InnerMediaConnections {
@@ -318,21 +476,29 @@ impl InnerMediaConnections {
     /// [`platform::RtcPeerConnection`].
     ///
     /// Handles both audio and video media types, including setting up
-    /// [`EncodingParameters`] and codec preferences for video.
+    /// [`EncodingParameters`] for video and codec preferences.
     fn add_transceiver(
         &self,
         media_type: MediaType,
         direction: platform::TransceiverDirection,
     ) -> impl Future<Output = platform::Transceiver> + 'static + use<> {
         let peer = Rc::clone(&self.peer);
+        let kind = MediaKind::from(&media_type);
+        let preferred = self.codec_preferences.get(kind).to_vec();
 
         async move {
-            let kind = MediaKind::from(&media_type);
-
             match media_type {
                 MediaType::Audio(_) => {
-                    peer.add_transceiver(kind, TransceiverInit::new(direction))
-                        .await
+                    let transceiver = peer
+                        .add_transceiver(kind, TransceiverInit::new(direction))
+                        .await;
+                    let codecs =
+                        probe_preferred_codecs(kind, direction, &preferred)
+                            .await;
+                    if !codecs.is_empty() {
+                        transceiver.set_codec_preferences(codecs);
+                    }
+                    transceiver
                 }
                 MediaType::Video(settings) => {
                     let init = TransceiverInit::new(direction);
@@ -360,8 +526,15 @@ impl InnerMediaConnections {
                     //       all major UAs.
                     //       Track for `parameters.encodings.codec` here:
                     //       https://tinyurl.com/wytxmuss
-                    if let Some(target_codecs) = target_codecs {
-                        transceiver.set_codec_preferences(target_codecs);
+                    let codecs = match target_codecs {
+                        Some(target_codecs) => target_codecs,
+                        None => {
+                            probe_preferred_codecs(kind, direction, &preferred)
+                                .await
+                        }
+                    };
+                    if !codecs.is_empty() {
+                        transceiver.set_codec_preferences(codecs);
                     }
                     transceiver
                 }
@@ -392,16 +565,275 @@ impl MediaConnections {
     #[must_use]
     pub fn new(
         peer: Rc<platform::RtcPeerConnection>,
-        peer_events_sender: mpsc::UnboundedSender<PeerEvent>,
+        peer_events_sender: PeerEventSender,
     ) -> Self {
         Self(RefCell::new(InnerMediaConnections {
             peer,
             peer_events_sender,
             senders: HashMap::new(),
             receivers: HashMap::new(),
+            codec_preferences: CodecPreferences::default(),
+            recv_slots: Vec::new(),
+            sendrecv_pairs: HashMap::new(),
+            max_send_resolution: None,
+            loss_based_resolution: None,
+            adaptive_bitrate: None,
         }))
     }
 
+    /// Returns [`sender::ResolutionCeiling`] of all the video [`Sender`]s, if
+    /// any.
+    #[must_use]
+    pub fn max_send_resolution(&self) -> Option<sender::ResolutionCeiling> {
+        self.0.borrow().max_send_resolution
+    }
+
+    /// Sets [`sender::ResolutionCeiling`] of all the video [`Sender`]s,
+    /// including the ones created later.
+    ///
+    /// [`None`] removes the ceiling.
+    pub async fn set_max_send_resolution(
+        &self,
+        ceiling: Option<sender::ResolutionCeiling>,
+    ) {
+        self.0.borrow_mut().max_send_resolution = ceiling;
+        let senders: Vec<_> =
+            self.0.borrow().senders.values().map(Component::obj).collect();
+        for sender in senders {
+            if let Err(e) = sender.set_max_resolution(ceiling).await {
+                logging::emit(
+                    log::Level::Error,
+                    None,
+                    LogCategory::Media,
+                    format_args!("Failed to clamp send resolution: {e}"),
+                );
+            }
+        }
+    }
+
+    /// Returns [`sender::LossBasedResolutionConfig`] of all the video
+    /// [`Sender`]s, if loss-based resolution downgrade is enabled.
+    #[must_use]
+    pub fn loss_based_resolution(
+        &self,
+    ) -> Option<sender::LossBasedResolutionConfig> {
+        self.0.borrow().loss_based_resolution
+    }
+
+    /// Enables loss-based resolution downgrade of all the video [`Sender`]s,
+    /// including the ones created later, with the provided
+    /// [`sender::LossBasedResolutionConfig`].
+    ///
+    /// [`None`] disables it, so the original resolution is restored on the
+    /// next [`platform::RtcStats`] scrape.
+    pub fn set_loss_based_resolution(
+        &self,
+        config: Option<sender::LossBasedResolutionConfig>,
+    ) {
+        let mut inner = self.0.borrow_mut();
+        inner.loss_based_resolution = config;
+        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+        for sender in inner.senders.values() {
+            if let Some(config) = config {
+                sender.enable_loss_based_resolution(config);
+            } else {
+                sender.disable_loss_based_resolution();
+            }
+        }
+    }
+
+    /// Returns [`sender::AdaptiveBitrateConfig`] of all the video [`Sender`]s,
+    /// if adaptive bitrate is enabled.
+    #[must_use]
+    pub fn adaptive_bitrate(&self) -> Option<sender::AdaptiveBitrateConfig> {
+        self.0.borrow().adaptive_bitrate
+    }
+
+    /// Enables adaptive bitrate of all the video [`Sender`]s, including the
+    /// ones created later, with the provided
+    /// [`sender::AdaptiveBitrateConfig`].
+    ///
+    /// [`None`] disables it, leaving the last applied bitrate as is.
+    pub fn set_adaptive_bitrate(
+        &self,
+        config: Option<sender::AdaptiveBitrateConfig>,
+    ) {
+        let mut inner = self.0.borrow_mut();
+        inner.adaptive_bitrate = config;
+        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+        for sender in inner.senders.values() {
+            if sender.caps().media_kind() != MediaKind::Video {
+                continue;
+            }
+            if let Some(config) = config {
+                sender.enable_adaptive_bitrate(config);
+            } else {
+                sender.disable_adaptive_bitrate();
+            }
+        }
+    }
+
+    /// Re-applies [`sender::ResolutionCeiling`] and loss-based resolution
+    /// downgrade of all the video [`Sender`]s according to the
+    /// [`sender::NetworkConditions`] of each of them observed in the provided
+    /// [`platform::RtcStats`] and their current capture resolution.
+    pub async fn adjust_senders_resolution(&self, stats: &platform::RtcStats) {
+        let senders: Vec<_> =
+            self.0.borrow().senders.values().map(Component::obj).collect();
+        for sender in senders {
+            let conditions =
+                sender.network_conditions(stats).unwrap_or_default();
+            if let Err(e) = sender.adjust_resolution(conditions).await {
+                logging::emit(
+                    log::Level::Error,
+                    None,
+                    LogCategory::Media,
+                    format_args!("Failed to adjust send resolution: {e}"),
+                );
+            }
+        }
+    }
+
+    /// Re-applies [`sender::ResolutionCeiling`] of all the video [`Sender`]s
+    /// according to their current capture resolution.
+    ///
+    /// Called whenever local tracks are inserted into the [`Sender`]s, since
+    /// the capture resolution might have changed.
+    pub async fn clamp_senders_resolution(&self) {
+        let senders: Vec<_> =
+            self.0.borrow().senders.values().map(Component::obj).collect();
+        for sender in senders {
+            if let Err(e) = sender.apply_resolution_scale().await {
+                logging::emit(
+                    log::Level::Error,
+                    None,
+                    LogCategory::Media,
+                    format_args!("Failed to clamp send resolution: {e}"),
+                );
+            }
+        }
+    }
+
+    /// Adds a `recvonly` [`platform::Transceiver`] of the provided
+    /// [`MediaKind`] to be bound to the next [`Receiver`] of this
+    /// [`MediaKind`].
+    ///
+    /// Resolves with a [`oneshot::Receiver`] of the [`TrackId`] of the
+    /// [`Receiver`] the added [`platform::Transceiver`] is bound to.
+    pub async fn add_recv_slot(
+        &self,
+        kind: MediaKind,
+    ) -> oneshot::Receiver<TrackId> {
+        let peer = Rc::clone(&self.0.borrow().peer);
+        let transceiver = peer
+            .add_transceiver(
+                kind,
+                TransceiverInit::new(platform::TransceiverDirection::RECV),
+            )
+            .await;
+
+        let (bound_tx, bound_rx) = oneshot::channel();
+        self.0.borrow_mut().recv_slots.push(RecvSlot {
+            kind,
+            transceiver,
+            bound_tx,
+        });
+        bound_rx
+    }
+
+    /// Takes a [`Sender`] of the provided [`TrackConstraints`]' kinds, whose
+    /// [`platform::Transceiver`] isn't reused by any other [`Receiver`] yet,
+    /// binding it to the [`Receiver`] with the provided [`TrackId`], so its
+    /// [`platform::Transceiver`] can be used as a `sendrecv` one.
+    fn take_sendrecv_sender(
+        &self,
+        caps: &TrackConstraints,
+        track_id: TrackId,
+    ) -> Option<Rc<Sender>> {
+        let mut inner = self.0.borrow_mut();
+        let inner = &mut *inner;
+        let (sender_id, sender) = inner.senders.iter().find(|(id, sndr)| {
+            sndr.caps().media_kind() == caps.media_kind()
+                && sndr.caps().media_source_kind() == caps.media_source_kind()
+                && !inner.sendrecv_pairs.values().any(|s| s == *id)
+        })?;
+        _ = inner.sendrecv_pairs.insert(track_id, *sender_id);
+
+        Some(sender.obj())
+    }
+
+    /// Takes a [`platform::Transceiver`] of the provided [`MediaKind`]
+    /// pre-allocated via [`MediaConnections::add_recv_slot()`], if any,
+    /// binding it to the [`Receiver`] with the provided [`TrackId`].
+    fn take_recv_slot(
+        &self,
+        kind: MediaKind,
+        track_id: TrackId,
+    ) -> Option<platform::Transceiver> {
+        let mut inner = self.0.borrow_mut();
+        let idx = inner.recv_slots.iter().position(|s| s.kind == kind)?;
+        let slot = inner.recv_slots.remove(idx);
+        _ = slot.bound_tx.send(track_id);
+
+        Some(slot.transceiver)
+    }
+
+    /// Returns preferred [`proto::Codec`]s of the provided [`MediaKind`].
+    #[must_use]
+    pub fn preferred_codecs(&self, kind: MediaKind) -> Vec<proto::Codec> {
+        self.0.borrow().codec_preferences.get(kind).to_vec()
+    }
+
+    /// Applies the provided [`CodecPreferences`] to all the
+    /// [`platform::Transceiver`]s, taking effect on the next negotiation.
+    ///
+    /// Preferred [`proto::Codec`]s unsupported by the platform are dropped:
+    /// the ones of the [`Sender`]s are probed against the sending
+    /// capabilities, and the ones of the [`Receiver`]s against the receiving
+    /// capabilities.
+    pub fn set_codec_preferences(
+        &self,
+        preferences: CodecPreferences,
+    ) -> impl Future<Output = ()> + 'static + use<> {
+        let mut inner = self.0.borrow_mut();
+        let senders: Vec<_> =
+            inner.senders.values().map(Component::obj).collect();
+        let receivers: Vec<_> =
+            inner.receivers.values().map(Component::obj).collect();
+        inner.codec_preferences = preferences.clone();
+
+        async move {
+            for kind in [MediaKind::Audio, MediaKind::Video] {
+                let preferred = preferences.get(kind);
+                let send_codecs = probe_preferred_codecs(
+                    kind,
+                    platform::TransceiverDirection::SEND,
+                    preferred,
+                )
+                .await;
+                for sender in
+                    senders.iter().filter(|s| s.caps().media_kind() == kind)
+                {
+                    sender.set_preferred_codecs(preferred.to_vec());
+                    sender.set_codec_preferences(send_codecs.clone());
+                }
+                let recv_codecs = probe_preferred_codecs(
+                    kind,
+                    platform::TransceiverDirection::RECV,
+                    preferred,
+                )
+                .await;
+                for transceiver in receivers
+                    .iter()
+                    .filter(|r| r.caps().media_kind() == kind)
+                    .filter_map(|r| r.transceiver())
+                {
+                    transceiver.set_codec_preferences(recv_codecs.clone());
+                }
+            }
+        }
+    }
+
     /// Returns all [`Sender`]s and [`Receiver`]s from this [`MediaConnections`]
     /// with provided [`MediaKind`], [`TrackDirection`] and
     /// [`MediaSourceKind`].
@@ -495,6 +927,36 @@ impl MediaConnections {
         Ok(mids)
     }
 
+    /// Returns `mid`s of the [`platform::Transceiver`]s of all the [`Sender`]s
+    /// and [`Receiver`]s having one, along with their [`TrackDirection`].
+    #[must_use]
+    pub fn mids_by_direction(&self) -> Vec<(TrackId, TrackDirection, String)> {
+        let inner = self.0.borrow();
+        let senders = inner
+            .senders
+            .iter()
+            .filter_map(|(id, s)| Some((*id, TrackDirection::Send, s.mid()?)));
+        let receivers = inner
+            .receivers
+            .iter()
+            .filter_map(|(id, r)| Some((*id, TrackDirection::Recv, r.mid()?)));
+        senders.chain(receivers).collect()
+    }
+
+    /// Returns [`platform::Transceiver`]s of all the [`Sender`]s and
+    /// [`Receiver`]s having one.
+    #[must_use]
+    pub fn transceivers(&self) -> Vec<(TrackId, platform::Transceiver)> {
+        let inner = self.0.borrow();
+        let senders =
+            inner.senders.iter().map(|(id, s)| (*id, s.transceiver()));
+        let receivers = inner
+            .receivers
+            .iter()
+            .filter_map(|(id, r)| Some((*id, r.transceiver()?)));
+        senders.chain(receivers).collect()
+    }
+
     /// Returns activity statuses of the all the [`Sender`]s and [`Receiver`]s
     /// from these [`MediaConnections`].
     pub fn get_transceivers_statuses(
@@ -519,6 +981,44 @@ impl MediaConnections {
         future::join_all(transceivers).map(|r| r.into_iter().collect())
     }
 
+    /// Returns [`TrackStatus`] of the [`Sender`] or [`Receiver`] with the
+    /// provided [`TrackId`].
+    ///
+    /// Returns [`None`] if there is no [`Sender`] or [`Receiver`] with the
+    /// provided [`TrackId`] in these [`MediaConnections`].
+    pub async fn track_status(&self, track_id: TrackId) -> Option<TrackStatus> {
+        let (sender, receiver) = {
+            let inner = self.0.borrow();
+            (
+                inner.senders.get(&track_id).map(|s| (s.obj(), s.state())),
+                inner.receivers.get(&track_id).map(|r| (r.obj(), r.state())),
+            )
+        };
+
+        if let Some((sender, state)) = sender {
+            let live = match sender.get_send_track() {
+                Some(track) => {
+                    track.state().await == MediaStreamTrackState::Live
+                }
+                None => false,
+            };
+            return Some(TrackStatus {
+                enabled: state.enabled(),
+                muted: state.is_muted(),
+                live,
+                direction: TrackDirection::Send,
+            });
+        }
+
+        let (receiver, state) = receiver?;
+        Some(TrackStatus {
+            enabled: state.enabled_individual(),
+            muted: state.muted(),
+            live: receiver.is_track_live().await,
+            direction: TrackDirection::Recv,
+        })
+    }
+
     /// Returns [`Rc`] to [`TransceiverSide`] with a provided [`TrackId`].
     ///
     /// Returns `None` if [`TransceiverSide`] with a provided [`TrackId`]
@@ -613,13 +1113,8 @@ impl MediaConnections {
             .collect::<Vec<_>>();
         for (sender, state) in senders {
             if let Some(track) = tracks.get(&state.id()).cloned() {
-                if sender.caps().satisfies(track.as_ref()).await {
-                    sender_and_track.push((sender, track));
-                } else {
-                    return Err(tracerr::new!(
-                        InsertLocalTracksError::InvalidMediaTrack
-                    ));
-                }
+                Self::check_local_track(&sender, &state, &track).await?;
+                sender_and_track.push((sender, track));
             } else if sender.caps().required() {
                 return Err(tracerr::new!(
                     InsertLocalTracksError::NotEnoughTracks
@@ -638,45 +1133,138 @@ impl MediaConnections {
         .await
         .map(drop)
         .map_err(tracerr::map_from_and_wrap!())?;
+        self.clamp_senders_resolution().await;
 
         Ok(media_exchange_state_updates)
     }
 
-    /// Adds a new track to the corresponding [`Receiver`].
+    /// Inserts the provided [`local::Track`] into the [`Sender`] with the
+    /// provided [`TrackId`] only, leaving all the other [`Sender`]s intact.
+    ///
+    /// [`None`] `track` is treated the same way as a missing one in the
+    /// [`MediaConnections::insert_local_tracks()`].
+    ///
+    /// Returns the [`media_exchange_state::Stable`] update for the [`Sender`],
+    /// if any.
     ///
     /// # Errors
     ///
-    /// Errors with a transceivers `mid` if could not find [`Receiver`] by this
-    /// `mid`.
+    /// See [`InsertLocalTracksError`] for details.
+    pub async fn insert_local_track(
+        &self,
+        track_id: TrackId,
+        track: Option<Rc<local::Track>>,
+    ) -> Result<
+        Option<media_exchange_state::Stable>,
+        Traced<InsertLocalTracksError>,
+    > {
+        let (sender, state) = self
+            .0
+            .borrow()
+            .senders
+            .get(&track_id)
+            .map(|c| (c.obj(), c.state()))
+            .ok_or_else(|| {
+                tracerr::new!(InsertLocalTracksError::NoSuchSender(track_id))
+            })?;
+
+        let Some(track) = track else {
+            return if sender.caps().required() {
+                Err(tracerr::new!(InsertLocalTracksError::NotEnoughTracks))
+            } else {
+                Ok(Some(media_exchange_state::Stable::Disabled))
+            };
+        };
+        Self::check_local_track(&sender, &state, &track).await?;
+        sender
+            .insert_track(track)
+            .await
+            .map_err(tracerr::map_from_and_wrap!())?;
+        self.clamp_senders_resolution().await;
+
+        Ok(None)
+    }
+
+    /// Checks whether the provided [`local::Track`] can be inserted into the
+    /// provided [`Sender`].
+    ///
+    /// # Errors
+    ///
+    /// With [`InsertLocalTracksError::InvalidMediaTrack`] if the
+    /// [`local::Track`] doesn't satisfy the [`Sender`]'s constraints.
+    ///
+    /// With [`InsertLocalTracksError::UnsupportedCodec`] if the [`Sender`]
+    /// requires a codec unsupported by the platform.
+    async fn check_local_track(
+        sender: &Sender,
+        state: &sender::State,
+        track: &local::Track,
+    ) -> Result<(), Traced<InsertLocalTracksError>> {
+        if !sender.caps().satisfies(track).await {
+            return Err(tracerr::new!(
+                InsertLocalTracksError::InvalidMediaTrack
+            ));
+        }
+        if let MediaType::Video(settings) = state.media_type() {
+            for codec in settings
+                .encoding_parameters
+                .iter()
+                .filter_map(|e| e.codec.as_ref())
+            {
+                if probe_target_codecs(iter::once(codec)).await.is_none() {
+                    return Err(tracerr::new!(
+                        InsertLocalTracksError::UnsupportedCodec {
+                            track_id: state.id(),
+                            mime_type: codec.mime_type.clone(),
+                        }
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds a new track to the corresponding [`Receiver`].
+    ///
+    /// Adding the same [`platform::MediaStreamTrack`] to the same [`Receiver`]
+    /// again is a no-op resulting in a [`RemoteTrackAdded::Duplicate`].
     ///
-    /// # Panics
+    /// # Errors
+    ///
+    /// With [`AddRemoteTrackError::NoMid`] if the provided
+    /// [`platform::Transceiver`] doesn't have a [`mid`]. Not supposed to
+    /// happen, since [`platform::MediaStreamTrack`] is only fired when a
+    /// [`platform::Transceiver`] is negotiated, thus have a [`mid`].
     ///
-    /// If the provided [`platform::Transceiver`] doesn't have a [`mid`]. Not
-    /// supposed to happen, since [`platform::MediaStreamTrack`] is only fired
-    /// when a [`platform::Transceiver`] is negotiated, thus have a [`mid`].
+    /// With [`AddRemoteTrackError::NoReceiver`] if could not find
+    /// [`Receiver`] by the [`mid`].
     ///
     /// [`mid`]: https://w3.org/TR/webrtc#dom-rtptransceiver-mid
     pub async fn add_remote_track(
         &self,
         track: platform::MediaStreamTrack,
         transceiver: platform::Transceiver,
-    ) -> Result<(), String> {
-        // Cannot fail, since transceiver is guaranteed to be negotiated at this
-        // point.
-        let mid = transceiver.mid().ok_or("No Transceiver::mid found")?;
+    ) -> Result<RemoteTrackAdded, Traced<AddRemoteTrackError>> {
+        let mid = transceiver
+            .mid()
+            .ok_or_else(|| tracerr::new!(AddRemoteTrackError::NoMid))?;
         let receiver = self
             .0
             .borrow()
             .receivers
-            .values()
-            .find(|rcvr| rcvr.mid().as_ref() == Some(&mid))
-            .map(Component::obj);
-
-        if let Some(rcvr) = receiver {
-            rcvr.set_remote_track(transceiver, track).await;
-            Ok(())
+            .iter()
+            .find(|(_, rcvr)| rcvr.mid().as_ref() == Some(&mid))
+            .map(|(track_id, rcvr)| {
+                (*track_id, rcvr.state().sender_id().clone(), rcvr.obj())
+            });
+        let Some((track_id, sender_id, rcvr)) = receiver else {
+            return Err(tracerr::new!(AddRemoteTrackError::NoReceiver(mid)));
+        };
+
+        if rcvr.set_remote_track(transceiver, track).await {
+            Ok(RemoteTrackAdded::Added)
         } else {
-            Err(mid)
+            Ok(RemoteTrackAdded::Duplicate { sender_id, track_id })
         }
     }
 
@@ -714,6 +1302,126 @@ impl MediaConnections {
         .map(drop)
     }
 
+    /// Updates [`FrameSize`]s, received bytes and received video frames of
+    /// all the [`Receiver`]s with the ones reported by the provided inbound
+    /// [RTP] [`platform::RtcStats`].
+    ///
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    pub fn update_receivers_frame_sizes(&self, stats: &platform::RtcStats) {
+        let inner = self.0.borrow();
+        for stat in &stats.0 {
+            let RtcStatsType::InboundRtp(inbound) = &stat.stats else {
+                continue;
+            };
+            let Some(mid) = inbound.mid.as_deref() else {
+                continue;
+            };
+            let frame_size = match inbound.media_specific_stats {
+                RtcInboundRtpStreamMediaType::Video {
+                    frame_width: Some(width),
+                    frame_height: Some(height),
+                    ..
+                } => Some(FrameSize { width, height }),
+                RtcInboundRtpStreamMediaType::Audio { .. }
+                | RtcInboundRtpStreamMediaType::Video { .. } => None,
+            };
+            if let Some(rcvr) = inner
+                .receivers
+                .values()
+                .find(|rcvr| rcvr.mid().as_deref() == Some(mid))
+            {
+                rcvr.set_frame_size(frame_size);
+                rcvr.record_bytes_received(&stat.id, inbound.bytes_received);
+            }
+        }
+    }
+
+    /// Updates frozen state of the [`Receiver`]s' [`remote::Track`]s based on
+    /// the video frames counters of the inbound [RTP] stats in the provided
+    /// [`platform::RtcStats`].
+    ///
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    pub async fn detect_receivers_freezes(&self, stats: &platform::RtcStats) {
+        let updates: Vec<_> = {
+            let inner = self.0.borrow();
+            stats
+                .0
+                .iter()
+                .filter_map(|stat| {
+                    let RtcStatsType::InboundRtp(inbound) = &stat.stats else {
+                        return None;
+                    };
+                    let frames = match inbound.media_specific_stats {
+                        RtcInboundRtpStreamMediaType::Video {
+                            frames_decoded,
+                            frames_received,
+                            ..
+                        } => frames_decoded.or(frames_received)?,
+                        RtcInboundRtpStreamMediaType::Audio { .. } => {
+                            return None;
+                        }
+                    };
+                    let mid = inbound.mid.as_deref()?;
+                    let rcvr = inner
+                        .receivers
+                        .values()
+                        .find(|rcvr| rcvr.mid().as_deref() == Some(mid))?
+                        .obj();
+                    Some((rcvr, frames, stat.timestamp.into()))
+                })
+                .collect()
+        };
+        for (rcvr, frames, at) in updates {
+            rcvr.update_frames(frames, at).await;
+        }
+    }
+
+    /// Marks all the [`Sender`]s, whose outbound [RTP] stats in the provided
+    /// [`platform::RtcStats`] report any sent packets, as having sent media,
+    /// and updates their sent bytes.
+    ///
+    /// Outbound [RTP] stats are matched with [`Sender`]s via the
+    /// [`local::Track`] IDs reported by the related media source stats.
+    ///
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    pub fn update_senders_media_sent(&self, stats: &platform::RtcStats) {
+        let track_ids: HashMap<_, _> = stats
+            .0
+            .iter()
+            .filter_map(|stat| {
+                let RtcStatsType::MediaSource(source) = &stat.stats else {
+                    return None;
+                };
+                Some((&stat.id.0, source.track_identifier.as_deref()?))
+            })
+            .collect();
+
+        let inner = self.0.borrow();
+        for stat in &stats.0 {
+            let RtcStatsType::OutboundRtp(outbound) = &stat.stats else {
+                continue;
+            };
+            if outbound.packets_sent.unwrap_or_default() == 0 {
+                continue;
+            }
+            let Some(track_id) = outbound
+                .media_source_id
+                .as_ref()
+                .and_then(|id| track_ids.get(id))
+            else {
+                continue;
+            };
+            if let Some(sndr) = inner.senders.values().find(|sndr| {
+                sndr.get_send_track().is_some_and(|t| t.id() == *track_id)
+            }) {
+                sndr.set_media_sent();
+                if let Some(bytes_sent) = outbound.bytes_sent {
+                    sndr.record_bytes_sent(&stat.id, bytes_sent);
+                }
+            }
+        }
+    }
+
     /// Returns all [`Sender`]s which are matches provided
     /// [`LocalStreamUpdateCriteria`] and doesn't have [`local::Track`].
     pub fn get_senders_without_tracks_ids(
@@ -733,6 +1441,35 @@ impl MediaConnections {
             .collect()
     }
 
+    /// Adjusts bitrate of all the [`Sender`]s with enabled adaptive bitrate
+    /// according to the [`sender::NetworkConditions`] of each of them observed
+    /// in the provided [`platform::RtcStats`].
+    pub async fn adjust_senders_bitrate(&self, stats: &platform::RtcStats) {
+        let adjust_fut = future::join_all(
+            self.0
+                .borrow()
+                .senders
+                .values()
+                .filter(|s| s.adaptive_bitrate_target().is_some())
+                .filter_map(|s| {
+                    let sender = s.obj();
+                    let conditions = sender.network_conditions(stats)?;
+                    Some(async move {
+                        if let Err(e) = sender.adjust_bitrate(conditions).await
+                        {
+                            logging::emit(
+                                log::Level::Error,
+                                None,
+                                LogCategory::Media,
+                                format_args!("Failed to adjust bitrate: {e}"),
+                            );
+                        }
+                    })
+                }),
+        );
+        drop(adjust_fut.await);
+    }
+
     /// Drops [`local::Track`]s of all [`Sender`]s which are matches provided
     /// [`LocalStreamUpdateCriteria`].
     pub async fn drop_send_tracks(&self, kinds: LocalStreamUpdateCriteria) {
@@ -758,10 +1495,18 @@ impl MediaConnections {
     /// provided [`TrackId`] from these [`MediaConnections`].
     pub fn remove_track(&self, track_id: TrackId) {
         let mut inner = self.0.borrow_mut();
-        if inner.receivers.remove(&track_id).is_none() {
-            drop(inner.senders.remove(&track_id));
+        if inner.receivers.remove(&track_id).is_some() {
+            _ = inner.sendrecv_pairs.remove(&track_id);
+        } else if inner.senders.remove(&track_id).is_some() {
+            inner.sendrecv_pairs.retain(|_, sender_id| *sender_id != track_id);
         }
     }
+
+    /// Returns [`Sender`] with a provided [`TrackId`].
+    #[must_use]
+    pub fn get_sender_by_id(&self, id: TrackId) -> Option<Rc<Sender>> {
+        self.0.borrow().senders.get(&id).map(Component::obj)
+    }
 }
 
 #[cfg(feature = "mockable")]
@@ -797,12 +1542,6 @@ impl MediaConnections {
         self.0.borrow().receivers.get(&id).map(Component::obj)
     }
 
-    /// Returns [`Sender`] with a provided [`TrackId`].
-    #[must_use]
-    pub fn get_sender_by_id(&self, id: TrackId) -> Option<Rc<Sender>> {
-        self.0.borrow().senders.get(&id).map(Component::obj)
-    }
-
     /// Indicates whether all [`Sender`]s with [`MediaKind::Audio`] are enabled.
     #[must_use]
     pub fn is_send_audio_enabled(&self) -> bool {
@@ -5,7 +5,8 @@ use std::{iter, rc::Rc};
 use futures::StreamExt as _;
 use medea_client_api_proto as proto;
 use medea_client_api_proto::{
-    MediaSourceKind, MediaType, MemberId, TrackId, TrackPatchEvent,
+    MediaSourceKind, MediaType, MemberId, QualityLayer, TrackId,
+    TrackPatchEvent,
 };
 use medea_macro::watchers;
 use medea_reactive::{
@@ -74,6 +75,18 @@ pub struct State {
     /// [`remote::Track`]: crate::media::track::remote::Track
     muted: ObservableCell<bool>,
 
+    /// [`QualityLayer`] of the [`Receiver`]'s [`remote::Track`] requested by
+    /// the client.
+    ///
+    /// [`remote::Track`]: crate::media::track::remote::Track
+    quality_layer: ObservableCell<Option<QualityLayer>>,
+
+    /// Indicator whether forwarding of the [`Receiver`]'s [`remote::Track`] is
+    /// paused by a media server.
+    ///
+    /// [`remote::Track`]: crate::media::track::remote::Track
+    paused: ObservableCell<bool>,
+
     /// Indicator whether this [`Receiver`] is working in a [P2P mesh] or [SFU]
     /// mode.
     ///
@@ -96,6 +109,7 @@ impl AsProtoState for State {
             media_type: self.media_type.clone(),
             sender_id: self.sender_id.clone(),
             muted: false,
+            paused: self.paused(),
             media_direction: self.media_direction().into(),
         }
     }
@@ -121,6 +135,8 @@ impl SynchronizableState for State {
                 ),
             ),
             muted: ObservableCell::new(input.muted),
+            quality_layer: ObservableCell::new(None),
+            paused: ObservableCell::new(input.paused),
             media_direction: ObservableCell::new(input.media_direction.into()),
             connection_mode: input.connection_mode,
             sync_phase: ObservableCell::new(SyncPhase::Synced),
@@ -146,6 +162,7 @@ impl SynchronizableState for State {
             input.media_direction.is_enabled_general(),
         ));
         self.media_direction.set(input.media_direction.into());
+        self.paused.set(input.paused);
 
         self.sync_phase.set(SyncPhase::Synced);
     }
@@ -197,6 +214,7 @@ impl From<&State> for proto::state::Receiver {
             sender_id: from.sender_id.clone(),
             media_direction: from.media_direction().into(),
             muted: false,
+            paused: from.paused(),
         }
     }
 }
@@ -225,6 +243,8 @@ impl State {
                 media_direction.is_enabled_general().into(),
             ),
             muted: ObservableCell::new(muted),
+            quality_layer: ObservableCell::new(None),
+            paused: ObservableCell::new(false),
             sync_phase: ObservableCell::new(SyncPhase::Synced),
             connection_mode,
             media_direction: ObservableCell::new(media_direction.into()),
@@ -274,12 +294,34 @@ impl State {
         self.muted.get()
     }
 
+    /// Indicates whether forwarding of the [`Receiver`]'s [`remote::Track`] is
+    /// paused by a media server.
+    ///
+    /// [`remote::Track`]: crate::media::track::remote::Track
+    #[must_use]
+    pub fn paused(&self) -> bool {
+        self.paused.get()
+    }
+
     /// Returns the current general [`MediaDirection`] of this [`State`].
     #[must_use]
     pub fn media_direction(&self) -> MediaDirection {
         self.media_direction.get()
     }
 
+    /// Requests the provided [`QualityLayer`] of the [`Receiver`]'s
+    /// [`remote::Track`] from a media server.
+    ///
+    /// Requests are sent even if the same [`QualityLayer`] has been requested
+    /// already, since a media server may have reset it meanwhile.
+    ///
+    /// [`remote::Track`]: crate::media::track::remote::Track
+    pub fn request_quality_layer(&self, layer: QualityLayer) {
+        // `ObservableCell` doesn't notify about the same value being set.
+        self.quality_layer.set(None);
+        self.quality_layer.set(Some(layer));
+    }
+
     /// Updates this [`State`] with the provided [`TrackPatchEvent`].
     pub fn update(&self, track_patch: &TrackPatchEvent) {
         if self.id != track_patch.id {
@@ -293,6 +335,9 @@ impl State {
         if let Some(muted) = track_patch.muted {
             self.muted.set(muted);
         }
+        if let Some(paused) = track_patch.paused {
+            self.paused.set(paused);
+        }
         if let Some(direction) = track_patch.media_direction {
             self.media_direction.set(direction.into());
         }
@@ -387,6 +432,35 @@ impl Component {
         }
     }
 
+    /// Watcher for the pause state updates.
+    ///
+    /// Propagates the pause state to the associated [`Receiver`] and its media
+    /// track (if any).
+    #[watch(self.paused.subscribe())]
+    fn pause_state_changed(receiver: &Receiver, _: &State, paused: bool) {
+        receiver.paused.set(paused);
+        if let Some(track) = receiver.track.borrow().as_ref() {
+            track.set_paused(paused);
+        }
+    }
+
+    /// Watcher for the requested [`QualityLayer`] updates.
+    ///
+    /// Sends [`TrackEvent::QualityLayerIntention`][1] with the requested
+    /// [`QualityLayer`].
+    ///
+    /// [1]: crate::peer::TrackEvent::QualityLayerIntention
+    #[watch(self.quality_layer.subscribe())]
+    fn quality_layer_changed(
+        receiver: &Receiver,
+        _: &State,
+        layer: Option<QualityLayer>,
+    ) {
+        if let Some(layer) = layer {
+            receiver.send_quality_layer_intention(layer);
+        }
+    }
+
     /// Stops transition timeouts on [`SyncPhase::Desynced`].
     ///
     /// Sends media state intentions and resets transition timeouts on
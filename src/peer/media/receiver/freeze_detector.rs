@@ -0,0 +1,130 @@
+//! Detector of frozen video of a [`Receiver`], based on its inbound [RTP]
+//! stats.
+//!
+//! [`Receiver`]: super::Receiver
+//! [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+
+use std::time::{Duration, SystemTime};
+
+/// Default duration without new frames, after which video is considered
+/// frozen.
+pub const DEFAULT_FREEZE_THRESHOLD: Duration = Duration::from_secs(2);
+
+/// Detector of frozen video, tracking progress of the frames counter reported
+/// by inbound [RTP] stats across their scrapes.
+///
+/// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+#[derive(Clone, Copy, Debug)]
+pub struct FreezeDetector {
+    /// Duration without new frames, after which video is considered frozen.
+    threshold: Duration,
+
+    /// Last observed frames counter and the time it has last progressed at.
+    last_progress: Option<(u64, SystemTime)>,
+
+    /// Indicator whether video is frozen at the moment.
+    frozen: bool,
+}
+
+impl Default for FreezeDetector {
+    fn default() -> Self {
+        Self::new(DEFAULT_FREEZE_THRESHOLD)
+    }
+}
+
+impl FreezeDetector {
+    /// Creates a new [`FreezeDetector`] considering video frozen once no new
+    /// frames are observed during the provided `threshold`.
+    #[must_use]
+    pub const fn new(threshold: Duration) -> Self {
+        Self { threshold, last_progress: None, frozen: false }
+    }
+
+    /// Indicates whether video is frozen at the moment.
+    #[must_use]
+    pub const fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Forgets all the observed frames, considering video not frozen.
+    pub const fn reset(&mut self) {
+        self.last_progress = None;
+        self.frozen = false;
+    }
+
+    /// Records the `frames` counter observed at the provided time.
+    ///
+    /// Returns the new frozen state if it has been changed.
+    pub fn update(&mut self, frames: u64, at: SystemTime) -> Option<bool> {
+        let progressed_at = match self.last_progress {
+            Some((last_frames, progressed_at)) if last_frames == frames => {
+                progressed_at
+            }
+            Some(_) | None => {
+                self.last_progress = Some((frames, at));
+                at
+            }
+        };
+        let stalled_for =
+            at.duration_since(progressed_at).unwrap_or(Duration::ZERO);
+
+        let frozen = stalled_for >= self.threshold;
+        (frozen != self.frozen).then(|| {
+            self.frozen = frozen;
+            frozen
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::UNIX_EPOCH;
+
+    use super::*;
+
+    fn at(millis: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn not_frozen_while_frames_progress() {
+        let mut detector = FreezeDetector::default();
+
+        for i in 0..10 {
+            assert_eq!(detector.update(i * 30, at(i * 1000)), None);
+        }
+        assert!(!detector.is_frozen());
+    }
+
+    #[test]
+    fn freezes_after_threshold() {
+        let mut detector = FreezeDetector::default();
+
+        assert_eq!(detector.update(100, at(0)), None);
+        assert_eq!(detector.update(100, at(1000)), None);
+        assert_eq!(detector.update(100, at(2000)), Some(true));
+        assert_eq!(detector.update(100, at(3000)), None);
+        assert!(detector.is_frozen());
+    }
+
+    #[test]
+    fn unfreezes_on_new_frames() {
+        let mut detector = FreezeDetector::new(Duration::from_secs(1));
+
+        assert_eq!(detector.update(100, at(0)), None);
+        assert_eq!(detector.update(100, at(1500)), Some(true));
+        assert_eq!(detector.update(130, at(2500)), Some(false));
+        assert_eq!(detector.update(130, at(3000)), None);
+        assert!(!detector.is_frozen());
+    }
+
+    #[test]
+    fn counter_reset_counts_as_progress() {
+        let mut detector = FreezeDetector::default();
+
+        assert_eq!(detector.update(100, at(0)), None);
+        assert_eq!(detector.update(5, at(1500)), None);
+        assert_eq!(detector.update(5, at(3000)), None);
+        assert_eq!(detector.update(5, at(3500)), Some(true));
+    }
+}
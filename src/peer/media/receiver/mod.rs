@@ -1,25 +1,49 @@
 //! Implementation of the `MediaTrack` with a `Recv` direction.
 
 mod component;
+mod freeze_detector;
+mod simulcast;
 
-use std::cell::{Cell, RefCell};
+use std::{
+    cell::{Cell, RefCell},
+    time::SystemTime,
+};
 
 use futures::channel::mpsc;
 use medea_client_api_proto as proto;
-use proto::{ConnectionMode, TrackId};
+use proto::{ConnectionMode, TrackId, stats::StatId};
 
 #[doc(inline)]
-pub use self::component::{Component, State};
-use super::TransceiverSide as _;
+pub use self::{
+    component::{Component, State},
+    freeze_detector::DEFAULT_FREEZE_THRESHOLD,
+    simulcast::SimulcastLayer,
+};
+use self::{freeze_detector::FreezeDetector, simulcast::LayerEstimator};
+use super::{BytesCounter, TransceiverSide as _};
 use crate::{
-    media::{MediaDirection, RecvConstraints, TrackConstraints, track::remote},
+    media::{
+        MediaDirection, MediaKind, RecvConstraints, TrackConstraints,
+        track::{MediaStreamTrackState, remote},
+    },
     peer::{
-        MediaConnections, MediaStateControllable as _, PeerEvent, TrackEvent,
-        media::media_exchange_state,
+        MediaConnections, MediaStateControllable as _, PeerEvent,
+        PeerEventSender, TrackEvent, media::media_exchange_state,
+        send_peer_event,
     },
-    platform, utils,
+    platform,
 };
 
+/// Size of a video frame decoded by a [`Receiver`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FrameSize {
+    /// Width of the frame in pixels.
+    pub width: u64,
+
+    /// Height of the frame in pixels.
+    pub height: u64,
+}
+
 /// Representation of a [`remote::Track`] that is being received from some
 /// remote peer. It may have two states: `waiting` and `receiving`.
 ///
@@ -73,17 +97,37 @@ pub struct Receiver {
     /// Indicator whether this [`remote::Track`] is muted.
     muted: Cell<bool>,
 
+    /// Indicator whether forwarding of this [`remote::Track`] is paused by a
+    /// media server.
+    paused: Cell<bool>,
+
+    /// Last observed [`FrameSize`] of this [`remote::Track`].
+    frame_size: Cell<Option<FrameSize>>,
+
+    /// Total number of bytes received by this [`Receiver`].
+    bytes_received: BytesCounter,
+
+    /// [`FreezeDetector`] of the video received by this [`Receiver`].
+    freeze_detector: RefCell<FreezeDetector>,
+
+    /// [`LayerEstimator`] of the video received by this [`Receiver`].
+    layer_estimator: RefCell<LayerEstimator>,
+
     /// Channel for sending [`PeerEvent`]s to the remote peer.
-    peer_events_sender: mpsc::UnboundedSender<PeerEvent>,
+    peer_events_sender: PeerEventSender,
 
     /// Channel for sending [`TrackEvent`]s to the actual [`remote::Track`].
     track_events_sender: mpsc::UnboundedSender<TrackEvent>,
 }
 
 impl Receiver {
-    /// Creates a new [`platform::Transceiver`] if provided `mid` is [`None`],
-    /// otherwise creates a [`Receiver`] without a [`platform::Transceiver`]. It
-    /// will be injected when a [`remote::Track`] will arrive.
+    /// Creates a new [`platform::Transceiver`] if provided `mid` is [`None`]
+    /// (or takes a pre-allocated one, if any), otherwise creates a [`Receiver`]
+    /// without a [`platform::Transceiver`]. It will be injected when a
+    /// [`remote::Track`] will arrive.
+    ///
+    /// A pre-allocated [`platform::Transceiver`] of the same kind (if any) is
+    /// bound to the created [`Receiver`] in both cases.
     ///
     /// Created [`platform::Transceiver`] direction is set to
     /// [`TransceiverDirection::INACTIVE`][1] if `enabled_individual` is
@@ -106,20 +150,15 @@ impl Receiver {
         #[expect(clippy::if_then_some_else_none, reason = "more readable")]
         let transceiver = if state.mid().is_none() {
             // Try to find send transceiver that can be used as `sendrecv`.
-            let sender = media_connections
-                .0
-                .borrow()
-                .senders
-                .values()
-                .find(|sndr| {
-                    sndr.caps().media_kind() == caps.media_kind()
-                        && sndr.caps().media_source_kind()
-                            == caps.media_source_kind()
-                })
-                .map(utils::component::Component::obj);
+            let sender =
+                media_connections.take_sendrecv_sender(&caps, state.track_id());
 
             let trnsvr = if let Some(s) = sender {
                 s.transceiver()
+            } else if let Some(slot) = media_connections
+                .take_recv_slot(caps.media_kind(), state.track_id())
+            {
+                slot
             } else {
                 let new_transceiver =
                     media_connections.0.borrow().add_transceiver(
@@ -136,9 +175,22 @@ impl Receiver {
                 .await;
             Some(trnsvr)
         } else {
+            // Server-offered track is received via its own negotiated
+            // `platform::Transceiver`, so the pre-allocated one is released.
+            if let Some(slot) = media_connections
+                .take_recv_slot(caps.media_kind(), state.track_id())
+            {
+                slot.set_recv(false).await;
+            }
             None
         };
 
+        let layer_estimator = match state.media_type() {
+            proto::MediaType::Video(settings) => {
+                LayerEstimator::new(&settings.encoding_parameters)
+            }
+            proto::MediaType::Audio(_) => LayerEstimator::default(),
+        };
         let peer_events_sender =
             media_connections.0.borrow().peer_events_sender.clone();
         let this = Self {
@@ -154,6 +206,11 @@ impl Receiver {
             enabled_general: Cell::new(state.enabled_individual()),
             enabled_individual: Cell::new(state.enabled_general()),
             muted: Cell::new(state.muted()),
+            paused: Cell::new(state.paused()),
+            frame_size: Cell::new(None),
+            bytes_received: BytesCounter::default(),
+            freeze_detector: RefCell::new(FreezeDetector::default()),
+            layer_estimator: RefCell::new(layer_estimator),
             media_direction: Cell::new(state.media_direction()),
             track_events_sender,
         };
@@ -195,6 +252,128 @@ impl Receiver {
         self.mid.borrow().clone()
     }
 
+    /// Returns the last observed [`FrameSize`] of this [`Receiver`]'s
+    /// [`remote::Track`], as reported by the `frameWidth` and `frameHeight`
+    /// of its [inbound RTP stats][1].
+    ///
+    /// Returns [`None`] if no video frames have been decoded yet, or this
+    /// [`Receiver`] receives audio.
+    ///
+    /// [1]: https://w3.org/TR/webrtc-stats/#dom-rtcinboundrtpstreamstats
+    #[must_use]
+    pub fn frame_size(&self) -> Option<FrameSize> {
+        self.frame_size.get()
+    }
+
+    /// Updates the [`FrameSize`] of this [`Receiver`] with the one observed in
+    /// its inbound [RTP] stats.
+    ///
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    pub fn set_frame_size(&self, frame_size: Option<FrameSize>) {
+        if let Some(frame_size) = frame_size {
+            self.layer_estimator.borrow_mut().record(frame_size);
+        }
+        self.frame_size.set(frame_size);
+    }
+
+    /// Indicates whether the video received by this [`Receiver`] is sent with
+    /// multiple simulcast layers.
+    #[must_use]
+    pub fn is_simulcast(&self) -> bool {
+        self.layer_estimator.borrow().is_simulcast()
+    }
+
+    /// Returns the [`SimulcastLayer`] currently received by this [`Receiver`],
+    /// estimated by the last observed [`FrameSize`].
+    ///
+    /// Returns [`None`] if the received video isn't simulcast, or no video
+    /// frames have been decoded yet.
+    #[must_use]
+    pub fn current_layer(&self) -> Option<SimulcastLayer> {
+        self.layer_estimator.borrow().estimate(self.frame_size.get()?)
+    }
+
+    /// Returns playback volume of the audio received by this [`Receiver`] in
+    /// `0.0..=1.0` range.
+    ///
+    /// The volume is set via the current [`remote::Track`] of this
+    /// [`Receiver`], and is carried over to all the following ones (e.g.
+    /// replaced on renegotiation), so it persists across the
+    /// [`remote::Track`] being muted and unmuted. It's independent from the
+    /// output audio device selection.
+    #[must_use]
+    pub fn volume(&self) -> f64 {
+        self.track.borrow().as_ref().map_or(1.0, remote::Track::volume)
+    }
+
+    /// Indicates whether the video received by this [`Receiver`] is frozen
+    /// at the moment.
+    #[must_use]
+    pub fn is_frozen(&self) -> bool {
+        self.freeze_detector.borrow().is_frozen()
+    }
+
+    /// Records the total number of video `frames` reported by the inbound
+    /// [RTP] stats of this [`Receiver`] at the provided time, marking its
+    /// [`remote::Track`] as frozen or unfrozen if no new frames appear during
+    /// the [`DEFAULT_FREEZE_THRESHOLD`].
+    ///
+    /// Frames are not tracked while no video is expected to be received (the
+    /// [`remote::Track`] is disabled, muted, paused, or not [live][1], or the
+    /// sender doesn't send it), so the [`remote::Track`] is never considered
+    /// frozen in such case.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediastreamtrack-readystate
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    pub async fn update_frames(&self, frames: u64, at: SystemTime) {
+        let changed = if self.is_video_expected().await {
+            self.freeze_detector.borrow_mut().update(frames, at)
+        } else {
+            let was_frozen = self.freeze_detector.borrow().is_frozen();
+            self.freeze_detector.borrow_mut().reset();
+            was_frozen.then_some(false)
+        };
+        if let Some(frozen) = changed {
+            if let Some(track) = self.track.borrow().as_ref() {
+                track.set_frozen(frozen);
+            }
+        }
+    }
+
+    /// Indicates whether new video frames are expected to be received by this
+    /// [`Receiver`] at the moment.
+    async fn is_video_expected(&self) -> bool {
+        let is_enabled = self.enabled_general.get()
+            && self.enabled_individual.get()
+            && !self.muted.get()
+            && !self.paused.get()
+            && self.media_direction.get() == MediaDirection::SendRecv
+            && self
+                .track
+                .borrow()
+                .as_ref()
+                .is_some_and(|t| t.get_track().enabled());
+
+        is_enabled && self.is_track_live().await
+    }
+
+    /// Returns the total number of bytes received by this [`Receiver`] since
+    /// its creation.
+    ///
+    /// Updated on each [`platform::RtcStats`] scrape, and never decreases.
+    #[must_use]
+    pub fn bytes_received(&self) -> u64 {
+        self.bytes_received.total()
+    }
+
+    /// Records the cumulative `bytes_received` reported by the inbound [RTP]
+    /// stats with the provided [`StatId`].
+    ///
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    pub fn record_bytes_received(&self, stat_id: &StatId, bytes_received: u64) {
+        self.bytes_received.record(stat_id, bytes_received);
+    }
+
     /// Indicates whether this [`Receiver`] receives media data.
     pub async fn is_receiving(&self) -> bool {
         let transceiver = self.transceiver.borrow().clone();
@@ -207,21 +386,45 @@ impl Receiver {
         self.enabled_individual.get() && is_recv_direction
     }
 
+    /// Indicates whether the [`remote::Track`] of this [`Receiver`] is
+    /// [live][1].
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediastreamtrack-readystate
+    pub async fn is_track_live(&self) -> bool {
+        let track = self.track.borrow().clone();
+        match track {
+            Some(track) => {
+                track.get_track().ready_state().await
+                    == MediaStreamTrackState::Live
+            }
+            None => false,
+        }
+    }
+
     /// Sends [`TrackEvent::MediaExchangeIntention`] with the provided
     /// [`media_exchange_state`].
     pub fn send_media_exchange_state_intention(
         &self,
         state: media_exchange_state::Transition,
     ) {
-        _ = self.track_events_sender.unbounded_send(
+        drop(self.track_events_sender.unbounded_send(
             TrackEvent::MediaExchangeIntention {
                 id: self.track_id,
                 enabled: matches!(
                     state,
                     media_exchange_state::Transition::Enabling(_)
                 ),
+                label: None,
             },
-        );
+        ));
+    }
+
+    /// Sends [`TrackEvent::QualityLayerIntention`] with the provided
+    /// [`proto::QualityLayer`].
+    pub fn send_quality_layer_intention(&self, layer: proto::QualityLayer) {
+        drop(self.track_events_sender.unbounded_send(
+            TrackEvent::QualityLayerIntention { id: self.track_id, layer },
+        ));
     }
 
     /// Adds the provided [`platform::MediaStreamTrack`] and
@@ -229,25 +432,34 @@ impl Receiver {
     ///
     /// Sets [`platform::MediaStreamTrack::enabled`] same as
     /// `enabled_individual` of this [`Receiver`].
+    ///
+    /// Returns `false` if the provided [`platform::MediaStreamTrack`] is
+    /// already set, so nothing has been changed.
     pub async fn set_remote_track(
         &self,
         transceiver: platform::Transceiver,
         new_track: platform::MediaStreamTrack,
-    ) {
+    ) -> bool {
         if let Some(old_track) = self.track.borrow().as_ref() {
             if old_track.id() == new_track.id() {
-                return;
+                return false;
             }
         }
 
         self.set_transceiver(transceiver);
 
+        let volume = self.volume();
         let new_track = remote::Track::new(
             new_track,
-            self.caps.media_source_kind(),
+            self.caps.media_kind(),
+            (self.caps.media_kind() == MediaKind::Video)
+                .then(|| self.caps.media_source_kind()),
             self.muted.get(),
             self.media_direction.get(),
         );
+        new_track.set_paused(self.paused.get());
+        new_track.set_volume(volume);
+        self.freeze_detector.borrow_mut().reset();
         if let Some(prev_track) = self.track.replace(Some(new_track)) {
             platform::spawn(async move {
                 prev_track.stop().await;
@@ -266,6 +478,8 @@ impl Receiver {
         }
 
         self.maybe_notify_track().await;
+
+        true
     }
 
     /// Updates [`MediaDirection`] of this [`Receiver`].
@@ -296,6 +510,11 @@ impl Receiver {
     ///
     /// Returns [`None`] if this [`Receiver`] doesn't have a
     /// [`platform::Transceiver`].
+    ///
+    /// This is an escape hatch for features not wrapped by this crate.
+    /// Mutating the returned [`platform::Transceiver`] directly (e.g. changing
+    /// its direction) may desync it from the state of this [`Receiver`].
+    #[must_use]
     pub fn transceiver(&self) -> Option<platform::Transceiver> {
         self.transceiver.borrow().clone()
     }
@@ -314,12 +533,13 @@ impl Receiver {
             return;
         }
         if let Some(track) = self.track.borrow().as_ref() {
-            drop(self.peer_events_sender.unbounded_send(
+            send_peer_event(
+                &self.peer_events_sender,
                 PeerEvent::NewRemoteTrack {
                     sender_id: self.sender_id.clone(),
                     track: track.clone(),
                 },
-            ));
+            );
             self.is_track_notified.set(true);
         }
     }
@@ -0,0 +1,150 @@
+//! Estimation of the simulcast layer received by a [`Receiver`].
+//!
+//! [`Receiver`]: super::Receiver
+
+use medea_client_api_proto as proto;
+
+use super::FrameSize;
+
+/// Simulcast layer of a remote video.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SimulcastLayer {
+    /// [RID] of this [`SimulcastLayer`].
+    ///
+    /// [RID]: https://w3.org/TR/webrtc#dom-rtcrtpcodingparameters-rid
+    pub rid: String,
+
+    /// Factor the resolution of this [`SimulcastLayer`] is scaled down by,
+    /// comparing to the original one.
+    pub scale_resolution_down_by: u8,
+}
+
+/// Estimator of the [`SimulcastLayer`] being received, based on the observed
+/// [`FrameSize`]s.
+///
+/// Inbound [RTP] stats don't report [RID]s of the forwarded streams, so the
+/// largest observed [`FrameSize`] is assumed to belong to the layer with the
+/// lowest [scaleResolutionDownBy][1], and the current one is matched against
+/// it. Thus, the estimation is only accurate once the top layer has been
+/// received at least once.
+///
+/// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+/// [RID]: https://w3.org/TR/webrtc#dom-rtcrtpcodingparameters-rid
+/// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+#[derive(Clone, Debug, Default)]
+pub struct LayerEstimator {
+    /// [`SimulcastLayer`]s of the remote video.
+    layers: Vec<SimulcastLayer>,
+
+    /// Longest side (in pixels) of all the observed [`FrameSize`]s.
+    max_side: u64,
+}
+
+impl LayerEstimator {
+    /// Creates a new [`LayerEstimator`] of the [`SimulcastLayer`]s described
+    /// by the provided [`proto::EncodingParameters`].
+    #[must_use]
+    pub fn new(encodings: &[proto::EncodingParameters]) -> Self {
+        Self {
+            layers: encodings
+                .iter()
+                .map(|e| SimulcastLayer {
+                    rid: e.rid.clone(),
+                    scale_resolution_down_by: e
+                        .scale_resolution_down_by
+                        .unwrap_or(1)
+                        .max(1),
+                })
+                .collect(),
+            max_side: 0,
+        }
+    }
+
+    /// Indicates whether the remote video has multiple [`SimulcastLayer`]s.
+    #[must_use]
+    pub fn is_simulcast(&self) -> bool {
+        self.layers.len() > 1
+    }
+
+    /// Records the provided observed [`FrameSize`].
+    pub fn record(&mut self, frame_size: FrameSize) {
+        self.max_side =
+            self.max_side.max(frame_size.width.max(frame_size.height));
+    }
+
+    /// Estimates the [`SimulcastLayer`] of the provided [`FrameSize`].
+    ///
+    /// [`None`] if the remote video isn't simulcast, or no [`FrameSize`]s
+    /// have been observed yet.
+    #[must_use]
+    pub fn estimate(&self, frame_size: FrameSize) -> Option<SimulcastLayer> {
+        if !self.is_simulcast() {
+            return None;
+        }
+        let side = frame_size.width.max(frame_size.height);
+        if side == 0 || self.max_side == 0 {
+            return None;
+        }
+        let top_scale = self
+            .layers
+            .iter()
+            .map(|l| u64::from(l.scale_resolution_down_by))
+            .min()?;
+
+        // Scales are compared multiplied by `side` to stay in integers.
+        let observed_scale = self.max_side * top_scale;
+        self.layers
+            .iter()
+            .min_by_key(|l| {
+                (u64::from(l.scale_resolution_down_by) * side)
+                    .abs_diff(observed_scale)
+            })
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encoding(rid: &str, scale: u8) -> proto::EncodingParameters {
+        proto::EncodingParameters {
+            rid: rid.into(),
+            active: true,
+            max_bitrate: None,
+            scale_resolution_down_by: Some(scale),
+            scalability_mode: None,
+            codec: None,
+        }
+    }
+
+    const fn size(width: u64, height: u64) -> FrameSize {
+        FrameSize { width, height }
+    }
+
+    #[test]
+    fn single_encoding_is_not_simulcast() {
+        let mut estimator = LayerEstimator::new(&[encoding("h", 1)]);
+        estimator.record(size(1280, 720));
+
+        assert!(!estimator.is_simulcast());
+        assert_eq!(estimator.estimate(size(1280, 720)), None);
+    }
+
+    #[test]
+    fn estimates_layer_by_frame_size() {
+        let mut estimator = LayerEstimator::new(&[
+            encoding("q", 4),
+            encoding("h", 2),
+            encoding("f", 1),
+        ]);
+        assert_eq!(estimator.estimate(size(1280, 720)), None);
+
+        estimator.record(size(1280, 720));
+        assert_eq!(estimator.estimate(size(1280, 720)).unwrap().rid, "f");
+
+        estimator.record(size(640, 360));
+        assert_eq!(estimator.estimate(size(640, 360)).unwrap().rid, "h");
+        assert_eq!(estimator.estimate(size(320, 180)).unwrap().rid, "q");
+    }
+}
@@ -0,0 +1,349 @@
+//! Controller adjusting [maxBitrate][1] of a [`Sender`] based on the observed
+//! network conditions.
+//!
+//! [`Sender`]: super::Sender
+//! [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-maxbitrate
+
+use std::time::Duration;
+
+use medea_client_api_proto::stats::RtcStatsType;
+
+use crate::platform;
+
+/// Configuration of an [`AdaptiveBitrateController`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AdaptiveBitrateConfig {
+    /// Lower bound of the target bitrate (in bits per second).
+    pub min_bitrate: u32,
+
+    /// Upper bound of the target bitrate (in bits per second).
+    ///
+    /// Also used as the initial target bitrate.
+    pub max_bitrate: u32,
+
+    /// Round trip time, exceeding of which is considered as a network
+    /// congestion.
+    pub high_rtt: Duration,
+
+    /// Fraction of lost packets (in `0.0..=1.0` range), exceeding of which is
+    /// considered as a network congestion.
+    pub high_fraction_lost: f64,
+}
+
+/// Network conditions observed in [`platform::RtcStats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct NetworkConditions {
+    /// The worst observed round trip time.
+    pub rtt: Option<Duration>,
+
+    /// The worst observed fraction of lost packets.
+    pub fraction_lost: Option<f64>,
+}
+
+impl NetworkConditions {
+    /// Extracts the worst [`NetworkConditions`] reported by the
+    /// [remote-inbound-rtp][1] entries of the provided [`platform::RtcStats`]
+    /// related to the [`platform::MediaStreamTrack`] with the provided ID.
+    ///
+    /// [remote-inbound-rtp][1] entries are matched with the
+    /// [`platform::MediaStreamTrack`] via their [outbound-rtp][2] entries
+    /// (having the same SSRC) and the [media-source][3] entries of the latter.
+    ///
+    /// Returns [`None`] if there are no such entries.
+    ///
+    /// [1]: https://w3.org/TR/webrtc-stats#dom-rtcstatstype-remote-inbound-rtp
+    /// [2]: https://w3.org/TR/webrtc-stats#dom-rtcstatstype-outbound-rtp
+    /// [3]: https://w3.org/TR/webrtc-stats#dom-rtcstatstype-media-source
+    #[must_use]
+    pub fn from_stats(
+        stats: &platform::RtcStats,
+        track_id: &str,
+    ) -> Option<Self> {
+        let find_stat = |id: &str| stats.0.iter().find(|s| s.id.0 == id);
+        let is_track_stream = |outbound_id: &str| {
+            let Some(RtcStatsType::OutboundRtp(outbound)) =
+                find_stat(outbound_id).map(|s| &s.stats)
+            else {
+                return false;
+            };
+            let Some(RtcStatsType::MediaSource(source)) = outbound
+                .media_source_id
+                .as_deref()
+                .and_then(find_stat)
+                .map(|s| &s.stats)
+            else {
+                return false;
+            };
+            source.track_identifier.as_deref() == Some(track_id)
+        };
+
+        stats
+            .0
+            .iter()
+            .filter_map(|stat| {
+                let RtcStatsType::RemoteInboundRtp(remote) = &stat.stats else {
+                    return None;
+                };
+                if !is_track_stream(remote.local_id.as_deref()?) {
+                    return None;
+                }
+                Some(Self {
+                    rtt: remote.round_trip_time.and_then(|rtt| {
+                        Duration::try_from_secs_f64(rtt.0).ok()
+                    }),
+                    fraction_lost: remote.fraction_lost.map(|f| f.0),
+                })
+            })
+            .reduce(|a, b| Self {
+                rtt: a.rtt.max(b.rtt),
+                fraction_lost: match (a.fraction_lost, b.fraction_lost) {
+                    (Some(a), Some(b)) => Some(a.max(b)),
+                    (a, b) => a.or(b),
+                },
+            })
+    }
+
+    /// Indicates whether these [`NetworkConditions`] are congested according
+    /// to the provided [`AdaptiveBitrateConfig`].
+    fn is_congested(&self, config: &AdaptiveBitrateConfig) -> bool {
+        self.rtt.is_some_and(|rtt| rtt > config.high_rtt)
+            || self
+                .fraction_lost
+                .is_some_and(|lost| lost > config.high_fraction_lost)
+    }
+}
+
+/// Controller of a target bitrate, nudging it up or down within the configured
+/// bounds depending on the observed [`NetworkConditions`].
+///
+/// Decreases the target bitrate by 15% on a congestion and increases it by 5%
+/// otherwise.
+#[derive(Clone, Copy, Debug)]
+pub struct AdaptiveBitrateController {
+    /// [`AdaptiveBitrateConfig`] of this [`AdaptiveBitrateController`].
+    config: AdaptiveBitrateConfig,
+
+    /// Current target bitrate (in bits per second).
+    target: u32,
+}
+
+impl AdaptiveBitrateController {
+    /// Creates a new [`AdaptiveBitrateController`] with the provided
+    /// [`AdaptiveBitrateConfig`].
+    #[must_use]
+    pub const fn new(config: AdaptiveBitrateConfig) -> Self {
+        Self { target: config.max_bitrate, config }
+    }
+
+    /// Returns the current target bitrate (in bits per second).
+    #[must_use]
+    pub const fn target(&self) -> u32 {
+        self.target
+    }
+
+    /// Adjusts the target bitrate according to the provided
+    /// [`NetworkConditions`].
+    ///
+    /// Returns the new target bitrate if it has been changed.
+    pub fn update(&mut self, conditions: NetworkConditions) -> Option<u32> {
+        let target = if conditions.is_congested(&self.config) {
+            self.target - self.target / 100 * 15
+        } else {
+            self.target.saturating_add(self.target / 20).max(1)
+        };
+        let target =
+            target.min(self.config.max_bitrate).max(self.config.min_bitrate);
+
+        (target != self.target).then(|| {
+            self.target = target;
+            target
+        })
+    }
+}
+
+/// Splits the provided total `target` bitrate (in bits per second) between
+/// the encodings with the provided [scaleResolutionDownBy][1] factors,
+/// proportionally to the number of pixels each of them sends.
+///
+/// So lower simulcast layers get a proportionally lower bitrate instead of the
+/// same one as the full-resolution layer.
+///
+/// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+#[must_use]
+pub fn split_bitrate(target: u32, scales: &[f32]) -> Vec<u32> {
+    let weights: Vec<_> =
+        scales.iter().map(|s| f64::from(s.max(1.0)).powi(-2)).collect();
+    let total: f64 = weights.iter().sum();
+    weights
+        .iter()
+        .map(|w| {
+            #[expect( // intended
+                clippy::as_conversions,
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                reason = "the result is within `0..=target` range"
+            )]
+            let bitrate = (f64::from(target) * w / total).round() as u32;
+            bitrate
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use medea_client_api_proto::stats::{
+        Float, HighResTimeStamp, MediaKind, MediaSourceStats,
+        RtcOutboundRtpStreamMediaType, RtcOutboundRtpStreamStats,
+        RtcRemoteInboundRtpStreamStats, RtcStat, StatId,
+    };
+
+    use super::*;
+
+    const CONFIG: AdaptiveBitrateConfig = AdaptiveBitrateConfig {
+        min_bitrate: 100_000,
+        max_bitrate: 1_000_000,
+        high_rtt: Duration::from_millis(300),
+        high_fraction_lost: 0.05,
+    };
+
+    const fn conditions(rtt_ms: u64, fraction_lost: f64) -> NetworkConditions {
+        NetworkConditions {
+            rtt: Some(Duration::from_millis(rtt_ms)),
+            fraction_lost: Some(fraction_lost),
+        }
+    }
+
+    fn stat(id: &str, stats: RtcStatsType) -> RtcStat {
+        RtcStat {
+            id: StatId(id.into()),
+            timestamp: HighResTimeStamp(0.0),
+            stats,
+        }
+    }
+
+    /// Returns [`RtcStat`]s of an audio stream sent from the track with the
+    /// provided ID, reporting the provided fraction of lost packets.
+    fn track_stream(track_id: &str, fraction_lost: f64) -> Vec<RtcStat> {
+        vec![
+            stat(
+                &format!("source-{track_id}"),
+                RtcStatsType::MediaSource(Box::new(MediaSourceStats {
+                    track_identifier: Some(track_id.into()),
+                    kind: MediaKind::Audio {
+                        audio_level: None,
+                        total_audio_energy: None,
+                        total_samples_duration: None,
+                    },
+                })),
+            ),
+            stat(
+                &format!("outbound-{track_id}"),
+                RtcStatsType::OutboundRtp(Box::new(
+                    RtcOutboundRtpStreamStats {
+                        track_id: None,
+                        media_type: RtcOutboundRtpStreamMediaType::Audio {
+                            total_samples_sent: None,
+                            voice_activity_flag: None,
+                        },
+                        bytes_sent: None,
+                        packets_sent: None,
+                        media_source_id: Some(format!("source-{track_id}")),
+                    },
+                )),
+            ),
+            stat(
+                &format!("remote-inbound-{track_id}"),
+                RtcStatsType::RemoteInboundRtp(Box::new(
+                    RtcRemoteInboundRtpStreamStats {
+                        local_id: Some(format!("outbound-{track_id}")),
+                        jitter: None,
+                        round_trip_time: Some(Float(0.1)),
+                        fraction_lost: Some(Float(fraction_lost)),
+                        reports_received: None,
+                        round_trip_time_measurements: None,
+                    },
+                )),
+            ),
+        ]
+    }
+
+    #[test]
+    fn splits_bitrate_between_layers() {
+        assert_eq!(split_bitrate(1_000_000, &[1.0]), vec![1_000_000]);
+        assert_eq!(
+            split_bitrate(2_100_000, &[4.0, 2.0, 1.0]),
+            vec![100_000, 400_000, 1_600_000],
+        );
+        assert_eq!(
+            split_bitrate(1_000_000, &[1.0, 1.0]),
+            vec![500_000, 500_000]
+        );
+    }
+
+    #[test]
+    fn extracts_conditions_of_the_provided_track_only() {
+        let stats = platform::RtcStats(
+            track_stream("foo", 0.1)
+                .into_iter()
+                .chain(track_stream("bar", 0.5))
+                .collect(),
+        );
+
+        assert_eq!(
+            NetworkConditions::from_stats(&stats, "foo"),
+            Some(NetworkConditions {
+                rtt: Some(Duration::from_millis(100)),
+                fraction_lost: Some(0.1),
+            }),
+        );
+        assert_eq!(
+            NetworkConditions::from_stats(&stats, "bar"),
+            Some(NetworkConditions {
+                rtt: Some(Duration::from_millis(100)),
+                fraction_lost: Some(0.5),
+            }),
+        );
+        assert_eq!(NetworkConditions::from_stats(&stats, "baz"), None);
+    }
+
+    #[test]
+    fn starts_from_max_bitrate() {
+        let controller = AdaptiveBitrateController::new(CONFIG);
+
+        assert_eq!(controller.target(), CONFIG.max_bitrate);
+    }
+
+    #[test]
+    fn decreases_on_high_rtt() {
+        let mut controller = AdaptiveBitrateController::new(CONFIG);
+
+        assert_eq!(controller.update(conditions(500, 0.0)), Some(850_000));
+    }
+
+    #[test]
+    fn decreases_on_high_loss() {
+        let mut controller = AdaptiveBitrateController::new(CONFIG);
+
+        assert_eq!(controller.update(conditions(50, 0.1)), Some(850_000));
+    }
+
+    #[test]
+    fn increases_on_good_conditions() {
+        let mut controller = AdaptiveBitrateController::new(CONFIG);
+        _ = controller.update(conditions(500, 0.0));
+
+        assert_eq!(controller.update(conditions(50, 0.0)), Some(892_500));
+    }
+
+    #[test]
+    fn stays_within_bounds() {
+        let mut controller = AdaptiveBitrateController::new(CONFIG);
+
+        assert_eq!(controller.update(conditions(50, 0.0)), None);
+        for _ in 0..100 {
+            _ = controller.update(conditions(500, 0.5));
+        }
+        assert_eq!(controller.target(), CONFIG.min_bitrate);
+        assert_eq!(controller.update(conditions(500, 0.5)), None);
+    }
+}
@@ -28,7 +28,10 @@ use crate::{
             ProhibitedStateError, media_exchange_state, mute_state,
         },
     },
-    platform::transceiver::probe_target_codecs,
+    platform::transceiver::{
+        Direction, UpdateSendEncodingError, probe_preferred_codecs,
+        probe_target_codecs,
+    },
     utils::{AsProtoState, SynchronizableState, Updatable, component},
 };
 
@@ -142,6 +145,12 @@ pub struct State {
     /// [`local::Track`]: crate::media::track::local::Track
     local_track: ObservableCell<LocalTrackState>,
 
+    /// Application-defined label of the [`Sender`], used by a media server to
+    /// correlate its [`local::Track`].
+    ///
+    /// [`local::Track`]: crate::media::track::local::Track
+    label: ObservableCell<Option<String>>,
+
     /// [`SyncPhase`] of the [`Component`].
     sync_phase: ObservableCell<SyncPhase>,
 }
@@ -193,6 +202,7 @@ impl SynchronizableState for State {
             local_track: ObservableCell::new(LocalTrackState::Stable),
             sync_phase: ObservableCell::new(SyncPhase::Synced),
             send_encodings: ProgressableCell::new(Vec::new()),
+            label: ObservableCell::new(None),
         }
     }
 
@@ -312,6 +322,7 @@ impl State {
             send_constraints,
             connection_mode,
             local_track: ObservableCell::new(LocalTrackState::Stable),
+            label: ObservableCell::new(None),
         }
     }
 
@@ -365,6 +376,20 @@ impl State {
         self.muted.muted()
     }
 
+    /// Returns the application-defined label of this [`State`], if any.
+    #[must_use]
+    pub fn label(&self) -> Option<String> {
+        self.label.get()
+    }
+
+    /// Sets the application-defined label of this [`State`], which is included
+    /// into all the [`TrackPatchCommand`]s of the [`Sender`].
+    ///
+    /// [`TrackPatchCommand`]: proto::TrackPatchCommand
+    pub fn set_label(&self, label: String) {
+        self.label.set(Some(label));
+    }
+
     /// Returns [`Future`] which will be resolved once
     /// [getUserMedia()][1]/[getDisplayMedia()][2] request for this [`State`] is
     /// resolved.
@@ -574,6 +599,20 @@ impl Component {
         }
     }
 
+    /// Watcher for the [`State::label`] updates.
+    ///
+    /// Updates [`Sender`]'s label and sends
+    /// [`TrackEvent::LabelUpdateIntention`][1] with it.
+    ///
+    /// [1]: crate::peer::TrackEvent::LabelUpdateIntention
+    #[watch(self.label.subscribe().skip(1))]
+    fn label_changed(sender: &Sender, _: &State, label: Option<String>) {
+        drop(sender.label.replace(label.clone()));
+        if let Some(label) = label {
+            sender.send_label_intention(label);
+        }
+    }
+
     /// Stops transition timeouts on a [`SyncPhase::Desynced`].
     ///
     /// Sends media state intentions and resets transition timeouts on a
@@ -641,10 +680,16 @@ impl Component {
         //       Track for `parameters.encodings.codec` here:
         //       https://tinyurl.com/wytxmuss
         if let Some(target_codecs) = target_codecs {
-            sender.transceiver.set_codec_preferences(target_codecs);
+            sender.set_codec_preferences(target_codecs);
         } else {
             // Empty list resets preferences.
-            sender.transceiver.set_codec_preferences(Vec::new());
+            let preferred = probe_preferred_codecs(
+                sender.caps.media_kind(),
+                Direction::SEND,
+                &sender.preferred_codecs(),
+            )
+            .await;
+            sender.set_codec_preferences(preferred);
         }
 
         sender
@@ -652,6 +697,19 @@ impl Component {
             .update_send_encodings(&enc_params)
             .await
             .map_err(RtcPeerConnectionError::UpdateSendEncodingsError)
+            .map_err(tracerr::wrap!())?;
+        sender.set_configured_scales(&enc_params);
+        sender
+            .apply_resolution_scale()
+            .await
+            .map_err(UpdateSendEncodingError::SetSenderParameters)
+            .map_err(RtcPeerConnectionError::UpdateSendEncodingsError)
+            .map_err(tracerr::wrap!())?;
+        sender
+            .apply_degradation_preference()
+            .await
+            .map_err(UpdateSendEncodingError::SetSenderParameters)
+            .map_err(RtcPeerConnectionError::UpdateSendEncodingsError)
             .map_err(tracerr::wrap!())
     }
 }
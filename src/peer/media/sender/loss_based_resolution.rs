@@ -0,0 +1,173 @@
+//! Controller stepping [scaleResolutionDownBy][1] of a [`Sender`] based on the
+//! observed packet loss.
+//!
+//! [`Sender`]: super::Sender
+//! [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+
+use std::time::Duration;
+
+/// Configuration of a [`LossBasedResolutionController`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LossBasedResolutionConfig {
+    /// Fraction of lost packets (in `0.0..=1.0` range), exceeding of which for
+    /// the [`LossBasedResolutionConfig::sustain`] period lowers the
+    /// resolution.
+    pub high_fraction_lost: f64,
+
+    /// Fraction of lost packets (in `0.0..=1.0` range), staying below which
+    /// for the [`LossBasedResolutionConfig::sustain`] period raises the
+    /// resolution back.
+    pub low_fraction_lost: f64,
+
+    /// Period the packet loss should stay above or below the thresholds for
+    /// before the resolution is stepped.
+    pub sustain: Duration,
+
+    /// Step of the [scaleResolutionDownBy][1] factor.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    pub step: f32,
+
+    /// Upper bound of the [scaleResolutionDownBy][1] factor.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    pub max_scale: f32,
+}
+
+/// Packet loss trend observed by a [`LossBasedResolutionController`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Trend {
+    /// Packet loss is above [`LossBasedResolutionConfig::high_fraction_lost`].
+    High,
+
+    /// Packet loss is below [`LossBasedResolutionConfig::low_fraction_lost`].
+    Low,
+}
+
+/// Controller of a [scaleResolutionDownBy][1] factor, stepping it up on a
+/// sustained high packet loss and down once the loss clears.
+///
+/// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+#[derive(Clone, Copy, Debug)]
+pub struct LossBasedResolutionController {
+    /// [`LossBasedResolutionConfig`] of this
+    /// [`LossBasedResolutionController`].
+    config: LossBasedResolutionConfig,
+
+    /// Current [scaleResolutionDownBy][1] factor.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    scale: f32,
+
+    /// Current packet loss [`Trend`] and the time it has started at.
+    trend: Option<(Trend, Duration)>,
+}
+
+impl LossBasedResolutionController {
+    /// Creates a new [`LossBasedResolutionController`] with the provided
+    /// [`LossBasedResolutionConfig`].
+    #[must_use]
+    pub const fn new(config: LossBasedResolutionConfig) -> Self {
+        Self { config, scale: 1.0, trend: None }
+    }
+
+    /// Returns the current [scaleResolutionDownBy][1] factor.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    #[must_use]
+    pub const fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Records the provided fraction of lost packets observed at the provided
+    /// time.
+    ///
+    /// Returns the new [scaleResolutionDownBy][1] factor if it has been
+    /// changed.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    pub fn update(&mut self, fraction_lost: f64, at: Duration) -> Option<f32> {
+        let trend = if fraction_lost > self.config.high_fraction_lost {
+            Trend::High
+        } else if fraction_lost < self.config.low_fraction_lost {
+            Trend::Low
+        } else {
+            self.trend = None;
+            return None;
+        };
+        let since = match self.trend {
+            Some((current, since)) if current == trend => since,
+            Some(_) | None => {
+                self.trend = Some((trend, at));
+                at
+            }
+        };
+        if at.saturating_sub(since) < self.config.sustain {
+            return None;
+        }
+
+        // Next step requires the trend to be sustained once again.
+        self.trend = Some((trend, at));
+        let scale = match trend {
+            Trend::High => {
+                (self.scale + self.config.step).min(self.config.max_scale)
+            }
+            Trend::Low => (self.scale - self.config.step).max(1.0),
+        };
+        ((scale - self.scale).abs() > f32::EPSILON).then(|| {
+            self.scale = scale;
+            scale
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: LossBasedResolutionConfig = LossBasedResolutionConfig {
+        high_fraction_lost: 0.1,
+        low_fraction_lost: 0.02,
+        sustain: Duration::from_secs(3),
+        step: 0.5,
+        max_scale: 2.0,
+    };
+
+    const fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn downgrades_on_sustained_loss() {
+        let mut controller = LossBasedResolutionController::new(CONFIG);
+
+        assert_eq!(controller.update(0.2, secs(0)), None);
+        assert_eq!(controller.update(0.2, secs(2)), None);
+        assert_eq!(controller.update(0.2, secs(3)), Some(1.5));
+        assert_eq!(controller.update(0.2, secs(4)), None);
+        assert_eq!(controller.update(0.2, secs(6)), Some(2.0));
+        assert_eq!(controller.update(0.2, secs(9)), None);
+        assert!((controller.scale() - 2.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn ignores_short_loss_spikes() {
+        let mut controller = LossBasedResolutionController::new(CONFIG);
+
+        assert_eq!(controller.update(0.2, secs(0)), None);
+        assert_eq!(controller.update(0.05, secs(2)), None);
+        assert_eq!(controller.update(0.2, secs(3)), None);
+        assert_eq!(controller.update(0.2, secs(5)), None);
+    }
+
+    #[test]
+    fn recovers_once_loss_clears() {
+        let mut controller = LossBasedResolutionController::new(CONFIG);
+        _ = controller.update(0.2, secs(0));
+        _ = controller.update(0.2, secs(3));
+
+        assert_eq!(controller.update(0.0, secs(4)), None);
+        assert_eq!(controller.update(0.0, secs(7)), Some(1.0));
+        assert_eq!(controller.update(0.0, secs(10)), None);
+    }
+}
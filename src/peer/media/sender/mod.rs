@@ -1,25 +1,41 @@
 //! Implementation of the `MediaTrack` with a `Send` direction.
 
+mod adaptive_bitrate;
 mod component;
+mod loss_based_resolution;
+mod resolution_ceiling;
 
 use std::{
     cell::{Cell, RefCell},
     rc::Rc,
+    time::Duration,
 };
 
 use derive_more::with_trait::{Display, From};
 use futures::channel::mpsc;
-use medea_client_api_proto::TrackId;
+use medea_client_api_proto::{self as proto, TrackId, stats::StatId};
+use medea_reactive::ObservableCell;
 use tracerr::Traced;
 
 #[doc(inline)]
-pub use self::component::{Component, State};
+pub use self::{
+    adaptive_bitrate::{
+        AdaptiveBitrateConfig, AdaptiveBitrateController, NetworkConditions,
+    },
+    component::{Component, State},
+    loss_based_resolution::{
+        LossBasedResolutionConfig, LossBasedResolutionController,
+    },
+    resolution_ceiling::ResolutionCeiling,
+};
 use super::{
-    MediaConnections, MediaStateControllable as _, media_exchange_state,
-    mute_state,
+    BytesCounter, MediaConnections, MediaStateControllable as _,
+    media_exchange_state, mute_state,
 };
 use crate::{
-    media::{LocalTracksConstraints, TrackConstraints, track::local},
+    media::{
+        LocalTracksConstraints, MediaKind, TrackConstraints, track::local,
+    },
     peer::TrackEvent,
     platform,
     utils::Caused,
@@ -41,6 +57,34 @@ pub enum CreateError {
     TransceiverNotFound(String),
 }
 
+/// Errors occurring in [`Sender::insert_dtmf()`] method.
+#[derive(Caused, Clone, Debug, Display)]
+#[cause(error = platform::Error)]
+pub enum InsertDtmfError {
+    /// [`PeerConnection`] has no [`Sender`] with the provided [`TrackId`].
+    ///
+    /// [`PeerConnection`]: crate::peer::PeerConnection
+    #[display("No `Sender` with `{_0}` ID")]
+    NoSuchSender(TrackId),
+
+    /// [`Sender`] doesn't send audio, so cannot send DTMF tones.
+    #[display("DTMF tones can only be sent by an audio `Sender`")]
+    NotAudioSender,
+
+    /// Underlying [RTCRtpSender][1] has no [RTCDTMFSender][2].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#rtcrtpsender-interface
+    /// [2]: https://w3.org/TR/webrtc#rtcdtmfsender
+    #[display("`Sender` is not capable of sending DTMF tones")]
+    NoDtmfSender,
+
+    /// [RTCDTMFSender.insertDTMF()][1] call failed.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcdtmfsender-insertdtmf
+    #[display("Failed to insert DTMF tones: {_0}")]
+    InsertFailed(platform::Error),
+}
+
 /// Error occuring in [`RTCRtpSender.replaceTrack()`][1] method.
 ///
 /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpsender-replacetrack
@@ -75,6 +119,10 @@ pub struct Sender {
     /// Indicator whether this [`local::Track`] is enabled generally.
     enabled_general: Cell<bool>,
 
+    /// Application-defined label of this [`Sender`], used by a media server
+    /// to correlate its [`local::Track`].
+    label: RefCell<Option<String>>,
+
     /// [MediaStreamConstraints][1] of this [`local::Track`].
     ///
     /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamconstraints
@@ -82,6 +130,44 @@ pub struct Sender {
 
     /// Channel for sending [`TrackEvent`]s to the actual [`local::Track`].
     track_events_tx: mpsc::UnboundedSender<TrackEvent>,
+
+    /// [`AdaptiveBitrateController`] of this [`Sender`], if enabled.
+    bitrate_controller: RefCell<Option<AdaptiveBitrateController>>,
+
+    /// Indicator whether outbound [RTP] stats of this [`Sender`] have ever
+    /// reported any sent packets.
+    ///
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    media_sent: ObservableCell<bool>,
+
+    /// Total number of bytes sent by this [`Sender`].
+    bytes_sent: BytesCounter,
+
+    /// [`platform::DegradationPreference`] of this [`Sender`], if set.
+    degradation_preference: Cell<Option<platform::DegradationPreference>>,
+
+    /// Ordered list of preferred [`proto::Codec`]s of this [`Sender`], applied
+    /// if its [`proto::EncodingParameters`] don't specify any codecs.
+    preferred_codecs: RefCell<Vec<proto::Codec>>,
+
+    /// [`ResolutionCeiling`] of this [`Sender`], if any.
+    max_resolution: Cell<Option<ResolutionCeiling>>,
+
+    /// [`LossBasedResolutionController`] of this [`Sender`], if enabled.
+    resolution_controller: RefCell<Option<LossBasedResolutionController>>,
+
+    /// [scaleResolutionDownBy][1] factors of the [`ResolutionCeiling`] and of
+    /// the loss-based downgrade applied on top of the encodings configured by
+    /// a media server.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    resolution_scale: Cell<(f32, f32)>,
+
+    /// [scaleResolutionDownBy][1] factors of the encodings of this [`Sender`]
+    /// configured by a media server.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    configured_scales: RefCell<Vec<f32>>,
 }
 
 impl Sender {
@@ -155,6 +241,22 @@ impl Sender {
             }
         };
 
+        let preferred_codecs =
+            media_connections.preferred_codecs(caps.media_kind());
+        let max_resolution = media_connections.max_send_resolution();
+        let resolution_controller = media_connections
+            .loss_based_resolution()
+            .map(LossBasedResolutionController::new);
+        let bitrate_controller = media_connections
+            .adaptive_bitrate()
+            .filter(|_| caps.media_kind() == MediaKind::Video)
+            .map(AdaptiveBitrateController::new);
+        let configured_scales = match state.media_type() {
+            proto::MediaType::Video(settings) => {
+                scales_of(&settings.encoding_parameters)
+            }
+            proto::MediaType::Audio(_) => Vec::new(),
+        };
         let this = Rc::new(Self {
             track_id: state.id(),
             caps,
@@ -162,9 +264,19 @@ impl Sender {
             enabled_general: Cell::new(state.is_enabled_general()),
             enabled_individual: Cell::new(state.is_enabled_individual()),
             muted: Cell::new(state.is_muted()),
+            label: RefCell::new(state.label()),
             track_events_tx,
             send_constraints,
             track: RefCell::new(None),
+            bitrate_controller: RefCell::new(bitrate_controller),
+            media_sent: ObservableCell::new(false),
+            bytes_sent: BytesCounter::default(),
+            degradation_preference: Cell::new(None),
+            preferred_codecs: RefCell::new(preferred_codecs),
+            max_resolution: Cell::new(max_resolution),
+            resolution_controller: RefCell::new(resolution_controller),
+            resolution_scale: Cell::new((1.0, 1.0)),
+            configured_scales: RefCell::new(configured_scales),
         });
 
         state
@@ -243,6 +355,11 @@ impl Sender {
     }
 
     /// Returns [`platform::Transceiver`] of this [`Sender`].
+    ///
+    /// This is an escape hatch for features not wrapped by this crate.
+    /// Mutating the returned [`platform::Transceiver`] directly (e.g. changing
+    /// its direction or replacing its track) may desync it from the state of
+    /// this [`Sender`].
     #[must_use]
     pub fn transceiver(&self) -> platform::Transceiver {
         self.transceiver.clone()
@@ -262,6 +379,47 @@ impl Sender {
         self.transceiver.mid()
     }
 
+    /// Schedules the provided DTMF `tones` to be sent by this [`Sender`],
+    /// replacing any tones which are still queued.
+    ///
+    /// DTMF is not supported on native platforms yet, so this method always
+    /// errors with an [`InsertDtmfError::NoDtmfSender`] there.
+    ///
+    /// # Errors
+    ///
+    /// With an [`InsertDtmfError::NotAudioSender`] if this [`Sender`] doesn't
+    /// send audio.
+    ///
+    /// With an [`InsertDtmfError::NoDtmfSender`] if the underlying
+    /// [`platform::Transceiver`] has no [`platform::DtmfSender`].
+    ///
+    /// With an [`InsertDtmfError::InsertFailed`] if the provided `tones`
+    /// cannot be inserted.
+    pub fn insert_dtmf(
+        &self,
+        tones: &str,
+        duration: Duration,
+        inter_tone_gap: Duration,
+    ) -> Result<(), Traced<InsertDtmfError>> {
+        if self.caps.media_kind() != MediaKind::Audio {
+            return Err(tracerr::new!(InsertDtmfError::NotAudioSender));
+        }
+        self.transceiver
+            .dtmf_sender()
+            .ok_or_else(|| tracerr::new!(InsertDtmfError::NoDtmfSender))?
+            .insert_dtmf(tones, duration, inter_tone_gap)
+            .map_err(InsertDtmfError::InsertFailed)
+            .map_err(tracerr::wrap!())
+    }
+
+    /// Indicates whether this [`Sender`] is currently playing out DTMF tones.
+    #[must_use]
+    pub fn is_playing_dtmf(&self) -> bool {
+        self.transceiver
+            .dtmf_sender()
+            .is_some_and(|dtmf| !dtmf.tone_buffer().is_empty())
+    }
+
     /// Indicates whether this [`Sender`] is enabled in
     /// [`LocalTracksConstraints`].
     fn enabled_in_cons(&self) -> bool {
@@ -277,26 +435,379 @@ impl Sender {
         &self,
         state: media_exchange_state::Transition,
     ) {
-        _ = self.track_events_tx.unbounded_send(
+        drop(self.track_events_tx.unbounded_send(
             TrackEvent::MediaExchangeIntention {
                 id: self.track_id,
                 enabled: matches!(
                     state,
                     media_exchange_state::Transition::Enabling(_)
                 ),
+                label: self.label(),
             },
-        );
+        ));
     }
 
     /// Sends [`TrackEvent::MuteUpdateIntention`] with the provided
     /// [`mute_state`].
     pub fn send_mute_state_intention(&self, state: mute_state::Transition) {
-        _ = self.track_events_tx.unbounded_send(
+        drop(self.track_events_tx.unbounded_send(
             TrackEvent::MuteUpdateIntention {
                 id: self.track_id,
                 muted: matches!(state, mute_state::Transition::Muting(_)),
+                label: self.label(),
             },
-        );
+        ));
+    }
+
+    /// Sends [`TrackEvent::LabelUpdateIntention`] with the provided `label`.
+    pub fn send_label_intention(&self, label: String) {
+        drop(self.track_events_tx.unbounded_send(
+            TrackEvent::LabelUpdateIntention { id: self.track_id, label },
+        ));
+    }
+
+    /// Returns the application-defined label of this [`Sender`], if any.
+    #[must_use]
+    pub fn label(&self) -> Option<String> {
+        self.label.borrow().clone()
+    }
+
+    /// Extracts [`NetworkConditions`] of the media sent by this [`Sender`] from
+    /// the provided [`platform::RtcStats`].
+    ///
+    /// Returns [`None`] if this [`Sender`] has no [`local::Track`] or the
+    /// provided [`platform::RtcStats`] have no related entries.
+    #[must_use]
+    pub fn network_conditions(
+        &self,
+        stats: &platform::RtcStats,
+    ) -> Option<NetworkConditions> {
+        let track_id = self.get_send_track()?.id();
+        NetworkConditions::from_stats(stats, &track_id)
+    }
+
+    /// Enables adaptive bitrate for this [`Sender`] with the provided
+    /// [`AdaptiveBitrateConfig`].
+    ///
+    /// Once enabled, [maxBitrate][1] of all the encodings of this [`Sender`]
+    /// is adjusted on every [`platform::RtcStats`] scrape according to the
+    /// observed [`NetworkConditions`].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-maxbitrate
+    pub fn enable_adaptive_bitrate(&self, config: AdaptiveBitrateConfig) {
+        _ = self
+            .bitrate_controller
+            .replace(Some(AdaptiveBitrateController::new(config)));
+    }
+
+    /// Disables adaptive bitrate for this [`Sender`].
+    ///
+    /// The last applied [maxBitrate][1] is left as is.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-maxbitrate
+    pub fn disable_adaptive_bitrate(&self) {
+        _ = self.bitrate_controller.take();
+    }
+
+    /// Returns the current target bitrate (in bits per second) of this
+    /// [`Sender`], if adaptive bitrate is enabled.
+    #[must_use]
+    pub fn adaptive_bitrate_target(&self) -> Option<u32> {
+        self.bitrate_controller
+            .borrow()
+            .as_ref()
+            .map(AdaptiveBitrateController::target)
+    }
+
+    /// Adjusts [maxBitrate][1] of all the encodings of this [`Sender`]
+    /// according to the provided [`NetworkConditions`], if adaptive bitrate is
+    /// enabled.
+    ///
+    /// The target bitrate is split between the encodings proportionally to
+    /// their resolutions, so simulcast layers keep their relative bitrates.
+    ///
+    /// # Errors
+    ///
+    /// With [`platform::Error`] if the underlying [setParameters()][2] call
+    /// fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-maxbitrate
+    /// [2]: https://w3.org/TR/webrtc#dom-rtcrtpsender-setparameters
+    pub async fn adjust_bitrate(
+        &self,
+        conditions: NetworkConditions,
+    ) -> Result<(), platform::Error> {
+        let target = self
+            .bitrate_controller
+            .borrow_mut()
+            .as_mut()
+            .and_then(|c| c.update(conditions));
+        let Some(target) = target else {
+            return Ok(());
+        };
+
+        let params = self.transceiver.get_send_parameters().await;
+        let encodings = params.encodings();
+        let configured = self.configured_scales.borrow().clone();
+        let scales: Vec<_> = (0..encodings.len())
+            .map(|i| configured.get(i).copied().unwrap_or(1.0))
+            .collect();
+        for (enc, bitrate) in encodings
+            .iter()
+            .zip(adaptive_bitrate::split_bitrate(target, &scales))
+        {
+            enc.set_max_bitrate(bitrate);
+        }
+        self.transceiver.set_send_parameters(params).await
+    }
+
+    /// Sets [`ResolutionCeiling`] of this [`Sender`], so its video is never
+    /// sent above it regardless of the capture resolution.
+    ///
+    /// [`None`] removes the ceiling, restoring the encodings configured by a
+    /// media server.
+    ///
+    /// # Errors
+    ///
+    /// With [`platform::Error`] if the underlying [setParameters()][1] call
+    /// fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpsender-setparameters
+    pub async fn set_max_resolution(
+        &self,
+        ceiling: Option<ResolutionCeiling>,
+    ) -> Result<(), platform::Error> {
+        self.max_resolution.set(ceiling);
+        self.apply_resolution_scale().await
+    }
+
+    /// Enables loss-based resolution downgrade for this [`Sender`] with the
+    /// provided [`LossBasedResolutionConfig`].
+    ///
+    /// Once enabled, [scaleResolutionDownBy][1] of all the encodings of this
+    /// [`Sender`] is stepped up on a sustained packet loss and back down once
+    /// it clears, according to the [`NetworkConditions`] observed on every
+    /// [`platform::RtcStats`] scrape.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    pub fn enable_loss_based_resolution(
+        &self,
+        config: LossBasedResolutionConfig,
+    ) {
+        _ = self
+            .resolution_controller
+            .replace(Some(LossBasedResolutionController::new(config)));
+    }
+
+    /// Disables loss-based resolution downgrade for this [`Sender`].
+    ///
+    /// The original resolution is restored on the next
+    /// [`platform::RtcStats`] scrape.
+    pub fn disable_loss_based_resolution(&self) {
+        _ = self.resolution_controller.take();
+    }
+
+    /// Returns the current [scaleResolutionDownBy][1] factor of this
+    /// [`Sender`], if loss-based resolution downgrade is enabled.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    #[must_use]
+    pub fn loss_based_resolution_scale(&self) -> Option<f32> {
+        self.resolution_controller
+            .borrow()
+            .as_ref()
+            .map(LossBasedResolutionController::scale)
+    }
+
+    /// Steps [scaleResolutionDownBy][1] of all the encodings of this
+    /// [`Sender`] according to the provided [`NetworkConditions`], if
+    /// loss-based resolution downgrade is enabled.
+    ///
+    /// # Errors
+    ///
+    /// With [`platform::Error`] if the underlying [setParameters()][2] call
+    /// fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    /// [2]: https://w3.org/TR/webrtc#dom-rtcrtpsender-setparameters
+    pub async fn adjust_resolution(
+        &self,
+        conditions: NetworkConditions,
+    ) -> Result<(), platform::Error> {
+        if let Some(fraction_lost) = conditions.fraction_lost {
+            _ = self
+                .resolution_controller
+                .borrow_mut()
+                .as_mut()
+                .and_then(|c| c.update(fraction_lost, platform::now()));
+        }
+        self.apply_resolution_scale().await
+    }
+
+    /// Applies [`ResolutionCeiling`] and loss-based downgrade of this
+    /// [`Sender`] via [scaleResolutionDownBy][1] of its encodings, based on
+    /// the current capture resolution.
+    ///
+    /// No-op if the resulting factors haven't changed since the last call.
+    ///
+    /// # Errors
+    ///
+    /// With [`platform::Error`] if the underlying [setParameters()][2] call
+    /// fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    /// [2]: https://w3.org/TR/webrtc#dom-rtcrtpsender-setparameters
+    pub async fn apply_resolution_scale(&self) -> Result<(), platform::Error> {
+        if self.caps.media_kind() != MediaKind::Video {
+            return Ok(());
+        }
+        let resolution = self.track.borrow().as_ref().and_then(|t| {
+            let track: &platform::MediaStreamTrack = t.as_ref().as_ref();
+            Some((track.width()?, track.height()?))
+        });
+        let ceiling_scale = self
+            .max_resolution
+            .get()
+            .zip(resolution)
+            .map_or(1.0, |(c, (width, height))| c.scale_for(width, height));
+        let loss_scale = self.loss_based_resolution_scale().unwrap_or(1.0);
+        let (applied_ceiling, applied_loss) = self.resolution_scale.get();
+        if (ceiling_scale - applied_ceiling).abs() < f32::EPSILON
+            && (loss_scale - applied_loss).abs() < f32::EPSILON
+        {
+            return Ok(());
+        }
+
+        let params = self.transceiver.get_send_parameters().await;
+        let configured = self.configured_scales.borrow().clone();
+        for (i, enc) in params.encodings().iter().enumerate() {
+            let configured = configured.get(i).copied().unwrap_or(1.0);
+            // Ceiling only limits the layers exceeding it, while loss-based
+            // downgrade lowers all the layers proportionally.
+            let scale = configured.max(ceiling_scale) * loss_scale;
+            enc.set_scale_resolution_down_by(scale.into());
+        }
+        self.transceiver.set_send_parameters(params).await?;
+        self.resolution_scale.set((ceiling_scale, loss_scale));
+
+        Ok(())
+    }
+
+    /// Records [scaleResolutionDownBy][1] factors of the provided
+    /// [`proto::EncodingParameters`] configured by a media server, which have
+    /// just been applied as is.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    fn set_configured_scales(&self, encodings: &[proto::EncodingParameters]) {
+        drop(self.configured_scales.replace(scales_of(encodings)));
+        self.resolution_scale.set((1.0, 1.0));
+    }
+
+    /// Sets [`platform::DegradationPreference`] of this [`Sender`], defining
+    /// how its video is degraded when bandwidth or CPU are constrained.
+    ///
+    /// The preference is re-applied whenever encodings of this [`Sender`] are
+    /// updated.
+    ///
+    /// # Errors
+    ///
+    /// With [`platform::Error`] if the underlying [setParameters()][1] call
+    /// fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpsender-setparameters
+    pub async fn set_degradation_preference(
+        &self,
+        pref: platform::DegradationPreference,
+    ) -> Result<(), platform::Error> {
+        self.degradation_preference.set(Some(pref));
+        self.apply_degradation_preference().await
+    }
+
+    /// Applies the [`platform::DegradationPreference`] of this [`Sender`] to
+    /// its [`platform::Transceiver`], if any has been set.
+    ///
+    /// # Errors
+    ///
+    /// With [`platform::Error`] if the underlying [setParameters()][1] call
+    /// fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpsender-setparameters
+    async fn apply_degradation_preference(
+        &self,
+    ) -> Result<(), platform::Error> {
+        let Some(pref) = self.degradation_preference.get() else {
+            return Ok(());
+        };
+        let params = self.transceiver.get_send_parameters().await;
+        params.set_degradation_preference(pref);
+        self.transceiver.set_send_parameters(params).await
+    }
+
+    /// Returns a [`Future`] resolving once outbound [RTP] stats of this
+    /// [`Sender`] report any sent packets for the first time, meaning that
+    /// media has actually left the machine.
+    ///
+    /// Resolves immediately if media has been sent already. Never resolves if
+    /// [`platform::RtcStats`] are not scraped.
+    ///
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    pub fn first_media_sent(&self) -> impl Future<Output = ()> + use<> {
+        let media_sent = self.media_sent.when_eq(true);
+        async move {
+            _ = media_sent.await;
+        }
+    }
+
+    /// Indicates whether outbound [RTP] stats of this [`Sender`] have ever
+    /// reported any sent packets.
+    ///
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    #[must_use]
+    pub fn is_media_sent(&self) -> bool {
+        self.media_sent.get()
+    }
+
+    /// Marks this [`Sender`] as having sent media, resolving the
+    /// [`Sender::first_media_sent()`] [`Future`]s.
+    pub fn set_media_sent(&self) {
+        self.media_sent.set(true);
+    }
+
+    /// Returns the total number of bytes sent by this [`Sender`] since its
+    /// creation.
+    ///
+    /// Updated on each [`platform::RtcStats`] scrape, and never decreases.
+    #[must_use]
+    pub fn bytes_sent(&self) -> u64 {
+        self.bytes_sent.total()
+    }
+
+    /// Returns ordered list of preferred [`proto::Codec`]s of this [`Sender`].
+    #[must_use]
+    pub fn preferred_codecs(&self) -> Vec<proto::Codec> {
+        self.preferred_codecs.borrow().clone()
+    }
+
+    /// Sets ordered list of preferred [`proto::Codec`]s of this [`Sender`].
+    pub fn set_preferred_codecs(&self, codecs: Vec<proto::Codec>) {
+        drop(self.preferred_codecs.replace(codecs));
+    }
+
+    /// Sets the provided `codecs` as codec preferences of this [`Sender`]'s
+    /// [`platform::Transceiver`].
+    pub fn set_codec_preferences(
+        &self,
+        codecs: Vec<platform::CodecCapability>,
+    ) {
+        self.transceiver.set_codec_preferences(codecs);
+    }
+
+    /// Records the cumulative `bytes_sent` reported by the outbound [RTP]
+    /// stats with the provided [`StatId`].
+    ///
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    pub fn record_bytes_sent(&self, stat_id: &StatId, bytes_sent: u64) {
+        self.bytes_sent.record(stat_id, bytes_sent);
     }
 }
 
@@ -334,6 +845,17 @@ impl Sender {
     }
 }
 
+/// Returns [scaleResolutionDownBy][1] factors of the provided
+/// [`proto::EncodingParameters`].
+///
+/// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+fn scales_of(encodings: &[proto::EncodingParameters]) -> Vec<f32> {
+    encodings
+        .iter()
+        .map(|e| e.scale_resolution_down_by.map_or(1.0, f32::from))
+        .collect()
+}
+
 impl Drop for Sender {
     fn drop(&mut self) {
         let transceiver = self.transceiver.clone();
@@ -0,0 +1,64 @@
+//! Ceiling of the video resolution sent by a [`Sender`].
+//!
+//! [`Sender`]: super::Sender
+
+/// Maximum video resolution sent by a [`Sender`] regardless of its capture
+/// resolution.
+///
+/// Applied independently of orientation, so `1280x720` ceiling limits a
+/// portrait `720x1280` capture the same way as a landscape one.
+///
+/// [`Sender`]: super::Sender
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ResolutionCeiling {
+    /// Maximum width (in pixels).
+    pub width: u32,
+
+    /// Maximum height (in pixels).
+    pub height: u32,
+}
+
+impl ResolutionCeiling {
+    /// Returns the minimal [scaleResolutionDownBy][1] factor keeping the
+    /// provided capture resolution under this [`ResolutionCeiling`].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    #[must_use]
+    pub fn scale_for(self, width: u32, height: u32) -> f32 {
+        let ratio = |captured: u32, max: u32| {
+            f64::from(captured) / f64::from(max.max(1))
+        };
+        let scale = ratio(width.max(height), self.width.max(self.height))
+            .max(ratio(width.min(height), self.width.min(self.height)))
+            .max(1.0);
+
+        #[expect( // no better way
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            reason = "no better way"
+        )]
+        let scale = scale as f32;
+        scale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HD: ResolutionCeiling =
+        ResolutionCeiling { width: 1280, height: 720 };
+
+    #[test]
+    fn downscales_above_ceiling() {
+        assert!((HD.scale_for(1920, 1080) - 1.5).abs() < f32::EPSILON);
+        assert!((HD.scale_for(1080, 1920) - 1.5).abs() < f32::EPSILON);
+        assert!((HD.scale_for(3840, 2160) - 3.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn never_upscales() {
+        assert!((HD.scale_for(640, 480) - 1.0).abs() < f32::EPSILON);
+        assert!((HD.scale_for(1280, 720) - 1.0).abs() < f32::EPSILON);
+    }
+}
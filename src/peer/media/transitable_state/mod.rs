@@ -61,6 +61,7 @@ impl MediaState {
                 id: track_id,
                 muted: Some(mute == mute_state::Stable::Muted),
                 enabled: None,
+                label: None,
             },
             Self::MediaExchange(media_exchange) => TrackPatchCommand {
                 id: track_id,
@@ -68,6 +69,7 @@ impl MediaState {
                     media_exchange == media_exchange_state::Stable::Enabled,
                 ),
                 muted: None,
+                label: None,
             },
         }
     }
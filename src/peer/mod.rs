@@ -2,9 +2,19 @@
 //!
 //! [1]: https://w3.org/TR/webrtc#rtcpeerconnection-interface
 
+mod candidate_summary;
 mod component;
+mod connection_timeline;
+mod data_channel;
+mod disconnect_grace;
+mod ice_candidate_filter;
+mod ice_servers_resolver;
+mod ice_transport_preference;
 pub mod media;
 pub mod repo;
+pub mod sdp;
+mod sdp_munging;
+mod stats_history;
 mod stream_update_criteria;
 mod tracks_request;
 
@@ -12,45 +22,179 @@ use std::{
     cell::{Cell, RefCell},
     collections::{HashMap, hash_map::DefaultHasher},
     hash::{Hash as _, Hasher as _},
-    rc::Rc,
+    rc::{Rc, Weak},
+    time::Duration,
 };
 
 use derive_more::with_trait::{Display, From};
-use futures::{StreamExt as _, channel::mpsc, future};
+use futures::{
+    FutureExt as _, StreamExt as _,
+    channel::mpsc,
+    future::{self, AbortHandle, Abortable},
+    stream::LocalBoxStream,
+};
 use medea_client_api_proto::{
-    Command, ConnectionMode, IceConnectionState, MediaSourceKind, MemberId,
-    PeerConnectionState, PeerId as Id, PeerId, TrackId, TrackPatchCommand,
-    stats::StatId,
+    Command, ConnectionMode, IceConnectionState, IceServer, MediaSourceKind,
+    MemberId, PeerConnectionState, PeerId as Id, PeerId, QualityLayer, TrackId,
+    TrackPatchCommand, stats::StatId,
 };
 use medea_macro::dispatchable;
+use medea_reactive::ObservableCell;
 use tracerr::Traced;
 
 #[doc(inline)]
 pub use self::{
-    component::{Component, DESCRIPTION_APPROVE_TIMEOUT, State},
+    candidate_summary::{CandidateCounts, CandidateSummary},
+    component::{
+        Component, DESCRIPTION_APPROVE_TIMEOUT, Politeness, SdpError, State,
+    },
+    connection_timeline::{ConnectionTimeline, IceRestartStats},
+    data_channel::DataChannel,
+    disconnect_grace::DisconnectGrace,
+    ice_candidate_filter::{IceCandidateFilter, IceCandidateType},
+    ice_servers_resolver::IceServersResolver,
+    ice_transport_preference::IceTransportProtocol,
     media::{
-        GetMidsError, InsertLocalTracksError, MediaConnections,
-        MediaExchangeState, MediaExchangeStateController, MediaState,
-        MediaStateControllable, MuteState, MuteStateController,
-        ProhibitedStateError, TrackDirection, TransceiverSide,
-        TransitableState, TransitableStateController, media_exchange_state,
-        mute_state, receiver, sender,
+        AddRemoteTrackError, CodecPreferences, GetMidsError,
+        InsertLocalTracksError, MediaConnections, MediaExchangeState,
+        MediaExchangeStateController, MediaState, MediaStateControllable,
+        MuteState, MuteStateController, ProhibitedStateError, RemoteTrackAdded,
+        TrackDirection, TrackStatus, TransceiverSide, TransitableState,
+        TransitableStateController, media_exchange_state, mute_state, receiver,
+        sender,
     },
     platform::RtcPeerConnectionError,
+    sdp_munging::{OpusConfig, SdpMunging},
+    stats_history::StatsSnapshot,
     stream_update_criteria::LocalStreamUpdateCriteria,
     tracks_request::{SimpleTracksRequest, TracksRequest, TracksRequestError},
 };
+use self::{
+    connection_timeline::IceTransitionTimestamps, stats_history::StatsHistory,
+};
 use crate::{
     connection::Connections,
     media::{
         InitLocalTracksError, LocalTracksConstraints, MediaKind, MediaManager,
-        MediaStreamSettings, RecvConstraints,
+        MediaStreamSettings, RecvConstraints, TrackConstraints, VideoSource,
         track::{local, remote},
     },
     platform,
-    utils::Caused,
+    utils::{
+        Caused,
+        logging::{self, LogCategory},
+    },
 };
 
+/// Label of the temporary [`DataChannel`] used by
+/// [`PeerConnection::probe_bandwidth()`].
+const BANDWIDTH_PROBE_LABEL: &str = "medea-bandwidth-probe";
+
+/// Size (in bytes) of a single message sent by
+/// [`PeerConnection::probe_bandwidth()`].
+const BANDWIDTH_PROBE_CHUNK_SIZE: usize = 16 * 1024;
+
+/// Interval between the messages sent by
+/// [`PeerConnection::probe_bandwidth()`].
+const BANDWIDTH_PROBE_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Maximum time [`PeerConnection::probe_bandwidth()`] waits for its temporary
+/// [`DataChannel`] to be negotiated and opened.
+const BANDWIDTH_PROBE_OPEN_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[cfg(debug_assertions)]
+thread_local! {
+    /// Number of [`PeerEvent`]s dropped by the [`send_peer_event()`], since
+    /// their consumer was gone.
+    static DROPPED_PEER_EVENTS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Returns the number of [`PeerEvent`]s dropped on the current thread, since
+/// their consumer was torn down before they've been sent.
+///
+/// Dropped [`PeerEvent`]s are counted in debug builds only, so this is always
+/// `0` in release builds.
+#[cfg_attr(
+    not(debug_assertions),
+    expect(clippy::missing_const_for_fn, reason = "non-`const` in debug")
+)]
+#[must_use]
+pub fn dropped_peer_events() -> u64 {
+    #[cfg(debug_assertions)]
+    {
+        DROPPED_PEER_EVENTS.with(Cell::get)
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        0
+    }
+}
+
+/// Sender of [`PeerEvent`]s.
+///
+/// In `mockable` builds, also delivers every sent [`PeerEvent`] to all the
+/// subscribers added via `PeerConnection::subscribe_events()`, right when
+/// it's sent.
+#[derive(Clone, Debug)]
+pub struct PeerEventSender {
+    /// Underlying [`PeerEvent`]s tx.
+    tx: mpsc::UnboundedSender<PeerEvent>,
+
+    /// Subscribers to the [`PeerEvent`]s sent via this [`PeerEventSender`].
+    #[cfg(feature = "mockable")]
+    subscribers: Rc<RefCell<Vec<mpsc::UnboundedSender<PeerEvent>>>>,
+}
+
+impl From<mpsc::UnboundedSender<PeerEvent>> for PeerEventSender {
+    fn from(tx: mpsc::UnboundedSender<PeerEvent>) -> Self {
+        Self {
+            tx,
+            #[cfg(feature = "mockable")]
+            subscribers: Rc::default(),
+        }
+    }
+}
+
+impl PeerEventSender {
+    /// Sends the provided [`PeerEvent`], returning `false` if its receiving
+    /// side is gone already.
+    fn send(&self, event: PeerEvent) -> bool {
+        #[cfg(feature = "mockable")]
+        self.subscribers
+            .borrow_mut()
+            .retain(|s| s.unbounded_send(PeerEvent::clone(&event)).is_ok());
+        self.tx.unbounded_send(event).is_ok()
+    }
+}
+
+/// Sends the provided [`PeerEvent`] via the provided `sender`.
+///
+/// In debug builds, accounts the [`PeerEvent`] in the
+/// [`dropped_peer_events()`] and logs it, if the receiving side is gone
+/// already.
+fn send_peer_event(sender: &PeerEventSender, event: PeerEvent) {
+    let is_sent = sender.send(event);
+    #[cfg(debug_assertions)]
+    if !is_sent {
+        DROPPED_PEER_EVENTS.with(|count| count.set(count.get() + 1));
+        logging::emit(
+            log::Level::Debug,
+            None,
+            LogCategory::Media,
+            format_args!("`PeerEvent` is dropped, since its consumer is gone"),
+        );
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        _ = is_sent;
+    }
+}
+
+/// [`platform::IceCandidate`] failed to be added in
+/// [`PeerConnection::add_ice_candidates()`], along with its error.
+pub type FailedIceCandidate =
+    (platform::IceCandidate, Traced<RtcPeerConnectionError>);
+
 /// Errors occurring in [`PeerConnection::update_local_stream()`] method.
 #[derive(Caused, Clone, Debug, Display, From)]
 #[cause(error = platform::Error)]
@@ -63,13 +207,45 @@ pub enum UpdateLocalStreamError {
 
     /// Errors occurred in [`MediaConnections::insert_local_tracks()`] method.
     InsertLocalTracksError(#[cause] InsertLocalTracksError),
+
+    /// [`local::Track`]s acquisition has been cancelled via
+    /// [`PeerConnection::cancel_local_stream_update()`].
+    #[display("Local stream update has been cancelled")]
+    #[from(ignore)]
+    Cancelled,
+}
+
+/// Errors occurring in [`PeerConnection::switch_video_device()`] and
+/// [`PeerConnection::switch_audio_device()`] methods.
+#[derive(Caused, Clone, Debug, Display, From)]
+#[cause(error = platform::Error)]
+pub enum SwitchDeviceError {
+    /// [`PeerConnection`] has no [`Sender`] with the provided [`TrackId`].
+    ///
+    /// [`Sender`]: sender::Sender
+    #[display("No `Sender` with `{_0}` ID")]
+    #[from(ignore)]
+    NoSuchSender(TrackId),
+
+    /// [`Sender`] with the provided [`TrackId`] doesn't send a media of the
+    /// required kind sourced from a media device.
+    ///
+    /// [`Sender`]: sender::Sender
+    #[display("`Sender` `{_0}` doesn't send media of the required kind")]
+    #[from(ignore)]
+    WrongSenderKind(TrackId),
+
+    /// [`MediaManager`] failed to acquire a [`local::Track`] from the new
+    /// media device.
+    #[display("Failed to acquire local media: {_0}")]
+    CouldNotGetLocalMedia(#[cause] InitLocalTracksError),
 }
 
 /// Events emitted from a [`Sender`] or a [`Receiver`].
 ///
 /// [`Receiver`]: receiver::Receiver
 /// [`Sender`]: sender::Sender
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub enum TrackEvent {
     /// Intention of the `MediaTrack` to mute/unmute himself.
     MuteUpdateIntention {
@@ -78,6 +254,9 @@ pub enum TrackEvent {
 
         /// The muting intention itself.
         muted: bool,
+
+        /// Application-defined label of the `MediaTrack`, if any.
+        label: Option<String>,
     },
 
     /// Intention of the `MediaTrack` to enabled/disable himself.
@@ -87,6 +266,28 @@ pub enum TrackEvent {
 
         /// The enabling/disabling intention itself.
         enabled: bool,
+
+        /// Application-defined label of the `MediaTrack`, if any.
+        label: Option<String>,
+    },
+
+    /// Intention of the `MediaTrack` to be relabeled.
+    LabelUpdateIntention {
+        /// ID of the `MediaTrack` which sends this intention.
+        id: TrackId,
+
+        /// New application-defined label of the `MediaTrack`.
+        label: String,
+    },
+
+    /// Intention of the `MediaTrack` to be received in the specific
+    /// [`QualityLayer`].
+    QualityLayerIntention {
+        /// ID of the `MediaTrack` which sends this intention.
+        id: TrackId,
+
+        /// Desired [`QualityLayer`] of the `MediaTrack`.
+        layer: QualityLayer,
     },
 }
 
@@ -195,6 +396,16 @@ pub enum PeerEvent {
         track: remote::Track,
     },
 
+    /// Remote peer created a new [`DataChannel`] on a
+    /// [`platform::RtcPeerConnection`].
+    NewDataChannel {
+        /// ID of the [`PeerConnection`] the [`DataChannel`] was created on.
+        peer_id: Id,
+
+        /// Created [`DataChannel`].
+        channel: DataChannel,
+    },
+
     /// [`platform::RtcPeerConnection`] sent new local track to remote members.
     NewLocalTrack {
         /// Local [`local::Track`] that is sent to remote members.
@@ -229,6 +440,20 @@ pub enum PeerEvent {
         peer_connection_state: PeerConnectionState,
     },
 
+    /// [`platform::RtcPeerConnection`]'s [signaling state][1] changed.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-peerconnection-signaling-state
+    SignalingStateChanged {
+        /// ID of the [`PeerConnection`] that sends
+        /// [`signalingstatechange`][1] event.
+        ///
+        /// [1]: https://w3.org/TR/webrtc#event-signalingstatechange
+        peer_id: Id,
+
+        /// New [`platform::SignalingState`].
+        signaling_state: platform::SignalingState,
+    },
+
     /// [`platform::RtcPeerConnection`]'s [`platform::RtcStats`] update.
     StatsUpdate {
         /// ID of the [`PeerConnection`] for which [` platform::RtcStats`] was
@@ -239,6 +464,20 @@ pub enum PeerEvent {
         stats: platform::RtcStats,
     },
 
+    /// [`platform::RtcStats`] have been scraped from the
+    /// [`platform::RtcPeerConnection`].
+    ///
+    /// Unlike [`PeerEvent::StatsUpdate`], is emitted regardless of whether
+    /// stats sending is enabled, so the scraped stats can be used locally.
+    StatsScraped {
+        /// ID of the [`PeerConnection`] the [`platform::RtcStats`] have been
+        /// scraped from.
+        peer_id: Id,
+
+        /// All the scraped [`platform::RtcStats`] of this [`PeerConnection`].
+        stats: platform::RtcStats,
+    },
+
     /// [`PeerConnection::update_local_stream`] was failed, so
     /// `on_failed_local_stream` callback should be called.
     FailedLocalMedia {
@@ -278,6 +517,28 @@ pub enum PeerEvent {
         transceivers_statuses: HashMap<TrackId, bool>,
     },
 
+    /// [currentDirection][1] of a [`platform::Transceiver`] has been changed
+    /// by a negotiation.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtptransceiver-currentdirection
+    TransceiverDirectionChanged {
+        /// ID of the [`PeerConnection`] the [`platform::Transceiver`] belongs
+        /// to.
+        peer_id: PeerId,
+
+        /// ID of the track the [`platform::Transceiver`] is used by.
+        track_id: TrackId,
+
+        /// New negotiated [`platform::TransceiverDirection`].
+        direction: platform::TransceiverDirection,
+    },
+
+    /// [`PeerConnection`] requires a renegotiation to apply its changes.
+    RenegotiationNeeded {
+        /// ID of the [`PeerConnection`] requiring a renegotiation.
+        peer_id: PeerId,
+    },
+
     /// [`Component`] resends his intentions.
     MediaUpdateCommand {
         /// Actual intentions of the [`Component`].
@@ -302,7 +563,7 @@ pub struct PeerConnection {
     media_manager: Rc<MediaManager>,
 
     /// [`PeerEvent`]s tx.
-    peer_events_sender: Rc<mpsc::UnboundedSender<PeerEvent>>,
+    peer_events_sender: Rc<PeerEventSender>,
 
     /// Indicator whether the underlying [`platform::RtcPeerConnection`] has a
     /// remote description.
@@ -312,6 +573,10 @@ pub struct PeerConnection {
     /// description for the underlying [`platform::RtcPeerConnection`].
     ice_candidates_buffer: RefCell<Vec<platform::IceCandidate>>,
 
+    /// Number of [`platform::IceCandidate`]s being either buffered or added to
+    /// the underlying [`platform::RtcPeerConnection`] at the moment.
+    pending_ice_candidates: ObservableCell<usize>,
+
     /// Last hashes of all the [`platform::RtcStats`] which were already sent
     /// to a server, so we won't duplicate stats that were already sent.
     ///
@@ -319,6 +584,56 @@ pub struct PeerConnection {
     /// values.
     sent_stats_cache: RefCell<HashMap<StatId, u64>>,
 
+    /// Indicator whether the scraped [`platform::RtcStats`] are sent to a
+    /// server via [`PeerEvent::StatsUpdate`]s.
+    stats_sending_enabled: Cell<bool>,
+
+    /// History of the [`platform::RtcStats`] scraped from this
+    /// [`PeerConnection`].
+    stats_history: RefCell<StatsHistory>,
+
+    /// [`DisconnectGrace`] delaying the [`PeerConnectionState::Disconnected`]
+    /// reports of this [`PeerConnection`].
+    disconnect_grace: Rc<DisconnectGrace>,
+
+    /// [`IceCandidateFilter`] applied to the discovered ICE candidates before
+    /// emitting them.
+    ice_candidate_filter: Rc<RefCell<Option<IceCandidateFilter>>>,
+
+    /// Indicator whether discovered host ICE candidates with [mDNS]
+    /// addresses should be dropped instead of being emitted.
+    ///
+    /// [mDNS]: https://tools.ietf.org/html/draft-ietf-mmusic-mdns-ice-candidates
+    drop_mdns_candidates: Rc<Cell<bool>>,
+
+    /// Indicator whether stereo audio is negotiated, so the captured audio
+    /// should have 2 channels.
+    stereo_audio: Cell<bool>,
+
+    /// Last observed negotiated [`platform::TransceiverDirection`]s of the
+    /// [`platform::Transceiver`]s of this [`PeerConnection`].
+    transceiver_directions:
+        RefCell<HashMap<TrackId, platform::TransceiverDirection>>,
+
+    /// [`CandidateSummary`] of the last scraped [`platform::RtcStats`].
+    candidate_summary: Cell<CandidateSummary>,
+
+    /// Indicator whether only relay ICE candidates are requested to be used by
+    /// this [`PeerConnection`].
+    force_relay: bool,
+
+    /// Timestamps of the [`IceConnectionState`] transitions of this
+    /// [`PeerConnection`].
+    ice_transitions: Rc<Cell<IceTransitionTimestamps>>,
+
+    /// [`IceRestartStats`] of this [`PeerConnection`].
+    ice_restart_stats: Cell<IceRestartStats>,
+
+    /// [`AbortHandle`] of the [`local::Track`]s acquisition performed by the
+    /// latest [`PeerConnection::update_local_stream()`] call, while it's in
+    /// progress.
+    local_stream_update_abort: RefCell<Option<Rc<AbortHandle>>>,
+
     /// Local media stream constraints used in this [`PeerConnection`].
     send_constraints: LocalTracksConstraints,
 
@@ -334,9 +649,37 @@ pub struct PeerConnection {
     /// Constraints to the [`remote::Track`] from this [`PeerConnection`]. Used
     /// to disable or enable media receiving.
     recv_constraints: Rc<RecvConstraints>,
+
+    /// [`DataChannel`]s created by the remote peer on this
+    /// [`PeerConnection`].
+    remote_data_channels: Rc<RefCell<Vec<DataChannel>>>,
+
+    /// Subscribers to the [`DataChannel`]s created by the remote peer on this
+    /// [`PeerConnection`].
+    on_data_channel_subs: Rc<RefCell<Vec<mpsc::UnboundedSender<DataChannel>>>>,
 }
 
 impl PeerConnection {
+    /// Replaces [`IceServer`]s of the underlying
+    /// [`platform::RtcPeerConnection`] with the provided ones.
+    ///
+    /// Affects only the ICE candidates gathered afterwards.
+    ///
+    /// # Errors
+    ///
+    /// With [`RtcPeerConnectionError::SetConfigurationFailed`] if
+    /// [RTCPeerConnection.setConfiguration()][1] fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcpeerconnection-setconfiguration
+    pub fn update_ice_servers(
+        &self,
+        ice_servers: Vec<IceServer>,
+    ) -> Result<(), Traced<RtcPeerConnectionError>> {
+        self.peer
+            .set_ice_servers(ice_servers)
+            .map_err(tracerr::map_from_and_wrap!())
+    }
+
     /// Creates a new [`PeerConnection`].
     ///
     /// Provided `peer_events_sender` will be used to emit [`PeerEvent`]s from
@@ -359,12 +702,15 @@ impl PeerConnection {
     ) -> Result<Rc<Self>, Traced<RtcPeerConnectionError>> {
         let peer = Rc::new(
             platform::RtcPeerConnection::new(
-                state.ice_servers().clone(),
+                state.rtc_ice_servers(),
                 state.force_relay(),
+                state.bundle_policy(),
+                state.rtcp_mux_policy(),
             )
             .await
             .map_err(tracerr::map_from_and_wrap!())?,
         );
+        let peer_events_sender = PeerEventSender::from(peer_events_sender);
         let (track_events_sender, mut track_events_rx) = mpsc::unbounded();
         let media_connections = Rc::new(MediaConnections::new(
             Rc::clone(&peer),
@@ -389,12 +735,27 @@ impl PeerConnection {
             media_manager,
             peer_events_sender: Rc::new(peer_events_sender),
             sent_stats_cache: RefCell::new(HashMap::new()),
+            stats_sending_enabled: Cell::new(true),
+            stats_history: RefCell::default(),
+            disconnect_grace: Rc::default(),
+            ice_candidate_filter: Rc::default(),
+            drop_mdns_candidates: Rc::default(),
+            stereo_audio: Cell::new(false),
+            transceiver_directions: RefCell::default(),
+            candidate_summary: Cell::default(),
+            force_relay: state.force_relay(),
+            ice_transitions: Rc::default(),
+            ice_restart_stats: Cell::default(),
+            local_stream_update_abort: RefCell::new(None),
             has_remote_description: Cell::new(false),
             ice_candidates_buffer: RefCell::new(Vec::new()),
+            pending_ice_candidates: ObservableCell::new(0),
             send_constraints,
             connections,
             track_events_sender,
             recv_constraints,
+            remote_data_channels: Rc::default(),
+            on_data_channel_subs: Rc::default(),
         };
 
         peer.bind_event_listeners(state);
@@ -408,9 +769,17 @@ impl PeerConnection {
         {
             let id = self.id;
             let weak_sender = Rc::downgrade(&self.peer_events_sender);
+            let filter = Rc::clone(&self.ice_candidate_filter);
+            let drop_mdns = Rc::clone(&self.drop_mdns_candidates);
             self.peer.on_ice_candidate(Some(move |candidate| {
                 if let Some(sender) = weak_sender.upgrade() {
-                    Self::on_ice_candidate(id, &sender, candidate);
+                    Self::on_ice_candidate(
+                        id,
+                        &sender,
+                        filter.borrow().as_ref(),
+                        drop_mdns.get(),
+                        candidate,
+                    );
                 }
             }));
         }
@@ -426,16 +795,114 @@ impl PeerConnection {
             }));
         }
 
+        self.bind_state_change_listeners();
+
+        // Bind to `datachannel` event.
+        {
+            let id = self.id;
+            let weak_sender = Rc::downgrade(&self.peer_events_sender);
+            let channels = Rc::downgrade(&self.remote_data_channels);
+            let subs = Rc::downgrade(&self.on_data_channel_subs);
+            self.peer.on_data_channel(Some(move |channel| {
+                let channel = DataChannel::new(channel);
+                if let Some(chans) = channels.upgrade() {
+                    chans.borrow_mut().push(channel.clone());
+
+                    // Closed channels are of no use anymore, so they're
+                    // forgotten.
+                    let closed = channel.when_closed();
+                    let channels = Weak::clone(&channels);
+                    platform::spawn(async move {
+                        closed.await;
+                        if let Some(channels) = channels.upgrade() {
+                            channels.borrow_mut().retain(|c| !c.is_closed());
+                        }
+                    });
+                }
+                if let Some(subs) = subs.upgrade() {
+                    subs.borrow_mut().retain(|sub| {
+                        sub.unbounded_send(channel.clone()).is_ok()
+                    });
+                }
+                if let Some(sender) = weak_sender.upgrade() {
+                    send_peer_event(
+                        &sender,
+                        PeerEvent::NewDataChannel { peer_id: id, channel },
+                    );
+                }
+            }));
+        }
+
+        // Bind to `track` event.
+        {
+            let media_conns = Rc::downgrade(&self.media_connections);
+            let connection_mode = state.connection_mode();
+            let peer_id = self.id;
+            self.peer.on_track(Some(move |track, transceiver| {
+                if let Some(c) = media_conns.upgrade() {
+                    platform::spawn(async move {
+                        let res = c.add_remote_track(track, transceiver).await;
+                        if connection_mode == ConnectionMode::Mesh {
+                            Self::on_remote_track_added(peer_id, res);
+                        }
+                    });
+                }
+            }));
+        }
+    }
+
+    /// Logs the result of adding a remote track in [`ConnectionMode::Mesh`].
+    ///
+    /// The same member's track may be delivered more than once (e.g. on
+    /// renegotiation), so [`RemoteTrackAdded::Duplicate`]s are expected and
+    /// ignored.
+    fn on_remote_track_added(
+        peer_id: Id,
+        res: Result<RemoteTrackAdded, Traced<AddRemoteTrackError>>,
+    ) {
+        match res {
+            Ok(RemoteTrackAdded::Added) => {}
+            Ok(RemoteTrackAdded::Duplicate { sender_id, track_id }) => {
+                logging::emit(
+                    log::Level::Debug,
+                    Some(peer_id),
+                    LogCategory::Media,
+                    format_args!(
+                        "Ignoring duplicated remote track {track_id} of \
+                         `{sender_id}` member",
+                    ),
+                );
+            }
+            Err(e) => {
+                logging::emit(
+                    log::Level::Error,
+                    Some(peer_id),
+                    LogCategory::Media,
+                    format_args!("{e}"),
+                );
+            }
+        }
+    }
+
+    /// Binds listeners of the [ICE connection state][1], [connection
+    /// state][2] and [signaling state][3] changes of this [`PeerConnection`].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dfn-ice-connection-state
+    /// [2]: https://w3.org/TR/webrtc#dom-peerconnection-connection-state
+    /// [3]: https://w3.org/TR/webrtc#dom-peerconnection-signaling-state
+    fn bind_state_change_listeners(&self) {
         // Bind to `iceconnectionstatechange` event.
         {
             let id = self.id;
             let weak_sender = Rc::downgrade(&self.peer_events_sender);
+            let transitions = Rc::clone(&self.ice_transitions);
             self.peer.on_ice_connection_state_change(Some(
                 move |ice_connection_state| {
                     if let Some(sender) = weak_sender.upgrade() {
                         Self::on_ice_connection_state_changed(
                             id,
                             &sender,
+                            &transitions,
                             ice_connection_state,
                         );
                     }
@@ -447,35 +914,32 @@ impl PeerConnection {
         {
             let id = self.id;
             let weak_sender = Rc::downgrade(&self.peer_events_sender);
+            let disconnect_grace = Rc::clone(&self.disconnect_grace);
             self.peer.on_connection_state_change(Some(
                 move |peer_connection_state| {
-                    if let Some(sender) = weak_sender.upgrade() {
-                        Self::on_connection_state_changed(
-                            id,
-                            &sender,
-                            peer_connection_state,
-                        );
-                    }
+                    let weak_sender = Weak::clone(&weak_sender);
+                    disconnect_grace.report(peer_connection_state, move |s| {
+                        if let Some(sender) = weak_sender.upgrade() {
+                            Self::on_connection_state_changed(id, &sender, s);
+                        }
+                    });
                 },
             ));
         }
 
-        // Bind to `track` event.
+        // Bind to `signalingstatechange` event.
         {
-            let media_conns = Rc::downgrade(&self.media_connections);
-            let connection_mode = state.connection_mode();
-            self.peer.on_track(Some(move |track, transceiver| {
-                if let Some(c) = media_conns.upgrade() {
-                    platform::spawn(async move {
-                        if let (Err(mid), ConnectionMode::Mesh) = (
-                            c.add_remote_track(track, transceiver).await,
-                            connection_mode,
-                        ) {
-                            log::error!(
-                                "Cannot add new remote track with mid={mid}",
-                            );
-                        }
-                    });
+            let id = self.id;
+            let weak_sender = Rc::downgrade(&self.peer_events_sender);
+            self.peer.on_signaling_state_change(Some(move |signaling_state| {
+                if let Some(sender) = weak_sender.upgrade() {
+                    send_peer_event(
+                        &sender,
+                        PeerEvent::SignalingStateChanged {
+                            peer_id: id,
+                            signaling_state,
+                        },
+                    );
                 }
             }));
         }
@@ -484,33 +948,68 @@ impl PeerConnection {
     /// Handles [`TrackEvent`]s emitted from a [`Sender`] or a [`Receiver`].
     ///
     /// Sends a [`PeerEvent::MediaUpdateCommand`] with a
-    /// [`Command::UpdateTracks`] on [`TrackEvent::MediaExchangeIntention`] and
-    /// [`TrackEvent::MuteUpdateIntention`].
+    /// [`Command::UpdateTracks`] on [`TrackEvent::MediaExchangeIntention`],
+    /// [`TrackEvent::MuteUpdateIntention`] and
+    /// [`TrackEvent::LabelUpdateIntention`], or with a
+    /// [`Command::RequestQualityLayer`] on
+    /// [`TrackEvent::QualityLayerIntention`].
     ///
     /// [`Sender`]: sender::Sender
     /// [`Receiver`]: receiver::Receiver
     fn handle_track_event(
         peer_id: PeerId,
-        peer_events_sender: &mpsc::UnboundedSender<PeerEvent>,
+        peer_events_sender: &PeerEventSender,
         event: TrackEvent,
     ) {
         let patch = match event {
-            TrackEvent::MediaExchangeIntention { id, enabled } => {
-                TrackPatchCommand { id, muted: None, enabled: Some(enabled) }
+            TrackEvent::MediaExchangeIntention { id, enabled, label } => {
+                TrackPatchCommand {
+                    id,
+                    muted: None,
+                    enabled: Some(enabled),
+                    label,
+                }
+            }
+            TrackEvent::MuteUpdateIntention { id, muted, label } => {
+                TrackPatchCommand {
+                    id,
+                    muted: Some(muted),
+                    enabled: None,
+                    label,
+                }
+            }
+            TrackEvent::LabelUpdateIntention { id, label } => {
+                TrackPatchCommand {
+                    id,
+                    muted: None,
+                    enabled: None,
+                    label: Some(label),
+                }
             }
-            TrackEvent::MuteUpdateIntention { id, muted } => {
-                TrackPatchCommand { id, muted: Some(muted), enabled: None }
+            TrackEvent::QualityLayerIntention { id, layer } => {
+                send_peer_event(
+                    peer_events_sender,
+                    PeerEvent::MediaUpdateCommand {
+                        command: Command::RequestQualityLayer {
+                            peer_id,
+                            track_id: id,
+                            layer,
+                        },
+                    },
+                );
+                return;
             }
         };
 
-        _ = peer_events_sender
-            .unbounded_send(PeerEvent::MediaUpdateCommand {
+        send_peer_event(
+            peer_events_sender,
+            PeerEvent::MediaUpdateCommand {
                 command: Command::UpdateTracks {
                     peer_id,
                     tracks_patches: vec![patch],
                 },
-            })
-            .ok();
+            },
+        );
     }
 
     /// Returns all [`TrackId`]s of [`Sender`]s that match the provided
@@ -535,7 +1034,55 @@ impl PeerConnection {
 
     /// Filters out already sent stats, and send new stats from the provided
     /// [`platform::RtcStats`].
+    ///
+    /// Does nothing if stats sending is disabled via
+    /// [`State::set_stats_sending_enabled()`].
     pub fn send_peer_stats(&self, stats: platform::RtcStats) {
+        if let Some(stats) = self.filter_unsent_stats(stats) {
+            send_peer_event(
+                &self.peer_events_sender,
+                PeerEvent::StatsUpdate { peer_id: self.id, stats },
+            );
+        }
+    }
+
+    /// Scrapes [`platform::RtcStats`] of this [`PeerConnection`] which haven't
+    /// been sent to a server yet.
+    ///
+    /// Unlike [`PeerConnection::scrape_and_send_peer_stats()`], doesn't emit a
+    /// [`PeerEvent::StatsUpdate`], but returns the scraped stats, so they can
+    /// be sent to a server right away.
+    ///
+    /// Returns [`None`] if there is nothing to send, stats sending is disabled
+    /// via [`State::set_stats_sending_enabled()`], or the stats cannot be
+    /// scraped.
+    pub async fn scrape_unsent_peer_stats(&self) -> Option<platform::RtcStats> {
+        match self.peer.get_stats().await {
+            Ok(stats) => self.filter_unsent_stats(stats),
+            Err(e) => {
+                logging::emit(
+                    log::Level::Error,
+                    Some(self.id),
+                    LogCategory::Stats,
+                    format_args!("{e}"),
+                );
+                None
+            }
+        }
+    }
+
+    /// Filters out the already sent stats from the provided
+    /// [`platform::RtcStats`].
+    ///
+    /// Returns [`None`] if there is nothing to send, or stats sending is
+    /// disabled via [`State::set_stats_sending_enabled()`].
+    fn filter_unsent_stats(
+        &self,
+        stats: platform::RtcStats,
+    ) -> Option<platform::RtcStats> {
+        if !self.stats_sending_enabled.get() {
+            return None;
+        }
         let mut stats_cache = self.sent_stats_cache.borrow_mut();
         let stats = platform::RtcStats(
             stats
@@ -565,92 +1112,415 @@ impl PeerConnection {
                 .collect(),
         );
 
-        if !stats.0.is_empty() {
-            drop(self.peer_events_sender.unbounded_send(
-                PeerEvent::StatsUpdate { peer_id: self.id, stats },
-            ));
-        }
+        (!stats.0.is_empty()).then_some(stats)
     }
 
     /// Sends [`platform::RtcStats`] update of this [`PeerConnection`] to a
     /// server.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(peer_id = self.id.0)),
+    )]
     pub async fn scrape_and_send_peer_stats(&self) {
         match self.peer.get_stats().await {
-            Ok(stats) => self.send_peer_stats(stats),
-            Err(e) => log::error!("{e}"),
+            Ok(stats) => {
+                self.media_connections.update_receivers_frame_sizes(&stats);
+                self.media_connections.detect_receivers_freezes(&stats).await;
+                self.media_connections.update_senders_media_sent(&stats);
+                self.stats_history.borrow_mut().push(&stats);
+                self.candidate_summary.set(CandidateSummary::from(&stats));
+                self.media_connections.adjust_senders_bitrate(&stats).await;
+                self.media_connections.adjust_senders_resolution(&stats).await;
+                send_peer_event(
+                    &self.peer_events_sender,
+                    PeerEvent::StatsScraped {
+                        peer_id: self.id,
+                        stats: stats.clone(),
+                    },
+                );
+                self.send_peer_stats(stats);
+            }
+            Err(e) => logging::emit(
+                log::Level::Error,
+                Some(self.id),
+                LogCategory::Stats,
+                format_args!("{e}"),
+            ),
         }
     }
 
-    /// Indicates whether all [`TransceiverSide`]s with the provided
-    /// [`MediaKind`], [`TrackDirection`] and [`MediaSourceKind`] are in the
-    /// provided [`MediaState`].
-    #[must_use]
-    pub fn is_all_transceiver_sides_in_media_state(
-        &self,
-        kind: MediaKind,
-        direction: TrackDirection,
-        source_kind: Option<MediaSourceKind>,
-        state: MediaState,
-    ) -> bool {
-        self.media_connections.is_all_tracks_in_media_state(
-            kind,
-            direction,
-            source_kind,
-            state,
-        )
+    /// Sets the [`IceCandidateFilter`] to be applied to the ICE candidates
+    /// discovered by this [`PeerConnection`] before emitting them.
+    fn set_ice_candidate_filter(&self, filter: Option<IceCandidateFilter>) {
+        drop(self.ice_candidate_filter.replace(filter));
     }
 
-    /// Returns the [`PeerId`] of this [`PeerConnection`].
-    pub const fn id(&self) -> PeerId {
-        self.id
+    /// Returns [`CandidateSummary`] of the last scraped
+    /// [`platform::RtcStats`] of this [`PeerConnection`].
+    ///
+    /// Empty until [`platform::RtcStats`] are scraped for the first time.
+    #[must_use]
+    pub fn candidate_summary(&self) -> CandidateSummary {
+        self.candidate_summary.get()
     }
 
-    /// Handle `icecandidate` event from the underlying peer emitting
-    /// [`PeerEvent::IceCandidateDiscovered`] event into this peer's
-    /// `peer_events_sender`.
-    fn on_ice_candidate(
-        id: Id,
-        sender: &mpsc::UnboundedSender<PeerEvent>,
-        candidate: platform::IceCandidate,
-    ) {
-        drop(sender.unbounded_send(PeerEvent::IceCandidateDiscovered {
-            peer_id: id,
-            candidate: candidate.candidate,
-            sdp_m_line_index: candidate.sdp_m_line_index,
-            sdp_mid: candidate.sdp_mid,
-        }));
+    /// Indicates whether the ICE candidate pair selected by this
+    /// [`PeerConnection`] is relayed via a TURN server, reading it from fresh
+    /// [`platform::RtcStats`].
+    ///
+    /// Returns [`None`] if no candidate pair is selected yet (or the
+    /// [`platform::RtcStats`] cannot be retrieved).
+    ///
+    /// Logs a warning if relaying is forced, but the selected candidate pair
+    /// isn't relayed, which usually means a misconfigured TURN server.
+    pub async fn is_relayed(&self) -> Option<bool> {
+        let stats = match self.peer.get_stats().await {
+            Ok(stats) => stats,
+            Err(e) => {
+                logging::emit(
+                    log::Level::Error,
+                    Some(self.id),
+                    LogCategory::Stats,
+                    format_args!("{e}"),
+                );
+                return None;
+            }
+        };
+
+        let is_relayed = candidate_summary::is_relayed(&stats);
+        if self.force_relay && is_relayed == Some(false) {
+            logging::emit(
+                log::Level::Warn,
+                Some(self.id),
+                LogCategory::Ice,
+                format_args!(
+                    "Relaying is forced, but the selected ICE candidate pair \
+                     isn't relayed",
+                ),
+            );
+        }
+        is_relayed
     }
 
-    /// Handle `icecandidateerror` event from the underlying peer emitting
-    /// [`PeerEvent::IceCandidateError`] event into this peer's
-    /// `peer_events_sender`.
-    fn on_ice_candidate_error(
-        id: Id,
-        sender: &mpsc::UnboundedSender<PeerEvent>,
-        error: platform::IceCandidateError,
-    ) {
-        drop(sender.unbounded_send(PeerEvent::IceCandidateError {
-            peer_id: id,
-            address: error.address,
-            port: error.port,
-            url: error.url,
-            error_code: error.error_code,
-            error_text: error.error_text,
-        }));
+    /// Returns the URL of the TURN server currently relaying media of this
+    /// [`PeerConnection`], reading it from fresh [`platform::RtcStats`].
+    ///
+    /// Returns [`None`] if no candidate pair is selected yet, the selected one
+    /// isn't relayed by this side, or the [`platform::RtcStats`] cannot be
+    /// retrieved.
+    pub async fn relay_url(&self) -> Option<String> {
+        match self.peer.get_stats().await {
+            Ok(stats) => candidate_summary::relay_url(&stats),
+            Err(e) => {
+                logging::emit(
+                    log::Level::Error,
+                    Some(self.id),
+                    LogCategory::Stats,
+                    format_args!("{e}"),
+                );
+                None
+            }
+        }
     }
 
-    /// Handle `iceconnectionstatechange` event from the underlying peer
+    /// Estimates the available uplink bandwidth (in bits per second) of this
+    /// [`PeerConnection`], so it can be decided whether to start sending video
+    /// at all.
+    ///
+    /// Bursts unreliable messages through a temporary [`DataChannel`] during
+    /// the provided `duration` to ramp up the congestion controller, and then
+    /// reads the [availableOutgoingBitrate][1] of the selected ICE candidate
+    /// pair from fresh [`platform::RtcStats`]. The temporary [`DataChannel`]
+    /// is closed afterwards.
+    ///
+    /// Since the temporary [`DataChannel`] is opened only once negotiated, a
+    /// renegotiation is requested via [`PeerEvent::RenegotiationNeeded`], and
+    /// the burst starts once it's opened. If data channels aren't supported on
+    /// the current platform, or the [`DataChannel`] isn't opened in
+    /// [`BANDWIDTH_PROBE_OPEN_TIMEOUT`], the estimate is read without any
+    /// burst.
+    ///
+    /// Returns [`None`] if no ICE candidate pair is selected yet, it has no
+    /// estimate reported, or the [`platform::RtcStats`] cannot be retrieved.
+    ///
+    /// [1]: https://w3.org/TR/webrtc-stats#dom-rtcicecandidatepairstats-availableoutgoingbitrate
+    pub async fn probe_bandwidth(&self, duration: Duration) -> Option<u64> {
+        let channel = self
+            .create_data_channel(
+                BANDWIDTH_PROBE_LABEL,
+                platform::DataChannelInit {
+                    ordered: false,
+                    max_retransmits: Some(0),
+                },
+            )
+            .map_err(|e| {
+                logging::emit(
+                    log::Level::Debug,
+                    Some(self.id),
+                    LogCategory::Stats,
+                    format_args!("Bandwidth is probed without a burst: {e}"),
+                );
+            })
+            .ok();
+        if let Some(channel) = &channel {
+            send_peer_event(
+                &self.peer_events_sender,
+                PeerEvent::RenegotiationNeeded { peer_id: self.id },
+            );
+            let opened = future::select(
+                channel.when_open(),
+                platform::delay_for(BANDWIDTH_PROBE_OPEN_TIMEOUT).boxed_local(),
+            )
+            .await;
+            if let future::Either::Left(_) = opened {
+                let chunk = vec![0; BANDWIDTH_PROBE_CHUNK_SIZE];
+                let mut elapsed = Duration::ZERO;
+                while elapsed < duration {
+                    // Sending fails while the channel's buffer is full, which
+                    // is fine for probing.
+                    _ = channel.send(&chunk).ok();
+                    platform::delay_for(BANDWIDTH_PROBE_INTERVAL).await;
+                    elapsed += BANDWIDTH_PROBE_INTERVAL;
+                }
+            } else {
+                logging::emit(
+                    log::Level::Debug,
+                    Some(self.id),
+                    LogCategory::Stats,
+                    format_args!(
+                        "Bandwidth is probed without a burst: `DataChannel` \
+                         hasn't been opened in \
+                         {BANDWIDTH_PROBE_OPEN_TIMEOUT:?}",
+                    ),
+                );
+            }
+        }
+
+        let stats = self.peer.get_stats().await;
+        if let Some(channel) = channel {
+            channel.close();
+        }
+        match stats {
+            Ok(stats) => candidate_summary::available_outgoing_bitrate(&stats),
+            Err(e) => {
+                logging::emit(
+                    log::Level::Error,
+                    Some(self.id),
+                    LogCategory::Stats,
+                    format_args!("{e}"),
+                );
+                None
+            }
+        }
+    }
+
+    /// Returns the [`ConnectionTimeline`] of the last ICE connectivity checks
+    /// of this [`PeerConnection`].
+    ///
+    /// Derived from the observed [`IceConnectionState`] transitions' times.
+    #[must_use]
+    pub fn connection_timeline(&self) -> ConnectionTimeline {
+        self.ice_transitions.get().timeline()
+    }
+
+    /// Sets the [`sender::ResolutionCeiling`] of all the video [`Sender`]s of
+    /// this [`PeerConnection`], so their video is never sent above it
+    /// regardless of the capture resolution.
+    ///
+    /// The ceiling is re-applied on every [`platform::RtcStats`] scrape if the
+    /// capture resolution changes. [`None`] removes the ceiling.
+    ///
+    /// [`Sender`]: media::Sender
+    pub async fn set_max_send_resolution(
+        &self,
+        ceiling: Option<sender::ResolutionCeiling>,
+    ) {
+        self.media_connections.set_max_send_resolution(ceiling).await;
+    }
+
+    /// Enables loss-based resolution downgrade of all the video [`Sender`]s of
+    /// this [`PeerConnection`] with the provided
+    /// [`sender::LossBasedResolutionConfig`].
+    ///
+    /// [`None`] disables it.
+    ///
+    /// [`Sender`]: media::Sender
+    pub fn set_loss_based_resolution(
+        &self,
+        config: Option<sender::LossBasedResolutionConfig>,
+    ) {
+        self.media_connections.set_loss_based_resolution(config);
+    }
+
+    /// Enables adaptive bitrate of all the video [`Sender`]s of this
+    /// [`PeerConnection`] with the provided [`sender::AdaptiveBitrateConfig`].
+    ///
+    /// [`None`] disables it.
+    ///
+    /// [`Sender`]: media::Sender
+    pub fn set_adaptive_bitrate(
+        &self,
+        config: Option<sender::AdaptiveBitrateConfig>,
+    ) {
+        self.media_connections.set_adaptive_bitrate(config);
+    }
+
+    /// Pre-allocates a `recvonly` [`platform::Transceiver`] of the provided
+    /// [`MediaKind`], so media can be received before its sender exists.
+    ///
+    /// The pre-allocated [`platform::Transceiver`] is bound to the next
+    /// [`Receiver`] of the same [`MediaKind`] created by the media server,
+    /// instead of adding a new one for it. Resolves with the [`TrackId`] of
+    /// that [`Receiver`], or [`None`] if this [`PeerConnection`] is dropped
+    /// before that.
+    ///
+    /// [`Receiver`]: media::Receiver
+    pub async fn add_recv_transceiver(
+        &self,
+        kind: MediaKind,
+    ) -> Option<TrackId> {
+        let bound = self.media_connections.add_recv_slot(kind).await;
+        bound.await.ok()
+    }
+
+    /// Returns the [`IceRestartStats`] of this [`PeerConnection`].
+    #[must_use]
+    pub fn ice_restart_stats(&self) -> IceRestartStats {
+        self.ice_restart_stats.get()
+    }
+
+    /// Returns the retained [`StatsSnapshot`]s of this [`PeerConnection`],
+    /// ordered from the oldest to the newest.
+    ///
+    /// Always empty unless enabled via [`State::set_stats_history_capacity()`].
+    #[must_use]
+    pub fn stats_history(&self) -> Vec<StatsSnapshot> {
+        self.stats_history.borrow().snapshots()
+    }
+
+    /// Indicates whether all [`TransceiverSide`]s with the provided
+    /// [`MediaKind`], [`TrackDirection`] and [`MediaSourceKind`] are in the
+    /// provided [`MediaState`].
+    #[must_use]
+    pub fn is_all_transceiver_sides_in_media_state(
+        &self,
+        kind: MediaKind,
+        direction: TrackDirection,
+        source_kind: Option<MediaSourceKind>,
+        state: MediaState,
+    ) -> bool {
+        self.media_connections.is_all_tracks_in_media_state(
+            kind,
+            direction,
+            source_kind,
+            state,
+        )
+    }
+
+    /// Returns the [`PeerId`] of this [`PeerConnection`].
+    pub const fn id(&self) -> PeerId {
+        self.id
+    }
+
+    /// Handle `icecandidate` event from the underlying peer emitting
+    /// [`PeerEvent::IceCandidateDiscovered`] event into this peer's
+    /// `peer_events_sender`.
+    ///
+    /// Candidates not passing the provided [`IceCandidateFilter`] are dropped,
+    /// as well as host candidates with [mDNS] addresses if `drop_mdns` is
+    /// `true`.
+    ///
+    /// [mDNS]: https://tools.ietf.org/html/draft-ietf-mmusic-mdns-ice-candidates
+    fn on_ice_candidate(
+        id: Id,
+        sender: &PeerEventSender,
+        filter: Option<&IceCandidateFilter>,
+        drop_mdns: bool,
+        candidate: platform::IceCandidate,
+    ) {
+        if drop_mdns
+            && ice_candidate_filter::is_mdns_host_candidate(
+                &candidate.candidate,
+            )
+        {
+            logging::emit(
+                log::Level::Debug,
+                Some(id),
+                LogCategory::Ice,
+                format_args!(
+                    "mDNS ICE candidate dropped: {}",
+                    candidate.candidate,
+                ),
+            );
+            return;
+        }
+        if filter.is_some_and(|f| !f.allows(&candidate.candidate)) {
+            logging::emit(
+                log::Level::Debug,
+                Some(id),
+                LogCategory::Ice,
+                format_args!(
+                    "ICE candidate filtered out: {}",
+                    candidate.candidate,
+                ),
+            );
+            return;
+        }
+        send_peer_event(
+            sender,
+            PeerEvent::IceCandidateDiscovered {
+                peer_id: id,
+                candidate: candidate.candidate,
+                sdp_m_line_index: candidate.sdp_m_line_index,
+                sdp_mid: candidate.sdp_mid,
+            },
+        );
+    }
+
+    /// Handle `icecandidateerror` event from the underlying peer emitting
+    /// [`PeerEvent::IceCandidateError`] event into this peer's
+    /// `peer_events_sender`.
+    fn on_ice_candidate_error(
+        id: Id,
+        sender: &PeerEventSender,
+        error: platform::IceCandidateError,
+    ) {
+        send_peer_event(
+            sender,
+            PeerEvent::IceCandidateError {
+                peer_id: id,
+                address: error.address,
+                port: error.port,
+                url: error.url,
+                error_code: error.error_code,
+                error_text: error.error_text,
+            },
+        );
+    }
+
+    /// Handle `iceconnectionstatechange` event from the underlying peer
     /// emitting [`PeerEvent::IceConnectionStateChanged`] event into this peer's
     /// `peer_events_sender`.
+    ///
+    /// Records the transition time into the provided
+    /// [`IceTransitionTimestamps`].
     fn on_ice_connection_state_changed(
         peer_id: Id,
-        sender: &mpsc::UnboundedSender<PeerEvent>,
+        sender: &PeerEventSender,
+        transitions: &Cell<IceTransitionTimestamps>,
         ice_connection_state: IceConnectionState,
     ) {
-        drop(sender.unbounded_send(PeerEvent::IceConnectionStateChanged {
-            peer_id,
-            ice_connection_state,
-        }));
+        let mut timestamps = transitions.get();
+        timestamps.record(ice_connection_state, platform::monotonic_now());
+        transitions.set(timestamps);
+
+        send_peer_event(
+            sender,
+            PeerEvent::IceConnectionStateChanged {
+                peer_id,
+                ice_connection_state,
+            },
+        );
     }
 
     /// Handles `connectionstatechange` event from the underlying peer emitting
@@ -658,13 +1528,16 @@ impl PeerConnection {
     /// `peer_events_sender`.
     fn on_connection_state_changed(
         peer_id: Id,
-        sender: &mpsc::UnboundedSender<PeerEvent>,
+        sender: &PeerEventSender,
         peer_connection_state: PeerConnectionState,
     ) {
-        drop(sender.unbounded_send(PeerEvent::PeerConnectionStateChanged {
-            peer_id,
-            peer_connection_state,
-        }));
+        send_peer_event(
+            sender,
+            PeerEvent::PeerConnectionStateChanged {
+                peer_id,
+                peer_connection_state,
+            },
+        );
     }
 
     /// Sends [`PeerConnection`]'s connection state and ICE connection state to
@@ -673,6 +1546,7 @@ impl PeerConnection {
         Self::on_ice_connection_state_changed(
             self.id,
             &self.peer_events_sender,
+            &self.ice_transitions,
             self.peer.ice_connection_state(),
         );
 
@@ -689,6 +1563,10 @@ impl PeerConnection {
     /// configured to trigger ICE restart.
     fn restart_ice(&self) {
         self.peer.restart_ice();
+
+        let mut stats = self.ice_restart_stats.get();
+        stats.record(platform::now());
+        self.ice_restart_stats.set(stats);
     }
 
     /// Returns all [`TransceiverSide`]s from this [`PeerConnection`] with
@@ -771,9 +1649,17 @@ impl PeerConnection {
     /// [`local::Track`]s cannot be inserted into [`PeerConnection`]s
     /// [`Sender`]s.
     ///
+    /// With an [`UpdateLocalStreamError::Cancelled`] if the [`local::Track`]s
+    /// acquisition has been cancelled via
+    /// [`PeerConnection::cancel_local_stream_update()`].
+    ///
     /// [`Sender`]: sender::Sender
     /// [1]: https://w3.org/TR/mediacapture-streams#mediastream
     /// [2]: https://w3.org/TR/webrtc#rtcpeerconnection-interface
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(peer_id = self.id.0)),
+    )]
     pub async fn update_local_stream(
         &self,
         criteria: LocalStreamUpdateCriteria,
@@ -782,14 +1668,225 @@ impl PeerConnection {
         Traced<UpdateLocalStreamError>,
     > {
         self.inner_update_local_stream(criteria).await.inspect_err(|e| {
-            drop(self.peer_events_sender.unbounded_send(
+            if matches!(e.as_ref(), UpdateLocalStreamError::Cancelled) {
+                return;
+            }
+            send_peer_event(
+                &self.peer_events_sender,
                 PeerEvent::FailedLocalMedia {
                     error: tracerr::map_from(e.clone()),
                 },
-            ));
+            );
         })
     }
 
+    /// Cancels the [`local::Track`]s acquisition performed by the latest
+    /// in-flight [`PeerConnection::update_local_stream()`], if any.
+    ///
+    /// The cancelled [`PeerConnection::update_local_stream()`] resolves with
+    /// an [`UpdateLocalStreamError::Cancelled`] without inserting the acquired
+    /// [`local::Track`]s, which are stopped unless used somewhere else. The
+    /// pending [getUserMedia()][1] request itself cannot be aborted, as the
+    /// platforms don't support it.
+    ///
+    /// No-op if the [`local::Track`]s have been acquired and are being
+    /// inserted already.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediadevices-getusermedia
+    pub fn cancel_local_stream_update(&self) {
+        if let Some(abort) = self.local_stream_update_abort.take() {
+            abort.abort();
+        }
+    }
+
+    /// Switches the video [`Sender`] with the provided [`TrackId`] to the media
+    /// device with the provided `device_id`, without renegotiation.
+    ///
+    /// Acquires a new [`local::Track`] from the media device and inserts it
+    /// into the [`Sender`] via [RTCRtpSender.replaceTrack()][1]. The previous
+    /// [`local::Track`] of the [`Sender`] is stopped, unless it's used
+    /// somewhere else.
+    ///
+    /// The new [`local::Track`] is checked and its resolution is clamped the
+    /// same way as on a [`PeerConnection::update_local_stream()`].
+    ///
+    /// If the [`local::Track`] cannot be inserted (e.g. due to a codec
+    /// mismatch), then the [`Sender`] is left without a [`local::Track`], and
+    /// a renegotiation is requested via [`PeerEvent::RenegotiationNeeded`]
+    /// instead, during which a new [`local::Track`] will be acquired from
+    /// scratch.
+    ///
+    /// Note, that the [`LocalTracksConstraints`] are not changed, so the next
+    /// [`PeerConnection::update_local_stream()`] will acquire a
+    /// [`local::Track`] according to them.
+    ///
+    /// # Errors
+    ///
+    /// With a [`SwitchDeviceError::NoSuchSender`] if there is no [`Sender`]
+    /// with the provided [`TrackId`].
+    ///
+    /// With a [`SwitchDeviceError::WrongSenderKind`] if the [`Sender`] with
+    /// the provided [`TrackId`] doesn't send a video from a media device.
+    ///
+    /// With a [`SwitchDeviceError::CouldNotGetLocalMedia`] if a
+    /// [`local::Track`] cannot be acquired from the media device.
+    ///
+    /// [`Sender`]: sender::Sender
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpsender-replacetrack
+    pub async fn switch_video_device(
+        &self,
+        track_id: TrackId,
+        device_id: String,
+    ) -> Result<(), Traced<SwitchDeviceError>> {
+        let sender =
+            self.media_connections.get_sender_by_id(track_id).ok_or_else(
+                || tracerr::new!(SwitchDeviceError::NoSuchSender(track_id)),
+            )?;
+        let TrackConstraints::Video(VideoSource::Device(mut caps)) =
+            sender.caps().clone()
+        else {
+            return Err(tracerr::new!(SwitchDeviceError::WrongSenderKind(
+                track_id
+            )));
+        };
+        caps.device_id(device_id);
+        let mut settings = MediaStreamSettings::new();
+        settings.device_video(caps);
+
+        self.switch_device(track_id, settings).await
+    }
+
+    /// Switches the audio [`Sender`] with the provided [`TrackId`] to the media
+    /// device with the provided `device_id`, without renegotiation.
+    ///
+    /// Works the same way as [`PeerConnection::switch_video_device()`] does.
+    ///
+    /// Mute state of the [`Sender`] is preserved: if the [`Sender`] is muted,
+    /// then the new [`local::Track`] is inserted muted as well.
+    ///
+    /// # Errors
+    ///
+    /// With a [`SwitchDeviceError::NoSuchSender`] if there is no [`Sender`]
+    /// with the provided [`TrackId`].
+    ///
+    /// With a [`SwitchDeviceError::WrongSenderKind`] if the [`Sender`] with
+    /// the provided [`TrackId`] doesn't send an audio.
+    ///
+    /// With a [`SwitchDeviceError::CouldNotGetLocalMedia`] if a
+    /// [`local::Track`] cannot be acquired from the media device.
+    ///
+    /// [`Sender`]: sender::Sender
+    pub async fn switch_audio_device(
+        &self,
+        track_id: TrackId,
+        device_id: String,
+    ) -> Result<(), Traced<SwitchDeviceError>> {
+        let sender =
+            self.media_connections.get_sender_by_id(track_id).ok_or_else(
+                || tracerr::new!(SwitchDeviceError::NoSuchSender(track_id)),
+            )?;
+        let TrackConstraints::Audio(mut caps) = sender.caps().clone() else {
+            return Err(tracerr::new!(SwitchDeviceError::WrongSenderKind(
+                track_id
+            )));
+        };
+        caps.device_id(device_id);
+        let mut settings = MediaStreamSettings::new();
+        settings.audio(caps);
+
+        self.switch_device(track_id, settings).await
+    }
+
+    /// Schedules the provided DTMF `tones` to be sent by the audio [`Sender`]
+    /// with the provided [`TrackId`].
+    ///
+    /// See [`Sender::insert_dtmf()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// With an [`InsertDtmfError::NoSuchSender`] if there is no [`Sender`]
+    /// with the provided [`TrackId`].
+    ///
+    /// Otherwise, errors the same way as [`Sender::insert_dtmf()`] does.
+    ///
+    /// [`InsertDtmfError::NoSuchSender`]: sender::InsertDtmfError::NoSuchSender
+    /// [`Sender`]: sender::Sender
+    /// [`Sender::insert_dtmf()`]: sender::Sender::insert_dtmf
+    pub fn insert_dtmf(
+        &self,
+        track_id: TrackId,
+        tones: &str,
+        duration: Duration,
+        inter_tone_gap: Duration,
+    ) -> Result<(), Traced<sender::InsertDtmfError>> {
+        self.media_connections
+            .get_sender_by_id(track_id)
+            .ok_or_else(|| {
+                tracerr::new!(sender::InsertDtmfError::NoSuchSender(track_id))
+            })?
+            .insert_dtmf(tones, duration, inter_tone_gap)
+            .map_err(tracerr::wrap!())
+    }
+
+    /// Acquires a new [`local::Track`] with the provided
+    /// [`MediaStreamSettings`] and inserts it into the [`Sender`] with the
+    /// provided [`TrackId`], falling back to a renegotiation if it cannot be
+    /// inserted.
+    ///
+    /// # Errors
+    ///
+    /// With a [`SwitchDeviceError::CouldNotGetLocalMedia`] if a
+    /// [`local::Track`] cannot be acquired.
+    ///
+    /// [`Sender`]: sender::Sender
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(peer_id = self.id.0)),
+    )]
+    async fn switch_device(
+        &self,
+        track_id: TrackId,
+        settings: MediaStreamSettings,
+    ) -> Result<(), Traced<SwitchDeviceError>> {
+        let Some((track, _)) = self
+            .media_manager
+            .get_tracks(settings)
+            .await
+            .map_err(tracerr::map_from_and_wrap!())?
+            .into_iter()
+            .next()
+        else {
+            return Ok(());
+        };
+
+        if let Err(e) = self
+            .media_connections
+            .insert_local_track(track_id, Some(track))
+            .await
+        {
+            logging::emit(
+                log::Level::Warn,
+                Some(self.id),
+                LogCategory::Negotiation,
+                format_args!(
+                    "Failed to insert track into `Sender` `{track_id}`, \
+                     falling back to renegotiation: {e}",
+                ),
+            );
+            if let Some(sender) =
+                self.media_connections.get_sender_by_id(track_id)
+            {
+                sender.remove_track().await;
+            }
+            send_peer_event(
+                &self.peer_events_sender,
+                PeerEvent::RenegotiationNeeded { peer_id: self.id },
+            );
+        }
+
+        Ok(())
+    }
+
     /// Returns [`MediaStreamSettings`] for the provided [`MediaKind`] and
     /// [`MediaSourceKind`].
     ///
@@ -818,6 +1915,28 @@ impl PeerConnection {
             .map(|opt| opt.map(|s| MediaStreamSettings::from(&s)))
     }
 
+    /// Validates that constraints of the [`Sender`]s matching the provided
+    /// [`LocalStreamUpdateCriteria`], merged with the
+    /// [`LocalTracksConstraints`], can be represented as a
+    /// [`SimpleTracksRequest`], without acquiring any [`local::Track`]s.
+    ///
+    /// Allows to detect configuration errors before
+    /// [`PeerConnection::update_local_stream()`] prompts a user for
+    /// permissions.
+    ///
+    /// # Errors
+    ///
+    /// Errors with a [`TracksRequestError`] if failed to create or merge
+    /// [`SimpleTracksRequest`].
+    ///
+    /// [`Sender`]: sender::Sender
+    pub fn validate_tracks_request(
+        &self,
+        criteria: LocalStreamUpdateCriteria,
+    ) -> Result<(), Traced<TracksRequestError>> {
+        self.get_simple_tracks_request(criteria).map(drop)
+    }
+
     /// Returns [`SimpleTracksRequest`] for the provided
     /// [`LocalStreamUpdateCriteria`].
     ///
@@ -838,6 +1957,9 @@ impl PeerConnection {
         required_caps
             .merge(self.send_constraints.inner())
             .map_err(tracerr::map_from_and_wrap!())?;
+        if self.stereo_audio.get() {
+            required_caps.request_stereo_audio();
+        }
 
         Ok(Some(required_caps))
     }
@@ -856,17 +1978,49 @@ impl PeerConnection {
         {
             let used_caps = MediaStreamSettings::from(&required_caps);
 
-            let media_tracks = self
-                .media_manager
-                .get_tracks(used_caps)
-                .await
-                .map_err(tracerr::map_from_and_wrap!())?;
-            let peer_tracks = required_caps
-                .parse_tracks(
-                    media_tracks.iter().map(|(t, _)| t).cloned().collect(),
-                )
-                .await
-                .map_err(tracerr::map_from_and_wrap!())?;
+            let (abort, abort_reg) = AbortHandle::new_pair();
+            let abort = Rc::new(abort);
+            drop(
+                self.local_stream_update_abort.replace(Some(Rc::clone(&abort))),
+            );
+            let acquired = Abortable::new(
+                async {
+                    let media_tracks = self
+                        .media_manager
+                        .get_tracks(used_caps)
+                        .await
+                        .map_err(tracerr::map_from_and_wrap!())?;
+                    let peer_tracks = required_caps
+                        .parse_tracks(
+                            media_tracks
+                                .iter()
+                                .map(|(t, _)| t)
+                                .cloned()
+                                .collect(),
+                        )
+                        .await
+                        .map_err(tracerr::map_from_and_wrap!())?;
+                    Ok::<_, Traced<UpdateLocalStreamError>>((
+                        media_tracks,
+                        peer_tracks,
+                    ))
+                },
+                abort_reg,
+            )
+            .await;
+            // Newer call may have replaced the `AbortHandle` already.
+            let is_latest = self
+                .local_stream_update_abort
+                .borrow()
+                .as_ref()
+                .is_some_and(|a| Rc::ptr_eq(a, &abort));
+            if is_latest {
+                drop(self.local_stream_update_abort.take());
+            }
+            let Ok(acquired) = acquired else {
+                return Err(tracerr::new!(UpdateLocalStreamError::Cancelled));
+            };
+            let (media_tracks, peer_tracks) = acquired?;
 
             let media_exchange_states_updates = self
                 .media_connections
@@ -876,9 +2030,10 @@ impl PeerConnection {
 
             for (local_track, is_new) in media_tracks {
                 if is_new {
-                    drop(self.peer_events_sender.unbounded_send(
+                    send_peer_event(
+                        &self.peer_events_sender,
                         PeerEvent::NewLocalTrack { local_track },
-                    ));
+                    );
                 }
             }
 
@@ -942,7 +2097,9 @@ impl PeerConnection {
     /// # Errors
     ///
     /// With [`platform::RtcPeerConnectionError::SetRemoteDescriptionFailed`] if
-    /// [RTCPeerConnection.setRemoteDescription()][2] fails.
+    /// [RTCPeerConnection.setRemoteDescription()][2] fails. The underlying
+    /// [RTCPeerConnection][1] is rolled back to the `stable` signaling state in
+    /// such case, so it remains usable for the subsequent negotiations.
     ///
     /// With [`platform::RtcPeerConnectionError::AddIceCandidateFailed`] if
     /// [RtcPeerConnection.addIceCandidate()][3] fails when adding buffered ICE
@@ -951,22 +2108,49 @@ impl PeerConnection {
     /// [1]: https://w3.org/TR/webrtc#rtcpeerconnection-interface
     /// [2]: https://w3.org/TR/webrtc#dom-peerconnection-setremotedescription
     /// [3]: https://w3.org/TR/webrtc#dom-peerconnection-addicecandidate
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(peer_id = self.id.0)),
+    )]
     async fn set_remote_description(
         &self,
         desc: platform::SdpType,
     ) -> Result<(), Traced<RtcPeerConnectionError>> {
-        self.peer
-            .set_remote_description(desc)
-            .await
-            .map_err(tracerr::map_from_and_wrap!())?;
+        if let Err(e) = self.peer.set_remote_description(desc).await {
+            self.rollback_signaling_state().await;
+            // Buffered candidates belong to the rejected description, so are
+            // discarded to not block `PeerConnection::await_ice_flush()`.
+            let discarded = self.ice_candidates_buffer.take();
+            self.pending_ice_candidates
+                .mutate(|mut c| *c = c.saturating_sub(discarded.len()));
+            return Err(tracerr::map_from_and_wrap!()(e));
+        }
         self.has_remote_description.set(true);
         self.media_connections.sync_receivers().await;
 
+        self.flush_ice_candidates_buffer().await
+    }
+
+    /// Adds all the buffered [`platform::IceCandidate`]s to the underlying
+    /// [`platform::RtcPeerConnection`].
+    ///
+    /// # Errors
+    ///
+    /// With [`platform::RtcPeerConnectionError::AddIceCandidateFailed`] if
+    /// [RtcPeerConnection.addIceCandidate()][1] fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-peerconnection-addicecandidate
+    async fn flush_ice_candidates_buffer(
+        &self,
+    ) -> Result<(), Traced<RtcPeerConnectionError>> {
         let ice_candidates_buffer_flush_fut = future::try_join_all(
             self.ice_candidates_buffer.borrow_mut().drain(..).map(
                 |candidate| {
                     let peer = Rc::clone(&self.peer);
+                    let pending =
+                        PendingIceCandidate(&self.pending_ice_candidates);
                     async move {
+                        let _pending = pending;
                         peer.add_ice_candidate(
                             &candidate.candidate,
                             candidate.sdp_m_line_index,
@@ -977,16 +2161,54 @@ impl PeerConnection {
                 },
             ),
         );
+        #[cfg(feature = "tracing")]
+        let ice_candidates_buffer_flush_fut = tracing::Instrument::instrument(
+            ice_candidates_buffer_flush_fut,
+            tracing::debug_span!("ice_candidates_buffer_flush"),
+        );
         ice_candidates_buffer_flush_fut
             .await
             .map(drop)
-            .map_err(tracerr::map_from_and_wrap!())?;
+            .map_err(tracerr::map_from_and_wrap!())
+    }
 
-        Ok(())
+    /// Rollbacks the underlying [RTCPeerConnection][1] to the
+    /// [`stable` signaling state][2], if it's not there already.
+    ///
+    /// Failure of the rollback is only logged, since it's performed while
+    /// handling another error.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#rtcpeerconnection-interface
+    /// [2]: https://w3.org/TR/webrtc#dom-rtcsignalingstate-stable
+    async fn rollback_signaling_state(&self) {
+        if self.peer.signaling_state() == platform::SignalingState::Stable {
+            return;
+        }
+        if let Err(e) = self.peer.rollback().await {
+            logging::emit(
+                log::Level::Warn,
+                Some(self.id),
+                LogCategory::Negotiation,
+                format_args!("Failed to rollback signaling state: {e}"),
+            );
+        }
+    }
+
+    /// Returns the current [`platform::SignalingState`] of the underlying
+    /// [RTCPeerConnection][1].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#rtcpeerconnection-interface
+    #[must_use]
+    pub fn signaling_state(&self) -> platform::SignalingState {
+        self.peer.signaling_state()
     }
 
     /// Adds remote peers [ICE Candidate][1] to this peer.
     ///
+    /// An empty `candidate` is not a malformed one, but signals the
+    /// [end-of-candidates][2], so is passed to the underlying
+    /// [`platform::RtcPeerConnection`] as such.
+    ///
     /// # Errors
     ///
     /// With [`RtcPeerConnectionError::AddIceCandidateFailed`] if
@@ -994,14 +2216,21 @@ impl PeerConnection {
     /// [ICE candidates][1].
     ///
     /// [1]: https://tools.ietf.org/html/rfc5245#section-2
+    /// [2]: https://w3.org/TR/webrtc#dfn-end-of-candidates
     /// [3]: https://w3.org/TR/webrtc#dom-peerconnection-addicecandidate
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(peer_id = self.id.0)),
+    )]
     pub async fn add_ice_candidate(
         &self,
         candidate: String,
         sdp_m_line_index: Option<u16>,
         sdp_mid: Option<String>,
     ) -> Result<(), Traced<RtcPeerConnectionError>> {
+        self.pending_ice_candidates.mutate(|mut c| *c += 1);
         if self.has_remote_description.get() {
+            let _pending = PendingIceCandidate(&self.pending_ice_candidates);
             self.peer
                 .add_ice_candidate(&candidate, sdp_m_line_index, &sdp_mid)
                 .await
@@ -1014,11 +2243,214 @@ impl PeerConnection {
         Ok(())
     }
 
+    /// Adds the provided remote peers [ICE Candidate][1]s to this peer
+    /// concurrently.
+    ///
+    /// Each candidate is added the same way as via
+    /// [`PeerConnection::add_ice_candidate()`], so the ones arriving before a
+    /// remote description is set are buffered.
+    ///
+    /// # Errors
+    ///
+    /// With all the [`platform::IceCandidate`]s failed to be added, along with
+    /// their [`RtcPeerConnectionError`]s.
+    ///
+    /// [1]: https://tools.ietf.org/html/rfc5245#section-2
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(peer_id = self.id.0)),
+    )]
+    pub async fn add_ice_candidates(
+        &self,
+        candidates: Vec<(String, Option<u16>, Option<String>)>,
+    ) -> Result<(), Vec<FailedIceCandidate>> {
+        let candidates: Vec<_> = candidates
+            .into_iter()
+            .map(|(candidate, sdp_m_line_index, sdp_mid)| {
+                platform::IceCandidate { candidate, sdp_m_line_index, sdp_mid }
+            })
+            .collect();
+
+        let results = future::join_all(candidates.iter().cloned().map(|c| {
+            self.add_ice_candidate(c.candidate, c.sdp_m_line_index, c.sdp_mid)
+        }))
+        .await;
+
+        let failed: Vec<_> = candidates
+            .into_iter()
+            .zip(results)
+            .filter_map(|(c, res)| res.err().map(|e| (c, e)))
+            .collect();
+        if failed.is_empty() { Ok(()) } else { Err(failed) }
+    }
+
     /// Removes a [`sender::Component`] and a [`receiver::Component`] with the
     /// provided [`TrackId`] from this [`PeerConnection`].
     pub fn remove_track(&self, track_id: TrackId) {
         self.media_connections.remove_track(track_id);
     }
+
+    /// Creates a new [`DataChannel`] with the provided `label` on this
+    /// [`PeerConnection`].
+    ///
+    /// # Errors
+    ///
+    /// With [`platform::DataChannelError::Unsupported`] if the current
+    /// platform doesn't support data channels (which is the case for all the
+    /// native platforms at the moment).
+    pub fn create_data_channel(
+        &self,
+        label: &str,
+        options: platform::DataChannelInit,
+    ) -> Result<DataChannel, Traced<platform::DataChannelError>> {
+        self.peer.create_data_channel(label, options).map(DataChannel::new)
+    }
+
+    /// Returns all the open [`DataChannel`]s created by the remote peer on
+    /// this [`PeerConnection`].
+    ///
+    /// Closed [`DataChannel`]s are removed automatically.
+    #[must_use]
+    pub fn remote_data_channels(&self) -> Vec<DataChannel> {
+        self.remote_data_channels.borrow().clone()
+    }
+
+    /// Returns a [`LocalBoxStream`] of all the [`DataChannel`]s created by the
+    /// remote peer on this [`PeerConnection`] after this call.
+    #[must_use]
+    pub fn on_data_channel(&self) -> LocalBoxStream<'static, DataChannel> {
+        let (tx, rx) = mpsc::unbounded();
+        self.on_data_channel_subs.borrow_mut().push(tx);
+
+        Box::pin(rx)
+    }
+
+    /// Indicates whether an SDP offer/answer exchange is in progress on this
+    /// [`PeerConnection`], meaning that its [`platform::SignalingState`] is
+    /// not [`platform::SignalingState::Stable`].
+    #[must_use]
+    pub fn is_negotiating(&self) -> bool {
+        self.peer.signaling_state() != platform::SignalingState::Stable
+    }
+
+    /// Returns the current local SDP of this [`PeerConnection`], if any.
+    #[must_use]
+    pub fn local_description(&self) -> Option<String> {
+        self.peer.local_description()
+    }
+
+    /// Returns the current remote SDP of this [`PeerConnection`], if any.
+    #[must_use]
+    pub fn remote_description(&self) -> Option<String> {
+        self.peer.remote_description()
+    }
+
+    /// Returns the negotiated [currentDirection][1] of the
+    /// [`platform::Transceiver`] of each track of this [`PeerConnection`].
+    ///
+    /// [`None`] means that the [`platform::Transceiver`] hasn't been
+    /// negotiated yet (or the platform doesn't expose its
+    /// [currentDirection][1]).
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtptransceiver-currentdirection
+    #[must_use]
+    pub fn transceiver_directions(
+        &self,
+    ) -> HashMap<TrackId, Option<platform::TransceiverDirection>> {
+        self.media_connections
+            .transceivers()
+            .into_iter()
+            .map(|(track_id, t)| (track_id, t.current_direction()))
+            .collect()
+    }
+
+    /// Returns [`TrackStatus`] of the [`Sender`] or [`Receiver`] with the
+    /// provided [`TrackId`] in a single call.
+    ///
+    /// Returns [`None`] if there is no such track in this [`PeerConnection`].
+    ///
+    /// [`Receiver`]: receiver::Receiver
+    /// [`Sender`]: sender::Sender
+    pub async fn track_status(&self, track_id: TrackId) -> Option<TrackStatus> {
+        self.media_connections.track_status(track_id).await
+    }
+
+    /// Emits a [`PeerEvent::TransceiverDirectionChanged`] for every
+    /// [`platform::Transceiver`] whose negotiated
+    /// [`platform::TransceiverDirection`] differs from the last observed one.
+    fn check_transceiver_directions(&self) {
+        let mut last = self.transceiver_directions.borrow_mut();
+        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+        for (track_id, direction) in self.transceiver_directions() {
+            let Some(direction) = direction else {
+                continue;
+            };
+            if last.insert(track_id, direction) != Some(direction) {
+                send_peer_event(
+                    &self.peer_events_sender,
+                    PeerEvent::TransceiverDirectionChanged {
+                        peer_id: self.id,
+                        track_id,
+                        direction,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Returns `mid`s of the [`platform::Transceiver`]s of all the
+    /// [`Receiver`]s having one.
+    ///
+    /// [`Receiver`]: receiver::Receiver
+    #[must_use]
+    pub fn receivers_mids(&self) -> Vec<(TrackId, String)> {
+        self.media_connections
+            .mids_by_direction()
+            .into_iter()
+            .filter_map(|(track_id, direction, mid)| {
+                (direction == TrackDirection::Recv).then_some((track_id, mid))
+            })
+            .collect()
+    }
+
+    /// Returns the negotiated codecs in use per each [`TrackId`], parsed from
+    /// the current local and remote SDP of this [`PeerConnection`].
+    ///
+    /// Codecs of the [`Sender`]s are taken from the remote SDP (as the remote
+    /// side decides what it receives), while codecs of the [`Receiver`]s are
+    /// taken from the local one. Tracks without a negotiated codec are
+    /// omitted.
+    ///
+    /// [`Receiver`]: receiver::Receiver
+    /// [`Sender`]: sender::Sender
+    #[must_use]
+    pub fn negotiated_codecs(&self) -> HashMap<TrackId, sdp::RtpMap> {
+        let parse =
+            |sdp: Option<String>| sdp?.parse::<sdp::SessionDescription>().ok();
+        let (Some(local), Some(remote)) = (
+            parse(self.peer.local_description()),
+            parse(self.peer.remote_description()),
+        ) else {
+            return HashMap::new();
+        };
+
+        self.media_connections
+            .mids_by_direction()
+            .into_iter()
+            .filter_map(|(track_id, direction, mid)| {
+                let description = match direction {
+                    TrackDirection::Send => &remote,
+                    TrackDirection::Recv => &local,
+                };
+                let codec = description
+                    .media
+                    .iter()
+                    .find(|m| m.mid() == Some(mid.as_str()))?
+                    .primary_codec()?;
+                Some((track_id, codec))
+            })
+            .collect()
+    }
 }
 
 #[cfg(feature = "mockable")]
@@ -1026,6 +2458,60 @@ impl PeerConnection {
 #[expect(clippy::allow_attributes, reason = "`#[expect]` is not considered")]
 #[allow(clippy::multiple_inherent_impl, reason = "feature gated")]
 impl PeerConnection {
+    /// Returns a new [`mpsc::UnboundedReceiver`] of all the [`PeerEvent`]s
+    /// emitted by this [`PeerConnection`] after this call, in the order they
+    /// are emitted.
+    ///
+    /// [`PeerEvent`]s are still delivered to the `peer_events_sender`
+    /// provided to the [`PeerConnection::new()`].
+    #[must_use]
+    pub fn subscribe_events(&self) -> mpsc::UnboundedReceiver<PeerEvent> {
+        let (tx, rx) = mpsc::unbounded();
+        self.peer_events_sender.subscribers.borrow_mut().push(tx);
+        rx
+    }
+
+    /// Pushes the provided synthetic [`IceConnectionState`] transition through
+    /// the same handler as the [iceconnectionstatechange][1] events of the
+    /// underlying [`platform::RtcPeerConnection`].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#event-iceconnectionstatechange
+    pub fn simulate_ice_connection_state(&self, state: IceConnectionState) {
+        Self::on_ice_connection_state_changed(
+            self.id,
+            &self.peer_events_sender,
+            &self.ice_transitions,
+            state,
+        );
+    }
+
+    /// Pushes the provided synthetic [`PeerConnectionState`] transition
+    /// through the same handler as the [connectionstatechange][1] events of
+    /// the underlying [`platform::RtcPeerConnection`].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#event-connectionstatechange
+    pub fn simulate_connection_state(&self, state: PeerConnectionState) {
+        Self::on_connection_state_changed(
+            self.id,
+            &self.peer_events_sender,
+            state,
+        );
+    }
+
+    /// Returns [`platform::BundlePolicy`] of the underlying
+    /// [`platform::RtcPeerConnection`].
+    #[must_use]
+    pub fn bundle_policy(&self) -> platform::BundlePolicy {
+        self.peer.bundle_policy()
+    }
+
+    /// Returns [`platform::RtcpMuxPolicy`] of the underlying
+    /// [`platform::RtcPeerConnection`].
+    #[must_use]
+    pub fn rtcp_mux_policy(&self) -> platform::RtcpMuxPolicy {
+        self.peer.rtcp_mux_policy()
+    }
+
     /// Returns [`RtcStats`] of this [`PeerConnection`].
     ///
     /// # Errors
@@ -1050,12 +2536,96 @@ impl PeerConnection {
         self.media_connections.is_recv_video_enabled()
     }
 
+    /// Returns a [`Future`] resolving once all the buffered
+    /// [`platform::IceCandidate`]s are flushed, and all the in-flight
+    /// [RTCPeerConnection.addIceCandidate()][1] calls are completed.
+    ///
+    /// Buffered [`platform::IceCandidate`]s are flushed only once a remote
+    /// description is set, so the returned [`Future`] doesn't resolve until
+    /// then, unless there is nothing buffered.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-peerconnection-addicecandidate
+    pub async fn await_ice_flush(&self) {
+        _ = self.pending_ice_candidates.when_eq(0).await;
+    }
+
     /// Returns inner [`IceCandidate`]'s buffer length. Used in tests.
     #[must_use]
     pub fn candidates_buffer_len(&self) -> usize {
         self.ice_candidates_buffer.borrow().len()
     }
 
+    /// Injects the provided synthetic remote [`platform::IceCandidate`] into
+    /// this [`PeerConnection`], the same way as the ones received from a
+    /// media server.
+    ///
+    /// The [`platform::IceCandidate`] is buffered unless this
+    /// [`PeerConnection`] has a remote description.
+    ///
+    /// # Errors
+    ///
+    /// See [`PeerConnection::add_ice_candidate()`] for details.
+    pub async fn inject_ice_candidate(
+        &self,
+        candidate: platform::IceCandidate,
+    ) -> Result<(), Traced<RtcPeerConnectionError>> {
+        let platform::IceCandidate { candidate, sdp_m_line_index, sdp_mid } =
+            candidate;
+        self.add_ice_candidate(candidate, sdp_m_line_index, sdp_mid).await
+    }
+
+    /// Returns all the currently buffered [`platform::IceCandidate`]s in the
+    /// order they have been received.
+    #[must_use]
+    pub fn buffered_ice_candidates(&self) -> Vec<platform::IceCandidate> {
+        self.ice_candidates_buffer.borrow().clone()
+    }
+
+    /// Removes all the buffered [`platform::IceCandidate`]s without adding
+    /// them to the underlying [`platform::RtcPeerConnection`].
+    pub fn take_buffered_ice_candidates(&self) -> Vec<platform::IceCandidate> {
+        let taken = self.ice_candidates_buffer.take();
+        self.pending_ice_candidates
+            .mutate(|mut c| *c = c.saturating_sub(taken.len()));
+        taken
+    }
+
+    /// Adds all the buffered [`platform::IceCandidate`]s to the underlying
+    /// [`platform::RtcPeerConnection`] regardless of whether it has a remote
+    /// description.
+    ///
+    /// # Errors
+    ///
+    /// With [`platform::RtcPeerConnectionError::AddIceCandidateFailed`] if
+    /// [RtcPeerConnection.addIceCandidate()][1] fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-peerconnection-addicecandidate
+    pub async fn flush_ice_candidates(
+        &self,
+    ) -> Result<(), Traced<RtcPeerConnectionError>> {
+        self.flush_ice_candidates_buffer().await
+    }
+
+    /// Indicates whether this [`PeerConnection`] considers having a remote
+    /// description, so doesn't buffer [`platform::IceCandidate`]s anymore.
+    #[must_use]
+    pub fn has_remote_description(&self) -> bool {
+        self.has_remote_description.get()
+    }
+
+    /// Simulates the remote description being set on (or removed from) this
+    /// [`PeerConnection`], without touching the underlying
+    /// [`platform::RtcPeerConnection`].
+    ///
+    /// The buffered [`platform::IceCandidate`]s are left as is, so should be
+    /// either [flushed][1] or [taken][2] explicitly.
+    ///
+    /// [1]: PeerConnection::flush_ice_candidates
+    /// [2]: PeerConnection::take_buffered_ice_candidates
+    pub fn set_has_remote_description(&self, has: bool) {
+        self.has_remote_description.set(has);
+    }
+
     /// Lookups [`Sender`] by provided [`TrackId`].
     #[must_use]
     pub fn get_sender_by_id(&self, id: TrackId) -> Option<Rc<media::Sender>> {
@@ -1122,9 +2692,20 @@ impl PeerConnection {
     }
 }
 
+/// Guard of a [`platform::IceCandidate`] being added to a
+/// [`platform::RtcPeerConnection`], decrementing the number of
+/// [`PeerConnection::pending_ice_candidates`] on [`Drop`].
+struct PendingIceCandidate<'a>(&'a ObservableCell<usize>);
+
+impl Drop for PendingIceCandidate<'_> {
+    fn drop(&mut self) {
+        self.0.mutate(|mut c| *c = c.saturating_sub(1));
+    }
+}
+
 impl Drop for PeerConnection {
-    /// Drops `on_track` and `on_ice_candidate` callbacks to prevent possible
-    /// leaks.
+    /// Drops `on_track`, `on_ice_candidate` and `on_signaling_state_change`
+    /// callbacks to prevent possible leaks.
     fn drop(&mut self) {
         self.peer.on_track::<Box<
             dyn FnMut(platform::MediaStreamTrack, platform::Transceiver),
@@ -1135,5 +2716,28 @@ impl Drop for PeerConnection {
             .on_ice_candidate_error::<Box<dyn FnMut(
                 platform::IceCandidateError
             )>>(None);
+        self.peer
+            .on_signaling_state_change::<Box<dyn FnMut(
+                platform::SignalingState,
+            )>>(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_dropped_peer_events() {
+        let (tx, rx) = mpsc::unbounded();
+        let tx = PeerEventSender::from(tx);
+        let dropped = dropped_peer_events();
+
+        send_peer_event(&tx, PeerEvent::RenegotiationNeeded { peer_id: Id(1) });
+        assert_eq!(dropped_peer_events(), dropped);
+
+        drop(rx);
+        send_peer_event(&tx, PeerEvent::RenegotiationNeeded { peer_id: Id(1) });
+        assert_eq!(dropped_peer_events(), dropped + 1);
     }
 }
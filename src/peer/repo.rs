@@ -1,9 +1,14 @@
 //! Component responsible for the [`peer::Component`] creating and removing.
 
-use std::{cell::RefCell, collections::HashMap, rc::Rc, time::Duration};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+    time::Duration,
+};
 
 use futures::{channel::mpsc, future};
-use medea_client_api_proto::{self as proto, PeerId};
+use medea_client_api_proto::{self as proto, IceServer, PeerId};
 use medea_macro::watchers;
 use medea_reactive::ObservableHashMap;
 use tracerr::Traced;
@@ -36,6 +41,55 @@ impl Component {
         self.peers.borrow().values().map(component::Component::obj).collect()
     }
 
+    /// Sets the [`peer::sender::ResolutionCeiling`] of all the video senders
+    /// of all the [`PeerConnection`]s, including the ones created later.
+    ///
+    /// [`None`] removes the ceiling.
+    pub async fn set_max_send_resolution(
+        &self,
+        ceiling: Option<peer::sender::ResolutionCeiling>,
+    ) {
+        self.obj().max_send_resolution.set(ceiling);
+        drop(
+            future::join_all(
+                self.get_all()
+                    .iter()
+                    .map(|p| p.set_max_send_resolution(ceiling)),
+            )
+            .await,
+        );
+    }
+
+    /// Enables loss-based resolution downgrade of all the video senders of all
+    /// the [`PeerConnection`]s, including the ones created later, with the
+    /// provided [`peer::sender::LossBasedResolutionConfig`].
+    ///
+    /// [`None`] disables it.
+    pub fn set_loss_based_resolution(
+        &self,
+        config: Option<peer::sender::LossBasedResolutionConfig>,
+    ) {
+        self.obj().loss_based_resolution.set(config);
+        for peer in self.get_all() {
+            peer.set_loss_based_resolution(config);
+        }
+    }
+
+    /// Enables adaptive bitrate of all the video senders of all the
+    /// [`PeerConnection`]s, including the ones created later, with the
+    /// provided [`peer::sender::AdaptiveBitrateConfig`].
+    ///
+    /// [`None`] disables it.
+    pub fn set_adaptive_bitrate(
+        &self,
+        config: Option<peer::sender::AdaptiveBitrateConfig>,
+    ) {
+        self.obj().adaptive_bitrate.set(config);
+        for peer in self.get_all() {
+            peer.set_adaptive_bitrate(config);
+        }
+    }
+
     /// Notifies all [`peer::Component`]s about a RPC connection loss.
     pub fn connection_lost(&self) {
         #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
@@ -119,6 +173,21 @@ pub struct Repository {
     ///
     /// [`remote::Track`]: crate::media::track::remote::Track
     recv_constraints: Rc<RecvConstraints>,
+
+    /// [`peer::sender::ResolutionCeiling`] of all the video senders of the
+    /// [`PeerConnection`]s from this [`Repository`], if any.
+    max_send_resolution: Cell<Option<peer::sender::ResolutionCeiling>>,
+
+    /// [`peer::sender::LossBasedResolutionConfig`] of all the video senders of
+    /// the [`PeerConnection`]s from this [`Repository`], if loss-based
+    /// resolution downgrade is enabled.
+    loss_based_resolution:
+        Cell<Option<peer::sender::LossBasedResolutionConfig>>,
+
+    /// [`peer::sender::AdaptiveBitrateConfig`] of all the video senders of the
+    /// [`PeerConnection`]s from this [`Repository`], if adaptive bitrate is
+    /// enabled.
+    adaptive_bitrate: Cell<Option<peer::sender::AdaptiveBitrateConfig>>,
 }
 
 impl Repository {
@@ -144,6 +213,9 @@ impl Repository {
             send_constraints,
             recv_constraints,
             connections,
+            max_send_resolution: Cell::new(None),
+            loss_based_resolution: Cell::new(None),
+            adaptive_bitrate: Cell::new(None),
         }
     }
 
@@ -201,6 +273,58 @@ impl State {
     pub fn remove(&self, peer_id: PeerId) {
         drop(self.0.borrow_mut().remove(&peer_id));
     }
+
+    /// Replaces [`IceServer`]s of all the [`peer::State`]s with the provided
+    /// ones.
+    ///
+    /// See [`peer::State::set_ice_servers()`] for details.
+    pub fn update_ice_servers(&self, ice_servers: &[IceServer]) {
+        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+        for peer in self.0.borrow().values() {
+            peer.set_ice_servers(ice_servers.to_vec());
+        }
+    }
+
+    /// Enables or disables sending [`platform::RtcStats`] to the media server
+    /// by all the [`peer::State`]s.
+    ///
+    /// See [`peer::State::set_stats_sending_enabled()`] for details.
+    pub fn set_stats_sending_enabled(&self, enabled: bool) {
+        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+        for peer in self.0.borrow().values() {
+            peer.set_stats_sending_enabled(enabled);
+        }
+    }
+
+    /// Enables or disables early media in all the [`peer::State`]s.
+    ///
+    /// See [`peer::State::set_early_media()`] for details.
+    pub fn set_early_media(&self, enabled: bool) {
+        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+        for peer in self.0.borrow().values() {
+            peer.set_early_media(enabled);
+        }
+    }
+
+    /// Requests Media Server to renegotiate all the [`peer::State`]s.
+    ///
+    /// See [`peer::State::renegotiate()`] for details.
+    pub fn renegotiate(&self) {
+        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+        for peer in self.0.borrow().values() {
+            peer.renegotiate();
+        }
+    }
+
+    /// Sets the disconnect grace period of all the [`peer::State`]s.
+    ///
+    /// See [`peer::State::set_disconnect_grace_period()`] for details.
+    pub fn set_disconnect_grace_period(&self, period: Duration) {
+        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+        for peer in self.0.borrow().values() {
+            peer.set_disconnect_grace_period(period);
+        }
+    }
 }
 
 impl AsProtoState for State {
@@ -242,6 +366,15 @@ impl Component {
             .map_err(tracerr::map_from_and_wrap!())?,
             new_peer,
         );
+        if let Some(ceiling) = peers.max_send_resolution.get() {
+            peer.set_max_send_resolution(Some(ceiling)).await;
+        }
+        if let Some(config) = peers.loss_based_resolution.get() {
+            peer.set_loss_based_resolution(Some(config));
+        }
+        if let Some(config) = peers.adaptive_bitrate.get() {
+            peer.set_adaptive_bitrate(Some(config));
+        }
 
         drop(peers.peers.borrow_mut().insert(peer_id, peer));
 
@@ -0,0 +1,828 @@
+//! Platform-agnostic [SDP] parsing, serializing and munging.
+//!
+//! [SDP]: https://tools.ietf.org/html/rfc8866
+
+use std::{fmt, str::FromStr};
+
+use derive_more::with_trait::Display;
+use tracerr::Traced;
+
+use crate::{platform, utils::Caused};
+
+/// Errors occurring when parsing a [`SessionDescription`].
+#[derive(Caused, Clone, Debug, Display, Eq, PartialEq)]
+#[cause(error = platform::Error)]
+pub enum SdpParseError {
+    /// [SDP] doesn't start with a [`v=` line][1].
+    ///
+    /// [SDP]: https://tools.ietf.org/html/rfc8866
+    /// [1]: https://tools.ietf.org/html/rfc8866#section-5.1
+    #[display("SDP doesn't start with a `v=` line")]
+    NoVersion,
+
+    /// Line isn't of a `<type>=<value>` form.
+    #[display("Malformed SDP line: {_0}")]
+    MalformedLine(String),
+
+    /// [`m=` line][1] doesn't have all the required fields.
+    ///
+    /// [1]: https://tools.ietf.org/html/rfc8866#section-5.14
+    #[display("Malformed SDP media line: {_0}")]
+    MalformedMediaLine(String),
+}
+
+/// Parsed [SDP] session description.
+///
+/// Lines are kept as is, so serializing an unmodified [`SessionDescription`]
+/// produces the original [SDP] (normalized to `CRLF` line endings).
+///
+/// [SDP]: https://tools.ietf.org/html/rfc8866
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionDescription {
+    /// Session-level lines, starting with the `v=` one.
+    pub session: Vec<String>,
+
+    /// [`MediaSection`]s in the order of their appearance.
+    pub media: Vec<MediaSection>,
+}
+
+impl SessionDescription {
+    /// Returns an [`Iterator`] over the [`MediaSection`]s of the provided
+    /// `kind` (`audio`, `video` or `application`).
+    pub fn media_of_kind<'a>(
+        &'a mut self,
+        kind: &'a str,
+    ) -> impl Iterator<Item = &'a mut MediaSection> {
+        self.media.iter_mut().filter(move |m| m.kind == kind)
+    }
+
+    /// Moves payload types of the provided `codecs` to the front of all the
+    /// [`MediaSection`]s of the provided `kind`, in the provided order.
+    ///
+    /// See [`MediaSection::prefer_codecs()`] for details.
+    pub fn prefer_codecs(&mut self, kind: &str, codecs: &[&str]) {
+        for section in self.media_of_kind(kind) {
+            section.prefer_codecs(codecs);
+        }
+    }
+
+    /// Removes all the [header extensions][1] whose URI matches the provided
+    /// `predicate`, both session-level and media-level ones.
+    ///
+    /// [1]: https://tools.ietf.org/html/rfc8285
+    pub fn remove_extensions<F>(&mut self, predicate: F)
+    where
+        F: Fn(&str) -> bool,
+    {
+        retain_extensions(&mut self.session, &predicate);
+        for section in &mut self.media {
+            retain_extensions(&mut section.lines, &predicate);
+        }
+    }
+}
+
+impl FromStr for SessionDescription {
+    type Err = Traced<SdpParseError>;
+
+    fn from_str(sdp: &str) -> Result<Self, Self::Err> {
+        use SdpParseError as E;
+
+        let mut session = Vec::new();
+        let mut media: Vec<MediaSection> = Vec::new();
+        for line in sdp.lines().filter(|l| !l.is_empty()) {
+            if line.as_bytes().get(1) != Some(&b'=') {
+                return Err(tracerr::new!(E::MalformedLine(line.into())));
+            }
+            if session.is_empty() && !line.starts_with("v=") {
+                return Err(tracerr::new!(E::NoVersion));
+            }
+
+            if let Some(m_line) = line.strip_prefix("m=") {
+                media.push(MediaSection::parse(m_line).ok_or_else(|| {
+                    tracerr::new!(E::MalformedMediaLine(line.into()))
+                })?);
+            } else if let Some(section) = media.last_mut() {
+                section.lines.push(line.into());
+            } else {
+                session.push(line.into());
+            }
+        }
+        if session.is_empty() {
+            return Err(tracerr::new!(E::NoVersion));
+        }
+
+        Ok(Self { session, media })
+    }
+}
+
+impl fmt::Display for SessionDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in &self.session {
+            write!(f, "{line}\r\n")?;
+        }
+        for section in &self.media {
+            write!(f, "{section}")?;
+        }
+        Ok(())
+    }
+}
+
+/// [Media description][1] of a [`SessionDescription`].
+///
+/// [1]: https://tools.ietf.org/html/rfc8866#section-5.14
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MediaSection {
+    /// Media type (`audio`, `video` or `application`).
+    pub kind: String,
+
+    /// Transport port (possibly with a number of ports).
+    pub port: String,
+
+    /// Transport protocol.
+    pub protocol: String,
+
+    /// Media formats (payload types for [RTP] media) in the order of
+    /// preference.
+    ///
+    /// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+    pub formats: Vec<String>,
+
+    /// Lines following the `m=` line of this [`MediaSection`].
+    pub lines: Vec<String>,
+}
+
+impl MediaSection {
+    /// Parses a [`MediaSection`] out of the provided `m=` line value.
+    fn parse(m_line: &str) -> Option<Self> {
+        let mut fields = m_line.split_whitespace();
+        Some(Self {
+            kind: fields.next()?.into(),
+            port: fields.next()?.into(),
+            protocol: fields.next()?.into(),
+            formats: fields.map(Into::into).collect(),
+            lines: Vec::new(),
+        })
+    }
+
+    /// Returns an [`Iterator`] over values of all the attributes with the
+    /// provided `name`.
+    ///
+    /// Property attributes (like `a=rtcp-mux`) have an empty value.
+    pub fn attributes<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a str> {
+        self.lines.iter().filter_map(move |line| {
+            let rest = line.strip_prefix("a=")?.strip_prefix(name)?;
+            if rest.is_empty() { Some(rest) } else { rest.strip_prefix(':') }
+        })
+    }
+
+    /// Indicates whether this [`MediaSection`] has an attribute with the
+    /// provided `name`.
+    #[must_use]
+    pub fn has_attribute(&self, name: &str) -> bool {
+        self.attributes(name).next().is_some()
+    }
+
+    /// Returns [mid] of this [`MediaSection`], if any.
+    ///
+    /// [mid]: https://tools.ietf.org/html/rfc5888#section-4
+    #[must_use]
+    pub fn mid(&self) -> Option<&str> {
+        self.attributes("mid").next()
+    }
+
+    /// Returns all the [`RtpMap`]s of this [`MediaSection`].
+    #[must_use]
+    pub fn rtp_maps(&self) -> Vec<RtpMap> {
+        self.attributes("rtpmap").filter_map(RtpMap::parse).collect()
+    }
+
+    /// Returns the [`RtpMap`] of the most preferred media codec of this
+    /// [`MediaSection`], skipping the auxiliary ones ([RTX], [RED], FEC,
+    /// comfort noise and DTMF).
+    ///
+    /// Being called on a negotiated [`MediaSection`], returns the codec in
+    /// use. [`None`] if this [`MediaSection`] is rejected (has a zero port).
+    ///
+    /// [RED]: https://tools.ietf.org/html/rfc2198
+    /// [RTX]: https://tools.ietf.org/html/rfc4588
+    #[must_use]
+    pub fn primary_codec(&self) -> Option<RtpMap> {
+        /// Encoding names of the auxiliary codecs.
+        const AUXILIARY: &[&str] =
+            &["rtx", "red", "ulpfec", "flexfec-03", "CN", "telephone-event"];
+
+        if self.port == "0" {
+            return None;
+        }
+        let maps = self.rtp_maps();
+        self.formats.iter().find_map(|pt| {
+            maps.iter()
+                .find(|m| {
+                    &m.payload_type == pt
+                        && !AUXILIARY
+                            .iter()
+                            .any(|a| m.encoding_name.eq_ignore_ascii_case(a))
+                })
+                .cloned()
+        })
+    }
+
+    /// Returns payload types of the provided `codec` (matched by its encoding
+    /// name, case-insensitively), followed by payload types of their [RTX]
+    /// retransmissions.
+    ///
+    /// [RTX]: https://tools.ietf.org/html/rfc4588
+    #[must_use]
+    pub fn payload_types_of(&self, codec: &str) -> Vec<String> {
+        let mut pts: Vec<String> = self
+            .rtp_maps()
+            .into_iter()
+            .filter(|m| m.encoding_name.eq_ignore_ascii_case(codec))
+            .map(|m| m.payload_type)
+            .collect();
+        let rtx: Vec<String> = self
+            .attributes("fmtp")
+            .filter_map(|fmtp| {
+                let (pt, params) = fmtp.split_once(' ')?;
+                let apt = params
+                    .split(';')
+                    .find_map(|p| p.trim().strip_prefix("apt="))?;
+                pts.iter().any(|p| p == apt).then(|| pt.to_owned())
+            })
+            .collect();
+        pts.extend(rtx);
+        pts
+    }
+
+    /// Moves payload types of the provided `codecs` (along with their [RTX]
+    /// retransmissions) to the front of the [`MediaSection::formats`], in the
+    /// provided order.
+    ///
+    /// Order of the rest payload types is preserved.
+    ///
+    /// [RTX]: https://tools.ietf.org/html/rfc4588
+    pub fn prefer_codecs(&mut self, codecs: &[&str]) {
+        let mut preferred: Vec<String> = Vec::new();
+        for pt in codecs.iter().flat_map(|c| self.payload_types_of(c)) {
+            if self.formats.contains(&pt) && !preferred.contains(&pt) {
+                preferred.push(pt);
+            }
+        }
+        let rest = self.formats.iter().filter(|f| !preferred.contains(f));
+        self.formats = preferred.iter().chain(rest).cloned().collect();
+    }
+
+    /// Removes the provided payload types from this [`MediaSection`], along
+    /// with all their `rtpmap`, `fmtp` and `rtcp-fb` attributes.
+    pub fn remove_payload_types(&mut self, pts: &[String]) {
+        self.formats.retain(|f| !pts.contains(f));
+        self.lines.retain(|line| {
+            payload_type_of_line(line)
+                .is_none_or(|pt| !pts.iter().any(|p| p == pt))
+        });
+    }
+
+    /// Changes the provided `from` payload type of this [`MediaSection`] to
+    /// the provided `to` one, in the `m=` line and all the `rtpmap`, `fmtp`
+    /// and `rtcp-fb` attributes, including the references from the [RTX]
+    /// `apt` and [RED] `fmtp` parameters.
+    ///
+    /// If the `to` payload type is already used in this [`MediaSection`], it
+    /// takes the place of the `from` one, so payload types remain unique.
+    ///
+    /// [RED]: https://tools.ietf.org/html/rfc2198
+    /// [RTX]: https://tools.ietf.org/html/rfc4588
+    pub fn remap_payload_type(&mut self, from: &str, to: &str) {
+        if from == to {
+            return;
+        }
+        let remap = |pt: &str| -> String {
+            if pt == from {
+                to.into()
+            } else if pt == to {
+                from.into()
+            } else {
+                pt.into()
+            }
+        };
+
+        for format in &mut self.formats {
+            *format = remap(format);
+        }
+        for line in &mut self.lines {
+            let Some((prefix, value)) = ["a=rtpmap:", "a=fmtp:", "a=rtcp-fb:"]
+                .into_iter()
+                .find_map(|prefix| Some((prefix, line.strip_prefix(prefix)?)))
+            else {
+                continue;
+            };
+            let (pt, rest) = value.split_once(' ').unwrap_or((value, ""));
+            let rest = if prefix == "a=fmtp:" {
+                remap_fmtp_params(rest, &remap)
+            } else {
+                rest.into()
+            };
+            let pt = remap(pt);
+            *line = if rest.is_empty() {
+                format!("{prefix}{pt}")
+            } else {
+                format!("{prefix}{pt} {rest}")
+            };
+        }
+    }
+
+    /// Returns value of the provided `fmtp` parameter of the provided
+    /// payload type, if any.
+    ///
+    /// Flag parameters (without `=`) have an empty value.
+    #[must_use]
+    pub fn fmtp_param(&self, payload_type: &str, name: &str) -> Option<&str> {
+        self.attributes("fmtp").find_map(|fmtp| {
+            let (pt, params) = fmtp.split_once(' ')?;
+            if pt != payload_type {
+                return None;
+            }
+            params.split(';').find_map(|param| {
+                let (n, v) =
+                    param.trim().split_once('=').unwrap_or((param, ""));
+                (n.trim() == name).then_some(v)
+            })
+        })
+    }
+
+    /// Sets the provided `fmtp` parameter of the provided payload type,
+    /// replacing the existing one.
+    ///
+    /// [`None`] removes the parameter. A new `fmtp` attribute is added right
+    /// after the `rtpmap` one of the payload type, if there is none yet.
+    pub fn set_fmtp_param(
+        &mut self,
+        payload_type: &str,
+        name: &str,
+        value: Option<&str>,
+    ) {
+        let prefix = format!("a=fmtp:{payload_type} ");
+        let param = value.map(|v| format!("{name}={v}"));
+        if let Some(line) =
+            self.lines.iter_mut().find(|l| l.starts_with(&prefix))
+        {
+            let mut params: Vec<String> = line
+                .strip_prefix(prefix.as_str())
+                .unwrap_or_default()
+                .split(';')
+                .map(str::trim)
+                .filter(|p| {
+                    !p.is_empty()
+                        && p.split_once('=').map_or(*p, |(n, _)| n) != name
+                })
+                .map(Into::into)
+                .collect();
+            params.extend(param);
+            *line = format!("{prefix}{}", params.join(";"));
+            if params.is_empty() {
+                self.lines.retain(|l| l.trim_end() != prefix.trim_end());
+            }
+        } else if let Some(param) = param {
+            let rtpmap = format!("a=rtpmap:{payload_type} ");
+            let at = self
+                .lines
+                .iter()
+                .position(|l| l.starts_with(&rtpmap))
+                .map_or(self.lines.len(), |i| i + 1);
+            self.lines.insert(at, format!("{prefix}{param}"));
+        }
+    }
+
+    /// Sets the [bandwidth][1] of the provided `modifier` (`AS`, `TIAS`, etc)
+    /// of this [`MediaSection`], replacing the existing one.
+    ///
+    /// [`None`] removes the bandwidth of the provided `modifier`.
+    ///
+    /// [1]: https://tools.ietf.org/html/rfc8866#section-5.8
+    pub fn set_bandwidth(&mut self, modifier: &str, value: Option<u32>) {
+        let prefix = format!("b={modifier}:");
+        self.lines.retain(|line| !line.starts_with(&prefix));
+        if let Some(value) = value {
+            // `b=` lines follow the `i=` and `c=` ones.
+            let at = self
+                .lines
+                .iter()
+                .position(|l| !l.starts_with("i=") && !l.starts_with("c="))
+                .unwrap_or(self.lines.len());
+            self.lines.insert(at, format!("{prefix}{value}"));
+        }
+    }
+
+    /// Returns the [bandwidth][1] of the provided `modifier` (`AS`, `TIAS`,
+    /// etc) of this [`MediaSection`], if any.
+    ///
+    /// [1]: https://tools.ietf.org/html/rfc8866#section-5.8
+    #[must_use]
+    pub fn bandwidth(&self, modifier: &str) -> Option<u32> {
+        self.lines.iter().find_map(|line| {
+            line.strip_prefix("b=")?
+                .strip_prefix(modifier)?
+                .strip_prefix(':')?
+                .parse()
+                .ok()
+        })
+    }
+
+    /// Removes all the [header extensions][1] of this [`MediaSection`] whose
+    /// URI matches the provided `predicate`.
+    ///
+    /// [1]: https://tools.ietf.org/html/rfc8285
+    pub fn remove_extensions<F>(&mut self, predicate: F)
+    where
+        F: Fn(&str) -> bool,
+    {
+        retain_extensions(&mut self.lines, &predicate);
+    }
+}
+
+impl fmt::Display for MediaSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "m={} {} {}", self.kind, self.port, self.protocol)?;
+        for format in &self.formats {
+            write!(f, " {format}")?;
+        }
+        write!(f, "\r\n")?;
+        for line in &self.lines {
+            write!(f, "{line}\r\n")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parsed [rtpmap][1] attribute.
+///
+/// [1]: https://tools.ietf.org/html/rfc8866#section-6.6
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RtpMap {
+    /// Payload type being mapped.
+    pub payload_type: String,
+
+    /// Encoding name of the codec (e.g. `VP8` or `opus`).
+    pub encoding_name: String,
+
+    /// Clock rate of the codec.
+    pub clock_rate: u32,
+
+    /// Encoding parameters of the codec (number of channels for audio), if
+    /// any.
+    pub encoding_params: Option<String>,
+}
+
+impl RtpMap {
+    /// Returns number of audio channels of the codec, if specified.
+    #[must_use]
+    pub fn channels(&self) -> Option<u16> {
+        self.encoding_params.as_deref()?.parse().ok()
+    }
+
+    /// Parses a [`RtpMap`] out of the provided `rtpmap` attribute value.
+    fn parse(value: &str) -> Option<Self> {
+        let (payload_type, encoding) = value.split_once(' ')?;
+        let mut parts = encoding.trim().split('/');
+        Some(Self {
+            payload_type: payload_type.into(),
+            encoding_name: parts.next()?.into(),
+            clock_rate: parts.next()?.parse().ok()?,
+            encoding_params: parts.next().map(Into::into),
+        })
+    }
+}
+
+/// Returns the payload type the provided `rtpmap`, `fmtp` or `rtcp-fb`
+/// attribute `line` relates to.
+fn payload_type_of_line(line: &str) -> Option<&str> {
+    let value = ["a=rtpmap:", "a=fmtp:", "a=rtcp-fb:"]
+        .iter()
+        .find_map(|prefix| line.strip_prefix(prefix))?;
+    value.split_whitespace().next()
+}
+
+/// Remaps the payload types referenced by the provided `fmtp` attribute
+/// `params` with the provided `remap` function.
+///
+/// Both the [RTX] `apt` parameter and the [RED] list of redundant payload
+/// types are remapped, while the rest parameters are preserved as is.
+///
+/// [RED]: https://tools.ietf.org/html/rfc2198
+/// [RTX]: https://tools.ietf.org/html/rfc4588
+fn remap_fmtp_params<F>(params: &str, remap: &F) -> String
+where
+    F: Fn(&str) -> String,
+{
+    let is_red = !params.is_empty()
+        && params
+            .split('/')
+            .all(|pt| !pt.is_empty() && pt.bytes().all(|b| b.is_ascii_digit()));
+    if is_red {
+        return params.split('/').map(remap).collect::<Vec<_>>().join("/");
+    }
+
+    params
+        .split(';')
+        .map(|param| {
+            let trimmed = param.trim_start();
+            trimmed.strip_prefix("apt=").map_or_else(
+                || param.into(),
+                |apt| {
+                    let indent =
+                        param.strip_suffix(trimmed).unwrap_or_default();
+                    format!("{indent}apt={}", remap(apt))
+                },
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// Returns URI of the provided [extmap][1] attribute `line`.
+///
+/// [1]: https://tools.ietf.org/html/rfc8285#section-8
+fn extension_uri_of_line(line: &str) -> Option<&str> {
+    line.strip_prefix("a=extmap:")?.split_whitespace().nth(1)
+}
+
+/// Removes the [extmap][1] attribute `lines` whose URI matches the provided
+/// `predicate`.
+///
+/// [1]: https://tools.ietf.org/html/rfc8285#section-8
+fn retain_extensions<F>(lines: &mut Vec<String>, predicate: &F)
+where
+    F: Fn(&str) -> bool,
+{
+    lines.retain(|line| {
+        extension_uri_of_line(line).is_none_or(|uri| !predicate(uri))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OFFER: &str = "v=0\r\n\
+        o=- 4611731400430051336 2 IN IP4 127.0.0.1\r\n\
+        s=-\r\n\
+        t=0 0\r\n\
+        a=group:BUNDLE 0 1 2\r\n\
+        a=extmap-allow-mixed\r\n\
+        a=msid-semantic: WMS\r\n\
+        m=audio 9 UDP/TLS/RTP/SAVPF 111 63 9 0 8 13 110 126\r\n\
+        c=IN IP4 0.0.0.0\r\n\
+        a=rtcp:9 IN IP4 0.0.0.0\r\n\
+        a=ice-ufrag:4ZcD\r\n\
+        a=ice-pwd:2/1muCWoOi3uLifh0NuRHlQ4\r\n\
+        a=fingerprint:sha-256 75:74:5A:A6:A4:E5:52:F4:A7:67:4C:01:C7:EE:91:\
+        3F:21:3D:A2:E3:53:7B:6F:30:86:F2:30:FF:A6:22:D2:04\r\n\
+        a=setup:actpass\r\n\
+        a=mid:0\r\n\
+        a=extmap:1 urn:ietf:params:rtp-hdrext:ssrc-audio-level\r\n\
+        a=extmap:2 http://www.webrtc.org/experiments/rtp-hdrext/abs-send-time\r\n\
+        a=sendrecv\r\n\
+        a=rtcp-mux\r\n\
+        a=rtpmap:111 opus/48000/2\r\n\
+        a=rtcp-fb:111 transport-cc\r\n\
+        a=fmtp:111 minptime=10;useinbandfec=1\r\n\
+        a=rtpmap:63 red/48000/2\r\n\
+        a=fmtp:63 111/111\r\n\
+        a=rtpmap:9 G722/8000\r\n\
+        a=rtpmap:0 PCMU/8000\r\n\
+        a=rtpmap:8 PCMA/8000\r\n\
+        a=rtpmap:13 CN/8000\r\n\
+        a=rtpmap:110 telephone-event/48000\r\n\
+        a=rtpmap:126 telephone-event/8000\r\n\
+        m=video 9 UDP/TLS/RTP/SAVPF 96 97 98 99 45\r\n\
+        c=IN IP4 0.0.0.0\r\n\
+        a=mid:1\r\n\
+        a=extmap:14 urn:ietf:params:rtp-hdrext:toffset\r\n\
+        a=extmap:2 http://www.webrtc.org/experiments/rtp-hdrext/abs-send-time\r\n\
+        a=sendrecv\r\n\
+        a=rtcp-mux\r\n\
+        a=rtcp-rsize\r\n\
+        a=rtpmap:96 VP8/90000\r\n\
+        a=rtcp-fb:96 goog-remb\r\n\
+        a=rtcp-fb:96 nack\r\n\
+        a=rtpmap:97 rtx/90000\r\n\
+        a=fmtp:97 apt=96\r\n\
+        a=rtpmap:98 VP9/90000\r\n\
+        a=rtcp-fb:98 nack\r\n\
+        a=fmtp:98 profile-id=0\r\n\
+        a=rtpmap:99 rtx/90000\r\n\
+        a=fmtp:99 apt=98\r\n\
+        a=rtpmap:45 AV1/90000\r\n\
+        m=application 9 UDP/DTLS/SCTP webrtc-datachannel\r\n\
+        c=IN IP4 0.0.0.0\r\n\
+        a=mid:2\r\n\
+        a=sctp-port:5000\r\n\
+        a=max-message-size:262144\r\n";
+
+    fn offer() -> SessionDescription {
+        OFFER.parse().unwrap()
+    }
+
+    #[test]
+    fn round_trips() {
+        let sdp = offer();
+
+        assert_eq!(sdp.session.len(), 7);
+        assert_eq!(sdp.media.len(), 3);
+        assert_eq!(sdp.to_string(), OFFER);
+    }
+
+    #[test]
+    fn normalizes_line_endings() {
+        let lf = OFFER.replace("\r\n", "\n");
+
+        assert_eq!(
+            lf.parse::<SessionDescription>().unwrap().to_string(),
+            OFFER
+        );
+    }
+
+    #[test]
+    fn parses_media_sections() {
+        let sdp = offer();
+        let video = &sdp.media[1];
+
+        assert_eq!(video.kind, "video");
+        assert_eq!(video.protocol, "UDP/TLS/RTP/SAVPF");
+        assert_eq!(video.formats, ["96", "97", "98", "99", "45"]);
+        assert_eq!(video.mid(), Some("1"));
+        assert!(video.has_attribute("rtcp-mux"));
+        assert!(!sdp.media[2].has_attribute("rtcp-mux"));
+        assert_eq!(
+            sdp.media[0].rtp_maps()[0],
+            RtpMap {
+                payload_type: "111".into(),
+                encoding_name: "opus".into(),
+                clock_rate: 48000,
+                encoding_params: Some("2".into()),
+            },
+        );
+        assert_eq!(video.payload_types_of("vp9"), ["98", "99"]);
+    }
+
+    #[test]
+    fn prefers_codecs() {
+        let mut sdp = offer();
+        sdp.prefer_codecs("video", &["AV1", "VP9"]);
+        sdp.prefer_codecs("audio", &["PCMA", "unknown"]);
+
+        assert_eq!(sdp.media[1].formats, ["45", "98", "99", "96", "97"]);
+        assert_eq!(
+            sdp.media[0].formats,
+            ["8", "111", "63", "9", "0", "13", "110", "126"],
+        );
+        assert!(
+            sdp.to_string()
+                .contains("m=video 9 UDP/TLS/RTP/SAVPF 45 98 99 96 97\r\n"),
+        );
+    }
+
+    #[test]
+    fn removes_payload_types() {
+        let mut sdp = offer();
+        let video = &mut sdp.media[1];
+        let vp8 = video.payload_types_of("VP8");
+        video.remove_payload_types(&vp8);
+
+        assert_eq!(video.formats, ["98", "99", "45"]);
+        assert!(video.lines.iter().all(|l| !l.contains(":96 ")));
+        assert!(video.lines.iter().all(|l| !l.contains(":97 ")));
+        assert!(video.lines.contains(&"a=fmtp:99 apt=98".into()));
+    }
+
+    #[test]
+    fn remaps_payload_types() {
+        let mut sdp = offer();
+        let video = &mut sdp.media[1];
+        video.remap_payload_type("96", "100");
+        video.remap_payload_type("97", "98");
+
+        assert_eq!(video.formats, ["100", "98", "97", "99", "45"]);
+        assert_eq!(video.payload_types_of("VP8"), ["100", "98"]);
+        assert_eq!(video.payload_types_of("VP9"), ["97", "99"]);
+        assert!(video.lines.contains(&"a=rtcp-fb:100 goog-remb".into()));
+        assert!(video.lines.contains(&"a=rtcp-fb:97 nack".into()));
+        assert!(video.lines.contains(&"a=fmtp:98 apt=100".into()));
+        assert!(video.lines.contains(&"a=fmtp:97 profile-id=0".into()));
+        assert!(video.lines.contains(&"a=fmtp:99 apt=97".into()));
+
+        let audio = &mut sdp.media[0];
+        audio.remap_payload_type("111", "109");
+
+        assert_eq!(audio.formats[0], "109");
+        assert!(audio.lines.contains(&"a=rtpmap:109 opus/48000/2".into()));
+        assert!(audio.lines.contains(&"a=rtcp-fb:109 transport-cc".into()));
+        assert!(
+            audio
+                .lines
+                .contains(&"a=fmtp:109 minptime=10;useinbandfec=1".into()),
+        );
+        assert!(audio.lines.contains(&"a=fmtp:63 109/109".into()));
+    }
+
+    #[test]
+    fn removes_extensions() {
+        let mut sdp = offer();
+        sdp.remove_extensions(|uri| uri.ends_with("abs-send-time"));
+
+        let sdp = sdp.to_string();
+        assert!(!sdp.contains("abs-send-time"));
+        assert!(sdp.contains("a=extmap:1 urn:ietf:params:rtp-hdrext:ssrc"));
+        assert!(sdp.contains("a=extmap:14 urn:ietf:params:rtp-hdrext:toffset"));
+    }
+
+    #[test]
+    fn sets_bandwidth() {
+        let mut sdp = offer();
+        let audio = &mut sdp.media[0];
+        audio.set_bandwidth("AS", Some(64));
+        audio.set_bandwidth("AS", Some(32));
+
+        assert_eq!(audio.bandwidth("AS"), Some(32));
+        assert_eq!(audio.bandwidth("TIAS"), None);
+        assert!(sdp.to_string().contains(
+            "m=audio 9 UDP/TLS/RTP/SAVPF 111 63 9 0 8 13 110 126\r\n\
+             c=IN IP4 0.0.0.0\r\n\
+             b=AS:32\r\n\
+             a=rtcp:9 IN IP4 0.0.0.0\r\n",
+        ));
+
+        sdp.media[0].set_bandwidth("AS", None);
+        assert_eq!(sdp.to_string(), OFFER);
+    }
+
+    #[test]
+    fn sets_fmtp_params() {
+        let mut sdp = offer();
+        let audio = &mut sdp.media[0];
+
+        assert_eq!(audio.fmtp_param("111", "useinbandfec"), Some("1"));
+        assert_eq!(audio.fmtp_param("111", "usedtx"), None);
+        assert_eq!(audio.fmtp_param("9", "minptime"), None);
+
+        audio.set_fmtp_param("111", "useinbandfec", Some("0"));
+        audio.set_fmtp_param("111", "usedtx", Some("1"));
+        audio.set_fmtp_param("111", "minptime", None);
+        audio.set_fmtp_param("9", "ptime", Some("20"));
+
+        assert!(
+            audio.lines.contains(&"a=fmtp:111 useinbandfec=0;usedtx=1".into()),
+        );
+        let g722 = audio
+            .lines
+            .iter()
+            .position(|l| l == "a=rtpmap:9 G722/8000")
+            .unwrap();
+        assert_eq!(audio.lines[g722 + 1], "a=fmtp:9 ptime=20");
+
+        audio.set_fmtp_param("9", "ptime", None);
+        assert!(audio.lines.iter().all(|l| !l.starts_with("a=fmtp:9")));
+    }
+
+    #[test]
+    fn finds_primary_codec() {
+        let mut sdp = offer();
+
+        let opus = sdp.media[0].primary_codec().unwrap();
+        assert_eq!(opus.encoding_name, "opus");
+        assert_eq!(opus.clock_rate, 48000);
+        assert_eq!(opus.channels(), Some(2));
+
+        sdp.prefer_codecs("audio", &["red", "PCMU"]);
+        assert_eq!(sdp.media[0].primary_codec().unwrap().encoding_name, "PCMU");
+        assert_eq!(sdp.media[1].primary_codec().unwrap().channels(), None);
+        assert_eq!(sdp.media[2].primary_codec(), None);
+
+        sdp.media[1].port = "0".into();
+        assert_eq!(sdp.media[1].primary_codec(), None);
+    }
+
+    #[test]
+    fn rejects_malformed_sdp() {
+        let err = |sdp: &str| {
+            sdp.parse::<SessionDescription>().unwrap_err().into_inner()
+        };
+
+        assert_eq!(err(""), SdpParseError::NoVersion);
+        assert_eq!(
+            err("o=- 1 2 IN IP4 127.0.0.1\r\n"),
+            SdpParseError::NoVersion
+        );
+        assert_eq!(
+            err("v=0\r\ngarbage\r\n"),
+            SdpParseError::MalformedLine("garbage".into()),
+        );
+        assert_eq!(
+            err("v=0\r\nm=audio 9\r\n"),
+            SdpParseError::MalformedMediaLine("m=audio 9".into()),
+        );
+    }
+}
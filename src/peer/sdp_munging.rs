@@ -0,0 +1,488 @@
+//! Munging of the local SDP offers and answers created by a
+//! [`PeerConnection`].
+//!
+//! [`PeerConnection`]: super::PeerConnection
+
+use crate::{
+    media::MediaKind,
+    peer::sdp::{MediaSection, SessionDescription},
+    utils::logging::{self, LogCategory},
+};
+
+/// Configuration of the [Opus] codec applied via its [`fmtp` parameters][1].
+///
+/// [`None`] fields leave the platform defaults as is.
+///
+/// [1]: https://tools.ietf.org/html/rfc7587#section-6.1
+/// [Opus]: https://opus-codec.org
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct OpusConfig {
+    /// Maximum average bitrate (in bits per second) of the encoded audio.
+    pub max_average_bitrate: Option<u32>,
+
+    /// Indicator whether the discontinuous transmission (DTX) should be used,
+    /// saving bandwidth during silence.
+    pub dtx: Option<bool>,
+
+    /// Maximum sample rate (in Hz) of the audio the receiving side is capable
+    /// to render.
+    pub max_playback_rate: Option<u32>,
+
+    /// Indicator whether stereo audio should be both sent and received.
+    ///
+    /// Requires the captured audio to have 2 channels (see the
+    /// [`AudioTrackConstraints::channel_count`]) to be actually sent in
+    /// stereo.
+    ///
+    /// [`AudioTrackConstraints::channel_count`]: crate::media::AudioTrackConstraints::channel_count
+    pub stereo: Option<bool>,
+}
+
+impl OpusConfig {
+    /// Sets the `fmtp` parameters of the provided [Opus] payload type of the
+    /// provided [`MediaSection`] according to this [`OpusConfig`].
+    ///
+    /// [Opus]: https://opus-codec.org
+    fn apply(self, section: &mut MediaSection, payload_type: &str) {
+        let params = [
+            ("maxaveragebitrate", self.max_average_bitrate),
+            ("usedtx", self.dtx.map(u32::from)),
+            ("maxplaybackrate", self.max_playback_rate),
+            ("stereo", self.stereo.map(u32::from)),
+            ("sprop-stereo", self.stereo.map(u32::from)),
+        ];
+        for (name, value) in params {
+            if let Some(value) = value {
+                section.set_fmtp_param(
+                    payload_type,
+                    name,
+                    Some(&value.to_string()),
+                );
+            }
+        }
+    }
+}
+
+/// Options of munging the local SDP offers and answers before applying them.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SdpMunging {
+    /// [`b=AS`][1] bandwidth (in kbps) of the audio [`MediaSection`]s.
+    ///
+    /// [`MediaSection`]: crate::peer::sdp::MediaSection
+    /// [1]: https://tools.ietf.org/html/rfc8866#section-5.8
+    audio_bandwidth: Option<u32>,
+
+    /// [`b=AS`][1] bandwidth (in kbps) of the video [`MediaSection`]s.
+    ///
+    /// [`MediaSection`]: crate::peer::sdp::MediaSection
+    /// [1]: https://tools.ietf.org/html/rfc8866#section-5.8
+    video_bandwidth: Option<u32>,
+
+    /// Indicator whether audio redundancy ([RED] and Opus [in-band FEC]) is
+    /// enforced (`true`) or stripped (`false`).
+    ///
+    /// [`None`] leaves the platform defaults as is.
+    ///
+    /// [in-band FEC]: https://tools.ietf.org/html/rfc7587#section-3.1.1
+    /// [RED]: https://tools.ietf.org/html/rfc2198
+    audio_redundancy: Option<bool>,
+
+    /// [`OpusConfig`] applied to the audio [`MediaSection`]s.
+    opus: OpusConfig,
+
+    /// Payload types enforced for the codecs with the provided encoding names
+    /// (matched case-insensitively).
+    payload_types: Vec<(String, u8)>,
+}
+
+impl SdpMunging {
+    /// Returns the [`b=AS`][1] bandwidth (in kbps) injected into the
+    /// [`MediaSection`]s of the provided [`MediaKind`], if any.
+    ///
+    /// [`MediaSection`]: crate::peer::sdp::MediaSection
+    /// [1]: https://tools.ietf.org/html/rfc8866#section-5.8
+    #[must_use]
+    pub const fn bandwidth(&self, kind: MediaKind) -> Option<u32> {
+        match kind {
+            MediaKind::Audio => self.audio_bandwidth,
+            MediaKind::Video => self.video_bandwidth,
+        }
+    }
+
+    /// Sets the [`b=AS`][1] bandwidth (in kbps) to be injected into the
+    /// [`MediaSection`]s of the provided [`MediaKind`].
+    ///
+    /// [`None`] stops the injection.
+    ///
+    /// [`MediaSection`]: crate::peer::sdp::MediaSection
+    /// [1]: https://tools.ietf.org/html/rfc8866#section-5.8
+    pub const fn set_bandwidth(&mut self, kind: MediaKind, kbps: Option<u32>) {
+        match kind {
+            MediaKind::Audio => self.audio_bandwidth = kbps,
+            MediaKind::Video => self.video_bandwidth = kbps,
+        }
+    }
+
+    /// Returns whether audio redundancy ([RED] and Opus [in-band FEC]) is
+    /// enforced (`true`) or stripped (`false`).
+    ///
+    /// [`None`] means that the platform defaults are left as is.
+    ///
+    /// [in-band FEC]: https://tools.ietf.org/html/rfc7587#section-3.1.1
+    /// [RED]: https://tools.ietf.org/html/rfc2198
+    #[must_use]
+    pub const fn audio_redundancy(&self) -> Option<bool> {
+        self.audio_redundancy
+    }
+
+    /// Sets whether audio redundancy ([RED] and Opus [in-band FEC]) should be
+    /// enforced (`true`) or stripped (`false`), reverting to plain Opus.
+    ///
+    /// [`None`] leaves the platform defaults as is.
+    ///
+    /// [in-band FEC]: https://tools.ietf.org/html/rfc7587#section-3.1.1
+    /// [RED]: https://tools.ietf.org/html/rfc2198
+    pub const fn set_audio_redundancy(&mut self, enabled: Option<bool>) {
+        self.audio_redundancy = enabled;
+    }
+
+    /// Returns the [`OpusConfig`] applied to the audio [`MediaSection`]s.
+    #[must_use]
+    pub const fn opus(&self) -> OpusConfig {
+        self.opus
+    }
+
+    /// Sets the [`OpusConfig`] to be applied to the audio [`MediaSection`]s.
+    pub const fn set_opus(&mut self, config: OpusConfig) {
+        self.opus = config;
+    }
+
+    /// Returns the payload type enforced for the provided `codec` (matched by
+    /// its encoding name, case-insensitively), if any.
+    #[must_use]
+    pub fn payload_type(&self, codec: &str) -> Option<u8> {
+        self.payload_types
+            .iter()
+            .find_map(|(c, pt)| c.eq_ignore_ascii_case(codec).then_some(*pt))
+    }
+
+    /// Sets the payload type to be enforced for the provided `codec` (matched
+    /// by its encoding name, case-insensitively).
+    ///
+    /// Only the most preferred payload type of the `codec` in each
+    /// [`MediaSection`] is remapped. If the provided `payload_type` is already
+    /// used by another codec, their payload types are swapped.
+    ///
+    /// [`None`] stops the remapping. Payload types outside of the `0..=127`
+    /// range are ignored.
+    pub fn set_payload_type(&mut self, codec: &str, payload_type: Option<u8>) {
+        if let Some(pt) = payload_type.filter(|pt| *pt > 127) {
+            logging::emit(
+                log::Level::Warn,
+                None,
+                LogCategory::Negotiation,
+                format_args!(
+                    "Ignoring invalid payload type {pt} for `{codec}`"
+                ),
+            );
+            return;
+        }
+        self.payload_types.retain(|(c, _)| !c.eq_ignore_ascii_case(codec));
+        if let Some(pt) = payload_type {
+            self.payload_types.push((codec.into(), pt));
+        }
+    }
+
+    /// Applies this [`SdpMunging`] to the provided local SDP offer.
+    ///
+    /// Returns the provided SDP as is if there is nothing to munge, or it
+    /// cannot be parsed.
+    #[must_use]
+    pub fn apply_to_offer(&self, sdp: String) -> String {
+        self.apply(sdp, true)
+    }
+
+    /// Applies this [`SdpMunging`] to the provided local SDP answer.
+    ///
+    /// Payload types are never remapped in an answer, since they must match
+    /// the ones of the remote offer ([RFC 3264, Section 6.1][1]).
+    ///
+    /// Returns the provided SDP as is if there is nothing to munge, or it
+    /// cannot be parsed.
+    ///
+    /// [1]: https://tools.ietf.org/html/rfc3264#section-6.1
+    #[must_use]
+    pub fn apply_to_answer(&self, sdp: String) -> String {
+        self.apply(sdp, false)
+    }
+
+    /// Applies this [`SdpMunging`] to the provided SDP, remapping payload
+    /// types only if `remap_payload_types` is `true`.
+    fn apply(&self, sdp: String, remap_payload_types: bool) -> String {
+        if self == &Self::default() {
+            return sdp;
+        }
+        let mut parsed = match sdp.parse::<SessionDescription>() {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                logging::emit(
+                    log::Level::Warn,
+                    None,
+                    LogCategory::Negotiation,
+                    format_args!("Failed to munge SDP: {e}"),
+                );
+                return sdp;
+            }
+        };
+
+        for kind in [MediaKind::Audio, MediaKind::Video] {
+            if let Some(kbps) = self.bandwidth(kind) {
+                for section in parsed.media_of_kind(kind.as_str()) {
+                    section.set_bandwidth("AS", Some(kbps));
+                }
+            }
+        }
+
+        if let Some(enabled) = self.audio_redundancy {
+            for section in parsed.media_of_kind(MediaKind::Audio.as_str()) {
+                let fec = if enabled { "1" } else { "0" };
+                for pt in section.payload_types_of("opus") {
+                    section.set_fmtp_param(&pt, "useinbandfec", Some(fec));
+                }
+                if enabled {
+                    section.prefer_codecs(&["red"]);
+                } else {
+                    let red = section.payload_types_of("red");
+                    section.remove_payload_types(&red);
+                }
+            }
+        }
+
+        if self.opus != OpusConfig::default() {
+            for section in parsed.media_of_kind(MediaKind::Audio.as_str()) {
+                for pt in section.payload_types_of("opus") {
+                    self.opus.apply(section, &pt);
+                }
+            }
+        }
+
+        let payload_types: &[_] =
+            if remap_payload_types { &self.payload_types } else { &[] };
+        for (codec, pt) in payload_types {
+            for section in &mut parsed.media {
+                let maps = section.rtp_maps();
+                let from = section.formats.iter().find(|f| {
+                    maps.iter().any(|m| {
+                        &&m.payload_type == f
+                            && m.encoding_name.eq_ignore_ascii_case(codec)
+                    })
+                });
+                if let Some(from) = from.cloned() {
+                    section.remap_payload_type(&from, &pt.to_string());
+                }
+            }
+        }
+
+        parsed.to_string()
+    }
+
+    /// Logs a warning if the provided remote SDP answer doesn't retain the
+    /// audio redundancy enforced by this [`SdpMunging`].
+    pub fn check_answer(&self, answer: &str) {
+        if self.audio_redundancy != Some(true) {
+            return;
+        }
+        let Ok(answer) = answer.parse::<SessionDescription>() else {
+            return;
+        };
+        if !has_audio_redundancy(&answer) {
+            logging::emit(
+                log::Level::Warn,
+                None,
+                LogCategory::Negotiation,
+                format_args!(
+                    "Remote SDP answer doesn't retain audio redundancy",
+                ),
+            );
+        }
+    }
+}
+
+/// Indicates whether all the audio [`MediaSection`]s of the provided
+/// [`SessionDescription`] use audio redundancy ([RED] or Opus
+/// [in-band FEC]).
+///
+/// [`MediaSection`]: crate::peer::sdp::MediaSection
+/// [in-band FEC]: https://tools.ietf.org/html/rfc7587#section-3.1.1
+/// [RED]: https://tools.ietf.org/html/rfc2198
+fn has_audio_redundancy(sdp: &SessionDescription) -> bool {
+    sdp.media
+        .iter()
+        .filter(|m| m.kind == MediaKind::Audio.as_str() && m.port != "0")
+        .all(|section| {
+            !section.payload_types_of("red").is_empty()
+                || section.payload_types_of("opus").iter().any(|pt| {
+                    section.fmtp_param(pt, "useinbandfec") == Some("1")
+                })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SDP: &str = "v=0\r\n\
+        o=- 1 2 IN IP4 127.0.0.1\r\n\
+        s=-\r\n\
+        t=0 0\r\n\
+        m=audio 9 UDP/TLS/RTP/SAVPF 111 63\r\n\
+        c=IN IP4 0.0.0.0\r\n\
+        a=mid:0\r\n\
+        a=rtpmap:111 opus/48000/2\r\n\
+        a=fmtp:111 minptime=10\r\n\
+        a=rtpmap:63 red/48000/2\r\n\
+        a=fmtp:63 111/111\r\n\
+        m=video 9 UDP/TLS/RTP/SAVPF 96\r\n\
+        c=IN IP4 0.0.0.0\r\n\
+        a=mid:1\r\n\
+        a=rtpmap:96 VP8/90000\r\n";
+
+    #[test]
+    fn leaves_sdp_intact_by_default() {
+        let sdp = SDP.replace("\r\n", "\n");
+
+        assert_eq!(SdpMunging::default().apply_to_offer(sdp.clone()), sdp);
+    }
+
+    #[test]
+    fn injects_bandwidth() {
+        let mut munging = SdpMunging::default();
+        munging.set_bandwidth(MediaKind::Video, Some(500));
+
+        let munged: SessionDescription =
+            munging.apply_to_offer(SDP.into()).parse().unwrap();
+        assert_eq!(munged.media[0].bandwidth("AS"), None);
+        assert_eq!(munged.media[1].bandwidth("AS"), Some(500));
+        assert_eq!(munged.media[1].lines[1], "b=AS:500");
+    }
+
+    #[test]
+    fn enforces_audio_redundancy() {
+        let mut munging = SdpMunging::default();
+        munging.set_audio_redundancy(Some(true));
+
+        let munged: SessionDescription =
+            munging.apply_to_offer(SDP.into()).parse().unwrap();
+        let audio = &munged.media[0];
+        assert_eq!(audio.formats, ["63", "111"]);
+        assert_eq!(audio.fmtp_param("111", "useinbandfec"), Some("1"));
+        assert_eq!(audio.fmtp_param("111", "minptime"), Some("10"));
+        assert!(has_audio_redundancy(&munged));
+    }
+
+    #[test]
+    fn strips_audio_redundancy() {
+        let mut munging = SdpMunging::default();
+        munging.set_audio_redundancy(Some(false));
+
+        let munged: SessionDescription =
+            munging.apply_to_offer(SDP.into()).parse().unwrap();
+        let audio = &munged.media[0];
+        assert_eq!(audio.formats, ["111"]);
+        assert!(audio.rtp_maps().iter().all(|m| m.encoding_name == "opus"));
+        assert_eq!(audio.fmtp_param("111", "useinbandfec"), Some("0"));
+        assert!(!has_audio_redundancy(&munged));
+    }
+
+    #[test]
+    fn detects_redundancy_in_answer() {
+        let answer = |fmtp: &str| -> SessionDescription {
+            format!(
+                "v=0\r\n\
+                 m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+                 a=rtpmap:111 opus/48000/2\r\n\
+                 {fmtp}",
+            )
+            .parse()
+            .unwrap()
+        };
+
+        assert!(has_audio_redundancy(&answer("a=fmtp:111 useinbandfec=1\r\n")));
+        assert!(!has_audio_redundancy(&answer(
+            "a=fmtp:111 useinbandfec=0\r\n"
+        )));
+        assert!(!has_audio_redundancy(&answer("")));
+    }
+
+    #[test]
+    fn remaps_payload_types() {
+        let mut munging = SdpMunging::default();
+        munging.set_payload_type("OPUS", Some(109));
+        munging.set_payload_type("vp8", Some(63));
+        munging.set_payload_type("VP9", Some(200));
+
+        assert_eq!(munging.payload_type("opus"), Some(109));
+        assert_eq!(munging.payload_type("VP9"), None);
+
+        let munged: SessionDescription =
+            munging.apply_to_offer(SDP.into()).parse().unwrap();
+        let (audio, video) = (&munged.media[0], &munged.media[1]);
+        assert_eq!(audio.formats, ["109", "63"]);
+        assert_eq!(audio.fmtp_param("109", "minptime"), Some("10"));
+        assert!(audio.lines.contains(&"a=fmtp:63 109/109".into()));
+        assert_eq!(video.formats, ["63"]);
+        assert_eq!(video.rtp_maps()[0].encoding_name, "VP8");
+
+        // Re-applying on a renegotiation keeps the payload types.
+        let remunged: SessionDescription =
+            munging.apply_to_offer(munged.to_string()).parse().unwrap();
+        assert_eq!(remunged, munged);
+
+        // Answers keep the payload types of the remote offer.
+        let answer: SessionDescription =
+            munging.apply_to_answer(SDP.into()).parse().unwrap();
+        assert_eq!(answer, SDP.parse().unwrap());
+
+        munging.set_payload_type("opus", None);
+        assert_eq!(munging.payload_type("opus"), None);
+    }
+
+    #[test]
+    fn applies_opus_config() {
+        let mut munging = SdpMunging::default();
+        munging.set_opus(OpusConfig {
+            max_average_bitrate: Some(24000),
+            dtx: Some(true),
+            max_playback_rate: None,
+            stereo: None,
+        });
+
+        let munged: SessionDescription =
+            munging.apply_to_offer(SDP.into()).parse().unwrap();
+        let audio = &munged.media[0];
+        assert_eq!(audio.fmtp_param("111", "maxaveragebitrate"), Some("24000"));
+        assert_eq!(audio.fmtp_param("111", "usedtx"), Some("1"));
+        assert_eq!(audio.fmtp_param("111", "maxplaybackrate"), None);
+        assert_eq!(audio.fmtp_param("111", "minptime"), Some("10"));
+
+        // Re-applying on a renegotiation doesn't duplicate the parameters.
+        let remunged: SessionDescription =
+            munging.apply_to_offer(munged.to_string()).parse().unwrap();
+        assert_eq!(remunged, munged);
+    }
+
+    #[test]
+    fn negotiates_stereo() {
+        let mut munging = SdpMunging::default();
+        munging.set_opus(OpusConfig {
+            stereo: Some(true),
+            ..OpusConfig::default()
+        });
+
+        let munged: SessionDescription =
+            munging.apply_to_offer(SDP.into()).parse().unwrap();
+        assert!(munged.media[0].lines.contains(
+            &"a=fmtp:111 minptime=10;stereo=1;sprop-stereo=1".into()
+        ));
+    }
+}
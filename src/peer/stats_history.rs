@@ -0,0 +1,179 @@
+//! Bounded history of [`platform::RtcStats`] scraped from a
+//! [`PeerConnection`].
+//!
+//! [`PeerConnection`]: super::PeerConnection
+
+use std::collections::VecDeque;
+
+use medea_client_api_proto::stats::HighResTimeStamp;
+
+use crate::platform;
+
+/// Snapshot of [`platform::RtcStats`] stored in a [`StatsHistory`].
+#[derive(Clone, Debug)]
+pub struct StatsSnapshot {
+    /// Timestamp of this [`StatsSnapshot`].
+    ///
+    /// The latest timestamp among all the [`platform::RtcStats`] entries of
+    /// this [`StatsSnapshot`].
+    pub timestamp: HighResTimeStamp,
+
+    /// Actual [`platform::RtcStats`] of this [`StatsSnapshot`].
+    pub stats: platform::RtcStats,
+}
+
+/// Ring buffer retaining the last N [`StatsSnapshot`]s.
+///
+/// Disabled (doesn't retain anything) until its capacity is set.
+#[derive(Debug, Default)]
+pub struct StatsHistory {
+    /// Maximum number of [`StatsSnapshot`]s retained by this
+    /// [`StatsHistory`].
+    capacity: usize,
+
+    /// Retained [`StatsSnapshot`]s, ordered from the oldest to the newest.
+    snapshots: VecDeque<StatsSnapshot>,
+}
+
+impl StatsHistory {
+    /// Sets the maximum number of [`StatsSnapshot`]s retained by this
+    /// [`StatsHistory`], pruning the oldest ones if necessary.
+    ///
+    /// `0` disables this [`StatsHistory`] and clears it.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.prune();
+        self.snapshots.shrink_to(capacity);
+    }
+
+    /// Records the provided [`platform::RtcStats`] as the newest
+    /// [`StatsSnapshot`], pruning the oldest ones if necessary.
+    ///
+    /// Empty [`platform::RtcStats`] are not recorded.
+    pub fn push(&mut self, stats: &platform::RtcStats) {
+        if self.capacity == 0 {
+            return;
+        }
+        let Some(timestamp) =
+            stats.0.iter().map(|s| s.timestamp.0).reduce(f64::max)
+        else {
+            return;
+        };
+
+        self.snapshots.push_back(StatsSnapshot {
+            timestamp: HighResTimeStamp(timestamp),
+            stats: stats.clone(),
+        });
+        self.prune();
+    }
+
+    /// Returns all the retained [`StatsSnapshot`]s, ordered from the oldest to
+    /// the newest.
+    #[must_use]
+    pub fn snapshots(&self) -> Vec<StatsSnapshot> {
+        self.snapshots.iter().cloned().collect()
+    }
+
+    /// Removes the oldest [`StatsSnapshot`]s exceeding the capacity of this
+    /// [`StatsHistory`].
+    fn prune(&mut self) {
+        while self.snapshots.len() > self.capacity {
+            drop(self.snapshots.pop_front());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use medea_client_api_proto::stats::{
+        RtcStat, RtcStatsType, StatId, TrackStats,
+    };
+
+    use super::*;
+
+    /// Returns [`platform::RtcStats`] with the entries having the provided
+    /// timestamps.
+    fn stats(timestamps: &[f64]) -> platform::RtcStats {
+        platform::RtcStats(
+            timestamps
+                .iter()
+                .map(|&ts| RtcStat {
+                    id: StatId(format!("track-{ts}")),
+                    timestamp: HighResTimeStamp(ts),
+                    stats: RtcStatsType::Track(Box::new(TrackStats {
+                        track_identifier: "track".into(),
+                        remote_source: None,
+                        ended: None,
+                        kind: None,
+                    })),
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns timestamps of all the [`StatsSnapshot`]s retained by the
+    /// provided [`StatsHistory`].
+    fn timestamps(history: &StatsHistory) -> Vec<f64> {
+        history.snapshots().into_iter().map(|s| s.timestamp.0).collect()
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let mut history = StatsHistory::default();
+        history.push(&stats(&[1.0]));
+
+        assert!(history.snapshots().is_empty());
+    }
+
+    #[test]
+    fn evicts_oldest_snapshots() {
+        let mut history = StatsHistory::default();
+        history.set_capacity(2);
+        for ts in [1.0, 2.0, 3.0] {
+            history.push(&stats(&[ts]));
+        }
+
+        assert_eq!(timestamps(&history), [2.0, 3.0]);
+
+        history.push(&stats(&[4.0]));
+        assert_eq!(timestamps(&history), [3.0, 4.0]);
+    }
+
+    #[test]
+    fn orders_snapshots_from_oldest_to_newest() {
+        let mut history = StatsHistory::default();
+        history.set_capacity(3);
+        history.push(&stats(&[10.0, 12.0]));
+        history.push(&stats(&[5.0]));
+        history.push(&stats(&[20.0, 15.0]));
+
+        assert_eq!(timestamps(&history), [12.0, 5.0, 20.0]);
+    }
+
+    #[test]
+    fn skips_empty_stats() {
+        let mut history = StatsHistory::default();
+        history.set_capacity(2);
+        history.push(&stats(&[1.0]));
+        history.push(&stats(&[]));
+
+        assert_eq!(timestamps(&history), [1.0]);
+    }
+
+    #[test]
+    fn shrinking_capacity_prunes_oldest_snapshots() {
+        let mut history = StatsHistory::default();
+        history.set_capacity(3);
+        for ts in [1.0, 2.0, 3.0] {
+            history.push(&stats(&[ts]));
+        }
+
+        history.set_capacity(1);
+        assert_eq!(timestamps(&history), [3.0]);
+
+        history.set_capacity(0);
+        assert!(history.snapshots().is_empty());
+        history.push(&stats(&[4.0]));
+        assert!(history.snapshots().is_empty());
+    }
+}
@@ -196,6 +196,16 @@ impl SimpleTracksRequest {
         Ok(parsed_tracks)
     }
 
+    /// Requests 2 audio channels to be captured, unless the number of channels
+    /// is constrained explicitly.
+    pub const fn request_stereo_audio(&mut self) {
+        if let Some((_, audio)) = self.audio.as_mut() {
+            if audio.channel_count.is_none() {
+                audio.ideal_channel_count(2);
+            }
+        }
+    }
+
     /// Merges [`SimpleTracksRequest`] with provided [`MediaStreamSettings`].
     ///
     /// Applies new settings if possible, meaning that if this
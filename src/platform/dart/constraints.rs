@@ -13,7 +13,7 @@ use crate::{
     media::{
         AudioTrackConstraints, DeviceVideoTrackConstraints,
         DisplayVideoTrackConstraints,
-        constraints::{ConstrainBoolean, ConstrainString, ConstrainU32},
+        constraints::{ConstrainBoolean, ConstrainString, ConstrainU32Range},
     },
     platform::dart::utils::handle::DartHandle,
 };
@@ -103,6 +103,7 @@ mod constraints {
 /// Kind of a [MediaStreamConstraints.video][0] setting.
 ///
 /// [0]: https://tinyurl.com/3yvnbb9e
+#[derive(Clone, Copy, Debug)]
 enum VideoConstraintKind {
     FacingMode = 0,
     DeviceId = 1,
@@ -445,6 +446,16 @@ impl From<DeviceVideoTrackConstraints> for MediaTrackConstraints {
                 );
             }
         }
+        if let Some(frame_rate) = from.frame_rate {
+            unsafe {
+                set_video_constrain_u32(
+                    frame_rate,
+                    VideoConstraintKind::FrameRate,
+                    &optional,
+                    &mandatory,
+                );
+            }
+        }
 
         Self { optional, mandatory }
     }
@@ -539,39 +550,46 @@ unsafe fn set_constrain_string<T>(
     }
 }
 
-/// Applies the specified [`ConstrainU32`] to the provided  `optional` and
+/// Applies the specified [`ConstrainU32Range`] to the provided `optional` and
 /// `mandatory` [`DartHandle`]s representing the Dart side constraints.
+///
+/// Dart side constraints accept a single value only, so a `min`..`max` range
+/// is narrowed to its `ideal` value clamped into the range, or to its `min`
+/// (or `max`) bound if there is no `ideal` value.
 unsafe fn set_video_constrain_u32(
-    constrain: ConstrainU32,
+    constrain: ConstrainU32Range,
     kind: VideoConstraintKind,
     optional: &DartHandle,
     mandatory: &DartHandle,
 ) {
-    match constrain {
-        ConstrainU32::Ideal(val) => unsafe {
+    let clamp = |val: u32| {
+        let val = constrain.max.map_or(val, |max| val.min(max));
+        constrain.min.map_or(val, |min| val.max(min))
+    };
+
+    if let Some(ideal) = constrain.ideal {
+        unsafe {
             constraints::set_video_constraint_value(
                 optional.get(),
                 kind as i64,
-                DartValue::from(val),
-            )
-        }
-        .unwrap(),
-        ConstrainU32::Exact(val) => unsafe {
-            constraints::set_video_constraint_value(
-                mandatory.get(),
-                kind as i64,
-                DartValue::from(val),
+                DartValue::from(clamp(ideal)),
             )
         }
-        .unwrap(),
-        ConstrainU32::Range(min, _) => unsafe {
-            // TODO: Implement range constraints in `medea_flutter_webrtc`.
+        .unwrap();
+    }
+    let mandatory_val = constrain.exact.or_else(|| {
+        (constrain.min.is_some() || constrain.max.is_some()).then(|| {
+            clamp(constrain.ideal.or(constrain.min).unwrap_or(u32::MAX))
+        })
+    });
+    if let Some(mandatory_val) = mandatory_val {
+        unsafe {
             constraints::set_video_constraint_value(
                 mandatory.get(),
                 kind as i64,
-                DartValue::from(min),
+                DartValue::from(mandatory_val),
             )
         }
-        .unwrap(),
+        .unwrap();
     }
 }
@@ -0,0 +1,88 @@
+//! [RTCDataChannel][1] stub for the Dart platform.
+//!
+//! [1]: https://w3.org/TR/webrtc#dom-rtcdatachannel
+
+#![expect(clippy::missing_const_for_fn, reason = "`cfg` code uniformity")]
+
+use std::convert::Infallible;
+
+use tracerr::Traced;
+
+use crate::platform::DataChannelError;
+
+/// Representation of [RTCDataChannel][1].
+///
+/// Data channels are not supported on this platform yet, so this type is
+/// never instantiated, and [`RtcPeerConnection::create_data_channel()`] always
+/// errors with [`DataChannelError::Unsupported`].
+///
+/// [`RtcPeerConnection::create_data_channel()`]:
+///     crate::platform::RtcPeerConnection::create_data_channel
+///
+/// [1]: https://w3.org/TR/webrtc#dom-rtcdatachannel
+#[derive(Clone, Copy, Debug)]
+pub struct DataChannel(Infallible);
+
+impl DataChannel {
+    /// Returns [`label`][1] of this [`DataChannel`].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-datachannel-label
+    #[must_use]
+    pub fn label(&self) -> String {
+        match self.0 {}
+    }
+
+    /// Indicates whether this [`DataChannel`] is [open][1], so messages can be
+    /// sent via it.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcdatachannelstate-open
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        match self.0 {}
+    }
+
+    /// Sends the provided binary `data` to the remote peer.
+    ///
+    /// # Errors
+    ///
+    /// Never errors, since a [`DataChannel`] cannot be instantiated on this
+    /// platform.
+    pub fn send(&self, _: &[u8]) -> Result<(), Traced<DataChannelError>> {
+        match self.0 {}
+    }
+
+    /// Sets handler for a [`message`][1] event of this [`DataChannel`].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#event-datachannel-message
+    pub fn on_message<F>(&self, _: Option<F>)
+    where
+        F: 'static + FnMut(Vec<u8>),
+    {
+        match self.0 {}
+    }
+
+    /// Sets handler for an [`open`][1] event of this [`DataChannel`].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#event-datachannel-open
+    pub fn on_open<F>(&self, _: Option<F>)
+    where
+        F: 'static + FnMut(),
+    {
+        match self.0 {}
+    }
+
+    /// Sets handler for a [`close`][1] event of this [`DataChannel`].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#event-datachannel-close
+    pub fn on_close<F>(&self, _: Option<F>)
+    where
+        F: 'static + FnMut(),
+    {
+        match self.0 {}
+    }
+
+    /// Closes this [`DataChannel`].
+    pub fn close(&self) {
+        match self.0 {}
+    }
+}
@@ -0,0 +1,47 @@
+//! [RTCDTMFSender][1] stub for the Dart platform.
+//!
+//! [1]: https://w3.org/TR/webrtc#rtcdtmfsender
+
+#![expect(clippy::missing_const_for_fn, reason = "`cfg` code uniformity")]
+
+use std::{convert::Infallible, time::Duration};
+
+use crate::platform;
+
+/// Representation of [RTCDTMFSender][1].
+///
+/// DTMF is not supported on this platform, so this type is never
+/// instantiated.
+///
+/// [1]: https://w3.org/TR/webrtc#rtcdtmfsender
+#[derive(Clone, Copy, Debug)]
+pub struct DtmfSender(Infallible);
+
+impl DtmfSender {
+    /// Schedules the provided DTMF `tones` to be sent (see
+    /// [RTCDTMFSender.insertDTMF()][1]).
+    ///
+    /// # Errors
+    ///
+    /// Never errors, since a [`DtmfSender`] cannot be instantiated on this
+    /// platform.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcdtmfsender-insertdtmf
+    pub fn insert_dtmf(
+        &self,
+        _: &str,
+        _: Duration,
+        _: Duration,
+    ) -> Result<(), platform::Error> {
+        match self.0 {}
+    }
+
+    /// Returns [`toneBuffer`][1] of this [`DtmfSender`], containing the tones
+    /// remaining to be played out.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcdtmfsender-tonebuffer
+    #[must_use]
+    pub fn tone_buffer(&self) -> String {
+        match self.0 {}
+    }
+}
@@ -0,0 +1,113 @@
+//! HTTP requests performed via [`http`][0] Dart package.
+//!
+//! [0]: https://pub.dev/packages/http
+
+use medea_macro::dart_bridge;
+use tracerr::Traced;
+
+use crate::platform::{
+    HttpError, HttpRequest, HttpResponse,
+    dart::utils::{
+        NonNullDartValueArgExt as _, dart_future::FutureFromDart,
+        dart_string_into_rust, handle::DartHandle, map::DartMap,
+        string_into_c_str,
+    },
+};
+
+#[dart_bridge("flutter/lib/src/native/platform/http.g.dart")]
+mod http {
+    use std::{os::raw::c_char, ptr};
+
+    use dart_sys::Dart_Handle;
+
+    use crate::{
+        api::{DartValue, DartValueArg},
+        platform::Error,
+    };
+
+    extern "C" {
+        /// Sends an HTTP request with the provided `method` to the provided
+        /// `url`, along with the provided `headers` [`Map`] and optional
+        /// `body`.
+        ///
+        /// Returns a [`Future`] resolving into a [`Response`][0].
+        ///
+        /// [`Future`]: https://api.dart.dev/dart-async/Future-class.html
+        /// [`Map`]: https://api.dart.dev/dart-core/Map-class.html
+        /// [0]: https://pub.dev/documentation/http/latest/http/Response-class.html
+        pub fn fetch(
+            method: ptr::NonNull<c_char>,
+            url: ptr::NonNull<c_char>,
+            headers: Dart_Handle,
+            body: DartValue,
+        ) -> Result<Dart_Handle, Error>;
+
+        /// Returns status code of the provided [`Response`][0].
+        ///
+        /// [0]: https://pub.dev/documentation/http/latest/http/Response-class.html
+        pub fn status(response: Dart_Handle) -> Result<i64, Error>;
+
+        /// Returns value of the header with the provided `name` of the
+        /// provided [`Response`][0], if any.
+        ///
+        /// [0]: https://pub.dev/documentation/http/latest/http/Response-class.html
+        pub fn header(
+            response: Dart_Handle,
+            name: ptr::NonNull<c_char>,
+        ) -> Result<ptr::NonNull<DartValueArg<Option<String>>>, Error>;
+
+        /// Returns body of the provided [`Response`][0].
+        ///
+        /// [0]: https://pub.dev/documentation/http/latest/http/Response-class.html
+        pub fn body(
+            response: Dart_Handle,
+        ) -> Result<ptr::NonNull<c_char>, Error>;
+    }
+}
+
+/// Performs the provided [`HttpRequest`].
+///
+/// Only the `Location` header is available in the [`HttpResponse`], since it's
+/// the only one used by this library.
+///
+/// # Errors
+///
+/// With [`HttpError::RequestFailed`] if the request fails.
+pub async fn fetch(
+    request: HttpRequest,
+) -> Result<HttpResponse, Traced<HttpError>> {
+    let mut request_headers = DartMap::new();
+    for (name, value) in request.headers {
+        request_headers.set(name, value.into());
+    }
+    let fut = unsafe {
+        http::fetch(
+            string_into_c_str(request.method.to_string()),
+            string_into_c_str(request.url),
+            request_headers.as_handle(),
+            request.body.into(),
+        )
+    }
+    .unwrap();
+    let response = unsafe { FutureFromDart::execute::<DartHandle>(fut) }
+        .await
+        .map_err(|e| tracerr::new!(HttpError::RequestFailed(e)))?;
+
+    let status = unsafe { http::status(response.get()) }.unwrap();
+    let location = unsafe {
+        http::header(response.get(), string_into_c_str("Location".into()))
+    }
+    .unwrap();
+    let headers = Option::<String>::try_from(unsafe { location.unbox() })
+        .unwrap()
+        .map(|location| ("Location".to_owned(), location))
+        .into_iter()
+        .collect();
+    let body = unsafe { http::body(response.get()) }.unwrap();
+
+    Ok(HttpResponse {
+        status: status.try_into().unwrap_or_default(),
+        headers,
+        body: unsafe { dart_string_into_rust(body) },
+    })
+}
@@ -0,0 +1,96 @@
+//! [MediaRecorder][1] stub for the Dart platform.
+//!
+//! [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+
+#![expect(clippy::missing_const_for_fn, reason = "`cfg` code uniformity")]
+
+use std::{convert::Infallible, time::Duration};
+
+use futures::stream::LocalBoxStream;
+use tracerr::Traced;
+
+use crate::platform::{
+    MediaRecorderError, MediaRecorderEvent, MediaRecorderOptions,
+    MediaStreamTrack,
+};
+
+/// Representation of [MediaRecorder][1].
+///
+/// Recording is not supported on this platform, so this type is never
+/// instantiated.
+///
+/// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+#[derive(Clone, Copy, Debug)]
+pub struct MediaRecorder(Infallible);
+
+impl MediaRecorder {
+    /// Creates a new [`MediaRecorder`] recording the provided
+    /// [`MediaStreamTrack`]s.
+    ///
+    /// # Errors
+    ///
+    /// Always errors with [`MediaRecorderError::Unsupported`], since
+    /// recording is not supported on this platform.
+    pub fn new(
+        _: &[&MediaStreamTrack],
+        _: &MediaRecorderOptions,
+    ) -> Result<Self, Traced<MediaRecorderError>> {
+        Err(tracerr::new!(MediaRecorderError::Unsupported))
+    }
+
+    /// Returns a [`LocalBoxStream`] of [`MediaRecorderEvent`]s of this
+    /// [`MediaRecorder`].
+    #[must_use]
+    pub fn events(&self) -> LocalBoxStream<'static, MediaRecorderEvent> {
+        match self.0 {}
+    }
+
+    /// Indicates whether this [`MediaRecorder`] is recording or paused.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        match self.0 {}
+    }
+
+    /// Starts recording.
+    ///
+    /// # Errors
+    ///
+    /// Never errors, since a [`MediaRecorder`] cannot be instantiated on this
+    /// platform.
+    pub fn start(
+        &self,
+        _: Option<Duration>,
+    ) -> Result<(), Traced<MediaRecorderError>> {
+        match self.0 {}
+    }
+
+    /// Pauses recording.
+    ///
+    /// # Errors
+    ///
+    /// Never errors, since a [`MediaRecorder`] cannot be instantiated on this
+    /// platform.
+    pub fn pause(&self) -> Result<(), Traced<MediaRecorderError>> {
+        match self.0 {}
+    }
+
+    /// Resumes paused recording.
+    ///
+    /// # Errors
+    ///
+    /// Never errors, since a [`MediaRecorder`] cannot be instantiated on this
+    /// platform.
+    pub fn resume(&self) -> Result<(), Traced<MediaRecorderError>> {
+        match self.0 {}
+    }
+
+    /// Stops recording.
+    ///
+    /// # Errors
+    ///
+    /// Never errors, since a [`MediaRecorder`] cannot be instantiated on this
+    /// platform.
+    pub fn stop(&self) -> Result<(), Traced<MediaRecorderError>> {
+        match self.0 {}
+    }
+}
@@ -3,6 +3,10 @@
 //! [0]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
 
 use dart_sys::Dart_Handle;
+use futures::{
+    future::{self, LocalBoxFuture},
+    stream::LocalBoxStream,
+};
 use medea_macro::dart_bridge;
 
 use crate::{
@@ -73,6 +77,14 @@ mod media_stream_track {
             track: Dart_Handle,
         ) -> Result<ptr::NonNull<DartValueArg<Option<u32>>>, Error>;
 
+        /// Returns [frame rate][1] of the provided [MediaStreamTrack][0].
+        ///
+        /// [0]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+        /// [1]: https://tinyurl.com/w3-streams#dom-mediatracksettings-framerate
+        pub fn frame_rate(
+            track: Dart_Handle,
+        ) -> Result<ptr::NonNull<DartValueArg<Option<f64>>>, Error>;
+
         /// Returns [enabled][1] field of the provided [MediaStreamTrack][0].
         ///
         /// [0]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
@@ -344,6 +356,17 @@ impl MediaStreamTrack {
         Option::try_from(unsafe { width.unbox() }).unwrap()
     }
 
+    /// Returns [frame rate][1] of this [`MediaStreamTrack`].
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediatracksettings-framerate
+    #[must_use]
+    pub fn frame_rate(&self) -> Option<f64> {
+        let frame_rate =
+            unsafe { media_stream_track::frame_rate(self.inner.get()) }
+                .unwrap();
+        Option::try_from(unsafe { frame_rate.unbox() }).unwrap()
+    }
+
     /// Returns [enabled][1] field of this [`MediaStreamTrack`].
     ///
     /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack-enabled
@@ -361,6 +384,13 @@ impl MediaStreamTrack {
             .unwrap();
     }
 
+    /// Sets playback volume of this [`MediaStreamTrack`].
+    ///
+    /// No-op, since [`MediaStreamTrack`]s are rendered by Flutter widgets
+    /// outside of this library, so they should apply the volume in the
+    /// `on_volume_changed` callback of a `RemoteMediaTrack`.
+    pub const fn set_volume(&self, _: f64) {}
+
     /// Returns [readiness state][1] of this [`MediaStreamTrack`].
     ///
     /// [1]: https://tinyurl.com/w3-streams#dom-mediastreamtrack-readystate
@@ -435,6 +465,31 @@ impl MediaStreamTrack {
         }
     }
 
+    /// Indicates whether this [`MediaStreamTrack`] is [muted][1], meaning that
+    /// it's unable to provide media data at the moment.
+    ///
+    /// Always returns `false`, since native tracks start producing media right
+    /// after being acquired.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack-muted
+    #[must_use]
+    pub const fn muted(&self) -> bool {
+        false
+    }
+
+    /// Returns a [`Future`] resolving once this [`MediaStreamTrack`] is
+    /// [unmuted][1] or [ended][2].
+    ///
+    /// Resolves immediately, since native tracks are never [muted][3].
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#event-mediastreamtrack-unmute
+    /// [2]: https://tinyurl.com/w3-streams#event-mediastreamtrack-ended
+    /// [3]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack-muted
+    #[must_use]
+    pub fn on_unmute_or_ended(&self) -> LocalBoxFuture<'static, ()> {
+        Box::pin(future::ready(()))
+    }
+
     /// Indicates whether an `OnAudioLevelChangedCallback` is supported for this
     /// [`MediaStreamTrack`].
     #[must_use]
@@ -475,6 +530,29 @@ impl MediaStreamTrack {
         Ok(())
     }
 
+    /// Returns a [`LocalBoxStream`] of audio levels (in the `[0;100]` range)
+    /// of this [`MediaStreamTrack`].
+    ///
+    /// Always returns [`None`], since audio levels can be observed only via
+    /// the single callback set by
+    /// [`MediaStreamTrack::on_audio_level_changed()`].
+    #[must_use]
+    pub const fn audio_level_stream(
+        &self,
+    ) -> Option<LocalBoxStream<'static, i32>> {
+        None
+    }
+
+    /// Returns the total number of frames delivered by this
+    /// [`MediaStreamTrack`] to its sinks.
+    ///
+    /// Always returns [`None`], since native tracks don't expose such
+    /// statistics.
+    #[must_use]
+    pub const fn delivered_frames(&self) -> Option<f64> {
+        None
+    }
+
     /// Indicates whether this [`MediaStreamTrack`] supports audio processing
     /// functions:
     /// - [`MediaStreamTrack::is_noise_suppression_enabled()`]
@@ -12,13 +12,17 @@
 
 pub mod codec_capability;
 pub mod constraints;
+pub mod data_channel;
+pub mod dtmf_sender;
 pub mod error;
 pub mod executor;
+pub mod http;
 pub mod ice_candidate;
 pub mod ice_server;
 pub mod media_device_info;
 pub mod media_devices;
 pub mod media_display_info;
+pub mod media_recorder;
 pub mod media_track;
 pub mod peer_connection;
 pub mod rtc_stats;
@@ -28,18 +32,28 @@ pub mod transceiver;
 pub mod transport;
 pub mod utils;
 
-use std::{cell::RefCell, mem::ManuallyDrop, panic};
+use std::{
+    cell::RefCell,
+    mem::ManuallyDrop,
+    panic,
+    sync::LazyLock,
+    time::{Duration, Instant, SystemTime},
+};
 
 use libc::c_void;
 
 pub use self::{
     codec_capability::CodecCapability,
     constraints::{DisplayMediaStreamConstraints, MediaStreamConstraints},
+    data_channel::DataChannel,
+    dtmf_sender::DtmfSender,
     error::Error,
     executor::spawn,
+    http::fetch,
     media_device_info::MediaDeviceInfo,
     media_devices::MediaDevices,
     media_display_info::MediaDisplayInfo,
+    media_recorder::MediaRecorder,
     media_track::MediaStreamTrack,
     peer_connection::RtcPeerConnection,
     rtc_stats::RtcStats,
@@ -49,6 +63,25 @@ pub use self::{
 };
 use crate::platform::utils::dart_api;
 
+/// Returns the current time as a [`Duration`] elapsed since the UNIX epoch.
+#[must_use]
+pub fn now() -> Duration {
+    SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default()
+}
+
+/// Returns the current time of a monotonic clock as a [`Duration`] elapsed
+/// since an arbitrary point in time.
+///
+/// Unlike [`now()`], it's not affected by system clock adjustments, so should
+/// be used for measuring durations.
+#[must_use]
+pub fn monotonic_now() -> Duration {
+    /// Arbitrary point in time the returned [`Duration`]s are measured from.
+    static ORIGIN: LazyLock<Instant> = LazyLock::new(Instant::now);
+
+    ORIGIN.elapsed()
+}
+
 /// Function to initialize `dart_api_dl` functions.
 ///
 /// # Safety
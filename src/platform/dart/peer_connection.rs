@@ -4,7 +4,7 @@
 
 use std::rc::Rc;
 
-use derive_more::with_trait::Display;
+use derive_more::with_trait::{Debug, Display};
 use medea_client_api_proto::{
     IceConnectionState, IceServer, PeerConnectionState, stats::RtcStat,
 };
@@ -23,18 +23,21 @@ use super::{
 use crate::{
     media::MediaKind,
     platform::{
+        BundlePolicy, DataChannel, DataChannelError, DataChannelInit,
         IceCandidate, IceCandidateError, RtcPeerConnectionError, RtcStats,
-        RtcStatsError, SdpType,
+        RtcStatsError, RtcpMuxPolicy, SdpType, SignalingState,
         dart::{
             ice_server::RtcIceServers,
             transceiver::Transceiver,
             utils::{
-                callback::Callback, dart_future::FutureFromDart,
-                handle::DartHandle, ice_connection_from_int,
-                peer_connection_state_from_int,
+                NonNullDartValueArgExt as _, callback::Callback,
+                dart_future::FutureFromDart, handle::DartHandle,
+                ice_connection_from_int, peer_connection_state_from_int,
+                signaling_state_from_int,
             },
         },
     },
+    utils::logging::{self, LogCategory},
 };
 
 type RtcPeerConnectionResult<T> = Result<T, Traced<RtcPeerConnectionError>>;
@@ -45,7 +48,7 @@ mod peer_connection {
 
     use dart_sys::Dart_Handle;
 
-    use crate::platform::Error;
+    use crate::{api::DartValueArg, platform::Error};
 
     extern "C" {
         /// Returns [`IceConnectionState`] of the provided [`PeerConnection`].
@@ -150,6 +153,30 @@ mod peer_connection {
             offer: ptr::NonNull<c_char>,
         ) -> Result<Dart_Handle, Error>;
 
+        /// Returns a [`SignalingState`] of the provided [`PeerConnection`].
+        pub fn signaling_state(peer: Dart_Handle) -> Result<i32, Error>;
+
+        /// Sets the provided callback to a [`signalingstatechange`][1] event
+        /// of the provided [`PeerConnection`].
+        ///
+        /// [1]: https://w3.org/TR/webrtc#event-signalingstatechange
+        pub fn on_signaling_state_change(
+            peer: Dart_Handle,
+            cb: Dart_Handle,
+        ) -> Result<(), Error>;
+
+        /// Returns SDP of the local description of the provided
+        /// [`PeerConnection`], if any.
+        pub fn local_description(
+            peer: Dart_Handle,
+        ) -> Result<ptr::NonNull<DartValueArg<Option<String>>>, Error>;
+
+        /// Returns SDP of the remote description of the provided
+        /// [`PeerConnection`], if any.
+        pub fn remote_description(
+            peer: Dart_Handle,
+        ) -> Result<ptr::NonNull<DartValueArg<Option<String>>>, Error>;
+
         /// Closes the provided [`PeerConnection`].
         pub fn close(peer: Dart_Handle) -> Result<(), Error>;
     }
@@ -166,6 +193,10 @@ pub struct RtcPeerConnection {
 impl RtcPeerConnection {
     /// Instantiates a new [`RtcPeerConnection`].
     ///
+    /// Configuring [`BundlePolicy`] and [`RtcpMuxPolicy`] is not supported on
+    /// this platform yet, so only a warning is logged if non-default ones are
+    /// provided.
+    ///
     /// # Errors
     ///
     /// Errors with [`RtcPeerConnectionError::PeerCreationError`] if
@@ -173,10 +204,26 @@ impl RtcPeerConnection {
     pub async fn new<I>(
         ice_servers: I,
         is_force_relayed: bool,
+        bundle_policy: BundlePolicy,
+        rtcp_mux_policy: RtcpMuxPolicy,
     ) -> RtcPeerConnectionResult<Self>
     where
         I: IntoIterator<Item = IceServer>,
     {
+        if bundle_policy != BundlePolicy::default()
+            || rtcp_mux_policy != RtcpMuxPolicy::default()
+        {
+            logging::emit(
+                log::Level::Warn,
+                None,
+                LogCategory::Negotiation,
+                format_args!(
+                    "Configuring `bundlePolicy` and `rtcpMuxPolicy` is not \
+                     supported on this platform",
+                ),
+            );
+        }
+
         let ice_servers = RtcIceServers::from(ice_servers);
         let fut = unsafe {
             peer_connection::new_peer(
@@ -302,6 +349,36 @@ impl RtcPeerConnection {
         }
     }
 
+    /// Sets `handler` for a [RTCDataChannelEvent][1] (see
+    /// [`ondatachannel` callback][2]).
+    ///
+    /// Does nothing, since data channels are not supported on this platform.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcdatachannelevent
+    /// [2]: https://w3.org/TR/webrtc#dom-rtcpeerconnection-ondatachannel
+    pub fn on_data_channel<F>(&self, _: Option<F>)
+    where
+        F: 'static + FnMut(DataChannel),
+    {
+    }
+
+    /// Creates a new [`DataChannel`] with the provided `label` and `init`
+    /// options (see [RTCPeerConnection.createDataChannel()][1]).
+    ///
+    /// # Errors
+    ///
+    /// Always errors with [`DataChannelError::Unsupported`], since data
+    /// channels are not supported on this platform.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-peerconnection-createdatachannel
+    pub fn create_data_channel(
+        &self,
+        _: &str,
+        _: DataChannelInit,
+    ) -> Result<DataChannel, Traced<DataChannelError>> {
+        Err(tracerr::new!(DataChannelError::Unsupported))
+    }
+
     /// Returns [`IceConnectionState`] of this [`RtcPeerConnection`].
     #[must_use]
     pub fn ice_connection_state(&self) -> IceConnectionState {
@@ -320,6 +397,79 @@ impl RtcPeerConnection {
         peer_connection_state_from_int(conn_state)
     }
 
+    /// Sets `handler` for [`SignalingState`] changes (see
+    /// [`signalingstatechange` event][1]).
+    ///
+    /// [1]: https://w3.org/TR/webrtc#event-signalingstatechange
+    pub fn on_signaling_state_change<F>(&self, handler: Option<F>)
+    where
+        F: 'static + FnMut(SignalingState),
+    {
+        if let Some(mut h) = handler {
+            unsafe {
+                peer_connection::on_signaling_state_change(
+                    self.handle.get(),
+                    Callback::from_fn_mut(move |v| {
+                        h(signaling_state_from_int(v));
+                    })
+                    .into_dart(),
+                )
+            }
+            .unwrap();
+        }
+    }
+
+    /// Returns [`SignalingState`] of this [`RtcPeerConnection`].
+    #[must_use]
+    pub fn signaling_state(&self) -> SignalingState {
+        let state =
+            unsafe { peer_connection::signaling_state(self.handle.get()) }
+                .unwrap();
+        signaling_state_from_int(state)
+    }
+
+    /// Returns [`BundlePolicy`] of this [`RtcPeerConnection`].
+    ///
+    /// Configuring [`BundlePolicy`] is not supported on this platform yet, so
+    /// always returns the default one.
+    #[must_use]
+    pub fn bundle_policy(&self) -> BundlePolicy {
+        BundlePolicy::default()
+    }
+
+    /// Returns [`RtcpMuxPolicy`] of this [`RtcPeerConnection`].
+    ///
+    /// Configuring [`RtcpMuxPolicy`] is not supported on this platform yet, so
+    /// always returns the default one.
+    #[must_use]
+    pub fn rtcp_mux_policy(&self) -> RtcpMuxPolicy {
+        RtcpMuxPolicy::default()
+    }
+
+    /// Returns SDP of the [`localDescription`][1] of this
+    /// [`RtcPeerConnection`], if any.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-peerconnection-localdescription
+    #[must_use]
+    pub fn local_description(&self) -> Option<String> {
+        let sdp =
+            unsafe { peer_connection::local_description(self.handle.get()) }
+                .unwrap();
+        unsafe { sdp.unbox() }.try_into().unwrap()
+    }
+
+    /// Returns SDP of the [`remoteDescription`][1] of this
+    /// [`RtcPeerConnection`], if any.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-peerconnection-remotedescription
+    #[must_use]
+    pub fn remote_description(&self) -> Option<String> {
+        let sdp =
+            unsafe { peer_connection::remote_description(self.handle.get()) }
+                .unwrap();
+        unsafe { sdp.unbox() }.try_into().unwrap()
+    }
+
     /// Sets `handler` for an [`iceconnectionstatechange`][1] event.
     ///
     /// [1]: https://w3.org/TR/webrtc#event-iceconnectionstatechange
@@ -365,6 +515,9 @@ impl RtcPeerConnection {
     /// Adds remote [RTCPeerConnection][1]'s [ICE candidate][2] to this
     /// [`RtcPeerConnection`].
     ///
+    /// An empty `candidate` signals the [end-of-candidates][4], and is
+    /// forwarded to the underlying [RTCPeerConnection][1] as is.
+    ///
     /// # Errors
     ///
     /// With [`RtcPeerConnectionError::AddIceCandidateFailed`] if
@@ -373,6 +526,7 @@ impl RtcPeerConnection {
     /// [1]: https://w3.org/TR/webrtc#rtcpeerconnection-interface
     /// [2]: https://tools.ietf.org/html/rfc5245#section-2
     /// [3]: https://w3.org/TR/webrtc#dom-peerconnection-addicecandidate
+    /// [4]: https://w3.org/TR/webrtc#dfn-end-of-candidates
     pub async fn add_ice_candidate(
         &self,
         candidate: &str,
@@ -393,6 +547,29 @@ impl RtcPeerConnection {
         Ok(())
     }
 
+    /// Replaces [`IceServer`]s of this [`RtcPeerConnection`] with the provided
+    /// ones.
+    ///
+    /// Not supported on this platform yet, so only logs a warning.
+    ///
+    /// # Errors
+    ///
+    /// Never errors at the moment.
+    pub fn set_ice_servers<I>(&self, _: I) -> RtcPeerConnectionResult<()>
+    where
+        I: IntoIterator<Item = IceServer>,
+    {
+        logging::emit(
+            log::Level::Warn,
+            None,
+            LogCategory::Ice,
+            format_args!(
+                "Updating ICE servers is not supported on this platform",
+            ),
+        );
+        Ok(())
+    }
+
     /// Marks [`RtcPeerConnection`] to trigger ICE restart.
     ///
     /// After this function returns, the offer returned by the next call to
@@ -463,7 +640,7 @@ impl RtcPeerConnection {
     pub async fn rollback(&self) -> RtcPeerConnectionResult<()> {
         let fut =
             unsafe { peer_connection::rollback(self.handle.get()) }.unwrap();
-        unsafe { FutureFromDart::execute(fut) }
+        unsafe { FutureFromDart::execute::<()>(fut) }
             .await
             .map_err(RtcPeerConnectionError::SetLocalDescriptionFailed)
             .map_err(tracerr::wrap!())
@@ -590,7 +767,7 @@ impl RtcPeerConnection {
             )
         }
         .unwrap();
-        unsafe { FutureFromDart::execute(fut) }
+        unsafe { FutureFromDart::execute::<()>(fut) }
             .await
             .map_err(RtcPeerConnectionError::SetLocalDescriptionFailed)
             .map_err(tracerr::wrap!())
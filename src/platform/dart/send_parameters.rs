@@ -9,7 +9,7 @@ use medea_macro::dart_bridge;
 use super::{
     send_encoding_parameters::SendEncodingParameters, utils::list::DartList,
 };
-use crate::platform::dart::utils::handle::DartHandle;
+use crate::platform::{DegradationPreference, dart::utils::handle::DartHandle};
 
 #[dart_bridge("flutter/lib/src/native/platform/send_parameters.g.dart")]
 mod send_parameters {
@@ -52,6 +52,13 @@ impl SendParameters {
         encodings.into_boxed_slice()
     }
 
+    /// Sets [degradationPreference][1] of these [`SendParameters`].
+    ///
+    /// No-op, since `flutter_webrtc` doesn't support it.
+    ///
+    /// [1]: https://w3.org/TR/webrtc-extensions#dom-rtcrtpsendparameters-degradationpreference
+    pub const fn set_degradation_preference(&self, _: DegradationPreference) {}
+
     /// Returns the underlying [`Dart_Handle`] of these [`SendParameters`].
     #[must_use]
     pub fn handle(&self) -> Dart_Handle {
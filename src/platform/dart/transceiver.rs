@@ -16,7 +16,7 @@ use super::{
 use crate::{
     media::track::local,
     platform::{
-        self, TransceiverDirection,
+        self, DtmfSender, TransceiverDirection,
         dart::utils::{
             dart_future::FutureFromDart, handle::DartHandle, list::DartList,
         },
@@ -121,6 +121,15 @@ impl From<DartHandle> for Transceiver {
 }
 
 impl Transceiver {
+    /// Returns the underlying [`Dart_Handle`] of this [`Transceiver`].
+    ///
+    /// Mutating it directly may desync the state tracked by this crate, so
+    /// it should be used only for features this crate doesn't wrap.
+    #[must_use]
+    pub fn handle(&self) -> Dart_Handle {
+        self.0.get()
+    }
+
     /// Changes the receive direction of the specified [`Transceiver`].
     #[must_use]
     pub fn set_recv(&self, active: bool) -> LocalBoxFuture<'static, ()> {
@@ -198,6 +207,17 @@ impl Transceiver {
         unsafe { (*Box::from_raw(mid.as_ptr())).try_into().unwrap() }
     }
 
+    /// Returns the [currentDirection][1] of this [`Transceiver`] negotiated by
+    /// the last SDP offer/answer exchange, if any.
+    ///
+    /// Always returns [`None`], since it's not exposed on this platform.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtptransceiver-currentdirection
+    #[must_use]
+    pub const fn current_direction(&self) -> Option<TransceiverDirection> {
+        None
+    }
+
     /// Indicates whether the underlying [RTCRtpTransceiver] is stopped.
     ///
     /// [RTCRtpTransceiver]: https://w3.org/TR/webrtc#dom-rtcrtptransceiver
@@ -206,6 +226,17 @@ impl Transceiver {
         unsafe { transceiver::is_stopped(self.0.get()) }.unwrap()
     }
 
+    /// Returns [`DtmfSender`] of the underlying [RTCRtpSender], if it's
+    /// capable of sending DTMF.
+    ///
+    /// Always returns [`None`], since DTMF is not supported on this platform.
+    ///
+    /// [RTCRtpSender]: https://w3.org/TR/webrtc#rtcrtpsender-interface
+    #[must_use]
+    pub const fn dtmf_sender(&self) -> Option<DtmfSender> {
+        None
+    }
+
     /// Returns current [`TransceiverDirection`] of this [`Transceiver`].
     async fn direction(&self) -> TransceiverDirection {
         let fut = unsafe { transceiver::get_direction(self.0.get()) }.unwrap();
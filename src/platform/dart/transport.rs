@@ -12,7 +12,7 @@ use tracerr::Traced;
 
 use crate::{
     platform::{
-        RpcTransport, TransportError, TransportState,
+        RpcCompression, RpcTransport, TransportError, TransportState,
         dart::utils::{
             callback::Callback, dart_future::FutureFromDart,
             dart_string_into_rust, handle::DartHandle, string_into_c_str,
@@ -38,13 +38,18 @@ mod transport {
         /// [Subscribes][2] to the created [`WebSocket`][0] passing the given
         /// `on_message` and `on_close` callbacks.
         ///
+        /// Offers the [permessage-deflate][3] extension to the server if
+        /// `compression` is `true`.
+        ///
         /// [0]: https://api.dart.dev/stable/dart-io/WebSocket-class.html
         /// [1]: https://api.dart.dev/stable/dart-io/WebSocket/connect.html
         /// [2]: https://api.dart.dev/stable/dart-async/Stream/listen.html
+        /// [3]: https://tools.ietf.org/html/rfc7692
         pub fn connect(
             url: ptr::NonNull<c_char>,
             on_message: Dart_Handle,
             on_close: Dart_Handle,
+            compression: bool,
         ) -> Result<Dart_Handle, Error>;
 
         /// [Sends][1] the provided `message` via the provided [`WebSocket`][0].
@@ -107,6 +112,9 @@ pub struct WebSocketRpcTransport {
 
     /// State of this [`WebSocketRpcTransport`] connection.
     socket_state: Rc<ObservableCell<TransportState>>,
+
+    /// [`RpcCompression`] negotiated by this [`WebSocketRpcTransport`].
+    compression: RpcCompression,
 }
 
 impl WebSocketRpcTransport {
@@ -114,6 +122,13 @@ impl WebSocketRpcTransport {
     /// server with the [`RpcTransport::connect()`] method call.
     #[must_use]
     pub fn new() -> Self {
+        Self::with_compression(RpcCompression::Disabled)
+    }
+
+    /// Creates a new [`WebSocketRpcTransport`] negotiating the provided
+    /// [`RpcCompression`] with the server.
+    #[must_use]
+    pub fn with_compression(compression: RpcCompression) -> Self {
         Self {
             handle: RefCell::new(None),
             on_message_subs: Rc::new(RefCell::new(Vec::new())),
@@ -123,6 +138,7 @@ impl WebSocketRpcTransport {
             close_reason: Cell::new(
                 ClientDisconnect::RpcTransportUnexpectedlyDropped,
             ),
+            compression,
         }
     }
 }
@@ -188,6 +204,7 @@ impl RpcTransport for WebSocketRpcTransport {
                     string_into_c_str(url.as_ref().to_owned()),
                     on_message,
                     on_close,
+                    self.compression == RpcCompression::Deflate,
                 )
             }
             .unwrap();
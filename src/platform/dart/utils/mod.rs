@@ -14,6 +14,8 @@ use std::ptr;
 
 use medea_client_api_proto::{IceConnectionState, PeerConnectionState};
 
+use crate::platform::SignalingState;
+
 pub use self::string::{
     c_str_into_string, dart_string_into_rust, free_dart_native_string,
     string_into_c_str,
@@ -66,3 +68,17 @@ pub fn peer_connection_state_from_int(i: i32) -> PeerConnectionState {
         _ => unreachable!(),
     }
 }
+
+/// Returns [`SignalingState`] based on the provided enum index.
+#[must_use]
+pub fn signaling_state_from_int(i: i32) -> SignalingState {
+    match i {
+        0 => SignalingState::Stable,
+        1 => SignalingState::HaveLocalOffer,
+        2 => SignalingState::HaveLocalPranswer,
+        3 => SignalingState::HaveRemoteOffer,
+        4 => SignalingState::HaveRemotePranswer,
+        5 => SignalingState::Closed,
+        _ => unreachable!(),
+    }
+}
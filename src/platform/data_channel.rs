@@ -0,0 +1,44 @@
+//! Platform-agnostic functionality of [RTCDataChannel][1].
+//!
+//! [1]: https://w3.org/TR/webrtc#dom-rtcdatachannel
+
+use derive_more::with_trait::Display;
+
+use crate::{platform, utils::Caused};
+
+/// Representation of [RTCDataChannelInit][1].
+///
+/// [1]: https://w3.org/TR/webrtc#dom-rtcdatachannelinit
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DataChannelInit {
+    /// Indicator whether messages must be delivered in the order they were
+    /// sent.
+    pub ordered: bool,
+
+    /// Maximum number of times a message is retransmitted in an unreliable
+    /// mode.
+    ///
+    /// [`None`] means that messages are retransmitted until delivered.
+    pub max_retransmits: Option<u16>,
+}
+
+impl Default for DataChannelInit {
+    fn default() -> Self {
+        Self { ordered: true, max_retransmits: None }
+    }
+}
+
+/// Errors that may occur when working with a [`platform::DataChannel`].
+#[derive(Caused, Clone, Debug, Display)]
+#[cause(error = platform::Error)]
+pub enum DataChannelError {
+    /// [RTCDataChannel.send()][1] failed.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcdatachannel-send
+    #[display("Failed to send data channel message: {_0}")]
+    SendFailed(platform::Error),
+
+    /// Data channels are not supported by the current platform.
+    #[display("Data channels are not supported on this platform")]
+    Unsupported,
+}
@@ -0,0 +1,108 @@
+//! Platform-agnostic functionality of HTTP requests.
+
+use derive_more::with_trait::Display;
+
+use crate::{platform, utils::Caused};
+
+/// Method of an [`HttpRequest`].
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum HttpMethod {
+    /// `POST` method.
+    #[display("POST")]
+    Post,
+
+    /// `PATCH` method.
+    #[display("PATCH")]
+    Patch,
+
+    /// `DELETE` method.
+    #[display("DELETE")]
+    Delete,
+}
+
+/// HTTP request performed via [`platform::fetch()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HttpRequest {
+    /// [`HttpMethod`] of this [`HttpRequest`].
+    pub method: HttpMethod,
+
+    /// URL this [`HttpRequest`] is sent to.
+    pub url: String,
+
+    /// Headers of this [`HttpRequest`].
+    pub headers: Vec<(String, String)>,
+
+    /// Body of this [`HttpRequest`], if any.
+    pub body: Option<String>,
+}
+
+impl HttpRequest {
+    /// Creates a new [`HttpRequest`] with the provided [`HttpMethod`] to the
+    /// provided `url`, without any headers and body.
+    #[must_use]
+    pub fn new(method: HttpMethod, url: impl Into<String>) -> Self {
+        Self { method, url: url.into(), headers: Vec::new(), body: None }
+    }
+
+    /// Adds the provided header to this [`HttpRequest`].
+    #[must_use]
+    pub fn header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the provided `body` of this [`HttpRequest`] along with its
+    /// `Content-Type`.
+    #[must_use]
+    pub fn body(self, content_type: &str, body: impl Into<String>) -> Self {
+        let mut this = self.header("Content-Type", content_type);
+        this.body = Some(body.into());
+        this
+    }
+}
+
+/// Response to an [`HttpRequest`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HttpResponse {
+    /// Status code of this [`HttpResponse`].
+    pub status: u16,
+
+    /// Headers of this [`HttpResponse`] exposed by the platform.
+    pub headers: Vec<(String, String)>,
+
+    /// Body of this [`HttpResponse`].
+    pub body: String,
+}
+
+impl HttpResponse {
+    /// Indicates whether this [`HttpResponse`] has a successful (`2xx`)
+    /// status code.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Returns value of the header with the provided `name` (matched
+    /// case-insensitively), if any.
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Errors that may occur when performing an [`HttpRequest`].
+#[derive(Caused, Clone, Debug, Display)]
+#[cause(error = platform::Error)]
+pub enum HttpError {
+    /// [`HttpRequest`] failed to be performed (network error, CORS violation,
+    /// etc).
+    #[display("HTTP request failed: {_0}")]
+    RequestFailed(platform::Error),
+}
@@ -0,0 +1,73 @@
+//! Platform-agnostic functionality of [MediaRecorder][1].
+//!
+//! [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+
+use derive_more::with_trait::Display;
+
+use crate::{platform, utils::Caused};
+
+/// Representation of [MediaRecorderOptions][1].
+///
+/// [1]: https://w3.org/TR/mediastream-recording#mediarecorderoptions-section
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MediaRecorderOptions {
+    /// [MIME type][1] of the recording (e.g. `video/webm;codecs=vp9,opus`).
+    ///
+    /// If [`None`], then the platform chooses it on its own.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#dom-mediarecorderoptions-mimetype
+    pub mime_type: Option<String>,
+
+    /// Target [bitrate][1] of the recording (in bits per second), shared
+    /// between its audio and video.
+    ///
+    /// If [`None`], then the platform chooses it on its own.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#dom-mediarecorderoptions-bitspersecond
+    pub bits_per_second: Option<u32>,
+}
+
+/// Event emitted by a [`platform::MediaRecorder`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MediaRecorderEvent {
+    /// Next chunk of the recorded data is available.
+    Data(Vec<u8>),
+
+    /// Recording has been stopped and all its data has been emitted.
+    Stopped,
+}
+
+/// Errors that may occur when working with a [`platform::MediaRecorder`].
+#[derive(Caused, Clone, Debug, Display)]
+#[cause(error = platform::Error)]
+pub enum MediaRecorderError {
+    /// [MediaRecorder][1] is not supported by the current platform.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+    #[display("MediaRecorder is not supported on this platform")]
+    Unsupported,
+
+    /// [MediaRecorder][1] cannot be created with the provided tracks or
+    /// [`MediaRecorderOptions`] (e.g. unsupported MIME type).
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#dom-mediarecorder-mediarecorder
+    #[display("Failed to create MediaRecorder: {_0}")]
+    CreateFailed(platform::Error),
+
+    /// More than one video track is provided, while [MediaRecorder][1]
+    /// records only the first one.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+    #[display("MediaRecorder cannot record more than one video track")]
+    MultipleVideoTracks,
+
+    /// Recording has never been started.
+    #[display("MediaRecorder is not started")]
+    NotStarted,
+
+    /// [MediaRecorder][1] cannot transit into the requested state.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+    #[display("Failed to change MediaRecorder state: {_0}")]
+    InvalidState(platform::Error),
+}
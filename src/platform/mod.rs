@@ -2,6 +2,9 @@
 
 pub mod callback;
 pub mod codec_capability;
+pub mod data_channel;
+pub mod http;
+pub mod media_recorder;
 pub mod peer_connection;
 pub mod rtc_stats;
 pub mod transceiver;
@@ -23,13 +26,19 @@ pub use self::wasm::*;
 pub use self::{
     callback::Callback,
     codec_capability::get_capabilities,
+    data_channel::{DataChannelError, DataChannelInit},
+    http::{HttpError, HttpMethod, HttpRequest, HttpResponse},
+    media_recorder::{
+        MediaRecorderError, MediaRecorderEvent, MediaRecorderOptions,
+    },
     peer_connection::{
-        IceCandidate, IceCandidateError, RtcPeerConnectionError, SdpType,
+        BundlePolicy, IceCandidate, IceCandidateError, RtcPeerConnectionError,
+        RtcpMuxPolicy, SdpType, SignalingState,
     },
     rtc_stats::RtcStatsError,
     send_encoding_parameters::SendEncodingParameters,
-    transceiver::Direction as TransceiverDirection,
-    transport::{RpcTransport, TransportError, TransportState},
+    transceiver::{DegradationPreference, Direction as TransceiverDirection},
+    transport::{RpcCompression, RpcTransport, TransportError, TransportState},
 };
 use crate::utils::Caused;
 
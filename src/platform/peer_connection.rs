@@ -12,7 +12,7 @@ use crate::{
 /// Representation of [RTCSdpType].
 ///
 /// [RTCSdpType]: https://w3.org/TR/webrtc#dom-rtcsdptype
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum SdpType {
     /// [`offer` type][1] of SDP.
     ///
@@ -25,10 +25,109 @@ pub enum SdpType {
     Answer(String),
 }
 
+/// Representation of [RTCBundlePolicy][1].
+///
+/// [1]: https://w3.org/TR/webrtc#dom-rtcbundlepolicy
+#[derive(Clone, Copy, Debug, Default, Display, Eq, PartialEq)]
+pub enum BundlePolicy {
+    /// [`balanced` policy][1]: gather ICE candidates for each media type in
+    /// use, negotiating a separate transport for each of them if the remote
+    /// endpoint isn't BUNDLE-aware.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcbundlepolicy-balanced
+    #[display("balanced")]
+    Balanced,
+
+    /// [`max-compat` policy][1]: gather ICE candidates for each track,
+    /// negotiating a separate transport for each of them if the remote
+    /// endpoint isn't BUNDLE-aware.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcbundlepolicy-max-compat
+    #[display("max-compat")]
+    MaxCompat,
+
+    /// [`max-bundle` policy][1]: gather ICE candidates for only one track,
+    /// bundling all the media onto a single transport.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcbundlepolicy-max-bundle
+    #[default]
+    #[display("max-bundle")]
+    MaxBundle,
+}
+
+/// Representation of [RTCRtcpMuxPolicy][1].
+///
+/// [1]: https://w3.org/TR/webrtc#dom-rtcrtcpmuxpolicy
+#[derive(Clone, Copy, Debug, Default, Display, Eq, PartialEq)]
+pub enum RtcpMuxPolicy {
+    /// `negotiate` policy: gather ICE candidates for both RTP and RTCP, using
+    /// RTCP multiplexing only if the remote endpoint supports it.
+    ///
+    /// Is not a part of the [WebRTC] specification anymore, so may be rejected
+    /// by a platform.
+    ///
+    /// [WebRTC]: https://w3.org/TR/webrtc
+    #[display("negotiate")]
+    Negotiate,
+
+    /// [`require` policy][1]: gather ICE candidates only for RTP, multiplexing
+    /// RTCP atop of it.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtcpmuxpolicy-require
+    #[default]
+    #[display("require")]
+    Require,
+}
+
+/// Representation of [RTCSignalingState][1].
+///
+/// [1]: https://w3.org/TR/webrtc#dom-rtcsignalingstate
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum SignalingState {
+    /// [`stable` state][1]: there is no offer/answer exchange in progress.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcsignalingstate-stable
+    #[display("stable")]
+    Stable,
+
+    /// [`have-local-offer` state][1]: a local offer has been applied.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcsignalingstate-have-local-offer
+    #[display("have-local-offer")]
+    HaveLocalOffer,
+
+    /// [`have-remote-offer` state][1]: a remote offer has been applied.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcsignalingstate-have-remote-offer
+    #[display("have-remote-offer")]
+    HaveRemoteOffer,
+
+    /// [`have-local-pranswer` state][1]: a remote offer and a local
+    /// provisional answer have been applied.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcsignalingstate-have-local-pranswer
+    #[display("have-local-pranswer")]
+    HaveLocalPranswer,
+
+    /// [`have-remote-pranswer` state][1]: a local offer and a remote
+    /// provisional answer have been applied.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcsignalingstate-have-remote-pranswer
+    #[display("have-remote-pranswer")]
+    HaveRemotePranswer,
+
+    /// [`closed` state][1]: the [RTCPeerConnection][2] has been closed.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcsignalingstate-closed
+    /// [2]: https://w3.org/TR/webrtc#dom-rtcpeerconnection
+    #[display("closed")]
+    Closed,
+}
+
 /// [RTCIceCandidate][1] representation.
 ///
 /// [1]: https://w3.org/TR/webrtc#rtcicecandidate-interface
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct IceCandidate {
     /// [`candidate` field][2] of the discovered [RTCIceCandidate][1].
     ///
@@ -150,6 +249,12 @@ pub enum RtcPeerConnectionError {
     #[from(ignore)]
     SetRemoteDescriptionFailed(platform::Error),
 
+    /// Occurs if the configuration of the [`platform::RtcPeerConnection`]
+    /// cannot be changed.
+    #[display("Failed to set PeerConnection configuration: {_0}")]
+    #[from(ignore)]
+    SetConfigurationFailed(platform::Error),
+
     /// [`Transceiver::update_send_encodings`] error.
     #[display("Failed to update sender encodings: {_0}")]
     #[from(ignore)]
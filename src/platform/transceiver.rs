@@ -8,7 +8,12 @@ use medea_client_api_proto as proto;
 #[cfg(target_family = "wasm")]
 use web_sys::RtcRtpTransceiverDirection;
 
-use crate::{media::MediaKind, platform, platform::Transceiver};
+use crate::{
+    media::MediaKind,
+    platform,
+    platform::Transceiver,
+    utils::logging::{self, LogCategory},
+};
 
 bitflags! {
     /// Representation of [RTCRtpTransceiverDirection][1].
@@ -42,6 +47,24 @@ bitflags! {
     }
 }
 
+/// Representation of [RTCDegradationPreference][1].
+///
+/// [1]: https://w3.org/TR/webrtc-extensions#dom-rtcdegradationpreference
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum DegradationPreference {
+    /// Degrade resolution in order to maintain framerate.
+    #[display("maintain-framerate")]
+    MaintainFramerate,
+
+    /// Degrade framerate in order to maintain resolution.
+    #[display("maintain-resolution")]
+    MaintainResolution,
+
+    /// Degrade both framerate and resolution in a balanced way.
+    #[display("balanced")]
+    Balanced,
+}
+
 #[expect(clippy::allow_attributes, reason = "`#[expect]` is not considered")]
 #[allow(clippy::multiple_inherent_impl, reason = "multiplatform structure")]
 impl Transceiver {
@@ -125,25 +148,60 @@ pub enum UpdateSendEncodingError {
     SetSenderParameters(platform::Error),
 }
 
+/// List of auxiliary "codecs" which are kept in codec preferences of every
+/// [`platform::Transceiver`] along with the preferred ones.
+const AUXILIARY_CODECS: [&str; 4] =
+    ["video/rtx", "video/red", "video/ulpfec", "audio/telephone-event"];
+
+/// Default values of [`proto::Codec`] parameters, which may be omitted by
+/// [`platform::CodecCapability`]s.
+const DEFAULT_PARAMS: [(&str, &str); 6] = [
+    ("profile-id", "0"), // VP9
+    ("packetization-mode", "0"),
+    ("profile-level-id", "42001f"), // H264
+    ("profile", "0"),
+    ("level-idx", "5"),
+    ("tier", "0"), // AV1
+];
+
+/// Indicates whether the provided [`platform::CodecCapability`] satisfies the
+/// provided target [`proto::Codec`].
+fn is_matching_codec(
+    cap: &platform::CodecCapability,
+    target: &proto::Codec,
+) -> bool {
+    if cap.mime_type() != target.mime_type
+        || cap.channels() != target.channels
+        || cap.clock_rate() != target.clock_rate
+    {
+        return false;
+    }
+
+    let cap_params = cap.parameters();
+    target.parameters.iter().all(|(k, v)| {
+        cap_params.get(k) == Some(v)
+            || DEFAULT_PARAMS.iter().any(|(dk, dv)| k == dk && v == dv)
+    })
+}
+
+/// Appends [`AUXILIARY_CODECS`] from the provided `caps` to the provided
+/// codec preferences list.
+fn append_auxiliary_codecs(
+    result: &mut Vec<platform::CodecCapability>,
+    caps: Vec<platform::CodecCapability>,
+) {
+    for cap in caps {
+        if AUXILIARY_CODECS.contains(&cap.mime_type().as_str()) {
+            result.push(cap);
+        }
+    }
+}
+
 /// Constructs codec preferences list based on the provided target
 /// [`proto::Codec`]s.
 pub async fn probe_target_codecs(
     target_codecs: impl IntoIterator<Item = &proto::Codec>,
 ) -> Option<Vec<platform::CodecCapability>> {
-    /// List of required "codecs" for every [`MediaKind::Video`] of a
-    /// [`platform::Transceiver`].
-    const REQUIRED_CODECS: [&str; 3] =
-        ["video/rtx", "video/red", "video/ulpfec"];
-
-    const DEFAULT_PARAMS: [(&str, &str); 6] = [
-        ("profile-id", "0"), // VP9
-        ("packetization-mode", "0"),
-        ("profile-level-id", "42001f"), // H264
-        ("profile", "0"),
-        ("level-idx", "5"),
-        ("tier", "0"), // AV1
-    ];
-
     let caps = platform::CodecCapability::get_sender_codec_capabilities(
         MediaKind::Video,
     )
@@ -152,41 +210,74 @@ pub async fn probe_target_codecs(
 
     let mut result = Vec::new();
     for target in target_codecs {
-        'cap: for cap in &caps {
-            if cap.mime_type() != target.mime_type
-                || cap.channels() != target.channels
-                || cap.clock_rate() != target.clock_rate
-            {
-                continue;
-            }
-
-            let cap_params = cap.parameters();
-
-            #[expect(clippy::iter_over_hash_type, reason = "doesn't matter")]
-            for (k, v) in &target.parameters {
-                if cap_params.get(k) != Some(v)
-                    && !DEFAULT_PARAMS.iter().any(|(dk, dv)| k == dk && v == dv)
-                {
-                    continue 'cap;
-                }
-            }
-
-            result.push(cap.clone());
-        }
+        result.extend(
+            caps.iter().filter(|cap| is_matching_codec(cap, target)).cloned(),
+        );
     }
     if result.is_empty() {
         None
     } else {
-        for cap in caps {
-            if REQUIRED_CODECS.contains(&cap.mime_type().as_str()) {
-                result.push(cap);
-            }
-        }
+        append_auxiliary_codecs(&mut result, caps);
 
         Some(result)
     }
 }
 
+/// Constructs codec preferences list of the provided [`MediaKind`] following
+/// the order of the provided preferred [`proto::Codec`]s, for a
+/// [`Transceiver`] of the provided [`Direction`].
+///
+/// Preferred [`proto::Codec`]s are probed against the
+/// [RTCRtpSender.getCapabilities()][1] if the [`Direction`] is sending, or
+/// against the [RTCRtpReceiver.getCapabilities()][2] otherwise. The missing
+/// ones are dropped with a warning. Empty list is returned if none of them are
+/// supported, which resets codec preferences.
+///
+/// [1]: https://w3.org/TR/webrtc#dom-rtcrtpsender-getcapabilities
+/// [2]: https://w3.org/TR/webrtc#dom-rtcrtpreceiver-getcapabilities
+pub async fn probe_preferred_codecs(
+    kind: MediaKind,
+    direction: Direction,
+    preferred: &[proto::Codec],
+) -> Vec<platform::CodecCapability> {
+    if preferred.is_empty() {
+        return Vec::new();
+    }
+    let caps = if direction.contains(Direction::SEND) {
+        platform::CodecCapability::get_sender_codec_capabilities(kind).await
+    } else {
+        platform::CodecCapability::get_receiver_codec_capabilities(kind).await
+    };
+    let Ok(caps) = caps else {
+        return Vec::new();
+    };
+
+    let mut result = Vec::new();
+    for codec in preferred {
+        let len = result.len();
+        result.extend(
+            caps.iter().filter(|cap| is_matching_codec(cap, codec)).cloned(),
+        );
+        if result.len() == len {
+            logging::emit(
+                log::Level::Warn,
+                None,
+                LogCategory::Media,
+                format_args!(
+                    "Preferred {kind} codec `{}` is not supported and is \
+                     dropped",
+                    codec.mime_type,
+                ),
+            );
+        }
+    }
+    if !result.is_empty() {
+        append_auxiliary_codecs(&mut result, caps);
+    }
+
+    result
+}
+
 #[cfg(not(target_family = "wasm"))]
 impl From<Direction> for i64 {
     fn from(from: Direction) -> Self {
@@ -15,7 +15,7 @@ use crate::{
 };
 
 /// Possible states of a [`RpcTransport`].
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TransportState {
     /// Socket has been created. The connection is not opened yet.
     Connecting,
@@ -36,11 +36,51 @@ pub enum TransportState {
 impl TransportState {
     /// Indicates whether the socket can be closed.
     #[must_use]
-    pub const fn can_close(self) -> bool {
+    pub const fn can_close(&self) -> bool {
         matches!(self, Self::Connecting | Self::Open)
     }
 }
 
+/// Compression of messages sent via a [`RpcTransport`].
+///
+/// Compression is negotiated with a server via the [permessage-deflate][1]
+/// [WebSocket] extension, so messages are sent uncompressed if the server
+/// doesn't support it.
+///
+/// Browsers negotiate [permessage-deflate][1] on their own and don't allow to
+/// configure it, so on the Web platform this option has no effect.
+///
+/// # Overhead
+///
+/// DEFLATE doesn't pay off on tiny messages, but the compression context is
+/// kept between messages, so repeating signalling messages shrink
+/// significantly. Sizes (in bytes) of compressed payloads measured on
+/// representative messages:
+///
+/// | Message                      | Raw  | First | Repeated |
+/// |------------------------------|------|-------|----------|
+/// | [`ClientMsg::Pong`]          | 11   | 13    | 7        |
+/// | `SetIceCandidate` command    | 292  | 196   | 14       |
+/// | `MakeSdpOffer` command       | 3875 | 959   | 45       |
+///
+/// Each compressed message also costs CPU time on both sides, so disabling
+/// compression might be preferable on fast networks.
+///
+/// [1]: https://tools.ietf.org/html/rfc7692
+/// [WebSocket]: https://developer.mozilla.org/docs/Web/API/WebSocket
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RpcCompression {
+    /// Messages are sent uncompressed.
+    #[default]
+    Disabled,
+
+    /// [permessage-deflate][1] extension is offered to a server, and used if
+    /// it accepts it.
+    ///
+    /// [1]: https://tools.ietf.org/html/rfc7692
+    Deflate,
+}
+
 /// RPC transport between a client and a server.
 #[async_trait(?Send)]
 #[cfg_attr(feature = "mockable", mockall::automock)]
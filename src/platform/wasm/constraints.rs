@@ -9,7 +9,7 @@ use web_sys::{
 use crate::media::{
     AudioTrackConstraints, DeviceVideoTrackConstraints,
     DisplayVideoTrackConstraints,
-    constraints::{ConstrainBoolean, ConstrainString, ConstrainU32},
+    constraints::{ConstrainBoolean, ConstrainString, ConstrainU32Range},
 };
 
 /// [MediaStreamConstraints][1] wrapper.
@@ -70,6 +70,10 @@ impl From<AudioTrackConstraints> for MediaTrackConstraints {
             constraints
                 .set_noise_suppression(&ConstrainBooleanParameters::from(ns));
         }
+        if let Some(channel_count) = track_constraints.channel_count {
+            constraints
+                .set_channel_count(&ConstrainDoubleRange::from(channel_count));
+        }
 
         constraints
     }
@@ -94,21 +98,28 @@ impl From<DeviceVideoTrackConstraints> for MediaTrackConstraints {
         if let Some(height) = track_constraints.height {
             constraints.set_height(&ConstrainDoubleRange::from(height));
         }
+        if let Some(frame_rate) = track_constraints.frame_rate {
+            constraints.set_frame_rate(&ConstrainDoubleRange::from(frame_rate));
+        }
 
         constraints
     }
 }
 
-impl From<ConstrainU32> for ConstrainDoubleRange {
-    fn from(from: ConstrainU32) -> Self {
+impl From<ConstrainU32Range> for ConstrainDoubleRange {
+    fn from(from: ConstrainU32Range) -> Self {
         let constraint = Self::new();
-        match from {
-            ConstrainU32::Exact(val) => constraint.set_exact(f64::from(val)),
-            ConstrainU32::Ideal(val) => constraint.set_ideal(f64::from(val)),
-            ConstrainU32::Range(min, max) => {
-                constraint.set_min(f64::from(min));
-                constraint.set_max(f64::from(max));
-            }
+        if let Some(exact) = from.exact {
+            constraint.set_exact(f64::from(exact));
+        }
+        if let Some(min) = from.min {
+            constraint.set_min(f64::from(min));
+        }
+        if let Some(ideal) = from.ideal {
+            constraint.set_ideal(f64::from(ideal));
+        }
+        if let Some(max) = from.max {
+            constraint.set_max(f64::from(max));
         }
         constraint
     }
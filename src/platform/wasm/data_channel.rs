@@ -0,0 +1,224 @@
+//! Wrapper around [RTCDataChannel][1].
+//!
+//! [1]: https://w3.org/TR/webrtc#dom-rtcdatachannel
+
+#![expect(clippy::unwrap_used, reason = "JS interop error is unexpected")]
+
+use std::{cell::RefCell, rc::Rc};
+
+use js_sys::{ArrayBuffer, Uint8Array};
+use tracerr::Traced;
+use wasm_bindgen::JsCast as _;
+use web_sys::{
+    Event, MessageEvent, RtcDataChannel as SysRtcDataChannel,
+    RtcDataChannelState, RtcDataChannelType,
+};
+
+use crate::{
+    platform::{
+        DataChannelError, data_channel::DataChannelInit,
+        wasm::utils::EventListener,
+    },
+    utils::logging::{self, LogCategory},
+};
+
+/// Representation of [RTCDataChannel][1].
+///
+/// [1]: https://w3.org/TR/webrtc#dom-rtcdatachannel
+#[derive(Debug)]
+pub struct DataChannel {
+    /// Underlying [RTCDataChannel][1].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcdatachannel
+    channel: Rc<SysRtcDataChannel>,
+
+    /// [`onmessage`][1] callback of the underlying [RTCDataChannel][2].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcdatachannel-onmessage
+    /// [2]: https://w3.org/TR/webrtc#dom-rtcdatachannel
+    on_message: RefCell<Option<EventListener<SysRtcDataChannel, MessageEvent>>>,
+
+    /// [`onopen`][1] callback of the underlying [RTCDataChannel][2].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcdatachannel-onopen
+    /// [2]: https://w3.org/TR/webrtc#dom-rtcdatachannel
+    on_open: RefCell<Option<EventListener<SysRtcDataChannel, Event>>>,
+
+    /// [`onclose`][1] callback of the underlying [RTCDataChannel][2].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcdatachannel-onclose
+    /// [2]: https://w3.org/TR/webrtc#dom-rtcdatachannel
+    on_close: RefCell<Option<EventListener<SysRtcDataChannel, Event>>>,
+}
+
+impl DataChannel {
+    /// Returns [`label`][1] of this [`DataChannel`].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-datachannel-label
+    #[must_use]
+    pub fn label(&self) -> String {
+        self.channel.label()
+    }
+
+    /// Indicates whether this [`DataChannel`] is [open][1], so messages can be
+    /// sent via it.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcdatachannelstate-open
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        self.channel.ready_state() == RtcDataChannelState::Open
+    }
+
+    /// Sends the provided binary `data` to the remote peer.
+    ///
+    /// # Errors
+    ///
+    /// With [`DataChannelError::SendFailed`] if [RTCDataChannel.send()][1]
+    /// fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcdatachannel-send
+    pub fn send(&self, data: &[u8]) -> Result<(), Traced<DataChannelError>> {
+        self.channel
+            .send_with_u8_array(data)
+            .map_err(Into::into)
+            .map_err(DataChannelError::SendFailed)
+            .map_err(tracerr::wrap!())
+    }
+
+    /// Sets handler for a [`message`][1] event of this [`DataChannel`].
+    ///
+    /// Textual messages are passed to the handler as UTF-8 bytes.
+    ///
+    /// # Panics
+    ///
+    /// If binding to the [`message`][1] event fails. Not supposed to ever
+    /// happen.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#event-datachannel-message
+    pub fn on_message<F>(&self, f: Option<F>)
+    where
+        F: 'static + FnMut(Vec<u8>),
+    {
+        let mut on_message = self.on_message.borrow_mut();
+        drop(match f {
+            None => on_message.take(),
+            Some(mut f) => {
+                on_message.replace(
+                    // Unwrapping is OK here, because this function shouldn't
+                    // error ever.
+                    EventListener::new_mut(
+                        Rc::clone(&self.channel),
+                        "message",
+                        move |msg: MessageEvent| {
+                            let data = msg.data();
+                            if let Some(text) = data.as_string() {
+                                f(text.into_bytes());
+                            } else if let Ok(buf) =
+                                data.dyn_into::<ArrayBuffer>()
+                            {
+                                f(Uint8Array::new(&buf).to_vec());
+                            } else {
+                                logging::emit(
+                                    log::Level::Error,
+                                    None,
+                                    LogCategory::Media,
+                                    format_args!(
+                                        "Unexpected data channel message type",
+                                    ),
+                                );
+                            }
+                        },
+                    )
+                    .unwrap(),
+                )
+            }
+        });
+    }
+
+    /// Sets handler for an [`open`][1] event of this [`DataChannel`].
+    ///
+    /// # Panics
+    ///
+    /// If binding to the [`open`][1] event fails. Not supposed to ever happen.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#event-datachannel-open
+    pub fn on_open<F>(&self, f: Option<F>)
+    where
+        F: 'static + FnMut(),
+    {
+        let mut on_open = self.on_open.borrow_mut();
+        drop(match f {
+            None => on_open.take(),
+            Some(mut f) => {
+                on_open.replace(
+                    // Unwrapping is OK here, because this function shouldn't
+                    // error ever.
+                    EventListener::new_mut(
+                        Rc::clone(&self.channel),
+                        "open",
+                        move |_: Event| f(),
+                    )
+                    .unwrap(),
+                )
+            }
+        });
+    }
+
+    /// Sets handler for a [`close`][1] event of this [`DataChannel`].
+    ///
+    /// # Panics
+    ///
+    /// If binding to the [`close`][1] event fails. Not supposed to ever
+    /// happen.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#event-datachannel-close
+    pub fn on_close<F>(&self, f: Option<F>)
+    where
+        F: 'static + FnMut(),
+    {
+        let mut on_close = self.on_close.borrow_mut();
+        drop(match f {
+            None => on_close.take(),
+            Some(mut f) => {
+                on_close.replace(
+                    // Unwrapping is OK here, because this function shouldn't
+                    // error ever.
+                    EventListener::new_mut(
+                        Rc::clone(&self.channel),
+                        "close",
+                        move |_: Event| f(),
+                    )
+                    .unwrap(),
+                )
+            }
+        });
+    }
+
+    /// Closes this [`DataChannel`].
+    pub fn close(&self) {
+        self.channel.close();
+    }
+}
+
+impl From<SysRtcDataChannel> for DataChannel {
+    fn from(channel: SysRtcDataChannel) -> Self {
+        channel.set_binary_type(RtcDataChannelType::Arraybuffer);
+        Self {
+            channel: Rc::new(channel),
+            on_message: RefCell::new(None),
+            on_open: RefCell::new(None),
+            on_close: RefCell::new(None),
+        }
+    }
+}
+
+impl From<DataChannelInit> for web_sys::RtcDataChannelInit {
+    fn from(init: DataChannelInit) -> Self {
+        let sys_init = Self::new();
+        sys_init.set_ordered(init.ordered);
+        if let Some(max_retransmits) = init.max_retransmits {
+            sys_init.set_max_retransmits(max_retransmits);
+        }
+        sys_init
+    }
+}
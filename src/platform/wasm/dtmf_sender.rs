@@ -0,0 +1,51 @@
+//! Wrapper around [RTCDTMFSender][1].
+//!
+//! [1]: https://w3.org/TR/webrtc#rtcdtmfsender
+
+use std::time::Duration;
+
+use derive_more::with_trait::From;
+use web_sys::RtcdtmfSender;
+
+use crate::platform;
+
+/// Wrapper around [RTCDTMFSender][1].
+///
+/// [1]: https://w3.org/TR/webrtc#rtcdtmfsender
+#[derive(Clone, Debug, From)]
+pub struct DtmfSender(RtcdtmfSender);
+
+impl DtmfSender {
+    /// Schedules the provided DTMF `tones` to be sent (see
+    /// [RTCDTMFSender.insertDTMF()][1]).
+    ///
+    /// # Errors
+    ///
+    /// With [`platform::Error`] if the underlying [insertDTMF()][1] call
+    /// fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcdtmfsender-insertdtmf
+    pub fn insert_dtmf(
+        &self,
+        tones: &str,
+        duration: Duration,
+        inter_tone_gap: Duration,
+    ) -> Result<(), platform::Error> {
+        self.0
+            .insert_dtmf_with_duration_and_inter_tone_gap(
+                tones,
+                u32::try_from(duration.as_millis()).unwrap_or(u32::MAX),
+                u32::try_from(inter_tone_gap.as_millis()).unwrap_or(u32::MAX),
+            )
+            .map_err(Into::into)
+    }
+
+    /// Returns [`toneBuffer`][1] of this [`DtmfSender`], containing the tones
+    /// remaining to be played out.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcdtmfsender-tonebuffer
+    #[must_use]
+    pub fn tone_buffer(&self) -> String {
+        self.0.tone_buffer()
+    }
+}
@@ -1,6 +1,6 @@
 //! More convenient wrapper for [`js_sys::Error`].
 
-use derive_more::with_trait::{Display, From};
+use derive_more::with_trait::{Display, From, Into};
 use wasm_bindgen::{
     JsCast as _, JsValue,
     convert::{IntoWasmAbi, OptionIntoWasmAbi},
@@ -8,7 +8,7 @@ use wasm_bindgen::{
 };
 
 /// Wrapper for JS value which returned from JS side as error.
-#[derive(Clone, Debug, Display, Eq, From, PartialEq)]
+#[derive(Clone, Debug, Display, Eq, From, Into, PartialEq)]
 #[display("{}", _0.to_string())] // this method is not `ToString::to_string()`
 pub struct Error(js_sys::Error);
 
@@ -19,12 +19,52 @@ impl Error {
     pub fn message(&self) -> String {
         self.0.message().into()
     }
+
+    /// Returns a name of the error.
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.0.name().into()
+    }
+
+    /// Converts the provided [OverconstrainedError][1] into an [`Error`]
+    /// mentioning the unsatisfied constraint in its message, as browsers tend
+    /// to leave it empty.
+    ///
+    /// Returns [`None`] if the provided `err` is not an
+    /// [OverconstrainedError][1].
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#overconstrainederror-interface
+    fn from_overconstrained(err: &js_sys::Error) -> Option<Self> {
+        const NAME: &str = "OverconstrainedError";
+
+        if err.name() != NAME {
+            return None;
+        }
+        let constraint = js_sys::Reflect::get(err, &"constraint".into())
+            .ok()?
+            .as_string()?;
+        let message = String::from(err.message());
+
+        let overconstrained = js_sys::Error::new(&if message.is_empty() {
+            format!("Constraint `{constraint}` cannot be satisfied")
+        } else {
+            format!("Constraint `{constraint}` cannot be satisfied: {message}")
+        });
+        overconstrained.set_name(NAME);
+        _ = js_sys::Reflect::set(
+            &overconstrained,
+            &"constraint".into(),
+            &constraint.into(),
+        );
+
+        Some(Self(overconstrained))
+    }
 }
 
 impl From<JsValue> for Error {
     fn from(val: JsValue) -> Self {
         match val.dyn_into::<js_sys::Error>() {
-            Ok(err) => Self(err),
+            Ok(err) => Self::from_overconstrained(&err).unwrap_or(Self(err)),
             Err(val) => val.as_string().map_or_else(
                 || Self(js_sys::Error::new(&format!("{val:?}"))),
                 |msg| Self(js_sys::Error::new(&msg)),
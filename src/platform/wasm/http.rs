@@ -0,0 +1,63 @@
+//! HTTP requests performed via [Fetch API][1].
+//!
+//! [1]: https://fetch.spec.whatwg.org
+
+use js_sys::Array;
+use tracerr::Traced;
+use wasm_bindgen::{JsCast as _, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Headers, Request, RequestInit, Response};
+
+use super::window;
+use crate::platform::{HttpError, HttpRequest, HttpResponse};
+
+/// Performs the provided [`HttpRequest`] via [fetch()][1].
+///
+/// Only [CORS-safelisted][2] response headers and the ones listed in the
+/// `Access-Control-Expose-Headers` are available in the [`HttpResponse`].
+///
+/// # Errors
+///
+/// With [`HttpError::RequestFailed`] if [fetch()][1] fails.
+///
+/// [1]: https://fetch.spec.whatwg.org#fetch-method
+/// [2]: https://fetch.spec.whatwg.org#cors-safelisted-response-header-name
+pub async fn fetch(
+    request: HttpRequest,
+) -> Result<HttpResponse, Traced<HttpError>> {
+    let failed = |e: JsValue| tracerr::new!(HttpError::RequestFailed(e.into()));
+
+    let headers = Headers::new().map_err(failed)?;
+    for (name, value) in &request.headers {
+        headers.set(name, value).map_err(failed)?;
+    }
+    let init = RequestInit::new();
+    init.set_method(&request.method.to_string());
+    init.set_headers(&headers);
+    if let Some(body) = &request.body {
+        init.set_body(&JsValue::from_str(body));
+    }
+    let req =
+        Request::new_with_str_and_init(&request.url, &init).map_err(failed)?;
+
+    let response: Response = JsFuture::from(window().fetch_with_request(&req))
+        .await
+        .map_err(failed)?
+        .unchecked_into();
+    let body = JsFuture::from(response.text().map_err(failed)?)
+        .await
+        .map_err(failed)?
+        .as_string()
+        .unwrap_or_default();
+    let headers = js_sys::try_iter(&response.headers())
+        .map_err(failed)?
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let entry = Array::from(&entry.ok()?);
+            Some((entry.get(0).as_string()?, entry.get(1).as_string()?))
+        })
+        .collect();
+
+    Ok(HttpResponse { status: response.status(), headers, body })
+}
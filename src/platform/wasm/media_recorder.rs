@@ -0,0 +1,319 @@
+//! Wrapper around [MediaRecorder][1].
+//!
+//! [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+
+#![expect(clippy::unwrap_used, reason = "JS interop error is unexpected")]
+
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use futures::{StreamExt as _, channel::mpsc, stream::LocalBoxStream};
+use js_sys::{ArrayBuffer, Uint8Array};
+use tracerr::Traced;
+use wasm_bindgen::{JsCast as _, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    AudioContext, Blob, BlobEvent, Event, MediaRecorder as SysMediaRecorder,
+    MediaRecorderOptions as SysMediaRecorderOptions, MediaStream,
+};
+
+use crate::{
+    media::MediaKind,
+    platform::{
+        self, MediaRecorderError, MediaRecorderEvent, MediaRecorderOptions,
+        MediaStreamTrack, wasm::utils::EventListener,
+    },
+    utils::logging::{self, LogCategory},
+};
+
+/// Event of a [MediaRecorder][1], whose data isn't read yet.
+///
+/// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+enum RawEvent {
+    /// [`dataavailable`][1] event with the recorded [`Blob`].
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#eventdef-mediarecorder-dataavailable
+    Data(Blob),
+
+    /// [`stop`][1] event.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#eventdef-mediarecorder-stop
+    Stopped,
+}
+
+/// Representation of [MediaRecorder][1].
+///
+/// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+#[derive(Debug)]
+pub struct MediaRecorder {
+    /// Underlying [MediaRecorder][1].
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+    recorder: Rc<SysMediaRecorder>,
+
+    /// [`AudioContext`] mixing multiple recorded audio tracks into a single
+    /// one, if there are any.
+    audio_mixer: Option<AudioContext>,
+
+    /// Subscribers to the [`MediaRecorderEvent`]s of this [`MediaRecorder`].
+    subs: Rc<RefCell<Vec<mpsc::UnboundedSender<MediaRecorderEvent>>>>,
+
+    /// [`dataavailable`][1] event listener of the underlying
+    /// [MediaRecorder][2].
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#eventdef-mediarecorder-dataavailable
+    /// [2]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+    _on_data: EventListener<SysMediaRecorder, BlobEvent>,
+
+    /// [`stop`][1] event listener of the underlying [MediaRecorder][2].
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#eventdef-mediarecorder-stop
+    /// [2]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+    _on_stop: EventListener<SysMediaRecorder, Event>,
+}
+
+impl MediaRecorder {
+    /// Creates a new [`MediaRecorder`] recording the provided
+    /// [`MediaStreamTrack`]s with the provided [`MediaRecorderOptions`].
+    ///
+    /// # Errors
+    ///
+    /// With [`MediaRecorderError::Unsupported`] if [MediaRecorder][1] is not
+    /// supported by the browser.
+    ///
+    /// With [`MediaRecorderError::CreateFailed`] if the browser rejects the
+    /// provided [`MediaStreamTrack`]s or [`MediaRecorderOptions`].
+    ///
+    /// # Panics
+    ///
+    /// If binding to the [MediaRecorder][1] events fails. Not supposed to ever
+    /// happen.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+    pub fn new(
+        tracks: &[&MediaStreamTrack],
+        options: &MediaRecorderOptions,
+    ) -> Result<Self, Traced<MediaRecorderError>> {
+        let is_supported = js_sys::Reflect::has(
+            &platform::window(),
+            &JsValue::from_str("MediaRecorder"),
+        )
+        .unwrap_or(false);
+        if !is_supported {
+            return Err(tracerr::new!(MediaRecorderError::Unsupported));
+        }
+
+        let create_failed = |e: JsValue| {
+            tracerr::new!(MediaRecorderError::CreateFailed(e.into()))
+        };
+
+        let stream = MediaStream::new().map_err(create_failed)?;
+        let (audio_tracks, video_tracks): (Vec<_>, Vec<_>) =
+            tracks.iter().partition(|t| t.kind() == MediaKind::Audio);
+        for track in video_tracks {
+            stream.add_track(track.as_ref());
+        }
+        // `MediaRecorder` records only the first audio track of a stream, so
+        // multiple ones are mixed into a single one.
+        let audio_mixer = if audio_tracks.len() > 1 {
+            let mixer = AudioContext::new().map_err(create_failed)?;
+            let dest = mixer
+                .create_media_stream_destination()
+                .map_err(create_failed)?;
+            for track in audio_tracks {
+                let src_stream = MediaStream::new().map_err(create_failed)?;
+                src_stream.add_track(track.as_ref());
+                _ = mixer
+                    .create_media_stream_source(&src_stream)
+                    .and_then(|src| src.connect_with_audio_node(&dest))
+                    .map_err(create_failed)?;
+            }
+            for track in dest.stream().get_audio_tracks().iter() {
+                stream.add_track(&track.unchecked_into());
+            }
+            Some(mixer)
+        } else {
+            for track in audio_tracks {
+                stream.add_track(track.as_ref());
+            }
+            None
+        };
+        let sys_options = SysMediaRecorderOptions::new();
+        if let Some(mime_type) = &options.mime_type {
+            sys_options.set_mime_type(mime_type);
+        }
+        if let Some(bits_per_second) = options.bits_per_second {
+            sys_options.set_bits_per_second(bits_per_second);
+        }
+        let recorder = Rc::new(
+            SysMediaRecorder::new_with_media_stream_and_media_recorder_options(
+                &stream,
+                &sys_options,
+            )
+            .map_err(create_failed)?,
+        );
+
+        // Blobs are read asynchronously, so they're queued to emit the
+        // recorded data in order, and the stop event after all of it.
+        let (events_tx, events_rx) = mpsc::unbounded();
+        let on_data =
+            EventListener::new_mut(Rc::clone(&recorder), "dataavailable", {
+                let events_tx = events_tx.clone();
+                move |e: BlobEvent| {
+                    if let Some(blob) = e.data() {
+                        _ = events_tx.unbounded_send(RawEvent::Data(blob));
+                    }
+                }
+            })
+            .unwrap();
+        let on_stop = EventListener::new_mut(
+            Rc::clone(&recorder),
+            "stop",
+            move |_: Event| {
+                _ = events_tx.unbounded_send(RawEvent::Stopped);
+            },
+        )
+        .unwrap();
+
+        let subs = Rc::new(RefCell::new(Vec::new()));
+        platform::spawn({
+            let subs = Rc::clone(&subs);
+            let mut events = events_rx.then(async |event| match event {
+                RawEvent::Data(blob) => {
+                    let data = JsFuture::from(blob.array_buffer())
+                        .await
+                        .ok()
+                        .and_then(|buf| buf.dyn_into::<ArrayBuffer>().ok())
+                        .map(|buf| Uint8Array::new(&buf).to_vec());
+                    data.map(MediaRecorderEvent::Data)
+                }
+                RawEvent::Stopped => Some(MediaRecorderEvent::Stopped),
+            });
+            async move {
+                while let Some(event) = events.next().await {
+                    let Some(event) = event else {
+                        logging::emit(
+                            log::Level::Error,
+                            None,
+                            LogCategory::Media,
+                            format_args!("Failed to read recorded data"),
+                        );
+                        continue;
+                    };
+                    subs.borrow_mut().retain(
+                        |sub: &mpsc::UnboundedSender<MediaRecorderEvent>| {
+                            sub.unbounded_send(event.clone()).is_ok()
+                        },
+                    );
+                }
+            }
+        });
+
+        Ok(Self {
+            recorder,
+            audio_mixer,
+            subs,
+            _on_data: on_data,
+            _on_stop: on_stop,
+        })
+    }
+
+    /// Returns a [`LocalBoxStream`] of [`MediaRecorderEvent`]s of this
+    /// [`MediaRecorder`].
+    #[must_use]
+    pub fn events(&self) -> LocalBoxStream<'static, MediaRecorderEvent> {
+        let (tx, rx) = mpsc::unbounded();
+        self.subs.borrow_mut().push(tx);
+        Box::pin(rx)
+    }
+
+    /// Indicates whether this [`MediaRecorder`] is recording or paused (see
+    /// [MediaRecorder.state][1]).
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#dom-mediarecorder-state
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.recorder.state() != web_sys::RecordingState::Inactive
+    }
+
+    /// Starts recording (see [MediaRecorder.start()][1]).
+    ///
+    /// If a `timeslice` is provided, then the recorded data is emitted in
+    /// chunks of the provided duration, otherwise it's emitted as a single
+    /// chunk once the recording is stopped.
+    ///
+    /// # Errors
+    ///
+    /// With [`MediaRecorderError::InvalidState`] if the recording is started
+    /// already.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#dom-mediarecorder-start
+    pub fn start(
+        &self,
+        timeslice: Option<Duration>,
+    ) -> Result<(), Traced<MediaRecorderError>> {
+        match timeslice {
+            Some(timeslice) => self.recorder.start_with_time_slice(
+                timeslice.as_millis().try_into().unwrap_or(i32::MAX),
+            ),
+            None => self.recorder.start(),
+        }
+        .map_err(|e| tracerr::new!(MediaRecorderError::InvalidState(e.into())))
+    }
+
+    /// Pauses recording (see [MediaRecorder.pause()][1]).
+    ///
+    /// # Errors
+    ///
+    /// With [`MediaRecorderError::InvalidState`] if the recording is not
+    /// started.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#dom-mediarecorder-pause
+    pub fn pause(&self) -> Result<(), Traced<MediaRecorderError>> {
+        self.recorder.pause().map_err(|e| {
+            tracerr::new!(MediaRecorderError::InvalidState(e.into()))
+        })
+    }
+
+    /// Resumes paused recording (see [MediaRecorder.resume()][1]).
+    ///
+    /// # Errors
+    ///
+    /// With [`MediaRecorderError::InvalidState`] if the recording is not
+    /// started.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#dom-mediarecorder-resume
+    pub fn resume(&self) -> Result<(), Traced<MediaRecorderError>> {
+        self.recorder.resume().map_err(|e| {
+            tracerr::new!(MediaRecorderError::InvalidState(e.into()))
+        })
+    }
+
+    /// Stops recording (see [MediaRecorder.stop()][1]).
+    ///
+    /// [`MediaRecorderEvent::Stopped`] is emitted once all the recorded data
+    /// is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Not supposed to error ever, since stopping an inactive
+    /// [MediaRecorder][2] is a no-op.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#dom-mediarecorder-stop
+    /// [2]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+    pub fn stop(&self) -> Result<(), Traced<MediaRecorderError>> {
+        self.recorder.stop().map_err(|e| {
+            tracerr::new!(MediaRecorderError::InvalidState(e.into()))
+        })
+    }
+}
+
+impl Drop for MediaRecorder {
+    fn drop(&mut self) {
+        if self.is_active() {
+            _ = self.recorder.stop();
+        }
+        if let Some(mixer) = &self.audio_mixer {
+            _ = mixer.close();
+        }
+    }
+}
@@ -2,10 +2,19 @@
 //!
 //! [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
 
-use std::{cell::RefCell, rc::Rc, time::Duration};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
 
 use derive_more::{Debug, with_trait::AsRef};
-use futures::{StreamExt as _, future, stream::LocalBoxStream};
+use futures::{
+    StreamExt as _,
+    channel::oneshot,
+    future::{self, LocalBoxFuture},
+    stream::LocalBoxStream,
+};
 use js_sys::{Error as JsError, Reflect};
 use medea_reactive::ObservableCell;
 use wasm_bindgen::JsValue;
@@ -64,6 +73,19 @@ pub struct MediaStreamTrack {
     ///
     /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
     audio_level_watcher: Rc<RefCell<Option<AudioLevelWatcher>>>,
+
+    /// [`web_sys::HtmlMediaElement`] rendering the underlying
+    /// [MediaStreamTrack][1], if it's attached to any.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    attached_to: RefCell<Option<web_sys::HtmlMediaElement>>,
+
+    /// Playback [volume][1] applied to the [`web_sys::HtmlMediaElement`] this
+    /// [MediaStreamTrack][2] is attached to.
+    ///
+    /// [1]: https://html.spec.whatwg.org/#dom-media-volume
+    /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    volume: Cell<f64>,
 }
 
 impl MediaStreamTrack {
@@ -86,6 +108,8 @@ impl MediaStreamTrack {
             on_ended: RefCell::new(None),
             on_audio_level: Rc::new(RefCell::new(None)),
             audio_level_watcher: Rc::new(RefCell::new(None)),
+            attached_to: RefCell::new(None),
+            volume: Cell::new(1.0),
         }
     }
 
@@ -181,6 +205,15 @@ impl MediaStreamTrack {
         w.try_into().ok()
     }
 
+    /// Return a [`frameRate`][1] of the underlying [MediaStreamTrack][2].
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediatracksettings-framerate
+    /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    #[must_use]
+    pub fn frame_rate(&self) -> Option<f64> {
+        self.sys_track.get_settings().get_frame_rate()
+    }
+
     /// Changes an [`enabled`][1] attribute in the underlying
     /// [MediaStreamTrack][2].
     ///
@@ -202,6 +235,62 @@ impl MediaStreamTrack {
         future::ready(())
     }
 
+    /// Attaches this [`MediaStreamTrack`] to the provided
+    /// [`web_sys::HtmlMediaElement`] for rendering, detaching it from the
+    /// previously attached one (if any).
+    ///
+    /// Attaching doesn't affect the underlying [MediaStreamTrack][1] itself,
+    /// so it keeps being received regardless of being attached or not.
+    ///
+    /// # Errors
+    ///
+    /// If a [MediaStream][2] cannot be created for the underlying
+    /// [MediaStreamTrack][1].
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    /// [2]: https://w3.org/TR/mediacapture-streams#mediastream
+    pub fn attach(
+        &self,
+        element: web_sys::HtmlMediaElement,
+    ) -> Result<(), platform::Error> {
+        let stream = web_sys::MediaStream::new_with_tracks(
+            &js_sys::Array::of1(&self.sys_track),
+        )?;
+        self.detach();
+        element.set_src_object(Some(&stream));
+        element.set_volume(self.volume.get());
+        drop(self.attached_to.replace(Some(element)));
+        Ok(())
+    }
+
+    /// Detaches this [`MediaStreamTrack`] from the
+    /// [`web_sys::HtmlMediaElement`] it's attached to (if any), without
+    /// stopping it.
+    pub fn detach(&self) {
+        if let Some(element) = self.attached_to.take() {
+            element.set_src_object(None);
+        }
+    }
+
+    /// Sets playback [volume][1] of this [`MediaStreamTrack`], applying it to
+    /// the [`web_sys::HtmlMediaElement`] it's attached to (if any), and to the
+    /// ones it will be attached to.
+    ///
+    /// [1]: https://html.spec.whatwg.org/#dom-media-volume
+    pub fn set_volume(&self, volume: f64) {
+        self.volume.set(volume);
+        if let Some(element) = self.attached_to.borrow().as_ref() {
+            element.set_volume(volume);
+        }
+    }
+
+    /// Indicates whether this [`MediaStreamTrack`] is attached to any
+    /// [`web_sys::HtmlMediaElement`].
+    #[must_use]
+    pub fn is_attached(&self) -> bool {
+        self.attached_to.borrow().is_some()
+    }
+
     /// Returns an [`enabled`][1] attribute of the underlying
     /// [MediaStreamTrack][2].
     ///
@@ -241,6 +330,8 @@ impl MediaStreamTrack {
             on_ended: RefCell::new(None),
             on_audio_level: Rc::new(RefCell::new(None)),
             audio_level_watcher: Rc::clone(&self.audio_level_watcher),
+            attached_to: RefCell::new(None),
+            volume: Cell::new(1.0),
         }
     }
 
@@ -273,6 +364,56 @@ impl MediaStreamTrack {
         });
     }
 
+    /// Indicates whether the underlying [MediaStreamTrack][1] is
+    /// [muted][2], meaning that it's unable to provide media data at the
+    /// moment.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    /// [2]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack-muted
+    #[must_use]
+    pub fn muted(&self) -> bool {
+        self.sys_track.muted()
+    }
+
+    /// Returns a [`Future`] resolving once the underlying
+    /// [MediaStreamTrack][1] is [unmuted][2] or [ended][3].
+    ///
+    /// Every call subscribes independently, so any number of the returned
+    /// [`Future`]s may be awaited concurrently. Dropping the returned
+    /// [`Future`] unsubscribes it.
+    ///
+    /// # Panics
+    ///
+    /// If binding to the [`unmute`][2] or [`ended`][3] event fails. Not
+    /// supposed to ever happen.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    /// [2]: https://w3.org/TR/mediacapture-streams#event-mediastreamtrack-unmute
+    /// [3]: https://tinyurl.com/w3-streams#event-mediastreamtrack-ended
+    #[expect(clippy::unwrap_used, reason = "shouldn't error ever")]
+    pub fn on_unmute_or_ended(&self) -> LocalBoxFuture<'static, ()> {
+        let (tx, rx) = oneshot::channel();
+        let tx = Rc::new(RefCell::new(Some(tx)));
+        let listeners = ["unmute", "ended"].map(|event| {
+            let tx = Rc::clone(&tx);
+            EventListener::new_once(
+                Rc::clone(&self.sys_track),
+                event,
+                move |_: web_sys::Event| {
+                    if let Some(tx) = tx.borrow_mut().take() {
+                        _ = tx.send(());
+                    }
+                },
+            )
+            .unwrap()
+        });
+
+        Box::pin(async move {
+            _ = rx.await;
+            drop(listeners);
+        })
+    }
+
     /// Indicates whether an `OnAudioLevelChangedCallback` is supported for this
     /// [`MediaStreamTrack`].
     #[must_use]
@@ -304,18 +445,7 @@ impl MediaStreamTrack {
         self.on_audio_level.borrow_mut().replace(Box::new(cb));
         let callback = Rc::clone(&self.on_audio_level);
 
-        let mut sub = {
-            let mut audio_level_watcher = self.audio_level_watcher.borrow_mut();
-            if let Some(watcher) = audio_level_watcher.as_ref() {
-                watcher.subscribe()
-            } else {
-                let watcher = AudioLevelWatcher::new(&self.sys_track)?;
-                let sub = watcher.subscribe();
-                *audio_level_watcher = Some(watcher);
-
-                sub
-            }
-        };
+        let mut sub = self.subscribe_audio_level()?;
 
         platform::spawn(async move {
             while let Some(level) = sub.next().await {
@@ -330,6 +460,60 @@ impl MediaStreamTrack {
         Ok(())
     }
 
+    /// Returns a [`LocalBoxStream`] of audio levels (in the `[0;100]` range)
+    /// of this [`MediaStreamTrack`].
+    ///
+    /// Doesn't affect the callback set via
+    /// [`MediaStreamTrack::on_audio_level_changed()`].
+    ///
+    /// Returns [`None`] if audio level is not available for this
+    /// [`MediaStreamTrack`].
+    #[must_use]
+    pub fn audio_level_stream(&self) -> Option<LocalBoxStream<'static, i32>> {
+        if !self.is_on_audio_level_available() {
+            return None;
+        }
+        self.subscribe_audio_level().ok()
+    }
+
+    /// Subscribes to the [`AudioLevelWatcher`] of this [`MediaStreamTrack`],
+    /// creating it if it doesn't exist yet.
+    fn subscribe_audio_level(
+        &self,
+    ) -> Result<LocalBoxStream<'static, i32>, platform::Error> {
+        let mut audio_level_watcher = self.audio_level_watcher.borrow_mut();
+        if let Some(watcher) = audio_level_watcher.as_ref() {
+            Ok(watcher.subscribe())
+        } else {
+            let watcher = AudioLevelWatcher::new(&self.sys_track)?;
+            let sub = watcher.subscribe();
+            *audio_level_watcher = Some(watcher);
+
+            Ok(sub)
+        }
+    }
+
+    /// Returns the total number of frames [delivered][1] by the underlying
+    /// [MediaStreamTrack][2] to its sinks.
+    ///
+    /// Returns [`None`] if the browser doesn't support
+    /// [MediaStreamTrack.stats][3].
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-extensions#dom-mediastreamtrackvideostats-deliveredframes
+    /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    /// [3]: https://w3.org/TR/mediacapture-extensions#dom-mediastreamtrack-stats
+    #[must_use]
+    pub fn delivered_frames(&self) -> Option<f64> {
+        let stats =
+            Reflect::get(&self.sys_track, &JsValue::from_str("stats")).ok()?;
+        if stats.is_undefined() || stats.is_null() {
+            return None;
+        }
+        Reflect::get(&stats, &JsValue::from_str("deliveredFrames"))
+            .ok()?
+            .as_f64()
+    }
+
     /// Indicates whether this [`MediaStreamTrack`] supports audio processing
     /// functions:
     /// - [`MediaStreamTrack::is_noise_suppression_enabled()`]
@@ -2,10 +2,14 @@
 
 pub mod codec_capability;
 pub mod constraints;
+pub mod data_channel;
+pub mod dtmf_sender;
 pub mod error;
+pub mod http;
 pub mod ice_server;
 pub mod media_device_info;
 pub mod media_devices;
+pub mod media_recorder;
 pub mod media_track;
 pub mod peer_connection;
 pub mod rtc_stats;
@@ -24,9 +28,13 @@ use web_sys::Window;
 pub use self::{
     codec_capability::CodecCapability,
     constraints::{DisplayMediaStreamConstraints, MediaStreamConstraints},
+    data_channel::DataChannel,
+    dtmf_sender::DtmfSender,
     error::Error,
+    http::fetch,
     media_device_info::MediaDeviceInfo,
     media_devices::MediaDevices,
+    media_recorder::MediaRecorder,
     media_track::MediaStreamTrack,
     peer_connection::RtcPeerConnection,
     rtc_stats::RtcStats,
@@ -92,6 +100,26 @@ pub async fn delay_for(delay: Duration) {
     .unwrap();
 }
 
+/// Returns the current time as a [`Duration`] elapsed since the UNIX epoch.
+#[must_use]
+pub fn now() -> Duration {
+    Duration::try_from_secs_f64(js_sys::Date::now() / 1000.0)
+        .unwrap_or_default()
+}
+
+/// Returns the current time of a monotonic clock as a [`Duration`] elapsed
+/// since an arbitrary point in time, via [performance.now()][1].
+///
+/// Unlike [`now()`], it's not affected by system clock adjustments, so should
+/// be used for measuring durations.
+///
+/// [1]: https://w3.org/TR/hr-time#dom-performance-now
+#[must_use]
+pub fn monotonic_now() -> Duration {
+    let now_ms = window().performance().map_or(0.0, |p| p.now());
+    Duration::try_from_secs_f64(now_ms / 1000.0).unwrap_or_default()
+}
+
 /// Returns [`Window`] object.
 ///
 /// # Panics
@@ -15,20 +15,23 @@ use medea_client_api_proto::{
 use tracerr::Traced;
 use wasm_bindgen_futures::JsFuture;
 use web_sys::{
-    Event, RtcBundlePolicy, RtcConfiguration, RtcIceCandidateInit,
-    RtcIceConnectionState, RtcIceTransportPolicy, RtcOfferOptions,
+    Event, RtcBundlePolicy, RtcConfiguration, RtcDataChannelEvent,
+    RtcDataChannelInit, RtcIceCandidateInit, RtcIceConnectionState,
+    RtcIceTransportPolicy, RtcOfferOptions,
     RtcPeerConnection as SysRtcPeerConnection, RtcPeerConnectionIceErrorEvent,
     RtcPeerConnectionIceEvent, RtcPeerConnectionState, RtcRtpTransceiver,
     RtcSdpType, RtcSessionDescription, RtcSessionDescriptionInit,
-    RtcStatsReport, RtcTrackEvent,
+    RtcSignalingState, RtcStatsReport, RtcTrackEvent,
 };
 
 use super::ice_server::RtcIceServers;
 use crate::{
     media::MediaKind,
     platform::{
-        self, IceCandidate, IceCandidateError, MediaStreamTrack,
-        RtcPeerConnectionError, RtcStats, SdpType, Transceiver,
+        self, BundlePolicy, DataChannel, DataChannelError, IceCandidate,
+        IceCandidateError, MediaStreamTrack, RtcPeerConnectionError, RtcStats,
+        RtcpMuxPolicy, SdpType, SignalingState, Transceiver,
+        data_channel::DataChannelInit,
         wasm::{transceiver::TransceiverInit, utils::EventListener},
     },
 };
@@ -101,6 +104,15 @@ pub struct RtcPeerConnection {
     on_connection_state_changed:
         RefCell<Option<EventListener<SysRtcPeerConnection, Event>>>,
 
+    /// [`signalingstatechange`][2] callback of [RTCPeerConnection][1],
+    /// fires whenever its [signaling state][3] changes.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#rtcpeerconnection-interface
+    /// [2]: https://w3.org/TR/webrtc#event-signalingstatechange
+    /// [3]: https://w3.org/TR/webrtc#dom-peerconnection-signaling-state
+    on_signaling_state_changed:
+        RefCell<Option<EventListener<SysRtcPeerConnection, Event>>>,
+
     /// [`ontrack`][2] callback of [RTCPeerConnection][1] to handle
     /// [`track`][3] event. It fires when [RTCPeerConnection][1] receives
     /// new [MediaStreamTrack][4] from remote peer.
@@ -111,19 +123,38 @@ pub struct RtcPeerConnection {
     /// [4]: https://developer.mozilla.org/en-US/docs/Web/API/MediaStreamTrack
     on_track:
         RefCell<Option<EventListener<SysRtcPeerConnection, RtcTrackEvent>>>,
+
+    /// [`ondatachannel`][2] callback of [RTCPeerConnection][1] to handle
+    /// [`datachannel`][3] event. It fires when remote peer creates a new
+    /// [RTCDataChannel][4].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#rtcpeerconnection-interface
+    /// [2]: https://w3.org/TR/webrtc#dom-rtcpeerconnection-ondatachannel
+    /// [3]: https://w3.org/TR/webrtc#event-datachannel
+    /// [4]: https://w3.org/TR/webrtc#dom-rtcdatachannel
+    on_data_channel: RefCell<
+        Option<EventListener<SysRtcPeerConnection, RtcDataChannelEvent>>,
+    >,
 }
 
 impl RtcPeerConnection {
     /// Instantiates new [`RtcPeerConnection`].
     ///
+    /// The provided [`BundlePolicy`] and [`RtcpMuxPolicy`] are applied to its
+    /// [RTCConfiguration][1].
+    ///
     /// # Errors
     ///
     /// Errors with [`RtcPeerConnectionError::PeerCreationError`] if
     /// [`SysRtcPeerConnection`] creation fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcconfiguration
     #[expect(clippy::unused_async, reason = "`cfg` code uniformity")]
     pub async fn new<I>(
         ice_servers: I,
         is_force_relayed: bool,
+        bundle_policy: BundlePolicy,
+        rtcp_mux_policy: RtcpMuxPolicy,
     ) -> RtcPeerConnectionResult<Self>
     where
         I: IntoIterator<Item = IceServer>,
@@ -134,7 +165,18 @@ impl RtcPeerConnection {
         } else {
             RtcIceTransportPolicy::All
         };
-        peer_conf.set_bundle_policy(RtcBundlePolicy::MaxBundle);
+        peer_conf.set_bundle_policy(match bundle_policy {
+            BundlePolicy::Balanced => RtcBundlePolicy::Balanced,
+            BundlePolicy::MaxCompat => RtcBundlePolicy::MaxCompat,
+            BundlePolicy::MaxBundle => RtcBundlePolicy::MaxBundle,
+        });
+        // `web_sys` has no binding for the `rtcpMuxPolicy` field.
+        _ = js_sys::Reflect::set(
+            &peer_conf,
+            &"rtcpMuxPolicy".into(),
+            &rtcp_mux_policy.to_string().into(),
+        )
+        .unwrap();
         peer_conf.set_ice_transport_policy(policy);
         peer_conf.set_ice_servers(&RtcIceServers::from(ice_servers));
         let peer = SysRtcPeerConnection::new_with_configuration(&peer_conf)
@@ -149,7 +191,9 @@ impl RtcPeerConnection {
             on_ice_candidate_error: RefCell::new(None),
             on_ice_connection_state_changed: RefCell::new(None),
             on_connection_state_changed: RefCell::new(None),
+            on_signaling_state_changed: RefCell::new(None),
             on_track: RefCell::new(None),
+            on_data_channel: RefCell::new(None),
         })
     }
 
@@ -214,6 +258,63 @@ impl RtcPeerConnection {
         });
     }
 
+    /// Sets handler for a [`RtcDataChannelEvent`] (see [RTCDataChannelEvent][1]
+    /// and [`ondatachannel` callback][2]).
+    ///
+    /// # Panics
+    ///
+    /// If binding to the [`datachannel`][3] event fails. Not supposed to ever
+    /// happen.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcdatachannelevent
+    /// [2]: https://w3.org/TR/webrtc#dom-rtcpeerconnection-ondatachannel
+    /// [3]: https://w3.org/TR/webrtc#event-datachannel
+    pub fn on_data_channel<F>(&self, f: Option<F>)
+    where
+        F: 'static + FnMut(DataChannel),
+    {
+        let mut on_data_channel = self.on_data_channel.borrow_mut();
+        drop(match f {
+            None => on_data_channel.take(),
+            Some(mut f) => {
+                on_data_channel.replace(
+                    // Unwrapping is OK here, because this function shouldn't
+                    // error ever.
+                    EventListener::new_mut(
+                        Rc::clone(&self.peer),
+                        "datachannel",
+                        move |msg: RtcDataChannelEvent| {
+                            f(DataChannel::from(msg.channel()));
+                        },
+                    )
+                    .unwrap(),
+                )
+            }
+        });
+    }
+
+    /// Creates a new [`DataChannel`] with the provided `label` and `init`
+    /// options (see [RTCPeerConnection.createDataChannel()][1]).
+    ///
+    /// # Errors
+    ///
+    /// Never errors on this platform, the [`Result`] is kept for `cfg` code
+    /// uniformity.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-peerconnection-createdatachannel
+    pub fn create_data_channel(
+        &self,
+        label: &str,
+        init: DataChannelInit,
+    ) -> Result<DataChannel, Traced<DataChannelError>> {
+        Ok(DataChannel::from(
+            self.peer.create_data_channel_with_data_channel_dict(
+                label,
+                &RtcDataChannelInit::from(init),
+            ),
+        ))
+    }
+
     /// Sets handler for an [`RtcPeerConnectionIceErrorEvent`] (see the
     /// [RTCPeerConnectionIceErrorEvent][1] and the
     /// [`onicecandidateerror` callback][2]).
@@ -314,6 +415,62 @@ impl RtcPeerConnection {
         parse_peer_connection_state(self.peer.connection_state())
     }
 
+    /// Returns [`SignalingState`] of this [`RtcPeerConnection`].
+    #[must_use]
+    pub fn signaling_state(&self) -> SignalingState {
+        parse_signaling_state(self.peer.signaling_state())
+    }
+
+    /// Returns [`BundlePolicy`] of the [RTCConfiguration][1] of this
+    /// [`RtcPeerConnection`].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcconfiguration
+    #[must_use]
+    pub fn bundle_policy(&self) -> BundlePolicy {
+        match self.peer.get_configuration().get_bundle_policy() {
+            Some(RtcBundlePolicy::Balanced) => BundlePolicy::Balanced,
+            Some(RtcBundlePolicy::MaxCompat) => BundlePolicy::MaxCompat,
+            _ => BundlePolicy::MaxBundle,
+        }
+    }
+
+    /// Returns [`RtcpMuxPolicy`] of the [RTCConfiguration][1] of this
+    /// [`RtcPeerConnection`].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcconfiguration
+    #[must_use]
+    pub fn rtcp_mux_policy(&self) -> RtcpMuxPolicy {
+        // `web_sys` has no binding for the `rtcpMuxPolicy` field.
+        let policy = js_sys::Reflect::get(
+            &self.peer.get_configuration(),
+            &"rtcpMuxPolicy".into(),
+        )
+        .ok()
+        .and_then(|p| p.as_string());
+        match policy.as_deref() {
+            Some("negotiate") => RtcpMuxPolicy::Negotiate,
+            _ => RtcpMuxPolicy::Require,
+        }
+    }
+
+    /// Returns SDP of the [`localDescription`][1] of this
+    /// [`RtcPeerConnection`], if any.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-peerconnection-localdescription
+    #[must_use]
+    pub fn local_description(&self) -> Option<String> {
+        self.peer.local_description().map(|desc| desc.sdp())
+    }
+
+    /// Returns SDP of the [`remoteDescription`][1] of this
+    /// [`RtcPeerConnection`], if any.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-peerconnection-remotedescription
+    #[must_use]
+    pub fn remote_description(&self) -> Option<String> {
+        self.peer.remote_description().map(|desc| desc.sdp())
+    }
+
     /// Sets handler for an [`iceconnectionstatechange`][1] event.
     ///
     /// # Panics
@@ -390,9 +547,45 @@ impl RtcPeerConnection {
         });
     }
 
+    /// Sets handler for a [`signalingstatechange`][1] event.
+    ///
+    /// # Panics
+    ///
+    /// If binding to the [`signalingstatechange`][1] event fails. Not supposed
+    /// to ever happen.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#event-signalingstatechange
+    pub fn on_signaling_state_change<F>(&self, f: Option<F>)
+    where
+        F: 'static + FnMut(SignalingState),
+    {
+        let mut on_signaling_state_changed =
+            self.on_signaling_state_changed.borrow_mut();
+        drop(match f {
+            None => on_signaling_state_changed.take(),
+            Some(mut f) => {
+                let peer = Rc::clone(&self.peer);
+                on_signaling_state_changed.replace(
+                    // Unwrapping is OK here, because this function shouldn't
+                    // error ever.
+                    EventListener::new_mut(
+                        Rc::clone(&self.peer),
+                        "signalingstatechange",
+                        move |_| {
+                            f(parse_signaling_state(peer.signaling_state()));
+                        },
+                    )
+                    .unwrap(),
+                )
+            }
+        });
+    }
+
     /// Adds remote [RTCPeerConnection][1]'s [ICE candidate][2] to this
     /// [`RtcPeerConnection`].
     ///
+    /// An empty `candidate` signals the [end-of-candidates][4].
+    ///
     /// # Errors
     ///
     /// With [`RtcPeerConnectionError::AddIceCandidateFailed`] if
@@ -401,18 +594,24 @@ impl RtcPeerConnection {
     /// [1]: https://w3.org/TR/webrtc#rtcpeerconnection-interface
     /// [2]: https://tools.ietf.org/html/rfc5245#section-2
     /// [3]: https://w3.org/TR/webrtc#dom-peerconnection-addicecandidate
+    /// [4]: https://w3.org/TR/webrtc#dfn-end-of-candidates
     pub async fn add_ice_candidate(
         &self,
         candidate: &str,
         sdp_m_line_index: Option<u16>,
         sdp_mid: &Option<String>,
     ) -> RtcPeerConnectionResult<()> {
-        let cand_init = RtcIceCandidateInit::new(candidate);
-        cand_init.set_sdp_m_line_index(sdp_m_line_index);
-        cand_init.set_sdp_mid(sdp_mid.as_ref().map(String::as_ref));
+        // Empty `candidate` signals the end of candidates, which is passed as
+        // `null` to avoid validation of `sdpMid` and `sdpMLineIndex`.
+        let cand_init = (!candidate.is_empty()).then(|| {
+            let init = RtcIceCandidateInit::new(candidate);
+            init.set_sdp_m_line_index(sdp_m_line_index);
+            init.set_sdp_mid(sdp_mid.as_ref().map(String::as_ref));
+            init
+        });
         JsFuture::from(
             self.peer.add_ice_candidate_with_opt_rtc_ice_candidate_init(
-                Some(cand_init).as_ref(),
+                cand_init.as_ref(),
             ),
         )
         .await
@@ -423,6 +622,36 @@ impl RtcPeerConnection {
         Ok(())
     }
 
+    /// Replaces [`IceServer`]s of this [`RtcPeerConnection`] with the provided
+    /// ones, keeping the rest of its [RTCConfiguration][1].
+    ///
+    /// New [`IceServer`]s are used only for the candidates gathered
+    /// afterwards, so an ICE restart is required to apply them to the
+    /// already established connection.
+    ///
+    /// # Errors
+    ///
+    /// With [`RtcPeerConnectionError::SetConfigurationFailed`] if
+    /// [RtcPeerConnection.setConfiguration()][2] fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcconfiguration
+    /// [2]: https://w3.org/TR/webrtc#dom-rtcpeerconnection-setconfiguration
+    pub fn set_ice_servers<I>(
+        &self,
+        ice_servers: I,
+    ) -> RtcPeerConnectionResult<()>
+    where
+        I: IntoIterator<Item = IceServer>,
+    {
+        let peer_conf = self.peer.get_configuration();
+        peer_conf.set_ice_servers(&RtcIceServers::from(ice_servers));
+        self.peer
+            .set_configuration_with_configuration(&peer_conf)
+            .map_err(Into::into)
+            .map_err(RtcPeerConnectionError::SetConfigurationFailed)
+            .map_err(tracerr::wrap!())
+    }
+
     /// Marks [`RtcPeerConnection`] to trigger ICE restart.
     ///
     /// After this function returns, the offer returned by the next call to
@@ -681,6 +910,23 @@ fn parse_peer_connection_state(
     }
 }
 
+/// Parses a [`SignalingState`] out of the given [`RtcSignalingState`].
+fn parse_signaling_state(state: RtcSignalingState) -> SignalingState {
+    use RtcSignalingState as S;
+
+    match state {
+        S::Stable => SignalingState::Stable,
+        S::HaveLocalOffer => SignalingState::HaveLocalOffer,
+        S::HaveRemoteOffer => SignalingState::HaveRemoteOffer,
+        S::HaveLocalPranswer => SignalingState::HaveLocalPranswer,
+        S::HaveRemotePranswer => SignalingState::HaveRemotePranswer,
+        S::Closed => SignalingState::Closed,
+        _ => {
+            unreachable!("unknown `RtcSignalingState::{state:?}`");
+        }
+    }
+}
+
 /// Parses a [`IceConnectionState`] out of the given [`RtcIceConnectionState`].
 fn parse_ice_connection_state(
     state: RtcIceConnectionState,
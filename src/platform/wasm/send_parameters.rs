@@ -3,9 +3,12 @@
 //! [0]: https://w3.org/TR/webrtc#dom-rtcrtpsendparameters
 
 use derive_more::{From, Into};
+use js_sys::Reflect;
+use wasm_bindgen::JsValue;
 use web_sys::{RtcRtpEncodingParameters, RtcRtpParameters};
 
 use super::send_encoding_parameters::SendEncodingParameters;
+use crate::platform::DegradationPreference;
 
 /// Representation of [RTCRtpSendParameters][0].
 ///
@@ -29,4 +32,16 @@ impl SendParameters {
             .collect::<Vec<_>>()
             .into_boxed_slice()
     }
+
+    /// Sets [degradationPreference][1] of these [`SendParameters`].
+    ///
+    /// [1]: https://w3.org/TR/webrtc-extensions#dom-rtcrtpsendparameters-degradationpreference
+    pub fn set_degradation_preference(&self, pref: DegradationPreference) {
+        // Not exposed by `web-sys`, since it's absent in WebRTC 1.0.
+        _ = Reflect::set(
+            &self.0,
+            &JsValue::from_str("degradationPreference"),
+            &JsValue::from_str(&pref.to_string()),
+        );
+    }
 }
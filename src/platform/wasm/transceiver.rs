@@ -11,7 +11,7 @@ use web_sys::{RtcRtpParameters, RtcRtpTransceiver, RtcRtpTransceiverInit};
 use crate::{
     media::track::local,
     platform::{
-        self, TransceiverDirection,
+        self, DtmfSender, TransceiverDirection,
         send_encoding_parameters::SendEncodingParameters,
         send_parameters::SendParameters,
         wasm::codec_capability::CodecCapability,
@@ -54,6 +54,15 @@ impl TransceiverInit {
 pub struct Transceiver(RtcRtpTransceiver);
 
 impl Transceiver {
+    /// Returns the underlying [`RtcRtpTransceiver`].
+    ///
+    /// Mutating it directly may desync the state tracked by this crate, so
+    /// it should be used only for features this crate doesn't wrap.
+    #[must_use]
+    pub const fn handle(&self) -> &RtcRtpTransceiver {
+        &self.0
+    }
+
     /// Returns current [`TransceiverDirection`] of this [`Transceiver`].
     fn direction(&self) -> TransceiverDirection {
         TransceiverDirection::from(self.0.direction())
@@ -131,12 +140,30 @@ impl Transceiver {
         self.0.mid()
     }
 
+    /// Returns the [currentDirection][1] of this [`Transceiver`] negotiated by
+    /// the last SDP offer/answer exchange, if any.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtptransceiver-currentdirection
+    #[must_use]
+    pub fn current_direction(&self) -> Option<TransceiverDirection> {
+        self.0.current_direction().map(Into::into)
+    }
+
     /// Indicates whether the underlying [`RtcRtpTransceiver`] is stopped.
     #[must_use]
     pub fn is_stopped(&self) -> bool {
         self.0.stopped()
     }
 
+    /// Returns [`DtmfSender`] of the underlying [RTCRtpSender], if it's
+    /// capable of sending DTMF.
+    ///
+    /// [RTCRtpSender]: https://w3.org/TR/webrtc#rtcrtpsender-interface
+    #[must_use]
+    pub fn dtmf_sender(&self) -> Option<DtmfSender> {
+        self.0.sender().dtmf().map(DtmfSender::from)
+    }
+
     /// Returns [`SendParameters`] of the underlying [RTCRtpSender].
     ///
     /// [RTCRtpSender]: https://w3.org/TR/webrtc#rtcrtpsender-interface
@@ -17,7 +17,9 @@ use web_sys::{CloseEvent, Event, MessageEvent, WebSocket as SysWebSocket};
 
 use crate::{
     platform::{
-        transport::{RpcTransport, TransportError, TransportState},
+        transport::{
+            RpcCompression, RpcTransport, TransportError, TransportState,
+        },
         wasm::utils::EventListener,
     },
     rpc::{ApiUrl, CloseMsg, websocket::ClientDisconnect},
@@ -140,6 +142,16 @@ impl WebSocketRpcTransport {
         Self(Rc::new(RefCell::new(InnerSocket::new())))
     }
 
+    /// Returns a new [`WebSocketRpcTransport`] using the provided
+    /// [`RpcCompression`].
+    ///
+    /// Browsers negotiate compression on their own, so the provided
+    /// [`RpcCompression`] is ignored.
+    #[must_use]
+    pub fn with_compression(_: RpcCompression) -> Self {
+        Self::new()
+    }
+
     /// Sets [`InnerSocket::on_close_listener`] which will update
     /// [`RpcTransport`]'s [`TransportState`] to [`TransportState::Closed`].
     fn set_on_close_listener(&self, socket: SysWebSocket) {
@@ -0,0 +1,158 @@
+//! Controller degrading a [`Room`] to audio-only media exchange on a sustained
+//! poor connection quality, and restoring video once it recovers.
+//!
+//! [`Room`]: super::Room
+
+use std::time::Duration;
+
+use medea_client_api_proto::ConnectionQualityScore;
+
+/// Configuration of an [`AudioOnlyFallbackController`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AudioOnlyFallbackConfig {
+    /// [`ConnectionQualityScore`], staying at or below which for the
+    /// [`AudioOnlyFallbackConfig::degrade_after`] period switches the media
+    /// exchange to audio-only.
+    pub degrade_at: ConnectionQualityScore,
+
+    /// [`ConnectionQualityScore`], staying at or above which for the
+    /// [`AudioOnlyFallbackConfig::restore_after`] period restores video.
+    ///
+    /// Should be higher than the [`AudioOnlyFallbackConfig::degrade_at`] one
+    /// to avoid flapping.
+    pub restore_at: ConnectionQualityScore,
+
+    /// Period the quality should stay poor for before video is paused.
+    pub degrade_after: Duration,
+
+    /// Period the quality should stay good for before video is restored.
+    pub restore_after: Duration,
+}
+
+impl Default for AudioOnlyFallbackConfig {
+    fn default() -> Self {
+        Self {
+            degrade_at: ConnectionQualityScore::Poor,
+            restore_at: ConnectionQualityScore::Medium,
+            degrade_after: Duration::from_secs(10),
+            restore_after: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Controller switching a [`Room`] to audio-only media exchange on a sustained
+/// poor [`ConnectionQualityScore`] and back once it stays good enough.
+///
+/// [`Room`]: super::Room
+#[derive(Clone, Copy, Debug)]
+pub struct AudioOnlyFallbackController {
+    /// [`AudioOnlyFallbackConfig`] of this [`AudioOnlyFallbackController`].
+    config: AudioOnlyFallbackConfig,
+
+    /// Indicator whether the media exchange is currently degraded to
+    /// audio-only.
+    is_degraded: bool,
+
+    /// Time the quality has started to favor switching the current mode at.
+    since: Option<Duration>,
+}
+
+impl AudioOnlyFallbackController {
+    /// Creates a new [`AudioOnlyFallbackController`] with the provided
+    /// [`AudioOnlyFallbackConfig`].
+    #[must_use]
+    pub const fn new(config: AudioOnlyFallbackConfig) -> Self {
+        Self { config, is_degraded: false, since: None }
+    }
+
+    /// Indicates whether the media exchange is currently degraded to
+    /// audio-only.
+    #[must_use]
+    pub const fn is_degraded(&self) -> bool {
+        self.is_degraded
+    }
+
+    /// Records the provided worst [`ConnectionQualityScore`] of a [`Room`]
+    /// observed at the provided time.
+    ///
+    /// Returns the new audio-only indicator if it has been changed.
+    ///
+    /// [`Room`]: super::Room
+    pub fn update(
+        &mut self,
+        score: ConnectionQualityScore,
+        at: Duration,
+    ) -> Option<bool> {
+        let (favors_switch, sustain) = if self.is_degraded {
+            (score >= self.config.restore_at, self.config.restore_after)
+        } else {
+            (score <= self.config.degrade_at, self.config.degrade_after)
+        };
+        if !favors_switch {
+            self.since = None;
+            return None;
+        }
+
+        let since = *self.since.get_or_insert(at);
+        if at.saturating_sub(since) < sustain {
+            return None;
+        }
+
+        self.since = None;
+        self.is_degraded = !self.is_degraded;
+        Some(self.is_degraded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn degrades_and_restores_with_hysteresis() {
+        use ConnectionQualityScore as S;
+
+        let mut controller =
+            AudioOnlyFallbackController::new(AudioOnlyFallbackConfig {
+                degrade_at: S::Poor,
+                restore_at: S::Medium,
+                degrade_after: secs(3),
+                restore_after: secs(5),
+            });
+
+        assert_eq!(controller.update(S::Poor, secs(0)), None);
+        assert_eq!(controller.update(S::Poor, secs(2)), None);
+        assert_eq!(controller.update(S::Poor, secs(3)), Some(true));
+        assert!(controller.is_degraded());
+
+        // `Low` is between the thresholds, so doesn't restore.
+        assert_eq!(controller.update(S::Low, secs(4)), None);
+        assert_eq!(controller.update(S::Low, secs(20)), None);
+
+        assert_eq!(controller.update(S::High, secs(21)), None);
+        assert_eq!(controller.update(S::High, secs(25)), None);
+        assert_eq!(controller.update(S::High, secs(26)), Some(false),);
+        assert!(!controller.is_degraded());
+    }
+
+    #[test]
+    fn interruptions_reset_sustain_period() {
+        use ConnectionQualityScore as S;
+
+        let mut controller =
+            AudioOnlyFallbackController::new(AudioOnlyFallbackConfig {
+                degrade_after: secs(3),
+                ..AudioOnlyFallbackConfig::default()
+            });
+
+        assert_eq!(controller.update(S::Poor, secs(0)), None);
+        assert_eq!(controller.update(S::Medium, secs(2)), None);
+        assert_eq!(controller.update(S::Poor, secs(3)), None);
+        assert_eq!(controller.update(S::Poor, secs(5)), None);
+        assert_eq!(controller.update(S::Poor, secs(8)), Some(true));
+    }
+}
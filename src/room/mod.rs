@@ -1,9 +1,13 @@
 //! Medea [`Room`].
 
+mod audio_only_fallback;
+
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     collections::{HashMap, HashSet},
+    mem,
     rc::{Rc, Weak},
+    time::Duration,
 };
 
 use async_recursion::async_recursion;
@@ -11,7 +15,7 @@ use async_trait::async_trait;
 use derive_more::with_trait::{Debug, Display, From, Into};
 use futures::{
     FutureExt as _, StreamExt as _, TryFutureExt as _, channel::mpsc, future,
-    future::LocalBoxFuture,
+    future::LocalBoxFuture, stream::LocalBoxStream,
 };
 use medea_client_api_proto::{
     self as proto, Command, ConnectionQualityScore, Event as RpcEvent,
@@ -22,6 +26,8 @@ use medea_client_api_proto::{
 use proto::{ConnectionMode, IceCandidateError};
 use tracerr::Traced;
 
+#[cfg(feature = "mockable")]
+use crate::connection::Connection;
 use crate::{
     api,
     connection::Connections,
@@ -31,23 +37,77 @@ use crate::{
         track::{local, remote},
     },
     peer::{
-        self, InsertLocalTracksError, LocalMediaError,
+        self, IceServersResolver, InsertLocalTracksError, LocalMediaError,
         LocalStreamUpdateCriteria, MediaState, PeerConnection, PeerEvent,
         PeerEventHandler, TrackDirection, TracksRequestError,
-        UpdateLocalStreamError, media::ProhibitedStateError,
+        UpdateLocalStreamError,
+        media::ProhibitedStateError,
         media_exchange_state, mute_state,
+        sender::{
+            AdaptiveBitrateConfig, LossBasedResolutionConfig, ResolutionCeiling,
+        },
     },
     platform,
     rpc::{
         ClientDisconnect, CloseReason, ConnectionInfo,
-        ConnectionInfoParseError, ReconnectHandle, RpcSession, SessionError,
+        ConnectionInfoParseError, ReconnectHandle, RpcSession,
+        ServerTimeOffset, SessionError, SessionState, rpc_session,
+    },
+    utils::{
+        AsProtoState as _, Caused,
+        logging::{self, LogCategory},
     },
-    utils::{AsProtoState as _, Caused},
+};
+
+#[doc(inline)]
+pub use self::audio_only_fallback::{
+    AudioOnlyFallbackConfig, AudioOnlyFallbackController,
 };
 
 /// Alias of [`Result`]s related to [`MediaState`] update functions.
 type ChangeMediaStateResult = Result<(), Traced<ChangeMediaStateError>>;
 
+/// Event of a [`Room`]'s connection lifecycle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoomLifecycleEvent {
+    /// [`Room`] is connecting to a media server and joining it.
+    Joining,
+
+    /// [`Room`] has joined a media server.
+    Joined,
+
+    /// [`Room`] has lost its connection with a media server.
+    ConnectionLost,
+
+    /// [`Room`] is reconnecting to a media server after a
+    /// [`RoomLifecycleEvent::ConnectionLost`].
+    Reconnecting,
+
+    /// [`Room`] has been closed.
+    Closed(CloseReason),
+}
+
+/// State of a [`Room`] returned by the [`RoomHandle::state()`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoomState {
+    /// [`Room`] hasn't been joined yet.
+    New,
+
+    /// [`Room`] is connecting to a media server and joining it.
+    Connecting,
+
+    /// [`Room`] has joined a media server.
+    Connected,
+
+    /// [`Room`] has lost its connection with a media server and is recovering
+    /// it.
+    Reconnecting,
+
+    /// [`Room`] has been closed, so all the [`RoomHandle`] methods fail with
+    /// a [`HandleDetachedError`].
+    Closed,
+}
+
 /// Reason of why [`Room`] has been closed.
 ///
 /// This struct is passed into [`RoomHandle::on_close`] callback.
@@ -108,6 +168,36 @@ impl RoomCloseReason {
     }
 }
 
+/// Fallback of local media acquisition to the previous
+/// [`MediaStreamSettings`], made because the requested ones couldn't be
+/// applied.
+///
+/// This struct is passed into [`RoomHandle::on_local_media_fallback`] callback.
+#[derive(Clone, Debug)]
+pub struct LocalMediaFallback {
+    /// [`MediaStreamSettings`] failed to be applied.
+    attempted: MediaStreamSettings,
+
+    /// [`MediaStreamSettings`] applied instead of the [`attempted`] ones.
+    ///
+    /// [`attempted`]: LocalMediaFallback::attempted
+    succeeded: MediaStreamSettings,
+}
+
+impl LocalMediaFallback {
+    /// Returns [`MediaStreamSettings`] failed to be applied.
+    #[must_use]
+    pub fn attempted(&self) -> MediaStreamSettings {
+        self.attempted.clone()
+    }
+
+    /// Returns [`MediaStreamSettings`] applied instead of the attempted ones.
+    #[must_use]
+    pub fn succeeded(&self) -> MediaStreamSettings {
+        self.succeeded.clone()
+    }
+}
+
 /// Errors occurring in [`RoomHandle::join()`] method.
 #[derive(Caused, Clone, Debug, Display, From)]
 #[cause(error = platform::Error)]
@@ -133,6 +223,7 @@ pub enum RoomJoinError {
 /// Error of [`RoomHandle`]'s [`Weak`] pointer being detached.
 #[derive(Caused, Clone, Copy, Debug, Display, Eq, From, PartialEq)]
 #[cause(error = platform::Error)]
+#[display("`RoomHandle` is in detached state, `Room` is already closed")]
 pub struct HandleDetachedError;
 
 /// Errors occurring when changing media state of [`Sender`]s and [`Receiver`]s.
@@ -160,6 +251,10 @@ pub enum ChangeMediaStateError {
     /// [`Sender`]: peer::media::Sender
     InsertLocalTracksError(#[cause] InsertLocalTracksError),
 
+    /// [`local::Track`]s acquisition has been cancelled.
+    #[display("Local stream update has been cancelled")]
+    LocalStreamUpdateCancelled,
+
     /// Requested state transition is not allowed by [`Sender`]'s settings.
     ///
     /// [`Sender`]: peer::media::Sender
@@ -192,10 +287,44 @@ impl From<UpdateLocalStreamError> for ChangeMediaStateError {
             UpdateErr::InvalidLocalTracks(e) => Self::from(e),
             UpdateErr::CouldNotGetLocalMedia(e) => Self::from(e),
             UpdateErr::InsertLocalTracksError(e) => Self::from(e),
+            UpdateErr::Cancelled => Self::LocalStreamUpdateCancelled,
         }
     }
 }
 
+/// Maximum size (in bytes) of the data sent with a
+/// [`RoomHandle::send_application_message()`].
+pub const MAX_APPLICATION_MESSAGE_LEN: usize = 4096;
+
+/// Errors occurring in [`RoomHandle::send_application_message()`] method.
+#[derive(Caused, Clone, Copy, Debug, Display)]
+#[cause(error = platform::Error)]
+pub enum SendApplicationMessageError {
+    /// [`RoomHandle`]'s [`Weak`] pointer is detached.
+    #[display("`RoomHandle` is in detached state")]
+    Detached,
+
+    /// Provided application data exceeds [`MAX_APPLICATION_MESSAGE_LEN`].
+    #[display(
+        "Application message of {_0} bytes exceeds the limit of \
+         {MAX_APPLICATION_MESSAGE_LEN} bytes"
+    )]
+    TooLarge(usize),
+}
+
+/// Errors occurring in [`RoomHandle::renegotiate()`] method.
+#[derive(Caused, Clone, Copy, Debug, Display)]
+#[cause(error = platform::Error)]
+pub enum RenegotiateError {
+    /// [`RoomHandle`]'s [`Weak`] pointer is detached.
+    #[display("`RoomHandle` is in detached state")]
+    Detached,
+
+    /// [`Room`] has no [`PeerConnection`] with the provided [`PeerId`].
+    #[display("`PeerConnection` with ID `{_0}` doesn't exist")]
+    UnknownPeer(PeerId),
+}
+
 /// Errors occurring when a [`Room`] tries to acquire [`local::Track`]s via
 /// [`MediaManager`].
 #[derive(Caused, Clone, Debug, Display, From)]
@@ -265,6 +394,161 @@ impl RoomHandle {
         Ok(())
     }
 
+    /// Gracefully leaves the [`Room`].
+    ///
+    /// Sends the final stats of all the [`PeerConnection`]s to a media
+    /// server, asks it to remove the `Member` from the [`Room`] and waits for
+    /// its acknowledgement during the provided `timeout`. Then closes the
+    /// [`Room`] regardless of whether the acknowledgement has been received.
+    ///
+    /// Unlike closing the [`Room`] directly, gives a media server a clear
+    /// signal that the `Member` has left intentionally.
+    ///
+    /// If the [`RpcSession`] isn't opened at the moment (e.g. the [`Room`]
+    /// isn't joined yet, or its connection is lost), then there is no one to
+    /// acknowledge the leaving, so the [`Room`] is closed immediately.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub async fn leave(
+        &self,
+        timeout: Duration,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        let inner = upgrade_inner!(self.0)?;
+        if !matches!(inner.rpc.state(), SessionState::Opened(_)) {
+            inner.rpc.close_with_reason(ClientDisconnect::RoomClosed);
+            return Ok(());
+        }
+
+        // Final stats are sent directly, rather than via `PeerEvent`s, so they
+        // reach a media server before the leaving request.
+        let final_stats = future::join_all(
+            inner.peers.get_all().into_iter().map(async |peer| {
+                Some((peer.id(), peer.scrape_unsent_peer_stats().await?))
+            }),
+        )
+        .await;
+        for (peer_id, stats) in final_stats.into_iter().flatten() {
+            inner.rpc.send_command(Command::AddPeerConnectionMetrics {
+                peer_id,
+                metrics: PeerMetrics::RtcStats(stats.0),
+            });
+        }
+        let rpc = Rc::clone(&inner.rpc);
+        drop(inner);
+
+        let left = rpc.on_normal_close();
+        rpc.leave();
+        if let future::Either::Right(_) =
+            future::select(left, platform::delay_for(timeout).boxed_local())
+                .await
+        {
+            logging::emit(
+                log::Level::Warn,
+                None,
+                LogCategory::Signalling,
+                format_args!(
+                    "`Room` leave hasn't been acknowledged in {timeout:?}",
+                ),
+            );
+        }
+        rpc.close_with_reason(ClientDisconnect::RoomClosed);
+
+        Ok(())
+    }
+
+    /// Returns a [`Stream`] of [`RoomLifecycleEvent`]s of the [`Room`].
+    ///
+    /// It's backed by the same [`RpcSession`] state driving the
+    /// [`RoomHandle::on_connection_loss`] and [`RoomHandle::on_close`]
+    /// callbacks.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    ///
+    /// [`Stream`]: futures::Stream
+    pub fn on_lifecycle(
+        &self,
+    ) -> Result<
+        LocalBoxStream<'static, RoomLifecycleEvent>,
+        Traced<HandleDetachedError>,
+    > {
+        use RoomLifecycleEvent as E;
+        use SessionState as S;
+
+        let inner = upgrade_inner!(self.0)?;
+        let mut is_lost = false;
+        Ok(inner
+            .rpc
+            .on_state_change()
+            .filter_map(move |state| {
+                let event = match state {
+                    S::Connecting(_) if is_lost => Some(E::Reconnecting),
+                    S::Connecting(_) => Some(E::Joining),
+                    S::Opened(_) => {
+                        is_lost = false;
+                        Some(E::Joined)
+                    }
+                    S::Lost(..) => {
+                        is_lost = true;
+                        Some(E::ConnectionLost)
+                    }
+                    S::Finished(reason) => Some(E::Closed(reason)),
+                    S::Uninitialized
+                    | S::Initialized(_)
+                    | S::Authorizing(_) => None,
+                };
+                future::ready(event)
+            })
+            .boxed_local())
+    }
+
+    /// Returns the current [`RoomState`] of the [`Room`].
+    ///
+    /// Never fails, returning [`RoomState::Closed`] if the [`Room`] has been
+    /// closed already.
+    #[must_use]
+    pub fn state(&self) -> RoomState {
+        use SessionState as S;
+
+        let Some(inner) = self.0.upgrade() else {
+            return RoomState::Closed;
+        };
+        match inner.rpc.state() {
+            S::Uninitialized | S::Initialized(_) => RoomState::New,
+            S::Connecting(_) | S::Authorizing(_)
+                if inner.is_connection_lost.get() =>
+            {
+                RoomState::Reconnecting
+            }
+            S::Connecting(_) | S::Authorizing(_) => RoomState::Connecting,
+            S::Opened(_) => RoomState::Connected,
+            S::Lost(..) => RoomState::Reconnecting,
+            S::Finished(_) => RoomState::Closed,
+        }
+    }
+
+    /// Returns the estimated offset between the media server and client
+    /// clocks, useful for synchronizing playback between `Member`s.
+    ///
+    /// The offset is estimated from several round trips to the media server,
+    /// rejecting the ones having the highest latency, so its accuracy is
+    /// bounded by a half of the best observed round trip time (see
+    /// [`ServerTimeOffset::accuracy`]).
+    ///
+    /// Returns [`None`] if the offset hasn't been estimated yet.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn server_time_offset(
+        &self,
+    ) -> Result<Option<ServerTimeOffset>, Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0).map(|inner| inner.rpc.server_time_offset())
+    }
+
     /// Sets callback, invoked when a new [`Connection`] with some remote `Peer`
     /// is established.
     ///
@@ -281,6 +565,70 @@ impl RoomHandle {
             .map(|inner| inner.connections.on_new_connection(f))
     }
 
+    /// Sets the period the current and future [`PeerConnection`]s of this
+    /// [`Room`] may stay disconnected before it's reported (to the media
+    /// server and via [`Connection`]s' quality scores), so brief network
+    /// blips don't reach an application.
+    ///
+    /// If the [`PeerConnection`] recovers within this period, no disconnect is
+    /// reported at all. [`Duration::ZERO`] (the default) disables the grace
+    /// period.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    ///
+    /// [`Connection`]: crate::connection::Connection
+    pub fn set_disconnect_grace_period(
+        &self,
+        period: Duration,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0).map(|inner| {
+            inner.disconnect_grace_period.set(period);
+            inner.peers.state().set_disconnect_grace_period(period);
+        })
+    }
+
+    /// Enables or disables sending [`platform::RtcStats`] to the media server
+    /// by all the current and future [`PeerConnection`]s of this [`Room`].
+    ///
+    /// [`platform::RtcStats`] are still scraped while sending is disabled, so
+    /// local stats APIs keep working.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn set_stats_sending_enabled(
+        &self,
+        enabled: bool,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0).map(|inner| {
+            inner.stats_sending_enabled.set(enabled);
+            inner.peers.state().set_stats_sending_enabled(enabled);
+        })
+    }
+
+    /// Enables or disables early media in all the current and future
+    /// [`PeerConnection`]s of this [`Room`].
+    ///
+    /// Once enabled, negotiations don't wait for local media acquisition
+    /// (e.g. while a media permission prompt is pending), so remote media is
+    /// received right away, and the local media is added via renegotiation
+    /// once acquired.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn set_early_media(
+        &self,
+        enabled: bool,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0).map(|inner| {
+            inner.early_media.set(enabled);
+            inner.peers.state().set_early_media(enabled);
+        })
+    }
+
     /// Sets `on_close` callback, invoked on this [`Room`] close, providing a
     /// [`RoomCloseReason`].
     ///
@@ -326,6 +674,22 @@ impl RoomHandle {
             .map(|inner| inner.on_failed_local_media.set_func(f))
     }
 
+    /// Sets `on_local_media_fallback` callback, invoked when local media
+    /// settings update fails and previous [`MediaStreamSettings`] are restored
+    /// instead (see `rollback_on_fail` argument of the
+    /// [`RoomHandle::set_local_media_settings()`]).
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn on_local_media_fallback(
+        &self,
+        f: platform::Function<api::LocalMediaFallback>,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0)
+            .map(|inner| inner.on_local_media_fallback.set_func(f))
+    }
+
     /// Sets `on_connection_loss` callback, invoked when a connection with
     /// server is lost.
     ///
@@ -789,6 +1153,265 @@ impl RoomHandle {
         )
         .map_err(tracerr::map_from_and_wrap!())
     }
+
+    /// Enables automatic audio-only fallback of this [`Room`].
+    ///
+    /// Once the worst quality of all the [`Connection`]s stays poor for the
+    /// configured period, outbound and inbound video is disabled (notifying a
+    /// media server as the [`RoomHandle::disable_video()`] and
+    /// [`RoomHandle::disable_remote_video()`] do), and enabled back once the
+    /// quality stays good enough for the configured period.
+    ///
+    /// Only the video being enabled at the moment of degradation is restored.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    ///
+    /// [`Connection`]: crate::connection::Connection
+    pub fn enable_audio_only_fallback(
+        &self,
+        config: AudioOnlyFallbackConfig,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        let inner = upgrade_inner!(self.0)?;
+        inner.disable_audio_only_fallback();
+        drop(inner.audio_only_fallback.replace(Some(AudioOnlyFallback {
+            controller: AudioOnlyFallbackController::new(config),
+            room: self.clone(),
+            paused: Vec::new(),
+        })));
+        Ok(())
+    }
+
+    /// Disables automatic audio-only fallback of this [`Room`], restoring the
+    /// video paused by it, if any.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn disable_audio_only_fallback(
+        &self,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0).map(|inner| inner.disable_audio_only_fallback())
+    }
+
+    /// Indicates whether this [`Room`] is currently degraded to audio-only
+    /// media exchange by its automatic audio-only fallback.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn is_audio_only_fallback_active(
+        &self,
+    ) -> Result<bool, Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0).map(|inner| {
+            inner
+                .audio_only_fallback
+                .borrow()
+                .as_ref()
+                .is_some_and(|f| f.controller.is_degraded())
+        })
+    }
+
+    /// Sends the provided application `data` to all the other `Member`s of
+    /// the [`Room`] via a media server, which delivers it to them via
+    /// [`ConnectionHandle::on_application_message`] callback.
+    ///
+    /// Intended for small application state updates only (like a raised hand
+    /// or a display name change), so the `data` must not exceed
+    /// [`MAX_APPLICATION_MESSAGE_LEN`] bytes. The delivery is not guaranteed
+    /// if the connection with a media server is lost meanwhile.
+    ///
+    /// # Errors
+    ///
+    /// With [`SendApplicationMessageError::Detached`] if an inner [`Weak`]
+    /// pointer upgrade fails.
+    ///
+    /// With [`SendApplicationMessageError::TooLarge`] if the provided `data`
+    /// exceeds [`MAX_APPLICATION_MESSAGE_LEN`].
+    ///
+    /// [`ConnectionHandle::on_application_message`]: crate::connection::ConnectionHandle::on_application_message
+    pub fn send_application_message(
+        &self,
+        data: Vec<u8>,
+    ) -> Result<(), Traced<SendApplicationMessageError>> {
+        let inner = self.0.upgrade().ok_or_else(|| {
+            tracerr::new!(SendApplicationMessageError::Detached)
+        })?;
+        if data.len() > MAX_APPLICATION_MESSAGE_LEN {
+            return Err(tracerr::new!(SendApplicationMessageError::TooLarge(
+                data.len()
+            )));
+        }
+        inner.rpc.send_command(Command::SendApplicationMessage { data });
+
+        Ok(())
+    }
+
+    /// Sets the maximum resolution of the video sent to all the
+    /// [`PeerConnection`]s of this [`Room`], regardless of what is captured.
+    ///
+    /// Applied via [scaleResolutionDownBy][1] of each video sender, based on
+    /// its current capture resolution, and re-applied whenever the capture
+    /// resolution changes.
+    ///
+    /// # Errors
+    ///
+    /// With [`HandleDetachedError`] if an inner [`Weak`] pointer upgrade
+    /// fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    pub async fn set_max_send_resolution(
+        &self,
+        width: u32,
+        height: u32,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        let inner = upgrade_inner!(self.0)?;
+        inner
+            .peers
+            .set_max_send_resolution(Some(ResolutionCeiling { width, height }))
+            .await;
+
+        Ok(())
+    }
+
+    /// Removes the maximum resolution of the sent video set via
+    /// [`RoomHandle::set_max_send_resolution()`].
+    ///
+    /// # Errors
+    ///
+    /// With [`HandleDetachedError`] if an inner [`Weak`] pointer upgrade
+    /// fails.
+    pub async fn reset_max_send_resolution(
+        &self,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        let inner = upgrade_inner!(self.0)?;
+        inner.peers.set_max_send_resolution(None).await;
+
+        Ok(())
+    }
+
+    /// Enables loss-based resolution downgrade of the video sent to all the
+    /// [`PeerConnection`]s of this [`Room`] with the provided
+    /// [`LossBasedResolutionConfig`].
+    ///
+    /// Once enabled, [scaleResolutionDownBy][1] of each video sender is
+    /// stepped up on a sustained packet loss reported for it, and back down
+    /// once the loss clears.
+    ///
+    /// # Errors
+    ///
+    /// With [`HandleDetachedError`] if an inner [`Weak`] pointer upgrade
+    /// fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-scaleresolutiondownby
+    pub fn enable_loss_based_resolution(
+        &self,
+        config: LossBasedResolutionConfig,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0)
+            .map(|inner| inner.peers.set_loss_based_resolution(Some(config)))
+    }
+
+    /// Disables loss-based resolution downgrade enabled via
+    /// [`RoomHandle::enable_loss_based_resolution()`], restoring the original
+    /// resolution of the sent video.
+    ///
+    /// # Errors
+    ///
+    /// With [`HandleDetachedError`] if an inner [`Weak`] pointer upgrade
+    /// fails.
+    pub fn disable_loss_based_resolution(
+        &self,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0)
+            .map(|inner| inner.peers.set_loss_based_resolution(None))
+    }
+
+    /// Enables adaptive bitrate of the video sent to all the
+    /// [`PeerConnection`]s of this [`Room`] with the provided
+    /// [`AdaptiveBitrateConfig`].
+    ///
+    /// Once enabled, [maxBitrate][1] of each video sender is nudged down on a
+    /// high round trip time or packet loss reported for it, and back up
+    /// otherwise, within the configured bounds.
+    ///
+    /// # Errors
+    ///
+    /// With [`HandleDetachedError`] if an inner [`Weak`] pointer upgrade
+    /// fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcrtpencodingparameters-maxbitrate
+    pub fn enable_adaptive_bitrate(
+        &self,
+        config: AdaptiveBitrateConfig,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0)
+            .map(|inner| inner.peers.set_adaptive_bitrate(Some(config)))
+    }
+
+    /// Disables adaptive bitrate enabled via
+    /// [`RoomHandle::enable_adaptive_bitrate()`], leaving the last applied
+    /// bitrate of the sent video as is.
+    ///
+    /// # Errors
+    ///
+    /// With [`HandleDetachedError`] if an inner [`Weak`] pointer upgrade
+    /// fails.
+    pub fn disable_adaptive_bitrate(
+        &self,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0)
+            .map(|inner| inner.peers.set_adaptive_bitrate(None))
+    }
+
+    /// Requests Media Server to renegotiate the [`PeerConnection`] with the
+    /// provided [`PeerId`], so a fresh SDP offer is generated once Media
+    /// Server assigns the [`NegotiationRole`].
+    ///
+    /// If a negotiation is in progress already, then the renegotiation is
+    /// queued, and all the calls made meanwhile are coalesced into a single
+    /// follow-up negotiation.
+    ///
+    /// # Errors
+    ///
+    /// With [`RenegotiateError::Detached`] if an inner [`Weak`] pointer
+    /// upgrade fails.
+    ///
+    /// With [`RenegotiateError::UnknownPeer`] if there is no
+    /// [`PeerConnection`] with the provided [`PeerId`] in this [`Room`].
+    pub fn renegotiate(
+        &self,
+        peer_id: PeerId,
+    ) -> Result<(), Traced<RenegotiateError>> {
+        let inner = self
+            .0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(RenegotiateError::Detached))?;
+        inner
+            .peers
+            .state()
+            .get(peer_id)
+            .ok_or_else(|| {
+                tracerr::new!(RenegotiateError::UnknownPeer(peer_id))
+            })?
+            .renegotiate();
+
+        Ok(())
+    }
+
+    /// Requests Media Server to renegotiate all the [`PeerConnection`]s of
+    /// this [`Room`].
+    ///
+    /// See [`RoomHandle::renegotiate()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// With [`HandleDetachedError`] if an inner [`Weak`] pointer upgrade
+    /// fails.
+    pub fn renegotiate_all(&self) -> Result<(), Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0).map(|inner| inner.peers.state().renegotiate())
+    }
 }
 
 /// [`Weak`] reference upgradeable to the [`Room`].
@@ -826,7 +1449,7 @@ impl Room {
             /// [`rpc::Client`] lost connection to the Media Server.
             ///
             /// [`rpc::Client`]: crate::rpc::Client
-            RpcClientLostConnection,
+            RpcClientLostConnection(rpc_session::ConnectionLostReason),
 
             /// [`rpc::Client`] lost restored connection to the Media Server.
             ///
@@ -842,7 +1465,7 @@ impl Room {
             peer_events_rx.map(RoomEvent::PeerEvent).fuse();
         let mut rpc_connection_lost = rpc
             .on_connection_loss()
-            .map(|()| RoomEvent::RpcClientLostConnection)
+            .map(RoomEvent::RpcClientLostConnection)
             .fuse();
         let mut rpc_client_reconnected = rpc
             .on_reconnected()
@@ -882,8 +1505,8 @@ impl Room {
                                 log::error!("{e}");
                             }
                         }
-                        RoomEvent::RpcClientLostConnection => {
-                            this_room.handle_rpc_connection_lost();
+                        RoomEvent::RpcClientLostConnection(reason) => {
+                            this_room.handle_rpc_connection_lost(&reason);
                         }
                         RoomEvent::RpcClientReconnected => {
                             this_room.handle_rpc_connection_recovered();
@@ -912,6 +1535,35 @@ impl Room {
         self.0.set_close_reason(reason);
     }
 
+    /// Sets the default [`IceServer`]s inherited by the [`PeerConnection`]s
+    /// created in this [`Room`] afterwards.
+    ///
+    /// [`IceServer`]s provided by the media server for a [`PeerConnection`]
+    /// always win over the default ones.
+    pub fn set_default_ice_servers(&self, ice_servers: Vec<IceServer>) {
+        drop(self.0.default_ice_servers.replace(ice_servers));
+    }
+
+    /// Sets the [`IceServersResolver`] inherited by the [`PeerConnection`]s
+    /// created in this [`Room`] afterwards.
+    pub fn set_ice_servers_resolver(
+        &self,
+        resolver: Option<IceServersResolver>,
+    ) {
+        drop(self.0.ice_servers_resolver.replace(resolver));
+    }
+
+    /// Sets the [`platform::BundlePolicy`] and [`platform::RtcpMuxPolicy`] of
+    /// the [`PeerConnection`]s created in this [`Room`] afterwards.
+    pub fn set_peer_policies(
+        &self,
+        bundle_policy: platform::BundlePolicy,
+        rtcp_mux_policy: platform::RtcpMuxPolicy,
+    ) {
+        self.0.bundle_policy.set(bundle_policy);
+        self.0.rtcp_mux_policy.set(rtcp_mux_policy);
+    }
+
     /// Creates a new external handle to [`Room`]. You can create them as many
     /// as you need.
     #[must_use]
@@ -942,6 +1594,21 @@ impl Room {
     }
 }
 
+/// Enabled audio-only fallback of a [`Room`].
+#[derive(Debug)]
+struct AudioOnlyFallback {
+    /// [`AudioOnlyFallbackController`] deciding when to switch the media
+    /// exchange mode.
+    controller: AudioOnlyFallbackController,
+
+    /// [`RoomHandle`] to change the video [`MediaState`]s with.
+    room: RoomHandle,
+
+    /// Video paused by this [`AudioOnlyFallback`], to be restored once the
+    /// quality recovers.
+    paused: Vec<(TrackDirection, MediaSourceKind)>,
+}
+
 /// Actual data of a [`Room`].
 ///
 /// Shared between an external [`RoomHandle`] and Rust side ([`Room`]).
@@ -970,6 +1637,37 @@ struct InnerRoom {
     /// [`Connection`]: crate::connection::Connection
     connections: Rc<Connections>,
 
+    /// Default [`IceServer`]s inherited by the [`PeerConnection`]s created in
+    /// this [`Room`], unless the media server provides its own ones.
+    default_ice_servers: RefCell<Vec<IceServer>>,
+
+    /// [`platform::BundlePolicy`] of the [`PeerConnection`]s created in this
+    /// [`Room`].
+    bundle_policy: Cell<platform::BundlePolicy>,
+
+    /// [`platform::RtcpMuxPolicy`] of the [`PeerConnection`]s created in this
+    /// [`Room`].
+    rtcp_mux_policy: Cell<platform::RtcpMuxPolicy>,
+
+    /// Indicator whether the [`PeerConnection`]s of this [`Room`] send their
+    /// scraped [`platform::RtcStats`] to the media server.
+    stats_sending_enabled: Cell<bool>,
+
+    /// Indicator whether negotiations of the [`PeerConnection`]s of this
+    /// [`Room`] don't wait for local media acquisition.
+    early_media: Cell<bool>,
+
+    /// Period the [`PeerConnection`]s of this [`Room`] may stay disconnected
+    /// before it's reported.
+    disconnect_grace_period: Cell<Duration>,
+
+    /// [`IceServersResolver`] inherited by the [`PeerConnection`]s created in
+    /// this [`Room`].
+    ice_servers_resolver: RefCell<Option<IceServersResolver>>,
+
+    /// Automatic audio-only fallback of this [`Room`], if enabled.
+    audio_only_fallback: RefCell<Option<AudioOnlyFallback>>,
+
     /// Callback invoked when a new local [`local::LocalMediaTrack`] will be
     /// added to this [`Room`].
     on_local_track: platform::Callback<api::LocalMediaTrack>,
@@ -978,6 +1676,10 @@ struct InnerRoom {
     /// [`MediaManager`] or failed inject stream into [`PeerConnection`].
     on_failed_local_media: Rc<platform::Callback<api::Error>>,
 
+    /// Callback invoked when [`MediaStreamSettings`] are rolled back because
+    /// the requested ones couldn't be applied.
+    on_local_media_fallback: platform::Callback<api::LocalMediaFallback>,
+
     /// Callback invoked when a [`RpcSession`] loses connection.
     on_connection_loss: platform::Callback<api::ReconnectHandle>,
 
@@ -992,6 +1694,10 @@ struct InnerRoom {
     /// Note that `None` will be considered as error and `is_err` will be
     /// `true` in [`CloseReason`] provided to callback.
     close_reason: RefCell<CloseReason>,
+
+    /// Indicator whether the connection with a media server has been lost and
+    /// not recovered yet.
+    is_connection_lost: Cell<bool>,
 }
 
 /// Errors occurring in [`RoomHandle::set_local_media_settings()`] method.
@@ -1120,14 +1826,24 @@ impl InnerRoom {
             send_constraints,
             recv_constraints,
             connections,
+            default_ice_servers: RefCell::new(Vec::new()),
+            bundle_policy: Cell::default(),
+            rtcp_mux_policy: Cell::default(),
+            stats_sending_enabled: Cell::new(true),
+            early_media: Cell::new(false),
+            disconnect_grace_period: Cell::new(Duration::ZERO),
+            ice_servers_resolver: RefCell::new(None),
+            audio_only_fallback: RefCell::new(None),
             on_connection_loss: platform::Callback::default(),
             on_failed_local_media: Rc::new(platform::Callback::default()),
+            on_local_media_fallback: platform::Callback::default(),
             on_local_track: platform::Callback::default(),
             on_close: Rc::new(platform::Callback::default()),
             close_reason: RefCell::new(CloseReason::ByClient {
                 reason: ClientDisconnect::RoomUnexpectedlyDropped,
                 is_err: true,
             }),
+            is_connection_lost: Cell::new(false),
         }
     }
 
@@ -1163,6 +1879,115 @@ impl InnerRoom {
         }
     }
 
+    /// Feeds the current worst [`ConnectionQualityScore`] of this [`Room`] to
+    /// its [`AudioOnlyFallback`] (if enabled), pausing or restoring video if
+    /// the fallback decides so.
+    fn update_audio_only_fallback(&self) {
+        use media_exchange_state::Stable::{Disabled, Enabled};
+
+        let Some(score) = self.connections.worst_quality_score() else {
+            return;
+        };
+        let mut fallback = self.audio_only_fallback.borrow_mut();
+        let Some(fallback) = fallback.as_mut() else {
+            return;
+        };
+        let Some(is_degraded) =
+            fallback.controller.update(score, platform::now())
+        else {
+            return;
+        };
+
+        let (state, videos) = if is_degraded {
+            fallback.paused = self.enabled_videos();
+            (Disabled, fallback.paused.clone())
+        } else {
+            (Enabled, mem::take(&mut fallback.paused))
+        };
+        logging::emit(
+            log::Level::Info,
+            None,
+            LogCategory::Media,
+            format_args!(
+                "Audio-only fallback {} on `{score}` connection quality",
+                if is_degraded { "activated" } else { "deactivated" },
+            ),
+        );
+        Self::spawn_video_state_change(fallback.room.clone(), state, videos);
+    }
+
+    /// Disables the [`AudioOnlyFallback`] of this [`Room`], restoring the
+    /// video paused by it, if any.
+    fn disable_audio_only_fallback(&self) {
+        if let Some(fallback) = self.audio_only_fallback.take() {
+            Self::spawn_video_state_change(
+                fallback.room,
+                media_exchange_state::Stable::Enabled,
+                fallback.paused,
+            );
+        }
+    }
+
+    /// Returns all the currently enabled video of this [`Room`].
+    fn enabled_videos(&self) -> Vec<(TrackDirection, MediaSourceKind)> {
+        [MediaSourceKind::Device, MediaSourceKind::Display]
+            .into_iter()
+            .flat_map(|source| {
+                let send = self
+                    .send_constraints
+                    .is_track_enabled(MediaKind::Video, Some(source.into()));
+                let recv = match source {
+                    MediaSourceKind::Device => {
+                        self.recv_constraints.is_video_device_enabled()
+                    }
+                    MediaSourceKind::Display => {
+                        self.recv_constraints.is_video_display_enabled()
+                    }
+                };
+                [
+                    send.then_some((TrackDirection::Send, source)),
+                    recv.then_some((TrackDirection::Recv, source)),
+                ]
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Spawns changing the provided video of the provided [`RoomHandle`] into
+    /// the provided [`media_exchange_state::Stable`].
+    fn spawn_video_state_change(
+        room: RoomHandle,
+        state: media_exchange_state::Stable,
+        videos: Vec<(TrackDirection, MediaSourceKind)>,
+    ) {
+        if videos.is_empty() {
+            return;
+        }
+        platform::spawn(async move {
+            for (direction, source) in videos {
+                if let Err(e) = room
+                    .change_media_state(
+                        state,
+                        MediaKind::Video,
+                        direction,
+                        Some(source),
+                    )
+                    .await
+                {
+                    logging::emit(
+                        log::Level::Warn,
+                        None,
+                        LogCategory::Media,
+                        format_args!(
+                            "Audio-only fallback failed to change \
+                             `{source:?}` video `{direction:?}` state: {e}",
+                        ),
+                    );
+                }
+            }
+        });
+    }
+
     /// Sets `close_reason` of this [`InnerRoom`].
     ///
     /// [`Drop`] implementation of [`InnerRoom`] is supposed to be triggered
@@ -1416,6 +2241,7 @@ impl InnerRoom {
         use ConstraintsUpdateError as E;
 
         let current_settings = self.send_constraints.inner();
+        let attempted_settings = rollback_on_fail.then(|| new_settings.clone());
         self.send_constraints.constrain(new_settings);
         let criteria_kinds_diff =
             self.send_constraints.calculate_kinds_diff(&current_settings);
@@ -1448,9 +2274,9 @@ impl InnerRoom {
                         )));
                     }
 
-                    let err = if rollback_on_fail {
+                    let err = if let Some(attempted) = attempted_settings {
                         self.set_local_media_settings(
-                            current_settings,
+                            current_settings.clone(),
                             stop_first,
                             false,
                         )
@@ -1460,6 +2286,12 @@ impl InnerRoom {
                                 e.clone(),
                             ))
                         })?;
+                        self.on_local_media_fallback.call1(
+                            LocalMediaFallback {
+                                attempted,
+                                succeeded: current_settings,
+                            },
+                        );
 
                         E::recovered(tracerr::map_from_and_wrap!()(e.clone()))
                     } else if stop_first {
@@ -1497,10 +2329,19 @@ impl InnerRoom {
 
     /// Stops state transition timers in all [`PeerConnection`]'s in this
     /// [`Room`].
-    fn handle_rpc_connection_lost(&self) {
+    ///
+    /// Invokes `on_connection_loss` callback with a [`ReconnectHandle`]
+    /// exposing the WebSocket close code and reason, if any.
+    fn handle_rpc_connection_lost(
+        &self,
+        reason: &rpc_session::ConnectionLostReason,
+    ) {
+        self.is_connection_lost.set(true);
         self.peers.connection_lost();
-        self.on_connection_loss
-            .call1(ReconnectHandle::new(Rc::downgrade(&self.rpc)));
+        self.on_connection_loss.call1(
+            ReconnectHandle::new(Rc::downgrade(&self.rpc))
+                .with_close_msg(reason.close_msg().cloned()),
+        );
     }
 
     /// Sends [`Command::SynchronizeMe`] with a current Client state to the
@@ -1509,6 +2350,7 @@ impl InnerRoom {
     /// Resets state transition timers in all [`PeerConnection`]'s in this
     /// [`Room`].
     fn handle_rpc_connection_recovered(&self) {
+        self.is_connection_lost.set(false);
         self.peers.connection_recovered();
         self.rpc.send_command(Command::SynchronizeMe {
             state: self.peers.state().as_proto(),
@@ -1543,6 +2385,12 @@ impl EventHandler for InnerRoom {
         ice_servers: Vec<IceServer>,
         force_relay: bool,
     ) -> Self::Output {
+        // `Peer` initially chosen as an offerer by a media server insists on
+        // its own SDP offers in case of collisions.
+        let politeness = match negotiation_role {
+            NegotiationRole::Offerer => peer::Politeness::Impolite,
+            NegotiationRole::Answerer(_) => peer::Politeness::Polite,
+        };
         let peer_state = peer::State::new(
             peer_id,
             ice_servers,
@@ -1550,6 +2398,18 @@ impl EventHandler for InnerRoom {
             Some(negotiation_role),
             connection_mode,
         );
+        peer_state.set_politeness(Some(politeness));
+        peer_state
+            .set_default_ice_servers(self.default_ice_servers.borrow().clone());
+        peer_state.set_bundle_policy(self.bundle_policy.get());
+        peer_state.set_rtcp_mux_policy(self.rtcp_mux_policy.get());
+        peer_state.set_stats_sending_enabled(self.stats_sending_enabled.get());
+        peer_state.set_early_media(self.early_media.get());
+        peer_state
+            .set_disconnect_grace_period(self.disconnect_grace_period.get());
+        peer_state.set_ice_servers_resolver(
+            self.ice_servers_resolver.borrow().clone(),
+        );
         for track in &tracks {
             peer_state.insert_track(track, self.send_constraints.clone());
         }
@@ -1673,6 +2533,38 @@ impl EventHandler for InnerRoom {
         Ok(())
     }
 
+    /// Replaces [`IceServer`]s of all the [`PeerConnection`]s in this [`Room`]
+    /// (including the ones not created yet) with the provided ones, applying
+    /// them via [`PeerConnection::update_ice_servers()`].
+    ///
+    /// Events are handled sequentially, so this never interleaves with another
+    /// event being handled. If a negotiation is in progress, it's not
+    /// interrupted: the ICE candidates already gathered for it remain valid,
+    /// and the new [`IceServer`]s are used for the candidates gathered
+    /// afterwards (e.g. after the next ICE restart).
+    async fn on_ice_servers_updated(
+        &self,
+        ice_servers: Vec<IceServer>,
+    ) -> Self::Output {
+        self.peers.state().update_ice_servers(&ice_servers);
+        Ok(())
+    }
+
+    /// Delivers the application data to the [`Connection`] with the `Member`
+    /// who sent it.
+    ///
+    /// [`Connection`]: crate::connection::Connection
+    async fn on_application_message_received(
+        &self,
+        member_id: MemberId,
+        data: Vec<u8>,
+    ) -> Self::Output {
+        if let Some(conn) = self.connections.get(&member_id) {
+            conn.receive_application_message(data);
+        }
+        Ok(())
+    }
+
     async fn on_room_joined(&self, _: MemberId) -> Self::Output {
         unreachable!("Room can't receive Event::RoomJoined")
     }
@@ -1767,6 +2659,28 @@ impl PeerEventHandler for InnerRoom {
         Ok(())
     }
 
+    /// Handles [`PeerEvent::NewDataChannel`] event.
+    ///
+    /// Remotely created [`peer::DataChannel`]s are not exposed via [`Room`]
+    /// API (they're available via [`PeerConnection::on_data_channel()`]), so
+    /// the received one is only logged.
+    async fn on_new_data_channel(
+        &self,
+        peer_id: PeerId,
+        channel: peer::DataChannel,
+    ) -> Self::Output {
+        logging::emit(
+            log::Level::Debug,
+            Some(peer_id),
+            LogCategory::Media,
+            format_args!(
+                "Data channel `{}` created by remote peer on `{peer_id}`",
+                channel.label(),
+            ),
+        );
+        Ok(())
+    }
+
     /// Invokes `on_local_track` [`Room`]'s callback.
     async fn on_new_local_track(
         &self,
@@ -1821,6 +2735,74 @@ impl PeerEventHandler for InnerRoom {
         Ok(())
     }
 
+    /// Handles [`PeerEvent::RenegotiationNeeded`] event and requests a new
+    /// negotiation of the [`PeerConnection`] with the provided [`PeerId`].
+    ///
+    /// [`PeerConnection`]: peer::PeerConnection
+    async fn on_renegotiation_needed(&self, peer_id: PeerId) -> Self::Output {
+        if let Some(peer_state) = self.peers.state().get(peer_id) {
+            peer_state.renegotiate();
+        }
+        Ok(())
+    }
+
+    /// Handles [`PeerEvent::SignalingStateChanged`] event.
+    ///
+    /// Signaling state is not exposed via [`Room`] API, so the change is only
+    /// logged for glare debugging.
+    async fn on_signaling_state_changed(
+        &self,
+        peer_id: PeerId,
+        signaling_state: platform::SignalingState,
+    ) -> Self::Output {
+        logging::emit(
+            log::Level::Debug,
+            Some(peer_id),
+            LogCategory::Negotiation,
+            format_args!(
+                "`{peer_id}` signaling state changed to `{signaling_state}`",
+            ),
+        );
+        Ok(())
+    }
+
+    /// Handles [`PeerEvent::TransceiverDirectionChanged`] event.
+    ///
+    /// Negotiated directions are not exposed via [`Room`] API, so the change
+    /// is only logged.
+    async fn on_transceiver_direction_changed(
+        &self,
+        peer_id: PeerId,
+        track_id: TrackId,
+        direction: platform::TransceiverDirection,
+    ) -> Self::Output {
+        logging::emit(
+            log::Level::Debug,
+            Some(peer_id),
+            LogCategory::Negotiation,
+            format_args!(
+                "Transceiver of `{track_id}` track on `{peer_id}` negotiated \
+                 `{direction:?}` direction",
+            ),
+        );
+        Ok(())
+    }
+
+    /// Handles [`PeerEvent::StatsScraped`] event by updating the inbound media
+    /// quality of [`Connection`]s and the audio-only fallback.
+    async fn on_stats_scraped(
+        &self,
+        peer_id: PeerId,
+        stats: platform::RtcStats,
+    ) -> Self::Output {
+        if let Some(peer) = self.peers.get(peer_id) {
+            self.connections
+                .update_inbound_stats(peer.receivers_mids(), &stats.0);
+        }
+        self.update_audio_only_fallback();
+        Ok(())
+    }
+
     /// Handles [`PeerEvent::StatsUpdate`] event and sends new stats to the RPC
     /// server.
     async fn on_stats_update(
@@ -1937,4 +2919,12 @@ impl Room {
     ) -> Option<Rc<peer::State>> {
         self.0.peers.state().get(peer_id)
     }
+
+    /// Returns [`Connection`] with the provided remote `Member`.
+    ///
+    /// Used to inspect [`Room`]'s inner state in integration tests.
+    #[must_use]
+    pub fn get_connection(&self, member_id: &MemberId) -> Option<Connection> {
+        self.0.connections.get(member_id)
+    }
 }
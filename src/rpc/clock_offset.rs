@@ -0,0 +1,158 @@
+//! Estimation of the offset between client and server clocks.
+
+use std::{collections::VecDeque, time::Duration};
+
+/// Maximum number of the last clock samples used for estimation, same as in
+/// [NTP clock filter][1].
+///
+/// [1]: https://tools.ietf.org/html/rfc5905#section-10
+const MAX_SAMPLES: usize = 8;
+
+/// Offset between server and client clocks.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ServerTimeOffset {
+    /// Offset (in milliseconds) to be added to the client time to get the
+    /// server time.
+    pub offset_ms: i64,
+
+    /// Maximum error of the [`ServerTimeOffset::offset_ms`].
+    ///
+    /// It's a half of the smallest observed round trip time, so the estimation
+    /// is accurate up to a few milliseconds on low latency networks, while it
+    /// degrades to tens of milliseconds on mobile ones.
+    pub accuracy: Duration,
+}
+
+/// Single clock sample obtained by a request-response exchange with a server.
+#[derive(Clone, Copy, Debug)]
+struct Sample {
+    /// Round trip time of the exchange.
+    rtt: Duration,
+
+    /// Offset (in milliseconds) between server and client clocks, assuming
+    /// the exchange to be symmetric.
+    offset_ms: i64,
+}
+
+/// Estimator of a [`ServerTimeOffset`], using an approach similar to the
+/// [NTP][1] one.
+///
+/// Keeps the last [`MAX_SAMPLES`] clock samples and rejects the ones having
+/// the highest round trip times as outliers, since they're the most likely to
+/// be distorted by asymmetric delays.
+///
+/// [1]: https://tools.ietf.org/html/rfc5905
+#[derive(Clone, Debug, Default)]
+pub struct ClockOffsetEstimator {
+    /// Last clock [`Sample`]s, ordered by their arrival.
+    samples: VecDeque<Sample>,
+}
+
+impl ClockOffsetEstimator {
+    /// Records a new clock sample of a request sent at `sent_at` and answered
+    /// with `server_time` received at `received_at`.
+    ///
+    /// All the times are measured since UNIX epoch.
+    pub fn record(
+        &mut self,
+        sent_at: Duration,
+        received_at: Duration,
+        server_time: Duration,
+    ) {
+        let rtt = received_at.saturating_sub(sent_at);
+        let midpoint = sent_at + rtt / 2;
+        let millis =
+            |d: Duration| i64::try_from(d.as_millis()).unwrap_or_default();
+
+        if self.samples.len() == MAX_SAMPLES {
+            _ = self.samples.pop_front();
+        }
+        self.samples.push_back(Sample {
+            rtt,
+            offset_ms: millis(server_time) - millis(midpoint),
+        });
+    }
+
+    /// Returns the estimated [`ServerTimeOffset`].
+    ///
+    /// Only the better half of the recorded samples (by round trip time) is
+    /// considered, and the median of their offsets is taken.
+    ///
+    /// Returns [`None`] if no samples have been recorded yet.
+    #[must_use]
+    pub fn offset(&self) -> Option<ServerTimeOffset> {
+        let mut samples: Vec<_> = self.samples.iter().copied().collect();
+        samples.sort_by_key(|s| s.rtt);
+        samples.truncate(samples.len().div_ceil(2));
+
+        let accuracy = samples.first()?.rtt / 2;
+        let mut offsets: Vec<_> = samples.iter().map(|s| s.offset_ms).collect();
+        offsets.sort_unstable();
+        let offset_ms = *offsets.get(offsets.len() / 2)?;
+
+        Some(ServerTimeOffset { offset_ms, accuracy })
+    }
+
+    /// Discards all the recorded samples.
+    pub fn reset(&mut self) {
+        self.samples.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn no_offset_without_samples() {
+        assert_eq!(ClockOffsetEstimator::default().offset(), None);
+    }
+
+    #[test]
+    fn compensates_round_trip_time() {
+        let mut estimator = ClockOffsetEstimator::default();
+        estimator.record(ms(1000), ms(1100), ms(5050));
+
+        assert_eq!(
+            estimator.offset(),
+            Some(ServerTimeOffset { offset_ms: 4000, accuracy: ms(50) }),
+        );
+    }
+
+    #[test]
+    fn handles_server_clock_behind() {
+        let mut estimator = ClockOffsetEstimator::default();
+        estimator.record(ms(5000), ms(5020), ms(2010));
+
+        assert_eq!(estimator.offset().map(|o| o.offset_ms), Some(-3000));
+    }
+
+    #[test]
+    fn rejects_high_rtt_outliers() {
+        let mut estimator = ClockOffsetEstimator::default();
+        estimator.record(ms(0), ms(20), ms(1010));
+        estimator.record(ms(100), ms(130), ms(1115));
+        estimator.record(ms(200), ms(1200), ms(1300));
+        estimator.record(ms(300), ms(2300), ms(3000));
+
+        assert_eq!(
+            estimator.offset(),
+            Some(ServerTimeOffset { offset_ms: 1000, accuracy: ms(10) }),
+        );
+    }
+
+    #[test]
+    fn keeps_only_last_samples() {
+        let mut estimator = ClockOffsetEstimator::default();
+        for i in 0..20 {
+            estimator.record(ms(i * 100), ms(i * 100 + 10), ms(i * 100 + 505));
+        }
+
+        assert_eq!(estimator.samples.len(), MAX_SAMPLES);
+        assert_eq!(estimator.offset().map(|o| o.offset_ms), Some(500));
+    }
+}
@@ -1,10 +1,13 @@
 //! Abstraction over RPC transport.
 
 mod backoff_delayer;
+mod clock_offset;
 mod heartbeat;
 mod reconnect_handle;
 pub mod rpc_session;
 pub mod websocket;
+pub mod whep;
+pub mod whip;
 
 use std::str::FromStr;
 
@@ -21,17 +24,24 @@ pub use self::rpc_session::MockRpcSession;
 #[doc(inline)]
 pub use self::{
     backoff_delayer::BackoffDelayer,
+    clock_offset::ServerTimeOffset,
     heartbeat::{Heartbeat, IdleTimeout, PingInterval},
     reconnect_handle::{ReconnectError, ReconnectHandle},
     rpc_session::{
-        RpcSession, SessionError, SessionState, WebSocketRpcSession,
+        RpcSession, SessionError, SessionState, TokenRefresher,
+        WebSocketRpcSession,
     },
-    websocket::{ClientDisconnect, RpcEvent, WebSocketRpcClient},
+    websocket::{
+        ClientDisconnect, RpcEvent, RpcMetrics, WebSocketRpcClient,
+        WebSocketRpcClientPool,
+    },
+    whep::{WhepClient, WhepError},
+    whip::{WhipClient, WhipError},
 };
 use crate::{platform, utils::Caused};
 
 /// [`Url`] to which transport layer will connect.
-#[derive(AsRef, Clone, Debug, Eq, From, PartialEq)]
+#[derive(AsRef, Clone, Debug, Eq, From, Hash, PartialEq)]
 #[as_ref(forward)]
 pub struct ApiUrl(Url);
 
@@ -76,6 +86,13 @@ impl ConnectionInfo {
     pub const fn credential(&self) -> &Credential {
         &self.credential
     }
+
+    /// Returns a copy of this [`ConnectionInfo`] with the provided
+    /// [`Credential`].
+    #[must_use]
+    pub fn with_credential(&self, credential: Credential) -> Self {
+        Self { credential, ..self.clone() }
+    }
 }
 
 /// Errors which can occur while [`ConnectionInfo`] parsing from the [`str`].
@@ -179,7 +196,7 @@ pub enum ClosedStateReason {
 
 /// Reason of why [`WebSocketRpcClient`]/[`platform::RpcTransport`] lost
 /// connection with a server.
-#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
 pub enum ConnectionLostReason {
     /// Connection has been closed with a close frame and the provided message.
     WithMessage(CloseMsg),
@@ -189,6 +206,17 @@ pub enum ConnectionLostReason {
     Idle,
 }
 
+impl ConnectionLostReason {
+    /// Returns the [`CloseMsg`] the connection has been closed with, if any.
+    #[must_use]
+    pub const fn close_msg(&self) -> Option<&CloseMsg> {
+        match self {
+            Self::WithMessage(msg) => Some(msg),
+            Self::Idle => None,
+        }
+    }
+}
+
 /// Errors that may occur in [`WebSocketRpcClient`].
 #[derive(Caused, Clone, Debug, Display, From)]
 #[cause(error = platform::Error)]
@@ -211,7 +239,7 @@ pub enum RpcClientError {
 }
 
 /// Connection with remote was closed.
-#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
 pub enum CloseMsg {
     /// Transport was gracefully closed by remote.
     ///
@@ -224,18 +252,68 @@ pub enum CloseMsg {
     ///
     /// Unexpected close determines by non-`1000` close code and for close code
     /// `1000` without reason.
-    #[display("Abnormal. Code: {_0}")]
-    Abnormal(u16),
+    ///
+    /// Contains the close code and the raw reason string provided by remote
+    /// (may be empty), so an application-specific close (e.g. an expired
+    /// authorization) can be distinguished from a generic connection drop.
+    #[display("Abnormal. Code: {_0}, Reason: {_1}")]
+    Abnormal(u16, String),
+}
+
+impl CloseMsg {
+    /// Returns the [close code][1] of this [`CloseMsg`].
+    ///
+    /// [1]: https://tools.ietf.org/html/rfc6455#section-7.4
+    #[must_use]
+    pub const fn code(&self) -> u16 {
+        match self {
+            Self::Normal(code, _) | Self::Abnormal(code, _) => *code,
+        }
+    }
+
+    /// Returns the close reason of this [`CloseMsg`].
+    #[must_use]
+    pub fn reason(&self) -> String {
+        match self {
+            Self::Normal(_, reason) => reason.to_string(),
+            Self::Abnormal(_, reason) => reason.clone(),
+        }
+    }
 }
 
 impl From<(u16, String)> for CloseMsg {
     fn from((code, reason): (u16, String)) -> Self {
         match code {
             1000 => serde_json::from_str::<CloseDescription>(&reason)
-                .map_or(Self::Abnormal(code), |desc| {
-                    Self::Normal(code, desc.reason)
-                }),
-            _ => Self::Abnormal(code),
+                .map_or_else(
+                    |_| Self::Abnormal(code, reason.clone()),
+                    |desc| Self::Normal(code, desc.reason),
+                ),
+            _ => Self::Abnormal(code, reason),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn close_msg_keeps_code_and_reason() {
+        let normal =
+            CloseMsg::from((1000, r#"{"reason":"Evicted"}"#.to_owned()));
+        assert_eq!(
+            normal,
+            CloseMsg::Normal(1000, CloseByServerReason::Evicted)
+        );
+        assert_eq!(normal.code(), 1000);
+        assert_eq!(normal.reason(), "Evicted");
+
+        let abnormal = CloseMsg::from((4001, "auth expired".to_owned()));
+        assert_eq!(abnormal.code(), 4001);
+        assert_eq!(abnormal.reason(), "auth expired");
+
+        let unparsed = CloseMsg::from((1000, "bye".to_owned()));
+        assert_eq!(unparsed, CloseMsg::Abnormal(1000, "bye".to_owned()));
+    }
+}
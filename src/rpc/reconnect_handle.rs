@@ -7,7 +7,7 @@ use tracerr::Traced;
 
 use crate::{
     platform,
-    rpc::{BackoffDelayer, RpcSession, SessionError},
+    rpc::{BackoffDelayer, CloseMsg, RpcSession, SessionError},
     utils::Caused,
 };
 
@@ -27,14 +27,48 @@ pub enum ReconnectError {
 ///
 /// This handle will be passed to a `Room.on_connection_loss` callback.
 #[derive(Clone, Debug)]
-pub struct ReconnectHandle(#[debug(skip)] Weak<dyn RpcSession>);
+pub struct ReconnectHandle {
+    /// [`RpcSession`] to reconnect.
+    #[debug(skip)]
+    rpc: Weak<dyn RpcSession>,
+
+    /// [`CloseMsg`] the connection has been closed with by a server, if any.
+    close_msg: Option<CloseMsg>,
+}
 
 impl ReconnectHandle {
     /// Instantiates new [`ReconnectHandle`] from the given [`RpcSession`]
     /// reference.
     #[must_use]
-    pub fn new(rpc: Weak<dyn RpcSession>) -> Self {
-        Self(rpc)
+    pub const fn new(rpc: Weak<dyn RpcSession>) -> Self {
+        Self { rpc, close_msg: None }
+    }
+
+    /// Sets the [`CloseMsg`] the connection has been closed with by a server.
+    #[must_use]
+    pub fn with_close_msg(mut self, close_msg: Option<CloseMsg>) -> Self {
+        self.close_msg = close_msg;
+        self
+    }
+
+    /// Returns the WebSocket [close code][1] the connection has been closed
+    /// with by a server, if any.
+    ///
+    /// [1]: https://tools.ietf.org/html/rfc6455#section-7.4
+    #[must_use]
+    pub fn close_code(&self) -> Option<u16> {
+        self.close_msg.as_ref().map(CloseMsg::code)
+    }
+
+    /// Returns the WebSocket close reason the connection has been closed with
+    /// by a server, if any.
+    ///
+    /// Allows to distinguish an application-specific close (e.g. an expired
+    /// authorization, requiring to re-authorize) from a generic connection
+    /// drop (worth retrying).
+    #[must_use]
+    pub fn close_reason(&self) -> Option<String> {
+        self.close_msg.as_ref().map(CloseMsg::reason)
     }
 
     /// Tries to reconnect after the provided delay in milliseconds.
@@ -53,7 +87,7 @@ impl ReconnectHandle {
         platform::delay_for(Duration::from_millis(u64::from(delay_ms))).await;
 
         let rpc = self
-            .0
+            .rpc
             .upgrade()
             .ok_or_else(|| tracerr::new!(ReconnectError::Detached))?;
 
@@ -99,7 +133,7 @@ impl ReconnectHandle {
             max_elapsed_time_ms.map(|val| Duration::from_millis(val.into())),
         )
         .retry(async || {
-            self.0
+            self.rpc
                 .upgrade()
                 .ok_or_else(|| {
                     backoff::Error::Permanent(tracerr::new!(
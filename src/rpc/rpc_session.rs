@@ -5,7 +5,8 @@
 
 use std::{
     cell::{Cell, RefCell},
-    rc::Rc,
+    fmt, ptr,
+    rc::{Rc, Weak},
 };
 
 use async_trait::async_trait;
@@ -24,8 +25,9 @@ use tracerr::Traced;
 use crate::{
     platform,
     rpc::{
-        ClientDisconnect, CloseReason, ConnectionInfo, RpcClientError,
-        WebSocketRpcClient, websocket::RpcEventHandler,
+        ApiUrl, ClientDisconnect, CloseMsg, CloseReason, ConnectionInfo,
+        RpcClientError, ServerTimeOffset, WebSocketRpcClient,
+        WebSocketRpcClientPool, websocket::RpcEventHandler,
     },
     utils::Caused,
 };
@@ -64,6 +66,43 @@ pub enum SessionError {
     /// So old connection process was canceled.
     #[display("New connection info was provided")]
     NewConnectionInfo,
+
+    /// [`TokenRefresher`] failed to provide a fresh authorization token before
+    /// reconnecting.
+    #[display("Failed to refresh authorization token: {_0}")]
+    TokenRefreshFailed(platform::Error),
+}
+
+/// Async callback fetching a fresh authorization token for a
+/// [`WebSocketRpcSession`] before reconnecting to a server.
+#[derive(Clone)]
+pub struct TokenRefresher(
+    Rc<dyn Fn() -> LocalBoxFuture<'static, Result<String, platform::Error>>>,
+);
+
+impl TokenRefresher {
+    /// Creates a new [`TokenRefresher`] from the provided callback.
+    #[must_use]
+    pub fn new<F>(refresh: F) -> Self
+    where
+        F: Fn() -> LocalBoxFuture<'static, Result<String, platform::Error>>
+            + 'static,
+    {
+        Self(Rc::new(refresh))
+    }
+
+    /// Fetches a fresh authorization token.
+    fn refresh(
+        &self,
+    ) -> LocalBoxFuture<'static, Result<String, platform::Error>> {
+        (self.0)()
+    }
+}
+
+impl fmt::Debug for TokenRefresher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TokenRefresher").finish_non_exhaustive()
+    }
 }
 
 /// Reason of why a [`RpcSession`] lost connection with a server.
@@ -77,6 +116,18 @@ pub enum ConnectionLostReason {
     Lost(super::ConnectionLostReason),
 }
 
+impl ConnectionLostReason {
+    /// Returns the [`CloseMsg`] a server has closed the connection with, if
+    /// any.
+    #[must_use]
+    pub const fn close_msg(&self) -> Option<&CloseMsg> {
+        match self {
+            Self::Lost(reason) => reason.close_msg(),
+            Self::ConnectError(_) => None,
+        }
+    }
+}
+
 impl Caused for ConnectionLostReason {
     type Error = platform::Error;
 
@@ -134,15 +185,42 @@ pub trait RpcSession {
     /// client will be dropped.
     fn close_with_reason(&self, close_reason: ClientDisconnect);
 
+    /// Returns the estimated offset between the server and client clocks.
+    ///
+    /// Returns [`None`] if it hasn't been estimated yet.
+    fn server_time_offset(&self) -> Option<ServerTimeOffset>;
+
+    /// Returns the current [`SessionState`] of this [`RpcSession`].
+    fn state(&self) -> SessionState;
+
+    /// Returns [`Stream`] of all [`SessionState`] changes of this
+    /// [`RpcSession`], starting with the current one.
+    ///
+    /// [`Stream`]: futures::Stream
+    fn on_state_change(&self) -> LocalBoxStream<'static, SessionState>;
+
+    /// Asks the server to remove the `Member` of this [`RpcSession`] from its
+    /// `Room`.
+    ///
+    /// [`RpcSession::on_normal_close`] will be resolved once the server
+    /// acknowledges it.
+    fn leave(&self);
+
     /// Subscribe to connection loss events.
     ///
     /// Connection loss is any unexpected [`platform::RpcTransport`] close. In
     /// case of connection loss, client side user should select reconnection
     /// strategy with [`ReconnectHandle`] (or simply close [`Room`]).
     ///
+    /// Provides the [`ConnectionLostReason`], containing the [`CloseMsg`] with
+    /// the close code and reason if the connection has been closed by a
+    /// server.
+    ///
     /// [`ReconnectHandle`]: crate::rpc::ReconnectHandle
     /// [`Room`]: crate::room::Room
-    fn on_connection_loss(&self) -> LocalBoxStream<'static, ()>;
+    fn on_connection_loss(
+        &self,
+    ) -> LocalBoxStream<'static, ConnectionLostReason>;
 
     /// Subscribe to reconnected events.
     ///
@@ -151,6 +229,29 @@ pub trait RpcSession {
     fn on_reconnected(&self) -> LocalBoxStream<'static, ()>;
 }
 
+/// Source of the [`WebSocketRpcClient`] used by a [`WebSocketRpcSession`].
+#[derive(Debug)]
+enum ClientSource {
+    /// The same [`WebSocketRpcClient`] is used regardless of the [`ApiUrl`]
+    /// being connected to.
+    Fixed(Rc<WebSocketRpcClient>),
+
+    /// [`WebSocketRpcClient`] is taken from the [`WebSocketRpcClientPool`]
+    /// for the [`ApiUrl`] being connected to.
+    Pool(Rc<WebSocketRpcClientPool>),
+}
+
+impl ClientSource {
+    /// Returns the [`WebSocketRpcClient`] to connect to the provided
+    /// [`ApiUrl`] with.
+    fn client_for(&self, url: &ApiUrl) -> Rc<WebSocketRpcClient> {
+        match self {
+            Self::Fixed(client) => Rc::clone(client),
+            Self::Pool(pool) => pool.get(url),
+        }
+    }
+}
+
 /// Client to talk with server via Client API RPC.
 ///
 /// Responsible for [`Room`] authorization and closing.
@@ -158,10 +259,16 @@ pub trait RpcSession {
 /// [`Room`]: crate::room::Room
 #[derive(Debug)]
 pub struct WebSocketRpcSession {
+    /// [`ClientSource`] of this [`WebSocketRpcSession`].
+    client_source: ClientSource,
+
     /// [WebSocket] based Rpc Client used to talk with `Medea` server.
     ///
+    /// [`None`] if this [`WebSocketRpcSession`] hasn't been bound to any
+    /// [`WebSocketRpcClient`] yet.
+    ///
     /// [WebSocket]: https://developer.mozilla.org/ru/docs/WebSockets
-    client: Rc<WebSocketRpcClient>,
+    client: RefCell<Option<Rc<WebSocketRpcClient>>>,
 
     /// Current [`SessionState`] of this [`WebSocketRpcSession`].
     state: ObservableCell<SessionState>,
@@ -172,6 +279,9 @@ pub struct WebSocketRpcSession {
 
     /// Subscribers of the [`RpcSession::subscribe`].
     event_txs: RefCell<Vec<mpsc::UnboundedSender<Event>>>,
+
+    /// [`TokenRefresher`] invoked before each reconnection, if any.
+    token_refresher: RefCell<Option<TokenRefresher>>,
 }
 
 impl WebSocketRpcSession {
@@ -180,21 +290,81 @@ impl WebSocketRpcSession {
     ///
     /// Spawns all [`WebSocketRpcSession`] task.
     pub fn new(client: Rc<WebSocketRpcClient>) -> Rc<Self> {
+        let this =
+            Self::with_client_source(ClientSource::Fixed(Rc::clone(&client)));
+        this.bind_client(client);
+        this
+    }
+
+    /// Returns new uninitialized [`WebSocketRpcSession`] taking its
+    /// [`WebSocketRpcClient`] from the provided [`WebSocketRpcClientPool`]
+    /// once the [`ApiUrl`] to connect to is known.
+    ///
+    /// Spawns all [`WebSocketRpcSession`] task.
+    pub fn with_pool(pool: Rc<WebSocketRpcClientPool>) -> Rc<Self> {
+        Self::with_client_source(ClientSource::Pool(pool))
+    }
+
+    /// Returns new uninitialized [`WebSocketRpcSession`] with the provided
+    /// [`ClientSource`], not bound to any [`WebSocketRpcClient`] yet.
+    fn with_client_source(client_source: ClientSource) -> Rc<Self> {
         let this = Rc::new(Self {
-            client,
+            client_source,
+            client: RefCell::new(None),
             state: ObservableCell::new(SessionState::Uninitialized),
             can_reconnect: Rc::new(Cell::new(false)),
             event_txs: RefCell::default(),
+            token_refresher: RefCell::new(None),
         });
 
         this.spawn_state_watcher();
-        this.spawn_connection_loss_watcher();
-        this.spawn_close_watcher();
-        this.spawn_server_msg_listener();
 
         this
     }
 
+    /// Sets the [`TokenRefresher`] invoked before each reconnection of this
+    /// [`WebSocketRpcSession`] to fetch a fresh authorization token.
+    ///
+    /// If it fails, the reconnection attempt fails with a
+    /// [`SessionError::TokenRefreshFailed`], so the
+    /// [`ReconnectHandle::reconnect_with_backoff()`] backs off and retries
+    /// it.
+    ///
+    /// [`ReconnectHandle::reconnect_with_backoff()`]: crate::rpc::ReconnectHandle::reconnect_with_backoff
+    pub fn set_token_refresher(&self, refresher: Option<TokenRefresher>) {
+        drop(self.token_refresher.replace(refresher));
+    }
+
+    /// Returns the [`WebSocketRpcClient`] to connect to the provided
+    /// [`ApiUrl`] with, binding this [`WebSocketRpcSession`] to it if it's not
+    /// bound yet.
+    fn client_for(self: &Rc<Self>, url: &ApiUrl) -> Rc<WebSocketRpcClient> {
+        let client = self.client_source.client_for(url);
+        if !self.is_bound_to(&Rc::downgrade(&client)) {
+            self.bind_client(Rc::clone(&client));
+        }
+        client
+    }
+
+    /// Binds this [`WebSocketRpcSession`] to the provided
+    /// [`WebSocketRpcClient`] and spawns all its [`WebSocketRpcClient`]
+    /// related tasks.
+    fn bind_client(self: &Rc<Self>, client: Rc<WebSocketRpcClient>) {
+        self.spawn_connection_loss_watcher(&client);
+        self.spawn_close_watcher(&client);
+        self.spawn_server_msg_listener(&client);
+        drop(self.client.replace(Some(client)));
+    }
+
+    /// Indicates whether this [`WebSocketRpcSession`] is currently bound to
+    /// the provided [`WebSocketRpcClient`].
+    fn is_bound_to(&self, client: &Weak<WebSocketRpcClient>) -> bool {
+        self.client
+            .borrow()
+            .as_ref()
+            .is_some_and(|c| ptr::eq(Rc::as_ptr(c), client.as_ptr()))
+    }
+
     /// Tries to establish transport connection to media server and authorize
     /// RPC session.
     ///
@@ -220,13 +390,29 @@ impl WebSocketRpcSession {
     ///
     /// Errors with [`SessionError`] if [`SessionState`] goes into
     /// [`SessionState::Lost`].
+    ///
+    /// Errors with [`SessionError::TokenRefreshFailed`] if the
+    /// [`TokenRefresher`] fails before reconnecting.
     async fn inner_connect(self: Rc<Self>) -> Result<(), Traced<SessionError>> {
         use SessionError as E;
         use SessionState as S;
 
         match self.state.get() {
             S::Connecting(_) | S::Authorizing(_) | S::Opened(_) => {}
-            S::Initialized(info) | S::Lost(_, info) => {
+            S::Initialized(info) => {
+                self.state.set(S::Connecting(info));
+            }
+            S::Lost(_, info) => {
+                let refresher = self.token_refresher.borrow().clone();
+                let info = if let Some(refresher) = refresher {
+                    let token = refresher
+                        .refresh()
+                        .await
+                        .map_err(|e| tracerr::new!(E::TokenRefreshFailed(e)))?;
+                    Rc::new(info.with_credential(token.into()))
+                } else {
+                    info
+                };
                 self.state.set(S::Connecting(info));
             }
             S::Uninitialized => {
@@ -273,7 +459,8 @@ impl WebSocketRpcSession {
             while let Some(state) = state_updates.next().await {
                 let this = upgrade_or_break!(weak_this);
                 match state {
-                    S::Connecting(info) => match Rc::clone(&this.client)
+                    S::Connecting(info) => match this
+                        .client_for(&info.url)
                         .connect(info.url.clone())
                         .await
                     {
@@ -288,7 +475,10 @@ impl WebSocketRpcSession {
                         }
                     },
                     S::Authorizing(info) => {
-                        this.client.join_room(
+                        let Some(client) = this.client.borrow().clone() else {
+                            continue;
+                        };
+                        client.join_room(
                             info.room_id.clone(),
                             info.member_id.clone(),
                             info.credential.clone(),
@@ -310,14 +500,21 @@ impl WebSocketRpcSession {
     /// Handler for the [`WebSocketRpcClient::on_connection_loss`].
     ///
     /// Sets [`WebSocketRpcSession::state`] to the [`SessionState::Lost`].
-    fn spawn_connection_loss_watcher(self: &Rc<Self>) {
+    fn spawn_connection_loss_watcher(
+        self: &Rc<Self>,
+        client: &Rc<WebSocketRpcClient>,
+    ) {
         use SessionState as S;
 
-        let mut client_on_connection_loss = self.client.on_connection_loss();
+        let mut client_on_connection_loss = client.on_connection_loss();
+        let weak_client = Rc::downgrade(client);
         let weak_this = Rc::downgrade(self);
         platform::spawn(async move {
             while let Some(reason) = client_on_connection_loss.next().await {
                 let this = upgrade_or_break!(weak_this);
+                if !this.is_bound_to(&weak_client) {
+                    break;
+                }
 
                 let state = this.state.get();
                 if matches!(state, S::Opened(_)) {
@@ -342,26 +539,36 @@ impl WebSocketRpcSession {
     }
 
     /// Spawns [`WebSocketRpcClient::on_normal_close`] listener.
-    fn spawn_close_watcher(self: &Rc<Self>) {
-        let on_normal_close = self.client.on_normal_close();
+    fn spawn_close_watcher(self: &Rc<Self>, client: &Rc<WebSocketRpcClient>) {
+        let on_normal_close = client.on_normal_close();
+        let weak_client = Rc::downgrade(client);
         let weak_this = Rc::downgrade(self);
         platform::spawn(async move {
             let reason = on_normal_close.await.unwrap_or_else(|_| {
                 ClientDisconnect::RpcClientUnexpectedlyDropped.into()
             });
             if let Some(this) = weak_this.upgrade() {
-                this.state.set(SessionState::Finished(reason));
+                if this.is_bound_to(&weak_client) {
+                    this.state.set(SessionState::Finished(reason));
+                }
             }
         });
     }
 
     /// Spawns [`WebSocketRpcClient::subscribe`] listener.
-    fn spawn_server_msg_listener(self: &Rc<Self>) {
-        let mut server_msg_rx = self.client.subscribe();
+    fn spawn_server_msg_listener(
+        self: &Rc<Self>,
+        client: &Rc<WebSocketRpcClient>,
+    ) {
+        let mut server_msg_rx = client.subscribe();
+        let weak_client = Rc::downgrade(client);
         let weak_this = Rc::downgrade(self);
         platform::spawn(async move {
             while let Some(msg) = server_msg_rx.next().await {
                 let this = upgrade_or_break!(weak_this);
+                if !this.is_bound_to(&weak_client) {
+                    break;
+                }
                 msg.dispatch_with(this.as_ref());
             }
         });
@@ -430,7 +637,9 @@ impl RpcSession for WebSocketRpcSession {
     /// [`SessionState::Opened`].
     fn send_command(&self, command: Command) {
         if let SessionState::Opened(info) = self.state.get() {
-            self.client.send_command(info.room_id.clone(), command);
+            if let Some(client) = self.client.borrow().as_ref() {
+                client.send_command(info.room_id.clone(), command);
+            }
         }
     }
 
@@ -464,31 +673,63 @@ impl RpcSession for WebSocketRpcSession {
     /// Provided [`ClientDisconnect`] will be provided to the underlying
     /// [`WebSocketRpcClient`] with [`WebSocketRpcClient::set_close_reason`].
     fn close_with_reason(&self, close_reason: ClientDisconnect) {
-        if let SessionState::Opened(info) = self.state.get() {
-            self.client
-                .leave_room(info.room_id.clone(), info.member_id.clone());
+        if let Some(client) = self.client.borrow().as_ref() {
+            if let SessionState::Opened(info) = self.state.get() {
+                client.leave_room(info.room_id.clone(), info.member_id.clone());
+            }
+            client.set_close_reason(close_reason);
         }
-
-        self.client.set_close_reason(close_reason);
         self.state.set(SessionState::Finished(close_reason.into()));
     }
 
+    fn state(&self) -> SessionState {
+        self.state.get()
+    }
+
+    fn on_state_change(&self) -> LocalBoxStream<'static, SessionState> {
+        self.state.subscribe()
+    }
+
+    fn server_time_offset(&self) -> Option<ServerTimeOffset> {
+        self.client.borrow().as_ref()?.server_time_offset()
+    }
+
+    /// Sends [`Command::LeaveRoom`] to the server if current [`SessionState`]
+    /// is [`SessionState::Opened`].
+    ///
+    /// [`SessionState`] will be transited to the [`SessionState::Finished`]
+    /// once the server acknowledges it.
+    fn leave(&self) {
+        if let SessionState::Opened(info) = self.state.get() {
+            if let Some(client) = self.client.borrow().as_ref() {
+                client.leave_room(info.room_id.clone(), info.member_id.clone());
+            }
+        }
+    }
+
     /// Returns [`Stream`] which will provided `Some(())` every time when
     /// [`SessionState`] goes to the [`SessionState::Lost`].
     ///
     /// [`Stream`]: futures::Stream
-    fn on_connection_loss(&self) -> LocalBoxStream<'static, ()> {
+    fn on_connection_loss(
+        &self,
+    ) -> LocalBoxStream<'static, ConnectionLostReason> {
         let can_reconnect = Rc::clone(&self.can_reconnect);
         self.state
             .subscribe()
             .filter_map(move |state| {
-                if matches!(state, SessionState::Lost(_, _))
-                    && can_reconnect.get()
-                {
-                    future::ready(Some(()))
-                } else {
-                    future::ready(None)
-                }
+                future::ready(match state {
+                    SessionState::Lost(reason, _) if can_reconnect.get() => {
+                        Some(reason)
+                    }
+                    SessionState::Uninitialized
+                    | SessionState::Initialized(_)
+                    | SessionState::Connecting(_)
+                    | SessionState::Authorizing(_)
+                    | SessionState::Lost(..)
+                    | SessionState::Opened(_)
+                    | SessionState::Finished(_) => None,
+                })
             })
             .boxed_local()
     }
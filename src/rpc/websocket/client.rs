@@ -2,12 +2,12 @@
 //!
 //! [WebSocket]: https://developer.mozilla.org/ru/docs/WebSockets
 
-use std::{cell::RefCell, rc::Rc, time::Duration};
+use std::{cell::RefCell, collections::HashMap, rc::Rc, time::Duration};
 
 use derive_more::with_trait::{Debug, Display};
 use futures::{
     channel::{mpsc, oneshot},
-    future::LocalBoxFuture,
+    future::{self, LocalBoxFuture},
     stream::{LocalBoxStream, StreamExt as _},
 };
 use medea_client_api_proto::{
@@ -23,10 +23,28 @@ use crate::{
     platform,
     rpc::{
         ApiUrl, CloseMsg, CloseReason, ClosedStateReason, ConnectionLostReason,
-        Heartbeat, IdleTimeout, PingInterval, RpcClientError,
+        Heartbeat, IdleTimeout, PingInterval, RpcClientError, ServerTimeOffset,
+        clock_offset::ClockOffsetEstimator,
+        websocket::metrics::{MeteredTransport, RpcMetrics},
+    },
+    utils::{
+        TaskHandle,
+        logging::{self, LogCategory},
     },
 };
 
+/// Number of the first [`ClientMsg::TimeSyncRequest`]s sent with the
+/// [`TIME_SYNC_BURST_INTERVAL`] after a connection is established.
+const TIME_SYNC_BURST_LEN: u32 = 8;
+
+/// Interval of sending the first [`TIME_SYNC_BURST_LEN`]
+/// [`ClientMsg::TimeSyncRequest`]s.
+const TIME_SYNC_BURST_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Interval of sending [`ClientMsg::TimeSyncRequest`]s after the first
+/// [`TIME_SYNC_BURST_LEN`] ones.
+const TIME_SYNC_INTERVAL: Duration = Duration::from_secs(30);
+
 /// Reasons of closing WebSocket RPC connection by a client side.
 #[derive(Copy, Clone, Display, Debug, Eq, PartialEq, Serialize)]
 pub enum ClientDisconnect {
@@ -127,6 +145,29 @@ struct Inner {
 
     /// Current [`ClientState`] of this [`WebSocketRpcClient`].
     state: ObservableCell<ClientState>,
+
+    /// Estimator of the offset between the server and client clocks.
+    clock_offset: ClockOffsetEstimator,
+
+    /// Sending times of the pending [`ClientMsg::TimeSyncRequest`]s by their
+    /// numbers.
+    time_sync_requests: HashMap<u32, Duration>,
+
+    /// Number of the last sent [`ClientMsg::TimeSyncRequest`].
+    last_time_sync_num: u32,
+
+    /// [`TaskHandle`] of the task sending [`ClientMsg::TimeSyncRequest`]s.
+    time_sync_task: Option<TaskHandle>,
+
+    /// [`RpcMetrics`] of this [`WebSocketRpcClient`].
+    ///
+    /// Shared with the [`MeteredTransport`] wrapping the current
+    /// [`platform::RpcTransport`].
+    metrics: Rc<RefCell<RpcMetrics>>,
+
+    /// Indicator whether this [`WebSocketRpcClient`] has ever established a
+    /// connection, so the next one is a reconnect.
+    was_connected: bool,
 }
 
 /// Factory closure producing a [`platform::RpcTransport`].
@@ -147,6 +188,12 @@ impl Inner {
             state: ObservableCell::new(ClientState::Closed(
                 ClosedStateReason::NeverConnected,
             )),
+            clock_offset: ClockOffsetEstimator::default(),
+            time_sync_requests: HashMap::new(),
+            last_time_sync_num: 0,
+            time_sync_task: None,
+            metrics: Rc::default(),
+            was_connected: false,
         })
     }
 }
@@ -233,15 +280,16 @@ impl WebSocketRpcClient {
     /// Stops [`Heartbeat`] and notifies all
     /// [`WebSocketRpcClient::on_connection_loss`] subs about connection
     /// loss.
-    fn handle_connection_loss(&self, close_msg: ConnectionLostReason) {
+    fn handle_connection_loss(&self, close_msg: &ConnectionLostReason) {
         self.0.borrow().state.set(ClientState::Closed(
-            ClosedStateReason::ConnectionLost(close_msg),
+            ClosedStateReason::ConnectionLost(close_msg.clone()),
         ));
         drop(self.0.borrow_mut().heartbeat.take());
+        drop(self.0.borrow_mut().time_sync_task.take());
         self.0
             .borrow_mut()
             .on_connection_loss_subs
-            .retain(|sub| sub.unbounded_send(close_msg).is_ok());
+            .retain(|sub| sub.unbounded_send(close_msg.clone()).is_ok());
     }
 
     /// Handles [`CloseMsg`] from a remote server.
@@ -250,12 +298,13 @@ impl WebSocketRpcClient {
     /// abnormal) regardless of the [`CloseReason`].
     fn handle_close_message(&self, close_msg: CloseMsg) {
         drop(self.0.borrow_mut().heartbeat.take());
+        drop(self.0.borrow_mut().time_sync_task.take());
 
         match close_msg {
             CloseMsg::Normal(_, reason) => match reason {
                 CloseByServerReason::Reconnected => (),
                 CloseByServerReason::Idle => {
-                    self.handle_connection_loss(ConnectionLostReason::Idle);
+                    self.handle_connection_loss(&ConnectionLostReason::Idle);
                 }
                 CloseByServerReason::Finished
                 | CloseByServerReason::Rejected
@@ -276,16 +325,18 @@ impl WebSocketRpcClient {
                         });
                 }
             },
-            CloseMsg::Abnormal(_) => {
-                self.handle_connection_loss(ConnectionLostReason::WithMessage(
-                    close_msg,
-                ));
+            CloseMsg::Abnormal(..) => {
+                self.handle_connection_loss(
+                    &ConnectionLostReason::WithMessage(close_msg),
+                );
             }
         }
     }
 
     /// Handles [`ServerMsg`]s from a remote server.
     fn on_transport_message(&self, msg: ServerMsg) {
+        self.0.borrow().metrics.borrow_mut().messages_received += 1;
+
         let msg = match msg {
             ServerMsg::Event { room_id, event } => match event {
                 Event::RoomJoined { member_id } => {
@@ -302,7 +353,9 @@ impl WebSocketRpcClient {
                 | Event::PeersRemoved { .. }
                 | Event::PeerUpdated { .. }
                 | Event::ConnectionQualityUpdated { .. }
-                | Event::StateSynchronized { .. } => {
+                | Event::StateSynchronized { .. }
+                | Event::IceServersUpdated { .. }
+                | Event::ApplicationMessageReceived { .. } => {
                     Some(RpcEvent::Event { room_id, event })
                 }
             },
@@ -327,6 +380,18 @@ impl WebSocketRpcClient {
                 );
                 None
             }
+            ServerMsg::TimeSyncResponse { num, server_time_ms } => {
+                let received_at = platform::now();
+                let mut inner = self.0.borrow_mut();
+                if let Some(sent_at) = inner.time_sync_requests.remove(&num) {
+                    inner.clock_offset.record(
+                        sent_at,
+                        received_at,
+                        Duration::from_millis(server_time_ms),
+                    );
+                }
+                None
+            }
             ServerMsg::Ping(_) => None,
         };
         if let Some(m) = msg {
@@ -359,13 +424,77 @@ impl WebSocketRpcClient {
         platform::spawn(async move {
             while on_idle.next().await.is_some() {
                 if let Some(this) = weak_this.upgrade() {
-                    this.handle_connection_loss(ConnectionLostReason::Idle);
+                    this.handle_connection_loss(&ConnectionLostReason::Idle);
                 }
             }
         });
         self.0.borrow_mut().heartbeat = Some(heartbeat);
     }
 
+    /// Sends a new [`ClientMsg::TimeSyncRequest`] to the server.
+    fn send_time_sync_request(&self) {
+        let mut inner = self.0.borrow_mut();
+        let Some(socket) = inner.sock.clone() else {
+            return;
+        };
+
+        let num = inner.last_time_sync_num.wrapping_add(1);
+        inner.last_time_sync_num = num;
+        inner
+            .time_sync_requests
+            .retain(|n, _| num.wrapping_sub(*n) < TIME_SYNC_BURST_LEN);
+        _ = inner.time_sync_requests.insert(num, platform::now());
+        drop(inner);
+
+        if let Err(e) = socket
+            .send(&ClientMsg::TimeSyncRequest(num))
+            .map_err(tracerr::map_from_and_wrap!(=> RpcClientError))
+        {
+            logging::emit(
+                log::Level::Error,
+                None,
+                LogCategory::Signalling,
+                format_args!("{e}"),
+            );
+        }
+    }
+
+    /// Starts sending [`ClientMsg::TimeSyncRequest`]s to the server for
+    /// estimating the offset between the server and client clocks.
+    ///
+    /// Must be called only if the server advertised its support via
+    /// [`RpcSettings::time_sync`].
+    ///
+    /// Sends a burst of [`TIME_SYNC_BURST_LEN`] requests first to get a
+    /// reasonable estimation quickly, and keeps it fresh with rarer requests
+    /// afterwards.
+    fn start_time_sync(self: &Rc<Self>) {
+        {
+            let mut inner = self.0.borrow_mut();
+            inner.clock_offset.reset();
+            inner.time_sync_requests.clear();
+        }
+
+        let weak_this = Rc::downgrade(self);
+        let (time_sync_fut, time_sync_handle) = future::abortable(async move {
+            let mut sent = 0;
+            loop {
+                upgrade_or_break!(weak_this).send_time_sync_request();
+                sent += 1;
+                platform::delay_for(if sent < TIME_SYNC_BURST_LEN {
+                    TIME_SYNC_BURST_INTERVAL
+                } else {
+                    TIME_SYNC_INTERVAL
+                })
+                .await;
+            }
+        });
+        platform::spawn(async move {
+            _ = time_sync_fut.await.ok();
+        });
+        self.0.borrow_mut().time_sync_task = Some(time_sync_handle.into());
+    }
+
     /// Tries to establish [`WebSocketRpcClient`] connection.
     async fn establish_connection(
         self: Rc<Self>,
@@ -375,7 +504,13 @@ impl WebSocketRpcClient {
         self.0.borrow().state.set(ClientState::Connecting);
 
         // Wait for transport opening.
-        let transport = (self.0.borrow().rpc_transport_factory)();
+        let transport: Rc<dyn platform::RpcTransport> = {
+            let inner = self.0.borrow();
+            Rc::new(MeteredTransport::new(
+                (inner.rpc_transport_factory)(),
+                Rc::clone(&inner.metrics),
+            ))
+        };
         let mut on_message = transport.on_message();
         transport.connect(url).await.map_err(|e| {
             let transport_err = e.into_inner();
@@ -388,10 +523,12 @@ impl WebSocketRpcClient {
         })?;
 
         // Wait for `ServerMsg::RpcSettings`.
-        if let Some(msg) = on_message.next().await {
+        let time_sync = if let Some(msg) = on_message.next().await {
+            self.0.borrow().metrics.borrow_mut().messages_received += 1;
             if let ServerMsg::RpcSettings(rpc_settings) = msg {
                 Rc::clone(&self)
                     .start_heartbeat(Rc::clone(&transport), rpc_settings);
+                rpc_settings.time_sync
             } else {
                 let close_reason =
                     ClosedStateReason::FirstServerMsgIsNotRpcSettings;
@@ -410,7 +547,7 @@ impl WebSocketRpcClient {
             return Err(tracerr::new!(RpcClientError::ConnectionFailed(
                 ClosedStateReason::FirstServerMsgIsNotRpcSettings
             )));
-        }
+        };
 
         // Subscribe to transport closing.
         {
@@ -441,6 +578,18 @@ impl WebSocketRpcClient {
         }
 
         drop(self.0.borrow_mut().sock.replace(transport));
+        {
+            let mut inner = self.0.borrow_mut();
+            if inner.was_connected {
+                inner.metrics.borrow_mut().reconnects += 1;
+            }
+            inner.was_connected = true;
+        }
+        if time_sync {
+            self.start_time_sync();
+        } else {
+            self.0.borrow_mut().clock_offset.reset();
+        }
         self.0.borrow().state.set(ClientState::Open);
 
         Ok(())
@@ -492,8 +641,9 @@ impl WebSocketRpcClient {
         self: Rc<Self>,
         url: ApiUrl,
     ) -> Result<(), Traced<RpcClientError>> {
+        let metrics = Rc::clone(&self.0.borrow().metrics);
         let current_url = self.0.borrow().url.clone();
-        if current_url.as_ref() == Some(&url) {
+        let res = if current_url.as_ref() == Some(&url) {
             let state = self.0.borrow().state.borrow().clone();
             match state {
                 ClientState::Open => Ok(()),
@@ -502,7 +652,11 @@ impl WebSocketRpcClient {
             }
         } else {
             self.establish_connection(url).await
+        };
+        if let Err(e) = &res {
+            metrics.borrow_mut().record_error(e.as_ref().clone());
         }
+        res
     }
 
     /// Subscribes on this [`WebSocketRpcClient`]'s [`RpcEvent`]s.
@@ -557,6 +711,22 @@ impl WebSocketRpcClient {
         Box::pin(rx)
     }
 
+    /// Returns the estimated [`ServerTimeOffset`] of this
+    /// [`WebSocketRpcClient`].
+    ///
+    /// Returns [`None`] if no [`ServerMsg::TimeSyncResponse`]s have been
+    /// received since the connection was established.
+    #[must_use]
+    pub fn server_time_offset(&self) -> Option<ServerTimeOffset> {
+        self.0.borrow().clock_offset.offset()
+    }
+
+    /// Returns a snapshot of the [`RpcMetrics`] of this [`WebSocketRpcClient`].
+    #[must_use]
+    pub fn metrics(&self) -> RpcMetrics {
+        self.0.borrow().metrics.borrow().clone()
+    }
+
     /// Sets reason being passed to the underlying transport when this client is
     /// dropped.
     pub fn set_close_reason(&self, close_reason: ClientDisconnect) {
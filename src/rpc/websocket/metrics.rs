@@ -0,0 +1,154 @@
+//! Metrics of a [`WebSocketRpcClient`].
+//!
+//! [`WebSocketRpcClient`]: super::WebSocketRpcClient
+
+use std::{cell::RefCell, io, rc::Rc};
+
+use async_trait::async_trait;
+use futures::stream::LocalBoxStream;
+use medea_client_api_proto::{ClientMsg, ServerMsg};
+use tracerr::Traced;
+
+use crate::{
+    platform::{RpcTransport, TransportError, TransportState},
+    rpc::{ApiUrl, ClientDisconnect, RpcClientError},
+    utils::logging::{self, LogCategory},
+};
+
+/// Snapshot of the counters of a [`WebSocketRpcClient`].
+///
+/// Counters are accumulated over all the connections of the
+/// [`WebSocketRpcClient`], including the ones before reconnects.
+///
+/// [`WebSocketRpcClient`]: super::WebSocketRpcClient
+#[derive(Clone, Debug, Default)]
+pub struct RpcMetrics {
+    /// Number of [`ClientMsg`]s sent to a server, including heartbeat ones.
+    pub messages_sent: u64,
+
+    /// Number of [`ServerMsg`]s received from a server, including heartbeat
+    /// ones.
+    pub messages_received: u64,
+
+    /// Total size (in bytes) of the serialized [`ClientMsg`]s sent to a
+    /// server.
+    ///
+    /// Doesn't account any framing or compression overhead of the underlying
+    /// [`RpcTransport`].
+    pub bytes_sent: u64,
+
+    /// Number of connections re-established after the first one.
+    pub reconnects: u64,
+
+    /// Last error occurred while connecting to a server or sending a
+    /// [`ClientMsg`] to it.
+    pub last_error: Option<RpcClientError>,
+}
+
+impl RpcMetrics {
+    /// Records the provided [`RpcClientError`] as the
+    /// [`RpcMetrics::last_error`].
+    pub(super) fn record_error(&mut self, err: RpcClientError) {
+        self.last_error = Some(err);
+    }
+}
+
+/// [`RpcTransport`] wrapper counting the [`ClientMsg`]s sent via the wrapped
+/// one in the shared [`RpcMetrics`].
+pub(super) struct MeteredTransport {
+    /// Wrapped [`RpcTransport`].
+    transport: Rc<dyn RpcTransport>,
+
+    /// [`RpcMetrics`] to update.
+    metrics: Rc<RefCell<RpcMetrics>>,
+}
+
+impl MeteredTransport {
+    /// Wraps the provided [`RpcTransport`] into a [`MeteredTransport`] updating
+    /// the provided [`RpcMetrics`].
+    pub(super) fn new(
+        transport: Rc<dyn RpcTransport>,
+        metrics: Rc<RefCell<RpcMetrics>>,
+    ) -> Self {
+        Self { transport, metrics }
+    }
+}
+
+#[async_trait(?Send)]
+impl RpcTransport for MeteredTransport {
+    async fn connect(&self, url: ApiUrl) -> Result<(), Traced<TransportError>> {
+        self.transport.connect(url).await
+    }
+
+    fn on_message(&self) -> LocalBoxStream<'static, ServerMsg> {
+        self.transport.on_message()
+    }
+
+    fn set_close_reason(&self, reason: ClientDisconnect) {
+        self.transport.set_close_reason(reason);
+    }
+
+    fn send(&self, msg: &ClientMsg) -> Result<(), Traced<TransportError>> {
+        let res = self.transport.send(msg);
+        let mut metrics = self.metrics.borrow_mut();
+        match &res {
+            Ok(()) => {
+                metrics.messages_sent += 1;
+                metrics.bytes_sent += serialized_len(msg);
+            }
+            Err(e) => {
+                metrics.record_error(e.as_ref().clone().into());
+            }
+        }
+        res
+    }
+
+    fn on_state_change(&self) -> LocalBoxStream<'static, TransportState> {
+        self.transport.on_state_change()
+    }
+}
+
+/// Returns the size (in bytes) of the provided [`ClientMsg`] serialized into
+/// JSON, without allocating it.
+fn serialized_len(msg: &ClientMsg) -> u64 {
+    /// [`io::Write`] counting the written bytes.
+    struct Counter(u64);
+
+    impl io::Write for Counter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0 =
+                self.0.saturating_add(buf.len().try_into().unwrap_or(u64::MAX));
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter(0);
+    if let Err(e) = serde_json::to_writer(&mut counter, msg) {
+        logging::emit(
+            log::Level::Error,
+            None,
+            LogCategory::Stats,
+            format_args!("Failed to serialize `ClientMsg`: {e}"),
+        );
+    }
+    counter.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialized_len_matches_json() {
+        let msg = ClientMsg::Pong(42);
+
+        assert_eq!(
+            serialized_len(&msg),
+            u64::try_from(serde_json::to_string(&msg).unwrap().len()).unwrap(),
+        );
+    }
+}
@@ -3,9 +3,13 @@
 //! [WebSocket]: https://developer.mozilla.org/ru/docs/WebSockets
 
 mod client;
+mod metrics;
+mod pool;
 
 #[doc(inline)]
 pub use self::client::{
     ClientDisconnect, ClientState, RpcEvent, RpcEventHandler,
     RpcTransportFactory, WebSocketRpcClient,
 };
+#[doc(inline)]
+pub use self::{metrics::RpcMetrics, pool::WebSocketRpcClientPool};
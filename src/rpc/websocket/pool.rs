@@ -0,0 +1,61 @@
+//! Pool of [`WebSocketRpcClient`]s shared between `Room`s.
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    rc::{Rc, Weak},
+};
+
+use derive_more::with_trait::Debug;
+
+use crate::{
+    platform,
+    rpc::{ApiUrl, WebSocketRpcClient},
+};
+
+/// Pool of [`WebSocketRpcClient`]s, multiplexing all the `Room`s targeting the
+/// same [`ApiUrl`] over a single [`WebSocketRpcClient`].
+///
+/// Holds [`WebSocketRpcClient`]s weakly, so the underlying
+/// [`platform::RpcTransport`] is closed once the last `Room` using it is
+/// closed.
+#[derive(Debug)]
+pub struct WebSocketRpcClientPool {
+    /// [`WebSocketRpcClient`]s of this [`WebSocketRpcClientPool`] by the
+    /// [`ApiUrl`]s they're connected to.
+    clients: RefCell<HashMap<ApiUrl, Weak<WebSocketRpcClient>>>,
+
+    /// Closure creating new [`platform::RpcTransport`]s for the
+    /// [`WebSocketRpcClient`]s of this [`WebSocketRpcClientPool`].
+    #[debug("{rpc_transport_factory:p}")]
+    rpc_transport_factory: Rc<dyn Fn() -> Rc<dyn platform::RpcTransport>>,
+}
+
+impl WebSocketRpcClientPool {
+    /// Creates a new empty [`WebSocketRpcClientPool`] creating
+    /// [`platform::RpcTransport`]s with the provided closure.
+    #[must_use]
+    pub fn new(
+        rpc_transport_factory: Rc<dyn Fn() -> Rc<dyn platform::RpcTransport>>,
+    ) -> Self {
+        Self { clients: RefCell::default(), rpc_transport_factory }
+    }
+
+    /// Returns a [`WebSocketRpcClient`] for the provided [`ApiUrl`], creating
+    /// a new one if there is no alive [`WebSocketRpcClient`] for it yet.
+    #[must_use]
+    pub fn get(&self, url: &ApiUrl) -> Rc<WebSocketRpcClient> {
+        let mut clients = self.clients.borrow_mut();
+        clients.retain(|_, client| client.strong_count() > 0);
+
+        if let Some(client) = clients.get(url).and_then(Weak::upgrade) {
+            return client;
+        }
+
+        let factory = Rc::clone(&self.rpc_transport_factory);
+        let client =
+            Rc::new(WebSocketRpcClient::new(Box::new(move || factory())));
+        drop(clients.insert(url.clone(), Rc::downgrade(&client)));
+        client
+    }
+}
@@ -0,0 +1,402 @@
+//! [WHEP] signalling backend, playing media from a [WHEP] endpoint of a
+//! standard media server instead of a Medea one.
+//!
+//! [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+
+use std::{cell::RefCell, rc::Rc};
+
+use derive_more::with_trait::{Display, From};
+use futures::{channel::mpsc, future};
+use medea_client_api_proto::{
+    self as proto, AudioSettings, ConnectionMode, IceServer, MediaDirection,
+    MediaSourceKind, MediaType, MemberId, NegotiationRole, PeerConnectionState,
+    PeerId, TrackId, VideoSettings,
+};
+use tracerr::Traced;
+use url::Url;
+
+use super::whip::{
+    SDP_CONTENT_TYPE, authorized, trickle_candidates, wait_for_offer,
+};
+use crate::{
+    api,
+    connection::Connections,
+    media::{LocalTracksConstraints, MediaManager, RecvConstraints},
+    peer::{self, PeerConnection, PeerEvent, RtcPeerConnectionError},
+    platform,
+    utils::{Caused, TaskHandle},
+};
+
+/// ID of the remote `Member` all the [`remote::Track`]s played from a [WHEP]
+/// endpoint are attributed to.
+///
+/// [`remote::Track`]: crate::media::track::remote::Track
+/// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+const REMOTE_MEMBER_ID: &str = "whep";
+
+/// Errors occurring in a [`WhepClient`].
+#[derive(Caused, Clone, Debug, Display, From)]
+#[cause(error = platform::Error)]
+pub enum WhepError {
+    /// Failed to create a [`PeerConnection`].
+    #[display("Failed to create `PeerConnection`: {_0}")]
+    #[from]
+    PeerConnection(#[cause] RtcPeerConnectionError),
+
+    /// HTTP request to the [WHEP] endpoint failed.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    #[display("WHEP request failed: {_0}")]
+    #[from]
+    Http(#[cause] platform::HttpError),
+
+    /// [WHEP] endpoint responded with an unsuccessful status code.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    #[display("WHEP endpoint responded with {_0} status")]
+    UnexpectedStatus(u16),
+
+    /// [WHEP] endpoint response doesn't point to the created session via a
+    /// valid `Location` header.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    #[display("WHEP endpoint response has no valid `Location` header")]
+    NoLocation,
+
+    /// [`PeerConnection`] hasn't produced an SDP offer.
+    #[display("SDP offer hasn't been created")]
+    NoOffer,
+
+    /// [`WhepClient::play()`] has been already called.
+    #[display("WHEP session has been already started")]
+    AlreadyStarted,
+
+    /// [`PeerConnection`] receiving the remote media has failed.
+    #[display("`PeerConnection` has failed")]
+    ConnectionFailed,
+}
+
+/// [WHEP] session started by a [`WhepClient`].
+///
+/// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+#[derive(Debug)]
+struct Session {
+    /// [`peer::Component`] receiving the remote media.
+    peer: peer::Component,
+
+    /// URL of the [WHEP] session resource, taken from the `Location` header.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    resource: String,
+
+    /// Bearer token authorizing requests to the [WHEP] endpoint, if any.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    token: Option<String>,
+
+    /// [`TaskHandle`] of the task trickling the discovered ICE candidates to
+    /// the [`Session::resource`] and routing the received
+    /// [`remote::Track`]s.
+    ///
+    /// [`remote::Track`]: crate::media::track::remote::Track
+    _task: TaskHandle,
+}
+
+/// Client of a [WHEP] endpoint, receiving remote media via a
+/// [`PeerConnection`].
+///
+/// Mimics a `Room` with a single remote `Member`: the received
+/// [`remote::Track`]s are exposed via the [`api::ConnectionHandle`] passed to
+/// the [`WhepClient::on_new_connection()`] callback.
+///
+/// [`remote::Track`]: crate::media::track::remote::Track
+/// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+#[derive(Debug)]
+pub struct WhepClient {
+    /// [`MediaManager`] the [`PeerConnection`] is created with.
+    media_manager: Rc<MediaManager>,
+
+    /// [`IceServer`]s the [`PeerConnection`] is created with.
+    ice_servers: Vec<IceServer>,
+
+    /// [`RecvConstraints`] of the received [`remote::Track`]s.
+    ///
+    /// [`remote::Track`]: crate::media::track::remote::Track
+    recv_constraints: Rc<RecvConstraints>,
+
+    /// [`Connections`] with the remote `Member` the [`remote::Track`]s are
+    /// received from.
+    ///
+    /// [`remote::Track`]: crate::media::track::remote::Track
+    connections: Rc<Connections>,
+
+    /// Started [WHEP] [`Session`], if any.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    session: RefCell<Option<Session>>,
+
+    /// Callback invoked when the playing fails after the [WHEP] session has
+    /// been started.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    on_failure: Rc<platform::Callback<api::Error>>,
+}
+
+impl WhepClient {
+    /// Creates a new [`WhepClient`] creating its [`PeerConnection`] with the
+    /// provided [`MediaManager`] and [`IceServer`]s.
+    #[must_use]
+    pub fn new(
+        media_manager: Rc<MediaManager>,
+        ice_servers: Vec<IceServer>,
+    ) -> Self {
+        let recv_constraints = Rc::new(RecvConstraints::default());
+        Self {
+            media_manager,
+            ice_servers,
+            connections: Rc::new(Connections::new(Rc::clone(
+                &recv_constraints,
+            ))),
+            recv_constraints,
+            session: RefCell::new(None),
+            on_failure: Rc::new(platform::Callback::default()),
+        }
+    }
+
+    /// Sets callback, invoked once the remote media starts being received,
+    /// providing the [`api::ConnectionHandle`] its [`remote::Track`]s are
+    /// exposed via.
+    ///
+    /// Should be set before calling [`WhepClient::play()`].
+    ///
+    /// [`remote::Track`]: crate::media::track::remote::Track
+    pub fn on_new_connection(
+        &self,
+        f: platform::Function<api::ConnectionHandle>,
+    ) {
+        self.connections.on_new_connection(f);
+    }
+
+    /// Sets callback, invoked when the playing fails after the [WHEP] session
+    /// has been started (the [`PeerConnection`] fails).
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    pub fn on_failure(&self, f: platform::Function<api::Error>) {
+        self.on_failure.set_func(f);
+    }
+
+    /// Starts playing the remote media from the [WHEP] endpoint with the
+    /// provided `url`.
+    ///
+    /// Creates a `recvonly` SDP offer, `POST`s it to the endpoint and applies
+    /// the returned SDP answer. The discovered ICE candidates are trickled to
+    /// the created session afterwards.
+    ///
+    /// # Errors
+    ///
+    /// With a [`WhepError::AlreadyStarted`] if this method has been already
+    /// called.
+    ///
+    /// With a [`WhepError::PeerConnection`] if fails to create a
+    /// [`PeerConnection`].
+    ///
+    /// With a [`WhepError::NoOffer`] if the [`PeerConnection`] hasn't produced
+    /// an SDP offer.
+    ///
+    /// With a [`WhepError::Http`] if the `POST` request fails.
+    ///
+    /// With a [`WhepError::UnexpectedStatus`] if the endpoint rejects the SDP
+    /// offer.
+    ///
+    /// With a [`WhepError::NoLocation`] if the endpoint response has no valid
+    /// `Location` header.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    pub async fn play(
+        &self,
+        url: String,
+        token: Option<String>,
+    ) -> Result<(), Traced<WhepError>> {
+        if self.session.borrow().is_some() {
+            return Err(tracerr::new!(WhepError::AlreadyStarted));
+        }
+
+        let (peer_events_tx, mut peer_events_rx) = mpsc::unbounded();
+        let state = peer::State::new(
+            PeerId(0),
+            self.ice_servers.clone(),
+            false,
+            Some(NegotiationRole::Offerer),
+            ConnectionMode::Sfu,
+        );
+        let send_constraints = LocalTracksConstraints::default();
+        for track in recv_tracks() {
+            state.insert_track(&track, send_constraints.clone());
+        }
+        let peer = PeerConnection::new(
+            &state,
+            peer_events_tx,
+            Rc::clone(&self.media_manager),
+            send_constraints,
+            Rc::clone(&self.connections),
+            Rc::clone(&self.recv_constraints),
+        )
+        .await
+        .map_err(tracerr::map_from_and_wrap!())?;
+        let peer = peer::Component::new(peer, Rc::new(state));
+
+        let (offer, candidates) = wait_for_offer(&mut peer_events_rx)
+            .await
+            .ok_or_else(|| tracerr::new!(WhepError::NoOffer))?;
+
+        let response = platform::fetch(authorized(
+            platform::HttpRequest::new(platform::HttpMethod::Post, &url)
+                .body(SDP_CONTENT_TYPE, &offer),
+            token.as_deref(),
+        ))
+        .await
+        .map_err(tracerr::map_from_and_wrap!())?;
+        if !response.is_success() {
+            return Err(tracerr::new!(WhepError::UnexpectedStatus(
+                response.status
+            )));
+        }
+        let resource = response
+            .header("Location")
+            .and_then(|location| Url::parse(&url).ok()?.join(location).ok())
+            .ok_or_else(|| tracerr::new!(WhepError::NoLocation))?
+            .to_string();
+
+        peer.state().apply_local_sdp(offer.clone());
+        peer.state().set_remote_sdp(response.body);
+
+        let connections = Rc::clone(&self.connections);
+        let on_failure = Rc::clone(&self.on_failure);
+        let (task, abort) = future::abortable(trickle_candidates(
+            resource.clone(),
+            token.clone(),
+            offer,
+            candidates,
+            peer_events_rx,
+            move |event| {
+                if let PeerEvent::NewRemoteTrack { sender_id, track } = event {
+                    if let Some(conn) = connections.get(&sender_id) {
+                        conn.add_remote_track(track);
+                    }
+                } else if let PeerEvent::PeerConnectionStateChanged {
+                    peer_connection_state: PeerConnectionState::Failed,
+                    ..
+                } = event
+                {
+                    on_failure.call1(api::Error::from(tracerr::new!(
+                        WhepError::ConnectionFailed
+                    )));
+                }
+            },
+        ));
+        platform::spawn(async move {
+            _ = task.await.ok();
+        });
+
+        drop(self.session.replace(Some(Session {
+            peer,
+            resource,
+            token,
+            _task: abort.into(),
+        })));
+
+        Ok(())
+    }
+
+    /// Returns URL of the [WHEP] session resource created by the endpoint, if
+    /// the session has been started.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    #[must_use]
+    pub fn resource(&self) -> Option<String> {
+        self.session.borrow().as_ref().map(|s| s.resource.clone())
+    }
+
+    /// Returns the [`PeerConnection`] receiving the remote media, if the
+    /// session has been started.
+    #[must_use]
+    pub fn peer(&self) -> Option<Rc<PeerConnection>> {
+        self.session.borrow().as_ref().map(|s| s.peer.obj())
+    }
+
+    /// Terminates the [WHEP] session (if started) by sending a `DELETE`
+    /// request to its resource URL.
+    ///
+    /// # Errors
+    ///
+    /// With a [`WhepError::Http`] if the `DELETE` request fails.
+    ///
+    /// With a [`WhepError::UnexpectedStatus`] if the endpoint fails to
+    /// terminate the session.
+    ///
+    /// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+    pub async fn close(&self) -> Result<(), Traced<WhepError>> {
+        let Some(session) = self.session.take() else {
+            return Ok(());
+        };
+
+        let response = platform::fetch(authorized(
+            platform::HttpRequest::new(
+                platform::HttpMethod::Delete,
+                &session.resource,
+            ),
+            session.token.as_deref(),
+        ))
+        .await
+        .map_err(tracerr::map_from_and_wrap!())?;
+        if !response.is_success() {
+            return Err(tracerr::new!(WhepError::UnexpectedStatus(
+                response.status
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the receive [`proto::Track`]s of the remote audio and video.
+fn recv_tracks() -> Vec<proto::Track> {
+    let audio = MediaType::Audio(AudioSettings { required: false });
+    let video = MediaType::Video(VideoSettings {
+        required: false,
+        source_kind: MediaSourceKind::Device,
+        encoding_parameters: Vec::new(),
+    });
+    [audio, video]
+        .into_iter()
+        .zip(0..)
+        .map(|(media_type, id)| proto::Track {
+            id: TrackId(id),
+            direction: proto::Direction::Recv {
+                sender: MemberId::from(REMOTE_MEMBER_ID),
+                mid: None,
+            },
+            media_direction: MediaDirection::SendRecv,
+            muted: false,
+            media_type,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn receives_audio_and_video() {
+        let tracks = recv_tracks();
+
+        assert_eq!(tracks.len(), 2);
+        assert!(matches!(tracks[0].media_type, MediaType::Audio(_)));
+        assert!(matches!(tracks[1].media_type, MediaType::Video(_)));
+        assert!(tracks.iter().all(|t| matches!(
+            &t.direction,
+            proto::Direction::Recv { sender, .. } if sender.0 == REMOTE_MEMBER_ID,
+        )));
+    }
+}
@@ -0,0 +1,529 @@
+//! [WHIP] signalling backend, publishing media to a [WHIP] endpoint of a
+//! standard media server instead of a Medea one.
+//!
+//! [WHIP]: https://tools.ietf.org/html/rfc9725
+
+use std::{cell::RefCell, rc::Rc};
+
+use derive_more::with_trait::{Display, From};
+use futures::{StreamExt as _, channel::mpsc, future};
+use medea_client_api_proto::{
+    self as proto, AudioSettings, ConnectionMode, IceServer, MediaDirection,
+    MediaSourceKind, MediaType, NegotiationRole, PeerConnectionState, PeerId,
+    TrackId, VideoSettings,
+};
+use tracerr::Traced;
+use url::Url;
+
+use crate::{
+    api,
+    connection::Connections,
+    media::{
+        LocalTracksConstraints, MediaManager, MediaStreamSettings,
+        RecvConstraints,
+    },
+    peer::{self, PeerConnection, PeerEvent, RtcPeerConnectionError, sdp},
+    platform,
+    utils::{
+        Caused, TaskHandle,
+        logging::{self, LogCategory},
+    },
+};
+
+/// [Content type][1] of [WHIP] and [WHEP] SDP offers and answers.
+///
+/// [1]: https://tools.ietf.org/html/rfc9725#section-4.2
+/// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+/// [WHIP]: https://tools.ietf.org/html/rfc9725
+pub(super) const SDP_CONTENT_TYPE: &str = "application/sdp";
+
+/// [Content type][1] of trickled [ICE] candidates.
+///
+/// [1]: https://tools.ietf.org/html/rfc8840#section-9
+/// [ICE]: https://webrtcglossary.com/ice
+const SDP_FRAG_CONTENT_TYPE: &str = "application/trickle-ice-sdpfrag";
+
+/// Errors occurring in a [`WhipClient`].
+#[derive(Caused, Clone, Debug, Display, From)]
+#[cause(error = platform::Error)]
+pub enum WhipError {
+    /// Failed to create a [`PeerConnection`].
+    #[display("Failed to create `PeerConnection`: {_0}")]
+    #[from]
+    PeerConnection(#[cause] RtcPeerConnectionError),
+
+    /// HTTP request to the [WHIP] endpoint failed.
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    #[display("WHIP request failed: {_0}")]
+    #[from]
+    Http(#[cause] platform::HttpError),
+
+    /// [WHIP] endpoint responded with an unsuccessful status code.
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    #[display("WHIP endpoint responded with {_0} status")]
+    UnexpectedStatus(u16),
+
+    /// [WHIP] endpoint response doesn't point to the created session via a
+    /// valid `Location` header.
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    #[display("WHIP endpoint response has no valid `Location` header")]
+    NoLocation,
+
+    /// [`PeerConnection`] hasn't produced an SDP offer.
+    #[display("SDP offer hasn't been created")]
+    NoOffer,
+
+    /// [`PeerConnection`] publishing the local media has failed.
+    #[display("`PeerConnection` has failed")]
+    ConnectionFailed,
+}
+
+/// Client of a [WHIP] endpoint, publishing local media via a
+/// [`PeerConnection`].
+///
+/// The [WHIP] session is terminated once [`WhipClient::close()`] is called.
+///
+/// [WHIP]: https://tools.ietf.org/html/rfc9725
+#[derive(Debug)]
+pub struct WhipClient {
+    /// [`peer::Component`] publishing the local media.
+    peer: peer::Component,
+
+    /// URL of the [WHIP] session resource, taken from the `Location` header.
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    resource: String,
+
+    /// Bearer token authorizing requests to the [WHIP] endpoint, if any.
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    token: Option<String>,
+
+    /// [`TaskHandle`] of the task trickling the discovered ICE candidates to
+    /// the [`WhipClient::resource`].
+    trickle_task: RefCell<Option<TaskHandle>>,
+
+    /// Callback invoked when the publishing fails after the [WHIP] session
+    /// has been created.
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    on_failure: Rc<platform::Callback<api::Error>>,
+}
+
+impl WhipClient {
+    /// Publishes the local media described by the provided
+    /// [`MediaStreamSettings`] to the [WHIP] endpoint with the provided `url`.
+    ///
+    /// Creates an SDP offer, `POST`s it to the endpoint and applies the
+    /// returned SDP answer. The discovered ICE candidates are trickled to the
+    /// created session afterwards.
+    ///
+    /// # Errors
+    ///
+    /// With a [`WhipError::PeerConnection`] if fails to create a
+    /// [`PeerConnection`].
+    ///
+    /// With a [`WhipError::NoOffer`] if the [`PeerConnection`] hasn't produced
+    /// an SDP offer.
+    ///
+    /// With a [`WhipError::Http`] if the `POST` request fails.
+    ///
+    /// With a [`WhipError::UnexpectedStatus`] if the endpoint rejects the SDP
+    /// offer.
+    ///
+    /// With a [`WhipError::NoLocation`] if the endpoint response has no valid
+    /// `Location` header.
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    pub async fn publish(
+        url: String,
+        token: Option<String>,
+        media_manager: Rc<MediaManager>,
+        settings: MediaStreamSettings,
+        ice_servers: Vec<IceServer>,
+    ) -> Result<Self, Traced<WhipError>> {
+        let (peer_events_tx, mut peer_events_rx) = mpsc::unbounded();
+        let state = peer::State::new(
+            PeerId(0),
+            ice_servers,
+            false,
+            Some(NegotiationRole::Offerer),
+            ConnectionMode::Sfu,
+        );
+        let send_constraints = LocalTracksConstraints::default();
+        send_constraints.constrain(settings);
+        for track in send_tracks(&send_constraints) {
+            state.insert_track(&track, send_constraints.clone());
+        }
+        let recv_constraints = Rc::new(RecvConstraints::default());
+        let peer = PeerConnection::new(
+            &state,
+            peer_events_tx,
+            media_manager,
+            send_constraints,
+            Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+            recv_constraints,
+        )
+        .await
+        .map_err(tracerr::map_from_and_wrap!())?;
+        let peer = peer::Component::new(peer, Rc::new(state));
+
+        let (offer, candidates) = wait_for_offer(&mut peer_events_rx)
+            .await
+            .ok_or_else(|| tracerr::new!(WhipError::NoOffer))?;
+
+        let response = platform::fetch(authorized(
+            platform::HttpRequest::new(platform::HttpMethod::Post, &url)
+                .body(SDP_CONTENT_TYPE, &offer),
+            token.as_deref(),
+        ))
+        .await
+        .map_err(tracerr::map_from_and_wrap!())?;
+        if !response.is_success() {
+            return Err(tracerr::new!(WhipError::UnexpectedStatus(
+                response.status
+            )));
+        }
+        let resource = response
+            .header("Location")
+            .and_then(|location| Url::parse(&url).ok()?.join(location).ok())
+            .ok_or_else(|| tracerr::new!(WhipError::NoLocation))?
+            .to_string();
+
+        peer.state().apply_local_sdp(offer.clone());
+        peer.state().set_remote_sdp(response.body);
+
+        let on_failure = Rc::new(platform::Callback::default());
+        let (trickle, abort) = future::abortable(trickle_candidates(
+            resource.clone(),
+            token.clone(),
+            offer,
+            candidates,
+            peer_events_rx,
+            {
+                let on_failure = Rc::clone(&on_failure);
+                move |event| on_peer_event(&on_failure, event)
+            },
+        ));
+        platform::spawn(async move {
+            _ = trickle.await.ok();
+        });
+
+        Ok(Self {
+            peer,
+            resource,
+            token,
+            trickle_task: RefCell::new(Some(abort.into())),
+            on_failure,
+        })
+    }
+
+    /// Sets callback, invoked when the publishing fails after the [WHIP]
+    /// session has been created (the [`PeerConnection`] fails or the local
+    /// media can't be updated).
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    pub fn on_failure(&self, f: platform::Function<api::Error>) {
+        self.on_failure.set_func(f);
+    }
+
+    /// Returns URL of the [WHIP] session resource created by the endpoint.
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    #[must_use]
+    pub fn resource(&self) -> &str {
+        &self.resource
+    }
+
+    /// Returns the [`PeerConnection`] publishing the local media.
+    #[must_use]
+    pub fn peer(&self) -> Rc<PeerConnection> {
+        self.peer.obj()
+    }
+
+    /// Terminates the [WHIP] session by sending a `DELETE` request to its
+    /// resource URL.
+    ///
+    /// # Errors
+    ///
+    /// With a [`WhipError::Http`] if the `DELETE` request fails.
+    ///
+    /// With a [`WhipError::UnexpectedStatus`] if the endpoint fails to
+    /// terminate the session.
+    ///
+    /// [WHIP]: https://tools.ietf.org/html/rfc9725
+    pub async fn close(&self) -> Result<(), Traced<WhipError>> {
+        drop(self.trickle_task.take());
+
+        let response = platform::fetch(authorized(
+            platform::HttpRequest::new(
+                platform::HttpMethod::Delete,
+                &self.resource,
+            ),
+            self.token.as_deref(),
+        ))
+        .await
+        .map_err(tracerr::map_from_and_wrap!())?;
+        if !response.is_success() {
+            return Err(tracerr::new!(WhipError::UnexpectedStatus(
+                response.status
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Reports the provided [`PeerEvent`] to the provided `on_failure` callback, if
+/// it signals a failure of the publishing.
+fn on_peer_event(
+    on_failure: &platform::Callback<api::Error>,
+    event: PeerEvent,
+) {
+    if let PeerEvent::FailedLocalMedia { error } = event {
+        on_failure.call1(api::Error::from(error));
+    } else if let PeerEvent::PeerConnectionStateChanged {
+        peer_connection_state: PeerConnectionState::Failed,
+        ..
+    } = event
+    {
+        on_failure.call1(api::Error::from(tracerr::new!(
+            WhipError::ConnectionFailed
+        )));
+    }
+}
+
+/// Returns the send [`proto::Track`]s enabled by the provided
+/// [`LocalTracksConstraints`].
+fn send_tracks(constraints: &LocalTracksConstraints) -> Vec<proto::Track> {
+    let audio = MediaType::Audio(AudioSettings { required: false });
+    let video = MediaType::Video(VideoSettings {
+        required: false,
+        source_kind: MediaSourceKind::Device,
+        encoding_parameters: Vec::new(),
+    });
+    [audio, video]
+        .into_iter()
+        .filter(|media_type| constraints.enabled(media_type))
+        .zip(0..)
+        .map(|(media_type, id)| proto::Track {
+            id: TrackId(id),
+            direction: proto::Direction::Send {
+                receivers: Vec::new(),
+                mid: None,
+            },
+            media_direction: MediaDirection::SendOnly,
+            muted: false,
+            media_type,
+        })
+        .collect()
+}
+
+/// Waits for the SDP offer to be produced by a [`PeerConnection`] emitting the
+/// provided `peer_events`, buffering the [`platform::IceCandidate`]s
+/// discovered meanwhile.
+///
+/// [`None`] if the `peer_events` have ended before the SDP offer is produced.
+pub(super) async fn wait_for_offer(
+    peer_events: &mut mpsc::UnboundedReceiver<PeerEvent>,
+) -> Option<(String, Vec<platform::IceCandidate>)> {
+    let mut candidates = Vec::new();
+    loop {
+        let event = peer_events.next().await?;
+        if let PeerEvent::NewSdpOffer { sdp_offer, .. } = event {
+            return Some((sdp_offer, candidates));
+        }
+        if let PeerEvent::IceCandidateDiscovered {
+            candidate,
+            sdp_m_line_index,
+            sdp_mid,
+            ..
+        } = event
+        {
+            candidates.push(platform::IceCandidate {
+                candidate,
+                sdp_m_line_index,
+                sdp_mid,
+            });
+        }
+    }
+}
+
+/// Adds the `Authorization` header with the provided bearer `token` to the
+/// provided [`platform::HttpRequest`], if any.
+pub(super) fn authorized(
+    request: platform::HttpRequest,
+    token: Option<&str>,
+) -> platform::HttpRequest {
+    match token {
+        Some(token) => {
+            request.header("Authorization", format!("Bearer {token}"))
+        }
+        None => request,
+    }
+}
+
+/// Trickles the provided buffered and the further discovered
+/// [`platform::IceCandidate`]s to the provided [WHIP] session `resource` via
+/// `PATCH` requests.
+///
+/// All the other [`PeerEvent`]s are passed to the provided `on_event`
+/// function.
+///
+/// [WHIP]: https://tools.ietf.org/html/rfc9725
+pub(super) async fn trickle_candidates(
+    resource: String,
+    token: Option<String>,
+    offer: String,
+    buffered: Vec<platform::IceCandidate>,
+    mut peer_events: mpsc::UnboundedReceiver<PeerEvent>,
+    on_event: impl Fn(PeerEvent),
+) {
+    let Ok(offer) = offer.parse::<sdp::SessionDescription>() else {
+        return;
+    };
+    let patch = async |candidates: &[platform::IceCandidate]| {
+        let request =
+            platform::HttpRequest::new(platform::HttpMethod::Patch, &resource)
+                .body(SDP_FRAG_CONTENT_TYPE, sdp_fragment(&offer, candidates));
+        if let Err(e) =
+            platform::fetch(authorized(request, token.as_deref())).await
+        {
+            logging::emit(
+                log::Level::Warn,
+                None,
+                LogCategory::Ice,
+                format_args!("Failed to trickle ICE candidates: {e}"),
+            );
+        }
+    };
+
+    if !buffered.is_empty() {
+        patch(&buffered).await;
+    }
+    while let Some(event) = peer_events.next().await {
+        if let PeerEvent::IceCandidateDiscovered {
+            candidate,
+            sdp_m_line_index,
+            sdp_mid,
+            ..
+        } = event
+        {
+            patch(&[platform::IceCandidate {
+                candidate,
+                sdp_m_line_index,
+                sdp_mid,
+            }])
+            .await;
+        } else {
+            on_event(event);
+        }
+    }
+}
+
+/// Builds a [trickle ICE SDP fragment][1] of the provided
+/// [`platform::IceCandidate`]s gathered for the provided SDP `offer`.
+///
+/// [1]: https://tools.ietf.org/html/rfc8840#section-4.4
+fn sdp_fragment(
+    offer: &sdp::SessionDescription,
+    candidates: &[platform::IceCandidate],
+) -> String {
+    let ice_attr =
+        |name| offer.media.iter().find_map(|m| m.attributes(name).next());
+    let mut fragment: String = ["ice-ufrag", "ice-pwd"]
+        .into_iter()
+        .filter_map(|name| Some(format!("a={name}:{}\r\n", ice_attr(name)?)))
+        .collect();
+    for (i, section) in offer.media.iter().enumerate() {
+        let lines: Vec<_> = candidates
+            .iter()
+            .filter(|c| match (&c.sdp_mid, section.mid()) {
+                (Some(mid), Some(section_mid)) => mid == section_mid,
+                _ => c.sdp_m_line_index.map(usize::from) == Some(i),
+            })
+            .map(|c| format!("a={}", c.candidate))
+            .collect();
+        if lines.is_empty() {
+            continue;
+        }
+        let section = sdp::MediaSection {
+            kind: section.kind.clone(),
+            port: "9".into(),
+            protocol: section.protocol.clone(),
+            formats: section.formats.clone(),
+            lines: section
+                .mid()
+                .map(|mid| format!("a=mid:{mid}"))
+                .into_iter()
+                .chain(lines)
+                .collect(),
+        };
+        fragment.push_str(&section.to_string());
+    }
+    fragment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OFFER: &str = "v=0\r\n\
+        o=- 1 2 IN IP4 127.0.0.1\r\n\
+        s=-\r\n\
+        t=0 0\r\n\
+        m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+        a=ice-ufrag:EsAw\r\n\
+        a=ice-pwd:P2uYro0UCOQ4zxjKXaWCBui1\r\n\
+        a=mid:0\r\n\
+        a=rtpmap:111 opus/48000/2\r\n\
+        m=video 9 UDP/TLS/RTP/SAVPF 96\r\n\
+        a=ice-ufrag:EsAw\r\n\
+        a=ice-pwd:P2uYro0UCOQ4zxjKXaWCBui1\r\n\
+        a=mid:1\r\n\
+        a=rtpmap:96 VP8/90000\r\n";
+
+    fn candidate(mid: &str, port: u16) -> platform::IceCandidate {
+        platform::IceCandidate {
+            candidate: format!(
+                "candidate:1 1 udp 2122260223 192.168.0.1 {port} typ host"
+            ),
+            sdp_m_line_index: None,
+            sdp_mid: Some(mid.into()),
+        }
+    }
+
+    #[test]
+    fn builds_sdp_fragment() {
+        let offer = OFFER.parse().unwrap();
+        let fragment = sdp_fragment(&offer, &[candidate("1", 5000)]);
+
+        assert_eq!(
+            fragment,
+            "a=ice-ufrag:EsAw\r\n\
+             a=ice-pwd:P2uYro0UCOQ4zxjKXaWCBui1\r\n\
+             m=video 9 UDP/TLS/RTP/SAVPF 96\r\n\
+             a=mid:1\r\n\
+             a=candidate:1 1 udp 2122260223 192.168.0.1 5000 typ host\r\n",
+        );
+    }
+
+    #[test]
+    fn groups_candidates_by_media() {
+        let offer = OFFER.parse().unwrap();
+        let fragment = sdp_fragment(
+            &offer,
+            &[candidate("0", 5000), candidate("1", 5001), candidate("0", 5002)],
+        );
+
+        let audio = fragment.find("m=audio").unwrap();
+        let video = fragment.find("m=video").unwrap();
+        assert!(fragment.find("5000").unwrap() < video);
+        assert!(fragment.find("5002").unwrap() < video);
+        assert!(fragment.find("5001").unwrap() > video);
+        assert!(audio < video);
+    }
+}
@@ -0,0 +1,142 @@
+//! Pluggable sink of structured log events.
+//!
+//! By default, all the events are passed to the [`log`] facade. Consumers
+//! routing logs differently (e.g. when embedding into Dart) may register their
+//! own sink via [`set_sink()`].
+
+use std::{cell::RefCell, fmt, rc::Rc};
+
+use derive_more::with_trait::Display;
+use medea_client_api_proto::PeerId;
+
+/// Category of a [`LogEvent`].
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum LogCategory {
+    /// ICE candidates gathering and connectivity.
+    #[display("ice")]
+    Ice,
+
+    /// Sending and receiving media.
+    #[display("media")]
+    Media,
+
+    /// SDP negotiation.
+    #[display("negotiation")]
+    Negotiation,
+
+    /// Communication with a media server.
+    #[display("signalling")]
+    Signalling,
+
+    /// Statistics gathering.
+    #[display("stats")]
+    Stats,
+}
+
+/// Structured log event.
+#[derive(Clone, Debug)]
+pub struct LogEvent {
+    /// Severity of this [`LogEvent`].
+    pub level: log::Level,
+
+    /// ID of the `PeerConnection` this [`LogEvent`] relates to, if any.
+    pub peer_id: Option<PeerId>,
+
+    /// [`LogCategory`] of this [`LogEvent`].
+    pub category: LogCategory,
+
+    /// Human-readable message of this [`LogEvent`].
+    pub message: String,
+}
+
+/// Sink receiving [`LogEvent`]s.
+type Sink = Rc<dyn Fn(LogEvent)>;
+
+thread_local! {
+    /// Sink registered via [`set_sink()`].
+    static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+/// Registers the provided sink receiving all the [`LogEvent`]s instead of the
+/// [`log`] facade.
+///
+/// If [`None`] is provided, then the [`log`] facade is used again.
+pub fn set_sink<F>(sink: Option<F>)
+where
+    F: Fn(LogEvent) + 'static,
+{
+    let sink = sink.map(|f| -> Sink { Rc::new(f) });
+    SINK.with(|s| drop(s.replace(sink)));
+}
+
+/// Emits a [`LogEvent`] with the provided parameters into the registered sink,
+/// or into the [`log`] facade if there is none.
+pub fn emit(
+    level: log::Level,
+    peer_id: Option<PeerId>,
+    category: LogCategory,
+    message: fmt::Arguments<'_>,
+) {
+    // Sink is cloned out, so it's able to re-register itself.
+    SINK.with(|s| s.borrow().clone()).map_or_else(
+        || log::log!(level, "{message}"),
+        |sink| {
+            sink(LogEvent {
+                level,
+                peer_id,
+                category,
+                message: message.to_string(),
+            });
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn routes_events_into_registered_sink() {
+        let received = Rc::new(RefCell::new(Vec::new()));
+        set_sink(Some({
+            let received = Rc::clone(&received);
+            move |e: LogEvent| received.borrow_mut().push(e)
+        }));
+
+        emit(
+            log::Level::Warn,
+            Some(PeerId(1)),
+            LogCategory::Ice,
+            format_args!("candidate {}", 7),
+        );
+        set_sink(None::<fn(LogEvent)>);
+        emit(log::Level::Warn, None, LogCategory::Ice, format_args!("lost"));
+
+        let received = received.borrow();
+        assert_eq!(received.len(), 1);
+        let event = received.first().unwrap();
+        assert_eq!(event.level, log::Level::Warn);
+        assert_eq!(event.peer_id, Some(PeerId(1)));
+        assert_eq!(event.category, LogCategory::Ice);
+        assert_eq!(event.message, "candidate 7");
+    }
+
+    #[test]
+    fn sink_may_reregister_itself() {
+        let calls = Rc::new(Cell::new(0));
+        set_sink(Some({
+            let calls = Rc::clone(&calls);
+            move |_: LogEvent| {
+                calls.set(calls.get() + 1);
+                set_sink(None::<fn(LogEvent)>);
+            }
+        }));
+
+        emit(log::Level::Info, None, LogCategory::Media, format_args!("a"));
+        emit(log::Level::Info, None, LogCategory::Media, format_args!("b"));
+
+        assert_eq!(calls.get(), 1);
+    }
+}
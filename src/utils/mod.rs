@@ -4,6 +4,7 @@
 mod errors;
 
 pub mod component;
+pub mod logging;
 mod resettable_delay;
 
 use derive_more::with_trait::From;
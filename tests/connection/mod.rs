@@ -0,0 +1,284 @@
+#![cfg(target_arch = "wasm32")]
+
+use std::{cell::RefCell, collections::HashSet, rc::Rc};
+
+use futures::channel::mpsc;
+use medea_client_api_proto::{
+    Command, ConnectionMode, MemberId, NegotiationRole, PeerId, QualityLayer,
+    Track, TrackId, TrackPatchEvent,
+    stats::{
+        HighResTimeStamp, RtcInboundRtpStreamMediaType,
+        RtcInboundRtpStreamStats, RtcStat, RtcStatsType, StatId,
+    },
+};
+use medea_jason::{
+    connection::Connections,
+    media::{MediaKind, MediaManager, RecvConstraints},
+    peer::{self, PeerEvent},
+};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen_test::*;
+
+use crate::{delay_for, get_test_recv_tracks, local_constraints};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Returns an inbound audio [RTP] stream [`RtcStat`] with the provided
+/// counters.
+///
+/// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+fn inbound_stat(id: &str, mid: &str, received: u64, lost: i64) -> RtcStat {
+    RtcStat {
+        id: StatId(id.into()),
+        timestamp: HighResTimeStamp(1584373509700.0),
+        stats: RtcStatsType::InboundRtp(Box::new(RtcInboundRtpStreamStats {
+            track_id: None,
+            mid: Some(mid.into()),
+            media_specific_stats: RtcInboundRtpStreamMediaType::Audio {
+                voice_activity_flag: None,
+                total_samples_received: None,
+                concealed_samples: None,
+                silent_concealed_samples: None,
+                audio_level: None,
+                total_audio_energy: None,
+                total_samples_duration: None,
+            },
+            bytes_received: 0,
+            packets_received: received,
+            packets_lost: Some(lost),
+            jitter: None,
+            total_decode_time: None,
+            jitter_buffer_emitted_count: None,
+        })),
+    }
+}
+
+/// Subscribes to the inbound quality updates of the [`Connection`] with the
+/// provided remote [`MemberId`], returning all the reported values.
+///
+/// [`Connection`]: medea_jason::connection::Connection
+fn subscribe_inbound_quality(
+    connections: &Connections,
+    member_id: &str,
+) -> Rc<RefCell<Vec<bool>>> {
+    let updates = Rc::new(RefCell::new(Vec::new()));
+    let cb = Closure::<dyn FnMut(bool)>::new({
+        let updates = Rc::clone(&updates);
+        move |is_good: bool| updates.borrow_mut().push(is_good)
+    });
+    connections
+        .get(&MemberId::from(member_id))
+        .unwrap()
+        .new_handle()
+        .on_inbound_quality_update(
+            js_sys::Function::from(cb.into_js_value()).into(),
+        )
+        .unwrap();
+    updates
+}
+
+/// Checks that [`Connections::update_inbound_stats`] updates each
+/// [`Connection`] once per stats scrape, with the stats of its own receivers
+/// only.
+///
+/// [`Connection`]: medea_jason::connection::Connection
+#[wasm_bindgen_test]
+async fn inbound_stats_are_dispatched_per_member() {
+    let connections = Connections::new(Rc::new(RecvConstraints::default()));
+    for (track_id, member_id) in [(0, "bob"), (1, "bob"), (2, "alice")] {
+        drop(connections.update_connections(
+            &TrackId(track_id),
+            HashSet::from([MemberId::from(member_id)]),
+        ));
+    }
+    let bob = subscribe_inbound_quality(&connections, "bob");
+    let alice = subscribe_inbound_quality(&connections, "alice");
+    let mids = || {
+        vec![
+            (TrackId(0), "0".to_owned()),
+            (TrackId(1), "1".to_owned()),
+            (TrackId(2), "2".to_owned()),
+        ]
+    };
+
+    connections.update_inbound_stats(
+        mids(),
+        &[
+            inbound_stat("a", "0", 100, 0),
+            inbound_stat("b", "1", 100, 0),
+            inbound_stat("c", "2", 50, 50),
+        ],
+    );
+    assert_eq!(*bob.borrow(), [true]);
+    assert_eq!(*alice.borrow(), [false]);
+
+    // Both streams of "bob" are accounted together, so the loss of a single
+    // one of them is diluted.
+    connections.update_inbound_stats(
+        mids(),
+        &[
+            inbound_stat("a", "0", 200, 0),
+            inbound_stat("b", "1", 195, 5),
+            inbound_stat("c", "2", 100, 50),
+        ],
+    );
+    assert_eq!(*bob.borrow(), [true]);
+    assert_eq!(*alice.borrow(), [false, true]);
+}
+
+/// Checks that counters of the inbound streams disappeared from the stats
+/// are discarded, so they are counted from scratch once reappear.
+#[wasm_bindgen_test]
+async fn gone_inbound_streams_are_pruned() {
+    let connections = Connections::new(Rc::new(RecvConstraints::default()));
+    drop(connections.update_connections(
+        &TrackId(0),
+        HashSet::from([MemberId::from("bob")]),
+    ));
+    let bob = subscribe_inbound_quality(&connections, "bob");
+    let mids = || vec![(TrackId(0), "0".to_owned())];
+
+    connections.update_inbound_stats(mids(), &[inbound_stat("a", "0", 100, 0)]);
+    assert_eq!(*bob.borrow(), [true]);
+
+    connections.update_inbound_stats(mids(), &[inbound_stat("b", "0", 10, 0)]);
+    connections.update_inbound_stats(mids(), &[inbound_stat("a", "0", 150, 5)]);
+    // If the counters of "a" were kept, then there would be only 50 packets
+    // received and 5 lost since the first update, which is a poor quality.
+    assert_eq!(*bob.borrow(), [true]);
+}
+
+/// Creates a new [`peer::Component`] receiving the provided [`Track`]s,
+/// returning it along with its [`Connections`] and emitted [`PeerEvent`]s.
+async fn get_recv_peer(
+    tracks: &[Track],
+) -> (peer::Component, Rc<Connections>, mpsc::UnboundedReceiver<PeerEvent>) {
+    let (tx, rx) = mpsc::unbounded();
+    let peer_state = peer::State::new(
+        PeerId(1),
+        Vec::new(),
+        false,
+        Some(NegotiationRole::Offerer),
+        ConnectionMode::Sfu,
+    );
+    let send_constraints = local_constraints(true, true);
+    for track in tracks {
+        peer_state.insert_track(track, send_constraints.clone());
+    }
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let connections = Rc::new(Connections::new(Rc::clone(&recv_constraints)));
+    let peer = peer::Component::new(
+        peer::PeerConnection::new(
+            &peer_state,
+            tx,
+            Rc::new(MediaManager::default()),
+            send_constraints,
+            Rc::clone(&connections),
+            recv_constraints,
+        )
+        .await
+        .unwrap(),
+        Rc::new(peer_state),
+    );
+    peer.state().when_local_sdp_updated().await.unwrap();
+
+    (peer, connections, rx)
+}
+
+/// Returns all the [`QualityLayer`]s requested via the provided [`PeerEvent`]s
+/// so far.
+async fn requested_quality_layers(
+    events: &mut mpsc::UnboundedReceiver<PeerEvent>,
+) -> Vec<(TrackId, QualityLayer)> {
+    delay_for(100).await;
+
+    let mut layers = Vec::new();
+    while let Ok(Some(event)) = events.try_next() {
+        if let PeerEvent::MediaUpdateCommand {
+            command: Command::RequestQualityLayer { track_id, layer, .. },
+        } = event
+        {
+            layers.push((track_id, layer));
+        }
+    }
+    layers
+}
+
+/// Checks that requesting the same [`QualityLayer`] again sends the request
+/// again, since a media server may have reset it meanwhile.
+#[wasm_bindgen_test]
+async fn same_quality_layer_is_requested_again() {
+    let (audio_rx, video_rx) = get_test_recv_tracks();
+    let (_peer, connections, mut events) =
+        get_recv_peer(&[audio_rx, video_rx]).await;
+    let conn = connections.get(&MemberId::from("bob")).unwrap();
+    let layer = QualityLayer { spatial: 1, temporal: None };
+    drop(requested_quality_layers(&mut events).await);
+
+    conn.request_quality(layer);
+    assert_eq!(
+        requested_quality_layers(&mut events).await,
+        [(TrackId(1), layer)],
+    );
+
+    conn.request_quality(layer);
+    assert_eq!(
+        requested_quality_layers(&mut events).await,
+        [(TrackId(1), layer)],
+    );
+}
+
+/// Checks that the requested [`QualityLayer`] is requested for the video
+/// receivers added to a [`Connection`] afterwards.
+///
+/// [`Connection`]: medea_jason::connection::Connection
+#[wasm_bindgen_test]
+async fn quality_layer_is_requested_for_new_receivers() {
+    let (audio_rx, video_rx) = get_test_recv_tracks();
+    let (peer, connections, mut events) = get_recv_peer(&[audio_rx]).await;
+    let conn = connections.get(&MemberId::from("bob")).unwrap();
+    let layer = QualityLayer { spatial: 0, temporal: Some(1) };
+
+    conn.request_quality(layer);
+    assert!(requested_quality_layers(&mut events).await.is_empty());
+
+    peer.state().insert_track(&video_rx, local_constraints(true, true));
+    assert_eq!(
+        requested_quality_layers(&mut events).await,
+        [(TrackId(1), layer)],
+    );
+}
+
+/// Checks that [`Connection::is_remote_muted()`] reflects the mute state of
+/// the receivers of the provided [`MediaKind`] signalled by a server.
+///
+/// [`Connection::is_remote_muted()`]: medea_jason::connection::Connection::is_remote_muted
+#[wasm_bindgen_test]
+async fn is_remote_muted_reflects_receivers_state() {
+    let (audio_rx, video_rx) = get_test_recv_tracks();
+    let (peer, connections, _events) =
+        get_recv_peer(&[audio_rx, video_rx]).await;
+    let conn = connections.get(&MemberId::from("bob")).unwrap();
+    assert!(!conn.is_remote_muted(MediaKind::Audio));
+    assert!(!conn.is_remote_muted(MediaKind::Video));
+
+    let set_muted = |muted| {
+        peer.state().get_receiver(TrackId(0)).unwrap().update(
+            &TrackPatchEvent {
+                id: TrackId(0),
+                receivers: None,
+                media_direction: None,
+                muted: Some(muted),
+                encoding_parameters: None,
+                paused: None,
+            },
+        );
+    };
+
+    set_muted(true);
+    assert!(conn.is_remote_muted(MediaKind::Audio));
+    assert!(!conn.is_remote_muted(MediaKind::Video));
+
+    set_muted(false);
+    assert!(!conn.is_remote_muted(MediaKind::Audio));
+}
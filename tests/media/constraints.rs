@@ -392,6 +392,72 @@ async fn multi_source_media_stream_constraints_build8() {
     };
 }
 
+// Make sure that `min`/`ideal`/`max` ranges are passed to
+// `MediaStreamConstraints` as is, without `exact` values.
+#[wasm_bindgen_test]
+async fn range_constraints_are_serialized() {
+    /// Returns the provided `field` of the provided `ConstrainULongRange` JS
+    /// object.
+    fn get(range: &JsValue, field: &str) -> Option<f64> {
+        js_val_to_option(js_sys::Reflect::get(range, &field.into()).unwrap())
+            .map(|v| v.as_f64().unwrap())
+    }
+
+    let mut video = DeviceVideoTrackConstraints::new();
+    video.width_range(Some(640), Some(1280), Some(1920));
+    video.height_range(Some(360), None, None);
+    video.frame_rate_range(None, Some(30), Some(60));
+    let mut constraints = MediaStreamSettings::new();
+    constraints.device_video(video);
+
+    let constraints: Option<MultiSourceTracksConstraints> = constraints.into();
+    let Some(MultiSourceTracksConstraints::Device(constraints)) = constraints
+    else {
+        unreachable!();
+    };
+    let video = constraints.as_ref().get_video();
+    let field =
+        |name: &str| js_sys::Reflect::get(&video, &name.into()).unwrap();
+
+    let width = field("width");
+    assert_eq!(get(&width, "min"), Some(640.0));
+    assert_eq!(get(&width, "ideal"), Some(1280.0));
+    assert_eq!(get(&width, "max"), Some(1920.0));
+    assert_eq!(get(&width, "exact"), None);
+
+    let height = field("height");
+    assert_eq!(get(&height, "min"), Some(360.0));
+    assert_eq!(get(&height, "ideal"), None);
+    assert_eq!(get(&height, "max"), None);
+    assert_eq!(get(&height, "exact"), None);
+
+    let frame_rate = field("frameRate");
+    assert_eq!(get(&frame_rate, "min"), None);
+    assert_eq!(get(&frame_rate, "ideal"), Some(30.0));
+    assert_eq!(get(&frame_rate, "max"), Some(60.0));
+    assert_eq!(get(&frame_rate, "exact"), None);
+}
+
+// Make sure that the tracks received with range constraints satisfy them.
+#[wasm_bindgen_test]
+async fn range_constraints_satisfies() {
+    let mut video = DeviceVideoTrackConstraints::new();
+    video.width_range(Some(160), Some(640), Some(1920));
+    let mut constraints = MediaStreamSettings::new();
+    constraints.device_video(video.clone());
+
+    let (track, _) = MediaManager::default()
+        .get_tracks(constraints)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert!(video.satisfies(track.as_ref()).await);
+    video.width_range(Some(10000), None, None);
+    assert!(!video.satisfies(track.as_ref()).await);
+}
+
 #[wasm_bindgen_test]
 async fn merge_audio() {}
 
@@ -1,23 +1,26 @@
 #![cfg(target_arch = "wasm32")]
 
 use js_sys::Array as JsArray;
+use medea_client_api_proto::MediaSourceKind;
 use medea_jason::{
     api,
     api::err::{
         EnumerateDevicesException, LocalMediaInitException,
         LocalMediaInitExceptionKind,
     },
+    media,
     media::{
         AudioTrackConstraints, DeviceVideoTrackConstraints,
         DisplayVideoTrackConstraints, GetUserMediaError, InitLocalTracksError,
-        MediaKind, MediaManager, MediaStreamSettings,
+        MediaKind, MediaManager, MediaStreamSettings, MediaStreamTrackState,
     },
+    platform,
 };
 use wasm_bindgen_futures::JsFuture;
 use wasm_bindgen_test::*;
 use web_sys as sys;
 
-use crate::{MockNavigator, is_firefox, jsval_cast};
+use crate::{MockNavigator, is_firefox, jsval_cast, window};
 
 wasm_bindgen_test_configure!(run_in_browser);
 
@@ -104,6 +107,82 @@ async fn failed_get_user_media() {
     }
 }
 
+/// Checks that an [OverconstrainedError][1] with an empty message is reported
+/// mentioning the unsatisfied constraint.
+///
+/// [1]: https://w3.org/TR/mediacapture-streams#overconstrainederror-interface
+#[wasm_bindgen_test]
+async fn overconstrained_error_names_constraint() {
+    let mock_navigator = MockNavigator::new();
+
+    let error = js_sys::Error::new("");
+    error.set_name("OverconstrainedError");
+    js_sys::Reflect::set(&error, &"constraint".into(), &"width".into())
+        .unwrap();
+
+    mock_navigator.error_get_user_media(error.into());
+    let media_manager = MediaManager::default();
+    let constraints = {
+        let mut video = api::DeviceVideoTrackConstraints::new();
+        video.width_in_range(10000, 20000);
+        let mut constraints = api::MediaStreamSettings::new();
+        constraints.device_video(video);
+        constraints
+    };
+    let err = JsFuture::from(
+        api::MediaManagerHandle::from(media_manager.new_handle())
+            .init_local_tracks(&constraints),
+    )
+    .await
+    .unwrap_err();
+    mock_navigator.stop();
+
+    let err =
+        jsval_cast::<LocalMediaInitException>(err, "LocalMediaInitException")
+            .unwrap();
+    assert_eq!(err.kind(), LocalMediaInitExceptionKind::GetUserMediaFailed);
+    assert_eq!(
+        err.message(),
+        "Failed to get local tracks: MediaDevices.getUserMedia() failed: \
+         OverconstrainedError: Constraint `width` cannot be satisfied",
+    );
+    let cause = err.cause().unwrap();
+    assert_eq!(cause.name(), "OverconstrainedError");
+    assert_eq!(cause.message(), "Constraint `width` cannot be satisfied");
+}
+
+#[wasm_bindgen_test]
+async fn exception_has_code_and_causes() {
+    let mock_navigator = MockNavigator::new();
+    mock_navigator.error_get_user_media("failed_get_user_media".into());
+    let media_manager = MediaManager::default();
+    let constraints = {
+        let mut constraints = api::MediaStreamSettings::new();
+        constraints.audio(api::AudioTrackConstraints::new());
+        constraints
+    };
+    let err = JsFuture::from(
+        api::MediaManagerHandle::from(media_manager.new_handle())
+            .init_local_tracks(&constraints),
+    )
+    .await
+    .unwrap_err();
+    mock_navigator.stop();
+
+    let code = js_sys::Reflect::get(&err, &"code".into()).unwrap();
+    assert_eq!(
+        code.as_string().unwrap(),
+        "LocalMediaInitException.GetUserMediaFailed",
+    );
+    let causes =
+        JsArray::from(&js_sys::Reflect::get(&err, &"causes".into()).unwrap());
+    assert_eq!(causes.length(), 1);
+    assert_eq!(
+        String::from(js_sys::Error::from(causes.get(0)).message()),
+        "failed_get_user_media",
+    );
+}
+
 #[wasm_bindgen_test]
 async fn failed_get_user_media2() {
     let mock_navigator = MockNavigator::new();
@@ -235,6 +314,137 @@ async fn new_track_if_previous_dropped() {
     mock_navigator.stop();
 }
 
+/// 1. Do `media_manager_handle.prewarm({audio:true})`;
+/// 2. Do `media_manager.get_stream({audio:true})`;
+/// 3. Assert that pre-warmed track was returned as a new one and no additional
+///    `getUserMedia` request was made.
+#[wasm_bindgen_test]
+async fn prewarmed_track_is_reused() {
+    let mock_navigator = MockNavigator::new();
+
+    let media_manager = MediaManager::default();
+    let constraints = {
+        let mut constraints = MediaStreamSettings::new();
+        constraints.audio(AudioTrackConstraints::new());
+        constraints
+    };
+
+    media_manager.new_handle().prewarm(constraints.clone()).await.unwrap();
+    assert_eq!(mock_navigator.get_user_media_requests_count(), 1);
+
+    let mut tracks = media_manager.get_tracks(constraints).await.unwrap();
+
+    assert_eq!(tracks.len(), 1);
+    let (track, is_new) = tracks.pop().unwrap();
+    assert!(is_new);
+    assert_eq!(track.kind(), MediaKind::Audio);
+    assert_eq!(mock_navigator.get_user_media_requests_count(), 1);
+
+    mock_navigator.stop();
+}
+
+/// 1. Make `getDisplayMedia` requests fail;
+/// 2. Do best-effort `media_manager.get_stream({audio:true, display:true})`;
+/// 3. Assert that audio track was acquired and display video is reported as
+///    failed.
+#[wasm_bindgen_test]
+async fn best_effort_acquires_remaining_kinds() {
+    let mock_navigator = MockNavigator::new();
+    mock_navigator.error_get_display_media("failed_display_media".into());
+
+    let media_manager = MediaManager::default();
+    let constraints = {
+        let mut constraints = MediaStreamSettings::new();
+        constraints.audio(AudioTrackConstraints::new());
+        constraints.display_video(DisplayVideoTrackConstraints::new());
+        constraints
+    };
+
+    let (tracks, failed) =
+        media_manager.get_tracks_best_effort(constraints).await;
+    mock_navigator.stop();
+
+    assert_eq!(tracks.len(), 1);
+    assert_eq!(tracks[0].0.kind(), MediaKind::Audio);
+    assert_eq!(failed.len(), 1);
+    let (kind, source_kind, err) = failed.into_iter().next().unwrap();
+    assert_eq!(kind, MediaKind::Video);
+    assert_eq!(source_kind, MediaSourceKind::Display);
+    assert!(matches!(
+        err.into_inner(),
+        InitLocalTracksError::GetDisplayMediaFailed(_),
+    ));
+}
+
+#[wasm_bindgen_test]
+async fn external_tracks_are_used_instead_of_gum() {
+    let constraints = sys::MediaStreamConstraints::new();
+    constraints.set_audio(&true.into());
+    let stream = JsFuture::from(
+        window()
+            .navigator()
+            .media_devices()
+            .unwrap()
+            .get_user_media_with_constraints(&constraints)
+            .unwrap(),
+    )
+    .await
+    .map(sys::MediaStream::from)
+    .unwrap();
+    let sys_track = sys::MediaStreamTrack::from(stream.get_tracks().get(0));
+
+    let mock_navigator = MockNavigator::new();
+    let media_manager = MediaManager::default();
+    media_manager.set_external_tracks(vec![(
+        platform::MediaStreamTrack::new(
+            sys_track.clone(),
+            Some(media::MediaSourceKind::Device),
+        ),
+        MediaSourceKind::Device,
+    )]);
+    let constraints = {
+        let mut constraints = MediaStreamSettings::new();
+        constraints.audio(AudioTrackConstraints::new());
+        constraints
+    };
+
+    let mut tracks =
+        media_manager.get_tracks(constraints.clone()).await.unwrap();
+    assert_eq!(tracks.len(), 1);
+    let (track, is_new) = tracks.pop().unwrap();
+    assert!(is_new);
+    assert_eq!(track.id(), sys_track.id());
+
+    let mut tracks = media_manager.get_tracks(constraints).await.unwrap();
+    let (_, is_new) = tracks.pop().unwrap();
+    assert!(!is_new);
+    assert_eq!(mock_navigator.get_user_media_requests_count(), 0);
+
+    drop(track);
+    drop(media_manager);
+    assert_eq!(sys_track.ready_state(), sys::MediaStreamTrackState::Live);
+
+    mock_navigator.stop();
+    sys_track.stop();
+}
+
+#[wasm_bindgen_test]
+async fn stop_tracks_stops_used_tracks() {
+    let media_manager = MediaManager::default();
+    let constraints = {
+        let mut constraints = MediaStreamSettings::new();
+        constraints.audio(AudioTrackConstraints::new());
+        constraints
+    };
+    let mut tracks = media_manager.get_tracks(constraints).await.unwrap();
+    let (track, _) = tracks.pop().unwrap();
+    assert_eq!(track.state().await, MediaStreamTrackState::Live);
+
+    media_manager.stop_tracks().await;
+
+    assert_eq!(track.state().await, MediaStreamTrackState::Ended);
+}
+
 /// 1. Do `media_manager.get_stream({audio:true, video:true}})`;
 /// 2. Do `media_manager.get_stream({audio:true}})`;
 /// 3. Do `media_manager.get_stream({video:true}})`;
@@ -1,5 +1,6 @@
 mod constraints;
 mod manager;
+mod recorder;
 mod track;
 
 use std::rc::Rc;
@@ -12,7 +13,9 @@ use medea_client_api_proto::{
 use medea_jason::{
     media::{MediaDirection, MediaManager, RecvConstraints},
     peer::{LocalStreamUpdateCriteria, MediaConnections, SimpleTracksRequest},
-    platform::{RtcPeerConnection, TransceiverDirection},
+    platform::{
+        BundlePolicy, RtcPeerConnection, RtcpMuxPolicy, TransceiverDirection,
+    },
 };
 use wasm_bindgen_test::*;
 
@@ -24,8 +27,17 @@ wasm_bindgen_test_configure!(run_in_browser);
 async fn sendrecv_works() {
     let (tx, _rx) = mpsc::unbounded();
     let media_connections = MediaConnections::new(
-        Rc::new(RtcPeerConnection::new(Vec::new(), false).await.unwrap()),
-        tx,
+        Rc::new(
+            RtcPeerConnection::new(
+                Vec::new(),
+                false,
+                BundlePolicy::default(),
+                RtcpMuxPolicy::default(),
+            )
+            .await
+            .unwrap(),
+        ),
+        tx.into(),
     );
     let send_audio_track = Track {
         id: TrackId(1),
@@ -0,0 +1,105 @@
+#![cfg(target_arch = "wasm32")]
+
+use std::rc::Rc;
+
+use futures::StreamExt as _;
+use medea_jason::{
+    media::{
+        AudioTrackConstraints, DeviceVideoTrackConstraints, MediaManager,
+        MediaStreamSettings, Recorder, track::local,
+    },
+    platform::{MediaRecorderError, MediaRecorderOptions},
+};
+use wasm_bindgen_test::*;
+
+use crate::{delay_for, timeout};
+
+/// Acquires new [`local::LocalMediaTrack`]s with the provided
+/// [`MediaStreamSettings`].
+async fn local_tracks(
+    settings: MediaStreamSettings,
+) -> Vec<local::LocalMediaTrack> {
+    MediaManager::default()
+        .get_tracks(settings)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(track, _)| local::LocalMediaTrack::new(track))
+        .collect()
+}
+
+/// Acquires a new video [`local::LocalMediaTrack`].
+async fn local_video_track() -> local::LocalMediaTrack {
+    let mut settings = MediaStreamSettings::new();
+    settings.device_video(DeviceVideoTrackConstraints::new());
+    local_tracks(settings).await.pop().unwrap()
+}
+
+/// Acquires a new audio [`local::LocalMediaTrack`].
+async fn local_audio_track() -> local::LocalMediaTrack {
+    let mut settings = MediaStreamSettings::new();
+    settings.audio(AudioTrackConstraints::new());
+    local_tracks(settings).await.pop().unwrap()
+}
+
+/// Checks that `Recorder::stop()` resolves right away if the recording has
+/// never been started.
+#[wasm_bindgen_test]
+async fn stop_resolves_if_not_started() {
+    let recorder = Recorder::new(MediaRecorderOptions::default());
+    recorder.add_local_track(local_video_track().await);
+
+    timeout(100, recorder.stop()).await.unwrap().unwrap();
+}
+
+/// Checks that starting and stopping a `Recorder` emits the recorded data,
+/// and that stopping an already stopped `Recorder` resolves right away.
+#[wasm_bindgen_test]
+async fn start_stop_emits_data() {
+    let recorder = Rc::new(Recorder::new(MediaRecorderOptions::default()));
+    recorder.add_local_track(local_video_track().await);
+    recorder.add_local_track(local_audio_track().await);
+    let mut data = recorder.on_data();
+
+    recorder.start(None).unwrap();
+    delay_for(500).await;
+    timeout(1000, recorder.stop()).await.unwrap().unwrap();
+
+    let chunk = timeout(100, data.next()).await.unwrap().unwrap();
+    assert!(!chunk.is_empty());
+
+    timeout(100, recorder.stop()).await.unwrap().unwrap();
+}
+
+/// Checks that multiple audio tracks are recorded along with a video track.
+#[wasm_bindgen_test]
+async fn records_multiple_audio_tracks() {
+    let recorder = Recorder::new(MediaRecorderOptions::default());
+    recorder.add_local_track(local_video_track().await);
+    recorder.add_local_track(local_audio_track().await);
+    recorder.add_local_track(local_audio_track().await);
+
+    recorder.start(None).unwrap();
+    timeout(1000, recorder.stop()).await.unwrap().unwrap();
+}
+
+/// Checks that `Recorder::start()` errors if more than one video track is
+/// added, instead of silently recording only the first one.
+#[wasm_bindgen_test]
+async fn multiple_video_tracks_are_rejected() {
+    let recorder = Recorder::new(MediaRecorderOptions::default());
+    recorder.add_local_track(local_video_track().await);
+    recorder.add_local_track(local_video_track().await);
+
+    let err = recorder.start(None).unwrap_err().into_inner();
+    assert!(matches!(err, MediaRecorderError::MultipleVideoTracks));
+}
+
+/// Checks that pausing a never started `Recorder` errors.
+#[wasm_bindgen_test]
+async fn pause_errors_if_not_started() {
+    let recorder = Recorder::new(MediaRecorderOptions::default());
+
+    let err = recorder.pause().unwrap_err().into_inner();
+    assert!(matches!(err, MediaRecorderError::NotStarted));
+}
@@ -1,22 +1,168 @@
 #![cfg(target_arch = "wasm32")]
 
-use std::rc::{Rc, Weak};
+use std::{
+    rc::{Rc, Weak},
+    time::Duration,
+};
 
 use futures::{
     StreamExt as _,
     channel::{mpsc, oneshot},
 };
+use medea_client_api_proto as proto;
 use medea_jason::{
     api::MediaDirection,
     media::{
-        DeviceVideoTrackConstraints, MediaManager, MediaStreamSettings,
-        track::remote,
+        AudioTrackConstraints, DeviceVideoTrackConstraints, MediaKind,
+        MediaManager, MediaSourceKind, MediaStreamSettings,
+        MediaStreamTrackState,
+        track::{local, remote},
     },
+    platform,
 };
-use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast as _, closure::Closure};
+use wasm_bindgen_futures::spawn_local;
 use wasm_bindgen_test::*;
 
-use crate::{get_audio_track, timeout};
+use crate::{delay_for, get_audio_track, timeout};
+
+/// Checks that `local::Track::settings()` reflects the actual settings of
+/// the underlying track.
+#[wasm_bindgen_test]
+async fn local_track_settings() {
+    let media_manager = MediaManager::default();
+    let mut video = DeviceVideoTrackConstraints::new();
+    video.exact_width(640);
+    video.exact_height(480);
+    let mut caps = MediaStreamSettings::new();
+    caps.device_video(video);
+
+    let mut tracks = media_manager.get_tracks(caps).await.unwrap();
+    let (track, _) = tracks.pop().unwrap();
+    let sys_settings = track.as_ref().as_ref().as_ref().get_settings();
+
+    let settings = track.settings();
+    assert_eq!(settings.width, Some(640));
+    assert_eq!(settings.height, Some(480));
+    assert_eq!(settings.device_id, sys_settings.get_device_id());
+    assert_eq!(settings.frame_rate, sys_settings.get_frame_rate());
+}
+
+/// Checks that `local::Track::is_producing()` detects live audio and video
+/// tracks producing media data.
+#[wasm_bindgen_test]
+async fn local_track_is_producing() {
+    let media_manager = MediaManager::default();
+    let mut caps = MediaStreamSettings::new();
+    caps.audio(AudioTrackConstraints::new());
+    caps.device_video(DeviceVideoTrackConstraints::new());
+
+    let tracks = media_manager.get_tracks(caps).await.unwrap();
+    assert_eq!(tracks.len(), 2);
+    for (track, _) in tracks {
+        assert!(track.is_producing(Duration::from_secs(3)).await);
+    }
+}
+
+/// Checks that `local::Track::is_producing()` resolves to `false` for an
+/// ended track.
+#[wasm_bindgen_test]
+async fn ended_local_track_is_not_producing() {
+    let media_manager = MediaManager::default();
+    let mut caps = MediaStreamSettings::new();
+    caps.device_video(DeviceVideoTrackConstraints::new());
+
+    let (track, _) =
+        media_manager.get_tracks(caps).await.unwrap().pop().unwrap();
+    track.as_ref().as_ref().as_ref().stop();
+
+    assert!(!track.is_producing(Duration::from_millis(500)).await);
+}
+
+/// Returns a [muted][1] [`web_sys::MediaStreamTrack`] received by a new
+/// [`web_sys::RtcPeerConnection`], which has to be kept alive alongside.
+///
+/// [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack-muted
+fn muted_sys_track() -> (web_sys::RtcPeerConnection, web_sys::MediaStreamTrack)
+{
+    let peer = web_sys::RtcPeerConnection::new().unwrap();
+    let sys_track = peer.add_transceiver_with_str("video").receiver().track();
+    assert!(sys_track.muted());
+
+    (peer, sys_track)
+}
+
+/// Spawns `local::Track::ready()` of the provided track, returning a channel
+/// receiving its result.
+fn spawn_ready(
+    track: &Rc<local::Track>,
+) -> oneshot::Receiver<MediaStreamTrackState> {
+    let (tx, rx) = oneshot::channel();
+    let track = Rc::clone(track);
+    spawn_local(async move {
+        _ = tx.send(track.ready().await);
+    });
+    rx
+}
+
+/// Checks that concurrent `local::Track::ready()` calls all wait for a muted
+/// track, and all resolve once it's unmuted.
+#[wasm_bindgen_test]
+async fn concurrent_ready_calls_resolve_on_unmute() {
+    let (_peer, sys_track) = muted_sys_track();
+    let track = Rc::new(local::Track::new_external(
+        platform::MediaStreamTrack::new(
+            <web_sys::MediaStreamTrack as Clone>::clone(&sys_track),
+            Some(MediaSourceKind::Device),
+        ),
+        proto::MediaSourceKind::Device,
+    ));
+
+    let mut first = spawn_ready(&track);
+    let mut second = spawn_ready(&track);
+    delay_for(100).await;
+    assert_eq!(first.try_recv(), Ok(None));
+    assert_eq!(second.try_recv(), Ok(None));
+
+    sys_track.dispatch_event(&web_sys::Event::new("unmute").unwrap()).unwrap();
+
+    assert_eq!(
+        timeout(1000, first).await.unwrap().unwrap(),
+        MediaStreamTrackState::Live,
+    );
+    assert_eq!(
+        timeout(1000, second).await.unwrap().unwrap(),
+        MediaStreamTrackState::Live,
+    );
+}
+
+/// Checks that `local::Track::ready()` of a muted track resolves once the
+/// track ends.
+#[wasm_bindgen_test]
+async fn ready_resolves_on_ended_while_muted() {
+    let (_peer, sys_track) = muted_sys_track();
+    let track = Rc::new(local::Track::new_external(
+        platform::MediaStreamTrack::new(
+            <web_sys::MediaStreamTrack as Clone>::clone(&sys_track),
+            Some(MediaSourceKind::Device),
+        ),
+        proto::MediaSourceKind::Device,
+    ));
+
+    let mut ready = spawn_ready(&track);
+    delay_for(100).await;
+    assert_eq!(ready.try_recv(), Ok(None));
+
+    // Stopping a track doesn't fire an `ended` event, so it's dispatched
+    // manually, as if the track has been ended by its source.
+    sys_track.stop();
+    sys_track.dispatch_event(&web_sys::Event::new("ended").unwrap()).unwrap();
+
+    assert_eq!(
+        timeout(1000, ready).await.unwrap().unwrap(),
+        MediaStreamTrackState::Ended,
+    );
+}
 
 /// Assert that track is stopped when all strong refs are dropped.
 #[wasm_bindgen_test]
@@ -39,6 +185,61 @@ async fn track_autostop() {
     assert_eq!(Weak::strong_count(&weak_track), 0);
 }
 
+/// Checks that `local::Track` mirror intent is toggled via
+/// `local::LocalMediaTrack` and is inherited by forked tracks.
+#[wasm_bindgen_test]
+async fn local_track_mirror_intent() {
+    let media_manager = MediaManager::default();
+    let mut caps = MediaStreamSettings::new();
+    caps.device_video(DeviceVideoTrackConstraints::new());
+
+    let (track, _) =
+        media_manager.get_tracks(caps).await.unwrap().pop().unwrap();
+    let handle = local::LocalMediaTrack::new(Rc::clone(&track));
+    assert!(!track.is_mirrored());
+    assert!(!handle.is_mirrored());
+
+    handle.set_mirror(true);
+    assert!(track.is_mirrored());
+    assert!(handle.is_mirrored());
+
+    let forked = track.fork().await;
+    assert!(forked.is_mirrored());
+
+    forked.set_mirror(false);
+    assert!(!forked.is_mirrored());
+    assert!(track.is_mirrored());
+
+    handle.set_mirror(false);
+    assert!(!handle.is_mirrored());
+}
+
+/// Checks that `remote::Track` reports kinds signalled by a media server.
+#[wasm_bindgen_test]
+async fn remote_track_exposes_signalled_kinds() {
+    let media_manager = MediaManager::default();
+    let mut caps = MediaStreamSettings::new();
+    caps.device_video(DeviceVideoTrackConstraints::new());
+    let mut tracks = media_manager.get_tracks(caps).await.unwrap();
+    let track = tracks.pop().unwrap().0.as_ref().as_ref().fork().await;
+
+    let display = remote::Track::new(
+        track,
+        MediaKind::Video,
+        Some(proto::MediaSourceKind::Display),
+        false,
+        MediaDirection::SendRecv.into(),
+    );
+    assert_eq!(display.kind(), MediaKind::Video);
+    assert_eq!(display.source_kind(), Some(MediaSourceKind::Display));
+    assert_eq!(display.media_source_kind(), MediaSourceKind::Display);
+
+    let audio: remote::Track = get_audio_track().await.into();
+    assert_eq!(audio.kind(), MediaKind::Audio);
+    assert_eq!(audio.source_kind(), None);
+    assert_eq!(audio.media_source_kind(), MediaSourceKind::Device);
+}
+
 #[wasm_bindgen_test]
 async fn on_track_unmuted_works() {
     let api_track = get_audio_track().await;
@@ -112,6 +313,130 @@ async fn on_track_muted_works() {
     timeout(100, dont_fire_rx.next()).await.unwrap_err();
 }
 
+#[wasm_bindgen_test]
+async fn on_track_paused_works() {
+    let api_track = get_audio_track().await;
+    let core_track: remote::Track = api_track.clone().into();
+
+    let core_track_clone = core_track.clone();
+    let (test_tx, test_rx) = oneshot::channel();
+    api_track.on_paused(
+        Closure::once_into_js(move || {
+            assert!(core_track_clone.paused());
+            test_tx.send(()).unwrap();
+        })
+        .into(),
+    );
+
+    let (dont_fire_tx, mut dont_fire_rx) = mpsc::unbounded();
+    let dont_fire = || {
+        let tx = dont_fire_tx.clone();
+        Closure::once_into_js(move || {
+            tx.unbounded_send(()).unwrap();
+        })
+        .into()
+    };
+    api_track.on_resumed(dont_fire());
+    api_track.on_muted(dont_fire());
+    api_track.on_stopped(dont_fire());
+
+    assert!(!api_track.paused());
+    core_track.set_paused(true);
+    assert!(api_track.paused());
+    assert!(!api_track.muted());
+
+    timeout(100, test_rx).await.unwrap().unwrap();
+    timeout(100, dont_fire_rx.next()).await.unwrap_err();
+}
+
+#[wasm_bindgen_test]
+async fn on_track_resumed_works() {
+    let api_track = get_audio_track().await;
+    let core_track: remote::Track = api_track.clone().into();
+
+    let core_track_clone = core_track.clone();
+    let (test_tx, test_rx) = oneshot::channel();
+    api_track.on_resumed(
+        Closure::once_into_js(move || {
+            assert!(!core_track_clone.paused());
+            test_tx.send(()).unwrap();
+        })
+        .into(),
+    );
+
+    core_track.set_paused(true);
+    delay_for(10).await;
+    core_track.set_paused(false);
+    assert!(!api_track.paused());
+
+    timeout(100, test_rx).await.unwrap().unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn on_volume_changed_works() {
+    let api_track = get_audio_track().await;
+    let core_track: remote::Track = api_track.clone().into();
+    assert_eq!(api_track.volume(), 1.0);
+
+    let (test_tx, test_rx) = oneshot::channel();
+    api_track.on_volume_changed(
+        Closure::once_into_js(move |volume: f64| {
+            test_tx.send(volume).unwrap();
+        })
+        .into(),
+    );
+
+    core_track.set_volume(1.5);
+
+    assert_eq!(timeout(100, test_rx).await.unwrap().unwrap(), 1.0);
+    api_track.set_volume(0.25);
+    assert_eq!(core_track.volume(), 0.25);
+}
+
+#[wasm_bindgen_test]
+async fn attach_and_detach_keep_track_alive() {
+    let api_track = get_audio_track().await;
+    let element: web_sys::HtmlMediaElement =
+        js_sys::eval("document.createElement('audio')")
+            .unwrap()
+            .unchecked_into();
+
+    api_track.attach(element.clone()).unwrap();
+    assert!(api_track.is_attached());
+    assert!(element.src_object().is_some());
+
+    api_track.detach();
+    assert!(!api_track.is_attached());
+    assert!(element.src_object().is_none());
+    assert_eq!(
+        api_track.get_track().ready_state(),
+        web_sys::MediaStreamTrackState::Live,
+    );
+
+    api_track.attach(element.clone()).unwrap();
+    assert!(element.src_object().is_some());
+}
+
+#[wasm_bindgen_test]
+async fn volume_is_applied_to_attached_element() {
+    let api_track = get_audio_track().await;
+    let element: web_sys::HtmlMediaElement =
+        js_sys::eval("document.createElement('audio')")
+            .unwrap()
+            .unchecked_into();
+
+    api_track.set_volume(0.5);
+    api_track.attach(element.clone()).unwrap();
+    assert_eq!(element.volume(), 0.5);
+
+    api_track.set_volume(0.25);
+    assert_eq!(element.volume(), 0.25);
+
+    api_track.detach();
+    api_track.set_volume(1.0);
+    assert_eq!(element.volume(), 0.25);
+}
+
 mod audio_processing {
     use medea_jason::{api, media::MediaManager};
     use wasm_bindgen::JsValue;
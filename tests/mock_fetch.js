@@ -0,0 +1,100 @@
+export class MockFetch {
+  constructor() {
+    let self = this;
+
+    this.requests = [];
+    this.authorizations = [];
+    this.peers = [];
+    this.status = 201;
+    this.location = 'resource/1';
+    this.withMedia = false;
+
+    this._fetch = window.fetch;
+
+    window.fetch = async function(input, init) {
+      let req = input instanceof Request ? input : new Request(input, init);
+      self.requests.push(req.method + ' ' + req.url);
+      self.authorizations.push(req.headers.get('Authorization') || '');
+
+      if (req.method === 'POST') {
+        if (self.status >= 300) {
+          return new Response(null, {status: self.status});
+        }
+        let answer = await self._answer(await req.text());
+        return new Response(answer, {
+          status: self.status,
+          headers: {'Content-Type': 'application/sdp', 'Location': self.location},
+        });
+      }
+      if (req.method === 'PATCH') {
+        await self._addCandidates(await req.text());
+      }
+      return new Response(null, {status: 204});
+    }
+  }
+
+  async _answer(offer) {
+    let pc = new RTCPeerConnection();
+    this.peers.push(pc);
+    await pc.setRemoteDescription({type: 'offer', sdp: offer});
+    if (this.withMedia) {
+      let audio = new AudioContext().createMediaStreamDestination();
+      let canvas = document.createElement('canvas');
+      canvas.getContext('2d').fillRect(0, 0, canvas.width, canvas.height);
+      let tracks = {
+        audio: audio.stream.getAudioTracks()[0],
+        video: canvas.captureStream().getVideoTracks()[0],
+      };
+      for (let transceiver of pc.getTransceivers()) {
+        let track = tracks[transceiver.receiver.track.kind];
+        await transceiver.sender.replaceTrack(track);
+        transceiver.direction = 'sendonly';
+      }
+    }
+    let gathered = new Promise((resolve) => {
+      pc.onicegatheringstatechange = () => {
+        if (pc.iceGatheringState === 'complete') {
+          resolve();
+        }
+      };
+    });
+    await pc.setLocalDescription(await pc.createAnswer());
+    await gathered;
+    return pc.localDescription.sdp;
+  }
+
+  async _addCandidates(fragment) {
+    let pc = this.peers[this.peers.length - 1];
+    let mid = null;
+    for (let line of fragment.split('\r\n')) {
+      if (line.startsWith('a=mid:')) {
+        mid = line.substring(6);
+      } else if (line.startsWith('a=candidate:') && pc) {
+        await pc.addIceCandidate({candidate: line.substring(2), sdpMid: mid});
+      }
+    }
+  }
+
+  set responseStatus(status) {
+    this.status = status;
+  }
+
+  set answerWithMedia(enabled) {
+    this.withMedia = enabled;
+  }
+
+  get requestsLog() {
+    return this.requests;
+  }
+
+  get authorizationsLog() {
+    return this.authorizations;
+  }
+
+  stop() {
+    window.fetch = this._fetch;
+    for (let pc of this.peers) {
+      pc.close();
+    }
+  }
+}
@@ -55,6 +55,25 @@ export class MockNavigator {
     window.navigator.mediaDevices.getDisplayMedia = async function() {return stream};
   }
 
+  holdGetUserMedia() {
+    let self = this;
+    let held = new Promise((resolve) => {
+      self._releaseGetUserMedia = resolve;
+    });
+    window.navigator.mediaDevices.getUserMedia = async function(arg) {
+      self.getUserMediaInvocations++;
+      await held;
+      return await self._getUserMedia.call(
+        window.navigator.mediaDevices,
+        arg
+      );
+    }
+  }
+
+  releaseGetUserMedia() {
+    this._releaseGetUserMedia();
+  }
+
   stop() {
     window.navigator.mediaDevices.getUserMedia = this._getUserMedia;
     window.navigator.mediaDevices.getDisplayMedia = this._getDisplayMedia;
@@ -9,17 +9,18 @@ use medea_client_api_proto::{ConnectionMode, TrackId, TrackPatchEvent};
 use medea_jason::{
     media::{LocalTracksConstraints, MediaManager, RecvConstraints},
     peer::{
-        LocalStreamUpdateCriteria, MediaConnections, MediaStateControllable,
-        SimpleTracksRequest, media_exchange_state,
+        InsertLocalTracksError, LocalStreamUpdateCriteria, MediaConnections,
+        MediaStateControllable, SimpleTracksRequest, TrackDirection,
+        TrackStatus, media_exchange_state,
     },
-    platform::RtcPeerConnection,
+    platform::{BundlePolicy, RtcPeerConnection, RtcpMuxPolicy},
     utils::Updatable as _,
 };
 use wasm_bindgen_test::*;
 
 use crate::{
     get_media_stream_settings, get_test_unrequired_tracks, is_firefox,
-    local_constraints,
+    local_constraints, yield_now,
 };
 
 wasm_bindgen_test_configure!(run_in_browser);
@@ -31,8 +32,17 @@ async fn get_test_media_connections(
     let (tx, rx) = mpsc::unbounded();
     mem::forget(rx);
     let media_connections = MediaConnections::new(
-        Rc::new(RtcPeerConnection::new(Vec::new(), false).await.unwrap()),
-        tx,
+        Rc::new(
+            RtcPeerConnection::new(
+                Vec::new(),
+                false,
+                BundlePolicy::default(),
+                RtcpMuxPolicy::default(),
+            )
+            .await
+            .unwrap(),
+        ),
+        tx.into(),
     );
     let (audio_track, video_track) = get_test_unrequired_tracks();
     let audio_track_id = audio_track.id;
@@ -86,8 +96,17 @@ async fn get_tracks_request1() {
     let (tx, rx) = mpsc::unbounded();
     mem::forget(rx);
     let media_connections = MediaConnections::new(
-        Rc::new(RtcPeerConnection::new(Vec::new(), false).await.unwrap()),
-        tx,
+        Rc::new(
+            RtcPeerConnection::new(
+                Vec::new(),
+                false,
+                BundlePolicy::default(),
+                RtcpMuxPolicy::default(),
+            )
+            .await
+            .unwrap(),
+        ),
+        tx.into(),
     );
     let (audio_track, video_track) = get_test_unrequired_tracks();
     media_connections
@@ -109,8 +128,17 @@ async fn get_tracks_request2() {
     let (tx, rx) = mpsc::unbounded();
     mem::forget(rx);
     let media_connections = MediaConnections::new(
-        Rc::new(RtcPeerConnection::new(Vec::new(), false).await.unwrap()),
-        tx,
+        Rc::new(
+            RtcPeerConnection::new(
+                Vec::new(),
+                false,
+                BundlePolicy::default(),
+                RtcpMuxPolicy::default(),
+            )
+            .await
+            .unwrap(),
+        ),
+        tx.into(),
     );
     media_connections
         .create_tracks(
@@ -154,6 +182,147 @@ async fn new_media_connections_with_disabled_video_tracks() {
     assert!(!video_track.enabled());
 }
 
+#[wasm_bindgen_test]
+async fn insert_local_track_touches_single_sender() {
+    let (media_connections, audio_track_id, video_track_id) =
+        get_test_media_connections(true, true).await;
+    let video_track = media_connections
+        .get_sender_by_id(video_track_id)
+        .unwrap()
+        .get_send_track()
+        .unwrap();
+
+    let (audio_track, _) = MediaManager::default()
+        .get_tracks(get_media_stream_settings(true, false))
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    let update = media_connections
+        .insert_local_track(audio_track_id, Some(Rc::clone(&audio_track)))
+        .await
+        .unwrap();
+    assert_eq!(update, None);
+
+    let audio_sender =
+        media_connections.get_sender_by_id(audio_track_id).unwrap();
+    assert!(Rc::ptr_eq(&audio_sender.get_send_track().unwrap(), &audio_track));
+    let video_sender =
+        media_connections.get_sender_by_id(video_track_id).unwrap();
+    assert!(Rc::ptr_eq(&video_sender.get_send_track().unwrap(), &video_track));
+
+    let update =
+        media_connections.insert_local_track(audio_track_id, None).await;
+    assert_eq!(update.unwrap(), Some(media_exchange_state::Stable::Disabled));
+
+    let err = media_connections
+        .insert_local_track(TrackId(100), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.into_inner(),
+        InsertLocalTracksError::NoSuchSender(TrackId(100)),
+    ));
+}
+
+/// Checks that inserting a video [`local::Track`] into a [`Sender`] requiring
+/// a codec unsupported by the platform errors with an
+/// [`InsertLocalTracksError::UnsupportedCodec`].
+///
+/// [`local::Track`]: medea_jason::media::track::local::Track
+/// [`Sender`]: medea_jason::peer::media::Sender
+#[wasm_bindgen_test]
+async fn insert_local_track_with_unsupported_codec() {
+    use std::collections::HashMap;
+
+    use medea_client_api_proto::{Codec, EncodingParameters, MediaType};
+
+    let (tx, rx) = mpsc::unbounded();
+    mem::forget(rx);
+    let media_connections = MediaConnections::new(
+        Rc::new(
+            RtcPeerConnection::new(
+                Vec::new(),
+                false,
+                BundlePolicy::default(),
+                RtcpMuxPolicy::default(),
+            )
+            .await
+            .unwrap(),
+        ),
+        tx.into(),
+    );
+    let (_, mut video_track) = get_test_unrequired_tracks();
+    let video_track_id = video_track.id;
+    let MediaType::Video(settings) = &mut video_track.media_type else {
+        unreachable!("video `Track` is expected");
+    };
+    settings.encoding_parameters = vec![EncodingParameters {
+        rid: "h".into(),
+        active: true,
+        codec: Some(Codec {
+            mime_type: "video/unsupported".into(),
+            clock_rate: 90000,
+            channels: None,
+            parameters: HashMap::new(),
+        }),
+        ..EncodingParameters::default()
+    }];
+    media_connections
+        .create_tracks(
+            vec![video_track],
+            &get_media_stream_settings(false, true).into(),
+            &RecvConstraints::default(),
+            ConnectionMode::Mesh,
+        )
+        .await
+        .unwrap();
+
+    let (track, _) = MediaManager::default()
+        .get_tracks(get_media_stream_settings(false, true))
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    let err = media_connections
+        .insert_local_track(video_track_id, Some(track))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err.into_inner(),
+        InsertLocalTracksError::UnsupportedCodec { track_id, mime_type }
+            if track_id == video_track_id && mime_type == "video/unsupported",
+    ));
+    assert!(
+        media_connections
+            .get_sender_by_id(video_track_id)
+            .unwrap()
+            .get_send_track()
+            .is_none()
+    );
+}
+
+#[wasm_bindgen_test]
+async fn track_status_of_senders() {
+    let (media_connections, audio_track_id, video_track_id) =
+        get_test_media_connections(true, false).await;
+
+    assert_eq!(
+        media_connections.track_status(audio_track_id).await,
+        Some(TrackStatus {
+            enabled: true,
+            muted: false,
+            live: true,
+            direction: TrackDirection::Send,
+        }),
+    );
+    assert!(
+        !media_connections.track_status(video_track_id).await.unwrap().enabled
+    );
+    assert_eq!(media_connections.track_status(TrackId(999)).await, None);
+}
+
 /// Tests for [`Sender::update`] function.
 ///
 /// This tests checks that [`TrackPatch`] works as expected.
@@ -190,8 +359,17 @@ mod sender_patch {
         let (tx, rx) = mpsc::unbounded();
         mem::forget(rx);
         let media_connections = MediaConnections::new(
-            Rc::new(RtcPeerConnection::new(Vec::new(), false).await.unwrap()),
-            tx,
+            Rc::new(
+                RtcPeerConnection::new(
+                    Vec::new(),
+                    false,
+                    BundlePolicy::default(),
+                    RtcpMuxPolicy::default(),
+                )
+                .await
+                .unwrap(),
+            ),
+            tx.into(),
         );
         let sender = media_connections
             .create_sender(
@@ -219,12 +397,25 @@ mod sender_patch {
             media_direction: Some(MediaDirection::RecvOnly),
             muted: None,
             encoding_parameters: None,
+            paused: None,
         });
         sender.state().when_updated().await;
 
         assert!(!sender.general_disabled());
     }
 
+    #[wasm_bindgen_test]
+    async fn set_label() {
+        let (sender, _, _media_connections) = audio_sender().await;
+        assert_eq!(sender.label(), None);
+
+        sender.state().set_label("camera-main".into());
+        yield_now().await;
+
+        assert_eq!(sender.state().label().as_deref(), Some("camera-main"));
+        assert_eq!(sender.label().as_deref(), Some("camera-main"));
+    }
+
     #[wasm_bindgen_test]
     async fn disable() {
         let (sender, track_id, _media_connections) = audio_sender().await;
@@ -234,6 +425,7 @@ mod sender_patch {
             media_direction: Some(MediaDirection::RecvOnly),
             muted: None,
             encoding_parameters: None,
+            paused: None,
         });
         sender.state().when_updated().await;
 
@@ -249,6 +441,7 @@ mod sender_patch {
             media_direction: Some(MediaDirection::SendRecv),
             muted: None,
             encoding_parameters: None,
+            paused: None,
         });
         sender.state().when_updated().await;
 
@@ -264,6 +457,7 @@ mod sender_patch {
             media_direction: Some(MediaDirection::RecvOnly),
             muted: None,
             encoding_parameters: None,
+            paused: None,
         });
         sender.state().when_updated().await;
         assert!(sender.general_disabled());
@@ -274,6 +468,7 @@ mod sender_patch {
             media_direction: Some(MediaDirection::RecvOnly),
             muted: None,
             encoding_parameters: None,
+            paused: None,
         });
         sender.state().when_updated().await;
 
@@ -289,6 +484,7 @@ mod sender_patch {
             media_direction: None,
             muted: None,
             encoding_parameters: None,
+            paused: None,
         });
         sender.state().when_updated().await;
 
@@ -365,6 +561,7 @@ mod sender_patch {
                 scale_resolution_down_by: Some(2),
                 scalability_mode: Some(ScalabilityMode::L1T2),
             }]),
+            paused: None,
         });
 
         sender.state().when_updated().await;
@@ -384,6 +581,68 @@ mod sender_patch {
         }
     }
 
+    /// Checks that [`Sender::set_degradation_preference()`] is applied to the
+    /// underlying sender and persists across encodings updates.
+    ///
+    /// [`Sender::set_degradation_preference()`]: sender::Sender::set_degradation_preference
+    #[wasm_bindgen_test]
+    async fn degradation_preference_persists() {
+        use js_sys::Reflect;
+        use medea_jason::platform::DegradationPreference;
+        use wasm_bindgen::JsValue;
+        use web_sys::RtcRtpParameters;
+
+        async fn current_preference(sender: &sender::Sender) -> JsValue {
+            let params: RtcRtpParameters =
+                sender.transceiver().get_send_parameters().await.into();
+            Reflect::get(&params, &JsValue::from_str("degradationPreference"))
+                .unwrap()
+        }
+
+        let (sender, track_id, _media_connections) =
+            video_sender(vec![EncodingParameters {
+                rid: "0".to_owned(),
+                scalability_mode: None,
+                active: true,
+                max_bitrate: None,
+                scale_resolution_down_by: None,
+                codec: None,
+            }])
+            .await;
+
+        sender
+            .set_degradation_preference(
+                DegradationPreference::MaintainResolution,
+            )
+            .await
+            .unwrap();
+
+        sender.state().update(TrackPatchEvent {
+            id: track_id,
+            receivers: None,
+            media_direction: None,
+            muted: None,
+            encoding_parameters: Some(vec![EncodingParameters {
+                rid: "0".to_owned(),
+                active: true,
+                codec: None,
+                max_bitrate: Some(100),
+                scale_resolution_down_by: None,
+                scalability_mode: None,
+            }]),
+            paused: None,
+        });
+        sender.state().when_updated().await;
+
+        if !is_firefox() {
+            // TODO: `degradationPreference` is not supported in Firefox.
+            assert_eq!(
+                current_preference(&sender).await,
+                JsValue::from_str("maintain-resolution"),
+            );
+        }
+    }
+
     /// Checks that [`Sender`]'s mute and media exchange states can be changed
     /// by [`SenderState`] update.
     #[wasm_bindgen_test]
@@ -405,6 +664,322 @@ mod sender_patch {
     }
 }
 
+#[wasm_bindgen_test]
+async fn receives_multiple_audio_tracks_from_same_member() {
+    use medea_client_api_proto::{
+        AudioSettings, MediaDirection, MediaType, MemberId,
+    };
+    use medea_jason::platform::TransceiverDirection;
+
+    let (media_connections, _, _) =
+        get_test_media_connections(true, true).await;
+    let (first_id, second_id) = (TrackId(10), TrackId(11));
+    for id in [first_id, second_id] {
+        let receiver = media_connections
+            .create_receiver(
+                id,
+                MediaType::Audio(AudioSettings { required: false }),
+                MediaDirection::SendRecv,
+                false,
+                None,
+                MemberId("bob".into()),
+                &RecvConstraints::default(),
+                ConnectionMode::Mesh,
+            )
+            .await;
+        media_connections.insert_receiver(receiver);
+    }
+
+    let first = media_connections
+        .get_receiver_by_id(first_id)
+        .unwrap()
+        .transceiver()
+        .unwrap();
+    let second = media_connections
+        .get_receiver_by_id(second_id)
+        .unwrap()
+        .transceiver()
+        .unwrap();
+    // Only the first one reuses the audio sender's transceiver as `sendrecv`.
+    assert!(first.has_direction(TransceiverDirection::SEND).await);
+    assert!(!second.has_direction(TransceiverDirection::SEND).await);
+    assert!(second.has_direction(TransceiverDirection::RECV).await);
+}
+
+#[wasm_bindgen_test]
+async fn recv_slot_is_bound_to_next_receiver() {
+    use medea_client_api_proto::{
+        AudioSettings, MediaDirection, MediaType, MemberId,
+    };
+    use medea_jason::media::MediaKind;
+
+    let (media_connections, _, _) =
+        get_test_media_connections(true, true).await;
+    let bound = media_connections.add_recv_slot(MediaKind::Audio).await;
+
+    // The audio sender's transceiver is reused as `sendrecv` first.
+    for id in [TrackId(10), TrackId(11)] {
+        let receiver = media_connections
+            .create_receiver(
+                id,
+                MediaType::Audio(AudioSettings { required: false }),
+                MediaDirection::SendRecv,
+                false,
+                None,
+                MemberId("bob".into()),
+                &RecvConstraints::default(),
+                ConnectionMode::Mesh,
+            )
+            .await;
+        media_connections.insert_receiver(receiver);
+    }
+
+    assert_eq!(bound.await.unwrap(), TrackId(11));
+}
+
+/// Checks that [`MediaConnections::add_remote_track()`] reports the same track
+/// added twice as a duplicate, and a track without a matching receiver as an
+/// error.
+#[wasm_bindgen_test]
+async fn duplicate_remote_track_is_reported() {
+    use std::collections::HashMap;
+
+    use futures::StreamExt as _;
+    use medea_client_api_proto::{
+        AudioSettings, MediaDirection, MediaType, MemberId,
+    };
+    use medea_jason::{
+        media::MediaKind,
+        peer::{AddRemoteTrackError, RemoteTrackAdded},
+        platform::{
+            MediaStreamTrack, SdpType, TransceiverDirection, TransceiverInit,
+        },
+    };
+
+    let remote_peer = RtcPeerConnection::new(
+        Vec::new(),
+        false,
+        BundlePolicy::default(),
+        RtcpMuxPolicy::default(),
+    )
+    .await
+    .unwrap();
+    let audio_mid = remote_peer
+        .add_transceiver(
+            MediaKind::Audio,
+            TransceiverInit::new(TransceiverDirection::SEND),
+        )
+        .await;
+    let video_mid = remote_peer
+        .add_transceiver(
+            MediaKind::Video,
+            TransceiverInit::new(TransceiverDirection::SEND),
+        )
+        .await;
+    let offer = remote_peer.create_offer().await.unwrap();
+    remote_peer.set_offer(&offer).await.unwrap();
+    let (audio_mid, video_mid) =
+        (audio_mid.mid().unwrap(), video_mid.mid().unwrap());
+
+    let peer = Rc::new(
+        RtcPeerConnection::new(
+            Vec::new(),
+            false,
+            BundlePolicy::default(),
+            RtcpMuxPolicy::default(),
+        )
+        .await
+        .unwrap(),
+    );
+    let (track_tx, mut track_rx) = mpsc::unbounded();
+    peer.on_track(Some(move |track, transceiver| {
+        _ = track_tx.unbounded_send((track, transceiver));
+    }));
+    let (tx, rx) = mpsc::unbounded();
+    mem::forget(rx);
+    let media_connections = MediaConnections::new(Rc::clone(&peer), tx.into());
+    let receiver = media_connections
+        .create_receiver(
+            TrackId(1),
+            MediaType::Audio(AudioSettings { required: false }),
+            MediaDirection::SendRecv,
+            false,
+            Some(audio_mid.clone()),
+            MemberId::from("bob"),
+            &RecvConstraints::default(),
+            ConnectionMode::Mesh,
+        )
+        .await;
+    media_connections.insert_receiver(receiver);
+
+    peer.set_remote_description(SdpType::Offer(offer)).await.unwrap();
+    let mut tracks = HashMap::new();
+    for _ in 0..2 {
+        let (track, transceiver) = track_rx.next().await.unwrap();
+        drop(tracks.insert(transceiver.mid().unwrap(), (track, transceiver)));
+    }
+
+    let (audio_track, audio_transceiver) = tracks.remove(&audio_mid).unwrap();
+    assert_eq!(
+        media_connections
+            .add_remote_track(
+                MediaStreamTrack::new(
+                    // Copies the reference, not the underlying track.
+                    <web_sys::MediaStreamTrack as Clone>::clone(
+                        audio_track.as_ref(),
+                    ),
+                    None,
+                ),
+                audio_transceiver.clone(),
+            )
+            .await
+            .unwrap(),
+        RemoteTrackAdded::Added,
+    );
+    assert_eq!(
+        media_connections
+            .add_remote_track(audio_track, audio_transceiver)
+            .await
+            .unwrap(),
+        RemoteTrackAdded::Duplicate {
+            sender_id: MemberId::from("bob"),
+            track_id: TrackId(1),
+        },
+    );
+
+    let (video_track, video_transceiver) = tracks.remove(&video_mid).unwrap();
+    let err = media_connections
+        .add_remote_track(video_track, video_transceiver)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.into_inner(),
+        AddRemoteTrackError::NoReceiver(mid) if mid == video_mid,
+    ));
+}
+
+#[wasm_bindgen_test]
+async fn recv_slot_is_bound_to_server_offered_receiver() {
+    use medea_client_api_proto::{
+        AudioSettings, MediaDirection, MediaType, MemberId,
+    };
+    use medea_jason::media::MediaKind;
+
+    let (media_connections, _, _) =
+        get_test_media_connections(true, true).await;
+    let bound = media_connections.add_recv_slot(MediaKind::Audio).await;
+
+    let receiver = media_connections
+        .create_receiver(
+            TrackId(10),
+            MediaType::Audio(AudioSettings { required: false }),
+            MediaDirection::SendRecv,
+            false,
+            Some("mid".into()),
+            MemberId("bob".into()),
+            &RecvConstraints::default(),
+            ConnectionMode::Mesh,
+        )
+        .await;
+    media_connections.insert_receiver(receiver);
+
+    assert_eq!(bound.await.unwrap(), TrackId(10));
+}
+
+/// Checks that [`MediaConnections::update_receivers_frame_sizes()`] updates
+/// the [`FrameSize`] of the [`Receiver`] matching the `mid` of an inbound
+/// [RTP] stat only.
+///
+/// [`FrameSize`]: medea_jason::peer::media::FrameSize
+/// [`Receiver`]: medea_jason::peer::media::Receiver
+/// [RTP]: https://en.wikipedia.org/wiki/Real-time_Transport_Protocol
+#[wasm_bindgen_test]
+async fn frame_sizes_are_updated_by_mid() {
+    use medea_client_api_proto::{
+        MediaDirection, MediaSourceKind, MediaType, MemberId, VideoSettings,
+        stats::{
+            HighResTimeStamp, RtcInboundRtpStreamMediaType,
+            RtcInboundRtpStreamStats, RtcStat, RtcStatsType, StatId,
+        },
+    };
+    use medea_jason::{peer::media::FrameSize, platform::RtcStats};
+
+    fn video_stat(mid: &str, size: Option<(u64, u64)>) -> RtcStat {
+        RtcStat {
+            id: StatId(format!("inbound-{mid}")),
+            timestamp: HighResTimeStamp(1584373509700.0),
+            stats: RtcStatsType::InboundRtp(Box::new(
+                RtcInboundRtpStreamStats {
+                    track_id: None,
+                    mid: Some(mid.into()),
+                    media_specific_stats: RtcInboundRtpStreamMediaType::Video {
+                        frames_decoded: None,
+                        key_frames_decoded: None,
+                        frame_width: size.map(|(w, _)| w),
+                        frame_height: size.map(|(_, h)| h),
+                        total_inter_frame_delay: None,
+                        frames_per_second: None,
+                        frame_bit_depth: None,
+                        fir_count: None,
+                        pli_count: None,
+                        sli_count: None,
+                        concealment_events: None,
+                        frames_received: None,
+                    },
+                    bytes_received: 0,
+                    packets_received: 0,
+                    packets_lost: None,
+                    jitter: None,
+                    total_decode_time: None,
+                    jitter_buffer_emitted_count: None,
+                },
+            )),
+        }
+    }
+
+    let (media_connections, _, _) =
+        get_test_media_connections(true, true).await;
+    let (first_id, second_id) = (TrackId(10), TrackId(11));
+    for (id, mid) in [(first_id, "mid-a"), (second_id, "mid-b")] {
+        let receiver = media_connections
+            .create_receiver(
+                id,
+                MediaType::Video(VideoSettings {
+                    required: false,
+                    source_kind: MediaSourceKind::Device,
+                    encoding_parameters: Vec::new(),
+                }),
+                MediaDirection::SendRecv,
+                false,
+                Some(mid.into()),
+                MemberId("bob".into()),
+                &RecvConstraints::default(),
+                ConnectionMode::Mesh,
+            )
+            .await;
+        media_connections.insert_receiver(receiver);
+    }
+    let first = media_connections.get_receiver_by_id(first_id).unwrap();
+    let second = media_connections.get_receiver_by_id(second_id).unwrap();
+
+    media_connections.update_receivers_frame_sizes(&RtcStats(vec![
+        video_stat("mid-b", Some((640, 480))),
+        video_stat("unknown", Some((1280, 720))),
+    ]));
+    assert_eq!(first.frame_size(), None);
+    assert_eq!(
+        second.frame_size(),
+        Some(FrameSize { width: 640, height: 480 }),
+    );
+
+    media_connections.update_receivers_frame_sizes(&RtcStats(vec![
+        video_stat("mid-a", Some((320, 240))),
+        video_stat("mid-b", None),
+    ]));
+    assert_eq!(first.frame_size(), Some(FrameSize { width: 320, height: 240 }),);
+    assert_eq!(second.frame_size(), None);
+}
+
 mod receiver_patch {
     use medea_client_api_proto::{
         AudioSettings, MediaDirection, MediaType, MemberId,
@@ -425,8 +1000,17 @@ mod receiver_patch {
     -> (receiver::Component, mpsc::UnboundedReceiver<PeerEvent>) {
         let (tx, rx) = mpsc::unbounded();
         let media_connections = MediaConnections::new(
-            Rc::new(RtcPeerConnection::new(Vec::new(), false).await.unwrap()),
-            tx,
+            Rc::new(
+                RtcPeerConnection::new(
+                    Vec::new(),
+                    false,
+                    BundlePolicy::default(),
+                    RtcpMuxPolicy::default(),
+                )
+                .await
+                .unwrap(),
+            ),
+            tx.into(),
         );
         let recv = media_connections
             .create_receiver(
@@ -453,6 +1037,7 @@ mod receiver_patch {
             media_direction: Some(MediaDirection::RecvOnly),
             muted: None,
             encoding_parameters: None,
+            paused: None,
         });
         receiver.state().when_updated().await;
 
@@ -468,6 +1053,7 @@ mod receiver_patch {
             media_direction: Some(MediaDirection::RecvOnly),
             muted: None,
             encoding_parameters: None,
+            paused: None,
         });
         receiver.state().when_updated().await;
 
@@ -483,6 +1069,7 @@ mod receiver_patch {
             media_direction: Some(MediaDirection::SendRecv),
             muted: None,
             encoding_parameters: None,
+            paused: None,
         });
         receiver.state().when_updated().await;
 
@@ -498,6 +1085,7 @@ mod receiver_patch {
             media_direction: Some(MediaDirection::RecvOnly),
             muted: None,
             encoding_parameters: None,
+            paused: None,
         });
         receiver.state().when_updated().await;
         assert!(!receiver.enabled_general());
@@ -508,6 +1096,7 @@ mod receiver_patch {
             media_direction: Some(MediaDirection::RecvOnly),
             muted: None,
             encoding_parameters: None,
+            paused: None,
         });
         receiver.state().when_updated().await;
 
@@ -523,12 +1112,64 @@ mod receiver_patch {
             media_direction: None,
             muted: None,
             encoding_parameters: None,
+            paused: None,
         });
         receiver.state().when_updated().await;
 
         assert!(receiver.enabled_general());
     }
 
+    #[wasm_bindgen_test]
+    async fn pause_and_resume() {
+        let (receiver, _tx) = get_receiver().await;
+        assert!(!receiver.state().paused());
+
+        receiver.state().update(&TrackPatchEvent {
+            id: TRACK_ID,
+            receivers: None,
+            media_direction: None,
+            muted: None,
+            encoding_parameters: None,
+            paused: Some(true),
+        });
+        receiver.state().when_updated().await;
+        assert!(receiver.state().paused());
+        assert!(receiver.enabled_general());
+
+        receiver.state().update(&TrackPatchEvent {
+            id: TRACK_ID,
+            receivers: None,
+            media_direction: None,
+            muted: None,
+            encoding_parameters: None,
+            paused: Some(false),
+        });
+        receiver.state().when_updated().await;
+        assert!(!receiver.state().paused());
+    }
+
+    /// Checks that the paused state of a [`Receiver`] survives its
+    /// [`ReceiverState`] being restored, e.g. after a reconnect.
+    #[wasm_bindgen_test]
+    async fn paused_is_restored_by_state() {
+        let (receiver, _tx) = get_receiver().await;
+
+        let mut proto_state = receiver.state().as_proto();
+        assert!(!proto_state.paused);
+        proto_state.paused = true;
+
+        let restored = receiver::State::from_proto(
+            proto_state.clone(),
+            &LocalTracksConstraints::default(),
+        );
+        assert!(restored.paused());
+
+        receiver.state().apply(proto_state, &LocalTracksConstraints::default());
+        receiver.state().when_updated().await;
+        assert!(receiver.state().paused());
+        assert!(receiver.state().as_proto().paused);
+    }
+
     /// Checks that [`Receiver`]'s media exchange state can be changed by
     /// [`ReceiverState`] update.
     #[wasm_bindgen_test]
@@ -555,7 +1196,10 @@ mod codec_probing {
     use medea_client_api_proto::Codec;
     use medea_jason::{
         media::MediaKind,
-        platform::{CodecCapability, transceiver::probe_target_codecs},
+        platform::{
+            CodecCapability, TransceiverDirection,
+            transceiver::{probe_preferred_codecs, probe_target_codecs},
+        },
     };
 
     use super::*;
@@ -666,6 +1310,140 @@ mod codec_probing {
         assert_eq!(res[0..2].to_vec(), &["video/VP9", "video/VP8"]);
     }
 
+    fn codec(mime_type: &str) -> Codec {
+        Codec {
+            mime_type: mime_type.to_owned(),
+            clock_rate: 90000,
+            channels: None,
+            parameters: HashMap::new(),
+        }
+    }
+
+    fn codec_key(
+        cap: &CodecCapability,
+    ) -> (String, u32, Option<u16>, Vec<(String, String)>) {
+        let mut params: Vec<_> = cap.parameters().into_iter().collect();
+        params.sort();
+        (cap.mime_type(), cap.clock_rate(), cap.channels(), params)
+    }
+
+    async fn preferred_mime_types(
+        kind: MediaKind,
+        preferred: &[Codec],
+    ) -> Vec<String> {
+        let mut res: Vec<_> =
+            probe_preferred_codecs(kind, TransceiverDirection::SEND, preferred)
+                .await
+                .into_iter()
+                .map(|c| c.mime_type())
+                .collect();
+        res.dedup();
+        res
+    }
+
+    #[wasm_bindgen_test]
+    async fn preferred_codecs_priority() {
+        let mut preferred = vec![codec("video/VP9"), codec("video/VP8")];
+
+        let res = preferred_mime_types(MediaKind::Video, &preferred).await;
+        assert_eq!(res[0..2].to_vec(), &["video/VP9", "video/VP8"]);
+        assert!(!res.contains(&"video/H264".to_owned()));
+        assert!(res.contains(&"video/rtx".to_owned()));
+
+        // reverse and repeat
+        preferred.reverse();
+
+        let res = preferred_mime_types(MediaKind::Video, &preferred).await;
+        assert_eq!(res[0..2].to_vec(), &["video/VP8", "video/VP9"]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn preferred_codecs_of_kind() {
+        let opus = Codec {
+            mime_type: "audio/opus".to_owned(),
+            clock_rate: 48000,
+            channels: Some(2),
+            parameters: HashMap::new(),
+        };
+
+        let res = preferred_mime_types(MediaKind::Audio, &[opus]).await;
+        assert_eq!(res[0], "audio/opus");
+        for mime_type in &res[1..] {
+            assert_eq!(mime_type, "audio/telephone-event");
+        }
+
+        // Video codecs are never preferred for audio.
+        assert!(
+            preferred_mime_types(MediaKind::Audio, &[codec("video/VP8")])
+                .await
+                .is_empty()
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn unsupported_preferred_codecs_are_dropped() {
+        let res = preferred_mime_types(
+            MediaKind::Video,
+            &[codec("video/unsupported"), codec("video/VP8")],
+        )
+        .await;
+        assert_eq!(res[0], "video/VP8");
+        assert!(!res.contains(&"video/unsupported".to_owned()));
+
+        assert!(
+            preferred_mime_types(
+                MediaKind::Video,
+                &[codec("video/unsupported")]
+            )
+            .await
+            .is_empty()
+        );
+        assert!(preferred_mime_types(MediaKind::Video, &[]).await.is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    async fn preferred_codecs_are_probed_by_direction() {
+        let preferred = [codec("video/VP8"), codec("video/VP9")];
+        let recv_caps: Vec<_> =
+            CodecCapability::get_receiver_codec_capabilities(MediaKind::Video)
+                .await
+                .unwrap()
+                .iter()
+                .map(codec_key)
+                .collect();
+        let send_caps: Vec<_> =
+            CodecCapability::get_sender_codec_capabilities(MediaKind::Video)
+                .await
+                .unwrap()
+                .iter()
+                .map(codec_key)
+                .collect();
+
+        let recv = probe_preferred_codecs(
+            MediaKind::Video,
+            TransceiverDirection::RECV,
+            &preferred,
+        )
+        .await;
+        assert!(!recv.is_empty());
+        assert_eq!(recv[0].mime_type(), "video/VP8");
+        for cap in &recv {
+            assert!(recv_caps.contains(&codec_key(cap)));
+        }
+
+        let send = probe_preferred_codecs(
+            MediaKind::Video,
+            TransceiverDirection::SEND | TransceiverDirection::RECV,
+            &preferred,
+        )
+        .await;
+        assert!(!send.is_empty());
+        assert_eq!(send[0].mime_type(), "video/VP8");
+        for cap in &send {
+            assert!(send_caps.contains(&codec_key(cap)));
+        }
+    }
+
     // This test is necessary to identify changes in the list of available
     // browser codecs, allowing us to add new required service codecs or
     // support new video codecs.
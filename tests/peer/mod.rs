@@ -2,7 +2,7 @@
 
 mod media;
 
-use std::{pin::Pin, rc::Rc};
+use std::{collections::HashMap, mem, pin::Pin, rc::Rc, time::Duration};
 
 use futures::{
     Stream, StreamExt as _,
@@ -10,9 +10,10 @@ use futures::{
     future::{self, FutureExt as _},
 };
 use medea_client_api_proto::{
-    AudioSettings, ConnectionMode, Direction, IceConnectionState,
-    MediaDirection, MediaSourceKind, MediaType, MemberId, NegotiationRole,
-    PeerId, Track, TrackId, TrackPatchEvent, VideoSettings,
+    AudioSettings, Codec, Command, ConnectionMode, Direction,
+    IceConnectionState, MediaDirection, MediaSourceKind, MediaType, MemberId,
+    NegotiationRole, PeerConnectionState, PeerId, Track, TrackId,
+    TrackPatchEvent, VideoSettings,
     stats::{
         HighResTimeStamp, KnownIceCandidatePairState, NonExhaustive,
         RtcInboundRtpStreamMediaType, RtcOutboundRtpStreamMediaType, RtcStat,
@@ -23,16 +24,18 @@ use medea_jason::{
     connection::Connections,
     media::{LocalTracksConstraints, MediaKind, MediaManager, RecvConstraints},
     peer::{
-        self, MediaStateControllable, PeerEvent, TrackDirection,
-        media_exchange_state,
+        self, LocalStreamUpdateCriteria, MediaStateControllable, OpusConfig,
+        PeerEvent, SwitchDeviceError, TrackDirection, TracksRequestError,
+        UpdateLocalStreamError, media_exchange_state, sender::InsertDtmfError,
     },
-    platform::RtcStats,
+    platform::{self, DataChannelInit, IceCandidate, RtcStats, SignalingState},
     utils::Updatable,
 };
 use wasm_bindgen_test::*;
+use web_sys::MediaStreamTrackState;
 
 use crate::{
-    delay_for, get_media_stream_settings, get_test_recv_tracks,
+    MockNavigator, delay_for, get_media_stream_settings, get_test_recv_tracks,
     get_test_unrequired_tracks, local_constraints, timeout,
 };
 
@@ -50,6 +53,7 @@ fn toggle_disable_track_update(id: TrackId, enabled: bool) -> TrackPatchEvent {
         media_direction: Some(media_direction),
         muted: None,
         encoding_parameters: None,
+        paused: None,
     }
 }
 
@@ -225,12 +229,620 @@ async fn new_with_disable_video() {
     assert!(!peer.is_send_video_enabled(None));
 }
 
+/// Checks that [`State::remove_tracks_by()`] removes all the matching tracks
+/// only, requesting a single renegotiation from Media Server.
+///
+/// [`State::remove_tracks_by()`]: peer::State::remove_tracks_by
+#[wasm_bindgen_test]
+async fn remove_tracks_by_kind() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let manager = Rc::new(MediaManager::default());
+    let (audio_track, video_track) = get_test_unrequired_tracks();
+    let peer_state = peer::State::new(
+        PeerId(1),
+        Vec::new(),
+        false,
+        Some(NegotiationRole::Offerer),
+        ConnectionMode::Mesh,
+    );
+    let send_constraints = local_constraints(true, true);
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let peer = peer::Component::new(
+        peer::PeerConnection::new(
+            &peer_state,
+            tx,
+            manager,
+            send_constraints.clone(),
+            Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+            recv_constraints,
+        )
+        .await
+        .unwrap(),
+        Rc::new(peer_state),
+    );
+    peer.state().insert_track(&audio_track, send_constraints.clone());
+    peer.state().insert_track(&video_track, send_constraints);
+    peer.state().when_local_sdp_updated().await.unwrap();
+    peer.state().reset_negotiation_role();
+    while let Ok(Some(_)) = rx.try_next() {}
+
+    assert!(
+        peer.state()
+            .remove_tracks_by(MediaKind::Video, Some(MediaSourceKind::Display))
+            .is_empty()
+    );
+    assert_eq!(
+        peer.state().remove_tracks_by(MediaKind::Video, None),
+        vec![VIDEO_TRACK_ID],
+    );
+    peer.state().when_all_updated().await;
+
+    assert!(peer.state().get_sender(VIDEO_TRACK_ID).is_none());
+    assert!(peer.get_sender_by_id(VIDEO_TRACK_ID).is_none());
+    assert!(peer.state().get_sender(AUDIO_TRACK_ID).is_some());
+    assert!(peer.get_sender_by_id(AUDIO_TRACK_ID).is_some());
+
+    assert_eq!(
+        peer.state().remove_tracks_by(MediaKind::Audio, None),
+        vec![AUDIO_TRACK_ID],
+    );
+    peer.state().when_all_updated().await;
+
+    assert!(peer.state().get_sender(AUDIO_TRACK_ID).is_none());
+    assert!(peer.get_sender_by_id(AUDIO_TRACK_ID).is_none());
+
+    delay_for(100).await;
+    let mut renegotiations = 0;
+    while let Ok(Some(event)) = rx.try_next() {
+        if let PeerEvent::MediaUpdateCommand {
+            command: Command::RequestRenegotiation { peer_id },
+        } = event
+        {
+            assert_eq!(peer_id, PeerId(1));
+            renegotiations += 1;
+        }
+    }
+    assert_eq!(renegotiations, 1);
+}
+
+/// Checks that [`PeerConnection::validate_tracks_request()`] detects
+/// unsatisfiable constraints without acquiring any tracks.
+///
+/// [`PeerConnection::validate_tracks_request()`]: peer::PeerConnection::validate_tracks_request
+#[wasm_bindgen_test]
+async fn validate_tracks_request_detects_too_many_tracks() {
+    let (tx, _rx) = mpsc::unbounded();
+    let manager = Rc::new(MediaManager::default());
+    let (audio_track, video_track) = get_test_unrequired_tracks();
+    let peer_state = peer::State::new(
+        PeerId(1),
+        Vec::new(),
+        false,
+        None,
+        ConnectionMode::Mesh,
+    );
+    let send_constraints = local_constraints(true, true);
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let peer = peer::Component::new(
+        peer::PeerConnection::new(
+            &peer_state,
+            tx,
+            manager,
+            send_constraints.clone(),
+            Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+            recv_constraints,
+        )
+        .await
+        .unwrap(),
+        Rc::new(peer_state),
+    );
+    peer.state().insert_track(&audio_track, send_constraints.clone());
+    peer.state().insert_track(&video_track, send_constraints.clone());
+    peer.state().when_all_updated().await;
+
+    peer.validate_tracks_request(LocalStreamUpdateCriteria::all()).unwrap();
+
+    let mut second_audio_track = audio_track;
+    second_audio_track.id = TrackId(3);
+    peer.state().insert_track(&second_audio_track, send_constraints);
+    peer.state().when_all_updated().await;
+
+    let err = peer
+        .validate_tracks_request(LocalStreamUpdateCriteria::all())
+        .unwrap_err();
+    assert!(matches!(err.into_inner(), TracksRequestError::TooManyAudioTracks));
+}
+
+#[wasm_bindgen_test]
+async fn add_candidates_to_answerer_before_offer() {
+    let (tx1, rx1) = mpsc::unbounded();
+    let (tx2, _) = mpsc::unbounded();
+    let (audio_track, video_track) = get_test_unrequired_tracks();
+
+    let manager = Rc::new(MediaManager::default());
+    let pc1_state = peer::State::new(
+        PeerId(1),
+        Vec::new(),
+        false,
+        Some(NegotiationRole::Offerer),
+        ConnectionMode::Mesh,
+    );
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let pc1 = peer::Component::new(
+        peer::PeerConnection::new(
+            &pc1_state,
+            tx1,
+            Rc::clone(&manager),
+            LocalTracksConstraints::default(),
+            Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+            recv_constraints,
+        )
+        .await
+        .unwrap(),
+        Rc::new(pc1_state),
+    );
+    pc1.state().insert_track(&audio_track, LocalTracksConstraints::default());
+    pc1.state().insert_track(&video_track, LocalTracksConstraints::default());
+    let pc1_offer = pc1.state().when_local_sdp_updated().await.unwrap();
+
+    let pc2_state = peer::State::new(
+        PeerId(2),
+        Vec::new(),
+        false,
+        None,
+        ConnectionMode::Mesh,
+    );
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let pc2 = peer::Component::new(
+        peer::PeerConnection::new(
+            &pc2_state,
+            tx2,
+            Rc::clone(&manager),
+            LocalTracksConstraints::default(),
+            Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+            recv_constraints,
+        )
+        .await
+        .unwrap(),
+        Rc::new(pc2_state),
+    );
+
+    handle_ice_candidates(rx1, &pc2, 1).await;
+    assert!(pc2.candidates_buffer_len() > 0);
+
+    pc2.state()
+        .set_negotiation_role(NegotiationRole::Answerer(pc1_offer))
+        .await;
+    pc2.state().when_local_sdp_updated().await.unwrap();
+    assert_eq!(pc2.candidates_buffer_len(), 0);
+}
+
+#[wasm_bindgen_test]
+async fn stereo_is_negotiated() {
+    let (tx1, _rx1) = mpsc::unbounded();
+    let (tx2, _rx2) = mpsc::unbounded();
+    let (audio_track, _) = get_test_unrequired_tracks();
+    let stereo = OpusConfig { stereo: Some(true), ..OpusConfig::default() };
+
+    let manager = Rc::new(MediaManager::default());
+    let pc1_state = peer::State::new(
+        PeerId(1),
+        Vec::new(),
+        false,
+        Some(NegotiationRole::Offerer),
+        ConnectionMode::Mesh,
+    );
+    pc1_state.set_opus_config(stereo);
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let pc1 = peer::Component::new(
+        peer::PeerConnection::new(
+            &pc1_state,
+            tx1,
+            Rc::clone(&manager),
+            LocalTracksConstraints::default(),
+            Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+            recv_constraints,
+        )
+        .await
+        .unwrap(),
+        Rc::new(pc1_state),
+    );
+    pc1.state().insert_track(&audio_track, LocalTracksConstraints::default());
+    let offer = pc1.state().when_local_sdp_updated().await.unwrap();
+    assert!(offer.contains("stereo=1;sprop-stereo=1"));
+
+    let pc2_state = peer::State::new(
+        PeerId(2),
+        Vec::new(),
+        false,
+        None,
+        ConnectionMode::Mesh,
+    );
+    pc2_state.set_opus_config(stereo);
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let pc2 = peer::Component::new(
+        peer::PeerConnection::new(
+            &pc2_state,
+            tx2,
+            Rc::clone(&manager),
+            LocalTracksConstraints::default(),
+            Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+            recv_constraints,
+        )
+        .await
+        .unwrap(),
+        Rc::new(pc2_state),
+    );
+    pc2.state().set_negotiation_role(NegotiationRole::Answerer(offer)).await;
+    let answer = pc2.state().when_local_sdp_updated().await.unwrap();
+    assert!(answer.contains("stereo=1"));
+}
+
+#[wasm_bindgen_test]
+async fn injected_candidates_are_buffered() {
+    let (tx, _rx) = mpsc::unbounded();
+    let pc_state = peer::State::new(
+        PeerId(1),
+        Vec::new(),
+        false,
+        None,
+        ConnectionMode::Mesh,
+    );
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let pc = peer::PeerConnection::new(
+        &pc_state,
+        tx,
+        Rc::new(MediaManager::default()),
+        LocalTracksConstraints::default(),
+        Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+        recv_constraints,
+    )
+    .await
+    .unwrap();
+
+    assert!(!pc.has_remote_description());
+    for port in [5000, 5001] {
+        pc.inject_ice_candidate(IceCandidate {
+            candidate: format!(
+                "candidate:1 1 udp 2122260223 192.168.0.1 {port} typ host"
+            ),
+            sdp_m_line_index: Some(0),
+            sdp_mid: Some("0".into()),
+        })
+        .await
+        .unwrap();
+    }
+    assert_eq!(pc.candidates_buffer_len(), 2);
+    let buffered = pc.buffered_ice_candidates();
+    assert!(buffered[0].candidate.contains("5000"));
+    assert!(buffered[1].candidate.contains("5001"));
+
+    assert_eq!(pc.take_buffered_ice_candidates().len(), 2);
+    assert_eq!(pc.candidates_buffer_len(), 0);
+    pc.await_ice_flush().await;
+
+    pc.set_has_remote_description(true);
+    assert!(pc.has_remote_description());
+}
+
+#[wasm_bindgen_test]
+async fn batched_candidates_are_buffered() {
+    let (tx, _rx) = mpsc::unbounded();
+    let pc_state = peer::State::new(
+        PeerId(1),
+        Vec::new(),
+        false,
+        None,
+        ConnectionMode::Mesh,
+    );
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let pc = peer::PeerConnection::new(
+        &pc_state,
+        tx,
+        Rc::new(MediaManager::default()),
+        LocalTracksConstraints::default(),
+        Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+        recv_constraints,
+    )
+    .await
+    .unwrap();
+    let candidates = || {
+        [5000, 5001]
+            .into_iter()
+            .map(|port| {
+                (
+                    format!(
+                        "candidate:1 1 udp 2122260223 192.168.0.1 {port} typ \
+                         host"
+                    ),
+                    Some(0),
+                    Some("0".into()),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    pc.add_ice_candidates(candidates()).await.unwrap();
+    assert_eq!(pc.candidates_buffer_len(), 2);
+    _ = pc.take_buffered_ice_candidates();
+
+    // No remote description is actually set, so all the candidates fail.
+    pc.set_has_remote_description(true);
+    let failed = pc.add_ice_candidates(candidates()).await.unwrap_err();
+    assert_eq!(failed.len(), 2);
+    assert!(failed[0].0.candidate.contains("5000"));
+    assert!(failed[1].0.candidate.contains("5001"));
+}
+
+#[wasm_bindgen_test]
+async fn add_candidates_to_offerer_before_answer() {
+    let (tx1, _) = mpsc::unbounded();
+    let (tx2, rx2) = mpsc::unbounded();
+    let (audio_track, video_track) = get_test_unrequired_tracks();
+
+    let manager = Rc::new(MediaManager::default());
+    let pc1_state = peer::State::new(
+        PeerId(1),
+        Vec::new(),
+        false,
+        Some(NegotiationRole::Offerer),
+        ConnectionMode::Mesh,
+    );
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let pc1 = peer::Component::new(
+        peer::PeerConnection::new(
+            &pc1_state,
+            tx1,
+            Rc::clone(&manager),
+            LocalTracksConstraints::default(),
+            Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+            recv_constraints,
+        )
+        .await
+        .unwrap(),
+        Rc::new(pc1_state),
+    );
+    pc1.state().insert_track(&audio_track, LocalTracksConstraints::default());
+    pc1.state().insert_track(&video_track, LocalTracksConstraints::default());
+
+    let pc2_state = peer::State::new(
+        PeerId(2),
+        Vec::new(),
+        false,
+        None,
+        ConnectionMode::Mesh,
+    );
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let pc2 = peer::Component::new(
+        peer::PeerConnection::new(
+            &pc2_state,
+            tx2,
+            Rc::clone(&manager),
+            LocalTracksConstraints::default(),
+            Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+            recv_constraints,
+        )
+        .await
+        .unwrap(),
+        Rc::new(pc2_state),
+    );
+
+    let offer = pc1.state().when_local_sdp_updated().await.unwrap();
+    pc2.state().set_negotiation_role(NegotiationRole::Answerer(offer)).await;
+    let answer = pc2.state().when_local_sdp_updated().await.unwrap();
+
+    handle_ice_candidates(rx2, &pc1, 1).await;
+
+    // assert that pc1 has buffered candidates
+    assert!(pc1.candidates_buffer_len() > 0);
+    pc1.state().set_remote_sdp(answer);
+    pc1.state().when_remote_sdp_processed().await;
+    // assert that pc1 has buffered candidates got fulshed
+    assert_eq!(pc1.candidates_buffer_len(), 0);
+}
+
+#[wasm_bindgen_test]
+async fn malformed_remote_sdp_is_rolled_back() {
+    let (tx1, _) = mpsc::unbounded();
+    let (tx2, _) = mpsc::unbounded();
+    let (audio_track, video_track) = get_test_unrequired_tracks();
+    let manager = Rc::new(MediaManager::default());
+    let pc1_state = peer::State::new(
+        PeerId(1),
+        Vec::new(),
+        false,
+        Some(NegotiationRole::Offerer),
+        ConnectionMode::Mesh,
+    );
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let pc1 = peer::Component::new(
+        peer::PeerConnection::new(
+            &pc1_state,
+            tx1,
+            Rc::clone(&manager),
+            LocalTracksConstraints::default(),
+            Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+            recv_constraints,
+        )
+        .await
+        .unwrap(),
+        Rc::new(pc1_state),
+    );
+    pc1.state().insert_track(&audio_track, LocalTracksConstraints::default());
+    pc1.state().insert_track(&video_track, LocalTracksConstraints::default());
+
+    pc1.state().when_local_sdp_updated().await.unwrap();
+    assert_eq!(pc1.signaling_state(), SignalingState::HaveLocalOffer);
+
+    pc1.state().set_remote_sdp("malformed SDP".into());
+    pc1.state().when_remote_sdp_processed().await;
+    assert_eq!(pc1.signaling_state(), SignalingState::Stable);
+    assert!(pc1.state().negotiation_role().is_none());
+
+    let pc2_state = peer::State::new(
+        PeerId(2),
+        Vec::new(),
+        false,
+        None,
+        ConnectionMode::Mesh,
+    );
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let pc2 = peer::Component::new(
+        peer::PeerConnection::new(
+            &pc2_state,
+            tx2,
+            manager,
+            LocalTracksConstraints::default(),
+            Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+            recv_constraints,
+        )
+        .await
+        .unwrap(),
+        Rc::new(pc2_state),
+    );
+
+    pc1.state().set_negotiation_role(NegotiationRole::Offerer).await;
+    let offer = pc1.state().when_local_sdp_updated().await.unwrap();
+    pc2.state().set_negotiation_role(NegotiationRole::Answerer(offer)).await;
+    let answer = pc2.state().when_local_sdp_updated().await.unwrap();
+    pc1.state().set_remote_sdp(answer);
+    pc1.state().when_remote_sdp_processed().await;
+
+    assert_eq!(pc1.signaling_state(), SignalingState::Stable);
+    assert!(pc1.state().negotiation_role().is_none());
+}
+
+/// Creates a new offering [`peer::Component`] with the provided
+/// [`peer::Politeness`], sending audio and video.
+async fn glaring_peer(
+    id: PeerId,
+    politeness: peer::Politeness,
+    manager: Rc<MediaManager>,
+) -> peer::Component {
+    let (tx, _) = mpsc::unbounded();
+    let (audio_track, video_track) = get_test_unrequired_tracks();
+    let state = peer::State::new(
+        id,
+        Vec::new(),
+        false,
+        Some(NegotiationRole::Offerer),
+        ConnectionMode::Mesh,
+    );
+    state.set_politeness(Some(politeness));
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let pc = peer::Component::new(
+        peer::PeerConnection::new(
+            &state,
+            tx,
+            manager,
+            LocalTracksConstraints::default(),
+            Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+            recv_constraints,
+        )
+        .await
+        .unwrap(),
+        Rc::new(state),
+    );
+    pc.state().insert_track(&audio_track, LocalTracksConstraints::default());
+    pc.state().insert_track(&video_track, LocalTracksConstraints::default());
+    pc
+}
+
+/// Checks that colliding SDP offers (glare) are resolved by the polite
+/// `Peer` abandoning its own offer, while the impolite one ignores the remote
+/// offer until its negotiation becomes stable.
 #[wasm_bindgen_test]
-async fn add_candidates_to_answerer_before_offer() {
+async fn offers_collision_is_resolved() {
+    let manager = Rc::new(MediaManager::default());
+    let impolite = glaring_peer(
+        PeerId(1),
+        peer::Politeness::Impolite,
+        Rc::clone(&manager),
+    )
+    .await;
+    let polite =
+        glaring_peer(PeerId(2), peer::Politeness::Polite, manager).await;
+
+    let impolite_offer =
+        impolite.state().when_local_sdp_updated().await.unwrap();
+    let polite_offer = polite.state().when_local_sdp_updated().await.unwrap();
+    assert_eq!(impolite.signaling_state(), SignalingState::HaveLocalOffer);
+    assert_eq!(polite.signaling_state(), SignalingState::HaveLocalOffer);
+
+    impolite
+        .state()
+        .set_negotiation_role(NegotiationRole::Answerer(polite_offer))
+        .await;
+    assert!(impolite.state().is_remote_offer_ignored());
+    assert_eq!(
+        impolite.state().negotiation_role(),
+        Some(NegotiationRole::Offerer),
+    );
+
+    polite
+        .state()
+        .set_negotiation_role(NegotiationRole::Answerer(impolite_offer))
+        .await;
+    assert!(!polite.state().is_remote_offer_ignored());
+    let answer = polite.state().when_local_sdp_updated().await.unwrap();
+    assert_eq!(polite.signaling_state(), SignalingState::Stable);
+
+    impolite.state().set_remote_sdp(answer);
+    impolite.state().when_remote_sdp_processed().await;
+    assert_eq!(impolite.signaling_state(), SignalingState::Stable);
+    assert!(impolite.state().negotiation_role().is_none());
+
+    timeout(1000, async {
+        while impolite.state().is_remote_offer_ignored() {
+            delay_for(10).await;
+        }
+    })
+    .await
+    .unwrap();
+}
+
+/// Checks that an impolite `Peer`, whose SDP offer hasn't been approved and
+/// so has been rolled back, doesn't treat the following remote offer as a
+/// collision.
+#[wasm_bindgen_test]
+async fn rolled_back_offer_does_not_collide() {
+    let manager = Rc::new(MediaManager::default());
+    let impolite = glaring_peer(
+        PeerId(1),
+        peer::Politeness::Impolite,
+        Rc::clone(&manager),
+    )
+    .await;
+    let polite =
+        glaring_peer(PeerId(2), peer::Politeness::Polite, manager).await;
+
+    impolite.state().when_local_sdp_updated().await.unwrap();
+    let polite_offer = polite.state().when_local_sdp_updated().await.unwrap();
+
+    // Media Server doesn't approve the offer, so it's rolled back.
+    timeout(2000, async {
+        while impolite.state().negotiation_role().is_some() {
+            delay_for(10).await;
+        }
+    })
+    .await
+    .unwrap();
+    assert_eq!(impolite.signaling_state(), SignalingState::Stable);
+
+    impolite
+        .state()
+        .set_negotiation_role(NegotiationRole::Answerer(polite_offer))
+        .await;
+    assert!(!impolite.state().is_remote_offer_ignored());
+    impolite.state().when_local_sdp_updated().await.unwrap();
+    assert_eq!(impolite.signaling_state(), SignalingState::Stable);
+}
+
+#[wasm_bindgen_test]
+async fn ice_flush_is_not_blocked_by_failed_remote_sdp() {
     let (tx1, rx1) = mpsc::unbounded();
     let (tx2, _) = mpsc::unbounded();
     let (audio_track, video_track) = get_test_unrequired_tracks();
-
     let manager = Rc::new(MediaManager::default());
     let pc1_state = peer::State::new(
         PeerId(1),
@@ -255,7 +867,7 @@ async fn add_candidates_to_answerer_before_offer() {
     );
     pc1.state().insert_track(&audio_track, LocalTracksConstraints::default());
     pc1.state().insert_track(&video_track, LocalTracksConstraints::default());
-    let pc1_offer = pc1.state().when_local_sdp_updated().await.unwrap();
+    pc1.state().when_local_sdp_updated().await.unwrap();
 
     let pc2_state = peer::State::new(
         PeerId(2),
@@ -269,7 +881,7 @@ async fn add_candidates_to_answerer_before_offer() {
         peer::PeerConnection::new(
             &pc2_state,
             tx2,
-            Rc::clone(&manager),
+            manager,
             LocalTracksConstraints::default(),
             Rc::new(Connections::new(Rc::clone(&recv_constraints))),
             recv_constraints,
@@ -283,16 +895,19 @@ async fn add_candidates_to_answerer_before_offer() {
     assert!(pc2.candidates_buffer_len() > 0);
 
     pc2.state()
-        .set_negotiation_role(NegotiationRole::Answerer(pc1_offer))
+        .set_negotiation_role(NegotiationRole::Answerer("malformed SDP".into()))
         .await;
-    pc2.state().when_local_sdp_updated().await.unwrap();
+    pc2.state().when_remote_sdp_processed().await;
+
+    timeout(1000, pc2.await_ice_flush()).await.unwrap();
     assert_eq!(pc2.candidates_buffer_len(), 0);
 }
 
 #[wasm_bindgen_test]
-async fn add_candidates_to_offerer_before_answer() {
-    let (tx1, _) = mpsc::unbounded();
+async fn normal_exchange_of_candidates() {
+    let (tx1, rx1) = mpsc::unbounded();
     let (tx2, rx2) = mpsc::unbounded();
+
     let (audio_track, video_track) = get_test_unrequired_tracks();
 
     let manager = Rc::new(MediaManager::default());
@@ -345,21 +960,95 @@ async fn add_candidates_to_offerer_before_answer() {
     let offer = pc1.state().when_local_sdp_updated().await.unwrap();
     pc2.state().set_negotiation_role(NegotiationRole::Answerer(offer)).await;
     let answer = pc2.state().when_local_sdp_updated().await.unwrap();
+    pc1.state().set_remote_sdp(answer);
+    pc1.state().when_remote_sdp_processed().await;
 
+    handle_ice_candidates(rx1, &pc2, 1).await;
     handle_ice_candidates(rx2, &pc1, 1).await;
+}
+
+/// Checks that [`peer::PeerConnection::is_negotiating()`] and the exposed
+/// SDPs follow the signaling state of the underlying peer.
+#[wasm_bindgen_test]
+async fn is_negotiating_follows_signaling_state() {
+    let (tx1, _rx1) = mpsc::unbounded();
+    let (tx2, _rx2) = mpsc::unbounded();
+
+    let (audio_track, video_track) = get_test_unrequired_tracks();
+
+    let manager = Rc::new(MediaManager::default());
+    let pc1_state = peer::State::new(
+        PeerId(1),
+        Vec::new(),
+        false,
+        None,
+        ConnectionMode::Mesh,
+    );
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let pc1 = peer::Component::new(
+        peer::PeerConnection::new(
+            &pc1_state,
+            tx1,
+            Rc::clone(&manager),
+            LocalTracksConstraints::default(),
+            Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+            recv_constraints,
+        )
+        .await
+        .unwrap(),
+        Rc::new(pc1_state),
+    );
+    pc1.state().insert_track(&audio_track, LocalTracksConstraints::default());
+    pc1.state().insert_track(&video_track, LocalTracksConstraints::default());
+
+    let pc2_state = peer::State::new(
+        PeerId(2),
+        Vec::new(),
+        false,
+        None,
+        ConnectionMode::Mesh,
+    );
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let pc2 = peer::Component::new(
+        peer::PeerConnection::new(
+            &pc2_state,
+            tx2,
+            Rc::clone(&manager),
+            LocalTracksConstraints::default(),
+            Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+            recv_constraints,
+        )
+        .await
+        .unwrap(),
+        Rc::new(pc2_state),
+    );
+
+    assert!(!pc1.obj().is_negotiating());
+    assert!(pc1.obj().local_description().is_none());
+
+    pc1.state().set_negotiation_role(NegotiationRole::Offerer).await;
+    let offer = pc1.state().when_local_sdp_updated().await.unwrap();
+    assert!(pc1.obj().is_negotiating());
+    assert!(pc1.obj().local_description().is_some());
+    assert!(pc1.obj().remote_description().is_none());
+
+    pc2.state().set_negotiation_role(NegotiationRole::Answerer(offer)).await;
+    let answer = pc2.state().when_local_sdp_updated().await.unwrap();
+    assert!(!pc2.obj().is_negotiating());
+    assert!(pc2.obj().local_description().is_some());
 
-    // assert that pc1 has buffered candidates
-    assert!(pc1.candidates_buffer_len() > 0);
     pc1.state().set_remote_sdp(answer);
     pc1.state().when_remote_sdp_processed().await;
-    // assert that pc1 has buffered candidates got fulshed
-    assert_eq!(pc1.candidates_buffer_len(), 0);
+    assert!(!pc1.obj().is_negotiating());
+    assert!(pc1.obj().remote_description().is_some());
 }
 
+/// Checks that an empty remote ICE candidate is treated as the
+/// end-of-candidates signal rather than a malformed candidate.
 #[wasm_bindgen_test]
-async fn normal_exchange_of_candidates() {
-    let (tx1, rx1) = mpsc::unbounded();
-    let (tx2, rx2) = mpsc::unbounded();
+async fn empty_candidate_signals_end_of_candidates() {
+    let (tx1, _rx1) = mpsc::unbounded();
+    let (tx2, _rx2) = mpsc::unbounded();
 
     let (audio_track, video_track) = get_test_unrequired_tracks();
 
@@ -400,7 +1089,7 @@ async fn normal_exchange_of_candidates() {
         peer::PeerConnection::new(
             &pc2_state,
             tx2,
-            Rc::clone(&manager),
+            manager,
             LocalTracksConstraints::default(),
             Rc::new(Connections::new(Rc::clone(&recv_constraints))),
             recv_constraints,
@@ -416,8 +1105,10 @@ async fn normal_exchange_of_candidates() {
     pc1.state().set_remote_sdp(answer);
     pc1.state().when_remote_sdp_processed().await;
 
-    handle_ice_candidates(rx1, &pc2, 1).await;
-    handle_ice_candidates(rx2, &pc1, 1).await;
+    pc1.add_ice_candidate(String::new(), None, None).await.unwrap();
+    pc2.add_ice_candidate(String::new(), Some(0), Some("0".into()))
+        .await
+        .unwrap();
 }
 
 async fn handle_ice_candidates(
@@ -483,15 +1174,270 @@ async fn send_event_on_new_local_stream() {
     peer.state().when_local_sdp_updated().await.unwrap();
 
     while let Some(event) = rx.next().await {
-        match event {
-            PeerEvent::NewLocalTrack { .. } => {
-                break;
-            }
-            _ => {}
+        match event {
+            PeerEvent::NewLocalTrack { .. } => {
+                break;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Waits until the provided [`MockNavigator`] receives the `count`
+/// [getUserMedia()][1] requests.
+///
+/// [1]: https://w3.org/TR/mediacapture-streams#dom-mediadevices-getusermedia
+async fn wait_get_user_media_requests(mock: &MockNavigator, count: i32) {
+    timeout(1000, async {
+        while mock.get_user_media_requests_count() < count {
+            delay_for(10).await;
+        }
+    })
+    .await
+    .unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn cancel_local_stream_update() {
+    let mock = MockNavigator::new();
+    mock.hold_get_user_media();
+
+    let (tx, mut rx) = mpsc::unbounded();
+    let manager = Rc::new(MediaManager::default());
+    let (audio_track, video_track) = get_test_unrequired_tracks();
+    let send_constraints: LocalTracksConstraints =
+        get_media_stream_settings(true, true).into();
+    let peer_state = peer::State::new(
+        PeerId(1),
+        Vec::new(),
+        false,
+        Some(NegotiationRole::Offerer),
+        ConnectionMode::Mesh,
+    );
+    peer_state.insert_track(&audio_track, send_constraints.clone());
+    peer_state.insert_track(&video_track, send_constraints.clone());
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let peer = peer::Component::new(
+        peer::PeerConnection::new(
+            &peer_state,
+            tx,
+            manager,
+            send_constraints,
+            Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+            recv_constraints,
+        )
+        .await
+        .unwrap(),
+        Rc::new(peer_state),
+    );
+
+    // Cancel the update started by the `peer::Component` itself.
+    wait_get_user_media_requests(&mock, 1).await;
+    peer.cancel_local_stream_update();
+
+    let (res, ()) = future::join(
+        peer.update_local_stream(LocalStreamUpdateCriteria::all()),
+        async {
+            wait_get_user_media_requests(&mock, 2).await;
+            peer.cancel_local_stream_update();
+        },
+    )
+    .await;
+    assert!(matches!(
+        res.unwrap_err().into_inner(),
+        UpdateLocalStreamError::Cancelled,
+    ));
+
+    mock.release_get_user_media();
+    delay_for(100).await;
+    mock.stop();
+
+    assert!(peer.get_send_tracks().is_empty());
+    assert!(
+        peer.get_sender_by_id(audio_track.id)
+            .unwrap()
+            .get_send_track()
+            .is_none()
+    );
+    assert!(
+        peer.get_sender_by_id(video_track.id)
+            .unwrap()
+            .get_send_track()
+            .is_none()
+    );
+    while let Some(event) = rx.next().now_or_never().flatten() {
+        assert!(
+            !matches!(
+                event,
+                PeerEvent::NewLocalTrack { .. }
+                    | PeerEvent::FailedLocalMedia { .. }
+            ),
+            "unexpected event: {event:?}",
+        );
+    }
+}
+
+/// Checks that [`platform::BundlePolicy`] and [`platform::RtcpMuxPolicy`] set
+/// on a [`peer::State`] are applied to the [RTCConfiguration][1] of the created
+/// [`peer::PeerConnection`].
+///
+/// [1]: https://w3.org/TR/webrtc#dom-rtcconfiguration
+#[wasm_bindgen_test]
+async fn peer_policies_are_applied_to_configuration() {
+    let (tx, _rx) = mpsc::unbounded();
+    let send_constraints = LocalTracksConstraints::default();
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let peer_state = peer::State::new(
+        PeerId(1),
+        Vec::new(),
+        false,
+        Some(NegotiationRole::Offerer),
+        ConnectionMode::Mesh,
+    );
+    assert_eq!(peer_state.bundle_policy(), platform::BundlePolicy::MaxBundle);
+    peer_state.set_bundle_policy(platform::BundlePolicy::MaxCompat);
+    peer_state.set_rtcp_mux_policy(platform::RtcpMuxPolicy::Require);
+
+    let peer = peer::PeerConnection::new(
+        &peer_state,
+        tx,
+        Rc::new(MediaManager::default()),
+        send_constraints,
+        Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+        recv_constraints,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(peer.bundle_policy(), platform::BundlePolicy::MaxCompat);
+    assert_eq!(peer.rtcp_mux_policy(), platform::RtcpMuxPolicy::Require);
+}
+
+#[wasm_bindgen_test]
+async fn signaling_state_changed_is_emitted() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let manager = Rc::new(MediaManager::default());
+    let (audio_track, video_track) = get_test_unrequired_tracks();
+    let send_constraints: LocalTracksConstraints =
+        get_media_stream_settings(true, true).into();
+
+    let peer_state = peer::State::new(
+        PeerId(1),
+        Vec::new(),
+        false,
+        Some(NegotiationRole::Offerer),
+        ConnectionMode::Mesh,
+    );
+    peer_state.insert_track(&audio_track, send_constraints.clone());
+    peer_state.insert_track(&video_track, send_constraints.clone());
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let peer = peer::Component::new(
+        peer::PeerConnection::new(
+            &peer_state,
+            tx,
+            manager,
+            send_constraints.clone(),
+            Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+            recv_constraints,
+        )
+        .await
+        .unwrap(),
+        Rc::new(peer_state),
+    );
+    peer.state().when_local_sdp_updated().await.unwrap();
+
+    while let Some(event) = rx.next().await {
+        if let PeerEvent::SignalingStateChanged { signaling_state, .. } = event
+        {
+            assert_eq!(signaling_state, SignalingState::HaveLocalOffer);
+            break;
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+async fn subscribed_events_are_delivered_in_order() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let manager = Rc::new(MediaManager::default());
+    let (audio_track, video_track) = get_test_unrequired_tracks();
+    let send_constraints: LocalTracksConstraints =
+        get_media_stream_settings(true, true).into();
+
+    let peer_state = peer::State::new(
+        PeerId(1),
+        Vec::new(),
+        false,
+        Some(NegotiationRole::Offerer),
+        ConnectionMode::Mesh,
+    );
+    peer_state.insert_track(&audio_track, send_constraints.clone());
+    peer_state.insert_track(&video_track, send_constraints.clone());
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let peer = peer::PeerConnection::new(
+        &peer_state,
+        tx,
+        manager,
+        send_constraints.clone(),
+        Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+        recv_constraints,
+    )
+    .await
+    .unwrap();
+    let mut subscriber = peer.subscribe_events();
+    let peer = peer::Component::new(peer, Rc::new(peer_state));
+    peer.state().when_local_sdp_updated().await.unwrap();
+
+    while let Some(event) = rx.next().await {
+        let subscribed = subscriber.next().await.unwrap();
+        assert_eq!(mem::discriminant(&event), mem::discriminant(&subscribed));
+        if matches!(event, PeerEvent::NewLocalTrack { .. }) {
+            break;
         }
     }
 }
 
+#[wasm_bindgen_test]
+async fn simulated_connection_states_are_emitted() {
+    let (tx, _rx) = mpsc::unbounded();
+    let pc_state = peer::State::new(
+        PeerId(1),
+        Vec::new(),
+        false,
+        None,
+        ConnectionMode::Mesh,
+    );
+    let recv_constraints = Rc::new(RecvConstraints::default());
+    let pc = peer::PeerConnection::new(
+        &pc_state,
+        tx,
+        Rc::new(MediaManager::default()),
+        LocalTracksConstraints::default(),
+        Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+        recv_constraints,
+    )
+    .await
+    .unwrap();
+    let mut events = pc.subscribe_events();
+
+    pc.simulate_ice_connection_state(IceConnectionState::Failed);
+    pc.simulate_connection_state(PeerConnectionState::Failed);
+
+    assert!(matches!(
+        events.next().await.unwrap(),
+        PeerEvent::IceConnectionStateChanged {
+            ice_connection_state: IceConnectionState::Failed,
+            ..
+        },
+    ));
+    assert!(matches!(
+        events.next().await.unwrap(),
+        PeerEvent::PeerConnectionStateChanged {
+            peer_connection_state: PeerConnectionState::Failed,
+            ..
+        },
+    ));
+}
+
 /// Setup signalling between two peers and wait for:
 /// 1. `IceConnectionState::Checking` from both peers.
 /// 2. `IceConnectionState::Connected` from both peers.
@@ -923,6 +1869,478 @@ async fn get_traffic_stats() {
     assert_eq!(second_peer_succeeded_pairs_count, 1);
 }
 
+/// Checks that [`PeerConnection::transceiver_directions()`] reports the
+/// negotiated directions of all the tracks.
+///
+/// [`PeerConnection::transceiver_directions()`]: peer::PeerConnection::transceiver_directions
+#[wasm_bindgen_test]
+async fn transceiver_directions_are_negotiated() {
+    use medea_jason::platform::TransceiverDirection;
+
+    let peers = InterconnectedPeers::new().await;
+
+    let first_directions = peers.first_peer.transceiver_directions();
+    assert_eq!(first_directions.len(), 2);
+    for track_id in [AUDIO_TRACK_ID, VIDEO_TRACK_ID] {
+        assert_eq!(
+            first_directions[&track_id],
+            Some(TransceiverDirection::SEND),
+        );
+    }
+
+    let second_directions = peers.second_peer.transceiver_directions();
+    assert_eq!(second_directions.len(), 2);
+    for direction in second_directions.into_values() {
+        assert_eq!(direction, Some(TransceiverDirection::RECV));
+    }
+}
+
+/// Checks that [`Sender::first_media_sent()`] resolves once scraped outbound
+/// RTP stats report sent packets.
+///
+/// [`Sender::first_media_sent()`]: medea_jason::peer::media::Sender::first_media_sent
+#[wasm_bindgen_test]
+async fn first_media_sent_resolves_on_sent_packets() {
+    let peers = InterconnectedPeers::new().await;
+    let sender = peers.first_peer.get_sender_by_id(VIDEO_TRACK_ID).unwrap();
+    assert!(!sender.is_media_sent());
+
+    let first_media_sent = sender.first_media_sent();
+    let scrape = async {
+        loop {
+            peers.first_peer.scrape_and_send_peer_stats().await;
+            delay_for(100).await;
+        }
+    };
+    timeout(5000, future::select(Box::pin(first_media_sent), Box::pin(scrape)))
+        .await
+        .unwrap();
+
+    assert!(sender.is_media_sent());
+    timeout(100, sender.first_media_sent()).await.unwrap();
+}
+
+/// Checks that [`PeerConnection::insert_dtmf()`] queues DTMF tones on a
+/// negotiated audio [`Sender`].
+///
+/// [`PeerConnection::insert_dtmf()`]: peer::PeerConnection::insert_dtmf
+/// [`Sender`]: medea_jason::peer::media::Sender
+#[wasm_bindgen_test]
+async fn insert_dtmf_queues_tones() {
+    let peers = InterconnectedPeers::new().await;
+    let sender = peers.first_peer.get_sender_by_id(AUDIO_TRACK_ID).unwrap();
+    assert!(!sender.is_playing_dtmf());
+
+    peers
+        .first_peer
+        .insert_dtmf(
+            AUDIO_TRACK_ID,
+            "1234#",
+            Duration::from_millis(100),
+            Duration::from_millis(70),
+        )
+        .unwrap();
+
+    assert!(sender.is_playing_dtmf());
+}
+
+/// Checks that [`PeerConnection::insert_dtmf()`] errors for unknown and
+/// video [`Sender`]s.
+///
+/// [`PeerConnection::insert_dtmf()`]: peer::PeerConnection::insert_dtmf
+/// [`Sender`]: medea_jason::peer::media::Sender
+#[wasm_bindgen_test]
+async fn insert_dtmf_errors() {
+    let peers = InterconnectedPeers::new().await;
+    let insert = |track_id| {
+        peers
+            .first_peer
+            .insert_dtmf(
+                track_id,
+                "1",
+                Duration::from_millis(100),
+                Duration::from_millis(70),
+            )
+            .unwrap_err()
+            .into_inner()
+    };
+
+    assert!(matches!(
+        insert(TrackId(100)),
+        InsertDtmfError::NoSuchSender(TrackId(100)),
+    ));
+    assert!(matches!(insert(VIDEO_TRACK_ID), InsertDtmfError::NotAudioSender,));
+}
+
+/// Returns the underlying [`web_sys::MediaStreamTrack`] currently sent by the
+/// provided [`Sender`].
+///
+/// [`Sender`]: medea_jason::peer::media::Sender
+fn sys_send_track(sender: &peer::media::Sender) -> web_sys::MediaStreamTrack {
+    let track = sender.get_send_track().unwrap();
+    let track: &platform::MediaStreamTrack = (*track).as_ref();
+    let sys_track: &web_sys::MediaStreamTrack = track.as_ref();
+    Clone::clone(sys_track)
+}
+
+/// Indicates whether a [`PeerEvent::RenegotiationNeeded`] has been emitted to
+/// the provided [`PeerEvent`]s so far.
+async fn renegotiation_needed(
+    events: &mut Pin<Box<dyn Stream<Item = PeerEvent>>>,
+) -> bool {
+    delay_for(100).await;
+
+    let mut needed = false;
+    while let Some(Some(event)) = events.next().now_or_never() {
+        needed |= matches!(event, PeerEvent::RenegotiationNeeded { .. });
+    }
+    needed
+}
+
+/// Checks that [`PeerConnection::switch_video_device()`] replaces the sent
+/// video track via `replaceTrack()` without renegotiation, and stops the
+/// previous one.
+///
+/// [`PeerConnection::switch_video_device()`]: peer::PeerConnection::switch_video_device
+#[wasm_bindgen_test]
+async fn switch_video_device_replaces_track() {
+    let mut peers = InterconnectedPeers::new().await;
+    let sender = peers.first_peer.get_sender_by_id(VIDEO_TRACK_ID).unwrap();
+    let prev_track = sys_send_track(&sender);
+    let device_id =
+        sender.get_send_track().unwrap().settings().device_id.unwrap();
+    drop(renegotiation_needed(&mut peers.peer_events_recv).await);
+
+    peers
+        .first_peer
+        .switch_video_device(VIDEO_TRACK_ID, device_id)
+        .await
+        .unwrap();
+
+    let new_track = sys_send_track(&sender);
+    assert_ne!(new_track.id(), prev_track.id());
+    assert_eq!(
+        sender.transceiver().handle().sender().track().unwrap().id(),
+        new_track.id(),
+    );
+    assert!(!renegotiation_needed(&mut peers.peer_events_recv).await);
+    assert_eq!(prev_track.ready_state(), MediaStreamTrackState::Ended);
+    assert_eq!(new_track.ready_state(), MediaStreamTrackState::Live);
+}
+
+/// Checks that [`PeerConnection::switch_video_device()`] falls back to a
+/// renegotiation if the new video track cannot be inserted via
+/// `replaceTrack()`, and stops the previous one.
+///
+/// [`PeerConnection::switch_video_device()`]: peer::PeerConnection::switch_video_device
+#[wasm_bindgen_test]
+async fn switch_video_device_falls_back_to_renegotiation() {
+    let mut peers = InterconnectedPeers::new().await;
+    let sender = peers.first_peer.get_sender_by_id(VIDEO_TRACK_ID).unwrap();
+    let prev_track = sys_send_track(&sender);
+    let device_id =
+        sender.get_send_track().unwrap().settings().device_id.unwrap();
+    drop(renegotiation_needed(&mut peers.peer_events_recv).await);
+
+    // `replaceTrack()` rejects on a stopped transceiver.
+    sender.transceiver().handle().stop();
+    peers
+        .first_peer
+        .switch_video_device(VIDEO_TRACK_ID, device_id)
+        .await
+        .unwrap();
+
+    assert!(sender.get_send_track().is_none());
+    assert!(renegotiation_needed(&mut peers.peer_events_recv).await);
+    assert_eq!(prev_track.ready_state(), MediaStreamTrackState::Ended);
+}
+
+/// Checks that [`PeerConnection::switch_audio_device()`] inserts a disabled
+/// track into a muted audio [`Sender`], and that unmuting enables it.
+///
+/// [`PeerConnection::switch_audio_device()`]: peer::PeerConnection::switch_audio_device
+/// [`Sender`]: medea_jason::peer::media::Sender
+#[wasm_bindgen_test]
+async fn switch_audio_device_preserves_mute_state() {
+    let mut peers = InterconnectedPeers::new().await;
+    let sender = peers.first_peer.get_sender_by_id(AUDIO_TRACK_ID).unwrap();
+    let device_id =
+        sender.get_send_track().unwrap().settings().device_id.unwrap();
+    let set_muted = |muted| TrackPatchEvent {
+        id: AUDIO_TRACK_ID,
+        receivers: None,
+        media_direction: None,
+        muted: Some(muted),
+        encoding_parameters: None,
+        paused: None,
+    };
+
+    peers.first_peer.state().patch_track(set_muted(true)).await;
+    peers.first_peer.state().when_updated().await;
+    assert!(sender.muted());
+    let prev_track = sys_send_track(&sender);
+    assert!(!prev_track.enabled());
+    drop(renegotiation_needed(&mut peers.peer_events_recv).await);
+
+    peers
+        .first_peer
+        .switch_audio_device(AUDIO_TRACK_ID, device_id)
+        .await
+        .unwrap();
+
+    let new_track = sys_send_track(&sender);
+    assert_ne!(new_track.id(), prev_track.id());
+    assert!(!new_track.enabled());
+    assert!(sender.muted());
+    assert!(!renegotiation_needed(&mut peers.peer_events_recv).await);
+    assert_eq!(prev_track.ready_state(), MediaStreamTrackState::Ended);
+
+    peers.first_peer.state().patch_track(set_muted(false)).await;
+    peers.first_peer.state().when_updated().await;
+    assert!(!sender.muted());
+    assert!(sys_send_track(&sender).enabled());
+}
+
+/// Checks that [`PeerConnection::switch_video_device()`] errors for unknown
+/// and audio [`Sender`]s.
+///
+/// [`PeerConnection::switch_video_device()`]: peer::PeerConnection::switch_video_device
+/// [`Sender`]: medea_jason::peer::media::Sender
+#[wasm_bindgen_test]
+async fn switch_video_device_errors_on_wrong_sender() {
+    let peers = InterconnectedPeers::new().await;
+
+    let err = peers
+        .first_peer
+        .switch_video_device(TrackId(100), "device".into())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.into_inner(),
+        SwitchDeviceError::NoSuchSender(TrackId(100)),
+    ));
+
+    let err = peers
+        .first_peer
+        .switch_video_device(AUDIO_TRACK_ID, "device".into())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.into_inner(),
+        SwitchDeviceError::WrongSenderKind(AUDIO_TRACK_ID),
+    ));
+}
+
+/// Checks that codec preferences set on a [`peer::State`] are applied only to
+/// the [`Sender`]s of the provided [`MediaKind`], keeping their order.
+///
+/// [`Sender`]: peer::sender::Sender
+#[wasm_bindgen_test]
+async fn codec_preferences_are_applied_per_kind() {
+    let peers = InterconnectedPeers::new().await;
+    let preferred: Vec<_> = ["video/VP9", "video/VP8"]
+        .into_iter()
+        .map(|mime_type| Codec {
+            mime_type: mime_type.to_owned(),
+            clock_rate: 90000,
+            channels: None,
+            parameters: HashMap::new(),
+        })
+        .collect();
+    let video = peers.first_peer.get_sender_by_id(VIDEO_TRACK_ID).unwrap();
+    let audio = peers.first_peer.get_sender_by_id(AUDIO_TRACK_ID).unwrap();
+
+    peers
+        .first_peer
+        .state()
+        .set_codec_preferences(MediaKind::Video, preferred.clone());
+    timeout(1000, async {
+        while video.preferred_codecs() != preferred {
+            delay_for(10).await;
+        }
+    })
+    .await
+    .unwrap();
+    assert!(audio.preferred_codecs().is_empty());
+
+    let mut reversed = preferred.clone();
+    reversed.reverse();
+    peers
+        .first_peer
+        .state()
+        .set_codec_preferences(MediaKind::Video, reversed.clone());
+    timeout(1000, async {
+        while video.preferred_codecs() != reversed {
+            delay_for(10).await;
+        }
+    })
+    .await
+    .unwrap();
+
+    peers.first_peer.state().set_codec_preferences(MediaKind::Video, vec![]);
+    timeout(1000, async {
+        while !video.preferred_codecs().is_empty() {
+            delay_for(10).await;
+        }
+    })
+    .await
+    .unwrap();
+}
+
+/// Checks that [`PeerConnection::probe_bandwidth()`] requests a renegotiation
+/// for its temporary `DataChannel` and actually sends bytes via it once it's
+/// opened.
+#[wasm_bindgen_test]
+async fn bandwidth_probe_sends_bytes() {
+    let InterconnectedPeers { first_peer, second_peer, mut peer_events_recv } =
+        InterconnectedPeers::new().await;
+
+    let negotiate = async {
+        while let Some(event) = peer_events_recv.next().await {
+            match event {
+                PeerEvent::RenegotiationNeeded { peer_id } => {
+                    assert_eq!(peer_id, PeerId(1));
+                    first_peer
+                        .state()
+                        .set_negotiation_role(NegotiationRole::Offerer)
+                        .await;
+                    let offer =
+                        first_peer.state().when_local_sdp_updated().await;
+                    second_peer
+                        .state()
+                        .set_negotiation_role(NegotiationRole::Answerer(
+                            offer.unwrap(),
+                        ))
+                        .await;
+                    let answer =
+                        second_peer.state().when_local_sdp_updated().await;
+                    first_peer.state().set_remote_sdp(answer.unwrap());
+                    first_peer.state().when_remote_sdp_processed().await;
+                }
+                PeerEvent::IceCandidateDiscovered {
+                    peer_id,
+                    candidate,
+                    sdp_m_line_index,
+                    sdp_mid,
+                } => {
+                    let peer =
+                        if peer_id.0 == 1 { &second_peer } else { &first_peer };
+                    peer.add_ice_candidate(
+                        candidate,
+                        sdp_m_line_index,
+                        sdp_mid,
+                    )
+                    .await
+                    .unwrap();
+                }
+                _ => (),
+            }
+        }
+    };
+    let probe = first_peer.probe_bandwidth(Duration::from_millis(500));
+    let probed =
+        timeout(10000, future::select(Box::pin(probe), Box::pin(negotiate)))
+            .await
+            .unwrap();
+    assert!(matches!(probed, future::Either::Left(_)));
+
+    let stats = first_peer.get_stats().await.unwrap();
+    let bytes_sent: u64 = stats
+        .0
+        .iter()
+        .filter_map(|s| match &s.stats {
+            RtcStatsType::DataChannel(channel) => channel.bytes_sent,
+            _ => None,
+        })
+        .sum();
+    assert!(bytes_sent > 0);
+}
+
+/// Checks that a [`DataChannel`] created by the remote peer is stored and
+/// reported via [`PeerConnection::on_data_channel()`], that messages are
+/// exchanged via it, and that it's removed once closed.
+///
+/// [`DataChannel`]: medea_jason::peer::DataChannel
+#[wasm_bindgen_test]
+async fn remote_data_channel_is_exposed() {
+    let InterconnectedPeers { first_peer, second_peer, mut peer_events_recv } =
+        InterconnectedPeers::new().await;
+    let mut remote_channels = second_peer.on_data_channel();
+
+    let local = first_peer
+        .create_data_channel("chat", DataChannelInit::default())
+        .unwrap();
+
+    let negotiate = async {
+        while let Some(event) = peer_events_recv.next().await {
+            match event {
+                PeerEvent::RenegotiationNeeded { peer_id } => {
+                    assert_eq!(peer_id, PeerId(1));
+                    first_peer
+                        .state()
+                        .set_negotiation_role(NegotiationRole::Offerer)
+                        .await;
+                    let offer =
+                        first_peer.state().when_local_sdp_updated().await;
+                    second_peer
+                        .state()
+                        .set_negotiation_role(NegotiationRole::Answerer(
+                            offer.unwrap(),
+                        ))
+                        .await;
+                    let answer =
+                        second_peer.state().when_local_sdp_updated().await;
+                    first_peer.state().set_remote_sdp(answer.unwrap());
+                    first_peer.state().when_remote_sdp_processed().await;
+                }
+                PeerEvent::IceCandidateDiscovered {
+                    peer_id,
+                    candidate,
+                    sdp_m_line_index,
+                    sdp_mid,
+                } => {
+                    let peer =
+                        if peer_id.0 == 1 { &second_peer } else { &first_peer };
+                    peer.add_ice_candidate(
+                        candidate,
+                        sdp_m_line_index,
+                        sdp_mid,
+                    )
+                    .await
+                    .unwrap();
+                }
+                _ => (),
+            }
+        }
+    };
+    let exchange = async {
+        let remote = remote_channels.next().await.unwrap();
+        assert_eq!(remote.label(), "chat");
+        assert_eq!(second_peer.remote_data_channels().len(), 1);
+
+        let mut messages = remote.on_message();
+        local.when_open().await;
+        local.send(&[1, 2, 3]).unwrap();
+        let message = messages.next().await.unwrap();
+
+        local.close();
+        remote.when_closed().await;
+        delay_for(100).await;
+        assert!(second_peer.remote_data_channels().is_empty());
+
+        message
+    };
+    let exchanged =
+        timeout(10000, future::select(Box::pin(exchange), Box::pin(negotiate)))
+            .await
+            .unwrap();
+
+    let future::Either::Left((message, _)) = exchanged else {
+        panic!("negotiation stopped before a message is exchanged");
+    };
+    assert_eq!(message, vec![1, 2, 3]);
+}
+
 /// Tests for a [`RtcStat`]s caching mechanism of the [`PeerConnection`].
 mod peer_stats_caching {
     use super::*;
@@ -1105,6 +2523,116 @@ mod peer_stats_caching {
         let first_rtc_stats = peer_events_stream.next().await.unwrap();
         assert_eq!(first_rtc_stats.0[0], stat);
     }
+
+    /// Tests that [`PeerConnection::send_peer_stats`] sends nothing while
+    /// stats sending is disabled, and resumes once it's enabled back.
+    #[wasm_bindgen_test]
+    async fn respects_disabled_sending() {
+        let (tx, peer_events_stream) = mpsc::unbounded();
+        let manager = Rc::new(MediaManager::default());
+        let peer_state = peer::State::new(
+            PeerId(1),
+            Vec::new(),
+            false,
+            None,
+            ConnectionMode::Mesh,
+        );
+        let recv_constraints = Rc::new(RecvConstraints::default());
+        let peer = peer::Component::new(
+            peer::PeerConnection::new(
+                &peer_state,
+                tx,
+                manager,
+                LocalTracksConstraints::default(),
+                Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+                recv_constraints,
+            )
+            .await
+            .unwrap(),
+            Rc::new(peer_state),
+        );
+        peer.state().set_stats_sending_enabled(false);
+        delay_for(10).await;
+
+        let stat = RtcStat {
+            id: StatId("2ef2e34c".to_string()),
+            timestamp: HighResTimeStamp(1584373509700.0),
+            stats: RtcStatsType::Track(Box::new(TrackStats {
+                track_identifier: "0d4f8e05-51d8-4f9b-90b2-453401fc8041"
+                    .to_string(),
+                kind: Some(TrackStatsKind::Audio),
+                remote_source: None,
+                ended: Some(false),
+            })),
+        };
+        peer.send_peer_stats(RtcStats(vec![stat.clone()]));
+
+        let mut peer_events_stream = peer_events_stream.filter_map(|event| {
+            Box::pin(async move {
+                if let PeerEvent::StatsUpdate { peer_id: _, stats } = event {
+                    Some(stats)
+                } else {
+                    None
+                }
+            })
+        });
+        timeout(100, peer_events_stream.next()).await.unwrap_err();
+
+        peer.state().set_stats_sending_enabled(true);
+        delay_for(10).await;
+        peer.send_peer_stats(RtcStats(vec![stat.clone()]));
+        let rtc_stats = peer_events_stream.next().await.unwrap();
+        assert_eq!(rtc_stats.0[0], stat);
+    }
+
+    /// Tests that [`PeerConnection::scrape_and_send_peer_stats`] keeps
+    /// emitting [`PeerEvent::StatsScraped`]s for local consumers (like inbound
+    /// media quality of `Connection`s) while stats sending is disabled.
+    #[wasm_bindgen_test]
+    async fn scraped_stats_are_emitted_while_sending_disabled() {
+        let (tx, mut peer_events_stream) = mpsc::unbounded();
+        let manager = Rc::new(MediaManager::default());
+        let peer_state = peer::State::new(
+            PeerId(1),
+            Vec::new(),
+            false,
+            None,
+            ConnectionMode::Mesh,
+        );
+        let recv_constraints = Rc::new(RecvConstraints::default());
+        let peer = peer::Component::new(
+            peer::PeerConnection::new(
+                &peer_state,
+                tx,
+                manager,
+                LocalTracksConstraints::default(),
+                Rc::new(Connections::new(Rc::clone(&recv_constraints))),
+                recv_constraints,
+            )
+            .await
+            .unwrap(),
+            Rc::new(peer_state),
+        );
+        peer.state().set_stats_sending_enabled(false);
+        delay_for(10).await;
+
+        peer.scrape_and_send_peer_stats().await;
+
+        let mut is_scraped = false;
+        while let Ok(Some(event)) = peer_events_stream.try_next() {
+            match event {
+                PeerEvent::StatsScraped { peer_id, .. } => {
+                    assert_eq!(peer_id, PeerId(1));
+                    is_scraped = true;
+                }
+                PeerEvent::StatsUpdate { .. } => {
+                    panic!("stats are sent while sending is disabled");
+                }
+                _ => {}
+            }
+        }
+        assert!(is_scraped);
+    }
 }
 
 #[wasm_bindgen_test]
@@ -1542,6 +3070,7 @@ async fn disable_and_enable_all_tracks() {
             media_direction: Some(MediaDirection::RecvOnly),
             muted: None,
             encoding_parameters: None,
+            paused: None,
         })
         .await;
     pc.state().when_updated().await;
@@ -1556,6 +3085,7 @@ async fn disable_and_enable_all_tracks() {
             media_direction: Some(MediaDirection::RecvOnly),
             muted: None,
             encoding_parameters: None,
+            paused: None,
         })
         .await;
     pc.state().when_updated().await;
@@ -1570,6 +3100,7 @@ async fn disable_and_enable_all_tracks() {
             media_direction: Some(MediaDirection::SendRecv),
             muted: None,
             encoding_parameters: None,
+            paused: None,
         })
         .await;
     pc.state().when_updated().await;
@@ -1584,9 +3115,40 @@ async fn disable_and_enable_all_tracks() {
             media_direction: Some(MediaDirection::SendRecv),
             muted: None,
             encoding_parameters: None,
+            paused: None,
         })
         .await;
     pc.state().when_updated().await;
     assert!(!audio_track.general_disabled());
     assert!(!video_track.general_disabled());
 }
+
+/// Checks that a [`PeerConnectionState::Disconnected`] is reported only if a
+/// peer doesn't recover within its disconnect grace period.
+#[wasm_bindgen_test]
+async fn disconnect_is_reported_after_grace_period() {
+    let grace = peer::DisconnectGrace::default();
+    grace.set_period(Duration::from_millis(300));
+    let (tx, mut rx) = mpsc::unbounded();
+    let report = |state| {
+        let tx = tx.clone();
+        grace.report(state, move |s| tx.unbounded_send(s).unwrap());
+    };
+
+    report(PeerConnectionState::Connected);
+    assert_eq!(rx.next().await, Some(PeerConnectionState::Connected));
+
+    // Brief blip isn't reported.
+    report(PeerConnectionState::Disconnected);
+    delay_for(100).await;
+    report(PeerConnectionState::Connected);
+    timeout(500, rx.next()).await.unwrap_err();
+
+    // Long disconnect is reported once the grace period elapses.
+    report(PeerConnectionState::Disconnected);
+    timeout(100, rx.next()).await.unwrap_err();
+    assert_eq!(
+        timeout(500, rx.next()).await.unwrap(),
+        Some(PeerConnectionState::Disconnected),
+    );
+}
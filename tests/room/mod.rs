@@ -14,6 +14,7 @@ use medea_jason::{
     api,
     jason::Jason,
     platform::{MockRpcTransport, RpcTransport, TransportState},
+    room::{RoomHandle, RoomLifecycleEvent},
     rpc::{CloseMsg, WebSocketRpcClient},
 };
 use medea_reactive::ObservableCell;
@@ -242,6 +243,91 @@ async fn room_dispose_works() {
     timeout(100, test_rx.next()).await.unwrap();
 }
 
+/// Checks that [`RoomHandle::state()`] reports the [`RoomState`] of a [`Room`]
+/// through its whole lifecycle.
+///
+/// [`RoomState`]: api::RoomState
+#[wasm_bindgen_test]
+async fn room_state_is_reported() {
+    let ws = Rc::new(WebSocketRpcClient::new(Box::new(move || {
+        let mut transport = MockRpcTransport::new();
+        transport
+            .expect_connect()
+            .return_once(|_| Box::pin(futures::future::ok(())));
+        transport.expect_on_message().returning_st({
+            move || {
+                Box::pin(stream::iter(vec![
+                    RPC_SETTINGS,
+                    ServerMsg::Event {
+                        room_id: "room_id".into(),
+                        event: Event::RoomJoined {
+                            member_id: "member_id".into(),
+                        },
+                    },
+                ]))
+            }
+        });
+        transport.expect_send().returning(|_| Ok(()));
+        transport.expect_set_close_reason().return_once(drop);
+        transport.expect_on_state_change().return_once_st(move || {
+            Box::pin(stream::once(async { TransportState::Open }))
+        });
+        let transport = Rc::new(transport);
+        transport as Rc<dyn RpcTransport>
+    })));
+    let jason = api::Jason::from(Jason::new(Some(ws)));
+
+    let room = jason.init_room();
+    room.on_failed_local_media(Closure::once_into_js(|| {}).into()).unwrap();
+    room.on_connection_loss(Closure::once_into_js(|| {}).into()).unwrap();
+    assert_eq!(room.state(), api::RoomState::New);
+
+    JsFuture::from(room.join(TEST_ROOM_URL.to_string())).await.unwrap();
+    assert_eq!(room.state(), api::RoomState::Connected);
+
+    let room_clone = room.clone_handle();
+    jason.close_room(room);
+    assert_eq!(room_clone.state(), api::RoomState::Closed);
+
+    jason.dispose();
+}
+
+/// Checks that closing a [`Room`] via one of its [`RoomHandle`]s detaches all
+/// the others.
+#[wasm_bindgen_test]
+async fn closing_room_detaches_its_clones() {
+    let jason = api::Jason::new();
+    let room = jason.init_room();
+    let room_clone = room.clone_handle();
+    room_clone.on_connection_loss(Closure::once_into_js(|| {}).into()).unwrap();
+
+    jason.close_room(room);
+
+    assert!(
+        room_clone
+            .on_connection_loss(Closure::once_into_js(|| {}).into())
+            .is_err()
+    );
+    assert_eq!(room_clone.state(), api::RoomState::Closed);
+    // Closing an already closed `Room` is a no-op.
+    jason.close_room(room_clone);
+
+    jason.dispose();
+}
+
+/// Checks that [`RoomHandle::leave`] doesn't wait for an acknowledgement if
+/// the [`Room`] isn't joined.
+#[wasm_bindgen_test]
+async fn leave_returns_immediately_if_not_joined() {
+    let jason = api::Jason::new();
+    let room = jason.init_room();
+
+    timeout(100, JsFuture::from(room.leave(10_000))).await.unwrap().unwrap();
+    assert_eq!(room.state(), api::RoomState::Closed);
+
+    jason.dispose();
+}
+
 /// Tests that [`Room`] will trigger [`RoomHandle::on_close`] callback on
 /// [`RpcTransport`] close.
 #[wasm_bindgen_test]
@@ -298,3 +384,59 @@ async fn room_closes_on_rpc_transport_close() {
 
     timeout(300, test_rx).await.unwrap().unwrap();
 }
+
+/// Tests that [`RoomHandle::on_lifecycle`] stream reports joining of a [`Room`]
+/// and its closing on [`RpcTransport`] close.
+#[wasm_bindgen_test]
+async fn on_lifecycle_reports_join_and_close() {
+    let on_state_change_mock =
+        Rc::new(ObservableCell::new(TransportState::Open));
+    let ws = Rc::new(WebSocketRpcClient::new(Box::new({
+        let on_state_change_mock = on_state_change_mock.clone();
+        move || {
+            let on_state_change_mock = on_state_change_mock.clone();
+            let mut transport = MockRpcTransport::new();
+            transport
+                .expect_connect()
+                .return_once(|_| Box::pin(futures::future::ok(())));
+            transport.expect_on_message().times(3).returning_st({
+                move || {
+                    Box::pin(stream::iter(vec![
+                        RPC_SETTINGS,
+                        ServerMsg::Event {
+                            room_id: "room_id".into(),
+                            event: Event::RoomJoined {
+                                member_id: "member_id".into(),
+                            },
+                        },
+                    ]))
+                }
+            });
+            transport.expect_send().return_once(|_| Ok(()));
+            transport.expect_set_close_reason().return_once(drop);
+            transport
+                .expect_on_state_change()
+                .return_once_st(move || on_state_change_mock.subscribe());
+            let transport = Rc::new(transport);
+            transport as Rc<dyn RpcTransport>
+        }
+    })));
+    let jason = api::Jason::from(Jason::new(Some(ws)));
+
+    let room = jason.init_room();
+    room.on_failed_local_media(Closure::once_into_js(|| {}).into()).unwrap();
+    room.on_connection_loss(Closure::once_into_js(|| {}).into()).unwrap();
+    let events = RoomHandle::from(room.clone()).on_lifecycle().unwrap();
+
+    JsFuture::from(room.join(TEST_ROOM_URL.to_string())).await.unwrap();
+    on_state_change_mock.set(TransportState::Closed(CloseMsg::Normal(
+        1200,
+        CloseReason::Finished,
+    )));
+
+    let events =
+        timeout(300, events.take(3).collect::<Vec<_>>()).await.unwrap();
+    assert_eq!(events[0], RoomLifecycleEvent::Joining);
+    assert_eq!(events[1], RoomLifecycleEvent::Joined);
+    assert!(matches!(events[2], RoomLifecycleEvent::Closed(_)));
+}
@@ -579,6 +579,7 @@ mod receivers_patch_send_tracks {
                     receivers: Some(Vec::new()),
                     muted: None,
                     encoding_parameters: None,
+                    paused: None,
                 })]),
                 negotiation_role: None,
             })
@@ -600,6 +601,7 @@ mod receivers_patch_send_tracks {
                     ])),
                     muted: None,
                     encoding_parameters: None,
+                    paused: None,
                 })]),
                 negotiation_role: None,
             })
@@ -1038,7 +1040,8 @@ mod disable_send_tracks {
                     TrackPatchCommand {
                         id: TrackId(1),
                         enabled: Some(false),
-                        muted: None
+                        muted: None,
+                        label: None,
                     }
                 );
             }
@@ -1053,6 +1056,7 @@ mod disable_send_tracks {
                     media_direction: Some(MediaDirection::RecvOnly),
                     muted: None,
                     encoding_parameters: None,
+                    paused: None,
                 })],
                 negotiation_role: None,
             })
@@ -1117,7 +1121,8 @@ mod disable_send_tracks {
                     TrackPatchCommand {
                         id: TrackId(1),
                         enabled: None,
-                        muted: Some(true)
+                        muted: Some(true),
+                        label: None,
                     }
                 );
             }
@@ -1132,6 +1137,7 @@ mod disable_send_tracks {
                     media_direction: None,
                     muted: Some(true),
                     encoding_parameters: None,
+                    paused: None,
                 })],
                 negotiation_role: None,
             })
@@ -1197,7 +1203,8 @@ mod disable_send_tracks {
                     TrackPatchCommand {
                         id: TrackId(2),
                         enabled: Some(false),
-                        muted: None
+                        muted: None,
+                        label: None,
                     }
                 );
             }
@@ -1212,6 +1219,7 @@ mod disable_send_tracks {
                     media_direction: Some(MediaDirection::RecvOnly),
                     muted: None,
                     encoding_parameters: None,
+                    paused: None,
                 })],
                 negotiation_role: None,
             })
@@ -1548,6 +1556,7 @@ mod patches_generation {
                                 MediaState::Mute(mute_state::Stable::Muted)
                             )),
                             encoding_parameters: None,
+                            paused: None,
                         })],
                         negotiation_role: None,
                     })
@@ -1604,6 +1613,7 @@ mod patches_generation {
                     id: TrackId(0),
                     enabled: Some(false),
                     muted: None,
+                    label: None,
                 }]
             }
         );
@@ -1653,6 +1663,7 @@ mod patches_generation {
                 id: TrackId(0),
                 enabled: Some(false),
                 muted: None,
+                label: None,
             }]
         );
 
@@ -1662,6 +1673,7 @@ mod patches_generation {
                 id: TrackId(0),
                 enabled: Some(false),
                 muted: None,
+                label: None,
             }]
         );
     }
@@ -1740,6 +1752,7 @@ mod patches_generation {
                         id: TrackId(1),
                         enabled: Some(false),
                         muted: None,
+                        label: None,
                     }]
                 ),
                 _ => {
@@ -1824,6 +1837,7 @@ mod patches_generation {
                         id: TrackId(2),
                         enabled: Some(false),
                         muted: None,
+                        label: None,
                     }]
                 ),
                 _ => {
@@ -1856,6 +1870,7 @@ mod patches_generation {
                     id: TrackId(0),
                     enabled: None,
                     muted: Some(true),
+                    label: None,
                 }]
             }
         );
@@ -1884,6 +1899,7 @@ mod patches_generation {
                     id: TrackId(0),
                     enabled: None,
                     muted: Some(false),
+                    label: None,
                 }]
             }
         );
@@ -1966,6 +1982,7 @@ async fn disable_by_server() {
                 media_direction: Some(MediaDirection::RecvOnly),
                 muted: None,
                 encoding_parameters: None,
+                paused: None,
             })],
         })
         .unwrap();
@@ -1998,6 +2015,7 @@ async fn enable_by_server() {
                 media_direction: Some(MediaDirection::RecvOnly),
                 muted: None,
                 encoding_parameters: None,
+                paused: None,
             })],
         })
         .unwrap();
@@ -2018,6 +2036,7 @@ async fn enable_by_server() {
                 media_direction: Some(MediaDirection::SendRecv),
                 muted: None,
                 encoding_parameters: None,
+                paused: None,
             })],
         })
         .unwrap();
@@ -2055,6 +2074,7 @@ async fn only_one_gum_performed_on_enable() {
                 media_direction: Some(MediaDirection::RecvOnly),
                 muted: None,
                 encoding_parameters: None,
+                paused: None,
             })],
         })
         .unwrap();
@@ -2075,6 +2095,7 @@ async fn only_one_gum_performed_on_enable() {
                 media_direction: Some(MediaDirection::RecvOnly),
                 muted: None,
                 encoding_parameters: None,
+                paused: None,
             })],
         })
         .unwrap();
@@ -2124,7 +2145,8 @@ async fn no_updates_sent_if_gum_fails_on_enable() {
             tracks_patches: vec![TrackPatchCommand {
                 id: TrackId(1),
                 enabled: Some(false),
-                muted: None
+                muted: None,
+                label: None,
             }]
         }
     );
@@ -2190,6 +2212,7 @@ async fn only_one_gum_performed_on_enable_by_server() {
                 media_direction: Some(MediaDirection::RecvOnly),
                 muted: None,
                 encoding_parameters: None,
+                paused: None,
             })],
         })
         .unwrap();
@@ -2209,6 +2232,7 @@ async fn only_one_gum_performed_on_enable_by_server() {
                 media_direction: Some(MediaDirection::RecvOnly),
                 muted: None,
                 encoding_parameters: None,
+                paused: None,
             })],
         })
         .unwrap();
@@ -2270,6 +2294,7 @@ async fn send_enabling_holds_local_tracks() {
                 media_direction: Some(MediaDirection::RecvOnly),
                 muted: None,
                 encoding_parameters: None,
+                paused: None,
             })],
         })
         .unwrap();
@@ -2756,6 +2781,44 @@ mod set_local_media_settings {
         assert_eq!(peer1.get_send_tracks().len(), 1);
     }
 
+    /// Checks that [`RoomHandle::on_local_media_fallback`] callback is invoked
+    /// when [`RoomHandle::set_local_media_settings`] rolls back to the previous
+    /// settings.
+    #[wasm_bindgen_test]
+    async fn calls_on_local_media_fallback_on_rollback() {
+        let (room, _peer1, _peer2) = room_with_connected_peers().await;
+        let room_handle = api::RoomHandle::from(room.new_handle());
+        let (cb, fallback_result) = js_callback!(|fallback: JsValue| {
+            cb_assert_eq!(fallback.is_object(), true);
+        });
+        room_handle.on_local_media_fallback(cb.into()).unwrap();
+
+        JsFuture::from(room_handle.set_local_media_settings(
+            &media_stream_settings(true, true),
+            false,
+            false,
+        ))
+        .await
+        .unwrap();
+
+        let mock_navigator = MockNavigator::new();
+        let err = jsval_cast::<MediaSettingsUpdateException>(
+            JsFuture::from(room_handle.set_local_media_settings(
+                &media_settings_with_device_id(),
+                true,
+                true,
+            ))
+            .await
+            .unwrap_err(),
+            "MediaSettingsUpdateException",
+        )
+        .unwrap();
+        mock_navigator.stop();
+        assert_eq!(err.rolled_back(), true);
+
+        wait_and_check_test_result(fallback_result, || {}).await;
+    }
+
     /// Checks that [`RoomHandle::set_local_media_settings`] will disable media
     /// types on rollback fail.
     #[wasm_bindgen_test]
@@ -2907,6 +2970,7 @@ mod state_synchronization {
             state::Receiver {
                 id: TrackId(1),
                 muted: false,
+                paused: false,
                 media_direction: MediaDirection::SendRecv,
                 sender_id: "".into(),
                 media_type: MediaType::Audio(AudioSettings { required: true }),
@@ -3080,6 +3144,310 @@ mod state_synchronization {
     }
 }
 
+/// Checks that a negotiation isn't blocked by a pending local media
+/// acquisition if early media is enabled, and that the acquired local media is
+/// added via a renegotiation afterwards.
+#[wasm_bindgen_test]
+async fn early_media_negotiates_before_local_media_is_acquired() {
+    let (event_tx, event_rx) = mpsc::unbounded();
+    let (room, mut commands_rx) = get_test_room(Box::pin(event_rx));
+    let room_handle = api::RoomHandle::from(room.new_handle());
+    room_handle.set_early_media(true).unwrap();
+    JsFuture::from(room_handle.set_local_media_settings(
+        &media_stream_settings(true, false),
+        false,
+        false,
+    ))
+    .await
+    .unwrap();
+
+    let mock = MockNavigator::new();
+    mock.hold_get_user_media();
+
+    let (audio_track, _) = get_test_tracks(false, false);
+    let audio_track_id = audio_track.id;
+    event_tx
+        .unbounded_send(Event::PeerCreated {
+            peer_id: PeerId(0),
+            negotiation_role: NegotiationRole::Offerer,
+            tracks: vec![audio_track],
+            ice_servers: Vec::new(),
+            force_relay: false,
+            connection_mode: ConnectionMode::Mesh,
+        })
+        .unwrap();
+
+    let sdp_offer = timeout(1000, async {
+        loop {
+            if let Command::MakeSdpOffer { peer_id, sdp_offer, .. } =
+                commands_rx.next().await.unwrap()
+            {
+                assert_eq!(peer_id, PeerId(0));
+                break sdp_offer;
+            }
+        }
+    })
+    .await
+    .unwrap();
+    let peer = room.get_peer_by_id(PeerId(0)).unwrap();
+    assert_eq!(mock.get_user_media_requests_count(), 1);
+    assert!(
+        peer.get_sender_by_id(audio_track_id)
+            .unwrap()
+            .get_send_track()
+            .is_none()
+    );
+
+    event_tx
+        .unbounded_send(Event::PeerCreated {
+            peer_id: PeerId(1),
+            negotiation_role: NegotiationRole::Answerer(sdp_offer),
+            tracks: vec![Track {
+                id: audio_track_id,
+                direction: Direction::Recv {
+                    sender: MemberId::from("Alice"),
+                    mid: Some(String::from("0")),
+                },
+                media_direction: MediaDirection::SendRecv,
+                muted: false,
+                media_type: MediaType::Audio(AudioSettings { required: true }),
+            }],
+            ice_servers: Vec::new(),
+            force_relay: false,
+            connection_mode: ConnectionMode::Mesh,
+        })
+        .unwrap();
+    timeout(1000, async {
+        loop {
+            if let Command::MakeSdpAnswer { sdp_answer, .. } =
+                commands_rx.next().await.unwrap()
+            {
+                event_tx
+                    .unbounded_send(Event::SdpAnswerMade {
+                        peer_id: PeerId(0),
+                        sdp_answer,
+                    })
+                    .unwrap();
+                break;
+            }
+        }
+    })
+    .await
+    .unwrap();
+
+    mock.release_get_user_media();
+    timeout(1000, async {
+        loop {
+            if let Command::RequestRenegotiation { peer_id } =
+                commands_rx.next().await.unwrap()
+            {
+                assert_eq!(peer_id, PeerId(0));
+                break;
+            }
+        }
+    })
+    .await
+    .unwrap();
+    event_tx
+        .unbounded_send(Event::PeerUpdated {
+            peer_id: PeerId(0),
+            updates: Vec::new(),
+            negotiation_role: Some(NegotiationRole::Offerer),
+        })
+        .unwrap();
+    timeout(1000, async {
+        loop {
+            if let Command::MakeSdpOffer { peer_id, .. } =
+                commands_rx.next().await.unwrap()
+            {
+                assert_eq!(peer_id, PeerId(0));
+                break;
+            }
+        }
+    })
+    .await
+    .unwrap();
+    assert!(
+        peer.get_sender_by_id(audio_track_id)
+            .unwrap()
+            .get_send_track()
+            .is_some()
+    );
+
+    mock.stop();
+}
+
+/// Checks that [`RoomHandle::renegotiate()`] calls made during a negotiation
+/// are coalesced into a single renegotiation requested from Media Server once
+/// the negotiation finishes, and that the client doesn't make an SDP offer
+/// until Media Server assigns it a [`NegotiationRole`].
+///
+/// [`RoomHandle::renegotiate()`]: medea_jason::room::RoomHandle::renegotiate
+#[wasm_bindgen_test]
+async fn renegotiation_requests_are_coalesced() {
+    let (event_tx, event_rx) = mpsc::unbounded();
+    let (room, mut commands_rx) = get_test_room(Box::pin(event_rx));
+    let room_handle = room.new_handle();
+
+    let (audio_track, video_track) = get_test_unrequired_tracks();
+    event_tx
+        .unbounded_send(Event::PeerCreated {
+            peer_id: PeerId(0),
+            negotiation_role: NegotiationRole::Offerer,
+            tracks: vec![audio_track, video_track],
+            ice_servers: Vec::new(),
+            force_relay: false,
+            connection_mode: ConnectionMode::Mesh,
+        })
+        .unwrap();
+    let sdp_offer = timeout(1000, async {
+        loop {
+            if let Command::MakeSdpOffer { sdp_offer, .. } =
+                commands_rx.next().await.unwrap()
+            {
+                break sdp_offer;
+            }
+        }
+    })
+    .await
+    .unwrap();
+
+    // Negotiation is in progress, so the requests are queued.
+    room_handle.renegotiate(PeerId(0)).unwrap();
+    room_handle.renegotiate(PeerId(0)).unwrap();
+    room_handle.renegotiate(PeerId(0)).unwrap();
+
+    let answerer = platform::RtcPeerConnection::new(
+        Vec::new(),
+        false,
+        platform::BundlePolicy::default(),
+        platform::RtcpMuxPolicy::default(),
+    )
+    .await
+    .unwrap();
+    answerer.set_offer(&sdp_offer).await.unwrap();
+    let sdp_answer = answerer.create_answer().await.unwrap();
+    event_tx
+        .unbounded_send(Event::SdpAnswerMade { peer_id: PeerId(0), sdp_answer })
+        .unwrap();
+
+    timeout(1000, async {
+        loop {
+            if let Command::RequestRenegotiation { peer_id } =
+                commands_rx.next().await.unwrap()
+            {
+                assert_eq!(peer_id, PeerId(0));
+                break;
+            }
+        }
+    })
+    .await
+    .unwrap();
+
+    // Requests made while waiting for Media Server are coalesced as well.
+    room_handle.renegotiate(PeerId(0)).unwrap();
+    delay_for(200).await;
+    while let Ok(Some(command)) = commands_rx.try_next() {
+        assert!(
+            !matches!(
+                command,
+                Command::RequestRenegotiation { .. }
+                    | Command::MakeSdpOffer { .. }
+            ),
+            "unexpected command: {command:?}",
+        );
+    }
+
+    event_tx
+        .unbounded_send(Event::PeerUpdated {
+            peer_id: PeerId(0),
+            updates: Vec::new(),
+            negotiation_role: Some(NegotiationRole::Offerer),
+        })
+        .unwrap();
+    timeout(1000, async {
+        loop {
+            match commands_rx.next().await.unwrap() {
+                Command::MakeSdpOffer { peer_id, .. } => {
+                    assert_eq!(peer_id, PeerId(0));
+                    break;
+                }
+                Command::RequestRenegotiation { .. } => {
+                    panic!("renegotiation is requested more than once");
+                }
+                _ => (),
+            }
+        }
+    })
+    .await
+    .unwrap();
+}
+
+/// Checks that a renegotiation is requested from Media Server again, if it
+/// hasn't assigned any [`NegotiationRole`] in response to the previous
+/// request.
+#[wasm_bindgen_test]
+async fn ignored_renegotiation_request_is_repeated() {
+    let (event_tx, event_rx) = mpsc::unbounded();
+    let (room, mut commands_rx) = get_test_room(Box::pin(event_rx));
+    let room_handle = api::RoomHandle::from(room.new_handle());
+
+    let (audio_track, video_track) = get_test_unrequired_tracks();
+    event_tx
+        .unbounded_send(Event::PeerCreated {
+            peer_id: PeerId(0),
+            negotiation_role: NegotiationRole::Offerer,
+            tracks: vec![audio_track, video_track],
+            ice_servers: Vec::new(),
+            force_relay: false,
+            connection_mode: ConnectionMode::Mesh,
+        })
+        .unwrap();
+    let sdp_offer = timeout(1000, async {
+        loop {
+            if let Command::MakeSdpOffer { sdp_offer, .. } =
+                commands_rx.next().await.unwrap()
+            {
+                break sdp_offer;
+            }
+        }
+    })
+    .await
+    .unwrap();
+    let answerer = platform::RtcPeerConnection::new(
+        Vec::new(),
+        false,
+        platform::BundlePolicy::default(),
+        platform::RtcpMuxPolicy::default(),
+    )
+    .await
+    .unwrap();
+    answerer.set_offer(&sdp_offer).await.unwrap();
+    let sdp_answer = answerer.create_answer().await.unwrap();
+    event_tx
+        .unbounded_send(Event::SdpAnswerMade { peer_id: PeerId(0), sdp_answer })
+        .unwrap();
+
+    for _ in 0..2 {
+        room_handle.renegotiate().unwrap();
+        timeout(1000, async {
+            loop {
+                if let Command::RequestRenegotiation { peer_id } =
+                    commands_rx.next().await.unwrap()
+                {
+                    assert_eq!(peer_id, PeerId(0));
+                    break;
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        // Media Server ignores the request, so it times out.
+        delay_for(700).await;
+    }
+}
+
 /// Checks that [`MediaState`] intentions are sent after [`peer::State`]
 /// synchronization.
 #[wasm_bindgen_test]
@@ -3163,8 +3531,14 @@ async fn sender_answerer() {
     .await
     .unwrap();
 
-    let peer =
-        platform::RtcPeerConnection::new(Vec::new(), false).await.unwrap();
+    let peer = platform::RtcPeerConnection::new(
+        Vec::new(),
+        false,
+        platform::BundlePolicy::default(),
+        platform::RtcpMuxPolicy::default(),
+    )
+    .await
+    .unwrap();
 
     let a_tr = peer
         .add_transceiver(
@@ -3247,3 +3621,281 @@ async fn sender_answerer() {
         }
     }
 }
+
+/// Checks that [`Event::IceServersUpdated`] replaces [`IceServer`]s of all the
+/// [`PeerConnection`]s in a [`Room`].
+#[wasm_bindgen_test]
+async fn ice_servers_updated() {
+    use medea_client_api_proto::IceServer;
+    use medea_jason::utils::AsProtoState as _;
+
+    let (room, _peer, event_tx, _command_rx) =
+        get_test_room_and_exist_peer(Vec::new(), None).await;
+
+    let ice_servers = vec![IceServer {
+        urls: vec!["turn:turn.example.com:3478".to_owned()],
+        username: None,
+        credential: None,
+    }];
+    event_tx
+        .unbounded_send(Event::IceServersUpdated {
+            ice_servers: ice_servers.clone(),
+        })
+        .unwrap();
+    delay_for(100).await;
+
+    let state = room.peers_state().as_proto();
+    assert_eq!(state.peers[&PeerId(1)].ice_servers, ice_servers);
+}
+
+/// Checks that [`PeerConnection`]s created in a [`Room`] inherit its default
+/// [`IceServer`]s, unless the media server provides its own ones.
+#[wasm_bindgen_test]
+async fn peers_inherit_default_ice_servers() {
+    use medea_client_api_proto::IceServer;
+
+    let (event_tx, event_rx) = mpsc::unbounded();
+    let (room, _commands_rx) = get_test_room(Box::pin(event_rx));
+
+    let default_ice_servers = vec![IceServer {
+        urls: vec!["turn:default.example.com:3478".to_owned()],
+        username: None,
+        credential: None,
+    }];
+    let provided_ice_servers = vec![IceServer {
+        urls: vec!["turn:provided.example.com:3478".to_owned()],
+        username: None,
+        credential: None,
+    }];
+    room.set_default_ice_servers(default_ice_servers.clone());
+
+    for (peer_id, ice_servers) in
+        [(PeerId(1), Vec::new()), (PeerId(2), provided_ice_servers.clone())]
+    {
+        event_tx
+            .unbounded_send(Event::PeerCreated {
+                peer_id,
+                negotiation_role: NegotiationRole::Offerer,
+                tracks: Vec::new(),
+                ice_servers,
+                force_relay: false,
+                connection_mode: ConnectionMode::Mesh,
+            })
+            .unwrap();
+    }
+    delay_for(100).await;
+
+    assert_eq!(
+        room.get_peer_state_by_id(PeerId(1)).unwrap().rtc_ice_servers(),
+        default_ice_servers,
+    );
+    assert_eq!(
+        room.get_peer_state_by_id(PeerId(2)).unwrap().rtc_ice_servers(),
+        provided_ice_servers,
+    );
+}
+
+/// Checks that [`RoomHandle::send_application_message()`] sends a
+/// [`Command::SendApplicationMessage`] with the provided data, and rejects the
+/// data exceeding [`MAX_APPLICATION_MESSAGE_LEN`].
+///
+/// [`RoomHandle::send_application_message()`]:
+///     medea_jason::room::RoomHandle::send_application_message
+#[wasm_bindgen_test]
+async fn sends_application_message() {
+    use medea_jason::room::{
+        MAX_APPLICATION_MESSAGE_LEN, SendApplicationMessageError,
+    };
+
+    let (room, mut commands_rx) = get_test_room(stream::pending().boxed());
+    let room_handle = room.new_handle();
+
+    room_handle.send_application_message(vec![1, 2, 3]).unwrap();
+    assert_eq!(
+        commands_rx.next().await.unwrap(),
+        Command::SendApplicationMessage { data: vec![1, 2, 3] },
+    );
+
+    let err = room_handle
+        .send_application_message(vec![0; MAX_APPLICATION_MESSAGE_LEN + 1])
+        .unwrap_err();
+    assert!(matches!(
+        err.into_inner(),
+        SendApplicationMessageError::TooLarge(len)
+            if len == MAX_APPLICATION_MESSAGE_LEN + 1,
+    ));
+    assert!(commands_rx.try_next().is_err());
+}
+
+/// Checks that [`Event::ApplicationMessageReceived`] is delivered to the
+/// [`Connection`] with its sender.
+///
+/// [`Connection`]: medea_jason::connection::Connection
+#[wasm_bindgen_test]
+async fn delivers_application_message_to_connection() {
+    let (audio_track, video_track) = get_test_tracks(false, false);
+    let (room, _peer, event_tx, _commands_rx) =
+        get_test_room_and_exist_peer(vec![audio_track, video_track], None)
+            .await;
+
+    let (cb, test_result) = js_callback!(|msg: JsValue| {
+        let msg =
+            jsval_cast::<api::ApplicationMessage>(msg, "ApplicationMessage")
+                .unwrap();
+        cb_assert_eq!(msg.data(), vec![4, 5, 6]);
+    });
+    room.get_connection(&MemberId::from("bob"))
+        .unwrap()
+        .new_handle()
+        .on_application_message(js_sys::Function::from(cb).into())
+        .unwrap();
+
+    event_tx
+        .unbounded_send(Event::ApplicationMessageReceived {
+            member_id: MemberId::from("bob"),
+            data: vec![4, 5, 6],
+        })
+        .unwrap();
+
+    wait_and_check_test_result(test_result, || {}).await;
+}
+
+mod leave {
+    use std::{cell::RefCell, str::FromStr as _};
+
+    use medea_jason::rpc::{
+        ClientDisconnect, CloseReason, ConnectionInfo, SessionState,
+    };
+
+    use super::*;
+
+    /// Call made to a [`MockRpcSession`] of a leaving [`Room`].
+    #[derive(Debug)]
+    enum RpcCall {
+        /// [`Command`] sent to a media server.
+        Command(Command),
+
+        /// Request to leave the [`Room`].
+        Leave,
+
+        /// Closing of the [`MockRpcSession`].
+        Close,
+    }
+
+    /// Returns a joined [`Room`] with a single [`PeerConnection`] and a log
+    /// of all the [`RpcCall`]s made to its [`MockRpcSession`].
+    ///
+    /// If `acknowledge` is `true`, then the leaving request is acknowledged
+    /// right away.
+    async fn get_joined_room(
+        acknowledge: bool,
+    ) -> (Room, Rc<RefCell<Vec<RpcCall>>>, mpsc::UnboundedSender<Event>) {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let (event_tx, event_rx) = mpsc::unbounded();
+        let (ack_tx, ack_rx) = oneshot::channel();
+        let ack_tx = RefCell::new(Some(ack_tx));
+        let info = Rc::new(ConnectionInfo::from_str(TEST_ROOM_URL).unwrap());
+
+        let mut rpc = MockRpcSession::new();
+        rpc.expect_subscribe().return_once(move || Box::pin(event_rx));
+        rpc.expect_on_connection_loss()
+            .return_once(|| stream::pending().boxed_local());
+        rpc.expect_on_reconnected()
+            .return_once(|| stream::pending().boxed_local());
+        rpc.expect_state()
+            .returning_st(move || SessionState::Opened(Rc::clone(&info)));
+        rpc.expect_send_command().returning_st({
+            let calls = Rc::clone(&calls);
+            move |cmd| calls.borrow_mut().push(RpcCall::Command(cmd))
+        });
+        rpc.expect_leave().returning_st({
+            let calls = Rc::clone(&calls);
+            move || {
+                calls.borrow_mut().push(RpcCall::Leave);
+                if acknowledge {
+                    if let Some(tx) = ack_tx.borrow_mut().take() {
+                        _ = tx.send(());
+                    }
+                }
+            }
+        });
+        rpc.expect_on_normal_close().return_once(move || {
+            Box::pin(async move {
+                if ack_rx.await.is_err() {
+                    future::pending::<()>().await;
+                }
+                CloseReason::ByClient {
+                    reason: ClientDisconnect::RoomClosed,
+                    is_err: false,
+                }
+            })
+        });
+        rpc.expect_close_with_reason().returning_st({
+            let calls = Rc::clone(&calls);
+            move |_| calls.borrow_mut().push(RpcCall::Close)
+        });
+
+        let room = Room::new(Rc::new(rpc), Rc::default());
+        let (audio_track, video_track) = get_test_recv_tracks();
+        event_tx
+            .unbounded_send(Event::PeerCreated {
+                peer_id: PeerId(1),
+                negotiation_role: NegotiationRole::Offerer,
+                tracks: vec![audio_track, video_track],
+                ice_servers: Vec::new(),
+                force_relay: false,
+                connection_mode: ConnectionMode::Mesh,
+            })
+            .unwrap();
+        // wait until Event::PeerCreated is handled
+        delay_for(200).await;
+        calls.borrow_mut().clear();
+
+        (room, calls, event_tx)
+    }
+
+    /// Checks that [`RoomHandle::leave`] sends the final stats before the
+    /// leaving request and returns once the leaving is acknowledged.
+    ///
+    /// [`RoomHandle::leave`]: medea_jason::room::RoomHandle::leave
+    #[wasm_bindgen_test]
+    async fn leave_sends_final_stats_first_and_waits_acknowledgement() {
+        let (room, calls, _event_tx) = get_joined_room(true).await;
+        let handle = api::RoomHandle::from(room.new_handle());
+
+        timeout(1000, JsFuture::from(handle.leave(10_000)))
+            .await
+            .unwrap()
+            .unwrap();
+
+        let calls = calls.borrow();
+        let leave_pos =
+            calls.iter().position(|c| matches!(c, RpcCall::Leave)).unwrap();
+        assert!(calls[leave_pos..].iter().all(|c| !matches!(
+            c,
+            RpcCall::Command(Command::AddPeerConnectionMetrics { .. }),
+        )));
+        assert!(matches!(calls.last(), Some(RpcCall::Close)));
+    }
+
+    /// Checks that [`RoomHandle::leave`] closes the [`Room`] once the provided
+    /// timeout passes, if the leaving isn't acknowledged.
+    ///
+    /// [`RoomHandle::leave`]: medea_jason::room::RoomHandle::leave
+    #[wasm_bindgen_test]
+    async fn leave_closes_room_on_timeout() {
+        let (room, calls, _event_tx) = get_joined_room(false).await;
+        let handle = api::RoomHandle::from(room.new_handle());
+
+        let leave = JsFuture::from(handle.leave(300));
+        spawn_local(async move {
+            _ = leave.await;
+        });
+
+        delay_for(100).await;
+        assert!(matches!(calls.borrow().last(), Some(RpcCall::Leave)));
+
+        delay_for(400).await;
+        assert!(matches!(calls.borrow().last(), Some(RpcCall::Close)));
+    }
+}
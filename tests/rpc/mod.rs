@@ -4,6 +4,8 @@ mod heartbeat;
 mod reconnect_handle;
 mod rpc_session;
 mod websocket;
+mod whep;
+mod whip;
 
 use std::{cell::Cell, collections::HashMap, rc::Rc};
 
@@ -31,6 +33,7 @@ wasm_bindgen_test_configure!(run_in_browser);
 pub const RPC_SETTINGS: ServerMsg = ServerMsg::RpcSettings(RpcSettings {
     idle_timeout_ms: 5_000,
     ping_interval_ms: 2_000,
+    time_sync: false,
 });
 
 /// Creates [`WebSocketRpcClient`] with the provided [`MockRpcTransport`].
@@ -77,6 +80,7 @@ async fn message_received_from_transport_is_transmitted_to_sub() {
                 ServerMsg::RpcSettings(RpcSettings {
                     idle_timeout_ms: 10_000,
                     ping_interval_ms: 10_000,
+                    time_sync: false,
                 }),
                 ServerMsg::Event { room_id: "".into(), event: SRV_EVENT },
             ])
@@ -121,6 +125,7 @@ async fn transport_is_dropped_when_client_is_dropped() {
         on_message_mock(RpcSettings {
             idle_timeout_ms: 10_000,
             ping_interval_ms: 500,
+            time_sync: false,
         })
     });
     let rpc_transport = Rc::new(transport);
@@ -156,6 +161,7 @@ async fn send_goes_to_transport() {
         on_message_mock(RpcSettings {
             idle_timeout_ms: 10_000,
             ping_interval_ms: 500,
+            time_sync: false,
         })
     });
     transport.expect_send().returning(move |e| {
@@ -203,6 +209,80 @@ async fn send_goes_to_transport() {
     timeout(1000, test_rx).await.unwrap().unwrap();
 }
 
+/// Connects [`WebSocketRpcClient`] to a server with the provided
+/// [`RpcSettings::time_sync`] and returns the number of
+/// [`ClientMsg::TimeSyncRequest`]s it sends.
+async fn sent_time_sync_requests(time_sync: bool) -> u32 {
+    let mut transport = MockRpcTransport::new();
+    transport.expect_connect().return_once(|_| Box::pin(future::ok(())));
+    transport
+        .expect_on_state_change()
+        .return_once(|| stream::once(async { TransportState::Open }).boxed());
+    transport.expect_on_message().returning(move || {
+        on_message_mock(RpcSettings {
+            idle_timeout_ms: 10_000,
+            ping_interval_ms: 500,
+            time_sync,
+        })
+    });
+    let requests = Rc::new(Cell::new(0));
+    let sent = Rc::clone(&requests);
+    transport.expect_send().returning(move |msg| {
+        if matches!(msg, ClientMsg::TimeSyncRequest(_)) {
+            sent.set(sent.get() + 1);
+        }
+        Ok(())
+    });
+    transport.expect_set_close_reason().return_const(());
+
+    let ws = new_client(Rc::new(transport));
+    ws.clone().connect(join_room_url()).await.unwrap();
+    delay_for(300).await;
+
+    requests.get()
+}
+
+/// Tests that [`ClientMsg::TimeSyncRequest`]s are sent only if the server
+/// advertised their support via [`RpcSettings::time_sync`].
+#[wasm_bindgen_test]
+async fn time_sync_requests_are_sent_only_if_supported() {
+    assert_eq!(sent_time_sync_requests(false).await, 0);
+    assert!(sent_time_sync_requests(true).await > 0);
+}
+
+/// Tests that [`WebSocketRpcClient::metrics`] counts sent and received
+/// messages.
+#[wasm_bindgen_test]
+async fn metrics_count_messages() {
+    let mut transport = MockRpcTransport::new();
+    transport.expect_connect().return_once(|_| Box::pin(future::ok(())));
+    transport
+        .expect_on_state_change()
+        .return_once(|| stream::once(async { TransportState::Open }).boxed());
+    transport.expect_on_message().returning(|| {
+        on_message_mock(RpcSettings {
+            idle_timeout_ms: 10_000,
+            ping_interval_ms: 500,
+            time_sync: false,
+        })
+    });
+    transport.expect_send().returning(|_| Ok(()));
+    transport.expect_set_close_reason().return_const(());
+
+    let ws = new_client(Rc::new(transport));
+    ws.clone().connect(join_room_url()).await.unwrap();
+
+    let before = ws.metrics();
+    ws.send_command("".into(), Command::LeaveRoom { member_id: "".into() });
+    let after = ws.metrics();
+
+    assert_eq!(before.messages_received, 1);
+    assert_eq!(after.messages_sent, before.messages_sent + 1);
+    assert!(after.bytes_sent > before.bytes_sent);
+    assert_eq!(after.reconnects, 0);
+    assert!(after.last_error.is_none());
+}
+
 /// Tests for [`WebSocketRpcClient::on_close`].
 mod on_close {
     use super::*;
@@ -224,6 +304,7 @@ mod on_close {
             on_message_mock(RpcSettings {
                 idle_timeout_ms: 10_000,
                 ping_interval_ms: 500,
+                time_sync: false,
             })
         });
         transport.expect_send().returning(|_| Ok(()));
@@ -291,7 +372,7 @@ mod on_close {
     ///    considered passed.
     #[wasm_bindgen_test]
     async fn dont_resolve_on_abnormal_close() {
-        let ws = get_client(CloseMsg::Abnormal(1500)).await;
+        let ws = get_client(CloseMsg::Abnormal(1500, String::new())).await;
 
         timeout(500, ws.on_normal_close()).await.unwrap_err();
     }
@@ -316,6 +397,7 @@ mod transport_close_reason_on_drop {
             on_message_mock(RpcSettings {
                 idle_timeout_ms: 10000,
                 ping_interval_ms: 500,
+                time_sync: false,
             })
         });
         transport.expect_send().return_once(|_| Ok(()));
@@ -415,6 +497,7 @@ mod connect {
                 on_message_mock(RpcSettings {
                     idle_timeout_ms: 3_000,
                     ping_interval_ms: 3_000,
+                    time_sync: false,
                 })
             });
             transport.expect_send().return_once(|_| Ok(()));
@@ -455,6 +538,7 @@ mod connect {
                 on_message_mock(RpcSettings {
                     idle_timeout_ms: 3_000,
                     ping_interval_ms: 3_000,
+                    time_sync: false,
                 })
             });
             transport.expect_send().return_once(|_| Ok(()));
@@ -504,6 +588,7 @@ mod connect {
                 on_message_mock(RpcSettings {
                     idle_timeout_ms: 3_000,
                     ping_interval_ms: 3_000,
+                    time_sync: false,
                 })
             });
             transport.expect_send().return_once(|_| Ok(()));
@@ -541,10 +626,12 @@ mod on_connection_loss {
                 on_message_mock(RpcSettings {
                     idle_timeout_ms: idle_timeout_ms.unwrap_or(u32::MAX),
                     ping_interval_ms: ping_interval_ms.unwrap_or(u32::MAX),
+                    time_sync: false,
                 })
             });
             transport.expect_set_close_reason().return_once(drop);
 
+            let transport_changes = transport_changes.clone();
             transport.expect_on_state_change().return_once(move || {
                 stream::once(async move { TransportState::Open })
                     .chain(
@@ -613,7 +700,7 @@ mod on_connection_loss {
         }
 
         connection_loss_helper(
-            TransportState::Closed(CloseMsg::Abnormal(1006)),
+            TransportState::Closed(CloseMsg::Abnormal(1006, String::new())),
             true,
             false,
         )
@@ -706,6 +793,7 @@ mod on_reconnected {
                 on_message_mock(RpcSettings {
                     idle_timeout_ms: 5_000,
                     ping_interval_ms: 2_000,
+                    time_sync: false,
                 })
             });
             transport.expect_send().return_once(|_| Ok(()));
@@ -730,6 +818,7 @@ mod on_reconnected {
             Rc::new(ObservableCell::new(ServerMsg::RpcSettings(RpcSettings {
                 idle_timeout_ms: 5_000,
                 ping_interval_ms: 2_000,
+                time_sync: false,
             })));
         let on_state_change_mock =
             Rc::new(ObservableCell::new(TransportState::Open));
@@ -760,8 +849,10 @@ mod on_reconnected {
         let mut on_reconnected_stream = ws.on_reconnected();
         ws.clone().connect(join_room_url()).await.unwrap();
 
-        on_state_change_mock
-            .set(TransportState::Closed(CloseMsg::Abnormal(1006)));
+        on_state_change_mock.set(TransportState::Closed(CloseMsg::Abnormal(
+            1006,
+            String::new(),
+        )));
         // Release async runtime so State::Closed can be processed.
         yield_now().await;
 
@@ -769,6 +860,7 @@ mod on_reconnected {
         on_message_mock.set(ServerMsg::RpcSettings(RpcSettings {
             idle_timeout_ms: 5_000,
             ping_interval_ms: 2_000,
+            time_sync: false,
         }));
 
         ws.connect(join_room_url()).await.unwrap();
@@ -60,7 +60,8 @@ async fn reconnect_with_backoff() {
         .connect(ConnectionInfo::from_str(TEST_ROOM_URL).unwrap());
     timeout(100, connect_fut).await.unwrap().unwrap();
 
-    transport_state.set(TransportState::Closed(CloseMsg::Abnormal(999)));
+    transport_state
+        .set(TransportState::Closed(CloseMsg::Abnormal(999, String::new())));
     timeout(100, session.on_connection_loss().next()).await.unwrap().unwrap();
     let handle =
         ReconnectHandle::new(Rc::downgrade(&session) as Weak<dyn RpcSession>);
@@ -103,7 +104,8 @@ async fn reconnect_with_backoff() {
     err.unwrap();
 
     // Checks that ReconnectError::Detached is fired when session is dropped.
-    transport_state.set(TransportState::Closed(CloseMsg::Abnormal(999)));
+    transport_state
+        .set(TransportState::Closed(CloseMsg::Abnormal(999, String::new())));
     timeout(100, session.on_connection_loss().next()).await.unwrap().unwrap();
 
     platform::spawn(async move {
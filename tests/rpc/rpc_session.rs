@@ -1,13 +1,13 @@
 #![cfg(target_arch = "wasm32")]
 
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     rc::Rc,
     str::FromStr,
     sync::atomic::{AtomicBool, Ordering},
 };
 
-use futures::{FutureExt as _, StreamExt as _, future, stream};
+use futures::{FutureExt as _, StreamExt as _, channel::mpsc, future, stream};
 use medea_client_api_proto::{
     ClientMsg, CloseReason, Command, Event, ServerMsg,
 };
@@ -17,10 +17,11 @@ use medea_jason::{
         WebSocketRpcTransport,
     },
     rpc::{
-        CloseMsg, ConnectionInfo, RpcSession, SessionError, WebSocketRpcClient,
-        WebSocketRpcSession,
+        CloseMsg, ConnectionInfo, RpcSession, SessionError, TokenRefresher,
+        WebSocketRpcClient, WebSocketRpcClientPool, WebSocketRpcSession,
     },
 };
+use wasm_bindgen::JsValue;
 use wasm_bindgen_test::*;
 
 use crate::{TEST_ROOM_URL, delay_for, rpc::RPC_SETTINGS, timeout};
@@ -209,7 +210,10 @@ async fn reconnect_after_transport_abnormal_close() {
                     stream::once(future::ready(TransportState::Open)).chain(
                         stream::once(async {
                             delay_for(20).await;
-                            TransportState::Closed(CloseMsg::Abnormal(999))
+                            TransportState::Closed(CloseMsg::Abnormal(
+                                999,
+                                String::new(),
+                            ))
                         }),
                     ),
                 )
@@ -266,3 +270,182 @@ async fn reconnect_after_transport_abnormal_close() {
         ],
     );
 }
+
+/// Makes sure that reconnect uses a token provided by the `TokenRefresher`, and
+/// fails if the `TokenRefresher` fails.
+#[wasm_bindgen_test]
+async fn reconnect_with_refreshed_token() {
+    let commands_sent = Rc::new(RefCell::new(Vec::new()));
+
+    let commands_sent_clone = Rc::clone(&commands_sent);
+    let session = WebSocketRpcSession::new(Rc::new(WebSocketRpcClient::new(
+        Box::new(move || {
+            let commands_sent_clone = Rc::clone(&commands_sent_clone);
+            let mut transport = MockRpcTransport::new();
+            transport
+                .expect_connect()
+                .return_once(|_| Box::pin(future::ok(())));
+            transport.expect_on_message().returning_st(|| {
+                Box::pin(stream::iter(vec![
+                    RPC_SETTINGS,
+                    ServerMsg::Event {
+                        room_id: "room_id".into(),
+                        event: Event::RoomJoined {
+                            member_id: "member_id".into(),
+                        },
+                    },
+                ]))
+            });
+            let commands_sent = Rc::clone(&commands_sent_clone);
+            transport.expect_send().returning_st(move |msg| {
+                commands_sent.borrow_mut().push(msg.clone());
+                Ok(())
+            });
+            transport.expect_set_close_reason().return_once(drop);
+            transport.expect_on_state_change().return_once_st(move || {
+                Box::pin(
+                    stream::once(future::ready(TransportState::Open)).chain(
+                        stream::once(async {
+                            delay_for(20).await;
+                            TransportState::Closed(CloseMsg::Abnormal(
+                                999,
+                                String::new(),
+                            ))
+                        }),
+                    ),
+                )
+            });
+            let transport = Rc::new(transport);
+            transport as Rc<dyn RpcTransport>
+        }),
+    )));
+
+    let refresh_fails = Rc::new(AtomicBool::new(true));
+    let refresh_fails_clone = Rc::clone(&refresh_fails);
+    session.set_token_refresher(Some(TokenRefresher::new(move || {
+        let fails = refresh_fails_clone.swap(false, Ordering::Relaxed);
+        async move {
+            if fails {
+                Err(platform::Error::from(JsValue::from_str("no token")))
+            } else {
+                Ok("fresh_token".to_owned())
+            }
+        }
+        .boxed_local()
+    })));
+
+    let mut on_reconnected = session.on_reconnected().fuse();
+    let mut on_connection_loss = session.on_connection_loss().fuse();
+
+    let connect_fut = Rc::clone(&session)
+        .connect(ConnectionInfo::from_str(TEST_ROOM_URL).unwrap());
+    timeout(100, connect_fut).await.unwrap().unwrap();
+
+    timeout(100, on_connection_loss.select_next_some()).await.unwrap();
+
+    // failed token refresh fails reconnect
+    let err = Rc::clone(&session).reconnect().await.unwrap_err().into_inner();
+    assert!(matches!(err, SessionError::TokenRefreshFailed(_)));
+
+    // successful reconnect with a fresh token
+    Rc::clone(&session).reconnect().await.unwrap();
+    on_reconnected.select_next_some().await;
+
+    let capabilities = platform::get_capabilities().await;
+
+    drop(session);
+    assert_eq!(
+        *commands_sent.borrow(),
+        vec![
+            // connect
+            ClientMsg::Command {
+                room_id: "room_id".into(),
+                command: Command::JoinRoom {
+                    member_id: "member_id".into(),
+                    credential: "token".into(),
+                    capabilities: capabilities.clone(),
+                },
+            },
+            // reconnect
+            ClientMsg::Command {
+                room_id: "room_id".into(),
+                command: Command::JoinRoom {
+                    member_id: "member_id".into(),
+                    credential: "fresh_token".into(),
+                    capabilities,
+                },
+            },
+        ],
+    );
+}
+
+/// Makes sure that [`WebSocketRpcSession`]s taking their [`WebSocketRpcClient`]
+/// from the same [`WebSocketRpcClientPool`] share a single [`RpcTransport`]
+/// when connecting to the same URL, and use separate ones otherwise.
+#[wasm_bindgen_test]
+async fn pooled_sessions_share_transport() {
+    let transports_created = Rc::new(Cell::new(0));
+
+    let transports_created_clone = Rc::clone(&transports_created);
+    let pool = Rc::new(WebSocketRpcClientPool::new(Rc::new(move || {
+        transports_created_clone.set(transports_created_clone.get() + 1);
+
+        let (msg_tx, msg_rx) = mpsc::unbounded();
+        let mut transport = MockRpcTransport::new();
+        transport.expect_connect().return_once(|_| Box::pin(future::ok(())));
+        transport
+            .expect_on_message()
+            .times(1)
+            .return_once_st(|| Box::pin(stream::iter(vec![RPC_SETTINGS])));
+        transport
+            .expect_on_message()
+            .times(1)
+            .return_once_st(move || Box::pin(msg_rx));
+        transport.expect_send().returning_st(move |msg| {
+            if let ClientMsg::Command {
+                room_id,
+                command: Command::JoinRoom { member_id, .. },
+            } = msg
+            {
+                msg_tx
+                    .unbounded_send(ServerMsg::Event {
+                        room_id: room_id.clone(),
+                        event: Event::RoomJoined {
+                            member_id: member_id.clone(),
+                        },
+                    })
+                    .unwrap();
+            }
+            Ok(())
+        });
+        transport.expect_set_close_reason().return_const(());
+        transport.expect_on_state_change().return_once_st(move || {
+            Box::pin(stream::once(async { TransportState::Open }))
+        });
+        let transport = Rc::new(transport);
+        transport as Rc<dyn RpcTransport>
+    })));
+
+    let session_a = WebSocketRpcSession::with_pool(Rc::clone(&pool));
+    let session_b = WebSocketRpcSession::with_pool(Rc::clone(&pool));
+    let session_c = WebSocketRpcSession::with_pool(pool);
+
+    for (session, url) in [
+        (&session_a, "ws://example.com/room_a/member_id?token=token"),
+        (&session_b, "ws://example.com/room_b/member_id?token=token"),
+    ] {
+        let connect =
+            Rc::clone(session).connect(ConnectionInfo::from_str(url).unwrap());
+        timeout(1000, connect).await.unwrap().unwrap();
+    }
+    assert_eq!(transports_created.get(), 1);
+
+    let connect = Rc::clone(&session_c).connect(
+        ConnectionInfo::from_str(
+            "ws://other.example.com/room_c/member_id?token=token",
+        )
+        .unwrap(),
+    );
+    timeout(1000, connect).await.unwrap().unwrap();
+    assert_eq!(transports_created.get(), 2);
+}
@@ -0,0 +1,75 @@
+#![cfg(target_arch = "wasm32")]
+
+use medea_jason::{jason::Jason, platform, rpc::WhepError};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+use crate::{MockFetch, wait_and_check_test_result};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// URL of the [WHEP] endpoint mocked by the [`MockFetch`].
+///
+/// [WHEP]: https://datatracker.ietf.org/doc/draft-ietf-wish-whep
+const ENDPOINT: &str = "https://whep.example/endpoint";
+
+/// Tests that [`WhepClient::play()`] `POST`s an SDP offer to the endpoint,
+/// exposes the received media via a new `Connection` and terminates the
+/// session via `DELETE` on close.
+///
+/// [`WhepClient::play()`]: medea_jason::rpc::WhepClient::play
+#[wasm_bindgen_test]
+async fn plays_from_whep_endpoint() {
+    let mock = MockFetch::new();
+    mock.set_answer_with_media(true);
+    let jason = Jason::new(None);
+    let client = jason.whep_client();
+
+    let (cb, test_result) = js_callback!(|handle: JsValue| {
+        cb_assert_eq!(handle.is_object(), true);
+    });
+    client.on_new_connection(platform::Function::from(js_sys::Function::from(
+        cb,
+    )));
+
+    client.play(ENDPOINT.into(), Some("secret".into())).await.unwrap();
+
+    assert_eq!(
+        client.resource().as_deref(),
+        Some("https://whep.example/resource/1"),
+    );
+    assert_eq!(mock.requests()[0], format!("POST {ENDPOINT}"));
+    assert_eq!(mock.authorizations()[0], "Bearer secret");
+    wait_and_check_test_result(test_result, || {}).await;
+
+    let err = client.play(ENDPOINT.into(), None).await.unwrap_err();
+    assert!(matches!(err.into_inner(), WhepError::AlreadyStarted));
+
+    client.close().await.unwrap();
+
+    assert_eq!(
+        mock.requests().last().unwrap(),
+        "DELETE https://whep.example/resource/1",
+    );
+    assert_eq!(client.resource(), None);
+
+    mock.stop();
+}
+
+/// Tests that [`WhepClient::play()`] fails with a
+/// [`WhepError::UnexpectedStatus`] if the endpoint rejects the SDP offer.
+///
+/// [`WhepClient::play()`]: medea_jason::rpc::WhepClient::play
+#[wasm_bindgen_test]
+async fn fails_on_rejected_offer() {
+    let mock = MockFetch::new();
+    mock.set_response_status(401);
+    let client = Jason::new(None).whep_client();
+
+    let err = client.play(ENDPOINT.into(), None).await.unwrap_err();
+
+    assert!(matches!(err.into_inner(), WhepError::UnexpectedStatus(401)));
+    assert_eq!(client.resource(), None);
+
+    mock.stop();
+}
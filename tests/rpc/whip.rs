@@ -0,0 +1,80 @@
+#![cfg(target_arch = "wasm32")]
+
+use medea_jason::{jason::Jason, rpc::WhipError};
+use wasm_bindgen_test::*;
+
+use crate::{MockFetch, delay_for, get_media_stream_settings, timeout};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// URL of the [WHIP] endpoint mocked by the [`MockFetch`].
+///
+/// [WHIP]: https://tools.ietf.org/html/rfc9725
+const ENDPOINT: &str = "https://whip.example/endpoint";
+
+/// Tests that [`Jason::publish_whip()`] `POST`s an SDP offer to the endpoint,
+/// trickles ICE candidates to the created session resource and terminates it
+/// via `DELETE` on close.
+#[wasm_bindgen_test]
+async fn publishes_to_whip_endpoint() {
+    let mock = MockFetch::new();
+    let jason = Jason::new(None);
+
+    let client = jason
+        .publish_whip(
+            ENDPOINT.into(),
+            Some("secret".into()),
+            get_media_stream_settings(true, true),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(client.resource(), "https://whip.example/resource/1");
+    assert_eq!(mock.requests()[0], format!("POST {ENDPOINT}"));
+    assert_eq!(mock.authorizations()[0], "Bearer secret");
+
+    timeout(5000, async {
+        while !mock
+            .requests()
+            .contains(&"PATCH https://whip.example/resource/1".to_owned())
+        {
+            delay_for(50).await;
+        }
+    })
+    .await
+    .unwrap();
+
+    client.close().await.unwrap();
+
+    assert_eq!(
+        mock.requests().last().unwrap(),
+        "DELETE https://whip.example/resource/1",
+    );
+    assert!(mock.authorizations().iter().all(|a| a == "Bearer secret"));
+
+    mock.stop();
+}
+
+/// Tests that [`Jason::publish_whip()`] fails with a
+/// [`WhipError::UnexpectedStatus`] if the endpoint rejects the SDP offer.
+#[wasm_bindgen_test]
+async fn fails_on_rejected_offer() {
+    let mock = MockFetch::new();
+    mock.set_response_status(403);
+    let jason = Jason::new(None);
+
+    let err = jason
+        .publish_whip(
+            ENDPOINT.into(),
+            None,
+            get_media_stream_settings(true, false),
+        )
+        .await
+        .unwrap_err()
+        .into_inner();
+
+    assert!(matches!(err, WhipError::UnexpectedStatus(403)));
+    assert_eq!(mock.requests(), vec![format!("POST {ENDPOINT}")]);
+
+    mock.stop();
+}
@@ -77,6 +77,7 @@ macro_rules! js_callback {
     }}
 }
 
+mod connection;
 mod media;
 mod peer;
 mod room;
@@ -137,10 +138,39 @@ extern "C" {
         stream: web_sys::MediaStream,
     );
 
+    #[wasm_bindgen(method, js_name = holdGetUserMedia)]
+    fn hold_get_user_media(this: &MockNavigator);
+
+    #[wasm_bindgen(method, js_name = releaseGetUserMedia)]
+    fn release_get_user_media(this: &MockNavigator);
+
     #[wasm_bindgen(method)]
     fn stop(this: &MockNavigator);
 }
 
+#[wasm_bindgen(module = "/tests/mock_fetch.js")]
+extern "C" {
+    pub type MockFetch;
+
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> MockFetch;
+
+    #[wasm_bindgen(method, setter = responseStatus)]
+    fn set_response_status(this: &MockFetch, status: u16);
+
+    #[wasm_bindgen(method, setter = answerWithMedia)]
+    fn set_answer_with_media(this: &MockFetch, enabled: bool);
+
+    #[wasm_bindgen(method, getter = requestsLog)]
+    fn requests(this: &MockFetch) -> Vec<String>;
+
+    #[wasm_bindgen(method, getter = authorizationsLog)]
+    fn authorizations(this: &MockFetch) -> Vec<String>;
+
+    #[wasm_bindgen(method)]
+    fn stop(this: &MockFetch);
+}
+
 /// Performs a unchecked conversion from a [`JsValue`] into an instance of the
 /// specified `FromWasmAbi<Abi = u32>` implementor.
 pub fn jsval_cast<T: RefFromWasmAbi<Abi = u32>>(
@@ -339,7 +369,8 @@ async fn get_video_track() -> api::RemoteMediaTrack {
     let track = tracks.pop().unwrap().0.as_ref().as_ref().fork().await;
     remote::Track::new(
         track,
-        MediaSourceKind::Device,
+        MediaKind::Video,
+        Some(MediaSourceKind::Device),
         false,
         MediaDirection::SendRecv.into(),
     )
@@ -354,7 +385,8 @@ async fn get_audio_track() -> api::RemoteMediaTrack {
     let track = tracks.pop().unwrap().0.as_ref().as_ref().fork().await;
     remote::Track::new(
         track,
-        MediaSourceKind::Device,
+        MediaKind::Audio,
+        None,
         false,
         MediaDirection::SendRecv.into(),
     )